@@ -17,8 +17,73 @@ void shutdownJuce()
     juce::shutdownJuce_GUI();
 }
 
+void throwInvalidArgument (rust::Str message)
+{
+    throw std::invalid_argument (static_cast<std::string> (message));
+}
+
 rust::Str toStr (const juce::String& string)
 {
     return { string.toRawUTF8(), string.getNumBytesAsUTF8() };
 }
+
+MidiDeviceInfo toMidiDeviceInfo (const juce::MidiDeviceInfo& info)
+{
+    return { info.name.toStdString(), info.identifier.toStdString() };
+}
+
+rust::Vec<MidiDeviceInfo> toMidiDeviceInfos (const juce::Array<juce::MidiDeviceInfo>& infos)
+{
+    rust::Vec<MidiDeviceInfo> result;
+    result.reserve (static_cast<size_t> (infos.size()));
+    std::transform (
+        std::begin (infos),
+        std::end (infos),
+        std::back_inserter (result),
+        toMidiDeviceInfo);
+    return result;
+}
+
+juce::File toFile (rust::Str path)
+{
+    return juce::File::getCurrentWorkingDirectory().getChildFile (static_cast<std::string> (path));
+}
+
+rust::Vec<rust::String> toRustStrings (const juce::StringArray& strings)
+{
+    rust::Vec<rust::String> result;
+    result.reserve (static_cast<size_t> (strings.size()));
+
+    for (const auto& string : strings)
+    {
+        result.push_back (string.toStdString());
+    }
+
+    return result;
+}
+
+rust::Vec<StringPair> toStringPairs (const juce::StringPairArray& pairs)
+{
+    rust::Vec<StringPair> result;
+    result.reserve (static_cast<size_t> (pairs.size()));
+
+    for (const auto& key : pairs.getAllKeys())
+    {
+        result.push_back ({ key.toStdString(), pairs[key].toStdString() });
+    }
+
+    return result;
+}
+
+juce::StringPairArray toStringPairArray (rust::Slice<const StringPair> pairs)
+{
+    juce::StringPairArray result;
+
+    for (const auto& pair : pairs)
+    {
+        result.set (static_cast<std::string> (pair.key), static_cast<std::string> (pair.value));
+    }
+
+    return result;
+}
 } // namespace cxx_juce
\ No newline at end of file
@@ -4,6 +4,27 @@
 #include "juce_core/juce_core.h"
 #include "juce_events/juce_events.h"
 
+// The remaining modules are only linked when their Cargo features are enabled.
+#if JUCE_MODULE_AVAILABLE_juce_audio_formats
+#include "juce_audio_formats/juce_audio_formats.h"
+#endif
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_processors
+#include "juce_audio_processors/juce_audio_processors.h"
+#endif
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_utils
+#include "juce_audio_utils/juce_audio_utils.h"
+#endif
+
+#if JUCE_MODULE_AVAILABLE_juce_data_structures
+#include "juce_data_structures/juce_data_structures.h"
+#endif
+
+#if JUCE_MODULE_AVAILABLE_juce_dsp
+#include "juce_dsp/juce_dsp.h"
+#endif
+
 #include "rust/cxx.h"
 
 namespace juce
@@ -16,13 +37,74 @@ namespace cxx_juce
 {
 struct BoxedAudioIODeviceCallback;
 struct BoxedAudioIODeviceType;
+struct BoxedMidiInputCallback;
+struct MidiThru;
+struct BoxedPairingDialogueCallback;
+struct BoxedAsyncCallback;
+struct BoxedTimerCallback;
+struct BoxedHighResolutionTimerCallback;
+struct BoxedInterprocessConnectionListener;
+struct BoxedInterprocessConnectionFactory;
+struct BoxedValueTreeListener;
+struct BoxedPostProgressCallback;
+struct BoxedTimeSliceClient;
+struct BoxedThreadPoolJob;
+struct BoxedInputStream;
+struct BoxedCustomAudioFormatReader;
+struct BoxedCustomAudioFormat;
+struct BoxedCustomAudioFormatWriter;
+struct BoxedWaveShaperFunction;
+struct MidiDeviceInfo;
+struct MidiRPNMessage;
+struct StringPair;
+struct CpuFeatures;
+struct ZipEntry;
+struct Complex;
+struct ProcessSpec;
+struct ReverbParameters;
+struct PluginDescription;
+struct ParameterInfo;
+struct ParameterGroupEntry;
+struct BoxedAudioProcessorListener;
+struct BoxedPluginEditorWindowListener;
+struct BoxedAudioPlayHead;
+struct BoxedAudioProcessor;
+struct AudioParameter;
+struct PlayHeadPosition;
+struct AudioChannelSet;
+struct BusesLayout;
+struct BusInfo;
+struct NodeId;
+struct NodeAndChannel;
+struct Connection;
 
 rust::String juceVersion();
 
 void initialiseJuce();
 void shutdownJuce();
+void throwInvalidArgument (rust::Str message);
 
 rust::Str toStr (const juce::String& string);
+MidiDeviceInfo toMidiDeviceInfo (const juce::MidiDeviceInfo& info);
+rust::Vec<MidiDeviceInfo> toMidiDeviceInfos (const juce::Array<juce::MidiDeviceInfo>& infos);
+juce::File toFile (rust::Str path);
+
+rust::Vec<rust::String> toRustStrings (const juce::StringArray& strings);
+rust::Vec<StringPair> toStringPairs (const juce::StringPairArray& pairs);
+juce::StringPairArray toStringPairArray (rust::Slice<const StringPair> pairs);
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_formats
+std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::AudioFormat& format, rust::Str path);
+std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::AudioFormat& format, rust::Box<BoxedInputStream> stream);
+std::unique_ptr<juce::AudioFormatReader> createMemoryMappedReader (juce::AudioFormat& format, rust::Str path);
+std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::AudioFormat& format,
+                                                              rust::Str path,
+                                                              double sampleRate,
+                                                              juce::uint32 numChannels,
+                                                              int bitsPerSample,
+                                                              const juce::StringPairArray& metadata,
+                                                              int qualityOptionIndex);
+#endif
 
 struct AudioDeviceSetup
 {
@@ -72,6 +154,166 @@ private:
     rust::Box<BoxedAudioIODeviceCallback> _callback;
 };
 
+class MidiThruHandle : public juce::MidiInputCallback
+{
+public:
+    explicit MidiThruHandle (juce::AudioDeviceManager& audioDeviceManager,
+                             rust::Box<MidiThru> midiThru);
+    ~MidiThruHandle() override;
+
+    void handleIncomingMidiMessage (juce::MidiInput* source, const juce::MidiMessage& message) override;
+
+private:
+    juce::AudioDeviceManager& _audioDeviceManager;
+    rust::Box<MidiThru> _midiThru;
+};
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_processors
+class AudioProcessorListenerHandle : public juce::AudioProcessorListener
+{
+public:
+    explicit AudioProcessorListenerHandle (juce::AudioProcessor& processor,
+                                           rust::Box<BoxedAudioProcessorListener> listener);
+    ~AudioProcessorListenerHandle() override;
+
+    void audioProcessorParameterChanged (juce::AudioProcessor* processor, int parameterIndex, float newValue) override;
+    void audioProcessorChanged (juce::AudioProcessor* processor, const ChangeDetails& details) override;
+    void audioProcessorParameterChangeGestureBegin (juce::AudioProcessor* processor, int parameterIndex) override;
+    void audioProcessorParameterChangeGestureEnd (juce::AudioProcessor* processor, int parameterIndex) override;
+
+private:
+    juce::AudioProcessor& _processor;
+    rust::Box<BoxedAudioProcessorListener> _listener;
+};
+
+class AudioPlayHeadHandle : public juce::AudioPlayHead
+{
+public:
+    explicit AudioPlayHeadHandle (juce::AudioProcessor& processor, rust::Box<BoxedAudioPlayHead> playHead);
+    ~AudioPlayHeadHandle() override;
+
+    juce::Optional<PositionInfo> getPosition() const override;
+
+private:
+    juce::AudioProcessor& _processor;
+    rust::Box<BoxedAudioPlayHead> _playHead;
+};
+
+class PluginEditorWindow : public juce::DocumentWindow
+{
+public:
+    PluginEditorWindow (juce::AudioProcessorEditor* editor,
+                        const juce::String& title,
+                        rust::Box<BoxedPluginEditorWindowListener> listener);
+    ~PluginEditorWindow() override;
+
+    void closeButtonPressed() override;
+    void visibilityChanged() override;
+    void moved() override;
+
+private:
+    rust::Box<BoxedPluginEditorWindowListener> _listener;
+};
+
+namespace plugin_editor_window
+{
+    std::unique_ptr<PluginEditorWindow> createPluginEditorWindow (juce::AudioProcessor& processor,
+                                                                  rust::Str title,
+                                                                  rust::Box<BoxedPluginEditorWindowListener> listener);
+} // namespace plugin_editor_window
+
+class RustAudioProcessorParameter : public juce::AudioProcessorParameterWithID,
+                                    private juce::AudioProcessorParameter::Listener
+{
+public:
+    RustAudioProcessorParameter (const ParameterInfo& info, rust::Box<AudioParameter> parameter);
+    ~RustAudioProcessorParameter() override;
+
+    float getValue() const override;
+    void setValue (float newValue) override;
+    float getDefaultValue() const override;
+    int getNumSteps() const override;
+    bool isDiscrete() const override;
+    bool isBoolean() const override;
+    juce::String getText (float value, int maximumStringLength) const override;
+    float getValueForText (const juce::String& text) const override;
+
+private:
+    void parameterValueChanged (int parameterIndex, float newValue) override;
+    void parameterGestureChanged (int parameterIndex, bool gestureIsStarting) override;
+
+    rust::Box<AudioParameter> _parameter;
+    float _defaultValue;
+    int _numSteps;
+    bool _isDiscrete;
+    bool _isBoolean;
+};
+
+class RustAudioProcessor : public juce::AudioProcessor
+{
+public:
+    explicit RustAudioProcessor (rust::Box<BoxedAudioProcessor> processor);
+
+    const juce::String getName() const override;
+    void prepareToPlay (double sampleRate, int maximumExpectedSamplesPerBlock) override;
+    void releaseResources() override;
+
+    using juce::AudioProcessor::processBlock;
+    void processBlock (juce::AudioBuffer<float>& buffer, juce::MidiBuffer& midiMessages) override;
+
+    void reset() override;
+    bool isBusesLayoutSupported (const BusesLayout& layouts) const override;
+    double getTailLengthSeconds() const override;
+    bool acceptsMidi() const override;
+    bool producesMidi() const override;
+
+    juce::AudioProcessorEditor* createEditor() override;
+    bool hasEditor() const override;
+
+    int getNumPrograms() override;
+    int getCurrentProgram() override;
+    void setCurrentProgram (int index) override;
+    const juce::String getProgramName (int index) override;
+    void changeProgramName (int index, const juce::String& newName) override;
+
+    void getStateInformation (juce::MemoryBlock& destData) override;
+    void setStateInformation (const void* data, int sizeInBytes) override;
+
+private:
+    rust::Box<BoxedAudioProcessor> _processor;
+    juce::String _name;
+    double _tailLengthSeconds;
+    bool _acceptsMidi;
+    bool _producesMidi;
+};
+
+namespace rust_audio_processor
+{
+    std::unique_ptr<RustAudioProcessor> createRustAudioProcessor (rust::Box<BoxedAudioProcessor> processor);
+    void addParameter (RustAudioProcessor& processor, rust::Box<AudioParameter> parameter);
+} // namespace rust_audio_processor
+
+namespace parameter_store
+{
+    rust::String createState (rust::Str stateType, rust::Slice<const rust::String> ids, rust::Slice<const float> values);
+    void parseState (rust::Str xml, rust::Str stateType, rust::Vec<rust::String>& ids, rust::Vec<float>& values);
+} // namespace parameter_store
+#endif
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_utils
+class AudioProcessorPlayerHandle
+{
+public:
+    explicit AudioProcessorPlayerHandle (juce::AudioDeviceManager& audioDeviceManager,
+                                         juce::AudioProcessorPlayer& player);
+    ~AudioProcessorPlayerHandle();
+
+private:
+    juce::AudioDeviceManager& _audioDeviceManager;
+    juce::AudioProcessorPlayer& _player;
+};
+#endif
+
 struct AudioDeviceManager
 {
     void initialiseWithDefaultDevices (rust::i32 inputChannels,
@@ -82,7 +324,17 @@ struct AudioDeviceManager
         addAudioCallback (rust::Box<BoxedAudioIODeviceCallback> callback);
     void addAudioDeviceType (rust::Box<BoxedAudioIODeviceType> audioIODeviceType);
     void setCurrentAudioDeviceType (rust::Str audioDeviceTypeName);
+    void setMidiInputDeviceEnabled (rust::Str identifier, bool enabled);
+    [[nodiscard]] bool isMidiInputDeviceEnabled (rust::Str identifier) const;
+    void setDefaultMidiOutputDevice (rust::Str identifier);
+    [[nodiscard]] rust::String getDefaultMidiOutputIdentifier() const;
+#if JUCE_MODULE_AVAILABLE_juce_audio_utils
+    [[nodiscard]] std::unique_ptr<AudioProcessorPlayerHandle> addAudioProcessorPlayer (juce::AudioProcessorPlayer& player);
+#endif
+    [[nodiscard]] std::unique_ptr<MidiThruHandle> addMidiThru (rust::Box<MidiThru> midiThru);
     void playTestSound();
+    void initialiseWithSavedState (rust::i32 inputChannels, rust::i32 outputChannels, rust::Str savedState);
+    [[nodiscard]] rust::String createStateXml() const;
     juce::AudioIODevice* getCurrentAudioDevice() const;
     const juce::OwnedArray<juce::AudioIODeviceType>& getAvailableDeviceTypes();
     juce::AudioIODeviceType* getCurrentDeviceTypeObject() const;
@@ -127,9 +379,1643 @@ namespace iir_filter
     std::array<rust::f32, 5> makeNotchFilter (double sampleRate, double cutoffFrequency, double q);
 } // namespace iir_filter
 
+// The smoothing of a value is a template parameter, so each smoothing is implemented behind
+// this interface.
+class SmoothedValue
+{
+public:
+    virtual ~SmoothedValue() = default;
+
+    virtual void reset (double sampleRate, double rampLengthInSeconds) = 0;
+    virtual void resetSteps (int numSteps) = 0;
+    virtual void setTargetValue (float newValue) = 0;
+    virtual void setCurrentAndTargetValue (float newValue) = 0;
+    virtual float getTargetValue() const = 0;
+    virtual float getCurrentValue() const = 0;
+    virtual bool isSmoothing() const = 0;
+    virtual float getNextValue() = 0;
+    virtual float skip (int numSamples) = 0;
+    virtual void applyGain (juce::AudioSampleBuffer& buffer) = 0;
+};
+
+namespace smoothed_value
+{
+    std::unique_ptr<SmoothedValue> createSmoothedValue (int smoothing, float initialValue);
+} // namespace smoothed_value
+
+namespace adsr
+{
+    std::unique_ptr<juce::ADSR> createADSR();
+    void setParameters (juce::ADSR& adsr, float attack, float decay, float sustain, float release);
+    void applyEnvelopeToBuffer (juce::ADSR& adsr, juce::AudioSampleBuffer& buffer);
+} // namespace adsr
+
+namespace float_vector_operations
+{
+    void add (rust::Slice<float> dest, rust::Slice<const float> src);
+    void addValue (rust::Slice<float> dest, float amount);
+    void multiply (rust::Slice<float> dest, rust::Slice<const float> src);
+    void multiplyByValue (rust::Slice<float> dest, float multiplier);
+    void copyWithMultiply (rust::Slice<float> dest, rust::Slice<const float> src, float multiplier);
+    std::array<float, 2> findMinAndMax (rust::Slice<const float> src);
+    void clip (rust::Slice<float> samples, float low, float high);
+    void convertFixedToFloat (rust::Slice<float> dest, rust::Slice<const int> src, float multiplier);
+} // namespace float_vector_operations
+
+namespace decibels
+{
+    float decibelsToGain (float decibels, float minusInfinityDb);
+    float gainToDecibels (float gain, float minusInfinityDb);
+    rust::String toString (float decibels, int decimalPlaces, float minusInfinityDb, bool shouldIncludeSuffix);
+} // namespace decibels
+
+// Each interpolator is a different class, so they're implemented behind this interface.
+class Interpolator
+{
+public:
+    virtual ~Interpolator() = default;
+
+    virtual float getBaseLatency() const = 0;
+    virtual void reset() = 0;
+    virtual size_t process (double speedRatio, rust::Slice<const float> input, rust::Slice<float> output) = 0;
+    virtual size_t processAdding (double speedRatio, rust::Slice<const float> input, rust::Slice<float> output, float gain) = 0;
+};
+
+namespace interpolator
+{
+    std::unique_ptr<Interpolator> createInterpolator (int type);
+} // namespace interpolator
+
 namespace message_manager
 {
     juce::MessageManager* getInstanceWithoutCreating();
-}
+    void runDispatchLoop();
+    bool runDispatchLoopUntil (int millisecondsToRunFor);
+    void stopDispatchLoop();
+    bool hasStopBeenCalled();
+    bool isThisTheMessageThread();
+    bool callAsync (rust::Box<BoxedAsyncCallback> callback);
+} // namespace message_manager
+
+class TimerHandle : public juce::Timer
+{
+public:
+    explicit TimerHandle (rust::Box<BoxedTimerCallback> callback);
+    ~TimerHandle() override;
+
+    void timerCallback() override;
+
+private:
+    rust::Box<BoxedTimerCallback> _callback;
+};
+
+namespace timer
+{
+    std::unique_ptr<TimerHandle> createTimer (rust::Box<BoxedTimerCallback> callback);
+} // namespace timer
+
+class HighResolutionTimerHandle : public juce::HighResolutionTimer
+{
+public:
+    explicit HighResolutionTimerHandle (rust::Box<BoxedHighResolutionTimerCallback> callback);
+    ~HighResolutionTimerHandle() override;
+
+    void hiResTimerCallback() override;
+
+private:
+    rust::Box<BoxedHighResolutionTimerCallback> _callback;
+};
+
+namespace high_resolution_timer
+{
+    std::unique_ptr<HighResolutionTimerHandle> createHighResolutionTimer (rust::Box<BoxedHighResolutionTimerCallback> callback);
+} // namespace high_resolution_timer
+
+class InterprocessConnection : public juce::InterprocessConnection
+{
+public:
+    InterprocessConnection (juce::uint32 magicMessageHeader, rust::Box<BoxedInterprocessConnectionListener> listener);
+    ~InterprocessConnection() override;
+
+    void connectionMade() override;
+    void connectionLost() override;
+    void messageReceived (const juce::MemoryBlock& message) override;
+
+private:
+    rust::Box<BoxedInterprocessConnectionListener> _listener;
+};
+
+namespace interprocess_connection
+{
+    std::unique_ptr<InterprocessConnection> createInterprocessConnection (juce::uint32 magicMessageHeader,
+                                                                         rust::Box<BoxedInterprocessConnectionListener> listener);
+    void connectToSocket (InterprocessConnection& connection, rust::Str hostName, int port, int timeoutMs);
+    void connectToPipe (InterprocessConnection& connection, rust::Str pipeName, int timeoutMs);
+    void createPipe (InterprocessConnection& connection, rust::Str pipeName, int timeoutMs, bool mustNotExist);
+    void disconnect (InterprocessConnection& connection);
+    bool sendMessage (const InterprocessConnection& connection, rust::Slice<const juce::uint8> message);
+    rust::String getConnectedHostName (const InterprocessConnection& connection);
+} // namespace interprocess_connection
+
+class InterprocessConnectionServer : public juce::InterprocessConnectionServer
+{
+public:
+    InterprocessConnectionServer (juce::uint32 magicMessageHeader, rust::Box<BoxedInterprocessConnectionFactory> factory);
+    ~InterprocessConnectionServer() override;
+
+    juce::InterprocessConnection* createConnectionObject() override;
+
+private:
+    juce::uint32 _magicMessageHeader;
+    rust::Box<BoxedInterprocessConnectionFactory> _factory;
+    std::vector<std::unique_ptr<InterprocessConnection>> _connections;
+};
+
+namespace interprocess_connection_server
+{
+    std::unique_ptr<InterprocessConnectionServer> createInterprocessConnectionServer (juce::uint32 magicMessageHeader,
+                                                                                     rust::Box<BoxedInterprocessConnectionFactory> factory);
+    void beginWaitingForSocket (InterprocessConnectionServer& server, int port, rust::Str bindAddress);
+} // namespace interprocess_connection_server
+
+class RustLogger : public juce::Logger
+{
+public:
+    void logMessage (const juce::String& message) override;
+};
+
+namespace logger
+{
+    void installLoggerForwarder();
+    void writeToLog (rust::Str message);
+} // namespace logger
+
+namespace var
+{
+    std::unique_ptr<juce::var> createVoid();
+    std::unique_ptr<juce::var> createUndefined();
+    std::unique_ptr<juce::var> fromBool (bool value);
+    std::unique_ptr<juce::var> fromInt (int value);
+    std::unique_ptr<juce::var> fromInt64 (juce::int64 value);
+    std::unique_ptr<juce::var> fromDouble (double value);
+    std::unique_ptr<juce::var> fromString (rust::Str value);
+    std::unique_ptr<juce::var> fromBinary (rust::Slice<const uint8_t> data);
+    std::unique_ptr<juce::var> createArray();
+    void append (juce::var& array, const juce::var& value);
+    bool toBool (const juce::var& value);
+    int toInt (const juce::var& value);
+    juce::int64 toInt64 (const juce::var& value);
+    double toDouble (const juce::var& value);
+    rust::String toString (const juce::var& value);
+    rust::Vec<uint8_t> toBinary (const juce::var& value);
+    size_t size (const juce::var& value);
+    std::unique_ptr<juce::var> getArrayElement (const juce::var& array, size_t index);
+    std::unique_ptr<juce::var> createObject();
+    void setProperty (juce::var& object, rust::Str name, const juce::var& value);
+    size_t getNumProperties (const juce::var& object);
+    rust::String getPropertyName (const juce::var& object, size_t index);
+    std::unique_ptr<juce::var> getPropertyValue (const juce::var& object, size_t index);
+} // namespace var
+
+namespace json
+{
+    std::unique_ptr<juce::var> parse (rust::Str text);
+    rust::String toString (const juce::var& value, bool allOnOneLine);
+} // namespace json
+
+namespace xml_element
+{
+    std::unique_ptr<juce::XmlElement> createXmlElement (rust::Str tagName);
+    std::unique_ptr<juce::XmlElement> createTextElement (rust::Str text);
+    std::unique_ptr<juce::XmlElement> parse (rust::Str text);
+    std::unique_ptr<juce::XmlElement> parseFile (rust::Str path);
+    std::unique_ptr<juce::XmlElement> createCopy (const juce::XmlElement& element);
+    rust::String getTagName (const juce::XmlElement& element);
+    void setTagName (juce::XmlElement& element, rust::Str tagName);
+    rust::String getText (const juce::XmlElement& element);
+    rust::String getAllSubText (const juce::XmlElement& element);
+    void setText (juce::XmlElement& element, rust::Str text);
+    void addTextElement (juce::XmlElement& element, rust::Str text);
+    rust::String getAttributeName (const juce::XmlElement& element, int index);
+    rust::String getAttributeValue (const juce::XmlElement& element, int index);
+    bool hasAttribute (const juce::XmlElement& element, rust::Str name);
+    rust::String getStringAttribute (const juce::XmlElement& element, rust::Str name);
+    void setAttribute (juce::XmlElement& element, rust::Str name, rust::Str value);
+    void removeAttribute (juce::XmlElement& element, rust::Str name);
+    juce::XmlElement* getChildByName (const juce::XmlElement& element, rust::Str tagName);
+    juce::XmlElement* getChildByAttribute (const juce::XmlElement& element, rust::Str name, rust::Str value);
+    void insertChildElement (juce::XmlElement& element, std::unique_ptr<juce::XmlElement> child, int index);
+    juce::XmlElement& createNewChildElement (juce::XmlElement& element, rust::Str tagName);
+    std::unique_ptr<juce::XmlElement> removeChildElement (juce::XmlElement& element, int index);
+    bool isEquivalentTo (const juce::XmlElement& element, const juce::XmlElement& other, bool ignoreOrderOfAttributes);
+    rust::String toString (const juce::XmlElement& element, bool singleLine, bool includeHeader);
+    void writeTo (const juce::XmlElement& element, rust::Str path, bool singleLine, bool includeHeader);
+} // namespace xml_element
+
+namespace file
+{
+    rust::String getSpecialLocation (int location);
+    rust::String getCurrentWorkingDirectory();
+    rust::String createTempFile (rust::Str suffix);
+    rust::String getFullPathName (rust::Str path);
+    rust::String getFileName (rust::Str path);
+    rust::String getFileNameWithoutExtension (rust::Str path);
+    rust::String getFileExtension (rust::Str path);
+    rust::String getParentDirectory (rust::Str path);
+    rust::String getChildFile (rust::Str path, rust::Str relativePath);
+    rust::String getSiblingFile (rust::Str path, rust::Str fileName);
+    rust::String withFileExtension (rust::Str path, rust::Str extension);
+    rust::String getNonexistentChildFile (rust::Str path, rust::Str prefix, rust::Str suffix);
+    rust::String getNonexistentSibling (rust::Str path);
+    bool exists (rust::Str path);
+    bool existsAsFile (rust::Str path);
+    bool isDirectory (rust::Str path);
+    juce::int64 getSize (rust::Str path);
+    void create (rust::Str path);
+    void createDirectory (rust::Str path);
+    bool deleteFile (rust::Str path);
+    bool deleteRecursively (rust::Str path);
+    bool moveToTrash (rust::Str path);
+    rust::Vec<rust::String> findChildFiles (rust::Str path, int whatToLookFor, bool searchRecursively, rust::Str wildcard);
+} // namespace file
+
+namespace zip_file
+{
+    std::unique_ptr<juce::ZipFile> openZipFile (rust::Str path);
+    std::unique_ptr<juce::ZipFile> createZipFileFromData (rust::Slice<const juce::uint8> data);
+    ZipEntry getZipEntry (const juce::ZipFile& zip, int index);
+    int getIndexOfFileName (const juce::ZipFile& zip, rust::Str fileName, bool ignoreCase);
+    rust::Vec<juce::uint8> readEntry (juce::ZipFile& zip, int index);
+    void uncompressTo (juce::ZipFile& zip, rust::Str targetDirectory, bool shouldOverwriteFiles);
+    void uncompressEntry (juce::ZipFile& zip, int index, rust::Str targetDirectory, bool shouldOverwriteFiles);
+} // namespace zip_file
+
+using ZipFileBuilder = juce::ZipFile::Builder;
+
+namespace zip_file_builder
+{
+    std::unique_ptr<ZipFileBuilder> createZipFileBuilder();
+    void addFile (ZipFileBuilder& builder, rust::Str path, int compressionLevel, rust::Str storedPath);
+    void addEntry (ZipFileBuilder& builder,
+                   rust::Slice<const juce::uint8> data,
+                   int compressionLevel,
+                   rust::Str storedPath,
+                   juce::int64 modificationTime);
+    void writeToFile (const ZipFileBuilder& builder, rust::Str path);
+    rust::Vec<juce::uint8> writeToMemory (const ZipFileBuilder& builder);
+} // namespace zip_file_builder
+
+#if JUCE_MODULE_AVAILABLE_juce_data_structures
+using PropertiesFileOptions = juce::PropertiesFile::Options;
+
+namespace properties_file
+{
+    std::unique_ptr<PropertiesFileOptions> createOptions (rust::Str applicationName,
+                                                          rust::Str filenameSuffix,
+                                                          rust::Str folderName,
+                                                          rust::Str osxLibrarySubFolder,
+                                                          bool commonToAllUsers,
+                                                          bool ignoreCaseOfKeyNames,
+                                                          bool doNotSave,
+                                                          int millisecondsBeforeSaving,
+                                                          int storageFormat);
+    rust::String getDefaultFile (const PropertiesFileOptions& options);
+    std::unique_ptr<juce::PropertiesFile> createPropertiesFile (rust::Str path, const PropertiesFileOptions& options);
+    rust::String getFile (const juce::PropertiesFile& file);
+    bool containsKey (const juce::PropertiesFile& file, rust::Str key);
+    bool getValue (const juce::PropertiesFile& file, rust::Str key, rust::String& value);
+    rust::String getXmlValue (const juce::PropertiesFile& file, rust::Str key);
+    rust::Vec<rust::String> getAllKeys (const juce::PropertiesFile& file);
+    void setValue (juce::PropertiesFile& file, rust::Str key, rust::Str value);
+    void setXmlValue (juce::PropertiesFile& file, rust::Str key, rust::Str xml);
+    void removeValue (juce::PropertiesFile& file, rust::Str key);
+    void setFallbackPropertySet (juce::PropertiesFile& file, juce::PropertiesFile& fallback);
+    void save (juce::PropertiesFile& file);
+    void saveIfNeeded (juce::PropertiesFile& file);
+} // namespace properties_file
+
+namespace undo_manager
+{
+    std::unique_ptr<juce::UndoManager> createUndoManager();
+    void beginNewTransaction (juce::UndoManager& undoManager, rust::Str name);
+    rust::String getUndoDescription (const juce::UndoManager& undoManager);
+    rust::String getRedoDescription (const juce::UndoManager& undoManager);
+} // namespace undo_manager
+
+class ValueTreeListenerHandle : public juce::ValueTree::Listener
+{
+public:
+    explicit ValueTreeListenerHandle (const juce::ValueTree& tree, rust::Box<BoxedValueTreeListener> listener);
+    ~ValueTreeListenerHandle() override;
+
+    void valueTreePropertyChanged (juce::ValueTree& tree, const juce::Identifier& property) override;
+    void valueTreeChildAdded (juce::ValueTree& parent, juce::ValueTree& child) override;
+    void valueTreeChildRemoved (juce::ValueTree& parent, juce::ValueTree& child, int index) override;
+    void valueTreeChildOrderChanged (juce::ValueTree& parent, int oldIndex, int newIndex) override;
+    void valueTreeParentChanged (juce::ValueTree& tree) override;
+
+private:
+    juce::ValueTree _tree;
+    rust::Box<BoxedValueTreeListener> _listener;
+};
+
+namespace value_tree
+{
+    std::unique_ptr<juce::ValueTree> createValueTree (rust::Str type);
+    std::unique_ptr<juce::ValueTree> copy (const juce::ValueTree& tree);
+    std::unique_ptr<juce::ValueTree> createCopy (const juce::ValueTree& tree);
+    bool equals (const juce::ValueTree& tree, const juce::ValueTree& other);
+    rust::String getType (const juce::ValueTree& tree);
+    rust::String getPropertyName (const juce::ValueTree& tree, int index);
+    bool hasProperty (const juce::ValueTree& tree, rust::Str name);
+    std::unique_ptr<juce::var> getProperty (const juce::ValueTree& tree, rust::Str name);
+    void setProperty (juce::ValueTree& tree, rust::Str name, const juce::var& value, juce::UndoManager* undoManager);
+    void removeProperty (juce::ValueTree& tree, rust::Str name, juce::UndoManager* undoManager);
+    void removeAllProperties (juce::ValueTree& tree, juce::UndoManager* undoManager);
+    void copyPropertiesFrom (juce::ValueTree& tree, const juce::ValueTree& source, juce::UndoManager* undoManager);
+    std::unique_ptr<juce::ValueTree> getChild (const juce::ValueTree& tree, int index);
+    std::unique_ptr<juce::ValueTree> getChildWithName (const juce::ValueTree& tree, rust::Str type);
+    std::unique_ptr<juce::ValueTree> getChildWithProperty (const juce::ValueTree& tree, rust::Str name, const juce::var& value);
+    std::unique_ptr<juce::ValueTree> getOrCreateChildWithName (juce::ValueTree& tree, rust::Str type, juce::UndoManager* undoManager);
+    void addChild (juce::ValueTree& tree, const juce::ValueTree& child, int index, juce::UndoManager* undoManager);
+    void removeChild (juce::ValueTree& tree, int index, juce::UndoManager* undoManager);
+    void removeAllChildren (juce::ValueTree& tree, juce::UndoManager* undoManager);
+    void moveChild (juce::ValueTree& tree, int currentIndex, int newIndex, juce::UndoManager* undoManager);
+    int indexOf (const juce::ValueTree& tree, const juce::ValueTree& child);
+    std::unique_ptr<juce::ValueTree> getParent (const juce::ValueTree& tree);
+    std::unique_ptr<juce::ValueTree> getRoot (const juce::ValueTree& tree);
+    rust::String toXmlString (const juce::ValueTree& tree);
+    std::unique_ptr<juce::ValueTree> fromXml (rust::Str xml);
+    std::unique_ptr<juce::XmlElement> createXml (const juce::ValueTree& tree);
+    std::unique_ptr<juce::ValueTree> fromXmlElement (const juce::XmlElement& xml);
+    rust::Vec<uint8_t> writeToBinary (const juce::ValueTree& tree);
+    std::unique_ptr<juce::ValueTree> readFromData (rust::Slice<const uint8_t> data);
+    std::unique_ptr<ValueTreeListenerHandle> addListener (const juce::ValueTree& tree,
+                                                          rust::Box<BoxedValueTreeListener> listener);
+} // namespace value_tree
+#endif
+
+namespace file_logger
+{
+    std::unique_ptr<juce::FileLogger> createFileLogger (rust::Str path, rust::Str welcomeMessage, juce::int64 maxInitialFileSizeBytes);
+    std::unique_ptr<juce::FileLogger> createDefaultAppLogger (rust::Str logFileSubDirectoryName,
+                                                              rust::Str logFileName,
+                                                              rust::Str welcomeMessage,
+                                                              juce::int64 maxInitialFileSizeBytes);
+    void logMessage (const juce::FileLogger& logger, rust::Str message);
+    rust::String getLogFile (const juce::FileLogger& logger);
+} // namespace file_logger
+
+namespace midi_message
+{
+    std::unique_ptr<juce::MidiMessage> createMidiMessage (rust::Slice<const rust::u8> data, double timestamp);
+    std::unique_ptr<juce::MidiMessage> copyMidiMessage (const juce::MidiMessage& message);
+    rust::Slice<const rust::u8> getRawData (const juce::MidiMessage& message);
+    std::unique_ptr<juce::MidiMessage> midiClock();
+    std::unique_ptr<juce::MidiMessage> midiStart();
+    std::unique_ptr<juce::MidiMessage> midiStop();
+    std::unique_ptr<juce::MidiMessage> midiContinue();
+    std::unique_ptr<juce::MidiMessage> songPositionPointer (rust::i32 positionInMidiBeats);
+    std::unique_ptr<juce::MidiMessage> quarterFrame (rust::i32 sequenceNumber, rust::i32 value);
+    std::unique_ptr<juce::MidiMessage> fullFrame (rust::i32 hours,
+                                                  rust::i32 minutes,
+                                                  rust::i32 seconds,
+                                                  rust::i32 frames,
+                                                  rust::i32 timecodeType);
+    std::array<rust::i32, 5> getFullFrameParameters (const juce::MidiMessage& message);
+    std::unique_ptr<juce::MidiMessage> controllerEvent (rust::i32 channel, rust::i32 controllerType, rust::i32 value);
+} // namespace midi_message
+
+namespace midi_buffer
+{
+    std::unique_ptr<juce::MidiBuffer> createMidiBuffer();
+    std::unique_ptr<juce::MidiBuffer> copyMidiBuffer (const juce::MidiBuffer& buffer);
+    void addEvent (juce::MidiBuffer& buffer, const juce::MidiMessage& message, rust::i32 sampleNumber);
+    void addRawEvent (juce::MidiBuffer& buffer, rust::Slice<const rust::u8> data, rust::i32 sampleNumber);
+    rust::Slice<const rust::u8> getRawData (const juce::MidiBuffer& buffer);
+} // namespace midi_buffer
+
+namespace midi_rpn
+{
+    std::unique_ptr<juce::MidiRPNDetector> createMidiRPNDetector();
+    bool parseControllerMessage (juce::MidiRPNDetector& detector,
+                                 rust::i32 channel,
+                                 rust::i32 controllerNumber,
+                                 rust::i32 controllerValue,
+                                 MidiRPNMessage& result);
+    std::unique_ptr<juce::MidiBuffer> generate (MidiRPNMessage message);
+} // namespace midi_rpn
+
+class MidiInput : public juce::MidiInputCallback
+{
+public:
+    MidiInput (rust::Str identifier, rust::Box<BoxedMidiInputCallback> callback);
+
+    [[nodiscard]] MidiDeviceInfo getDeviceInfo() const;
+    void start();
+    void stop();
+
+    void handleIncomingMidiMessage (juce::MidiInput* source, const juce::MidiMessage& message) override;
+
+private:
+    rust::Box<BoxedMidiInputCallback> _callback;
+    std::unique_ptr<juce::MidiInput> _midiInput;
+};
+
+namespace midi_input
+{
+    rust::Vec<MidiDeviceInfo> getAvailableDevices();
+    MidiDeviceInfo getDefaultDevice();
+    std::unique_ptr<MidiInput> openDevice (rust::Str identifier, rust::Box<BoxedMidiInputCallback> callback);
+} // namespace midi_input
+
+namespace memory_block
+{
+    std::unique_ptr<juce::MemoryBlock> createMemoryBlock (rust::Slice<const juce::uint8> data);
+    std::unique_ptr<juce::MemoryBlock> createMemoryBlockWithSize (size_t size);
+    std::unique_ptr<juce::MemoryBlock> copy (const juce::MemoryBlock& block);
+    bool equals (const juce::MemoryBlock& block, const juce::MemoryBlock& other);
+    const juce::uint8* getData (const juce::MemoryBlock& block);
+    juce::uint8* getMutableData (juce::MemoryBlock& block);
+    void append (juce::MemoryBlock& block, rust::Slice<const juce::uint8> data);
+    rust::String toBase64Encoding (const juce::MemoryBlock& block);
+    std::unique_ptr<juce::MemoryBlock> fromBase64Encoding (rust::Str encoded);
+} // namespace memory_block
+
+namespace base64
+{
+    rust::String toBase64 (rust::Slice<const juce::uint8> data);
+    rust::Vec<juce::uint8> convertFromBase64 (rust::Str encoded);
+} // namespace base64
+
+namespace random
+{
+    std::unique_ptr<juce::Random> createRandom();
+    std::unique_ptr<juce::Random> createRandomWithSeed (juce::int64 seed);
+    std::unique_ptr<juce::Random> copy (const juce::Random& random);
+    juce::Random* getSystemRandom();
+    void fillBitsRandomly (juce::Random& random, rust::Slice<juce::uint8> buffer);
+} // namespace random
+
+namespace system_stats
+{
+    rust::String getOperatingSystemName();
+    bool isOperatingSystem64Bit();
+    rust::String getDeviceDescription();
+    rust::String getDeviceManufacturer();
+    rust::String getComputerName();
+    rust::String getLogonName();
+    rust::String getFullUserName();
+    rust::String getUserLanguage();
+    rust::String getUserRegion();
+    rust::String getDisplayLanguage();
+    rust::String getCpuVendor();
+    rust::String getCpuModel();
+    int getCpuSpeedInMegahertz();
+    int getNumCpus();
+    int getNumPhysicalCpus();
+    int getMemorySizeInMegabytes();
+    int getPageSize();
+    CpuFeatures getCpuFeatures();
+} // namespace system_stats
+
+namespace time
+{
+    double getMillisecondCounterHiRes();
+    juce::uint32 getMillisecondCounter();
+    juce::int64 currentTimeMillis();
+    juce::int64 getHighResolutionTicks();
+    juce::int64 getHighResolutionTicksPerSecond();
+    double highResolutionTicksToSeconds (juce::int64 ticks);
+    juce::int64 secondsToHighResolutionTicks (double seconds);
+    std::array<int, 8> getLocalComponents (juce::int64 millisecondsSinceEpoch);
+    int getUtcOffsetSeconds (juce::int64 millisecondsSinceEpoch);
+    rust::String getTimeZone (juce::int64 millisecondsSinceEpoch);
+    rust::String toString (juce::int64 millisecondsSinceEpoch,
+                           bool includeDate,
+                           bool includeTime,
+                           bool includeSeconds,
+                           bool use24HourClock);
+    rust::String toISO8601 (juce::int64 millisecondsSinceEpoch, bool includeDividerCharacters);
+} // namespace time
+
+namespace uuid
+{
+    std::array<juce::uint8, 16> createUuid();
+    std::array<juce::uint8, 16> fromString (rust::Str string);
+    rust::String toString (std::array<juce::uint8, 16> bytes);
+    rust::String toDashedString (std::array<juce::uint8, 16> bytes);
+} // namespace uuid
+
+namespace url
+{
+    std::unique_ptr<juce::URL> createUrl (rust::Str url);
+    std::unique_ptr<juce::URL> copy (const juce::URL& url);
+    bool equals (const juce::URL& url, const juce::URL& other);
+    rust::String toString (const juce::URL& url, bool includeGetParameters);
+    rust::String getScheme (const juce::URL& url);
+    rust::String getDomain (const juce::URL& url);
+    rust::String getSubPath (const juce::URL& url);
+    rust::String getFileName (const juce::URL& url);
+    rust::Vec<StringPair> getParameters (const juce::URL& url);
+    std::unique_ptr<juce::URL> withParameter (const juce::URL& url, rust::Str name, rust::Str value);
+    std::unique_ptr<juce::URL> withPostData (const juce::URL& url, rust::Slice<const uint8_t> data);
+    rust::Vec<uint8_t> getPostData (const juce::URL& url);
+    std::unique_ptr<juce::URL> getChildUrl (const juce::URL& url, rust::Str subPath);
+    std::unique_ptr<juce::URL> getParentUrl (const juce::URL& url);
+} // namespace url
+
+namespace web_input_stream
+{
+    std::unique_ptr<juce::WebInputStream> createWebInputStream (const juce::URL& url,
+                                                                bool usePost,
+                                                                rust::Str extraHeaders,
+                                                                rust::Str requestCommand,
+                                                                int timeoutMs,
+                                                                int numRedirectsToFollow,
+                                                                rust::Box<BoxedPostProgressCallback> onPostProgress);
+    int read (juce::WebInputStream& stream, rust::Slice<juce::uint8> buffer);
+    rust::Vec<StringPair> getResponseHeaders (juce::WebInputStream& stream);
+    void cancel (const juce::WebInputStream& stream);
+} // namespace web_input_stream
+
+class TimeSliceClient : public juce::TimeSliceClient
+{
+public:
+    explicit TimeSliceClient (std::shared_ptr<juce::TimeSliceThread> thread, rust::Box<BoxedTimeSliceClient> client);
+    ~TimeSliceClient() override;
+
+    int useTimeSlice() override;
+
+private:
+    std::shared_ptr<juce::TimeSliceThread> _thread;
+    rust::Box<BoxedTimeSliceClient> _client;
+};
+
+namespace time_slice_thread
+{
+    std::shared_ptr<juce::TimeSliceThread> createTimeSliceThread (rust::Str name);
+    int getNumClients (const std::shared_ptr<juce::TimeSliceThread>& thread);
+    std::unique_ptr<TimeSliceClient> addClient (std::shared_ptr<juce::TimeSliceThread> thread,
+                                                rust::Box<BoxedTimeSliceClient> client);
+} // namespace time_slice_thread
+
+namespace thread_pool
+{
+    std::shared_ptr<juce::ThreadPool> createThreadPool (int numThreads);
+    void addJob (const std::shared_ptr<juce::ThreadPool>& pool, rust::Str name, rust::Box<BoxedThreadPoolJob> job);
+    int getNumJobs (const std::shared_ptr<juce::ThreadPool>& pool);
+    int getNumThreads (const std::shared_ptr<juce::ThreadPool>& pool);
+    bool removeAllJobs (const std::shared_ptr<juce::ThreadPool>& pool, bool interruptRunningJobs, int timeoutMs);
+} // namespace thread_pool
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_formats
+class BufferingThread : public juce::TimeSliceThread
+{
+public:
+    BufferingThread();
+    ~BufferingThread() override;
+};
+
+struct BufferingThreadHolder
+{
+    // Uses the shared default buffering thread if the thread is null.
+    explicit BufferingThreadHolder (std::shared_ptr<juce::TimeSliceThread> thread);
+
+    std::shared_ptr<juce::TimeSliceThread> bufferingThread;
+};
+#endif
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_utils
+class AudioFilePlayer : private BufferingThreadHolder
+{
+public:
+    explicit AudioFilePlayer (std::shared_ptr<juce::TimeSliceThread> bufferingThread);
+    ~AudioFilePlayer();
+
+    void setReader (std::unique_ptr<juce::AudioFormatReader> reader);
+    void start();
+    void stop();
+    [[nodiscard]] bool isPlaying() const;
+    [[nodiscard]] bool hasStreamFinished() const;
+    void setPosition (double seconds);
+    [[nodiscard]] double getCurrentPosition() const;
+    [[nodiscard]] double getLengthInSeconds() const;
+    void setGain (float gain);
+    [[nodiscard]] float getGain() const;
+    void setLooping (bool shouldLoop);
+    void prepareToPlay (int samplesPerBlockExpected, double sampleRate);
+    void releaseResources();
+    void getNextAudioBlock (juce::AudioSampleBuffer& buffer);
+
+private:
+    std::unique_ptr<juce::AudioFormatReaderSource> _readerSource;
+    juce::AudioTransportSource _transport;
+    bool _looping = false;
+};
+
+namespace audio_file_player
+{
+    std::unique_ptr<AudioFilePlayer> createAudioFilePlayer (std::shared_ptr<juce::TimeSliceThread> bufferingThread);
+} // namespace audio_file_player
+
+namespace audio_thumbnail_cache
+{
+    std::shared_ptr<juce::AudioThumbnailCache> createAudioThumbnailCache (int maxNumThumbsToStore);
+    void clear (const std::shared_ptr<juce::AudioThumbnailCache>& cache);
+} // namespace audio_thumbnail_cache
+
+class AudioThumbnail
+{
+public:
+    AudioThumbnail (int sourceSamplesPerThumbnailSample, std::shared_ptr<juce::AudioThumbnailCache> cache);
+
+    bool setSourceFile (rust::Str path);
+    void setReader (std::unique_ptr<juce::AudioFormatReader> reader, juce::int64 hash);
+    void clear();
+    void reset (int numChannels, double sampleRate, juce::int64 totalSamples);
+    void addBlock (juce::int64 sampleNumberInSource, const juce::AudioSampleBuffer& buffer);
+
+    [[nodiscard]] int getNumChannels() const;
+    [[nodiscard]] double getTotalLength() const;
+    [[nodiscard]] bool isFullyLoaded() const;
+    [[nodiscard]] double getProportionComplete() const;
+    [[nodiscard]] juce::int64 getNumSamplesFinished() const;
+    [[nodiscard]] float getApproximatePeak() const;
+    [[nodiscard]] juce::int64 getHashCode() const;
+    void getPeaks (int channelIndex,
+                   double startTime,
+                   double endTime,
+                   rust::Slice<float> minValues,
+                   rust::Slice<float> maxValues) const;
+
+private:
+    std::shared_ptr<juce::AudioThumbnailCache> _cache;
+    juce::AudioFormatManager _formatManager;
+    juce::AudioThumbnail _thumbnail;
+};
+
+namespace audio_thumbnail
+{
+    std::unique_ptr<AudioThumbnail> createAudioThumbnail (int sourceSamplesPerThumbnailSample,
+                                                          std::shared_ptr<juce::AudioThumbnailCache> cache);
+} // namespace audio_thumbnail
+
+namespace bluetooth_midi_device_pairing_dialogue
+{
+    bool isAvailable();
+    bool open (rust::Box<BoxedPairingDialogueCallback> callback);
+} // namespace bluetooth_midi_device_pairing_dialogue
+#endif
+
+namespace midi_output
+{
+    rust::Vec<MidiDeviceInfo> getAvailableDevices();
+    MidiDeviceInfo getDefaultDevice();
+    std::unique_ptr<juce::MidiOutput> openDevice (rust::Str identifier);
+    MidiDeviceInfo getDeviceInfo (const juce::MidiOutput& midiOutput);
+} // namespace midi_output
+
+class RustInputStream : public juce::InputStream
+{
+public:
+    explicit RustInputStream (rust::Box<BoxedInputStream> stream);
+
+    juce::int64 getTotalLength() override;
+    bool isExhausted() override;
+    int read (void* destBuffer, int maxBytesToRead) override;
+    juce::int64 getPosition() override;
+    bool setPosition (juce::int64 newPosition) override;
+
+private:
+    rust::Box<BoxedInputStream> _stream;
+};
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_formats
+class RustAudioFormatReader : public juce::AudioFormatReader
+{
+public:
+    explicit RustAudioFormatReader (rust::Box<BoxedCustomAudioFormatReader> reader);
+
+    bool readSamples (int* const* destChannels,
+                      int numDestChannels,
+                      int startOffsetInDestBuffer,
+                      juce::int64 startSampleInFile,
+                      int numSamples) override;
+
+private:
+    rust::Box<BoxedCustomAudioFormatReader> _reader;
+    juce::AudioSampleBuffer _buffer;
+};
+
+namespace custom_audio_format_reader
+{
+    std::unique_ptr<juce::AudioFormatReader> createCustomAudioFormatReader (rust::Box<BoxedCustomAudioFormatReader> reader);
+} // namespace custom_audio_format_reader
+
+class RustAudioFormat : public juce::AudioFormat
+{
+public:
+    explicit RustAudioFormat (rust::Box<BoxedCustomAudioFormat> format);
+
+    juce::Array<int> getPossibleSampleRates() override;
+    juce::Array<int> getPossibleBitDepths() override;
+    bool canDoStereo() override;
+    bool canDoMono() override;
+    bool canHandleFile (const juce::File& fileToTest) override;
+
+    juce::AudioFormatReader* createReaderFor (juce::InputStream* sourceStream,
+                                              bool deleteStreamIfOpeningFails) override;
+
+    using juce::AudioFormat::createWriterFor;
+    juce::AudioFormatWriter* createWriterFor (juce::OutputStream* streamToWriteTo,
+                                              double sampleRateToUse,
+                                              unsigned int numberOfChannels,
+                                              int bitsPerSample,
+                                              const juce::StringPairArray& metadataValues,
+                                              int qualityOptionIndex) override;
+
+private:
+    rust::Box<BoxedCustomAudioFormat> _format;
+};
+
+class RustAudioFormatWriter : public juce::AudioFormatWriter
+{
+public:
+    RustAudioFormatWriter (rust::Box<BoxedCustomAudioFormatWriter> writer,
+                           rust::Str formatName,
+                           double sampleRate,
+                           juce::uint32 numChannels,
+                           juce::uint32 bitsPerSample);
+
+    bool write (const int** samplesToWrite, int numSamples) override;
+    bool flush() override;
+
+private:
+    rust::Box<BoxedCustomAudioFormatWriter> _writer;
+    juce::AudioSampleBuffer _buffer;
+};
+
+namespace custom_audio_format
+{
+    void registerFormat (juce::AudioFormatManager& manager,
+                         rust::Box<BoxedCustomAudioFormat> format,
+                         bool makeThisTheDefaultFormat);
+    std::unique_ptr<juce::AudioFormatWriter> createCustomAudioFormatWriter (rust::Box<BoxedCustomAudioFormatWriter> writer,
+                                                                            rust::Str formatName,
+                                                                            double sampleRate,
+                                                                            juce::uint32 numChannels,
+                                                                            juce::uint32 bitsPerSample);
+} // namespace custom_audio_format
+#endif
+
+namespace input_stream
+{
+    int read (juce::InputStream& stream, rust::Slice<juce::uint8> buffer);
+} // namespace input_stream
+
+namespace output_stream
+{
+    bool write (juce::OutputStream& stream, rust::Slice<const juce::uint8> buffer);
+} // namespace output_stream
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_formats
+namespace audio_format_manager
+{
+    std::unique_ptr<juce::AudioFormatManager> createAudioFormatManager();
+    rust::Vec<rust::String> getKnownFormatNames (const juce::AudioFormatManager& manager);
+    rust::String getWildcardForAllFormats (const juce::AudioFormatManager& manager);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::AudioFormatManager& manager, rust::Str path);
+    std::unique_ptr<juce::AudioFormatReader> openReaderForFile (juce::AudioFormatManager& manager, rust::Str path);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::AudioFormatManager& manager, rust::Box<BoxedInputStream> stream);
+    std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::AudioFormatManager& manager,
+                                                                  rust::Str path,
+                                                                  double sampleRate,
+                                                                  juce::uint32 numChannels,
+                                                                  int bitsPerSample,
+                                                                  rust::Slice<const StringPair> metadata);
+} // namespace audio_format_manager
+
+namespace audio_format_reader
+{
+    rust::Str getFormatName (const juce::AudioFormatReader& reader);
+    double getSampleRate (const juce::AudioFormatReader& reader);
+    juce::uint32 getNumChannels (const juce::AudioFormatReader& reader);
+    juce::int64 getLengthInSamples (const juce::AudioFormatReader& reader);
+    juce::uint32 getBitsPerSample (const juce::AudioFormatReader& reader);
+    bool usesFloatingPointData (const juce::AudioFormatReader& reader);
+    rust::Vec<StringPair> getMetadataValues (const juce::AudioFormatReader& reader);
+    void read (juce::AudioFormatReader& reader,
+               juce::AudioSampleBuffer& buffer,
+               int destStartSample,
+               int numSamples,
+               juce::int64 readerStartSample);
+    void readChannels (juce::AudioFormatReader& reader,
+                       juce::AudioSampleBuffer& buffer,
+                       int destStartSample,
+                       int numSamples,
+                       juce::int64 readerStartSample,
+                       rust::Slice<const size_t> channels,
+                       bool fillLeftoverChannelsWithCopies);
+} // namespace audio_format_reader
+
+namespace memory_mapped_audio_format_reader
+{
+    bool mapEntireFile (juce::AudioFormatReader& reader);
+    bool mapSectionOfFile (juce::AudioFormatReader& reader, juce::int64 startSample, juce::int64 endSample);
+    std::array<juce::int64, 2> getMappedSection (const juce::AudioFormatReader& reader);
+    void touchSample (const juce::AudioFormatReader& reader, juce::int64 sample);
+    size_t getNumBytesUsed (const juce::AudioFormatReader& reader);
+    void getSample (const juce::AudioFormatReader& reader, juce::int64 sample, rust::Slice<float> result);
+} // namespace memory_mapped_audio_format_reader
+
+class BufferingAudioReader : private BufferingThreadHolder,
+                             public juce::BufferingAudioReader
+{
+public:
+    BufferingAudioReader (std::unique_ptr<juce::AudioFormatReader> source,
+                          std::shared_ptr<juce::TimeSliceThread> bufferingThread,
+                          int samplesToBuffer);
+};
+
+namespace buffering_audio_reader
+{
+    std::unique_ptr<juce::AudioFormatReader> createBufferingAudioReader (std::unique_ptr<juce::AudioFormatReader> source,
+                                                                         std::shared_ptr<juce::TimeSliceThread> bufferingThread,
+                                                                         int samplesToBuffer);
+    void setReadTimeout (juce::AudioFormatReader& reader, int timeoutMilliseconds);
+    bool readSamples (juce::AudioFormatReader& reader,
+                      juce::AudioSampleBuffer& buffer,
+                      int destStartSample,
+                      int numSamples,
+                      juce::int64 readerStartSample);
+} // namespace buffering_audio_reader
+
+class WriterThread : public juce::TimeSliceThread
+{
+public:
+    WriterThread();
+    ~WriterThread() override;
+};
+
+struct WriterThreadHolder
+{
+    // Uses the shared default writer thread if the thread is null.
+    explicit WriterThreadHolder (std::shared_ptr<juce::TimeSliceThread> thread);
+
+    std::shared_ptr<juce::TimeSliceThread> writerThread;
+};
+
+class ThreadedWriter : private WriterThreadHolder,
+                       public juce::AudioFormatWriter::ThreadedWriter
+{
+public:
+    ThreadedWriter (std::unique_ptr<juce::AudioFormatWriter> writer,
+                    std::shared_ptr<juce::TimeSliceThread> writerThread,
+                    int numSamplesToBuffer);
+
+    [[nodiscard]] int getNumChannels() const;
+
+private:
+    int _numChannels;
+};
+
+namespace threaded_writer
+{
+    std::unique_ptr<ThreadedWriter> createThreadedWriter (std::unique_ptr<juce::AudioFormatWriter> writer,
+                                                          std::shared_ptr<juce::TimeSliceThread> writerThread,
+                                                          int numSamplesToBuffer);
+    bool write (ThreadedWriter& writer, const juce::AudioSampleBuffer& buffer);
+} // namespace threaded_writer
+
+class ResamplingAudioFormatReader
+{
+public:
+    ResamplingAudioFormatReader (std::unique_ptr<juce::AudioFormatReader> reader, double sampleRate);
+
+    [[nodiscard]] double getSampleRate() const;
+    [[nodiscard]] int getNumChannels() const;
+    [[nodiscard]] juce::int64 getLengthInSamples() const;
+    [[nodiscard]] juce::int64 getPosition() const;
+    void setPosition (juce::int64 position);
+    void read (juce::AudioSampleBuffer& buffer);
+
+private:
+    double _sampleRate;
+    double _ratio;
+    int _numChannels;
+    juce::int64 _lengthInSamples;
+    juce::int64 _position = 0;
+    juce::AudioFormatReaderSource _readerSource;
+    juce::ResamplingAudioSource _resampler;
+};
+
+namespace resampling_audio_format_reader
+{
+    std::unique_ptr<ResamplingAudioFormatReader> createResamplingAudioFormatReader (std::unique_ptr<juce::AudioFormatReader> reader,
+                                                                                    double sampleRate);
+} // namespace resampling_audio_format_reader
+
+namespace audio_sample_buffer
+{
+    std::unique_ptr<juce::AudioSampleBuffer> createAudioSampleBuffer (int numChannels, int numSamples);
+    std::unique_ptr<juce::AudioSampleBuffer> copyAudioSampleBuffer (const juce::AudioSampleBuffer& buffer);
+} // namespace audio_sample_buffer
+
+namespace wav_audio_format
+{
+    std::unique_ptr<juce::WavAudioFormat> createWavAudioFormat();
+    std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::WavAudioFormat& format, rust::Str path);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::WavAudioFormat& format, rust::Box<BoxedInputStream> stream);
+    std::unique_ptr<juce::AudioFormatReader> createMemoryMappedReader (juce::WavAudioFormat& format, rust::Str path);
+    std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::WavAudioFormat& format,
+                                                                  rust::Str path,
+                                                                  double sampleRate,
+                                                                  juce::uint32 numChannels,
+                                                                  int bitsPerSample,
+                                                                  rust::Slice<const StringPair> metadata);
+} // namespace wav_audio_format
+
+namespace aiff_audio_format
+{
+    std::unique_ptr<juce::AiffAudioFormat> createAiffAudioFormat();
+    std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::AiffAudioFormat& format, rust::Str path);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::AiffAudioFormat& format, rust::Box<BoxedInputStream> stream);
+    std::unique_ptr<juce::AudioFormatReader> createMemoryMappedReader (juce::AiffAudioFormat& format, rust::Str path);
+    std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::AiffAudioFormat& format,
+                                                                  rust::Str path,
+                                                                  double sampleRate,
+                                                                  juce::uint32 numChannels,
+                                                                  int bitsPerSample,
+                                                                  rust::Slice<const StringPair> metadata);
+} // namespace aiff_audio_format
+
+#if JUCE_MAC || JUCE_IOS
+namespace core_audio_format
+{
+    std::unique_ptr<juce::CoreAudioFormat> createCoreAudioFormat();
+    rust::Vec<rust::String> getFileExtensions (const juce::CoreAudioFormat& format);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::CoreAudioFormat& format, rust::Str path);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::CoreAudioFormat& format, rust::Box<BoxedInputStream> stream);
+} // namespace core_audio_format
+#endif
+
+#if JUCE_USE_FLAC
+namespace flac_audio_format
+{
+    std::unique_ptr<juce::FlacAudioFormat> createFlacAudioFormat();
+    std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::FlacAudioFormat& format, rust::Str path);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::FlacAudioFormat& format, rust::Box<BoxedInputStream> stream);
+    std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::FlacAudioFormat& format,
+                                                                  rust::Str path,
+                                                                  double sampleRate,
+                                                                  juce::uint32 numChannels,
+                                                                  int bitsPerSample,
+                                                                  int compressionLevel);
+} // namespace flac_audio_format
+#endif
+
+#if JUCE_USE_LAME_AUDIO_FORMAT
+namespace lame_encoder_audio_format
+{
+    std::unique_ptr<juce::LAMEEncoderAudioFormat> createLAMEEncoderAudioFormat (rust::Str lameExecutable);
+    rust::Vec<rust::String> getQualityOptions (juce::LAMEEncoderAudioFormat& format);
+    std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::LAMEEncoderAudioFormat& format,
+                                                                  rust::Str path,
+                                                                  double sampleRate,
+                                                                  juce::uint32 numChannels,
+                                                                  int qualityOptionIndex);
+} // namespace lame_encoder_audio_format
+#endif
+
+#if JUCE_USE_MP3AUDIOFORMAT
+namespace mp3_audio_format
+{
+    std::unique_ptr<juce::MP3AudioFormat> createMP3AudioFormat();
+    std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::MP3AudioFormat& format, rust::Str path);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::MP3AudioFormat& format, rust::Box<BoxedInputStream> stream);
+} // namespace mp3_audio_format
+#endif
+
+#if JUCE_USE_OGGVORBIS
+namespace ogg_vorbis_audio_format
+{
+    std::unique_ptr<juce::OggVorbisAudioFormat> createOggVorbisAudioFormat();
+    rust::Vec<rust::String> getQualityOptions (juce::OggVorbisAudioFormat& format);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::OggVorbisAudioFormat& format, rust::Str path);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::OggVorbisAudioFormat& format, rust::Box<BoxedInputStream> stream);
+    std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::OggVorbisAudioFormat& format,
+                                                                  rust::Str path,
+                                                                  double sampleRate,
+                                                                  juce::uint32 numChannels,
+                                                                  int qualityOptionIndex,
+                                                                  rust::Slice<const StringPair> metadata);
+} // namespace ogg_vorbis_audio_format
+#endif
+
+#if JUCE_WINDOWS
+namespace windows_media_audio_format
+{
+    std::unique_ptr<juce::WindowsMediaAudioFormat> createWindowsMediaAudioFormat();
+    rust::Vec<rust::String> getFileExtensions (const juce::WindowsMediaAudioFormat& format);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::WindowsMediaAudioFormat& format, rust::Str path);
+    std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::WindowsMediaAudioFormat& format, rust::Box<BoxedInputStream> stream);
+} // namespace windows_media_audio_format
+#endif
+
+namespace audio_format_writer
+{
+    void write (juce::AudioFormatWriter& writer, const juce::AudioSampleBuffer& buffer, int startSample, int numSamples);
+    void flush (juce::AudioFormatWriter& writer);
+    double getSampleRate (const juce::AudioFormatWriter& writer);
+    juce::uint32 getNumChannels (const juce::AudioFormatWriter& writer);
+    juce::uint32 getBitsPerSample (const juce::AudioFormatWriter& writer);
+} // namespace audio_format_writer
+#endif
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_processors
+namespace plugin_description
+{
+    juce::PluginDescription toJuce (const PluginDescription& description);
+    PluginDescription fromJuce (const juce::PluginDescription& description);
+    rust::Vec<PluginDescription> fromJuce (const juce::Array<juce::PluginDescription>& descriptions);
+} // namespace plugin_description
+
+namespace audio_channel_set
+{
+    juce::AudioChannelSet toJuce (const AudioChannelSet& channelSet);
+    AudioChannelSet fromJuce (const juce::AudioChannelSet& channelSet);
+    AudioChannelSet canonical (size_t numChannels);
+    AudioChannelSet discrete (size_t numChannels);
+    rust::String getDescription (const AudioChannelSet& channelSet);
+    rust::String getSpeakerArrangement (const AudioChannelSet& channelSet);
+} // namespace audio_channel_set
+
+namespace buses_layout
+{
+    juce::AudioProcessor::BusesLayout toJuce (const BusesLayout& layout);
+    BusesLayout fromJuce (const juce::AudioProcessor::BusesLayout& layout);
+} // namespace buses_layout
+
+namespace audio_plugin_format_manager
+{
+    std::unique_ptr<juce::AudioPluginFormatManager> createAudioPluginFormatManager();
+    rust::Vec<rust::String> getFormatNames (const juce::AudioPluginFormatManager& manager);
+    juce::AudioPluginFormat& getFormat (const juce::AudioPluginFormatManager& manager, const juce::String& name);
+    rust::Vec<PluginDescription> scanPath (juce::AudioPluginFormatManager& manager, rust::Str path, bool recursive);
+    rust::Vec<PluginDescription> scanDefaultLocations (juce::AudioPluginFormatManager& manager);
+    std::unique_ptr<juce::AudioPluginInstance> createPluginInstance (juce::AudioPluginFormatManager& manager,
+                                                                     const PluginDescription& description,
+                                                                     double sampleRate,
+                                                                     size_t blockSize);
+} // namespace audio_plugin_format_manager
+
+namespace audio_processor
+{
+    // Processes a block after checking that the buffer matches the processor's channels and
+    // prepared block size, which JUCE would otherwise only assert.
+    void process (juce::AudioProcessor& processor, juce::AudioSampleBuffer& buffer, juce::MidiBuffer& midi);
+
+    juce::AudioProcessorParameter& getParameter (const juce::AudioProcessor& processor, size_t index);
+    rust::Vec<ParameterInfo> getParameterInfos (const juce::AudioProcessor& processor);
+    rust::Vec<ParameterGroupEntry> getParameterGroups (const juce::AudioProcessor& processor);
+    rust::Vec<juce::uint8> getStateInformation (juce::AudioProcessor& processor);
+    void setStateInformation (juce::AudioProcessor& processor, rust::Slice<const juce::uint8> data);
+    rust::Vec<juce::uint8> getCurrentProgramStateInformation (juce::AudioProcessor& processor);
+    void setCurrentProgramStateInformation (juce::AudioProcessor& processor, rust::Slice<const juce::uint8> data);
+} // namespace audio_processor
+
+namespace plugin_instance
+{
+    PluginDescription getPluginDescription (const juce::AudioPluginInstance& instance);
+    rust::String getName (const juce::AudioPluginInstance& instance);
+    void prepare (juce::AudioPluginInstance& instance, double sampleRate, size_t blockSize);
+    void process (juce::AudioPluginInstance& instance, juce::AudioSampleBuffer& buffer, juce::MidiBuffer& midi);
+    size_t getNumParameters (const juce::AudioPluginInstance& instance);
+    rust::Vec<ParameterInfo> getParameterInfos (const juce::AudioPluginInstance& instance);
+    rust::Vec<ParameterGroupEntry> getParameterGroups (const juce::AudioPluginInstance& instance);
+    float getParameterValue (const juce::AudioPluginInstance* instance, size_t index);
+    void setParameterValue (const juce::AudioPluginInstance* instance, size_t index, float value);
+    void beginParameterChangeGesture (const juce::AudioPluginInstance* instance, size_t index);
+    void endParameterChangeGesture (const juce::AudioPluginInstance* instance, size_t index);
+    rust::String getParameterText (const juce::AudioPluginInstance* instance, size_t index, float value);
+    float getParameterValueForText (const juce::AudioPluginInstance* instance, size_t index, rust::Str text);
+    rust::Vec<juce::uint8> getStateInformation (juce::AudioPluginInstance& instance);
+    void setStateInformation (juce::AudioPluginInstance& instance, rust::Slice<const juce::uint8> data);
+    rust::Vec<juce::uint8> getCurrentProgramStateInformation (juce::AudioPluginInstance& instance);
+    void setCurrentProgramStateInformation (juce::AudioPluginInstance& instance, rust::Slice<const juce::uint8> data);
+    void setCurrentProgram (juce::AudioPluginInstance& instance, size_t index);
+    rust::String getProgramName (juce::AudioPluginInstance& instance, size_t index);
+    std::unique_ptr<AudioProcessorListenerHandle> addListener (juce::AudioPluginInstance& instance,
+                                                               rust::Box<BoxedAudioProcessorListener> listener);
+    BusesLayout getBusesLayout (const juce::AudioPluginInstance& instance);
+    bool checkBusesLayoutSupported (const juce::AudioPluginInstance& instance, const BusesLayout& layout);
+    void setBusesLayout (juce::AudioPluginInstance& instance, const BusesLayout& layout);
+    rust::Vec<BusInfo> getBuses (const juce::AudioPluginInstance& instance, bool isInput);
+    void setBusEnabled (juce::AudioPluginInstance& instance, bool isInput, size_t index, bool enabled);
+    std::unique_ptr<AudioPlayHeadHandle> setPlayHead (juce::AudioPluginInstance& instance,
+                                                      rust::Box<BoxedAudioPlayHead> playHead);
+    std::unique_ptr<PluginEditorWindow> createEditorWindow (const juce::AudioPluginInstance& instance,
+                                                            rust::Str title,
+                                                            rust::Box<BoxedPluginEditorWindowListener> listener);
+} // namespace plugin_instance
+
+namespace known_plugin_list
+{
+    std::unique_ptr<juce::KnownPluginList> createKnownPluginList();
+    rust::Vec<PluginDescription> getTypes (const juce::KnownPluginList& list);
+    bool addType (juce::KnownPluginList& list, const PluginDescription& description);
+    void removeType (juce::KnownPluginList& list, const PluginDescription& description);
+    bool getTypeForIdentifierString (const juce::KnownPluginList& list, rust::Str identifier, PluginDescription& description);
+    void addToBlacklist (juce::KnownPluginList& list, rust::Str fileOrIdentifier);
+    void removeFromBlacklist (juce::KnownPluginList& list, rust::Str fileOrIdentifier);
+    rust::Vec<rust::String> getBlacklistedFiles (const juce::KnownPluginList& list);
+    rust::String toXml (const juce::KnownPluginList& list);
+    void fromXml (juce::KnownPluginList& list, rust::Str xml);
+} // namespace known_plugin_list
+
+namespace plugin_directory_scanner
+{
+    std::unique_ptr<juce::PluginDirectoryScanner> createPluginDirectoryScanner (juce::KnownPluginList& list,
+                                                                               const juce::AudioPluginFormatManager& manager,
+                                                                               rust::Str formatName,
+                                                                               rust::Slice<const rust::String> paths,
+                                                                               bool recursive,
+                                                                               rust::Str deadMansPedalFile);
+    bool scanNextFile (juce::PluginDirectoryScanner& scanner, bool dontRescanIfAlreadyInList, rust::String& nameOfPluginBeingScanned);
+    rust::String getNextPluginFileThatWillBeScanned (const juce::PluginDirectoryScanner& scanner);
+    rust::Vec<rust::String> getFailedFiles (const juce::PluginDirectoryScanner& scanner);
+} // namespace plugin_directory_scanner
+
+namespace out_of_process_scanner
+{
+    void setChildProcessScanner (juce::KnownPluginList& list, rust::Slice<const rust::String> command, int timeoutMs);
+    void clearCustomScanner (juce::KnownPluginList& list);
+    void runChildScan (const juce::AudioPluginFormatManager& manager, rust::Slice<const rust::String> args);
+} // namespace out_of_process_scanner
+
+// Hosts a plugin in a child process, passing audio to and from it through a memory mapped file,
+// so that the host survives the plugin crashing.
+class OutOfProcessPlugin
+{
+public:
+    OutOfProcessPlugin (juce::StringArray command,
+                        const PluginDescription& description,
+                        double sampleRate,
+                        size_t blockSize,
+                        int timeoutMs);
+    ~OutOfProcessPlugin();
+
+    rust::String getName() const;
+    size_t getNumInputChannels() const;
+    size_t getNumOutputChannels() const;
+    size_t getMaximumBlockSize() const;
+    bool hasCrashed() const;
+
+    void prepare (double sampleRate, size_t blockSize);
+    void process (juce::AudioSampleBuffer& buffer);
+    void releaseResources();
+
+    struct SharedState;
+
+private:
+    bool send (int command);
+    void sendOrThrow (int command);
+    float* getChannel (int channel) const;
+
+    juce::TemporaryFile _sharedFile;
+    juce::InterProcessLock _hostLock;
+    juce::NamedPipe _pipe;
+    std::unique_ptr<juce::MemoryMappedFile> _mappedFile;
+    SharedState* _state = nullptr;
+    juce::ChildProcess _process;
+    int _timeoutMs;
+    bool _crashed = false;
+
+    // The child can write anything to the shared memory, so these are only read from it once,
+    // after loading, and checked.
+    juce::String _name;
+    int _numInputChannels = 0;
+    int _numOutputChannels = 0;
+    int _maximumBlockSize = 0;
+    int _blockSize = 0;
+};
+
+namespace out_of_process_plugin
+{
+    std::unique_ptr<OutOfProcessPlugin> createOutOfProcessPlugin (rust::Slice<const rust::String> command,
+                                                                  const PluginDescription& description,
+                                                                  double sampleRate,
+                                                                  size_t blockSize,
+                                                                  int timeoutMs);
+    void runChild (juce::AudioPluginFormatManager& manager, rust::Slice<const rust::String> args);
+} // namespace out_of_process_plugin
+
+namespace audio_processor_graph
+{
+    std::unique_ptr<juce::AudioProcessorGraph> createAudioProcessorGraph();
+    NodeId addPlugin (juce::AudioProcessorGraph& graph, std::unique_ptr<juce::AudioPluginInstance> plugin);
+    NodeId addRustProcessor (juce::AudioProcessorGraph& graph, std::unique_ptr<RustAudioProcessor> processor);
+    NodeId addIOProcessor (juce::AudioProcessorGraph& graph, int ioType);
+    bool removeNode (juce::AudioProcessorGraph& graph, NodeId nodeId);
+    rust::Vec<NodeId> getNodeIds (const juce::AudioProcessorGraph& graph);
+    bool getNodeName (const juce::AudioProcessorGraph& graph, NodeId nodeId, rust::String& name);
+    bool setNodeBypassed (juce::AudioProcessorGraph& graph, NodeId nodeId, bool bypassed);
+    bool isNodeBypassed (const juce::AudioProcessorGraph& graph, NodeId nodeId);
+    int getNodeLatencySamples (const juce::AudioProcessorGraph& graph, NodeId nodeId);
+    rust::Vec<juce::uint8> getNodeStateInformation (juce::AudioProcessorGraph& graph, NodeId nodeId);
+    void setNodeStateInformation (juce::AudioProcessorGraph& graph, NodeId nodeId, rust::Slice<const juce::uint8> data);
+    rust::Vec<juce::uint8> getNodeCurrentProgramStateInformation (juce::AudioProcessorGraph& graph, NodeId nodeId);
+    void setNodeCurrentProgramStateInformation (juce::AudioProcessorGraph& graph,
+                                                NodeId nodeId,
+                                                rust::Slice<const juce::uint8> data);
+    bool addConnection (juce::AudioProcessorGraph& graph, const Connection& connection);
+    bool removeConnection (juce::AudioProcessorGraph& graph, const Connection& connection);
+    bool isConnected (const juce::AudioProcessorGraph& graph, const Connection& connection);
+    bool canConnect (const juce::AudioProcessorGraph& graph, const Connection& connection);
+    rust::Vec<Connection> getConnections (const juce::AudioProcessorGraph& graph);
+    bool disconnectNode (juce::AudioProcessorGraph& graph, NodeId nodeId);
+    void clear (juce::AudioProcessorGraph& graph);
+    void prepare (juce::AudioProcessorGraph& graph, size_t numInputChannels, size_t numOutputChannels, double sampleRate, size_t blockSize);
+    void process (juce::AudioProcessorGraph& graph, juce::AudioSampleBuffer& buffer, juce::MidiBuffer& midi);
+    std::unique_ptr<AudioPlayHeadHandle> setPlayHead (juce::AudioProcessorGraph& graph,
+                                                      rust::Box<BoxedAudioPlayHead> playHead);
+    std::unique_ptr<PluginEditorWindow> createEditorWindow (const juce::AudioProcessorGraph& graph,
+                                                            NodeId nodeId,
+                                                            rust::Str title,
+                                                            rust::Box<BoxedPluginEditorWindowListener> listener);
+} // namespace audio_processor_graph
+#endif
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_utils
+namespace audio_processor_player
+{
+    std::unique_ptr<juce::AudioProcessorPlayer> createAudioProcessorPlayer();
+    void setGraph (juce::AudioProcessorPlayer& player, juce::AudioProcessorGraph& graph);
+    void setPluginInstance (juce::AudioProcessorPlayer& player, juce::AudioPluginInstance& instance);
+    std::unique_ptr<juce::ScopedLock> lockProcessor (const juce::AudioProcessorPlayer& player);
+} // namespace audio_processor_player
+#endif
+
+#if JUCE_MODULE_AVAILABLE_juce_dsp
+namespace fft
+{
+    std::unique_ptr<juce::dsp::FFT> createFFT (int order);
+    void perform (const juce::dsp::FFT& fft, rust::Slice<const Complex> input, rust::Slice<Complex> output, bool inverse);
+    void performRealOnlyForwardTransform (const juce::dsp::FFT& fft, rust::Slice<float> data, bool onlyCalculateNonNegativeFrequencies);
+    void performRealOnlyInverseTransform (const juce::dsp::FFT& fft, rust::Slice<float> data);
+    void performFrequencyOnlyForwardTransform (const juce::dsp::FFT& fft, rust::Slice<float> data, bool ignoreNegativeFrequencies);
+} // namespace fft
+
+using WindowingFunction = juce::dsp::WindowingFunction<float>;
+
+namespace windowing_function
+{
+    std::unique_ptr<WindowingFunction> createWindowingFunction (size_t size, int method, bool normalise, float beta);
+    void fillWindowingTables (WindowingFunction& window, size_t size, int method, bool normalise, float beta);
+    void multiplyWithWindowingTable (const WindowingFunction& window, rust::Slice<float> samples);
+    void fillWindowingTable (rust::Slice<float> samples, int method, bool normalise, float beta);
+} // namespace windowing_function
+
+namespace dsp
+{
+    juce::dsp::ProcessSpec toProcessSpec (const ProcessSpec& spec);
+
+    // Processes as many channels as the processor was prepared for, in blocks no larger than
+    // the one it was prepared for.
+    template <typename Processor>
+    void processReplacing (Processor& processor, juce::AudioSampleBuffer& buffer, const juce::dsp::ProcessSpec& spec)
+    {
+        if (spec.maximumBlockSize == 0)
+        {
+            return;
+        }
+
+        const auto numChannels = juce::jmin (static_cast<size_t> (buffer.getNumChannels()), static_cast<size_t> (spec.numChannels));
+        auto block = juce::dsp::AudioBlock<float> (buffer).getSubsetChannelBlock (0, numChannels);
+
+        for (size_t start = 0; start < block.getNumSamples(); start += spec.maximumBlockSize)
+        {
+            auto subBlock = block.getSubBlock (start, juce::jmin (static_cast<size_t> (spec.maximumBlockSize), block.getNumSamples() - start));
+            processor.process (juce::dsp::ProcessContextReplacing<float> (subBlock));
+        }
+    }
+} // namespace dsp
+
+namespace convolution
+{
+    std::unique_ptr<juce::dsp::Convolution> createConvolution (int latency, int headSize);
+    bool loadImpulseResponseFromFile (juce::dsp::Convolution& convolution, rust::Str path, bool stereo, bool trim, size_t size, bool normalise);
+    bool loadImpulseResponseFromData (juce::dsp::Convolution& convolution, rust::Slice<const juce::uint8> data, bool stereo, bool trim, size_t size, bool normalise);
+    void loadImpulseResponseFromBuffer (juce::dsp::Convolution& convolution,
+                                        const juce::AudioSampleBuffer& buffer,
+                                        double sampleRate,
+                                        bool stereo,
+                                        bool trim,
+                                        bool normalise);
+    void prepare (juce::dsp::Convolution& convolution, const ProcessSpec& spec);
+    void process (juce::dsp::Convolution& convolution, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace convolution
+
+namespace dsp
+{
+    class IIRFilter
+    {
+    public:
+        explicit IIRFilter (rust::Slice<const float> coefficients);
+
+        void setCoefficients (rust::Slice<const float> coefficients);
+        void prepare (const ProcessSpec& spec);
+        void reset();
+        void process (juce::AudioSampleBuffer& buffer);
+        float processSample (size_t channel, float sample);
+
+    private:
+        juce::dsp::IIR::Coefficients<float>::Ptr _coefficients { new juce::dsp::IIR::Coefficients<float>() };
+        std::vector<juce::dsp::IIR::Filter<float>> _filters;
+    };
+} // namespace dsp
+
+namespace iir
+{
+    rust::Vec<float> design (int type, double sampleRate, float frequency, float q, float gainFactor);
+    double getMagnitudeForFrequency (rust::Slice<const float> coefficients, double frequency, double sampleRate);
+    double getPhaseForFrequency (rust::Slice<const float> coefficients, double frequency, double sampleRate);
+    std::unique_ptr<dsp::IIRFilter> createFilter (rust::Slice<const float> coefficients);
+} // namespace iir
+
+namespace dsp
+{
+    class FIRFilter
+    {
+    public:
+        explicit FIRFilter (rust::Slice<const float> coefficients);
+
+        void setCoefficients (rust::Slice<const float> coefficients);
+        void prepare (const ProcessSpec& spec);
+        void reset();
+        void process (juce::AudioSampleBuffer& buffer);
+        float processSample (size_t channel, float sample);
+
+    private:
+        juce::dsp::FIR::Coefficients<float>::Ptr _coefficients { new juce::dsp::FIR::Coefficients<float>() };
+        std::vector<juce::dsp::FIR::Filter<float>> _filters;
+    };
+} // namespace dsp
+
+namespace fir
+{
+    rust::Vec<float> designLowpassWindowMethod (double sampleRate, float frequency, size_t order, int windowingMethod, float beta);
+    rust::Vec<float> designLowpassKaiserMethod (double sampleRate, float frequency, float normalisedTransitionWidth, float amplitudeDb);
+    rust::Vec<float> designLowpassTransitionMethod (double sampleRate, float frequency, size_t order, float normalisedTransitionWidth, float spline);
+    rust::Vec<float> designLowpassLeastSquaresMethod (double sampleRate, float frequency, size_t order, float normalisedTransitionWidth, float stopBandWeight);
+    rust::Vec<float> designLowpassHalfBandEquirippleMethod (float normalisedTransitionWidth, float amplitudeDb);
+    double getMagnitudeForFrequency (rust::Slice<const float> coefficients, double frequency, double sampleRate);
+    double getPhaseForFrequency (rust::Slice<const float> coefficients, double frequency, double sampleRate);
+    std::unique_ptr<dsp::FIRFilter> createFilter (rust::Slice<const float> coefficients);
+} // namespace fir
+
+using FirstOrderTPTFilter = juce::dsp::FirstOrderTPTFilter<float>;
+
+namespace first_order_tpt_filter
+{
+    std::unique_ptr<FirstOrderTPTFilter> createFirstOrderTPTFilter();
+    void setType (FirstOrderTPTFilter& filter, int type);
+    int getType (const FirstOrderTPTFilter& filter);
+    void prepare (FirstOrderTPTFilter& filter, const ProcessSpec& spec);
+    void process (FirstOrderTPTFilter& filter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+    float processSample (FirstOrderTPTFilter& filter, size_t channel, float sample);
+} // namespace first_order_tpt_filter
+
+using StateVariableTPTFilter = juce::dsp::StateVariableTPTFilter<float>;
+
+namespace state_variable_tpt_filter
+{
+    std::unique_ptr<StateVariableTPTFilter> createStateVariableTPTFilter();
+    void setType (StateVariableTPTFilter& filter, int type);
+    int getType (const StateVariableTPTFilter& filter);
+    void prepare (StateVariableTPTFilter& filter, const ProcessSpec& spec);
+    void process (StateVariableTPTFilter& filter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+    float processSample (StateVariableTPTFilter& filter, size_t channel, float sample);
+} // namespace state_variable_tpt_filter
+
+using LinkwitzRileyFilter = juce::dsp::LinkwitzRileyFilter<float>;
+
+namespace linkwitz_riley_filter
+{
+    std::unique_ptr<LinkwitzRileyFilter> createLinkwitzRileyFilter();
+    void setType (LinkwitzRileyFilter& filter, int type);
+    int getType (const LinkwitzRileyFilter& filter);
+    void prepare (LinkwitzRileyFilter& filter, const ProcessSpec& spec);
+    void process (LinkwitzRileyFilter& filter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+    float processSample (LinkwitzRileyFilter& filter, size_t channel, float sample);
+    void processSampleBands (LinkwitzRileyFilter& filter, size_t channel, float sample, float& low, float& high);
+    void split (LinkwitzRileyFilter& filter, juce::AudioSampleBuffer& low, juce::AudioSampleBuffer& high, const ProcessSpec& spec);
+} // namespace linkwitz_riley_filter
+
+using Oscillator = juce::dsp::Oscillator<float>;
+
+namespace oscillator
+{
+    std::unique_ptr<Oscillator> createOscillator (int waveform, size_t lookupTableSize);
+    std::unique_ptr<Oscillator> createWavetableOscillator (rust::Slice<const float> wavetable);
+    void prepare (Oscillator& oscillator, const ProcessSpec& spec);
+    void process (Oscillator& oscillator, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace oscillator
+
+using LadderFilter = juce::dsp::LadderFilter<float>;
+
+namespace ladder_filter
+{
+    std::unique_ptr<LadderFilter> createLadderFilter();
+    void setMode (LadderFilter& filter, int mode);
+    void prepare (LadderFilter& filter, const ProcessSpec& spec);
+    void process (LadderFilter& filter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace ladder_filter
+
+namespace dsp
+{
+    // The interpolation of a delay line is a template parameter, so each interpolation is
+    // implemented behind this interface.
+    class DelayLine
+    {
+    public:
+        virtual ~DelayLine() = default;
+
+        virtual void setDelay (float delayInSamples) = 0;
+        virtual float getDelay() const = 0;
+        virtual void setMaximumDelayInSamples (size_t maximumDelayInSamples) = 0;
+        virtual size_t getMaximumDelayInSamples() const = 0;
+        virtual void prepare (const ProcessSpec& spec) = 0;
+        virtual void reset() = 0;
+        virtual void process (juce::AudioSampleBuffer& buffer) = 0;
+        virtual void pushSample (size_t channel, float sample) = 0;
+        virtual float popSample (size_t channel, float delayInSamples, bool updateReadPointer) = 0;
+    };
+} // namespace dsp
+
+namespace delay_line
+{
+    std::unique_ptr<dsp::DelayLine> createDelayLine (int interpolation, size_t maximumDelayInSamples);
+} // namespace delay_line
+
+namespace reverb
+{
+    std::unique_ptr<juce::Reverb> createReverb();
+    void setParameters (juce::Reverb& reverb, const ReverbParameters& parameters);
+    ReverbParameters getParameters (const juce::Reverb& reverb);
+    void process (juce::Reverb& reverb, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+    void processStereo (juce::Reverb& reverb, rust::Slice<float> left, rust::Slice<float> right);
+    void processMono (juce::Reverb& reverb, rust::Slice<float> samples);
+} // namespace reverb
+
+using Compressor = juce::dsp::Compressor<float>;
+
+namespace compressor
+{
+    std::unique_ptr<Compressor> createCompressor();
+    void prepare (Compressor& compressor, const ProcessSpec& spec);
+    void process (Compressor& compressor, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace compressor
+
+using Limiter = juce::dsp::Limiter<float>;
+
+namespace limiter
+{
+    std::unique_ptr<Limiter> createLimiter();
+    void prepare (Limiter& limiter, const ProcessSpec& spec);
+    void process (Limiter& limiter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace limiter
+
+using NoiseGate = juce::dsp::NoiseGate<float>;
+
+namespace noise_gate
+{
+    std::unique_ptr<NoiseGate> createNoiseGate();
+    void prepare (NoiseGate& noiseGate, const ProcessSpec& spec);
+    void process (NoiseGate& noiseGate, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace noise_gate
+
+using Phaser = juce::dsp::Phaser<float>;
+
+namespace phaser
+{
+    std::unique_ptr<Phaser> createPhaser();
+    void prepare (Phaser& phaser, const ProcessSpec& spec);
+    void process (Phaser& phaser, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace phaser
+
+using Chorus = juce::dsp::Chorus<float>;
+
+namespace chorus
+{
+    std::unique_ptr<Chorus> createChorus();
+    void prepare (Chorus& chorus, const ProcessSpec& spec);
+    void process (Chorus& chorus, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace chorus
+
+using LookupTableTransform = juce::dsp::LookupTableTransform<float>;
+
+namespace lookup_table_transform
+{
+    std::unique_ptr<LookupTableTransform> createLookupTableTransform (const BoxedWaveShaperFunction& function,
+                                                                      float minimumInput,
+                                                                      float maximumInput,
+                                                                      size_t numPoints);
+    void process (const LookupTableTransform& transform, rust::Slice<float> samples);
+} // namespace lookup_table_transform
+
+using WaveShaper = juce::dsp::WaveShaper<float, std::function<float (float)>>;
+
+namespace wave_shaper
+{
+    std::unique_ptr<WaveShaper> createWaveShaper (rust::Box<BoxedWaveShaperFunction> function);
+    std::unique_ptr<WaveShaper> createLookupTableWaveShaper (const BoxedWaveShaperFunction& function,
+                                                             float minimumInput,
+                                                             float maximumInput,
+                                                             size_t numPoints);
+    float processSample (const WaveShaper& shaper, float sample);
+    void process (WaveShaper& shaper, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace wave_shaper
+
+using DryWetMixer = juce::dsp::DryWetMixer<float>;
+
+namespace dry_wet_mixer
+{
+    std::unique_ptr<DryWetMixer> createDryWetMixer (size_t maximumWetLatencyInSamples);
+    void setMixingRule (DryWetMixer& mixer, int rule);
+    void prepare (DryWetMixer& mixer, const ProcessSpec& spec);
+    void pushDrySamples (DryWetMixer& mixer, const juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+    void mixWetSamples (DryWetMixer& mixer, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace dry_wet_mixer
+
+using Panner = juce::dsp::Panner<float>;
+
+namespace panner
+{
+    std::unique_ptr<Panner> createPanner();
+    void setRule (Panner& panner, int rule);
+    void prepare (Panner& panner, const ProcessSpec& spec);
+    void process (Panner& panner, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace panner
+
+using BallisticsFilter = juce::dsp::BallisticsFilter<float>;
+
+namespace ballistics_filter
+{
+    std::unique_ptr<BallisticsFilter> createBallisticsFilter();
+    void setLevelCalculationType (BallisticsFilter& filter, int type);
+    void prepare (BallisticsFilter& filter, const ProcessSpec& spec);
+    void process (BallisticsFilter& filter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace ballistics_filter
+
+namespace dsp
+{
+    // JUCE fixes the number of channels when the oversampling is created, so the oversampling
+    // is recreated whenever it's prepared.
+    class Oversampling
+    {
+    public:
+        Oversampling (size_t factor, int filterType);
+
+        void setMaximumQuality (bool maximumQuality);
+        void setUsingIntegerLatency (bool useIntegerLatency);
+        float getLatencyInSamples() const;
+        size_t getOversamplingFactor() const;
+        void prepare (const ProcessSpec& spec);
+        void reset();
+        juce::AudioSampleBuffer& processSamplesUp (const juce::AudioSampleBuffer& input);
+        void processSamplesDown (juce::AudioSampleBuffer& output);
+
+    private:
+        void recreate();
+        size_t limitNumChannels (const juce::AudioSampleBuffer& buffer) const;
+
+        size_t _factor;
+        juce::dsp::Oversampling<float>::FilterType _filterType;
+        bool _maximumQuality = true;
+        bool _useIntegerLatency = false;
+        juce::dsp::ProcessSpec _spec {};
+        bool _prepared = false;
+        std::unique_ptr<juce::dsp::Oversampling<float>> _oversampling;
+        juce::AudioSampleBuffer _upsampled;
+        std::vector<float*> _upsampledChannels;
+    };
+} // namespace dsp
+
+namespace oversampling
+{
+    std::unique_ptr<dsp::Oversampling> createOversampling (size_t factor, int filterType);
+} // namespace oversampling
+
+using Gain = juce::dsp::Gain<float>;
+
+namespace gain
+{
+    std::unique_ptr<Gain> createGain();
+    void prepare (Gain& gain, const ProcessSpec& spec);
+    void process (Gain& gain, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec);
+} // namespace gain
+#endif
 
-} // namespace cxx_juce
\ No newline at end of file
+} // namespace cxx_juce
@@ -0,0 +1,19 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::adsr
+{
+std::unique_ptr<juce::ADSR> createADSR()
+{
+    return std::make_unique<juce::ADSR>();
+}
+
+void setParameters (juce::ADSR& adsr, float attack, float decay, float sustain, float release)
+{
+    adsr.setParameters ({ attack, decay, sustain, release });
+}
+
+void applyEnvelopeToBuffer (juce::ADSR& adsr, juce::AudioSampleBuffer& buffer)
+{
+    adsr.applyEnvelopeToBuffer (buffer, 0, buffer.getNumSamples());
+}
+} // namespace cxx_juce::adsr
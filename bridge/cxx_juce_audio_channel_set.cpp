@@ -0,0 +1,88 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+namespace audio_channel_set
+{
+    juce::AudioChannelSet toJuce (const AudioChannelSet& channelSet)
+    {
+        juce::Array<juce::AudioChannelSet::ChannelType> channelTypes;
+
+        for (const auto channelType : channelSet.channel_types)
+        {
+            channelTypes.add (static_cast<juce::AudioChannelSet::ChannelType> (channelType));
+        }
+
+        return juce::AudioChannelSet::channelSetWithChannels (channelTypes);
+    }
+
+    AudioChannelSet fromJuce (const juce::AudioChannelSet& channelSet)
+    {
+        AudioChannelSet result;
+
+        for (const auto channelType : channelSet.getChannelTypes())
+        {
+            result.channel_types.push_back (static_cast<int32_t> (channelType));
+        }
+
+        return result;
+    }
+
+    AudioChannelSet canonical (size_t numChannels)
+    {
+        return fromJuce (juce::AudioChannelSet::canonicalChannelSet (static_cast<int> (numChannels)));
+    }
+
+    AudioChannelSet discrete (size_t numChannels)
+    {
+        return fromJuce (juce::AudioChannelSet::discreteChannels (static_cast<int> (numChannels)));
+    }
+
+    rust::String getDescription (const AudioChannelSet& channelSet)
+    {
+        return toJuce (channelSet).getDescription().toStdString();
+    }
+
+    rust::String getSpeakerArrangement (const AudioChannelSet& channelSet)
+    {
+        return toJuce (channelSet).getSpeakerArrangementAsString().toStdString();
+    }
+} // namespace audio_channel_set
+
+namespace buses_layout
+{
+    juce::AudioProcessor::BusesLayout toJuce (const BusesLayout& layout)
+    {
+        juce::AudioProcessor::BusesLayout result;
+
+        for (const auto& channelSet : layout.inputs)
+        {
+            result.inputBuses.add (audio_channel_set::toJuce (channelSet));
+        }
+
+        for (const auto& channelSet : layout.outputs)
+        {
+            result.outputBuses.add (audio_channel_set::toJuce (channelSet));
+        }
+
+        return result;
+    }
+
+    BusesLayout fromJuce (const juce::AudioProcessor::BusesLayout& layout)
+    {
+        BusesLayout result;
+
+        for (const auto& channelSet : layout.inputBuses)
+        {
+            result.inputs.push_back (audio_channel_set::fromJuce (channelSet));
+        }
+
+        for (const auto& channelSet : layout.outputBuses)
+        {
+            result.outputs.push_back (audio_channel_set::fromJuce (channelSet));
+        }
+
+        return result;
+    }
+} // namespace buses_layout
+} // namespace cxx_juce
@@ -12,6 +12,29 @@ void AudioDeviceManager::initialiseWithDefaultDevices (rust::i32 inputChannels,
     }
 }
 
+void AudioDeviceManager::initialiseWithSavedState (rust::i32 inputChannels,
+                                                   rust::i32 outputChannels,
+                                                   rust::Str savedState)
+{
+    const auto xml = juce::parseXML (static_cast<std::string> (savedState));
+    if (xml == nullptr)
+    {
+        throw std::invalid_argument ("the saved state isn't valid XML");
+    }
+
+    const auto result = _audioDeviceManager.initialise (inputChannels, outputChannels, xml.get(), true);
+    if (result.isNotEmpty())
+    {
+        throw std::runtime_error (result.toStdString());
+    }
+}
+
+[[nodiscard]] rust::String AudioDeviceManager::createStateXml() const
+{
+    const auto xml = _audioDeviceManager.createStateXml();
+    return xml != nullptr ? xml->toString().toStdString() : std::string();
+}
+
 [[nodiscard]] std::unique_ptr<AudioDeviceSetup> AudioDeviceManager::getAudioDeviceSetup() const
 {
     return std::make_unique<AudioDeviceSetup> (_audioDeviceManager.getAudioDeviceSetup());
@@ -246,6 +269,41 @@ void AudioDeviceManager::setCurrentAudioDeviceType (rust::Str audioDeviceTypeNam
     _audioDeviceManager.setCurrentAudioDeviceType (static_cast<std::string> (audioDeviceTypeName), true);
 }
 
+void AudioDeviceManager::setMidiInputDeviceEnabled (rust::Str identifier, bool enabled)
+{
+    _audioDeviceManager.setMidiInputDeviceEnabled (static_cast<std::string> (identifier), enabled);
+}
+
+bool AudioDeviceManager::isMidiInputDeviceEnabled (rust::Str identifier) const
+{
+    return _audioDeviceManager.isMidiInputDeviceEnabled (static_cast<std::string> (identifier));
+}
+
+void AudioDeviceManager::setDefaultMidiOutputDevice (rust::Str identifier)
+{
+    // MIDI input callbacks run under this lock, so a MIDI thru can't send to the old output while
+    // it's being replaced.
+    const juce::ScopedLock lock (_audioDeviceManager.getMidiCallbackLock());
+    _audioDeviceManager.setDefaultMidiOutputDevice (static_cast<std::string> (identifier));
+}
+
+rust::String AudioDeviceManager::getDefaultMidiOutputIdentifier() const
+{
+    return _audioDeviceManager.getDefaultMidiOutputIdentifier().toStdString();
+}
+
+#if JUCE_MODULE_AVAILABLE_juce_audio_utils
+[[nodiscard]] std::unique_ptr<AudioProcessorPlayerHandle> AudioDeviceManager::addAudioProcessorPlayer (juce::AudioProcessorPlayer& player)
+{
+    return std::make_unique<AudioProcessorPlayerHandle> (_audioDeviceManager, player);
+}
+#endif
+
+[[nodiscard]] std::unique_ptr<MidiThruHandle> AudioDeviceManager::addMidiThru (rust::Box<MidiThru> midiThru)
+{
+    return std::make_unique<MidiThruHandle> (_audioDeviceManager, std::move (midiThru));
+}
+
 void AudioDeviceManager::playTestSound()
 {
     _audioDeviceManager.playTestSound();
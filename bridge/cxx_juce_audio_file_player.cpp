@@ -0,0 +1,109 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+namespace
+{
+    constexpr auto readAheadSamples = 32768;
+} // namespace
+
+AudioFilePlayer::AudioFilePlayer (std::shared_ptr<juce::TimeSliceThread> bufferingThread)
+    : BufferingThreadHolder (std::move (bufferingThread))
+{
+}
+
+AudioFilePlayer::~AudioFilePlayer()
+{
+    _transport.setSource (nullptr);
+}
+
+void AudioFilePlayer::setReader (std::unique_ptr<juce::AudioFormatReader> reader)
+{
+    _transport.setSource (nullptr);
+
+    const auto sampleRate = reader->sampleRate;
+    const auto numChannels = static_cast<int> (reader->numChannels);
+
+    _readerSource = std::make_unique<juce::AudioFormatReaderSource> (reader.release(), true);
+    _readerSource->setLooping (_looping);
+    _transport.setSource (_readerSource.get(), readAheadSamples, bufferingThread.get(), sampleRate, numChannels);
+}
+
+void AudioFilePlayer::start()
+{
+    _transport.start();
+}
+
+void AudioFilePlayer::stop()
+{
+    _transport.stop();
+}
+
+bool AudioFilePlayer::isPlaying() const
+{
+    return _transport.isPlaying();
+}
+
+bool AudioFilePlayer::hasStreamFinished() const
+{
+    return _transport.hasStreamFinished();
+}
+
+void AudioFilePlayer::setPosition (double seconds)
+{
+    _transport.setPosition (seconds);
+}
+
+double AudioFilePlayer::getCurrentPosition() const
+{
+    return _transport.getCurrentPosition();
+}
+
+double AudioFilePlayer::getLengthInSeconds() const
+{
+    return _transport.getLengthInSeconds();
+}
+
+void AudioFilePlayer::setGain (float gain)
+{
+    _transport.setGain (gain);
+}
+
+float AudioFilePlayer::getGain() const
+{
+    return _transport.getGain();
+}
+
+void AudioFilePlayer::setLooping (bool shouldLoop)
+{
+    _looping = shouldLoop;
+
+    if (_readerSource != nullptr)
+    {
+        _readerSource->setLooping (shouldLoop);
+    }
+}
+
+void AudioFilePlayer::prepareToPlay (int samplesPerBlockExpected, double sampleRate)
+{
+    _transport.prepareToPlay (samplesPerBlockExpected, sampleRate);
+}
+
+void AudioFilePlayer::releaseResources()
+{
+    _transport.releaseResources();
+}
+
+void AudioFilePlayer::getNextAudioBlock (juce::AudioSampleBuffer& buffer)
+{
+    _transport.getNextAudioBlock (juce::AudioSourceChannelInfo (buffer));
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::audio_file_player
+{
+std::unique_ptr<AudioFilePlayer> createAudioFilePlayer (std::shared_ptr<juce::TimeSliceThread> bufferingThread)
+{
+    return std::make_unique<AudioFilePlayer> (std::move (bufferingThread));
+}
+} // namespace cxx_juce::audio_file_player
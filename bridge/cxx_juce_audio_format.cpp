@@ -0,0 +1,140 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::AudioFormat& format, rust::Str path)
+{
+    auto stream = toFile (path).createInputStream();
+
+    if (! stream)
+    {
+        return nullptr;
+    }
+
+    return std::unique_ptr<juce::AudioFormatReader> (format.createReaderFor (stream.release(), true));
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::AudioFormat& format, rust::Box<BoxedInputStream> stream)
+{
+    return std::unique_ptr<juce::AudioFormatReader> (
+        format.createReaderFor (new RustInputStream (std::move (stream)), true));
+}
+
+std::unique_ptr<juce::AudioFormatReader> createMemoryMappedReader (juce::AudioFormat& format, rust::Str path)
+{
+    return std::unique_ptr<juce::AudioFormatReader> (format.createMemoryMappedReader (toFile (path)));
+}
+
+namespace
+{
+// Holds on to what a writer writes while it's being created and only truncates the file once the
+// format has accepted the settings, so that an existing file is left untouched if it doesn't.
+class PendingFileOutputStream final : public juce::OutputStream
+{
+public:
+    explicit PendingFileOutputStream (const juce::File& file)
+        : _file (file.createOutputStream())
+    {
+    }
+
+    bool openedOk() const
+    {
+        return _file != nullptr && _file->openedOk();
+    }
+
+    bool commit()
+    {
+        _committed = _file->setPosition (0)
+                     && _file->truncate().wasOk()
+                     && _file->write (_pending.getData(), _pending.getDataSize())
+                     && _file->setPosition (_pending.getPosition());
+        return _committed;
+    }
+
+    void flush() override
+    {
+        getStream().flush();
+    }
+
+    bool setPosition (juce::int64 position) override
+    {
+        return getStream().setPosition (position);
+    }
+
+    juce::int64 getPosition() override
+    {
+        return getStream().getPosition();
+    }
+
+    bool write (const void* data, size_t numBytes) override
+    {
+        return getStream().write (data, numBytes);
+    }
+
+    bool writeRepeatedByte (juce::uint8 byte, size_t numTimesToRepeat) override
+    {
+        return getStream().writeRepeatedByte (byte, numTimesToRepeat);
+    }
+
+private:
+    juce::OutputStream& getStream()
+    {
+        if (_committed)
+        {
+            return *_file;
+        }
+
+        return _pending;
+    }
+
+    std::unique_ptr<juce::FileOutputStream> _file;
+    juce::MemoryOutputStream _pending;
+    bool _committed = false;
+};
+} // namespace
+
+std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::AudioFormat& format,
+                                                              rust::Str path,
+                                                              double sampleRate,
+                                                              juce::uint32 numChannels,
+                                                              int bitsPerSample,
+                                                              const juce::StringPairArray& metadata,
+                                                              int qualityOptionIndex)
+{
+    const auto file = toFile (path);
+    const auto existed = file.exists();
+    auto stream = std::make_unique<PendingFileOutputStream> (file);
+
+    if (! stream->openedOk())
+    {
+        throw std::runtime_error ("failed to open file for writing");
+    }
+
+    std::unique_ptr<juce::AudioFormatWriter> writer (format.createWriterFor (stream.get(),
+                                                                             sampleRate,
+                                                                             numChannels,
+                                                                             bitsPerSample,
+                                                                             metadata,
+                                                                             qualityOptionIndex));
+
+    if (writer == nullptr)
+    {
+        stream.reset();
+
+        if (! existed)
+        {
+            file.deleteFile();
+        }
+
+        throw std::invalid_argument ("unsupported sample rate, channel count or bit depth");
+    }
+
+    // The writer now owns the stream.
+    if (! stream.release()->commit())
+    {
+        throw std::runtime_error ("failed to truncate file");
+    }
+
+    return writer;
+}
+} // namespace cxx_juce
@@ -0,0 +1,74 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::audio_format_manager
+{
+std::unique_ptr<juce::AudioFormatManager> createAudioFormatManager()
+{
+    return std::make_unique<juce::AudioFormatManager>();
+}
+
+rust::Vec<rust::String> getKnownFormatNames (const juce::AudioFormatManager& manager)
+{
+    rust::Vec<rust::String> names;
+
+    for (auto* format : manager)
+    {
+        names.push_back (format->getFormatName().toStdString());
+    }
+
+    return names;
+}
+
+rust::String getWildcardForAllFormats (const juce::AudioFormatManager& manager)
+{
+    return manager.getWildcardForAllFormats().toStdString();
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::AudioFormatManager& manager, rust::Str path)
+{
+    return std::unique_ptr<juce::AudioFormatReader> (manager.createReaderFor (toFile (path)));
+}
+
+std::unique_ptr<juce::AudioFormatReader> openReaderForFile (juce::AudioFormatManager& manager, rust::Str path)
+{
+    const auto file = toFile (path);
+
+    if (! file.existsAsFile())
+    {
+        throw std::invalid_argument ("file not found: " + file.getFullPathName().toStdString());
+    }
+
+    auto reader = std::unique_ptr<juce::AudioFormatReader> (manager.createReaderFor (file));
+
+    if (reader == nullptr)
+    {
+        throw std::runtime_error ("no registered format can read " + file.getFullPathName().toStdString());
+    }
+
+    return reader;
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::AudioFormatManager& manager, rust::Box<BoxedInputStream> stream)
+{
+    return std::unique_ptr<juce::AudioFormatReader> (
+        manager.createReaderFor (std::make_unique<RustInputStream> (std::move (stream))));
+}
+
+std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::AudioFormatManager& manager,
+                                                              rust::Str path,
+                                                              double sampleRate,
+                                                              juce::uint32 numChannels,
+                                                              int bitsPerSample,
+                                                              rust::Slice<const StringPair> metadata)
+{
+    const auto extension = toFile (path).getFileExtension();
+    auto* format = manager.findFormatForFileExtension (extension);
+
+    if (format == nullptr)
+    {
+        throw std::invalid_argument ("no registered format for extension " + extension.quoted().toStdString());
+    }
+
+    return cxx_juce::createWriterForFile (*format, path, sampleRate, numChannels, bitsPerSample, toStringPairArray (metadata), 0);
+}
+} // namespace cxx_juce::audio_format_manager
@@ -0,0 +1,146 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::audio_format_reader
+{
+namespace
+{
+    // Reads each selected channel straight into the first buffer channel it's selected for,
+    // then copies it to any other buffer channels it's selected for.
+    void readSelectedChannels (juce::AudioFormatReader& reader,
+                               int** sources,
+                               juce::AudioSampleBuffer& buffer,
+                               int destStartSample,
+                               int numSamples,
+                               juce::int64 readerStartSample,
+                               rust::Slice<const size_t> channels)
+    {
+        std::fill_n (sources, reader.numChannels, nullptr);
+
+        for (size_t destChannel = 0; destChannel < channels.size(); ++destChannel)
+        {
+            auto*& source = sources[channels[destChannel]];
+
+            if (source == nullptr)
+            {
+                source = reinterpret_cast<int*> (buffer.getWritePointer (static_cast<int> (destChannel), destStartSample));
+            }
+        }
+
+        reader.read (sources, static_cast<int> (reader.numChannels), readerStartSample, numSamples, false);
+
+        for (size_t destChannel = 0; destChannel < channels.size(); ++destChannel)
+        {
+            auto* source = reinterpret_cast<float*> (sources[channels[destChannel]]);
+            auto* dest = buffer.getWritePointer (static_cast<int> (destChannel), destStartSample);
+
+            if (source == dest)
+            {
+                if (! reader.usesFloatingPointData)
+                {
+                    juce::FloatVectorOperations::convertFixedToFloat (dest, reinterpret_cast<const int*> (dest), 1.0f / static_cast<float> (0x7fffffff), numSamples);
+                }
+            }
+            else
+            {
+                juce::FloatVectorOperations::copy (dest, source, numSamples);
+            }
+        }
+    }
+} // namespace
+
+rust::Str getFormatName (const juce::AudioFormatReader& reader)
+{
+    return toStr (reader.getFormatName());
+}
+
+double getSampleRate (const juce::AudioFormatReader& reader)
+{
+    return reader.sampleRate;
+}
+
+juce::uint32 getNumChannels (const juce::AudioFormatReader& reader)
+{
+    return reader.numChannels;
+}
+
+juce::int64 getLengthInSamples (const juce::AudioFormatReader& reader)
+{
+    return reader.lengthInSamples;
+}
+
+juce::uint32 getBitsPerSample (const juce::AudioFormatReader& reader)
+{
+    return reader.bitsPerSample;
+}
+
+bool usesFloatingPointData (const juce::AudioFormatReader& reader)
+{
+    return reader.usesFloatingPointData;
+}
+
+rust::Vec<StringPair> getMetadataValues (const juce::AudioFormatReader& reader)
+{
+    return toStringPairs (reader.metadataValues);
+}
+
+void read (juce::AudioFormatReader& reader,
+           juce::AudioSampleBuffer& buffer,
+           int destStartSample,
+           int numSamples,
+           juce::int64 readerStartSample)
+{
+    reader.read (&buffer, destStartSample, numSamples, readerStartSample, true, true);
+}
+
+void readChannels (juce::AudioFormatReader& reader,
+                   juce::AudioSampleBuffer& buffer,
+                   int destStartSample,
+                   int numSamples,
+                   juce::int64 readerStartSample,
+                   rust::Slice<const size_t> channels,
+                   bool fillLeftoverChannelsWithCopies)
+{
+    if (channels.size() > static_cast<size_t> (buffer.getNumChannels()))
+    {
+        throw std::invalid_argument ("buffer has fewer channels than were selected");
+    }
+
+    for (const auto channel : channels)
+    {
+        if (channel >= reader.numChannels)
+        {
+            throw std::out_of_range ("channel index out of range");
+        }
+    }
+
+    const auto numSelected = static_cast<int> (channels.size());
+
+    if (numSamples > 0 && numSelected > 0)
+    {
+        // Like AudioFormatReader::read, avoid allocating the channel pointers unless there are
+        // more than 64 channels.
+        if (reader.numChannels <= 64)
+        {
+            int* sources[64];
+            readSelectedChannels (reader, sources, buffer, destStartSample, numSamples, readerStartSample, channels);
+        }
+        else
+        {
+            juce::HeapBlock<int*> sources (reader.numChannels);
+            readSelectedChannels (reader, sources, buffer, destStartSample, numSamples, readerStartSample, channels);
+        }
+    }
+
+    for (auto destChannel = numSelected; destChannel < buffer.getNumChannels(); ++destChannel)
+    {
+        if (fillLeftoverChannelsWithCopies && numSelected > 0)
+        {
+            buffer.copyFrom (destChannel, destStartSample, buffer, numSelected - 1, destStartSample, numSamples);
+        }
+        else
+        {
+            buffer.clear (destChannel, destStartSample, numSamples);
+        }
+    }
+}
+} // namespace cxx_juce::audio_format_reader
@@ -0,0 +1,40 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::audio_format_writer
+{
+void write (juce::AudioFormatWriter& writer, const juce::AudioSampleBuffer& buffer, int startSample, int numSamples)
+{
+    if (buffer.getNumChannels() < static_cast<int> (writer.getNumChannels()))
+    {
+        throw std::invalid_argument ("buffer has fewer channels than the writer");
+    }
+
+    if (! writer.writeFromAudioSampleBuffer (buffer, startSample, numSamples))
+    {
+        throw std::runtime_error ("failed to write samples");
+    }
+}
+
+void flush (juce::AudioFormatWriter& writer)
+{
+    if (! writer.flush())
+    {
+        throw std::runtime_error ("failed to flush writer");
+    }
+}
+
+double getSampleRate (const juce::AudioFormatWriter& writer)
+{
+    return writer.getSampleRate();
+}
+
+juce::uint32 getNumChannels (const juce::AudioFormatWriter& writer)
+{
+    return static_cast<juce::uint32> (writer.getNumChannels());
+}
+
+juce::uint32 getBitsPerSample (const juce::AudioFormatWriter& writer)
+{
+    return static_cast<juce::uint32> (writer.getBitsPerSample());
+}
+} // namespace cxx_juce::audio_format_writer
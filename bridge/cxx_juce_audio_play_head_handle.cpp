@@ -0,0 +1,46 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+AudioPlayHeadHandle::AudioPlayHeadHandle (juce::AudioProcessor& processor, rust::Box<BoxedAudioPlayHead> playHead)
+    : _processor (processor)
+    , _playHead (std::move (playHead))
+{
+    const juce::ScopedLock lock (_processor.getCallbackLock());
+    _processor.setPlayHead (this);
+}
+
+AudioPlayHeadHandle::~AudioPlayHeadHandle()
+{
+    const juce::ScopedLock lock (_processor.getCallbackLock());
+
+    // A newer handle may have replaced this one before it was dropped.
+    if (_processor.getPlayHead() == this)
+    {
+        _processor.setPlayHead (nullptr);
+    }
+}
+
+juce::Optional<juce::AudioPlayHead::PositionInfo> AudioPlayHeadHandle::getPosition() const
+{
+    PlayHeadPosition position {};
+
+    if (! ::audio_play_head::getPosition (*_playHead, position))
+    {
+        return {};
+    }
+
+    PositionInfo info;
+    info.setBpm (position.bpm);
+    info.setTimeSignature (TimeSignature { position.time_signature_numerator, position.time_signature_denominator });
+    info.setTimeInSamples (position.time_in_samples);
+    info.setTimeInSeconds (position.time_in_seconds);
+    info.setPpqPosition (position.ppq_position);
+    info.setPpqPositionOfLastBarStart (position.ppq_position_of_last_bar_start);
+    info.setIsPlaying (position.is_playing);
+    info.setIsRecording (position.is_recording);
+    info.setIsLooping (position.is_looping);
+    info.setLoopPoints (LoopPoints { position.ppq_loop_start, position.ppq_loop_end });
+    return info;
+}
+} // namespace cxx_juce
@@ -0,0 +1,114 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::audio_plugin_format_manager
+{
+std::unique_ptr<juce::AudioPluginFormatManager> createAudioPluginFormatManager()
+{
+    return std::make_unique<juce::AudioPluginFormatManager>();
+}
+
+rust::Vec<rust::String> getFormatNames (const juce::AudioPluginFormatManager& manager)
+{
+    rust::Vec<rust::String> names;
+
+    for (auto* format : manager.getFormats())
+    {
+        names.push_back (format->getName().toStdString());
+    }
+
+    return names;
+}
+
+juce::AudioPluginFormat& getFormat (const juce::AudioPluginFormatManager& manager, const juce::String& name)
+{
+    for (auto* format : manager.getFormats())
+    {
+        if (format->getName() == name)
+        {
+            return *format;
+        }
+    }
+
+    throw std::invalid_argument ("no registered plugin format named " + name.quoted().toStdString());
+}
+
+namespace
+{
+    void findAllTypesInPaths (juce::AudioPluginFormat& format,
+                              juce::OwnedArray<juce::PluginDescription>& found,
+                              const juce::FileSearchPath& paths,
+                              bool recursive)
+    {
+        for (const auto& identifier : format.searchPathsForPlugins (paths, recursive, false))
+        {
+            format.findAllTypesForFile (found, identifier);
+        }
+    }
+
+    rust::Vec<PluginDescription> toPluginDescriptions (const juce::OwnedArray<juce::PluginDescription>& found)
+    {
+        rust::Vec<PluginDescription> result;
+        result.reserve (static_cast<size_t> (found.size()));
+
+        for (const auto* description : found)
+        {
+            result.push_back (plugin_description::fromJuce (*description));
+        }
+
+        return result;
+    }
+} // namespace
+
+rust::Vec<PluginDescription> scanPath (juce::AudioPluginFormatManager& manager, rust::Str path, bool recursive)
+{
+    const auto file = toFile (path);
+    juce::OwnedArray<juce::PluginDescription> found;
+
+    for (auto* format : manager.getFormats())
+    {
+        if (format->fileMightContainThisPluginType (file.getFullPathName()))
+        {
+            format->findAllTypesForFile (found, file.getFullPathName());
+        }
+        else if (file.isDirectory() && ! format->isTrivialToScan())
+        {
+            // Formats that are trivial to scan, such as AudioUnit and LV2, list every plugin
+            // installed on the system regardless of the search path, so they're skipped here.
+            findAllTypesInPaths (*format, found, juce::FileSearchPath (file.getFullPathName()), recursive);
+        }
+    }
+
+    return toPluginDescriptions (found);
+}
+
+rust::Vec<PluginDescription> scanDefaultLocations (juce::AudioPluginFormatManager& manager)
+{
+    juce::OwnedArray<juce::PluginDescription> found;
+
+    for (auto* format : manager.getFormats())
+    {
+        findAllTypesInPaths (*format, found, format->getDefaultLocationsToSearch(), true);
+    }
+
+    return toPluginDescriptions (found);
+}
+
+std::unique_ptr<juce::AudioPluginInstance> createPluginInstance (juce::AudioPluginFormatManager& manager,
+                                                                 const PluginDescription& description,
+                                                                 double sampleRate,
+                                                                 size_t blockSize)
+{
+    juce::String errorMessage;
+    auto instance = manager.createPluginInstance (plugin_description::toJuce (description),
+                                                  sampleRate,
+                                                  static_cast<int> (blockSize),
+                                                  errorMessage);
+
+    if (instance == nullptr)
+    {
+        throw std::runtime_error (errorMessage.isEmpty() ? "failed to create plugin instance" : errorMessage.toStdString());
+    }
+
+    return instance;
+}
+} // namespace cxx_juce::audio_plugin_format_manager
@@ -0,0 +1,142 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::audio_processor
+{
+namespace
+{
+    void addParameterGroups (const juce::AudioProcessorParameterGroup& group,
+                             size_t depth,
+                             rust::Vec<ParameterGroupEntry>& groups)
+    {
+        ParameterGroupEntry entry;
+        entry.id = group.getID().toStdString();
+        entry.name = group.getName().toStdString();
+        entry.depth = depth;
+
+        for (const auto* parameter : group.getParameters (false))
+        {
+            entry.parameters.push_back (static_cast<size_t> (parameter->getParameterIndex()));
+        }
+
+        groups.push_back (std::move (entry));
+
+        for (const auto* subgroup : group.getSubgroups (false))
+        {
+            addParameterGroups (*subgroup, depth + 1, groups);
+        }
+    }
+
+    rust::Vec<juce::uint8> toRustVec (const juce::MemoryBlock& block)
+    {
+        rust::Vec<juce::uint8> result;
+        result.reserve (block.getSize());
+
+        for (const auto byte : block)
+        {
+            result.push_back (static_cast<juce::uint8> (byte));
+        }
+
+        return result;
+    }
+} // namespace
+
+void process (juce::AudioProcessor& processor, juce::AudioSampleBuffer& buffer, juce::MidiBuffer& midi)
+{
+    const auto requiredChannels = juce::jmax (processor.getTotalNumInputChannels(), processor.getTotalNumOutputChannels());
+
+    if (buffer.getNumChannels() < requiredChannels)
+    {
+        throw std::invalid_argument ("buffer has " + std::to_string (buffer.getNumChannels())
+                                     + " channels but the processor requires " + std::to_string (requiredChannels));
+    }
+
+    if (buffer.getNumSamples() > processor.getBlockSize())
+    {
+        throw std::invalid_argument ("buffer has " + std::to_string (buffer.getNumSamples())
+                                     + " samples but the processor was prepared for at most " + std::to_string (processor.getBlockSize()));
+    }
+
+    // Instruments may add their output to the buffer rather than replacing it, so like JUCE's
+    // player, only pass on the channels that hold input.
+    for (auto channel = processor.getTotalNumInputChannels(); channel < buffer.getNumChannels(); ++channel)
+    {
+        buffer.clear (channel, 0, buffer.getNumSamples());
+    }
+
+    processor.processBlock (buffer, midi);
+}
+
+juce::AudioProcessorParameter& getParameter (const juce::AudioProcessor& processor, size_t index)
+{
+    const auto& parameters = processor.getParameters();
+
+    if (index >= static_cast<size_t> (parameters.size()))
+    {
+        throw std::invalid_argument ("parameter index " + std::to_string (index) + " out of range");
+    }
+
+    return *parameters.getUnchecked (static_cast<int> (index));
+}
+
+rust::Vec<ParameterInfo> getParameterInfos (const juce::AudioProcessor& processor)
+{
+    constexpr auto maximumStringLength = 1024;
+
+    rust::Vec<ParameterInfo> result;
+    result.reserve (static_cast<size_t> (processor.getParameters().size()));
+
+    for (const auto* parameter : processor.getParameters())
+    {
+        const auto index = parameter->getParameterIndex();
+        const auto* hosted = dynamic_cast<const juce::HostedAudioProcessorParameter*> (parameter);
+
+        result.push_back ({
+            static_cast<size_t> (index),
+            (hosted != nullptr ? hosted->getParameterID() : juce::String (index)).toStdString(),
+            parameter->getName (maximumStringLength).toStdString(),
+            parameter->getLabel().toStdString(),
+            parameter->getDefaultValue(),
+            parameter->getNumSteps(),
+            parameter->isDiscrete(),
+            parameter->isBoolean(),
+            parameter->isAutomatable(),
+            parameter->isMetaParameter(),
+            toRustStrings (parameter->getAllValueStrings()),
+        });
+    }
+
+    return result;
+}
+
+rust::Vec<ParameterGroupEntry> getParameterGroups (const juce::AudioProcessor& processor)
+{
+    rust::Vec<ParameterGroupEntry> groups;
+    addParameterGroups (processor.getParameterTree(), 0, groups);
+    return groups;
+}
+
+rust::Vec<juce::uint8> getStateInformation (juce::AudioProcessor& processor)
+{
+    juce::MemoryBlock state;
+    processor.getStateInformation (state);
+    return toRustVec (state);
+}
+
+void setStateInformation (juce::AudioProcessor& processor, rust::Slice<const juce::uint8> data)
+{
+    // The size has already been checked to fit in an int.
+    processor.setStateInformation (data.data(), static_cast<int> (data.size()));
+}
+
+rust::Vec<juce::uint8> getCurrentProgramStateInformation (juce::AudioProcessor& processor)
+{
+    juce::MemoryBlock state;
+    processor.getCurrentProgramStateInformation (state);
+    return toRustVec (state);
+}
+
+void setCurrentProgramStateInformation (juce::AudioProcessor& processor, rust::Slice<const juce::uint8> data)
+{
+    processor.setCurrentProgramStateInformation (data.data(), static_cast<int> (data.size()));
+}
+} // namespace cxx_juce::audio_processor
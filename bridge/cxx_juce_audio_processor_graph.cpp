@@ -0,0 +1,228 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::audio_processor_graph
+{
+namespace
+{
+    using Graph = juce::AudioProcessorGraph;
+
+    Graph::NodeID toJuce (NodeId nodeId)
+    {
+        return Graph::NodeID (nodeId.uid);
+    }
+
+    NodeId fromJuce (Graph::NodeID nodeId)
+    {
+        return { nodeId.uid };
+    }
+
+    Graph::Connection toJuce (const Connection& connection)
+    {
+        return { { toJuce (connection.source.node_id), connection.source.channel_index },
+                 { toJuce (connection.destination.node_id), connection.destination.channel_index } };
+    }
+
+    Connection fromJuce (const Graph::Connection& connection)
+    {
+        return { { fromJuce (connection.source.nodeID), connection.source.channelIndex },
+                 { fromJuce (connection.destination.nodeID), connection.destination.channelIndex } };
+    }
+
+    NodeId addNode (Graph& graph, std::unique_ptr<juce::AudioProcessor> processor)
+    {
+        const auto node = graph.addNode (std::move (processor));
+
+        if (node == nullptr)
+        {
+            throw std::runtime_error ("failed to add node to graph");
+        }
+
+        return fromJuce (node->nodeID);
+    }
+
+    juce::AudioProcessor& getNodeProcessor (Graph& graph, NodeId nodeId)
+    {
+        auto* node = graph.getNodeForId (toJuce (nodeId));
+
+        if (node == nullptr)
+        {
+            throw std::invalid_argument ("no node with ID " + std::to_string (nodeId.uid));
+        }
+
+        return *node->getProcessor();
+    }
+} // namespace
+
+std::unique_ptr<Graph> createAudioProcessorGraph()
+{
+    return std::make_unique<Graph>();
+}
+
+NodeId addPlugin (Graph& graph, std::unique_ptr<juce::AudioPluginInstance> plugin)
+{
+    return addNode (graph, std::move (plugin));
+}
+
+NodeId addRustProcessor (Graph& graph, std::unique_ptr<RustAudioProcessor> processor)
+{
+    return addNode (graph, std::move (processor));
+}
+
+NodeId addIOProcessor (Graph& graph, int ioType)
+{
+    return addNode (graph,
+                    std::make_unique<Graph::AudioGraphIOProcessor> (static_cast<Graph::AudioGraphIOProcessor::IODeviceType> (ioType)));
+}
+
+bool removeNode (Graph& graph, NodeId nodeId)
+{
+    return graph.removeNode (toJuce (nodeId)) != nullptr;
+}
+
+rust::Vec<NodeId> getNodeIds (const Graph& graph)
+{
+    rust::Vec<NodeId> result;
+    result.reserve (static_cast<size_t> (graph.getNumNodes()));
+
+    for (const auto* node : graph.getNodes())
+    {
+        result.push_back (fromJuce (node->nodeID));
+    }
+
+    return result;
+}
+
+bool getNodeName (const Graph& graph, NodeId nodeId, rust::String& name)
+{
+    const auto* node = graph.getNodeForId (toJuce (nodeId));
+
+    if (node == nullptr)
+    {
+        return false;
+    }
+
+    name = node->getProcessor()->getName().toStdString();
+    return true;
+}
+
+bool setNodeBypassed (Graph& graph, NodeId nodeId, bool bypassed)
+{
+    auto* node = graph.getNodeForId (toJuce (nodeId));
+
+    if (node == nullptr)
+    {
+        return false;
+    }
+
+    node->setBypassed (bypassed);
+    return true;
+}
+
+bool isNodeBypassed (const Graph& graph, NodeId nodeId)
+{
+    const auto* node = graph.getNodeForId (toJuce (nodeId));
+    return node != nullptr && node->isBypassed();
+}
+
+int getNodeLatencySamples (const Graph& graph, NodeId nodeId)
+{
+    const auto* node = graph.getNodeForId (toJuce (nodeId));
+    return node != nullptr ? node->getProcessor()->getLatencySamples() : -1;
+}
+
+rust::Vec<juce::uint8> getNodeStateInformation (Graph& graph, NodeId nodeId)
+{
+    return audio_processor::getStateInformation (getNodeProcessor (graph, nodeId));
+}
+
+void setNodeStateInformation (Graph& graph, NodeId nodeId, rust::Slice<const juce::uint8> data)
+{
+    audio_processor::setStateInformation (getNodeProcessor (graph, nodeId), data);
+}
+
+rust::Vec<juce::uint8> getNodeCurrentProgramStateInformation (Graph& graph, NodeId nodeId)
+{
+    return audio_processor::getCurrentProgramStateInformation (getNodeProcessor (graph, nodeId));
+}
+
+void setNodeCurrentProgramStateInformation (Graph& graph, NodeId nodeId, rust::Slice<const juce::uint8> data)
+{
+    audio_processor::setCurrentProgramStateInformation (getNodeProcessor (graph, nodeId), data);
+}
+
+bool addConnection (Graph& graph, const Connection& connection)
+{
+    return graph.addConnection (toJuce (connection));
+}
+
+bool removeConnection (Graph& graph, const Connection& connection)
+{
+    return graph.removeConnection (toJuce (connection));
+}
+
+bool isConnected (const Graph& graph, const Connection& connection)
+{
+    return graph.isConnected (toJuce (connection));
+}
+
+bool canConnect (const Graph& graph, const Connection& connection)
+{
+    return graph.canConnect (toJuce (connection));
+}
+
+rust::Vec<Connection> getConnections (const Graph& graph)
+{
+    rust::Vec<Connection> result;
+
+    for (const auto& connection : graph.getConnections())
+    {
+        result.push_back (fromJuce (connection));
+    }
+
+    return result;
+}
+
+bool disconnectNode (Graph& graph, NodeId nodeId)
+{
+    return graph.disconnectNode (toJuce (nodeId));
+}
+
+void clear (Graph& graph)
+{
+    graph.clear();
+}
+
+void prepare (Graph& graph, size_t numInputChannels, size_t numOutputChannels, double sampleRate, size_t blockSize)
+{
+    graph.setPlayConfigDetails (static_cast<int> (numInputChannels),
+                                static_cast<int> (numOutputChannels),
+                                sampleRate,
+                                static_cast<int> (blockSize));
+    graph.prepareToPlay (sampleRate, static_cast<int> (blockSize));
+}
+
+void process (Graph& graph, juce::AudioSampleBuffer& buffer, juce::MidiBuffer& midi)
+{
+    audio_processor::process (graph, buffer, midi);
+}
+
+std::unique_ptr<AudioPlayHeadHandle> setPlayHead (Graph& graph, rust::Box<BoxedAudioPlayHead> playHead)
+{
+    return std::make_unique<AudioPlayHeadHandle> (graph, std::move (playHead));
+}
+
+std::unique_ptr<PluginEditorWindow> createEditorWindow (const Graph& graph,
+                                                        NodeId nodeId,
+                                                        rust::Str title,
+                                                        rust::Box<BoxedPluginEditorWindowListener> listener)
+{
+    const auto* node = graph.getNodeForId (toJuce (nodeId));
+
+    if (node == nullptr)
+    {
+        throw std::invalid_argument ("no node with ID " + std::to_string (nodeId.uid));
+    }
+
+    return plugin_editor_window::createPluginEditorWindow (*node->getProcessor(), title, std::move (listener));
+}
+} // namespace cxx_juce::audio_processor_graph
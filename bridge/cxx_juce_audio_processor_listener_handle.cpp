@@ -0,0 +1,37 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+AudioProcessorListenerHandle::AudioProcessorListenerHandle (juce::AudioProcessor& processor,
+                                                            rust::Box<BoxedAudioProcessorListener> listener)
+    : _processor (processor)
+    , _listener (std::move (listener))
+{
+    _processor.addListener (this);
+}
+
+AudioProcessorListenerHandle::~AudioProcessorListenerHandle()
+{
+    _processor.removeListener (this);
+}
+
+void AudioProcessorListenerHandle::audioProcessorParameterChanged (juce::AudioProcessor*, int parameterIndex, float newValue)
+{
+    ::audio_processor_listener::parameterChanged (*_listener, static_cast<size_t> (parameterIndex), newValue);
+}
+
+void AudioProcessorListenerHandle::audioProcessorChanged (juce::AudioProcessor*, const ChangeDetails&)
+{
+    ::audio_processor_listener::processorChanged (*_listener);
+}
+
+void AudioProcessorListenerHandle::audioProcessorParameterChangeGestureBegin (juce::AudioProcessor*, int parameterIndex)
+{
+    ::audio_processor_listener::parameterChangeGestureBegin (*_listener, static_cast<size_t> (parameterIndex));
+}
+
+void AudioProcessorListenerHandle::audioProcessorParameterChangeGestureEnd (juce::AudioProcessor*, int parameterIndex)
+{
+    ::audio_processor_listener::parameterChangeGestureEnd (*_listener, static_cast<size_t> (parameterIndex));
+}
+} // namespace cxx_juce
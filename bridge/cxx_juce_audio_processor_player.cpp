@@ -0,0 +1,43 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+AudioProcessorPlayerHandle::AudioProcessorPlayerHandle (juce::AudioDeviceManager& audioDeviceManager,
+                                                        juce::AudioProcessorPlayer& player)
+    : _audioDeviceManager (audioDeviceManager)
+    , _player (player)
+{
+    _audioDeviceManager.addAudioCallback (&_player);
+    _audioDeviceManager.addMidiInputDeviceCallback ({}, &_player);
+}
+
+AudioProcessorPlayerHandle::~AudioProcessorPlayerHandle()
+{
+    _audioDeviceManager.removeMidiInputDeviceCallback ({}, &_player);
+    _audioDeviceManager.removeAudioCallback (&_player);
+}
+
+namespace audio_processor_player
+{
+    std::unique_ptr<juce::AudioProcessorPlayer> createAudioProcessorPlayer()
+    {
+        return std::make_unique<juce::AudioProcessorPlayer>();
+    }
+
+    void setGraph (juce::AudioProcessorPlayer& player, juce::AudioProcessorGraph& graph)
+    {
+        player.setProcessor (&graph);
+    }
+
+    void setPluginInstance (juce::AudioProcessorPlayer& player, juce::AudioPluginInstance& instance)
+    {
+        player.setProcessor (&instance);
+    }
+
+    std::unique_ptr<juce::ScopedLock> lockProcessor (const juce::AudioProcessorPlayer& player)
+    {
+        // The player holds its processor's callback lock while it processes a block.
+        return std::make_unique<juce::ScopedLock> (player.getCurrentProcessor()->getCallbackLock());
+    }
+} // namespace audio_processor_player
+} // namespace cxx_juce
@@ -0,0 +1,16 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::audio_sample_buffer
+{
+std::unique_ptr<juce::AudioSampleBuffer> createAudioSampleBuffer (int numChannels, int numSamples)
+{
+    auto buffer = std::make_unique<juce::AudioSampleBuffer> (numChannels, numSamples);
+    buffer->clear();
+    return buffer;
+}
+
+std::unique_ptr<juce::AudioSampleBuffer> copyAudioSampleBuffer (const juce::AudioSampleBuffer& buffer)
+{
+    return std::make_unique<juce::AudioSampleBuffer> (buffer);
+}
+} // namespace cxx_juce::audio_sample_buffer
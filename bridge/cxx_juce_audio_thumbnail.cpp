@@ -0,0 +1,126 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::audio_thumbnail_cache
+{
+std::shared_ptr<juce::AudioThumbnailCache> createAudioThumbnailCache (int maxNumThumbsToStore)
+{
+    return std::make_shared<juce::AudioThumbnailCache> (maxNumThumbsToStore);
+}
+
+void clear (const std::shared_ptr<juce::AudioThumbnailCache>& cache)
+{
+    cache->clear();
+}
+} // namespace cxx_juce::audio_thumbnail_cache
+
+namespace cxx_juce
+{
+AudioThumbnail::AudioThumbnail (int sourceSamplesPerThumbnailSample, std::shared_ptr<juce::AudioThumbnailCache> cache)
+    : _cache (std::move (cache))
+    , _thumbnail (sourceSamplesPerThumbnailSample, _formatManager, *_cache)
+{
+    _formatManager.registerBasicFormats();
+}
+
+bool AudioThumbnail::setSourceFile (rust::Str path)
+{
+    const auto file = toFile (path);
+
+    if (! file.existsAsFile())
+    {
+        return false;
+    }
+
+    return _thumbnail.setSource (new juce::FileInputSource (file));
+}
+
+void AudioThumbnail::setReader (std::unique_ptr<juce::AudioFormatReader> reader, juce::int64 hash)
+{
+    _thumbnail.setReader (reader.release(), hash);
+}
+
+void AudioThumbnail::clear()
+{
+    _thumbnail.clear();
+}
+
+void AudioThumbnail::reset (int numChannels, double sampleRate, juce::int64 totalSamples)
+{
+    _thumbnail.reset (numChannels, sampleRate, totalSamples);
+}
+
+void AudioThumbnail::addBlock (juce::int64 sampleNumberInSource, const juce::AudioSampleBuffer& buffer)
+{
+    if (buffer.getNumChannels() < _thumbnail.getNumChannels())
+    {
+        throw std::invalid_argument ("buffer has fewer channels than the thumbnail");
+    }
+
+    _thumbnail.addBlock (sampleNumberInSource, buffer, 0, buffer.getNumSamples());
+}
+
+int AudioThumbnail::getNumChannels() const
+{
+    return _thumbnail.getNumChannels();
+}
+
+double AudioThumbnail::getTotalLength() const
+{
+    return _thumbnail.getTotalLength();
+}
+
+bool AudioThumbnail::isFullyLoaded() const
+{
+    return _thumbnail.isFullyLoaded();
+}
+
+double AudioThumbnail::getProportionComplete() const
+{
+    return _thumbnail.getProportionComplete();
+}
+
+juce::int64 AudioThumbnail::getNumSamplesFinished() const
+{
+    return _thumbnail.getNumSamplesFinished();
+}
+
+float AudioThumbnail::getApproximatePeak() const
+{
+    return _thumbnail.getApproximatePeak();
+}
+
+juce::int64 AudioThumbnail::getHashCode() const
+{
+    return _thumbnail.getHashCode();
+}
+
+void AudioThumbnail::getPeaks (int channelIndex,
+                               double startTime,
+                               double endTime,
+                               rust::Slice<float> minValues,
+                               rust::Slice<float> maxValues) const
+{
+    if (! juce::isPositiveAndBelow (channelIndex, _thumbnail.getNumChannels()))
+    {
+        throw std::out_of_range ("channel index out of range");
+    }
+
+    const auto numPoints = juce::jmin (minValues.size(), maxValues.size());
+    const auto timePerPoint = (endTime - startTime) / static_cast<double> (numPoints);
+
+    for (size_t i = 0; i < numPoints; ++i)
+    {
+        const auto pointStart = startTime + timePerPoint * static_cast<double> (i);
+        _thumbnail.getApproximateMinMax (pointStart, pointStart + timePerPoint, channelIndex, minValues[i], maxValues[i]);
+    }
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::audio_thumbnail
+{
+std::unique_ptr<AudioThumbnail> createAudioThumbnail (int sourceSamplesPerThumbnailSample,
+                                                      std::shared_ptr<juce::AudioThumbnailCache> cache)
+{
+    return std::make_unique<AudioThumbnail> (sourceSamplesPerThumbnailSample, std::move (cache));
+}
+} // namespace cxx_juce::audio_thumbnail
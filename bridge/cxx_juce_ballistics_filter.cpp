@@ -0,0 +1,24 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::ballistics_filter
+{
+std::unique_ptr<BallisticsFilter> createBallisticsFilter()
+{
+    return std::make_unique<BallisticsFilter>();
+}
+
+void setLevelCalculationType (BallisticsFilter& filter, int type)
+{
+    filter.setLevelCalculationType (static_cast<juce::dsp::BallisticsFilterLevelCalculationType> (type));
+}
+
+void prepare (BallisticsFilter& filter, const ProcessSpec& spec)
+{
+    filter.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (BallisticsFilter& filter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (filter, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::ballistics_filter
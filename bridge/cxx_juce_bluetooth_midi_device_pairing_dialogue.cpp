@@ -0,0 +1,34 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::bluetooth_midi_device_pairing_dialogue
+{
+bool isAvailable()
+{
+    return juce::BluetoothMidiDevicePairingDialogue::isAvailable();
+}
+
+bool open (rust::Box<BoxedPairingDialogueCallback> callback)
+{
+    if (! juce::MessageManager::existsAndIsCurrentThread())
+    {
+        throw std::runtime_error ("the pairing dialogue can only be opened on the message thread");
+    }
+
+    struct ExitCallback : juce::ModalComponentManager::Callback
+    {
+        explicit ExitCallback (rust::Box<BoxedPairingDialogueCallback> callback)
+            : _callback (std::move (callback))
+        {
+        }
+
+        void modalStateFinished (int) override
+        {
+            ::bluetooth_midi_device_pairing_dialogue::pairingDialogueClosed (std::move (_callback));
+        }
+
+        rust::Box<BoxedPairingDialogueCallback> _callback;
+    };
+
+    return juce::BluetoothMidiDevicePairingDialogue::open (new ExitCallback (std::move (callback)));
+}
+} // namespace cxx_juce::bluetooth_midi_device_pairing_dialogue
@@ -0,0 +1,63 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+BufferingThread::BufferingThread()
+    : juce::TimeSliceThread ("cxx-juce buffering audio reader")
+{
+    startThread();
+}
+
+BufferingThread::~BufferingThread()
+{
+    stopThread (1000);
+}
+
+BufferingThreadHolder::BufferingThreadHolder (std::shared_ptr<juce::TimeSliceThread> thread)
+    : bufferingThread (std::move (thread))
+{
+    if (bufferingThread == nullptr)
+    {
+        auto defaultThread = std::make_shared<juce::SharedResourcePointer<BufferingThread>>();
+        bufferingThread = std::shared_ptr<juce::TimeSliceThread> (defaultThread, &defaultThread->getObject());
+    }
+}
+
+BufferingAudioReader::BufferingAudioReader (std::unique_ptr<juce::AudioFormatReader> source,
+                                            std::shared_ptr<juce::TimeSliceThread> bufferingThread,
+                                            int samplesToBuffer)
+    : BufferingThreadHolder (std::move (bufferingThread))
+    , juce::BufferingAudioReader (source.release(), *BufferingThreadHolder::bufferingThread, samplesToBuffer)
+{
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::buffering_audio_reader
+{
+std::unique_ptr<juce::AudioFormatReader> createBufferingAudioReader (std::unique_ptr<juce::AudioFormatReader> source,
+                                                                     std::shared_ptr<juce::TimeSliceThread> bufferingThread,
+                                                                     int samplesToBuffer)
+{
+    return std::make_unique<BufferingAudioReader> (std::move (source), std::move (bufferingThread), samplesToBuffer);
+}
+
+void setReadTimeout (juce::AudioFormatReader& reader, int timeoutMilliseconds)
+{
+    static_cast<juce::BufferingAudioReader&> (reader).setReadTimeout (timeoutMilliseconds);
+}
+
+bool readSamples (juce::AudioFormatReader& reader,
+                  juce::AudioSampleBuffer& buffer,
+                  int destStartSample,
+                  int numSamples,
+                  juce::int64 readerStartSample)
+{
+    // The buffering reader always produces floating point samples, so the destination
+    // pointers are reinterpreted as the int pointers that readSamples expects.
+    return reader.readSamples (reinterpret_cast<int* const*> (buffer.getArrayOfWritePointers()),
+                               buffer.getNumChannels(),
+                               destStartSample,
+                               readerStartSample,
+                               numSamples);
+}
+} // namespace cxx_juce::buffering_audio_reader
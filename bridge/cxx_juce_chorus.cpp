@@ -0,0 +1,19 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::chorus
+{
+std::unique_ptr<Chorus> createChorus()
+{
+    return std::make_unique<Chorus>();
+}
+
+void prepare (Chorus& chorus, const ProcessSpec& spec)
+{
+    chorus.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (Chorus& chorus, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (chorus, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::chorus
@@ -0,0 +1,19 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::compressor
+{
+std::unique_ptr<Compressor> createCompressor()
+{
+    return std::make_unique<Compressor>();
+}
+
+void prepare (Compressor& compressor, const ProcessSpec& spec)
+{
+    compressor.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (Compressor& compressor, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (compressor, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::compressor
@@ -0,0 +1,100 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::convolution
+{
+namespace
+{
+    bool loadImpulseResponseFromReader (juce::dsp::Convolution& convolution,
+                                        std::unique_ptr<juce::AudioFormatReader> reader,
+                                        bool stereo,
+                                        bool trim,
+                                        size_t size,
+                                        bool normalise)
+    {
+        if (reader == nullptr)
+        {
+            return false;
+        }
+
+        auto numSamples = reader->lengthInSamples;
+
+        if (size > 0)
+        {
+            numSamples = juce::jmin (numSamples, static_cast<juce::int64> (size));
+        }
+
+        const auto numChannels = juce::jmin (static_cast<int> (reader->numChannels), stereo ? 2 : 1);
+        juce::AudioSampleBuffer buffer (numChannels, static_cast<int> (numSamples));
+
+        if (! reader->read (&buffer, 0, buffer.getNumSamples(), 0, true, numChannels > 1))
+        {
+            return false;
+        }
+
+        loadImpulseResponseFromBuffer (convolution, buffer, reader->sampleRate, stereo, trim, normalise);
+        return true;
+    }
+} // namespace
+
+std::unique_ptr<juce::dsp::Convolution> createConvolution (int latency, int headSize)
+{
+    if (headSize > 0)
+    {
+        return std::make_unique<juce::dsp::Convolution> (juce::dsp::Convolution::NonUniform { headSize });
+    }
+
+    return std::make_unique<juce::dsp::Convolution> (juce::dsp::Convolution::Latency { latency });
+}
+
+bool loadImpulseResponseFromFile (juce::dsp::Convolution& convolution, rust::Str path, bool stereo, bool trim, size_t size, bool normalise)
+{
+    juce::AudioFormatManager manager;
+    manager.registerBasicFormats();
+
+    return loadImpulseResponseFromReader (convolution,
+                                          std::unique_ptr<juce::AudioFormatReader> (manager.createReaderFor (toFile (path))),
+                                          stereo,
+                                          trim,
+                                          size,
+                                          normalise);
+}
+
+bool loadImpulseResponseFromData (juce::dsp::Convolution& convolution, rust::Slice<const juce::uint8> data, bool stereo, bool trim, size_t size, bool normalise)
+{
+    juce::AudioFormatManager manager;
+    manager.registerBasicFormats();
+
+    auto stream = std::make_unique<juce::MemoryInputStream> (data.data(), data.size(), false);
+
+    return loadImpulseResponseFromReader (convolution,
+                                          std::unique_ptr<juce::AudioFormatReader> (manager.createReaderFor (std::move (stream))),
+                                          stereo,
+                                          trim,
+                                          size,
+                                          normalise);
+}
+
+void loadImpulseResponseFromBuffer (juce::dsp::Convolution& convolution,
+                                    const juce::AudioSampleBuffer& buffer,
+                                    double sampleRate,
+                                    bool stereo,
+                                    bool trim,
+                                    bool normalise)
+{
+    convolution.loadImpulseResponse (juce::AudioSampleBuffer (buffer),
+                                     sampleRate,
+                                     stereo ? juce::dsp::Convolution::Stereo::yes : juce::dsp::Convolution::Stereo::no,
+                                     trim ? juce::dsp::Convolution::Trim::yes : juce::dsp::Convolution::Trim::no,
+                                     normalise ? juce::dsp::Convolution::Normalise::yes : juce::dsp::Convolution::Normalise::no);
+}
+
+void prepare (juce::dsp::Convolution& convolution, const ProcessSpec& spec)
+{
+    convolution.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (juce::dsp::Convolution& convolution, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (convolution, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::convolution
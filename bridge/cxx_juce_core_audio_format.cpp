@@ -0,0 +1,28 @@
+#include "cxx_juce_bindings.h"
+
+#if JUCE_MAC || JUCE_IOS
+
+namespace cxx_juce::core_audio_format
+{
+std::unique_ptr<juce::CoreAudioFormat> createCoreAudioFormat()
+{
+    return std::make_unique<juce::CoreAudioFormat>();
+}
+
+rust::Vec<rust::String> getFileExtensions (const juce::CoreAudioFormat& format)
+{
+    return toRustStrings (format.getFileExtensions());
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::CoreAudioFormat& format, rust::Str path)
+{
+    return cxx_juce::createReaderForFile (format, path);
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::CoreAudioFormat& format, rust::Box<BoxedInputStream> stream)
+{
+    return cxx_juce::createReaderForStream (format, std::move (stream));
+}
+} // namespace cxx_juce::core_audio_format
+
+#endif
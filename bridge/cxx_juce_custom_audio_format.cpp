@@ -0,0 +1,296 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+namespace
+{
+    // JUCE hands a format a raw stream that it must only take ownership of if it succeeds in
+    // creating a reader or writer (or, for readers, if asked to delete it on failure). Rust is
+    // handed a forwarding stream instead, and the ownership of the real stream is settled once
+    // Rust returns. A forwarding stream that's detached from the real stream fails to read, write
+    // or seek.
+    template <typename Stream>
+    struct StreamOwnership
+    {
+        explicit StreamOwnership (Stream* streamToOwn)
+            : stream (streamToOwn)
+        {
+        }
+
+        void settle (bool takeOwnership)
+        {
+            if (released)
+            {
+                if (takeOwnership)
+                {
+                    delete stream;
+                }
+            }
+            else if (takeOwnership)
+            {
+                ownsStream = true;
+            }
+            else
+            {
+                // The caller keeps the stream and may delete it, so the forwarding stream that
+                // Rust may still be holding must stop using it.
+                stream = nullptr;
+            }
+        }
+
+        void release()
+        {
+            if (ownsStream)
+            {
+                delete stream;
+            }
+
+            released = true;
+        }
+
+        Stream* stream = nullptr;
+        bool ownsStream = false;
+        bool released = false;
+    };
+
+    class ForwardingInputStream : public juce::InputStream
+    {
+    public:
+        explicit ForwardingInputStream (std::shared_ptr<StreamOwnership<juce::InputStream>> ownership)
+            : _ownership (std::move (ownership))
+        {
+        }
+
+        ~ForwardingInputStream() override
+        {
+            _ownership->release();
+        }
+
+        juce::int64 getTotalLength() override
+        {
+            auto* stream = _ownership->stream;
+            return stream != nullptr ? stream->getTotalLength() : -1;
+        }
+
+        bool isExhausted() override
+        {
+            auto* stream = _ownership->stream;
+            return stream == nullptr || stream->isExhausted();
+        }
+
+        int read (void* destBuffer, int maxBytesToRead) override
+        {
+            auto* stream = _ownership->stream;
+            return stream != nullptr ? stream->read (destBuffer, maxBytesToRead) : -1;
+        }
+
+        juce::int64 getPosition() override
+        {
+            auto* stream = _ownership->stream;
+            return stream != nullptr ? stream->getPosition() : -1;
+        }
+
+        bool setPosition (juce::int64 newPosition) override
+        {
+            auto* stream = _ownership->stream;
+            return stream != nullptr && stream->setPosition (newPosition);
+        }
+
+    private:
+        std::shared_ptr<StreamOwnership<juce::InputStream>> _ownership;
+    };
+
+    class ForwardingOutputStream : public juce::OutputStream
+    {
+    public:
+        explicit ForwardingOutputStream (std::shared_ptr<StreamOwnership<juce::OutputStream>> ownership)
+            : _ownership (std::move (ownership))
+        {
+        }
+
+        ~ForwardingOutputStream() override
+        {
+            _ownership->release();
+        }
+
+        void flush() override
+        {
+            if (auto* stream = _ownership->stream)
+            {
+                stream->flush();
+            }
+        }
+
+        bool setPosition (juce::int64 newPosition) override
+        {
+            auto* stream = _ownership->stream;
+            return stream != nullptr && stream->setPosition (newPosition);
+        }
+
+        juce::int64 getPosition() override
+        {
+            auto* stream = _ownership->stream;
+            return stream != nullptr ? stream->getPosition() : -1;
+        }
+
+        bool write (const void* dataToWrite, size_t numberOfBytes) override
+        {
+            auto* stream = _ownership->stream;
+            return stream != nullptr && stream->write (dataToWrite, numberOfBytes);
+        }
+
+    private:
+        std::shared_ptr<StreamOwnership<juce::OutputStream>> _ownership;
+    };
+
+    juce::StringArray toStringArray (const rust::Vec<rust::String>& strings)
+    {
+        juce::StringArray result;
+
+        for (const auto& string : strings)
+        {
+            result.add (static_cast<std::string> (string));
+        }
+
+        return result;
+    }
+
+    juce::Array<int> toIntArray (const rust::Vec<int>& values)
+    {
+        return juce::Array<int> (values.data(), static_cast<int> (values.size()));
+    }
+} // namespace
+
+RustAudioFormat::RustAudioFormat (rust::Box<BoxedCustomAudioFormat> format)
+    : juce::AudioFormat (std::string (::custom_audio_format::getFormatName (*format)),
+                         toStringArray (::custom_audio_format::getFileExtensions (*format)))
+    , _format (std::move (format))
+{
+}
+
+juce::Array<int> RustAudioFormat::getPossibleSampleRates()
+{
+    return toIntArray (::custom_audio_format::getPossibleSampleRates (*_format));
+}
+
+juce::Array<int> RustAudioFormat::getPossibleBitDepths()
+{
+    return toIntArray (::custom_audio_format::getPossibleBitDepths (*_format));
+}
+
+bool RustAudioFormat::canDoStereo()
+{
+    return ::custom_audio_format::canDoStereo (*_format);
+}
+
+bool RustAudioFormat::canDoMono()
+{
+    return ::custom_audio_format::canDoMono (*_format);
+}
+
+bool RustAudioFormat::canHandleFile (const juce::File& fileToTest)
+{
+    return ::custom_audio_format::canHandleFile (*_format, fileToTest.getFullPathName().toStdString());
+}
+
+juce::AudioFormatReader* RustAudioFormat::createReaderFor (juce::InputStream* sourceStream,
+                                                           bool deleteStreamIfOpeningFails)
+{
+    if (sourceStream == nullptr)
+    {
+        return nullptr;
+    }
+
+    auto ownership = std::make_shared<StreamOwnership<juce::InputStream>> (sourceStream);
+    auto reader = ::custom_audio_format::createReader (*_format, std::make_unique<ForwardingInputStream> (ownership));
+
+    ownership->settle (reader != nullptr || deleteStreamIfOpeningFails);
+
+    return reader.release();
+}
+
+juce::AudioFormatWriter* RustAudioFormat::createWriterFor (juce::OutputStream* streamToWriteTo,
+                                                           double sampleRateToUse,
+                                                           unsigned int numberOfChannels,
+                                                           int bitsPerSample,
+                                                           const juce::StringPairArray& metadataValues,
+                                                           int qualityOptionIndex)
+{
+    if (streamToWriteTo == nullptr)
+    {
+        return nullptr;
+    }
+
+    auto ownership = std::make_shared<StreamOwnership<juce::OutputStream>> (streamToWriteTo);
+    auto writer = ::custom_audio_format::createWriter (*_format,
+                                                       std::make_unique<ForwardingOutputStream> (ownership),
+                                                       sampleRateToUse,
+                                                       numberOfChannels,
+                                                       bitsPerSample,
+                                                       toStringPairs (metadataValues),
+                                                       qualityOptionIndex);
+
+    ownership->settle (writer != nullptr);
+
+    return writer.release();
+}
+
+RustAudioFormatWriter::RustAudioFormatWriter (rust::Box<BoxedCustomAudioFormatWriter> writer,
+                                              rust::Str formatName,
+                                              double sampleRate,
+                                              juce::uint32 numChannels,
+                                              juce::uint32 bitsPerSample)
+    : juce::AudioFormatWriter (nullptr, std::string (formatName), sampleRate, numChannels, bitsPerSample)
+    , _writer (std::move (writer))
+{
+    usesFloatingPointData = true;
+}
+
+bool RustAudioFormatWriter::write (const int** samplesToWrite, int numSamples)
+{
+    _buffer.setSize (static_cast<int> (numChannels), numSamples, false, false, true);
+
+    // The channel array is null-terminated, so it may have fewer channels than the writer.
+    auto sourceFinished = false;
+
+    for (auto channel = 0; channel < _buffer.getNumChannels(); ++channel)
+    {
+        sourceFinished = sourceFinished || samplesToWrite[channel] == nullptr;
+
+        if (sourceFinished)
+        {
+            _buffer.clear (channel, 0, numSamples);
+        }
+        else
+        {
+            _buffer.copyFrom (channel, 0, reinterpret_cast<const float*> (samplesToWrite[channel]), numSamples);
+        }
+    }
+
+    return ::custom_audio_format_writer::write (*_writer, _buffer);
+}
+
+bool RustAudioFormatWriter::flush()
+{
+    return ::custom_audio_format_writer::flush (*_writer);
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::custom_audio_format
+{
+void registerFormat (juce::AudioFormatManager& manager,
+                     rust::Box<BoxedCustomAudioFormat> format,
+                     bool makeThisTheDefaultFormat)
+{
+    manager.registerFormat (new RustAudioFormat (std::move (format)), makeThisTheDefaultFormat);
+}
+
+std::unique_ptr<juce::AudioFormatWriter> createCustomAudioFormatWriter (rust::Box<BoxedCustomAudioFormatWriter> writer,
+                                                                        rust::Str formatName,
+                                                                        double sampleRate,
+                                                                        juce::uint32 numChannels,
+                                                                        juce::uint32 bitsPerSample)
+{
+    return std::make_unique<RustAudioFormatWriter> (std::move (writer), formatName, sampleRate, numChannels, bitsPerSample);
+}
+} // namespace cxx_juce::custom_audio_format
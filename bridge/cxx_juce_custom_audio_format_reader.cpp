@@ -0,0 +1,52 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+RustAudioFormatReader::RustAudioFormatReader (rust::Box<BoxedCustomAudioFormatReader> reader)
+    : juce::AudioFormatReader (nullptr, std::string (::custom_audio_format_reader::getFormatName (*reader)))
+    , _reader (std::move (reader))
+{
+    sampleRate = ::custom_audio_format_reader::getSampleRate (*_reader);
+    numChannels = ::custom_audio_format_reader::getNumChannels (*_reader);
+    lengthInSamples = ::custom_audio_format_reader::getLengthInSamples (*_reader);
+    bitsPerSample = ::custom_audio_format_reader::getBitsPerSample (*_reader);
+    usesFloatingPointData = true;
+}
+
+bool RustAudioFormatReader::readSamples (int* const* destChannels,
+                                         int numDestChannels,
+                                         int startOffsetInDestBuffer,
+                                         juce::int64 startSampleInFile,
+                                         int numSamples)
+{
+    _buffer.setSize (static_cast<int> (numChannels), numSamples, false, false, true);
+    _buffer.clear();
+
+    const auto result = ::custom_audio_format_reader::readSamples (*_reader, _buffer, startSampleInFile);
+
+    for (auto channel = 0; channel < numDestChannels; ++channel)
+    {
+        if (auto* dest = reinterpret_cast<float*> (destChannels[channel]))
+        {
+            if (channel < _buffer.getNumChannels())
+            {
+                juce::FloatVectorOperations::copy (dest + startOffsetInDestBuffer, _buffer.getReadPointer (channel), numSamples);
+            }
+            else
+            {
+                juce::FloatVectorOperations::clear (dest + startOffsetInDestBuffer, numSamples);
+            }
+        }
+    }
+
+    return result;
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::custom_audio_format_reader
+{
+std::unique_ptr<juce::AudioFormatReader> createCustomAudioFormatReader (rust::Box<BoxedCustomAudioFormatReader> reader)
+{
+    return std::make_unique<RustAudioFormatReader> (std::move (reader));
+}
+} // namespace cxx_juce::custom_audio_format_reader
@@ -0,0 +1,19 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::decibels
+{
+float decibelsToGain (float decibels, float minusInfinityDb)
+{
+    return juce::Decibels::decibelsToGain (decibels, minusInfinityDb);
+}
+
+float gainToDecibels (float gain, float minusInfinityDb)
+{
+    return juce::Decibels::gainToDecibels (gain, minusInfinityDb);
+}
+
+rust::String toString (float decibels, int decimalPlaces, float minusInfinityDb, bool shouldIncludeSuffix)
+{
+    return juce::Decibels::toString (decibels, decimalPlaces, minusInfinityDb, shouldIncludeSuffix).toStdString();
+}
+} // namespace cxx_juce::decibels
@@ -0,0 +1,120 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::delay_line
+{
+namespace
+{
+    template <typename Interpolation>
+    class DelayLineImpl : public dsp::DelayLine
+    {
+    public:
+        explicit DelayLineImpl (size_t maximumDelayInSamples)
+            : _delayLine (static_cast<int> (maximumDelayInSamples))
+        {
+        }
+
+        void setDelay (float delayInSamples) override
+        {
+            _delayLine.setDelay (clampDelay (delayInSamples));
+        }
+
+        float getDelay() const override
+        {
+            return _delayLine.getDelay();
+        }
+
+        void setMaximumDelayInSamples (size_t maximumDelayInSamples) override
+        {
+            _delayLine.setMaximumDelayInSamples (static_cast<int> (maximumDelayInSamples));
+        }
+
+        size_t getMaximumDelayInSamples() const override
+        {
+            return static_cast<size_t> (_delayLine.getMaximumDelayInSamples());
+        }
+
+        void prepare (const ProcessSpec& spec) override
+        {
+            _delayLine.prepare (dsp::toProcessSpec (spec));
+            _numChannels = spec.num_channels;
+        }
+
+        void reset() override
+        {
+            _delayLine.reset();
+        }
+
+        void process (juce::AudioSampleBuffer& buffer) override
+        {
+            const auto numChannels = juce::jmin (static_cast<size_t> (buffer.getNumChannels()), _numChannels);
+
+            for (size_t channel = 0; channel < numChannels; ++channel)
+            {
+                auto* samples = buffer.getWritePointer (static_cast<int> (channel));
+
+                for (auto sample = 0; sample < buffer.getNumSamples(); ++sample)
+                {
+                    _delayLine.pushSample (static_cast<int> (channel), samples[sample]);
+                    samples[sample] = _delayLine.popSample (static_cast<int> (channel));
+                }
+            }
+        }
+
+        void pushSample (size_t channel, float sample) override
+        {
+            if (channel < _numChannels)
+            {
+                _delayLine.pushSample (static_cast<int> (channel), sample);
+            }
+        }
+
+        float popSample (size_t channel, float delayInSamples, bool updateReadPointer) override
+        {
+            if (channel >= _numChannels)
+            {
+                return 0.0f;
+            }
+
+            return _delayLine.popSample (static_cast<int> (channel),
+                                         delayInSamples < 0.0f ? -1.0f : clampDelay (delayInSamples),
+                                         updateReadPointer);
+        }
+
+    private:
+        float clampDelay (float delayInSamples) const
+        {
+            return juce::jlimit (0.0f, static_cast<float> (_delayLine.getMaximumDelayInSamples()), delayInSamples);
+        }
+
+        juce::dsp::DelayLine<float, Interpolation> _delayLine;
+        size_t _numChannels = 0;
+    };
+
+    enum class Interpolation
+    {
+        none,
+        linear,
+        lagrange3rd,
+        thiran,
+    };
+} // namespace
+
+std::unique_ptr<dsp::DelayLine> createDelayLine (int interpolation, size_t maximumDelayInSamples)
+{
+    namespace Interpolations = juce::dsp::DelayLineInterpolationTypes;
+
+    switch (static_cast<Interpolation> (interpolation))
+    {
+        case Interpolation::none:
+            return std::make_unique<DelayLineImpl<Interpolations::None>> (maximumDelayInSamples);
+        case Interpolation::linear:
+            return std::make_unique<DelayLineImpl<Interpolations::Linear>> (maximumDelayInSamples);
+        case Interpolation::lagrange3rd:
+            return std::make_unique<DelayLineImpl<Interpolations::Lagrange3rd>> (maximumDelayInSamples);
+        case Interpolation::thiran:
+            return std::make_unique<DelayLineImpl<Interpolations::Thiran>> (maximumDelayInSamples);
+    }
+
+    throw std::invalid_argument ("invalid interpolation");
+}
+} // namespace cxx_juce::delay_line
@@ -0,0 +1,40 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::dry_wet_mixer
+{
+namespace
+{
+    // The mixer's buffers are sized for the prepared spec, so blocks are limited to it.
+    template <typename SampleType>
+    juce::dsp::AudioBlock<SampleType> limitToSpec (juce::dsp::AudioBlock<SampleType> block, const ProcessSpec& spec)
+    {
+        return block.getSubsetChannelBlock (0, juce::jmin (block.getNumChannels(), spec.num_channels))
+            .getSubBlock (0, juce::jmin (block.getNumSamples(), spec.max_block_size));
+    }
+} // namespace
+
+std::unique_ptr<DryWetMixer> createDryWetMixer (size_t maximumWetLatencyInSamples)
+{
+    return std::make_unique<DryWetMixer> (static_cast<int> (maximumWetLatencyInSamples));
+}
+
+void setMixingRule (DryWetMixer& mixer, int rule)
+{
+    mixer.setMixingRule (static_cast<juce::dsp::DryWetMixingRule> (rule));
+}
+
+void prepare (DryWetMixer& mixer, const ProcessSpec& spec)
+{
+    mixer.prepare (dsp::toProcessSpec (spec));
+}
+
+void pushDrySamples (DryWetMixer& mixer, const juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    mixer.pushDrySamples (limitToSpec (juce::dsp::AudioBlock<const float> (buffer), spec));
+}
+
+void mixWetSamples (DryWetMixer& mixer, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    mixer.mixWetSamples (limitToSpec (juce::dsp::AudioBlock<float> (buffer), spec));
+}
+} // namespace cxx_juce::dry_wet_mixer
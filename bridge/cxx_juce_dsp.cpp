@@ -0,0 +1,11 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::dsp
+{
+juce::dsp::ProcessSpec toProcessSpec (const ProcessSpec& spec)
+{
+    return { spec.sample_rate,
+             static_cast<juce::uint32> (spec.max_block_size),
+             static_cast<juce::uint32> (spec.num_channels) };
+}
+} // namespace cxx_juce::dsp
@@ -0,0 +1,54 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::fft
+{
+namespace
+{
+    void checkSize (size_t size, size_t required)
+    {
+        if (size < required)
+        {
+            throw std::invalid_argument ("expected at least " + std::to_string (required) + " values but got " + std::to_string (size));
+        }
+    }
+} // namespace
+
+std::unique_ptr<juce::dsp::FFT> createFFT (int order)
+{
+    if (order < 0 || order > 30)
+    {
+        throw std::invalid_argument ("FFT order must be between 0 and 30");
+    }
+
+    return std::make_unique<juce::dsp::FFT> (order);
+}
+
+void perform (const juce::dsp::FFT& fft, rust::Slice<const Complex> input, rust::Slice<Complex> output, bool inverse)
+{
+    const auto size = static_cast<size_t> (fft.getSize());
+    checkSize (input.size(), size);
+    checkSize (output.size(), size);
+
+    fft.perform (reinterpret_cast<const juce::dsp::Complex<float>*> (input.data()),
+                 reinterpret_cast<juce::dsp::Complex<float>*> (output.data()),
+                 inverse);
+}
+
+void performRealOnlyForwardTransform (const juce::dsp::FFT& fft, rust::Slice<float> data, bool onlyCalculateNonNegativeFrequencies)
+{
+    checkSize (data.size(), 2 * static_cast<size_t> (fft.getSize()));
+    fft.performRealOnlyForwardTransform (data.data(), onlyCalculateNonNegativeFrequencies);
+}
+
+void performRealOnlyInverseTransform (const juce::dsp::FFT& fft, rust::Slice<float> data)
+{
+    checkSize (data.size(), 2 * static_cast<size_t> (fft.getSize()));
+    fft.performRealOnlyInverseTransform (data.data());
+}
+
+void performFrequencyOnlyForwardTransform (const juce::dsp::FFT& fft, rust::Slice<float> data, bool ignoreNegativeFrequencies)
+{
+    checkSize (data.size(), 2 * static_cast<size_t> (fft.getSize()));
+    fft.performFrequencyOnlyForwardTransform (data.data(), ignoreNegativeFrequencies);
+}
+} // namespace cxx_juce::fft
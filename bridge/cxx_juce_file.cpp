@@ -0,0 +1,166 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::file
+{
+namespace
+{
+    rust::String toRustString (const juce::File& file)
+    {
+        return file.getFullPathName().toStdString();
+    }
+
+    // In the order of the Rust SpecialLocation enum, which leaves out JUCE's platform specific
+    // locations.
+    constexpr juce::File::SpecialLocationType specialLocations[] {
+        juce::File::userHomeDirectory,
+        juce::File::userDocumentsDirectory,
+        juce::File::userDesktopDirectory,
+        juce::File::userMusicDirectory,
+        juce::File::userMoviesDirectory,
+        juce::File::userPicturesDirectory,
+        juce::File::userApplicationDataDirectory,
+        juce::File::commonApplicationDataDirectory,
+        juce::File::commonDocumentsDirectory,
+        juce::File::tempDirectory,
+        juce::File::currentExecutableFile,
+        juce::File::currentApplicationFile,
+        juce::File::invokedExecutableFile,
+        juce::File::hostApplicationPath,
+        juce::File::globalApplicationsDirectory,
+    };
+} // namespace
+
+rust::String getSpecialLocation (int location)
+{
+    jassert (location >= 0 && location < static_cast<int> (std::size (specialLocations)));
+    return toRustString (juce::File::getSpecialLocation (specialLocations[location]));
+}
+
+rust::String getCurrentWorkingDirectory()
+{
+    return toRustString (juce::File::getCurrentWorkingDirectory());
+}
+
+rust::String createTempFile (rust::Str suffix)
+{
+    return toRustString (juce::File::createTempFile (static_cast<std::string> (suffix)));
+}
+
+rust::String getFullPathName (rust::Str path)
+{
+    return toRustString (toFile (path));
+}
+
+rust::String getFileName (rust::Str path)
+{
+    return toFile (path).getFileName().toStdString();
+}
+
+rust::String getFileNameWithoutExtension (rust::Str path)
+{
+    return toFile (path).getFileNameWithoutExtension().toStdString();
+}
+
+rust::String getFileExtension (rust::Str path)
+{
+    return toFile (path).getFileExtension().toStdString();
+}
+
+rust::String getParentDirectory (rust::Str path)
+{
+    return toRustString (toFile (path).getParentDirectory());
+}
+
+rust::String getChildFile (rust::Str path, rust::Str relativePath)
+{
+    return toRustString (toFile (path).getChildFile (static_cast<std::string> (relativePath)));
+}
+
+rust::String getSiblingFile (rust::Str path, rust::Str fileName)
+{
+    return toRustString (toFile (path).getSiblingFile (static_cast<std::string> (fileName)));
+}
+
+rust::String withFileExtension (rust::Str path, rust::Str extension)
+{
+    return toRustString (toFile (path).withFileExtension (static_cast<std::string> (extension)));
+}
+
+rust::String getNonexistentChildFile (rust::Str path, rust::Str prefix, rust::Str suffix)
+{
+    return toRustString (toFile (path).getNonexistentChildFile (static_cast<std::string> (prefix),
+                                                                static_cast<std::string> (suffix)));
+}
+
+rust::String getNonexistentSibling (rust::Str path)
+{
+    return toRustString (toFile (path).getNonexistentSibling());
+}
+
+bool exists (rust::Str path)
+{
+    return toFile (path).exists();
+}
+
+bool existsAsFile (rust::Str path)
+{
+    return toFile (path).existsAsFile();
+}
+
+bool isDirectory (rust::Str path)
+{
+    return toFile (path).isDirectory();
+}
+
+juce::int64 getSize (rust::Str path)
+{
+    return toFile (path).getSize();
+}
+
+void create (rust::Str path)
+{
+    const auto result = toFile (path).create();
+
+    if (result.failed())
+    {
+        throw std::runtime_error (result.getErrorMessage().toStdString());
+    }
+}
+
+void createDirectory (rust::Str path)
+{
+    const auto result = toFile (path).createDirectory();
+
+    if (result.failed())
+    {
+        throw std::runtime_error (result.getErrorMessage().toStdString());
+    }
+}
+
+bool deleteFile (rust::Str path)
+{
+    return toFile (path).deleteFile();
+}
+
+bool deleteRecursively (rust::Str path)
+{
+    return toFile (path).deleteRecursively();
+}
+
+bool moveToTrash (rust::Str path)
+{
+    return toFile (path).moveToTrash();
+}
+
+rust::Vec<rust::String> findChildFiles (rust::Str path, int whatToLookFor, bool searchRecursively, rust::Str wildcard)
+{
+    rust::Vec<rust::String> result;
+
+    for (const auto& child : toFile (path).findChildFiles (whatToLookFor, searchRecursively, static_cast<std::string> (wildcard)))
+    {
+        result.push_back (toRustString (child));
+    }
+
+    return result;
+}
+} // namespace cxx_juce::file
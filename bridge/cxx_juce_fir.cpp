@@ -0,0 +1,203 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::dsp
+{
+namespace
+{
+    void assignCoefficients (juce::dsp::FIR::Coefficients<float>& target, rust::Slice<const float> coefficients)
+    {
+        auto& values = target.coefficients;
+        values.resize (static_cast<int> (coefficients.size()));
+        std::copy (coefficients.begin(), coefficients.end(), values.begin());
+    }
+} // namespace
+
+FIRFilter::FIRFilter (rust::Slice<const float> coefficients)
+{
+    assignCoefficients (*_coefficients, coefficients);
+}
+
+void FIRFilter::setCoefficients (rust::Slice<const float> coefficients)
+{
+    assignCoefficients (*_coefficients, coefficients);
+}
+
+void FIRFilter::prepare (const ProcessSpec& spec)
+{
+    auto monoSpec = toProcessSpec (spec);
+    monoSpec.numChannels = 1;
+
+    _filters.clear();
+    _filters.reserve (spec.num_channels);
+
+    for (size_t channel = 0; channel < spec.num_channels; ++channel)
+    {
+        _filters.emplace_back (_coefficients);
+        _filters.back().prepare (monoSpec);
+    }
+}
+
+void FIRFilter::reset()
+{
+    for (auto& filter : _filters)
+    {
+        filter.reset();
+    }
+}
+
+void FIRFilter::process (juce::AudioSampleBuffer& buffer)
+{
+    juce::dsp::AudioBlock<float> block (buffer);
+    const auto numChannels = juce::jmin (block.getNumChannels(), _filters.size());
+
+    for (size_t channel = 0; channel < numChannels; ++channel)
+    {
+        auto channelBlock = block.getSingleChannelBlock (channel);
+        _filters[channel].process (juce::dsp::ProcessContextReplacing<float> (channelBlock));
+    }
+}
+
+float FIRFilter::processSample (size_t channel, float sample)
+{
+    if (channel >= _filters.size())
+    {
+        return sample;
+    }
+
+    return _filters[channel].processSample (sample);
+}
+} // namespace cxx_juce::dsp
+
+namespace cxx_juce::fir
+{
+namespace
+{
+    using FilterDesign = juce::dsp::FilterDesign<float>;
+
+    void checkFrequency (double sampleRate, float frequency)
+    {
+        if (sampleRate <= 0.0 || frequency <= 0.0f || frequency > sampleRate * 0.5)
+        {
+            throw std::invalid_argument ("frequency must be between 0 and half the sample rate");
+        }
+    }
+
+    void checkOrder (size_t order)
+    {
+        if (order == 0)
+        {
+            throw std::invalid_argument ("order must be positive");
+        }
+    }
+
+    void checkTransitionWidth (float normalisedTransitionWidth)
+    {
+        if (normalisedTransitionWidth <= 0.0f || normalisedTransitionWidth > 0.5f)
+        {
+            throw std::invalid_argument ("normalised transition width must be between 0 and 0.5");
+        }
+    }
+
+    void checkAttenuation (float amplitudeDb)
+    {
+        if (amplitudeDb < -300.0f || amplitudeDb > -10.0f)
+        {
+            throw std::invalid_argument ("attenuation must be between -300 and -10 dB");
+        }
+    }
+
+    rust::Vec<float> toVec (const juce::dsp::FIR::Coefficients<float>& coefficients)
+    {
+        rust::Vec<float> result;
+        result.reserve (static_cast<size_t> (coefficients.coefficients.size()));
+
+        for (const auto coefficient : coefficients.coefficients)
+        {
+            result.push_back (coefficient);
+        }
+
+        return result;
+    }
+
+    juce::dsp::FIR::Coefficients<float> toCoefficients (rust::Slice<const float> coefficients)
+    {
+        return juce::dsp::FIR::Coefficients<float> (coefficients.data(), coefficients.size());
+    }
+} // namespace
+
+rust::Vec<float> designLowpassWindowMethod (double sampleRate, float frequency, size_t order, int windowingMethod, float beta)
+{
+    checkFrequency (sampleRate, frequency);
+    checkOrder (order);
+
+    return toVec (*FilterDesign::designFIRLowpassWindowMethod (frequency,
+                                                               sampleRate,
+                                                               order,
+                                                               static_cast<FilterDesign::WindowingMethod> (windowingMethod),
+                                                               beta));
+}
+
+rust::Vec<float> designLowpassKaiserMethod (double sampleRate, float frequency, float normalisedTransitionWidth, float amplitudeDb)
+{
+    checkFrequency (sampleRate, frequency);
+    checkTransitionWidth (normalisedTransitionWidth);
+    checkAttenuation (amplitudeDb);
+
+    return toVec (*FilterDesign::designFIRLowpassKaiserMethod (frequency, sampleRate, normalisedTransitionWidth, amplitudeDb));
+}
+
+rust::Vec<float> designLowpassTransitionMethod (double sampleRate, float frequency, size_t order, float normalisedTransitionWidth, float spline)
+{
+    checkFrequency (sampleRate, frequency);
+    checkOrder (order);
+    checkTransitionWidth (normalisedTransitionWidth);
+
+    if (spline < 1.0f || spline > 4.0f)
+    {
+        throw std::invalid_argument ("spline must be between 1 and 4");
+    }
+
+    return toVec (*FilterDesign::designFIRLowpassTransitionMethod (frequency, sampleRate, order, normalisedTransitionWidth, spline));
+}
+
+rust::Vec<float> designLowpassLeastSquaresMethod (double sampleRate, float frequency, size_t order, float normalisedTransitionWidth, float stopBandWeight)
+{
+    checkFrequency (sampleRate, frequency);
+    checkOrder (order);
+    checkTransitionWidth (normalisedTransitionWidth);
+
+    if (stopBandWeight < 1.0f || stopBandWeight > 100.0f)
+    {
+        throw std::invalid_argument ("stop band weight must be between 1 and 100");
+    }
+
+    return toVec (*FilterDesign::designFIRLowpassLeastSquaresMethod (frequency, sampleRate, order, normalisedTransitionWidth, stopBandWeight));
+}
+
+rust::Vec<float> designLowpassHalfBandEquirippleMethod (float normalisedTransitionWidth, float amplitudeDb)
+{
+    if (normalisedTransitionWidth <= 0.0f || normalisedTransitionWidth >= 0.5f)
+    {
+        throw std::invalid_argument ("normalised transition width must be between 0 and 0.5");
+    }
+
+    checkAttenuation (amplitudeDb);
+
+    return toVec (*FilterDesign::designFIRLowpassHalfBandEquirippleMethod (normalisedTransitionWidth, amplitudeDb));
+}
+
+double getMagnitudeForFrequency (rust::Slice<const float> coefficients, double frequency, double sampleRate)
+{
+    return toCoefficients (coefficients).getMagnitudeForFrequency (frequency, sampleRate);
+}
+
+double getPhaseForFrequency (rust::Slice<const float> coefficients, double frequency, double sampleRate)
+{
+    return toCoefficients (coefficients).getPhaseForFrequency (frequency, sampleRate);
+}
+
+std::unique_ptr<dsp::FIRFilter> createFilter (rust::Slice<const float> coefficients)
+{
+    return std::make_unique<dsp::FIRFilter> (coefficients);
+}
+} // namespace cxx_juce::fir
@@ -0,0 +1,34 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::first_order_tpt_filter
+{
+std::unique_ptr<FirstOrderTPTFilter> createFirstOrderTPTFilter()
+{
+    return std::make_unique<FirstOrderTPTFilter>();
+}
+
+void setType (FirstOrderTPTFilter& filter, int type)
+{
+    filter.setType (static_cast<juce::dsp::FirstOrderTPTFilterType> (type));
+}
+
+int getType (const FirstOrderTPTFilter& filter)
+{
+    return static_cast<int> (filter.getType());
+}
+
+void prepare (FirstOrderTPTFilter& filter, const ProcessSpec& spec)
+{
+    filter.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (FirstOrderTPTFilter& filter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (filter, buffer, dsp::toProcessSpec (spec));
+}
+
+float processSample (FirstOrderTPTFilter& filter, size_t channel, float sample)
+{
+    return filter.processSample (static_cast<int> (channel), sample);
+}
+} // namespace cxx_juce::first_order_tpt_filter
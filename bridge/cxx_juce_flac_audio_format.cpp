@@ -0,0 +1,38 @@
+#include "cxx_juce_bindings.h"
+
+#if JUCE_USE_FLAC
+
+namespace cxx_juce::flac_audio_format
+{
+std::unique_ptr<juce::FlacAudioFormat> createFlacAudioFormat()
+{
+    return std::make_unique<juce::FlacAudioFormat>();
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::FlacAudioFormat& format, rust::Str path)
+{
+    return cxx_juce::createReaderForFile (format, path);
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::FlacAudioFormat& format, rust::Box<BoxedInputStream> stream)
+{
+    return cxx_juce::createReaderForStream (format, std::move (stream));
+}
+
+std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::FlacAudioFormat& format,
+                                                              rust::Str path,
+                                                              double sampleRate,
+                                                              juce::uint32 numChannels,
+                                                              int bitsPerSample,
+                                                              int compressionLevel)
+{
+    if (! juce::isPositiveAndBelow (compressionLevel, format.getQualityOptions().size()))
+    {
+        throw std::invalid_argument ("invalid compression level");
+    }
+
+    return cxx_juce::createWriterForFile (format, path, sampleRate, numChannels, bitsPerSample, {}, compressionLevel);
+}
+} // namespace cxx_juce::flac_audio_format
+
+#endif
@@ -0,0 +1,54 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::float_vector_operations
+{
+namespace
+{
+    template <typename T>
+    int getNumValues (rust::Slice<T> values)
+    {
+        return static_cast<int> (values.size());
+    }
+} // namespace
+
+void add (rust::Slice<float> dest, rust::Slice<const float> src)
+{
+    juce::FloatVectorOperations::add (dest.data(), src.data(), getNumValues (dest));
+}
+
+void addValue (rust::Slice<float> dest, float amount)
+{
+    juce::FloatVectorOperations::add (dest.data(), amount, getNumValues (dest));
+}
+
+void multiply (rust::Slice<float> dest, rust::Slice<const float> src)
+{
+    juce::FloatVectorOperations::multiply (dest.data(), src.data(), getNumValues (dest));
+}
+
+void multiplyByValue (rust::Slice<float> dest, float multiplier)
+{
+    juce::FloatVectorOperations::multiply (dest.data(), multiplier, getNumValues (dest));
+}
+
+void copyWithMultiply (rust::Slice<float> dest, rust::Slice<const float> src, float multiplier)
+{
+    juce::FloatVectorOperations::copyWithMultiply (dest.data(), src.data(), multiplier, getNumValues (dest));
+}
+
+std::array<float, 2> findMinAndMax (rust::Slice<const float> src)
+{
+    const auto range = juce::FloatVectorOperations::findMinAndMax (src.data(), getNumValues (src));
+    return { range.getStart(), range.getEnd() };
+}
+
+void clip (rust::Slice<float> samples, float low, float high)
+{
+    juce::FloatVectorOperations::clip (samples.data(), samples.data(), low, high, getNumValues (samples));
+}
+
+void convertFixedToFloat (rust::Slice<float> dest, rust::Slice<const int> src, float multiplier)
+{
+    juce::FloatVectorOperations::convertFixedToFloat (dest.data(), src.data(), multiplier, getNumValues (dest));
+}
+} // namespace cxx_juce::float_vector_operations
@@ -0,0 +1,19 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::gain
+{
+std::unique_ptr<Gain> createGain()
+{
+    return std::make_unique<Gain>();
+}
+
+void prepare (Gain& gain, const ProcessSpec& spec)
+{
+    gain.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (Gain& gain, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (gain, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::gain
@@ -0,0 +1,28 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+HighResolutionTimerHandle::HighResolutionTimerHandle (rust::Box<BoxedHighResolutionTimerCallback> callback)
+    : _callback (std::move (callback))
+{
+}
+
+HighResolutionTimerHandle::~HighResolutionTimerHandle()
+{
+    // Waits for a running callback to finish, which must happen before the callback is dropped.
+    stopTimer();
+}
+
+void HighResolutionTimerHandle::hiResTimerCallback()
+{
+    ::high_resolution_timer_callback::call (*_callback);
+}
+
+namespace high_resolution_timer
+{
+std::unique_ptr<HighResolutionTimerHandle> createHighResolutionTimer (rust::Box<BoxedHighResolutionTimerCallback> callback)
+{
+    return std::make_unique<HighResolutionTimerHandle> (std::move (callback));
+}
+} // namespace high_resolution_timer
+} // namespace cxx_juce
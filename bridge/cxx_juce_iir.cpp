@@ -0,0 +1,178 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::dsp
+{
+namespace
+{
+    void assignCoefficients (juce::dsp::IIR::Coefficients<float>& target, rust::Slice<const float> coefficients)
+    {
+        auto& values = target.coefficients;
+        values.resize (static_cast<int> (coefficients.size()));
+        std::copy (coefficients.begin(), coefficients.end(), values.begin());
+    }
+} // namespace
+
+IIRFilter::IIRFilter (rust::Slice<const float> coefficients)
+{
+    assignCoefficients (*_coefficients, coefficients);
+}
+
+void IIRFilter::setCoefficients (rust::Slice<const float> coefficients)
+{
+    assignCoefficients (*_coefficients, coefficients);
+}
+
+void IIRFilter::prepare (const ProcessSpec& spec)
+{
+    auto monoSpec = toProcessSpec (spec);
+    monoSpec.numChannels = 1;
+
+    _filters.clear();
+    _filters.reserve (spec.num_channels);
+
+    for (size_t channel = 0; channel < spec.num_channels; ++channel)
+    {
+        _filters.emplace_back (_coefficients);
+        _filters.back().prepare (monoSpec);
+    }
+}
+
+void IIRFilter::reset()
+{
+    for (auto& filter : _filters)
+    {
+        filter.reset();
+    }
+}
+
+void IIRFilter::process (juce::AudioSampleBuffer& buffer)
+{
+    juce::dsp::AudioBlock<float> block (buffer);
+    const auto numChannels = juce::jmin (block.getNumChannels(), _filters.size());
+
+    for (size_t channel = 0; channel < numChannels; ++channel)
+    {
+        auto channelBlock = block.getSingleChannelBlock (channel);
+        _filters[channel].process (juce::dsp::ProcessContextReplacing<float> (channelBlock));
+    }
+
+    for (auto& filter : _filters)
+    {
+        filter.snapToZero();
+    }
+}
+
+float IIRFilter::processSample (size_t channel, float sample)
+{
+    if (channel >= _filters.size())
+    {
+        return sample;
+    }
+
+    return _filters[channel].processSample (sample);
+}
+} // namespace cxx_juce::dsp
+
+namespace cxx_juce::iir
+{
+namespace
+{
+    enum Type
+    {
+        firstOrderLowPass,
+        firstOrderHighPass,
+        firstOrderAllPass,
+        lowPass,
+        highPass,
+        bandPass,
+        notch,
+        allPass,
+        lowShelf,
+        highShelf,
+        peakFilter,
+    };
+
+    juce::dsp::IIR::Coefficients<float> toCoefficients (rust::Slice<const float> coefficients)
+    {
+        juce::dsp::IIR::Coefficients<float> result;
+        result.coefficients = juce::Array<float> (coefficients.data(), static_cast<int> (coefficients.size()));
+        return result;
+    }
+} // namespace
+
+rust::Vec<float> design (int type, double sampleRate, float frequency, float q, float gainFactor)
+{
+    using Coefficients = juce::dsp::IIR::Coefficients<float>;
+
+    if (sampleRate <= 0.0 || frequency <= 0.0f || frequency >= sampleRate * 0.5)
+    {
+        throw std::invalid_argument ("frequency must be between 0 and half the sample rate");
+    }
+
+    if (q <= 0.0f)
+    {
+        throw std::invalid_argument ("Q must be positive");
+    }
+
+    if (gainFactor <= 0.0f)
+    {
+        throw std::invalid_argument ("gain factor must be positive");
+    }
+
+    const auto coefficients = [&]
+    {
+        switch (type)
+        {
+            case firstOrderLowPass:
+                return Coefficients::makeFirstOrderLowPass (sampleRate, frequency);
+            case firstOrderHighPass:
+                return Coefficients::makeFirstOrderHighPass (sampleRate, frequency);
+            case firstOrderAllPass:
+                return Coefficients::makeFirstOrderAllPass (sampleRate, frequency);
+            case lowPass:
+                return Coefficients::makeLowPass (sampleRate, frequency, q);
+            case highPass:
+                return Coefficients::makeHighPass (sampleRate, frequency, q);
+            case bandPass:
+                return Coefficients::makeBandPass (sampleRate, frequency, q);
+            case notch:
+                return Coefficients::makeNotch (sampleRate, frequency, q);
+            case allPass:
+                return Coefficients::makeAllPass (sampleRate, frequency, q);
+            case lowShelf:
+                return Coefficients::makeLowShelf (sampleRate, frequency, q, gainFactor);
+            case highShelf:
+                return Coefficients::makeHighShelf (sampleRate, frequency, q, gainFactor);
+            case peakFilter:
+                return Coefficients::makePeakFilter (sampleRate, frequency, q, gainFactor);
+            default:
+                throw std::invalid_argument ("unknown filter type");
+        }
+    }();
+
+    rust::Vec<float> result;
+    result.reserve (static_cast<size_t> (coefficients->coefficients.size()));
+
+    for (const auto coefficient : coefficients->coefficients)
+    {
+        result.push_back (coefficient);
+    }
+
+    return result;
+}
+
+double getMagnitudeForFrequency (rust::Slice<const float> coefficients, double frequency, double sampleRate)
+{
+    return toCoefficients (coefficients).getMagnitudeForFrequency (frequency, sampleRate);
+}
+
+double getPhaseForFrequency (rust::Slice<const float> coefficients, double frequency, double sampleRate)
+{
+    return toCoefficients (coefficients).getPhaseForFrequency (frequency, sampleRate);
+}
+
+std::unique_ptr<dsp::IIRFilter> createFilter (rust::Slice<const float> coefficients)
+{
+    return std::make_unique<dsp::IIRFilter> (coefficients);
+}
+} // namespace cxx_juce::iir
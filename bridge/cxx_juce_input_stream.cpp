@@ -0,0 +1,55 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+RustInputStream::RustInputStream (rust::Box<BoxedInputStream> stream)
+    : _stream (std::move (stream))
+{
+}
+
+juce::int64 RustInputStream::getTotalLength()
+{
+    return ::input_stream::getTotalLength (*_stream);
+}
+
+bool RustInputStream::isExhausted()
+{
+    const auto length = getTotalLength();
+    return length >= 0 && getPosition() >= length;
+}
+
+int RustInputStream::read (void* destBuffer, int maxBytesToRead)
+{
+    if (maxBytesToRead <= 0)
+    {
+        return 0;
+    }
+
+    return ::input_stream::read (*_stream,
+                                 rust::Slice<juce::uint8> (static_cast<juce::uint8*> (destBuffer),
+                                                           static_cast<size_t> (maxBytesToRead)));
+}
+
+juce::int64 RustInputStream::getPosition()
+{
+    return ::input_stream::getPosition (*_stream);
+}
+
+bool RustInputStream::setPosition (juce::int64 newPosition)
+{
+    return ::input_stream::setPosition (*_stream, newPosition);
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::input_stream
+{
+int read (juce::InputStream& stream, rust::Slice<juce::uint8> buffer)
+{
+    if (buffer.empty())
+    {
+        return 0;
+    }
+
+    return stream.read (buffer.data(), static_cast<int> (juce::jmin (buffer.size(), size_t { std::numeric_limits<int>::max() })));
+}
+} // namespace cxx_juce::input_stream
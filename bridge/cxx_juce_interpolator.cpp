@@ -0,0 +1,78 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::interpolator
+{
+namespace
+{
+    enum class Type
+    {
+        zeroOrderHold,
+        linear,
+        catmullRom,
+        lagrange,
+        windowedSinc,
+    };
+
+    // Input beyond the end of the slice is read as silence, so the interpolator never reads
+    // outside the slice.
+    template <typename JuceInterpolator>
+    class InterpolatorImpl : public Interpolator
+    {
+    public:
+        float getBaseLatency() const override
+        {
+            return _interpolator.getBaseLatency();
+        }
+
+        void reset() override
+        {
+            _interpolator.reset();
+        }
+
+        size_t process (double speedRatio, rust::Slice<const float> input, rust::Slice<float> output) override
+        {
+            return static_cast<size_t> (_interpolator.process (speedRatio,
+                                                               input.data(),
+                                                               output.data(),
+                                                               static_cast<int> (output.size()),
+                                                               static_cast<int> (input.size()),
+                                                               0));
+        }
+
+        size_t processAdding (double speedRatio, rust::Slice<const float> input, rust::Slice<float> output, float gain) override
+        {
+            return static_cast<size_t> (_interpolator.processAdding (speedRatio,
+                                                                     input.data(),
+                                                                     output.data(),
+                                                                     static_cast<int> (output.size()),
+                                                                     static_cast<int> (input.size()),
+                                                                     0,
+                                                                     gain));
+        }
+
+    private:
+        JuceInterpolator _interpolator;
+    };
+} // namespace
+
+std::unique_ptr<Interpolator> createInterpolator (int type)
+{
+    namespace Interpolators = juce::Interpolators;
+
+    switch (static_cast<Type> (type))
+    {
+        case Type::zeroOrderHold:
+            return std::make_unique<InterpolatorImpl<Interpolators::ZeroOrderHold>>();
+        case Type::linear:
+            return std::make_unique<InterpolatorImpl<Interpolators::Linear>>();
+        case Type::catmullRom:
+            return std::make_unique<InterpolatorImpl<Interpolators::CatmullRom>>();
+        case Type::lagrange:
+            return std::make_unique<InterpolatorImpl<Interpolators::Lagrange>>();
+        case Type::windowedSinc:
+            return std::make_unique<InterpolatorImpl<Interpolators::WindowedSinc>>();
+    }
+
+    throw std::invalid_argument ("invalid interpolator type");
+}
+} // namespace cxx_juce::interpolator
@@ -0,0 +1,137 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+InterprocessConnection::InterprocessConnection (juce::uint32 magicMessageHeader,
+                                                rust::Box<BoxedInterprocessConnectionListener> listener)
+    : juce::InterprocessConnection (false, magicMessageHeader)
+    , _listener (std::move (listener))
+{
+}
+
+InterprocessConnection::~InterprocessConnection()
+{
+    // The connection's thread has to be stopped before the listener is destroyed.
+    juce::InterprocessConnection::disconnect();
+}
+
+void InterprocessConnection::connectionMade()
+{
+    ::interprocess_connection_listener::connectionMade (*_listener, *this);
+}
+
+void InterprocessConnection::connectionLost()
+{
+    ::interprocess_connection_listener::connectionLost (*_listener);
+}
+
+void InterprocessConnection::messageReceived (const juce::MemoryBlock& message)
+{
+    ::interprocess_connection_listener::messageReceived (
+        *_listener,
+        *this,
+        rust::Slice<const juce::uint8> (static_cast<const juce::uint8*> (message.getData()), message.getSize()));
+}
+
+InterprocessConnectionServer::InterprocessConnectionServer (juce::uint32 magicMessageHeader,
+                                                            rust::Box<BoxedInterprocessConnectionFactory> factory)
+    : _magicMessageHeader (magicMessageHeader)
+    , _factory (std::move (factory))
+{
+}
+
+InterprocessConnectionServer::~InterprocessConnectionServer()
+{
+    // Stop accepting connections before the existing ones are destroyed.
+    stop();
+}
+
+juce::InterprocessConnection* InterprocessConnectionServer::createConnectionObject()
+{
+    // Connections are only created on the server's thread, so this is a good time to clean up
+    // the ones that have been closed.
+    _connections.erase (std::remove_if (_connections.begin(),
+                                        _connections.end(),
+                                        [] (const auto& connection)
+                                        {
+                                            return ! connection->isConnected();
+                                        }),
+                        _connections.end());
+
+    auto listener = ::interprocess_connection_factory::createListener (*_factory);
+    return _connections.emplace_back (std::make_unique<InterprocessConnection> (_magicMessageHeader, std::move (listener))).get();
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::interprocess_connection
+{
+std::unique_ptr<InterprocessConnection> createInterprocessConnection (juce::uint32 magicMessageHeader,
+                                                                     rust::Box<BoxedInterprocessConnectionListener> listener)
+{
+    return std::make_unique<InterprocessConnection> (magicMessageHeader, std::move (listener));
+}
+
+void connectToSocket (InterprocessConnection& connection, rust::Str hostName, int port, int timeoutMs)
+{
+    const auto host = static_cast<std::string> (hostName);
+
+    if (! connection.connectToSocket (host, port, timeoutMs))
+    {
+        throw std::runtime_error ("failed to connect to " + host + ":" + std::to_string (port));
+    }
+}
+
+void connectToPipe (InterprocessConnection& connection, rust::Str pipeName, int timeoutMs)
+{
+    const auto name = static_cast<std::string> (pipeName);
+
+    if (! connection.connectToPipe (name, timeoutMs))
+    {
+        throw std::runtime_error ("failed to connect to pipe " + name);
+    }
+}
+
+void createPipe (InterprocessConnection& connection, rust::Str pipeName, int timeoutMs, bool mustNotExist)
+{
+    const auto name = static_cast<std::string> (pipeName);
+
+    if (! connection.createPipe (name, timeoutMs, mustNotExist))
+    {
+        throw std::runtime_error ("failed to create pipe " + name);
+    }
+}
+
+void disconnect (InterprocessConnection& connection)
+{
+    connection.disconnect();
+}
+
+bool sendMessage (const InterprocessConnection& connection, rust::Slice<const juce::uint8> message)
+{
+    // Sending is thread safe, and is allowed from the listener's callbacks, which only have a
+    // shared reference to the connection.
+    return const_cast<InterprocessConnection&> (connection).sendMessage (juce::MemoryBlock (message.data(), message.size()));
+}
+
+rust::String getConnectedHostName (const InterprocessConnection& connection)
+{
+    return connection.getConnectedHostName().toStdString();
+}
+} // namespace cxx_juce::interprocess_connection
+
+namespace cxx_juce::interprocess_connection_server
+{
+std::unique_ptr<InterprocessConnectionServer> createInterprocessConnectionServer (juce::uint32 magicMessageHeader,
+                                                                                 rust::Box<BoxedInterprocessConnectionFactory> factory)
+{
+    return std::make_unique<InterprocessConnectionServer> (magicMessageHeader, std::move (factory));
+}
+
+void beginWaitingForSocket (InterprocessConnectionServer& server, int port, rust::Str bindAddress)
+{
+    if (! server.beginWaitingForSocket (port, static_cast<std::string> (bindAddress)))
+    {
+        throw std::runtime_error ("failed to listen on port " + std::to_string (port));
+    }
+}
+} // namespace cxx_juce::interprocess_connection_server
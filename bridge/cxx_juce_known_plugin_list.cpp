@@ -0,0 +1,71 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::known_plugin_list
+{
+std::unique_ptr<juce::KnownPluginList> createKnownPluginList()
+{
+    return std::make_unique<juce::KnownPluginList>();
+}
+
+rust::Vec<PluginDescription> getTypes (const juce::KnownPluginList& list)
+{
+    return plugin_description::fromJuce (list.getTypes());
+}
+
+bool addType (juce::KnownPluginList& list, const PluginDescription& description)
+{
+    return list.addType (plugin_description::toJuce (description));
+}
+
+void removeType (juce::KnownPluginList& list, const PluginDescription& description)
+{
+    list.removeType (plugin_description::toJuce (description));
+}
+
+bool getTypeForIdentifierString (const juce::KnownPluginList& list, rust::Str identifier, PluginDescription& description)
+{
+    const auto type = list.getTypeForIdentifierString (static_cast<std::string> (identifier));
+
+    if (type == nullptr)
+    {
+        return false;
+    }
+
+    description = plugin_description::fromJuce (*type);
+    return true;
+}
+
+void addToBlacklist (juce::KnownPluginList& list, rust::Str fileOrIdentifier)
+{
+    list.addToBlacklist (static_cast<std::string> (fileOrIdentifier));
+}
+
+void removeFromBlacklist (juce::KnownPluginList& list, rust::Str fileOrIdentifier)
+{
+    list.removeFromBlacklist (static_cast<std::string> (fileOrIdentifier));
+}
+
+rust::Vec<rust::String> getBlacklistedFiles (const juce::KnownPluginList& list)
+{
+    return toRustStrings (list.getBlacklistedFiles());
+}
+
+rust::String toXml (const juce::KnownPluginList& list)
+{
+    return list.createXml()->toString().toStdString();
+}
+
+void fromXml (juce::KnownPluginList& list, rust::Str xml)
+{
+    const auto element = juce::parseXML (juce::String (static_cast<std::string> (xml)));
+
+    // recreateFromXml clears the list before checking the tag, so check it first to leave the
+    // list untouched on failure.
+    if (element == nullptr || ! element->hasTagName ("KNOWNPLUGINS"))
+    {
+        throw std::invalid_argument ("invalid plugin list XML");
+    }
+
+    list.recreateFromXml (*element);
+}
+} // namespace cxx_juce::known_plugin_list
@@ -0,0 +1,24 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::ladder_filter
+{
+std::unique_ptr<LadderFilter> createLadderFilter()
+{
+    return std::make_unique<LadderFilter>();
+}
+
+void setMode (LadderFilter& filter, int mode)
+{
+    filter.setMode (static_cast<juce::dsp::LadderFilterMode> (mode));
+}
+
+void prepare (LadderFilter& filter, const ProcessSpec& spec)
+{
+    filter.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (LadderFilter& filter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (filter, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::ladder_filter
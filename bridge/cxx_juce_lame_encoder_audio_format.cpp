@@ -0,0 +1,39 @@
+#include "cxx_juce_bindings.h"
+
+#if JUCE_USE_LAME_AUDIO_FORMAT
+
+namespace cxx_juce::lame_encoder_audio_format
+{
+std::unique_ptr<juce::LAMEEncoderAudioFormat> createLAMEEncoderAudioFormat (rust::Str lameExecutable)
+{
+    const auto lameApp = toFile (lameExecutable);
+
+    if (! lameApp.existsAsFile())
+    {
+        throw std::invalid_argument ("LAME executable not found");
+    }
+
+    return std::make_unique<juce::LAMEEncoderAudioFormat> (lameApp);
+}
+
+rust::Vec<rust::String> getQualityOptions (juce::LAMEEncoderAudioFormat& format)
+{
+    return toRustStrings (format.getQualityOptions());
+}
+
+std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::LAMEEncoderAudioFormat& format,
+                                                              rust::Str path,
+                                                              double sampleRate,
+                                                              juce::uint32 numChannels,
+                                                              int qualityOptionIndex)
+{
+    if (! juce::isPositiveAndBelow (qualityOptionIndex, format.getQualityOptions().size()))
+    {
+        throw std::invalid_argument ("invalid quality option");
+    }
+
+    return cxx_juce::createWriterForFile (format, path, sampleRate, numChannels, 16, {}, qualityOptionIndex);
+}
+} // namespace cxx_juce::lame_encoder_audio_format
+
+#endif
@@ -0,0 +1,19 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::limiter
+{
+std::unique_ptr<Limiter> createLimiter()
+{
+    return std::make_unique<Limiter>();
+}
+
+void prepare (Limiter& limiter, const ProcessSpec& spec)
+{
+    limiter.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (Limiter& limiter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (limiter, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::limiter
@@ -0,0 +1,58 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::linkwitz_riley_filter
+{
+std::unique_ptr<LinkwitzRileyFilter> createLinkwitzRileyFilter()
+{
+    return std::make_unique<LinkwitzRileyFilter>();
+}
+
+void setType (LinkwitzRileyFilter& filter, int type)
+{
+    filter.setType (static_cast<juce::dsp::LinkwitzRileyFilterType> (type));
+}
+
+int getType (const LinkwitzRileyFilter& filter)
+{
+    return static_cast<int> (filter.getType());
+}
+
+void prepare (LinkwitzRileyFilter& filter, const ProcessSpec& spec)
+{
+    filter.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (LinkwitzRileyFilter& filter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (filter, buffer, dsp::toProcessSpec (spec));
+}
+
+float processSample (LinkwitzRileyFilter& filter, size_t channel, float sample)
+{
+    return filter.processSample (static_cast<int> (channel), sample);
+}
+
+void processSampleBands (LinkwitzRileyFilter& filter, size_t channel, float sample, float& low, float& high)
+{
+    filter.processSample (static_cast<int> (channel), sample, low, high);
+}
+
+void split (LinkwitzRileyFilter& filter, juce::AudioSampleBuffer& low, juce::AudioSampleBuffer& high, const ProcessSpec& spec)
+{
+    const auto numChannels = juce::jmin (static_cast<size_t> (low.getNumChannels()),
+                                         static_cast<size_t> (high.getNumChannels()),
+                                         spec.num_channels);
+    const auto numSamples = juce::jmin (low.getNumSamples(), high.getNumSamples());
+
+    for (size_t channel = 0; channel < numChannels; ++channel)
+    {
+        auto* lowSamples = low.getWritePointer (static_cast<int> (channel));
+        auto* highSamples = high.getWritePointer (static_cast<int> (channel));
+
+        for (auto sample = 0; sample < numSamples; ++sample)
+        {
+            filter.processSample (static_cast<int> (channel), lowSamples[sample], lowSamples[sample], highSamples[sample]);
+        }
+    }
+}
+} // namespace cxx_juce::linkwitz_riley_filter
@@ -0,0 +1,58 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+void RustLogger::logMessage (const juce::String& message)
+{
+    // Fall back to JUCE's default output while no logger is set from Rust.
+    if (! ::logger::logMessage (toStr (message)))
+    {
+        juce::Logger::outputDebugString (message);
+    }
+}
+
+namespace logger
+{
+void installLoggerForwarder()
+{
+    // Never deleted, as JUCE may still write to its current logger during static destruction.
+    static auto* forwarder = new RustLogger();
+    juce::Logger::setCurrentLogger (forwarder);
+}
+
+void writeToLog (rust::Str message)
+{
+    juce::Logger::writeToLog (static_cast<std::string> (message));
+}
+} // namespace logger
+
+namespace file_logger
+{
+std::unique_ptr<juce::FileLogger> createFileLogger (rust::Str path, rust::Str welcomeMessage, juce::int64 maxInitialFileSizeBytes)
+{
+    return std::make_unique<juce::FileLogger> (toFile (path), static_cast<std::string> (welcomeMessage), maxInitialFileSizeBytes);
+}
+
+std::unique_ptr<juce::FileLogger> createDefaultAppLogger (rust::Str logFileSubDirectoryName,
+                                                          rust::Str logFileName,
+                                                          rust::Str welcomeMessage,
+                                                          juce::int64 maxInitialFileSizeBytes)
+{
+    return std::unique_ptr<juce::FileLogger> (juce::FileLogger::createDefaultAppLogger (static_cast<std::string> (logFileSubDirectoryName),
+                                                                                        static_cast<std::string> (logFileName),
+                                                                                        static_cast<std::string> (welcomeMessage),
+                                                                                        maxInitialFileSizeBytes));
+}
+
+void logMessage (const juce::FileLogger& logger, rust::Str message)
+{
+    // FileLogger serialises writes with its own lock, so it can be shared between threads.
+    const_cast<juce::FileLogger&> (logger).logMessage (static_cast<std::string> (message));
+}
+
+rust::String getLogFile (const juce::FileLogger& logger)
+{
+    return logger.getLogFile().getFullPathName().toStdString();
+}
+} // namespace file_logger
+} // namespace cxx_juce
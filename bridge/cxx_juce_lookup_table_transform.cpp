@@ -0,0 +1,34 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::lookup_table_transform
+{
+std::unique_ptr<LookupTableTransform> createLookupTableTransform (const BoxedWaveShaperFunction& function,
+                                                                  float minimumInput,
+                                                                  float maximumInput,
+                                                                  size_t numPoints)
+{
+    if (! (minimumInput < maximumInput))
+    {
+        throw std::invalid_argument ("minimum input must be less than maximum input");
+    }
+
+    if (numPoints < 2)
+    {
+        throw std::invalid_argument ("lookup table must have at least 2 points");
+    }
+
+    auto transform = std::make_unique<LookupTableTransform>();
+    transform->initialise ([&function] (float x)
+                           { return ::wave_shaper::call (function, x); },
+                           minimumInput,
+                           maximumInput,
+                           numPoints);
+
+    return transform;
+}
+
+void process (const LookupTableTransform& transform, rust::Slice<float> samples)
+{
+    transform.process (samples.data(), samples.data(), samples.size());
+}
+} // namespace cxx_juce::lookup_table_transform
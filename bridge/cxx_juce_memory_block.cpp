@@ -0,0 +1,79 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::memory_block
+{
+std::unique_ptr<juce::MemoryBlock> createMemoryBlock (rust::Slice<const juce::uint8> data)
+{
+    return std::make_unique<juce::MemoryBlock> (data.data(), data.size());
+}
+
+std::unique_ptr<juce::MemoryBlock> createMemoryBlockWithSize (size_t size)
+{
+    return std::make_unique<juce::MemoryBlock> (size, true);
+}
+
+std::unique_ptr<juce::MemoryBlock> copy (const juce::MemoryBlock& block)
+{
+    return std::make_unique<juce::MemoryBlock> (block);
+}
+
+bool equals (const juce::MemoryBlock& block, const juce::MemoryBlock& other)
+{
+    return block == other;
+}
+
+const juce::uint8* getData (const juce::MemoryBlock& block)
+{
+    return static_cast<const juce::uint8*> (block.getData());
+}
+
+juce::uint8* getMutableData (juce::MemoryBlock& block)
+{
+    return static_cast<juce::uint8*> (block.getData());
+}
+
+void append (juce::MemoryBlock& block, rust::Slice<const juce::uint8> data)
+{
+    block.append (data.data(), data.size());
+}
+
+rust::String toBase64Encoding (const juce::MemoryBlock& block)
+{
+    return block.toBase64Encoding().toStdString();
+}
+
+std::unique_ptr<juce::MemoryBlock> fromBase64Encoding (rust::Str encoded)
+{
+    auto block = std::make_unique<juce::MemoryBlock>();
+
+    if (! block->fromBase64Encoding (static_cast<std::string> (encoded)))
+    {
+        throw std::invalid_argument ("invalid base64 encoding");
+    }
+
+    return block;
+}
+} // namespace cxx_juce::memory_block
+
+namespace cxx_juce::base64
+{
+rust::String toBase64 (rust::Slice<const juce::uint8> data)
+{
+    return juce::Base64::toBase64 (data.data(), data.size()).toStdString();
+}
+
+rust::Vec<juce::uint8> convertFromBase64 (rust::Str encoded)
+{
+    juce::MemoryOutputStream stream;
+
+    if (! juce::Base64::convertFromBase64 (stream, static_cast<std::string> (encoded)))
+    {
+        throw std::invalid_argument ("invalid base64");
+    }
+
+    rust::Vec<juce::uint8> data;
+    data.reserve (stream.getDataSize());
+    std::copy_n (static_cast<const juce::uint8*> (stream.getData()), stream.getDataSize(), std::back_inserter (data));
+    return data;
+}
+} // namespace cxx_juce::base64
@@ -0,0 +1,65 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::memory_mapped_audio_format_reader
+{
+namespace
+{
+    juce::MemoryMappedAudioFormatReader& toMemoryMappedReader (juce::AudioFormatReader& reader)
+    {
+        return static_cast<juce::MemoryMappedAudioFormatReader&> (reader);
+    }
+
+    const juce::MemoryMappedAudioFormatReader& toMemoryMappedReader (const juce::AudioFormatReader& reader)
+    {
+        return static_cast<const juce::MemoryMappedAudioFormatReader&> (reader);
+    }
+} // namespace
+
+bool mapEntireFile (juce::AudioFormatReader& reader)
+{
+    return toMemoryMappedReader (reader).mapEntireFile();
+}
+
+bool mapSectionOfFile (juce::AudioFormatReader& reader, juce::int64 startSample, juce::int64 endSample)
+{
+    return toMemoryMappedReader (reader).mapSectionOfFile ({ startSample, endSample });
+}
+
+std::array<juce::int64, 2> getMappedSection (const juce::AudioFormatReader& reader)
+{
+    const auto section = toMemoryMappedReader (reader).getMappedSection();
+    return { section.getStart(), section.getEnd() };
+}
+
+void touchSample (const juce::AudioFormatReader& reader, juce::int64 sample)
+{
+    const auto& memoryMappedReader = toMemoryMappedReader (reader);
+
+    if (memoryMappedReader.getMappedSection().contains (sample))
+    {
+        memoryMappedReader.touchSample (sample);
+    }
+}
+
+size_t getNumBytesUsed (const juce::AudioFormatReader& reader)
+{
+    return toMemoryMappedReader (reader).getNumBytesUsed();
+}
+
+void getSample (const juce::AudioFormatReader& reader, juce::int64 sample, rust::Slice<float> result)
+{
+    const auto& memoryMappedReader = toMemoryMappedReader (reader);
+
+    if (result.size() < memoryMappedReader.numChannels)
+    {
+        throw std::invalid_argument ("result has fewer elements than the reader has channels");
+    }
+
+    if (! memoryMappedReader.getMappedSection().contains (sample))
+    {
+        throw std::out_of_range ("sample is outside of the mapped section");
+    }
+
+    memoryMappedReader.getSample (sample, result.data());
+}
+} // namespace cxx_juce::memory_mapped_audio_format_reader
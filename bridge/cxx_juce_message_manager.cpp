@@ -6,4 +6,71 @@ juce::MessageManager* getInstanceWithoutCreating()
 {
     return juce::MessageManager::getInstanceWithoutCreating();
 }
-} // namespace cxx_juce::message_manager
\ No newline at end of file
+
+namespace
+{
+    // Creates the message manager if there isn't one, making the calling thread the message thread.
+    juce::MessageManager& getInstanceOnMessageThread()
+    {
+        auto& messageManager = *juce::MessageManager::getInstance();
+
+        if (! messageManager.isThisTheMessageThread())
+        {
+            throw std::runtime_error ("the message loop can only be run on the message thread");
+        }
+
+        return messageManager;
+    }
+} // namespace
+
+void runDispatchLoop()
+{
+    getInstanceOnMessageThread().runDispatchLoop();
+}
+
+bool runDispatchLoopUntil (int millisecondsToRunFor)
+{
+    return getInstanceOnMessageThread().runDispatchLoopUntil (millisecondsToRunFor);
+}
+
+void stopDispatchLoop()
+{
+    if (auto* messageManager = juce::MessageManager::getInstanceWithoutCreating())
+    {
+        messageManager->stopDispatchLoop();
+    }
+}
+
+bool hasStopBeenCalled()
+{
+    const auto* messageManager = juce::MessageManager::getInstanceWithoutCreating();
+    return messageManager != nullptr && messageManager->hasStopMessageBeenSent();
+}
+
+bool isThisTheMessageThread()
+{
+    return juce::MessageManager::existsAndIsCurrentThread();
+}
+
+bool callAsync (rust::Box<BoxedAsyncCallback> callback)
+{
+    // A message rather than a std::function, which would need the callback to be copyable. If the
+    // message is never delivered the callback is dropped along with it.
+    struct AsyncCallbackMessage : juce::CallbackMessage
+    {
+        explicit AsyncCallbackMessage (rust::Box<BoxedAsyncCallback> callback)
+            : _callback (std::move (callback))
+        {
+        }
+
+        void messageCallback() override
+        {
+            ::async_callback::call (std::move (_callback));
+        }
+
+        rust::Box<BoxedAsyncCallback> _callback;
+    };
+
+    return (new AsyncCallbackMessage (std::move (callback)))->post();
+}
+} // namespace cxx_juce::message_manager
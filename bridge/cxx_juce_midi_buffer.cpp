@@ -0,0 +1,29 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::midi_buffer
+{
+std::unique_ptr<juce::MidiBuffer> createMidiBuffer()
+{
+    return std::make_unique<juce::MidiBuffer>();
+}
+
+std::unique_ptr<juce::MidiBuffer> copyMidiBuffer (const juce::MidiBuffer& buffer)
+{
+    return std::make_unique<juce::MidiBuffer> (buffer);
+}
+
+void addEvent (juce::MidiBuffer& buffer, const juce::MidiMessage& message, rust::i32 sampleNumber)
+{
+    buffer.addEvent (message, sampleNumber);
+}
+
+void addRawEvent (juce::MidiBuffer& buffer, rust::Slice<const rust::u8> data, rust::i32 sampleNumber)
+{
+    buffer.addEvent (data.data(), static_cast<int> (data.size()), sampleNumber);
+}
+
+rust::Slice<const rust::u8> getRawData (const juce::MidiBuffer& buffer)
+{
+    return { buffer.data.begin(), static_cast<size_t> (buffer.data.size()) };
+}
+} // namespace cxx_juce::midi_buffer
@@ -0,0 +1,53 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+MidiInput::MidiInput (rust::Str identifier, rust::Box<BoxedMidiInputCallback> callback)
+    : _callback (std::move (callback))
+    , _midiInput (juce::MidiInput::openDevice (static_cast<std::string> (identifier), this))
+{
+    if (! _midiInput)
+    {
+        throw std::runtime_error ("failed to open MIDI input device");
+    }
+}
+
+MidiDeviceInfo MidiInput::getDeviceInfo() const
+{
+    return toMidiDeviceInfo (_midiInput->getDeviceInfo());
+}
+
+void MidiInput::start()
+{
+    _midiInput->start();
+}
+
+void MidiInput::stop()
+{
+    _midiInput->stop();
+}
+
+void MidiInput::handleIncomingMidiMessage (juce::MidiInput*, const juce::MidiMessage& message)
+{
+    ::midi_input_callback::handleIncomingMidiMessage (*_callback,
+                                                      std::make_unique<juce::MidiMessage> (message));
+}
+
+namespace midi_input
+{
+    rust::Vec<MidiDeviceInfo> getAvailableDevices()
+    {
+        return toMidiDeviceInfos (juce::MidiInput::getAvailableDevices());
+    }
+
+    MidiDeviceInfo getDefaultDevice()
+    {
+        return toMidiDeviceInfo (juce::MidiInput::getDefaultDevice());
+    }
+
+    std::unique_ptr<MidiInput> openDevice (rust::Str identifier, rust::Box<BoxedMidiInputCallback> callback)
+    {
+        return std::make_unique<MidiInput> (identifier, std::move (callback));
+    }
+} // namespace midi_input
+} // namespace cxx_juce
@@ -0,0 +1,84 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::midi_message
+{
+std::unique_ptr<juce::MidiMessage> createMidiMessage (rust::Slice<const rust::u8> data, double timestamp)
+{
+    if (data.empty())
+    {
+        throw std::invalid_argument ("a MIDI message must contain at least one byte");
+    }
+
+    return std::make_unique<juce::MidiMessage> (data.data(),
+                                                static_cast<int> (data.size()),
+                                                timestamp);
+}
+
+std::unique_ptr<juce::MidiMessage> copyMidiMessage (const juce::MidiMessage& message)
+{
+    return std::make_unique<juce::MidiMessage> (message);
+}
+
+rust::Slice<const rust::u8> getRawData (const juce::MidiMessage& message)
+{
+    return { message.getRawData(), static_cast<size_t> (message.getRawDataSize()) };
+}
+
+std::unique_ptr<juce::MidiMessage> midiClock()
+{
+    return std::make_unique<juce::MidiMessage> (juce::MidiMessage::midiClock());
+}
+
+std::unique_ptr<juce::MidiMessage> midiStart()
+{
+    return std::make_unique<juce::MidiMessage> (juce::MidiMessage::midiStart());
+}
+
+std::unique_ptr<juce::MidiMessage> midiStop()
+{
+    return std::make_unique<juce::MidiMessage> (juce::MidiMessage::midiStop());
+}
+
+std::unique_ptr<juce::MidiMessage> midiContinue()
+{
+    return std::make_unique<juce::MidiMessage> (juce::MidiMessage::midiContinue());
+}
+
+std::unique_ptr<juce::MidiMessage> songPositionPointer (rust::i32 positionInMidiBeats)
+{
+    return std::make_unique<juce::MidiMessage> (juce::MidiMessage::songPositionPointer (positionInMidiBeats));
+}
+
+std::unique_ptr<juce::MidiMessage> quarterFrame (rust::i32 sequenceNumber, rust::i32 value)
+{
+    return std::make_unique<juce::MidiMessage> (juce::MidiMessage::quarterFrame (sequenceNumber, value));
+}
+
+std::unique_ptr<juce::MidiMessage> fullFrame (rust::i32 hours,
+                                              rust::i32 minutes,
+                                              rust::i32 seconds,
+                                              rust::i32 frames,
+                                              rust::i32 timecodeType)
+{
+    return std::make_unique<juce::MidiMessage> (juce::MidiMessage::fullFrame (
+        hours,
+        minutes,
+        seconds,
+        frames,
+        static_cast<juce::MidiMessage::SmpteTimecodeType> (timecodeType)));
+}
+
+std::array<rust::i32, 5> getFullFrameParameters (const juce::MidiMessage& message)
+{
+    int hours {}, minutes {}, seconds {}, frames {};
+    juce::MidiMessage::SmpteTimecodeType timecodeType {};
+    message.getFullFrameParameters (hours, minutes, seconds, frames, timecodeType);
+
+    return { hours, minutes, seconds, frames, static_cast<rust::i32> (timecodeType) };
+}
+
+std::unique_ptr<juce::MidiMessage> controllerEvent (rust::i32 channel, rust::i32 controllerType, rust::i32 value)
+{
+    return std::make_unique<juce::MidiMessage> (juce::MidiMessage::controllerEvent (channel, controllerType, value));
+}
+} // namespace cxx_juce::midi_message
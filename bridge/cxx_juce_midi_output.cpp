@@ -0,0 +1,30 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::midi_output
+{
+rust::Vec<MidiDeviceInfo> getAvailableDevices()
+{
+    return toMidiDeviceInfos (juce::MidiOutput::getAvailableDevices());
+}
+
+MidiDeviceInfo getDefaultDevice()
+{
+    return toMidiDeviceInfo (juce::MidiOutput::getDefaultDevice());
+}
+
+std::unique_ptr<juce::MidiOutput> openDevice (rust::Str identifier)
+{
+    auto midiOutput = juce::MidiOutput::openDevice (static_cast<std::string> (identifier));
+    if (! midiOutput)
+    {
+        throw std::runtime_error ("failed to open MIDI output device");
+    }
+
+    return midiOutput;
+}
+
+MidiDeviceInfo getDeviceInfo (const juce::MidiOutput& midiOutput)
+{
+    return toMidiDeviceInfo (midiOutput.getDeviceInfo());
+}
+} // namespace cxx_juce::midi_output
@@ -0,0 +1,38 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::midi_rpn
+{
+std::unique_ptr<juce::MidiRPNDetector> createMidiRPNDetector()
+{
+    return std::make_unique<juce::MidiRPNDetector>();
+}
+
+bool parseControllerMessage (juce::MidiRPNDetector& detector,
+                             rust::i32 channel,
+                             rust::i32 controllerNumber,
+                             rust::i32 controllerValue,
+                             MidiRPNMessage& result)
+{
+    juce::MidiRPNMessage message {};
+    if (! detector.parseControllerMessage (channel, controllerNumber, controllerValue, message))
+    {
+        return false;
+    }
+
+    result.channel = message.channel;
+    result.parameter_number = message.parameterNumber;
+    result.value = message.value;
+    result.is_nrpn = message.isNRPN;
+    result.is_14_bit_value = message.is14BitValue;
+    return true;
+}
+
+std::unique_ptr<juce::MidiBuffer> generate (MidiRPNMessage message)
+{
+    return std::make_unique<juce::MidiBuffer> (juce::MidiRPNGenerator::generate (message.channel,
+                                                                                 message.parameter_number,
+                                                                                 message.value,
+                                                                                 message.is_nrpn,
+                                                                                 message.is_14_bit_value));
+}
+} // namespace cxx_juce::midi_rpn
@@ -0,0 +1,32 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+MidiThruHandle::MidiThruHandle (juce::AudioDeviceManager& audioDeviceManager, rust::Box<MidiThru> midiThru)
+    : _audioDeviceManager (audioDeviceManager)
+    , _midiThru (std::move (midiThru))
+{
+    _audioDeviceManager.addMidiInputDeviceCallback ({}, this);
+}
+
+MidiThruHandle::~MidiThruHandle()
+{
+    _audioDeviceManager.removeMidiInputDeviceCallback ({}, this);
+}
+
+void MidiThruHandle::handleIncomingMidiMessage (juce::MidiInput*, const juce::MidiMessage& message)
+{
+    // The device manager calls this with its MIDI callback lock held, which is also held while the
+    // default output is changed.
+    auto* midiOutput = _audioDeviceManager.getDefaultMidiOutput();
+    if (! midiOutput)
+    {
+        return;
+    }
+
+    if (const auto result = ::midi_thru::process (*_midiThru, std::make_unique<juce::MidiMessage> (message)))
+    {
+        midiOutput->sendMessageNow (*result);
+    }
+}
+} // namespace cxx_juce
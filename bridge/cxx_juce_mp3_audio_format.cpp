@@ -0,0 +1,23 @@
+#include "cxx_juce_bindings.h"
+
+#if JUCE_USE_MP3AUDIOFORMAT
+
+namespace cxx_juce::mp3_audio_format
+{
+std::unique_ptr<juce::MP3AudioFormat> createMP3AudioFormat()
+{
+    return std::make_unique<juce::MP3AudioFormat>();
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::MP3AudioFormat& format, rust::Str path)
+{
+    return cxx_juce::createReaderForFile (format, path);
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::MP3AudioFormat& format, rust::Box<BoxedInputStream> stream)
+{
+    return cxx_juce::createReaderForStream (format, std::move (stream));
+}
+} // namespace cxx_juce::mp3_audio_format
+
+#endif
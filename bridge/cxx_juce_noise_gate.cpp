@@ -0,0 +1,19 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::noise_gate
+{
+std::unique_ptr<NoiseGate> createNoiseGate()
+{
+    return std::make_unique<NoiseGate>();
+}
+
+void prepare (NoiseGate& noiseGate, const ProcessSpec& spec)
+{
+    noiseGate.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (NoiseGate& noiseGate, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (noiseGate, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::noise_gate
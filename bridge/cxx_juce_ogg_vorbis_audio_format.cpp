@@ -0,0 +1,49 @@
+#include "cxx_juce_bindings.h"
+
+#if JUCE_USE_OGGVORBIS
+
+namespace cxx_juce::ogg_vorbis_audio_format
+{
+std::unique_ptr<juce::OggVorbisAudioFormat> createOggVorbisAudioFormat()
+{
+    return std::make_unique<juce::OggVorbisAudioFormat>();
+}
+
+rust::Vec<rust::String> getQualityOptions (juce::OggVorbisAudioFormat& format)
+{
+    return toRustStrings (format.getQualityOptions());
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::OggVorbisAudioFormat& format, rust::Str path)
+{
+    return cxx_juce::createReaderForFile (format, path);
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::OggVorbisAudioFormat& format, rust::Box<BoxedInputStream> stream)
+{
+    return cxx_juce::createReaderForStream (format, std::move (stream));
+}
+
+std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::OggVorbisAudioFormat& format,
+                                                              rust::Str path,
+                                                              double sampleRate,
+                                                              juce::uint32 numChannels,
+                                                              int qualityOptionIndex,
+                                                              rust::Slice<const StringPair> metadata)
+{
+    if (! juce::isPositiveAndBelow (qualityOptionIndex, format.getQualityOptions().size()))
+    {
+        throw std::invalid_argument ("invalid quality option");
+    }
+
+    return cxx_juce::createWriterForFile (format,
+                                          path,
+                                          sampleRate,
+                                          numChannels,
+                                          16,
+                                          toStringPairArray (metadata),
+                                          qualityOptionIndex);
+}
+} // namespace cxx_juce::ogg_vorbis_audio_format
+
+#endif
@@ -0,0 +1,80 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::oscillator
+{
+namespace
+{
+    enum class Waveform
+    {
+        sine,
+        triangle,
+        saw,
+        square,
+    };
+
+    std::function<float (float)> createGenerator (Waveform waveform)
+    {
+        using Constants = juce::MathConstants<float>;
+
+        switch (waveform)
+        {
+            case Waveform::sine:
+                return [] (float x) { return std::sin (x); };
+            case Waveform::triangle:
+                return [] (float x) { return 1.0f - 2.0f * std::abs (x) / Constants::pi; };
+            case Waveform::saw:
+                return [] (float x) { return x / Constants::pi; };
+            case Waveform::square:
+                return [] (float x) { return x < 0.0f ? -1.0f : 1.0f; };
+        }
+
+        throw std::invalid_argument ("invalid waveform");
+    }
+
+    // The generator is called with the phase in the range [-pi, pi], which covers one period of
+    // the wavetable. Samples are linearly interpolated, wrapping around at the end of the table.
+    std::function<float (float)> createWavetableGenerator (std::vector<float> table)
+    {
+        return [table = std::move (table)] (float x)
+        {
+            using Constants = juce::MathConstants<float>;
+
+            const auto size = table.size();
+            const auto position = juce::jlimit (0.0f,
+                                                static_cast<float> (size),
+                                                (x + Constants::pi) / Constants::twoPi * static_cast<float> (size));
+            const auto index = juce::jmin (static_cast<size_t> (position), size - 1);
+            const auto fraction = position - static_cast<float> (index);
+            const auto current = table[index];
+            const auto next = table[(index + 1) % size];
+
+            return current + fraction * (next - current);
+        };
+    }
+} // namespace
+
+std::unique_ptr<Oscillator> createOscillator (int waveform, size_t lookupTableSize)
+{
+    return std::make_unique<Oscillator> (createGenerator (static_cast<Waveform> (waveform)), lookupTableSize);
+}
+
+std::unique_ptr<Oscillator> createWavetableOscillator (rust::Slice<const float> wavetable)
+{
+    if (wavetable.empty())
+    {
+        throw std::invalid_argument ("wavetable is empty");
+    }
+
+    return std::make_unique<Oscillator> (createWavetableGenerator (std::vector<float> (wavetable.begin(), wavetable.end())));
+}
+
+void prepare (Oscillator& oscillator, const ProcessSpec& spec)
+{
+    oscillator.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (Oscillator& oscillator, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (oscillator, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::oscillator
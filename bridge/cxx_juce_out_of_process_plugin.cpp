@@ -0,0 +1,527 @@
+#include "cxx_juce_bindings.h"
+
+#include <array>
+#include <cstring>
+
+namespace cxx_juce
+{
+namespace
+{
+    namespace commands
+    {
+        enum : juce::int32
+        {
+            load,
+            prepare,
+            process,
+            release,
+            quit,
+        };
+    } // namespace commands
+
+    constexpr auto maxChannels = 64;
+    constexpr auto maxStringLength = 256;
+
+    // If the child hasn't been sent anything for this long it checks whether the host is still
+    // running.
+    constexpr auto idleIntervalMs = 1000;
+
+    // While waiting for a response the host checks whether the child is still running this often.
+    constexpr auto crashCheckIntervalMs = 100;
+
+    constexpr auto crashedMessage = "the plugin process has crashed";
+
+    // The host writes a byte to this pipe after each request and the child writes one back after
+    // each response, so that neither has to poll the shared memory.
+    juce::String getPipeName (const juce::String& lockName)
+    {
+        return lockName + "_pipe";
+    }
+
+    // The strings in the shared memory aren't necessarily terminated.
+    juce::String readString (const char* source)
+    {
+        return juce::String::fromUTF8 (source, static_cast<int> (strnlen (source, maxStringLength)));
+    }
+} // namespace
+
+// The start of the memory mapped file, followed by the audio channels. The host increments
+// `request` after filling in the command and its arguments and wakes the child through the pipe,
+// and the child sets `response` to match once it has carried the command out and wakes the host.
+struct OutOfProcessPlugin::SharedState
+{
+    std::atomic<juce::uint32> request;
+    std::atomic<juce::uint32> response;
+    juce::int32 command;
+    juce::int32 succeeded;
+    double sampleRate;
+    juce::int32 blockSize;
+    juce::int32 maximumBlockSize;
+    juce::int32 numSamples;
+    juce::int32 numInputChannels;
+    juce::int32 numOutputChannels;
+    char name[maxStringLength];
+    char error[maxStringLength];
+
+    static size_t getSize (size_t maximumBlockSize)
+    {
+        return sizeof (SharedState) + maxChannels * maximumBlockSize * sizeof (float);
+    }
+
+    float* getChannel (int channel, int maximumBlockSizeToUse)
+    {
+        return reinterpret_cast<float*> (this + 1) + static_cast<size_t> (channel) * static_cast<size_t> (maximumBlockSizeToUse);
+    }
+
+    int getNumChannels() const
+    {
+        return juce::jmax (numInputChannels, numOutputChannels);
+    }
+};
+
+static_assert (std::atomic<juce::uint32>::is_always_lock_free);
+
+OutOfProcessPlugin::OutOfProcessPlugin (juce::StringArray command,
+                                        const PluginDescription& description,
+                                        double sampleRate,
+                                        size_t blockSize,
+                                        int timeoutMs)
+    : _sharedFile (".shm")
+    , _hostLock (_sharedFile.getFile().getFileNameWithoutExtension())
+    , _timeoutMs (timeoutMs)
+{
+    if (blockSize == 0 || blockSize > static_cast<size_t> (std::numeric_limits<juce::int32>::max()))
+    {
+        throw std::invalid_argument ("the block size must be between 1 and " + std::to_string (std::numeric_limits<juce::int32>::max()));
+    }
+
+    _maximumBlockSize = static_cast<int> (blockSize);
+    _blockSize = _maximumBlockSize;
+
+    // The child treats the lock becoming free as the host having gone away.
+    if (! _hostLock.enter (0))
+    {
+        throw std::runtime_error ("failed to acquire the host lock");
+    }
+
+    const auto lockName = _sharedFile.getFile().getFileNameWithoutExtension();
+
+    if (! _pipe.createNewPipe (getPipeName (lockName), true))
+    {
+        throw std::runtime_error ("failed to create the pipe");
+    }
+
+    const auto size = SharedState::getSize (blockSize);
+
+    {
+        juce::FileOutputStream stream (_sharedFile.getFile());
+
+        if (stream.failedToOpen() || ! stream.writeRepeatedByte (0, size))
+        {
+            throw std::runtime_error ("failed to create the shared memory");
+        }
+    }
+
+    _mappedFile = std::make_unique<juce::MemoryMappedFile> (_sharedFile.getFile(), juce::MemoryMappedFile::readWrite, false);
+
+    if (_mappedFile->getData() == nullptr || _mappedFile->getSize() < size)
+    {
+        throw std::runtime_error ("failed to map the shared memory");
+    }
+
+    _state = new (_mappedFile->getData()) SharedState {};
+    _state->sampleRate = sampleRate;
+    _state->blockSize = _blockSize;
+    _state->maximumBlockSize = _maximumBlockSize;
+
+    const juce::TemporaryFile descriptionFile (".xml");
+
+    if (! plugin_description::toJuce (description).createXml()->writeTo (descriptionFile.getFile()))
+    {
+        throw std::runtime_error ("failed to write the plugin description");
+    }
+
+    command.add (_sharedFile.getFile().getFullPathName());
+    command.add (descriptionFile.getFile().getFullPathName());
+    command.add (lockName);
+
+    if (! _process.start (command, 0))
+    {
+        throw std::runtime_error ("failed to start the plugin process");
+    }
+
+    try
+    {
+        sendOrThrow (commands::load);
+    }
+    catch (...)
+    {
+        _process.kill();
+        throw;
+    }
+
+    _name = readString (_state->name);
+    _numInputChannels = juce::jlimit (0, maxChannels, static_cast<int> (_state->numInputChannels));
+    _numOutputChannels = juce::jlimit (0, maxChannels, static_cast<int> (_state->numOutputChannels));
+}
+
+OutOfProcessPlugin::~OutOfProcessPlugin()
+{
+    if (send (commands::quit) && _process.waitForProcessToFinish (_timeoutMs))
+    {
+        return;
+    }
+
+    _process.kill();
+}
+
+rust::String OutOfProcessPlugin::getName() const
+{
+    return _name.toStdString();
+}
+
+size_t OutOfProcessPlugin::getNumInputChannels() const
+{
+    return static_cast<size_t> (_numInputChannels);
+}
+
+size_t OutOfProcessPlugin::getNumOutputChannels() const
+{
+    return static_cast<size_t> (_numOutputChannels);
+}
+
+size_t OutOfProcessPlugin::getMaximumBlockSize() const
+{
+    return static_cast<size_t> (_maximumBlockSize);
+}
+
+bool OutOfProcessPlugin::hasCrashed() const
+{
+    return _crashed;
+}
+
+void OutOfProcessPlugin::prepare (double sampleRate, size_t blockSize)
+{
+    if (blockSize == 0 || blockSize > getMaximumBlockSize())
+    {
+        throw std::invalid_argument ("the block size must be between 1 and " + std::to_string (getMaximumBlockSize()));
+    }
+
+    _blockSize = static_cast<int> (blockSize);
+    _state->sampleRate = sampleRate;
+    _state->blockSize = _blockSize;
+    sendOrThrow (commands::prepare);
+}
+
+void OutOfProcessPlugin::process (juce::AudioSampleBuffer& buffer)
+{
+    const auto requiredChannels = juce::jmax (_numInputChannels, _numOutputChannels);
+
+    if (buffer.getNumChannels() < requiredChannels)
+    {
+        throw std::invalid_argument ("buffer has " + std::to_string (buffer.getNumChannels())
+                                     + " channels but the processor requires " + std::to_string (requiredChannels));
+    }
+
+    if (buffer.getNumSamples() > _blockSize)
+    {
+        throw std::invalid_argument ("buffer has " + std::to_string (buffer.getNumSamples())
+                                     + " samples but the processor was prepared for at most " + std::to_string (_blockSize));
+    }
+
+    for (auto channel = 0; channel < requiredChannels; ++channel)
+    {
+        std::copy_n (buffer.getReadPointer (channel), buffer.getNumSamples(), getChannel (channel));
+    }
+
+    for (auto channel = requiredChannels; channel < buffer.getNumChannels(); ++channel)
+    {
+        buffer.clear (channel, 0, buffer.getNumSamples());
+    }
+
+    _state->numSamples = buffer.getNumSamples();
+
+    try
+    {
+        sendOrThrow (commands::process);
+    }
+    catch (...)
+    {
+        buffer.clear();
+        throw;
+    }
+
+    for (auto channel = 0; channel < requiredChannels; ++channel)
+    {
+        std::copy_n (getChannel (channel), buffer.getNumSamples(), buffer.getWritePointer (channel));
+    }
+}
+
+void OutOfProcessPlugin::releaseResources()
+{
+    sendOrThrow (commands::release);
+}
+
+bool OutOfProcessPlugin::send (int command)
+{
+    if (_crashed)
+    {
+        return false;
+    }
+
+    _state->command = command;
+    const auto request = _state->request.load (std::memory_order_relaxed) + 1;
+    _state->request.store (request, std::memory_order_release);
+
+    const char wake = 0;
+
+    if (_pipe.write (&wake, 1, _timeoutMs) == 1)
+    {
+        const auto deadline = juce::Time::getMillisecondCounter() + static_cast<juce::uint32> (_timeoutMs);
+
+        for (;;)
+        {
+            const auto remainingMs = static_cast<int> (deadline - juce::Time::getMillisecondCounter());
+            char response;
+
+            if (_pipe.read (&response, 1, juce::jlimit (0, crashCheckIntervalMs, remainingMs)) == 1
+                && _state->response.load (std::memory_order_acquire) == request)
+            {
+                return true;
+            }
+
+            if (! _process.isRunning() || remainingMs <= 0)
+            {
+                break;
+            }
+        }
+    }
+
+    _process.kill();
+    _crashed = true;
+    return false;
+}
+
+void OutOfProcessPlugin::sendOrThrow (int command)
+{
+    if (! send (command))
+    {
+        throw std::runtime_error (crashedMessage);
+    }
+
+    if (! _state->succeeded)
+    {
+        throw std::runtime_error (readString (_state->error).toStdString());
+    }
+}
+
+float* OutOfProcessPlugin::getChannel (int channel) const
+{
+    return _state->getChannel (channel, _maximumBlockSize);
+}
+
+namespace out_of_process_plugin
+{
+namespace
+{
+    void copyString (const juce::String& source, char* destination)
+    {
+        source.copyToUTF8 (destination, maxStringLength);
+    }
+
+    juce::PluginDescription readDescription (rust::Str path)
+    {
+        juce::PluginDescription description;
+        const auto xml = juce::parseXML (toFile (path));
+
+        if (xml == nullptr || ! description.loadFromXml (*xml))
+        {
+            throw std::runtime_error ("failed to read the plugin description");
+        }
+
+        return description;
+    }
+
+    class Child
+    {
+    public:
+        Child (juce::AudioPluginFormatManager& manager, OutOfProcessPlugin::SharedState& state)
+            : _manager (manager)
+            , _state (state)
+        {
+        }
+
+        void handle (int command, rust::Str descriptionPath)
+        {
+            switch (command)
+            {
+                case commands::load:
+                    load (readDescription (descriptionPath));
+                    break;
+
+                case commands::prepare:
+                    plugin_instance::prepare (getInstance(), _state.sampleRate, static_cast<size_t> (_state.blockSize));
+                    break;
+
+                case commands::process:
+                    process();
+                    break;
+
+                case commands::release:
+                    getInstance().releaseResources();
+                    break;
+
+                default:
+                    break;
+            }
+        }
+
+    private:
+        void load (const juce::PluginDescription& description)
+        {
+            juce::String errorMessage;
+            _instance = _manager.createPluginInstance (description, _state.sampleRate, _state.blockSize, errorMessage);
+
+            if (_instance == nullptr)
+            {
+                throw std::runtime_error (errorMessage.isEmpty() ? "failed to create plugin instance" : errorMessage.toStdString());
+            }
+
+            if (juce::jmax (_instance->getTotalNumInputChannels(), _instance->getTotalNumOutputChannels()) > maxChannels)
+            {
+                throw std::runtime_error ("the plugin has more than " + std::to_string (maxChannels) + " channels");
+            }
+
+            copyString (_instance->getName(), _state.name);
+            _state.numInputChannels = _instance->getTotalNumInputChannels();
+            _state.numOutputChannels = _instance->getTotalNumOutputChannels();
+        }
+
+        void process()
+        {
+            std::array<float*, maxChannels> channels;
+
+            for (auto channel = 0; channel < maxChannels; ++channel)
+            {
+                channels[static_cast<size_t> (channel)] = _state.getChannel (channel, _state.maximumBlockSize);
+            }
+
+            juce::AudioSampleBuffer buffer (channels.data(), _state.getNumChannels(), _state.numSamples);
+            _midi.clear();
+            audio_processor::process (getInstance(), buffer, _midi);
+        }
+
+        juce::AudioPluginInstance& getInstance()
+        {
+            if (_instance == nullptr)
+            {
+                throw std::runtime_error ("no plugin has been loaded");
+            }
+
+            return *_instance;
+        }
+
+        juce::AudioPluginFormatManager& _manager;
+        OutOfProcessPlugin::SharedState& _state;
+        std::unique_ptr<juce::AudioPluginInstance> _instance;
+        juce::MidiBuffer _midi;
+    };
+} // namespace
+
+std::unique_ptr<OutOfProcessPlugin> createOutOfProcessPlugin (rust::Slice<const rust::String> command,
+                                                              const PluginDescription& description,
+                                                              double sampleRate,
+                                                              size_t blockSize,
+                                                              int timeoutMs)
+{
+    juce::StringArray arguments;
+
+    for (const auto& argument : command)
+    {
+        arguments.add (static_cast<std::string> (argument));
+    }
+
+    return std::make_unique<OutOfProcessPlugin> (std::move (arguments), description, sampleRate, blockSize, timeoutMs);
+}
+
+void runChild (juce::AudioPluginFormatManager& manager, rust::Slice<const rust::String> args)
+{
+    if (args.size() != 3)
+    {
+        throw std::invalid_argument ("expected a shared memory file, a plugin description file, and a lock name");
+    }
+
+    juce::MemoryMappedFile mappedFile (toFile (args[0]), juce::MemoryMappedFile::readWrite, false);
+
+    if (mappedFile.getData() == nullptr || mappedFile.getSize() < sizeof (OutOfProcessPlugin::SharedState))
+    {
+        throw std::runtime_error ("failed to map the shared memory");
+    }
+
+    auto& state = *static_cast<OutOfProcessPlugin::SharedState*> (mappedFile.getData());
+
+    if (mappedFile.getSize() < OutOfProcessPlugin::SharedState::getSize (static_cast<size_t> (state.maximumBlockSize)))
+    {
+        throw std::runtime_error ("the shared memory is too small");
+    }
+
+    const juce::String lockName (static_cast<std::string> (args[2]));
+    juce::InterProcessLock hostLock (lockName);
+    juce::NamedPipe pipe;
+
+    if (! pipe.openExisting (getPipeName (lockName)))
+    {
+        throw std::runtime_error ("failed to open the pipe");
+    }
+
+    Child child (manager, state);
+
+    auto lastRequest = state.response.load (std::memory_order_acquire);
+
+    for (;;)
+    {
+        char wake;
+
+        if (pipe.read (&wake, 1, idleIntervalMs) != 1)
+        {
+            if (hostLock.enter (0))
+            {
+                return;
+            }
+
+            continue;
+        }
+
+        const auto request = state.request.load (std::memory_order_acquire);
+
+        if (request == lastRequest)
+        {
+            continue;
+        }
+
+        lastRequest = request;
+
+        const auto command = state.command;
+        state.succeeded = 1;
+
+        try
+        {
+            child.handle (command, args[1]);
+        }
+        catch (const std::exception& e)
+        {
+            state.succeeded = 0;
+            copyString (juce::String::fromUTF8 (e.what()), state.error);
+        }
+
+        state.response.store (request, std::memory_order_release);
+        pipe.write (&wake, 1, idleIntervalMs);
+
+        if (command == commands::quit)
+        {
+            return;
+        }
+    }
+}
+} // namespace out_of_process_plugin
+} // namespace cxx_juce
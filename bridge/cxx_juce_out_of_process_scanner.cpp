@@ -0,0 +1,118 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::out_of_process_scanner
+{
+namespace
+{
+    constexpr auto pluginsTag = "PLUGINS";
+
+    // Scans each plugin by running the command with the format name, the file or identifier to
+    // scan, and a file to write the results to appended. The results are passed through a file
+    // rather than the child's output so that a child which hangs can be killed after the timeout
+    // without the host blocking on a full pipe.
+    class ChildProcessScanner : public juce::KnownPluginList::CustomScanner
+    {
+    public:
+        ChildProcessScanner (juce::StringArray command, int timeoutMs)
+            : _command (std::move (command))
+            , _timeoutMs (timeoutMs)
+        {
+        }
+
+        bool findPluginTypesFor (juce::AudioPluginFormat& format,
+                                 juce::OwnedArray<juce::PluginDescription>& result,
+                                 const juce::String& fileOrIdentifier) override
+        {
+            const juce::TemporaryFile output (".xml");
+
+            auto arguments = _command;
+            arguments.add (format.getName());
+            arguments.add (fileOrIdentifier);
+            arguments.add (output.getFile().getFullPathName());
+
+            juce::ChildProcess process;
+
+            if (! process.start (arguments, 0))
+            {
+                return false;
+            }
+
+            if (! process.waitForProcessToFinish (_timeoutMs))
+            {
+                process.kill();
+                return false;
+            }
+
+            if (process.getExitCode() != 0)
+            {
+                return false;
+            }
+
+            const auto xml = juce::parseXML (output.getFile());
+
+            if (xml == nullptr || ! xml->hasTagName (pluginsTag))
+            {
+                return false;
+            }
+
+            for (const auto* child : xml->getChildIterator())
+            {
+                auto description = std::make_unique<juce::PluginDescription>();
+
+                if (description->loadFromXml (*child))
+                {
+                    result.add (std::move (description));
+                }
+            }
+
+            return true;
+        }
+
+    private:
+        juce::StringArray _command;
+        int _timeoutMs;
+    };
+} // namespace
+
+void setChildProcessScanner (juce::KnownPluginList& list, rust::Slice<const rust::String> command, int timeoutMs)
+{
+    juce::StringArray arguments;
+
+    for (const auto& argument : command)
+    {
+        arguments.add (static_cast<std::string> (argument));
+    }
+
+    list.setCustomScanner (std::make_unique<ChildProcessScanner> (std::move (arguments), timeoutMs));
+}
+
+void clearCustomScanner (juce::KnownPluginList& list)
+{
+    list.setCustomScanner (nullptr);
+}
+
+void runChildScan (const juce::AudioPluginFormatManager& manager, rust::Slice<const rust::String> args)
+{
+    if (args.size() != 3)
+    {
+        throw std::invalid_argument ("expected a format name, a file or identifier, and an output file");
+    }
+
+    auto& format = audio_plugin_format_manager::getFormat (manager, static_cast<std::string> (args[0]));
+
+    juce::OwnedArray<juce::PluginDescription> found;
+    format.findAllTypesForFile (found, static_cast<std::string> (args[1]));
+
+    juce::XmlElement xml (pluginsTag);
+
+    for (const auto* description : found)
+    {
+        xml.addChildElement (description->createXml().release());
+    }
+
+    if (! xml.writeTo (toFile (args[2])))
+    {
+        throw std::runtime_error ("failed to write scan results");
+    }
+}
+} // namespace cxx_juce::out_of_process_scanner
@@ -0,0 +1,9 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::output_stream
+{
+bool write (juce::OutputStream& stream, rust::Slice<const juce::uint8> buffer)
+{
+    return buffer.empty() || stream.write (buffer.data(), buffer.size());
+}
+} // namespace cxx_juce::output_stream
@@ -0,0 +1,108 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::dsp
+{
+Oversampling::Oversampling (size_t factor, int filterType)
+    : _factor (factor)
+    , _filterType (static_cast<juce::dsp::Oversampling<float>::FilterType> (filterType))
+{
+    recreate();
+}
+
+void Oversampling::setMaximumQuality (bool maximumQuality)
+{
+    _maximumQuality = maximumQuality;
+    recreate();
+}
+
+void Oversampling::setUsingIntegerLatency (bool useIntegerLatency)
+{
+    _useIntegerLatency = useIntegerLatency;
+    recreate();
+}
+
+float Oversampling::getLatencyInSamples() const
+{
+    return _oversampling->getLatencyInSamples();
+}
+
+size_t Oversampling::getOversamplingFactor() const
+{
+    return _oversampling->getOversamplingFactor();
+}
+
+void Oversampling::prepare (const ProcessSpec& spec)
+{
+    _spec = toProcessSpec (spec);
+    _prepared = true;
+    recreate();
+}
+
+void Oversampling::reset()
+{
+    _oversampling->reset();
+}
+
+juce::AudioSampleBuffer& Oversampling::processSamplesUp (const juce::AudioSampleBuffer& input)
+{
+    const auto numChannels = limitNumChannels (input);
+    const auto upsampled = _oversampling->processSamplesUp (juce::dsp::AudioBlock<const float> (input).getSubsetChannelBlock (0, numChannels));
+
+    _upsampledChannels.resize (numChannels);
+
+    for (size_t channel = 0; channel < numChannels; ++channel)
+    {
+        _upsampledChannels[channel] = upsampled.getChannelPointer (channel);
+    }
+
+    _upsampled.setDataToReferTo (_upsampledChannels.data(),
+                                 static_cast<int> (numChannels),
+                                 static_cast<int> (upsampled.getNumSamples()));
+
+    return _upsampled;
+}
+
+void Oversampling::processSamplesDown (juce::AudioSampleBuffer& output)
+{
+    const auto numChannels = juce::jmin (limitNumChannels (output), _upsampledChannels.size());
+    _oversampling->processSamplesDown (juce::dsp::AudioBlock<float> (output).getSubsetChannelBlock (0, numChannels));
+}
+
+void Oversampling::recreate()
+{
+    _oversampling = std::make_unique<juce::dsp::Oversampling<float>> (juce::jmax (static_cast<size_t> (_spec.numChannels), static_cast<size_t> (1)),
+                                                                      _factor,
+                                                                      _filterType,
+                                                                      _maximumQuality,
+                                                                      _useIntegerLatency);
+    _upsampledChannels.clear();
+
+    if (_prepared)
+    {
+        _oversampling->initProcessing (static_cast<size_t> (_spec.maximumBlockSize));
+    }
+}
+
+size_t Oversampling::limitNumChannels (const juce::AudioSampleBuffer& buffer) const
+{
+    if (! _prepared)
+    {
+        throw std::runtime_error ("the oversampling hasn't been prepared");
+    }
+
+    if (buffer.getNumSamples() > static_cast<int> (_spec.maximumBlockSize))
+    {
+        throw std::invalid_argument ("the buffer is larger than the block size the oversampling was prepared for");
+    }
+
+    return juce::jmin (static_cast<size_t> (buffer.getNumChannels()), static_cast<size_t> (_spec.numChannels));
+}
+} // namespace cxx_juce::dsp
+
+namespace cxx_juce::oversampling
+{
+std::unique_ptr<dsp::Oversampling> createOversampling (size_t factor, int filterType)
+{
+    return std::make_unique<dsp::Oversampling> (factor, filterType);
+}
+} // namespace cxx_juce::oversampling
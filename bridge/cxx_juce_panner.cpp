@@ -0,0 +1,40 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::panner
+{
+namespace
+{
+    // The panner always produces a stereo pair, so it only ever processes the first two channels.
+    juce::dsp::ProcessSpec toStereoProcessSpec (const ProcessSpec& spec)
+    {
+        auto juceSpec = dsp::toProcessSpec (spec);
+        juceSpec.numChannels = 2;
+        return juceSpec;
+    }
+} // namespace
+
+std::unique_ptr<Panner> createPanner()
+{
+    return std::make_unique<Panner>();
+}
+
+void setRule (Panner& panner, int rule)
+{
+    panner.setRule (static_cast<juce::dsp::PannerRule> (rule));
+}
+
+void prepare (Panner& panner, const ProcessSpec& spec)
+{
+    panner.prepare (toStereoProcessSpec (spec));
+}
+
+void process (Panner& panner, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    if (buffer.getNumChannels() < 2)
+    {
+        return;
+    }
+
+    dsp::processReplacing (panner, buffer, toStereoProcessSpec (spec));
+}
+} // namespace cxx_juce::panner
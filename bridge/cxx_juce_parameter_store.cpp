@@ -0,0 +1,50 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::parameter_store
+{
+namespace
+{
+    // The same layout as the XML of an AudioProcessorValueTreeState's state.
+    const juce::Identifier parameterTag { "PARAM" };
+    const juce::Identifier idAttribute { "id" };
+    const juce::Identifier valueAttribute { "value" };
+} // namespace
+
+rust::String createState (rust::Str stateType, rust::Slice<const rust::String> ids, rust::Slice<const float> values)
+{
+    if (ids.size() != values.size())
+    {
+        throw std::invalid_argument ("every parameter needs a value");
+    }
+
+    juce::XmlElement state (juce::String (static_cast<std::string> (stateType)));
+
+    for (size_t index = 0; index < ids.size(); ++index)
+    {
+        auto* parameter = state.createNewChildElement (parameterTag);
+        parameter->setAttribute (idAttribute, juce::String (static_cast<std::string> (ids[index])));
+        parameter->setAttribute (valueAttribute, values[index]);
+    }
+
+    return state.toString().toStdString();
+}
+
+void parseState (rust::Str xml, rust::Str stateType, rust::Vec<rust::String>& ids, rust::Vec<float>& values)
+{
+    const auto state = juce::parseXML (juce::String (static_cast<std::string> (xml)));
+
+    if (state == nullptr || ! state->hasTagName (juce::String (static_cast<std::string> (stateType))))
+    {
+        throw std::invalid_argument ("expected a " + static_cast<std::string> (stateType) + " element");
+    }
+
+    for (const auto* parameter : state->getChildWithTagNameIterator (parameterTag))
+    {
+        if (parameter->hasAttribute (idAttribute) && parameter->hasAttribute (valueAttribute))
+        {
+            ids.push_back (parameter->getStringAttribute (idAttribute).toStdString());
+            values.push_back (static_cast<float> (parameter->getDoubleAttribute (valueAttribute)));
+        }
+    }
+}
+} // namespace cxx_juce::parameter_store
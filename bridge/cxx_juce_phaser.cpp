@@ -0,0 +1,19 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::phaser
+{
+std::unique_ptr<Phaser> createPhaser()
+{
+    return std::make_unique<Phaser>();
+}
+
+void prepare (Phaser& phaser, const ProcessSpec& spec)
+{
+    phaser.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (Phaser& phaser, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (phaser, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::phaser
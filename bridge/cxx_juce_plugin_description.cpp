@@ -0,0 +1,55 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::plugin_description
+{
+juce::PluginDescription toJuce (const PluginDescription& description)
+{
+    juce::PluginDescription result;
+    result.name = static_cast<std::string> (description.name);
+    result.descriptiveName = static_cast<std::string> (description.descriptive_name);
+    result.pluginFormatName = static_cast<std::string> (description.plugin_format_name);
+    result.category = static_cast<std::string> (description.category);
+    result.manufacturerName = static_cast<std::string> (description.manufacturer_name);
+    result.version = static_cast<std::string> (description.version);
+    result.fileOrIdentifier = static_cast<std::string> (description.file_or_identifier);
+    result.uniqueId = description.unique_id;
+    result.deprecatedUid = description.deprecated_uid;
+    result.isInstrument = description.is_instrument;
+    result.numInputChannels = static_cast<int> (description.num_input_channels);
+    result.numOutputChannels = static_cast<int> (description.num_output_channels);
+    result.hasSharedContainer = description.has_shared_container;
+    return result;
+}
+
+PluginDescription fromJuce (const juce::PluginDescription& description)
+{
+    return {
+        description.name.toStdString(),
+        description.descriptiveName.toStdString(),
+        description.pluginFormatName.toStdString(),
+        description.category.toStdString(),
+        description.manufacturerName.toStdString(),
+        description.version.toStdString(),
+        description.fileOrIdentifier.toStdString(),
+        description.uniqueId,
+        description.deprecatedUid,
+        description.isInstrument,
+        static_cast<size_t> (juce::jmax (0, description.numInputChannels)),
+        static_cast<size_t> (juce::jmax (0, description.numOutputChannels)),
+        description.hasSharedContainer,
+    };
+}
+
+rust::Vec<PluginDescription> fromJuce (const juce::Array<juce::PluginDescription>& descriptions)
+{
+    rust::Vec<PluginDescription> result;
+    result.reserve (static_cast<size_t> (descriptions.size()));
+
+    for (const auto& description : descriptions)
+    {
+        result.push_back (fromJuce (description));
+    }
+
+    return result;
+}
+} // namespace cxx_juce::plugin_description
@@ -0,0 +1,42 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::plugin_directory_scanner
+{
+std::unique_ptr<juce::PluginDirectoryScanner> createPluginDirectoryScanner (juce::KnownPluginList& list,
+                                                                           const juce::AudioPluginFormatManager& manager,
+                                                                           rust::Str formatName,
+                                                                           rust::Slice<const rust::String> paths,
+                                                                           bool recursive,
+                                                                           rust::Str deadMansPedalFile)
+{
+    auto& format = audio_plugin_format_manager::getFormat (manager, static_cast<std::string> (formatName));
+    juce::FileSearchPath searchPath;
+
+    for (const auto& path : paths)
+    {
+        searchPath.add (toFile (static_cast<std::string> (path)));
+    }
+
+    const auto pedal = deadMansPedalFile.empty() ? juce::File() : toFile (deadMansPedalFile);
+
+    return std::make_unique<juce::PluginDirectoryScanner> (list, format, searchPath, recursive, pedal, false);
+}
+
+bool scanNextFile (juce::PluginDirectoryScanner& scanner, bool dontRescanIfAlreadyInList, rust::String& nameOfPluginBeingScanned)
+{
+    juce::String name;
+    const auto moreRemaining = scanner.scanNextFile (dontRescanIfAlreadyInList, name);
+    nameOfPluginBeingScanned = name.toStdString();
+    return moreRemaining;
+}
+
+rust::String getNextPluginFileThatWillBeScanned (const juce::PluginDirectoryScanner& scanner)
+{
+    return scanner.getNextPluginFileThatWillBeScanned().toStdString();
+}
+
+rust::Vec<rust::String> getFailedFiles (const juce::PluginDirectoryScanner& scanner)
+{
+    return toRustStrings (scanner.getFailedFiles());
+}
+} // namespace cxx_juce::plugin_directory_scanner
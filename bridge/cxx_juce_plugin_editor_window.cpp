@@ -0,0 +1,71 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+PluginEditorWindow::PluginEditorWindow (juce::AudioProcessorEditor* editor,
+                                        const juce::String& title,
+                                        rust::Box<BoxedPluginEditorWindowListener> listener)
+    : juce::DocumentWindow (title,
+                            juce::LookAndFeel::getDefaultLookAndFeel().findColour (juce::ResizableWindow::backgroundColourId),
+                            juce::DocumentWindow::minimiseButton | juce::DocumentWindow::closeButton)
+    , _listener (std::move (listener))
+{
+    setUsingNativeTitleBar (true);
+    setContentOwned (editor, true);
+    setResizable (editor->isResizable(), false);
+    centreWithSize (getWidth(), getHeight());
+    setVisible (true);
+}
+
+PluginEditorWindow::~PluginEditorWindow()
+{
+    // Delete the editor while the window, and its processor, still exist.
+    clearContentComponent();
+}
+
+void PluginEditorWindow::closeButtonPressed()
+{
+    ::plugin_editor_window_listener::closeButtonPressed (*_listener);
+    setVisible (false);
+}
+
+void PluginEditorWindow::visibilityChanged()
+{
+    juce::DocumentWindow::visibilityChanged();
+    ::plugin_editor_window_listener::visibilityChanged (*_listener, isVisible());
+}
+
+void PluginEditorWindow::moved()
+{
+    juce::DocumentWindow::moved();
+    ::plugin_editor_window_listener::moved (*_listener, getX(), getY());
+}
+
+namespace plugin_editor_window
+{
+    std::unique_ptr<PluginEditorWindow> createPluginEditorWindow (juce::AudioProcessor& processor,
+                                                                  rust::Str title,
+                                                                  rust::Box<BoxedPluginEditorWindowListener> listener)
+    {
+        if (! juce::MessageManager::existsAndIsCurrentThread())
+        {
+            throw std::runtime_error ("editor windows can only be opened on the message thread");
+        }
+
+        // JUCE only allows one editor per processor, and it would be owned by the first window.
+        if (processor.getActiveEditor() != nullptr)
+        {
+            throw std::runtime_error ("the processor's editor is already open");
+        }
+
+        auto* editor = processor.createEditorIfNeeded();
+
+        if (editor == nullptr)
+        {
+            throw std::runtime_error ("the processor has no editor");
+        }
+
+        return std::make_unique<PluginEditorWindow> (editor, static_cast<std::string> (title), std::move (listener));
+    }
+} // namespace plugin_editor_window
+} // namespace cxx_juce
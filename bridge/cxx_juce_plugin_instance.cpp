@@ -0,0 +1,202 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::plugin_instance
+{
+PluginDescription getPluginDescription (const juce::AudioPluginInstance& instance)
+{
+    return plugin_description::fromJuce (instance.getPluginDescription());
+}
+
+rust::String getName (const juce::AudioPluginInstance& instance)
+{
+    return instance.getName().toStdString();
+}
+
+void prepare (juce::AudioPluginInstance& instance, double sampleRate, size_t blockSize)
+{
+    instance.setRateAndBufferSizeDetails (sampleRate, static_cast<int> (blockSize));
+    instance.prepareToPlay (sampleRate, static_cast<int> (blockSize));
+}
+
+void process (juce::AudioPluginInstance& instance, juce::AudioSampleBuffer& buffer, juce::MidiBuffer& midi)
+{
+    audio_processor::process (instance, buffer, midi);
+}
+
+size_t getNumParameters (const juce::AudioPluginInstance& instance)
+{
+    return static_cast<size_t> (instance.getParameters().size());
+}
+
+rust::Vec<ParameterInfo> getParameterInfos (const juce::AudioPluginInstance& instance)
+{
+    return audio_processor::getParameterInfos (instance);
+}
+
+rust::Vec<ParameterGroupEntry> getParameterGroups (const juce::AudioPluginInstance& instance)
+{
+    return audio_processor::getParameterGroups (instance);
+}
+
+namespace
+{
+    // The instance is null once the plugin has been deleted.
+    juce::AudioProcessorParameter& getParameter (const juce::AudioPluginInstance* instance, size_t index)
+    {
+        if (instance == nullptr)
+        {
+            throw std::runtime_error ("the plugin instance has been deleted");
+        }
+
+        return audio_processor::getParameter (*instance, index);
+    }
+} // namespace
+
+float getParameterValue (const juce::AudioPluginInstance* instance, size_t index)
+{
+    return getParameter (instance, index).getValue();
+}
+
+void setParameterValue (const juce::AudioPluginInstance* instance, size_t index, float value)
+{
+    getParameter (instance, index).setValueNotifyingHost (juce::jlimit (0.0f, 1.0f, value));
+}
+
+void beginParameterChangeGesture (const juce::AudioPluginInstance* instance, size_t index)
+{
+    getParameter (instance, index).beginChangeGesture();
+}
+
+void endParameterChangeGesture (const juce::AudioPluginInstance* instance, size_t index)
+{
+    getParameter (instance, index).endChangeGesture();
+}
+
+rust::String getParameterText (const juce::AudioPluginInstance* instance, size_t index, float value)
+{
+    return getParameter (instance, index).getText (juce::jlimit (0.0f, 1.0f, value), 1024).toStdString();
+}
+
+float getParameterValueForText (const juce::AudioPluginInstance* instance, size_t index, rust::Str text)
+{
+    return getParameter (instance, index).getValueForText (static_cast<std::string> (text));
+}
+
+namespace
+{
+    int checkProgramIndex (juce::AudioPluginInstance& instance, size_t index)
+    {
+        if (index >= static_cast<size_t> (instance.getNumPrograms()))
+        {
+            throw std::invalid_argument ("program index " + std::to_string (index) + " out of range");
+        }
+
+        return static_cast<int> (index);
+    }
+} // namespace
+
+rust::Vec<juce::uint8> getStateInformation (juce::AudioPluginInstance& instance)
+{
+    return audio_processor::getStateInformation (instance);
+}
+
+void setStateInformation (juce::AudioPluginInstance& instance, rust::Slice<const juce::uint8> data)
+{
+    audio_processor::setStateInformation (instance, data);
+}
+
+rust::Vec<juce::uint8> getCurrentProgramStateInformation (juce::AudioPluginInstance& instance)
+{
+    return audio_processor::getCurrentProgramStateInformation (instance);
+}
+
+void setCurrentProgramStateInformation (juce::AudioPluginInstance& instance, rust::Slice<const juce::uint8> data)
+{
+    audio_processor::setCurrentProgramStateInformation (instance, data);
+}
+
+void setCurrentProgram (juce::AudioPluginInstance& instance, size_t index)
+{
+    instance.setCurrentProgram (checkProgramIndex (instance, index));
+}
+
+rust::String getProgramName (juce::AudioPluginInstance& instance, size_t index)
+{
+    return instance.getProgramName (checkProgramIndex (instance, index)).toStdString();
+}
+
+std::unique_ptr<AudioProcessorListenerHandle> addListener (juce::AudioPluginInstance& instance,
+                                                           rust::Box<BoxedAudioProcessorListener> listener)
+{
+    return std::make_unique<AudioProcessorListenerHandle> (instance, std::move (listener));
+}
+
+BusesLayout getBusesLayout (const juce::AudioPluginInstance& instance)
+{
+    return buses_layout::fromJuce (instance.getBusesLayout());
+}
+
+bool checkBusesLayoutSupported (const juce::AudioPluginInstance& instance, const BusesLayout& layout)
+{
+    return instance.checkBusesLayoutSupported (buses_layout::toJuce (layout));
+}
+
+void setBusesLayout (juce::AudioPluginInstance& instance, const BusesLayout& layout)
+{
+    if (! instance.setBusesLayout (buses_layout::toJuce (layout)))
+    {
+        throw std::invalid_argument ("the plugin doesn't support the buses layout");
+    }
+}
+
+rust::Vec<BusInfo> getBuses (const juce::AudioPluginInstance& instance, bool isInput)
+{
+    rust::Vec<BusInfo> buses;
+
+    for (auto index = 0; index < instance.getBusCount (isInput); ++index)
+    {
+        const auto* bus = instance.getBus (isInput, index);
+
+        buses.push_back ({
+            bus->getName().toStdString(),
+            audio_channel_set::fromJuce (bus->getCurrentLayout()),
+            bus->isEnabled(),
+            bus->isMain(),
+        });
+    }
+
+    return buses;
+}
+
+void setBusEnabled (juce::AudioPluginInstance& instance, bool isInput, size_t index, bool enabled)
+{
+    auto* bus = instance.getBus (isInput, static_cast<int> (index));
+
+    if (bus == nullptr)
+    {
+        throw std::invalid_argument ("bus index out of range");
+    }
+
+    if (! bus->enable (enabled))
+    {
+        throw std::runtime_error (enabled ? "the plugin can't enable the bus" : "the plugin can't disable the bus");
+    }
+}
+
+std::unique_ptr<AudioPlayHeadHandle> setPlayHead (juce::AudioPluginInstance& instance,
+                                                  rust::Box<BoxedAudioPlayHead> playHead)
+{
+    return std::make_unique<AudioPlayHeadHandle> (instance, std::move (playHead));
+}
+
+std::unique_ptr<PluginEditorWindow> createEditorWindow (const juce::AudioPluginInstance& instance,
+                                                        rust::Str title,
+                                                        rust::Box<BoxedPluginEditorWindowListener> listener)
+{
+    // Opening an editor doesn't change the processing state, so like other hosts this is allowed
+    // while the instance is shared with the audio thread.
+    return plugin_editor_window::createPluginEditorWindow (const_cast<juce::AudioPluginInstance&> (instance),
+                                                           title,
+                                                           std::move (listener));
+}
+} // namespace cxx_juce::plugin_instance
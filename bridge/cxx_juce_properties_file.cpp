@@ -0,0 +1,122 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::properties_file
+{
+std::unique_ptr<PropertiesFileOptions> createOptions (rust::Str applicationName,
+                                                      rust::Str filenameSuffix,
+                                                      rust::Str folderName,
+                                                      rust::Str osxLibrarySubFolder,
+                                                      bool commonToAllUsers,
+                                                      bool ignoreCaseOfKeyNames,
+                                                      bool doNotSave,
+                                                      int millisecondsBeforeSaving,
+                                                      int storageFormat)
+{
+    auto options = std::make_unique<PropertiesFileOptions>();
+    options->applicationName = static_cast<std::string> (applicationName);
+    options->filenameSuffix = static_cast<std::string> (filenameSuffix);
+    options->folderName = static_cast<std::string> (folderName);
+    options->osxLibrarySubFolder = static_cast<std::string> (osxLibrarySubFolder);
+    options->commonToAllUsers = commonToAllUsers;
+    options->ignoreCaseOfKeyNames = ignoreCaseOfKeyNames;
+    options->doNotSave = doNotSave;
+    options->millisecondsBeforeSaving = millisecondsBeforeSaving;
+    options->storageFormat = static_cast<juce::PropertiesFile::StorageFormat> (storageFormat);
+    return options;
+}
+
+rust::String getDefaultFile (const PropertiesFileOptions& options)
+{
+    return options.getDefaultFile().getFullPathName().toStdString();
+}
+
+std::unique_ptr<juce::PropertiesFile> createPropertiesFile (rust::Str path, const PropertiesFileOptions& options)
+{
+    if (path.empty())
+    {
+        return std::make_unique<juce::PropertiesFile> (options);
+    }
+
+    return std::make_unique<juce::PropertiesFile> (toFile (path), options);
+}
+
+rust::String getFile (const juce::PropertiesFile& file)
+{
+    return file.getFile().getFullPathName().toStdString();
+}
+
+bool containsKey (const juce::PropertiesFile& file, rust::Str key)
+{
+    return file.containsKey (static_cast<std::string> (key));
+}
+
+bool getValue (const juce::PropertiesFile& file, rust::Str key, rust::String& value)
+{
+    const auto keyString = juce::String (static_cast<std::string> (key));
+
+    for (const juce::PropertySet* set = &file; set != nullptr; set = set->getFallbackPropertySet())
+    {
+        if (set->containsKey (keyString))
+        {
+            value = set->getValue (keyString).toStdString();
+            return true;
+        }
+    }
+
+    return false;
+}
+
+rust::String getXmlValue (const juce::PropertiesFile& file, rust::Str key)
+{
+    const auto xml = file.getXmlValue (static_cast<std::string> (key));
+    return xml != nullptr ? xml->toString().toStdString() : std::string();
+}
+
+rust::Vec<rust::String> getAllKeys (const juce::PropertiesFile& file)
+{
+    const juce::ScopedLock lock (file.getLock());
+    return toRustStrings (file.getAllProperties().getAllKeys());
+}
+
+void setValue (juce::PropertiesFile& file, rust::Str key, rust::Str value)
+{
+    file.setValue (static_cast<std::string> (key), juce::String (static_cast<std::string> (value)));
+}
+
+void setXmlValue (juce::PropertiesFile& file, rust::Str key, rust::Str xml)
+{
+    const auto element = juce::parseXML (static_cast<std::string> (xml));
+    if (element == nullptr)
+    {
+        throw std::invalid_argument ("the value isn't valid XML");
+    }
+
+    file.setValue (static_cast<std::string> (key), element.get());
+}
+
+void removeValue (juce::PropertiesFile& file, rust::Str key)
+{
+    file.removeValue (static_cast<std::string> (key));
+}
+
+void setFallbackPropertySet (juce::PropertiesFile& file, juce::PropertiesFile& fallback)
+{
+    file.setFallbackPropertySet (&fallback);
+}
+
+void save (juce::PropertiesFile& file)
+{
+    if (!file.save())
+    {
+        throw std::runtime_error ("failed to save " + file.getFile().getFullPathName().toStdString());
+    }
+}
+
+void saveIfNeeded (juce::PropertiesFile& file)
+{
+    if (!file.saveIfNeeded())
+    {
+        throw std::runtime_error ("failed to save " + file.getFile().getFullPathName().toStdString());
+    }
+}
+} // namespace cxx_juce::properties_file
@@ -0,0 +1,29 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::random
+{
+std::unique_ptr<juce::Random> createRandom()
+{
+    return std::make_unique<juce::Random>();
+}
+
+std::unique_ptr<juce::Random> createRandomWithSeed (juce::int64 seed)
+{
+    return std::make_unique<juce::Random> (seed);
+}
+
+std::unique_ptr<juce::Random> copy (const juce::Random& random)
+{
+    return std::make_unique<juce::Random> (random);
+}
+
+juce::Random* getSystemRandom()
+{
+    return &juce::Random::getSystemRandom();
+}
+
+void fillBitsRandomly (juce::Random& random, rust::Slice<juce::uint8> buffer)
+{
+    random.fillBitsRandomly (buffer.data(), buffer.size());
+}
+} // namespace cxx_juce::random
@@ -0,0 +1,73 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+namespace
+{
+    constexpr auto blockSize = 512;
+} // namespace
+
+ResamplingAudioFormatReader::ResamplingAudioFormatReader (std::unique_ptr<juce::AudioFormatReader> reader, double sampleRate)
+    : _sampleRate (sampleRate)
+    , _ratio (reader->sampleRate / sampleRate)
+    , _numChannels (static_cast<int> (reader->numChannels))
+    , _lengthInSamples (static_cast<juce::int64> (std::ceil (static_cast<double> (reader->lengthInSamples) / _ratio)))
+    , _readerSource (reader.release(), true)
+    , _resampler (&_readerSource, false, _numChannels)
+{
+    _resampler.setResamplingRatio (_ratio);
+    _resampler.prepareToPlay (blockSize, _sampleRate);
+}
+
+double ResamplingAudioFormatReader::getSampleRate() const
+{
+    return _sampleRate;
+}
+
+int ResamplingAudioFormatReader::getNumChannels() const
+{
+    return _numChannels;
+}
+
+juce::int64 ResamplingAudioFormatReader::getLengthInSamples() const
+{
+    return _lengthInSamples;
+}
+
+juce::int64 ResamplingAudioFormatReader::getPosition() const
+{
+    return _position;
+}
+
+void ResamplingAudioFormatReader::setPosition (juce::int64 position)
+{
+    _position = juce::jmax (juce::int64 { 0 }, position);
+    _readerSource.setNextReadPosition (static_cast<juce::int64> (static_cast<double> (_position) * _ratio));
+    _resampler.flushBuffers();
+}
+
+void ResamplingAudioFormatReader::read (juce::AudioSampleBuffer& buffer)
+{
+    if (buffer.getNumChannels() < _numChannels)
+    {
+        throw std::invalid_argument ("buffer has fewer channels than the reader");
+    }
+
+    _resampler.getNextAudioBlock (juce::AudioSourceChannelInfo (buffer));
+    _position += buffer.getNumSamples();
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::resampling_audio_format_reader
+{
+std::unique_ptr<ResamplingAudioFormatReader> createResamplingAudioFormatReader (std::unique_ptr<juce::AudioFormatReader> reader,
+                                                                                double sampleRate)
+{
+    if (sampleRate <= 0.0 || reader->sampleRate <= 0.0)
+    {
+        throw std::invalid_argument ("sample rates must be positive");
+    }
+
+    return std::make_unique<ResamplingAudioFormatReader> (std::move (reader), sampleRate);
+}
+} // namespace cxx_juce::resampling_audio_format_reader
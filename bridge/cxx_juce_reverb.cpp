@@ -0,0 +1,58 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::reverb
+{
+std::unique_ptr<juce::Reverb> createReverb()
+{
+    return std::make_unique<juce::Reverb>();
+}
+
+void setParameters (juce::Reverb& reverb, const ReverbParameters& parameters)
+{
+    juce::Reverb::Parameters result;
+    result.roomSize = juce::jlimit (0.0f, 1.0f, parameters.room_size);
+    result.damping = juce::jlimit (0.0f, 1.0f, parameters.damping);
+    result.wetLevel = juce::jlimit (0.0f, 1.0f, parameters.wet_level);
+    result.dryLevel = juce::jlimit (0.0f, 1.0f, parameters.dry_level);
+    result.width = juce::jlimit (0.0f, 1.0f, parameters.width);
+    result.freezeMode = parameters.freeze_mode ? 1.0f : 0.0f;
+
+    reverb.setParameters (result);
+}
+
+ReverbParameters getParameters (const juce::Reverb& reverb)
+{
+    const auto& parameters = reverb.getParameters();
+
+    return { parameters.roomSize,
+             parameters.damping,
+             parameters.wetLevel,
+             parameters.dryLevel,
+             parameters.width,
+             parameters.freezeMode >= 0.5f };
+}
+
+void process (juce::Reverb& reverb, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    const auto numChannels = juce::jmin (static_cast<size_t> (buffer.getNumChannels()), spec.num_channels);
+
+    if (numChannels == 1)
+    {
+        reverb.processMono (buffer.getWritePointer (0), buffer.getNumSamples());
+    }
+    else if (numChannels > 1)
+    {
+        reverb.processStereo (buffer.getWritePointer (0), buffer.getWritePointer (1), buffer.getNumSamples());
+    }
+}
+
+void processStereo (juce::Reverb& reverb, rust::Slice<float> left, rust::Slice<float> right)
+{
+    reverb.processStereo (left.data(), right.data(), static_cast<int> (juce::jmin (left.size(), right.size())));
+}
+
+void processMono (juce::Reverb& reverb, rust::Slice<float> samples)
+{
+    reverb.processMono (samples.data(), static_cast<int> (samples.size()));
+}
+} // namespace cxx_juce::reverb
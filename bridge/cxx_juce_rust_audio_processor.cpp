@@ -0,0 +1,226 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+namespace
+{
+    juce::AudioProcessor::BusesProperties createBuses (const BoxedAudioProcessor& processor)
+    {
+        const auto numInputChannels = static_cast<int> (::rust_audio_processor::getNumInputChannels (processor));
+        const auto numOutputChannels = static_cast<int> (::rust_audio_processor::getNumOutputChannels (processor));
+
+        juce::AudioProcessor::BusesProperties buses;
+
+        if (numInputChannels > 0)
+        {
+            buses = buses.withInput ("Input", juce::AudioChannelSet::canonicalChannelSet (numInputChannels), true);
+        }
+
+        if (numOutputChannels > 0)
+        {
+            buses = buses.withOutput ("Output", juce::AudioChannelSet::canonicalChannelSet (numOutputChannels), true);
+        }
+
+        return buses;
+    }
+} // namespace
+
+RustAudioProcessorParameter::RustAudioProcessorParameter (const ParameterInfo& info, rust::Box<AudioParameter> parameter)
+    : juce::AudioProcessorParameterWithID ({ static_cast<std::string> (info.id), 1 },
+                                           static_cast<std::string> (info.name),
+                                           juce::AudioProcessorParameterWithIDAttributes()
+                                               .withLabel (static_cast<std::string> (info.label))
+                                               .withAutomatable (info.is_automatable)
+                                               .withMeta (info.is_meta_parameter))
+    , _parameter (std::move (parameter))
+    , _defaultValue (info.default_value)
+    , _numSteps (info.num_steps)
+    , _isDiscrete (info.is_discrete)
+    , _isBoolean (info.is_boolean)
+{
+    // Gestures are passed on to the Rust parameter's listeners, and the Rust parameter passes the
+    // gestures and changes it begins to the host through this parameter.
+    addListener (this);
+    ::rust_audio_parameter::setHost (*_parameter, this);
+}
+
+RustAudioProcessorParameter::~RustAudioProcessorParameter()
+{
+    ::rust_audio_parameter::removeHost (*_parameter, this);
+    removeListener (this);
+}
+
+void RustAudioProcessorParameter::parameterValueChanged (int, float)
+{
+    // The Rust parameter notifies its listeners when its value is set.
+}
+
+void RustAudioProcessorParameter::parameterGestureChanged (int, bool gestureIsStarting)
+{
+    ::rust_audio_parameter::gestureChanged (*_parameter, gestureIsStarting);
+}
+
+float RustAudioProcessorParameter::getValue() const
+{
+    return ::rust_audio_parameter::getValue (*_parameter);
+}
+
+void RustAudioProcessorParameter::setValue (float newValue)
+{
+    ::rust_audio_parameter::setValue (*_parameter, newValue);
+}
+
+float RustAudioProcessorParameter::getDefaultValue() const
+{
+    return _defaultValue;
+}
+
+int RustAudioProcessorParameter::getNumSteps() const
+{
+    return _numSteps;
+}
+
+bool RustAudioProcessorParameter::isDiscrete() const
+{
+    return _isDiscrete;
+}
+
+bool RustAudioProcessorParameter::isBoolean() const
+{
+    return _isBoolean;
+}
+
+juce::String RustAudioProcessorParameter::getText (float value, int maximumStringLength) const
+{
+    return juce::String (static_cast<std::string> (::rust_audio_parameter::getText (*_parameter, value))).substring (0, maximumStringLength);
+}
+
+float RustAudioProcessorParameter::getValueForText (const juce::String& text) const
+{
+    return ::rust_audio_parameter::getValueForText (*_parameter, text.toStdString());
+}
+
+RustAudioProcessor::RustAudioProcessor (rust::Box<BoxedAudioProcessor> processor)
+    : juce::AudioProcessor (createBuses (*processor))
+    , _processor (std::move (processor))
+    , _name (static_cast<std::string> (::rust_audio_processor::getName (*_processor)))
+    , _tailLengthSeconds (::rust_audio_processor::getTailLengthSeconds (*_processor))
+    , _acceptsMidi (::rust_audio_processor::acceptsMidi (*_processor))
+    , _producesMidi (::rust_audio_processor::producesMidi (*_processor))
+{
+}
+
+const juce::String RustAudioProcessor::getName() const
+{
+    return _name;
+}
+
+// Hosts can call these from the message thread while the processor is playing, so they take the
+// callback lock, which JUCE holds while processing, to keep the Rust processor's access exclusive.
+void RustAudioProcessor::prepareToPlay (double sampleRate, int maximumExpectedSamplesPerBlock)
+{
+    const juce::ScopedLock lock (getCallbackLock());
+    ::rust_audio_processor::prepareToPlay (*_processor, sampleRate, static_cast<size_t> (juce::jmax (0, maximumExpectedSamplesPerBlock)));
+}
+
+void RustAudioProcessor::releaseResources()
+{
+    const juce::ScopedLock lock (getCallbackLock());
+    ::rust_audio_processor::releaseResources (*_processor);
+}
+
+void RustAudioProcessor::processBlock (juce::AudioBuffer<float>& buffer, juce::MidiBuffer& midiMessages)
+{
+    const juce::ScopedLock lock (getCallbackLock());
+    ::rust_audio_processor::processBlock (*_processor, buffer, midiMessages);
+}
+
+void RustAudioProcessor::reset()
+{
+    const juce::ScopedLock lock (getCallbackLock());
+    ::rust_audio_processor::reset (*_processor);
+}
+
+bool RustAudioProcessor::isBusesLayoutSupported (const BusesLayout& layouts) const
+{
+    // The layout is fixed by the Rust processor's channel counts.
+    return layouts.getMainInputChannels() == getMainBusNumInputChannels()
+           && layouts.getMainOutputChannels() == getMainBusNumOutputChannels();
+}
+
+double RustAudioProcessor::getTailLengthSeconds() const
+{
+    return _tailLengthSeconds;
+}
+
+bool RustAudioProcessor::acceptsMidi() const
+{
+    return _acceptsMidi;
+}
+
+bool RustAudioProcessor::producesMidi() const
+{
+    return _producesMidi;
+}
+
+juce::AudioProcessorEditor* RustAudioProcessor::createEditor()
+{
+    return nullptr;
+}
+
+bool RustAudioProcessor::hasEditor() const
+{
+    return false;
+}
+
+int RustAudioProcessor::getNumPrograms()
+{
+    return 1;
+}
+
+int RustAudioProcessor::getCurrentProgram()
+{
+    return 0;
+}
+
+void RustAudioProcessor::setCurrentProgram (int)
+{
+}
+
+const juce::String RustAudioProcessor::getProgramName (int)
+{
+    return {};
+}
+
+void RustAudioProcessor::changeProgramName (int, const juce::String&)
+{
+}
+
+void RustAudioProcessor::getStateInformation (juce::MemoryBlock& destData)
+{
+    const juce::ScopedLock lock (getCallbackLock());
+    const auto state = ::rust_audio_processor::getState (*_processor);
+    destData.replaceAll (state.data(), state.size());
+}
+
+void RustAudioProcessor::setStateInformation (const void* data, int sizeInBytes)
+{
+    const juce::ScopedLock lock (getCallbackLock());
+    ::rust_audio_processor::setState (*_processor,
+                                      { static_cast<const juce::uint8*> (data), static_cast<size_t> (juce::jmax (0, sizeInBytes)) });
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::rust_audio_processor
+{
+std::unique_ptr<RustAudioProcessor> createRustAudioProcessor (rust::Box<BoxedAudioProcessor> processor)
+{
+    return std::make_unique<RustAudioProcessor> (std::move (processor));
+}
+
+void addParameter (RustAudioProcessor& processor, rust::Box<AudioParameter> parameter)
+{
+    const auto info = ::rust_audio_parameter::getInfo (*parameter);
+    processor.addParameter (new RustAudioProcessorParameter (info, std::move (parameter)));
+}
+} // namespace cxx_juce::rust_audio_processor
@@ -0,0 +1,89 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::smoothed_value
+{
+namespace
+{
+    enum class Smoothing
+    {
+        linear,
+        multiplicative,
+    };
+
+    template <typename SmoothingType>
+    class SmoothedValueImpl : public SmoothedValue
+    {
+    public:
+        explicit SmoothedValueImpl (float initialValue)
+            : _value (initialValue)
+        {
+        }
+
+        void reset (double sampleRate, double rampLengthInSeconds) override
+        {
+            _value.reset (sampleRate, rampLengthInSeconds);
+        }
+
+        void resetSteps (int numSteps) override
+        {
+            _value.reset (numSteps);
+        }
+
+        void setTargetValue (float newValue) override
+        {
+            _value.setTargetValue (newValue);
+        }
+
+        void setCurrentAndTargetValue (float newValue) override
+        {
+            _value.setCurrentAndTargetValue (newValue);
+        }
+
+        float getTargetValue() const override
+        {
+            return _value.getTargetValue();
+        }
+
+        float getCurrentValue() const override
+        {
+            return _value.getCurrentValue();
+        }
+
+        bool isSmoothing() const override
+        {
+            return _value.isSmoothing();
+        }
+
+        float getNextValue() override
+        {
+            return _value.getNextValue();
+        }
+
+        float skip (int numSamples) override
+        {
+            return _value.skip (numSamples);
+        }
+
+        void applyGain (juce::AudioSampleBuffer& buffer) override
+        {
+            _value.applyGain (buffer, buffer.getNumSamples());
+        }
+
+    private:
+        juce::SmoothedValue<float, SmoothingType> _value;
+    };
+} // namespace
+
+std::unique_ptr<SmoothedValue> createSmoothedValue (int smoothing, float initialValue)
+{
+    switch (static_cast<Smoothing> (smoothing))
+    {
+        case Smoothing::linear:
+            return std::make_unique<SmoothedValueImpl<juce::ValueSmoothingTypes::Linear>> (initialValue);
+        case Smoothing::multiplicative:
+            return std::make_unique<SmoothedValueImpl<juce::ValueSmoothingTypes::Multiplicative>> (initialValue);
+    }
+
+    throw std::invalid_argument ("invalid smoothing");
+}
+} // namespace cxx_juce::smoothed_value
@@ -0,0 +1,34 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::state_variable_tpt_filter
+{
+std::unique_ptr<StateVariableTPTFilter> createStateVariableTPTFilter()
+{
+    return std::make_unique<StateVariableTPTFilter>();
+}
+
+void setType (StateVariableTPTFilter& filter, int type)
+{
+    filter.setType (static_cast<juce::dsp::StateVariableTPTFilterType> (type));
+}
+
+int getType (const StateVariableTPTFilter& filter)
+{
+    return static_cast<int> (filter.getType());
+}
+
+void prepare (StateVariableTPTFilter& filter, const ProcessSpec& spec)
+{
+    filter.prepare (dsp::toProcessSpec (spec));
+}
+
+void process (StateVariableTPTFilter& filter, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (filter, buffer, dsp::toProcessSpec (spec));
+}
+
+float processSample (StateVariableTPTFilter& filter, size_t channel, float sample)
+{
+    return filter.processSample (static_cast<int> (channel), sample);
+}
+} // namespace cxx_juce::state_variable_tpt_filter
@@ -0,0 +1,107 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::system_stats
+{
+rust::String getOperatingSystemName()
+{
+    return juce::SystemStats::getOperatingSystemName().toStdString();
+}
+
+bool isOperatingSystem64Bit()
+{
+    return juce::SystemStats::isOperatingSystem64Bit();
+}
+
+rust::String getDeviceDescription()
+{
+    return juce::SystemStats::getDeviceDescription().toStdString();
+}
+
+rust::String getDeviceManufacturer()
+{
+    return juce::SystemStats::getDeviceManufacturer().toStdString();
+}
+
+rust::String getComputerName()
+{
+    return juce::SystemStats::getComputerName().toStdString();
+}
+
+rust::String getLogonName()
+{
+    return juce::SystemStats::getLogonName().toStdString();
+}
+
+rust::String getFullUserName()
+{
+    return juce::SystemStats::getFullUserName().toStdString();
+}
+
+rust::String getUserLanguage()
+{
+    return juce::SystemStats::getUserLanguage().toStdString();
+}
+
+rust::String getUserRegion()
+{
+    return juce::SystemStats::getUserRegion().toStdString();
+}
+
+rust::String getDisplayLanguage()
+{
+    return juce::SystemStats::getDisplayLanguage().toStdString();
+}
+
+rust::String getCpuVendor()
+{
+    return juce::SystemStats::getCpuVendor().toStdString();
+}
+
+rust::String getCpuModel()
+{
+    return juce::SystemStats::getCpuModel().toStdString();
+}
+
+int getCpuSpeedInMegahertz()
+{
+    return juce::SystemStats::getCpuSpeedInMegahertz();
+}
+
+int getNumCpus()
+{
+    return juce::SystemStats::getNumCpus();
+}
+
+int getNumPhysicalCpus()
+{
+    return juce::SystemStats::getNumPhysicalCpus();
+}
+
+int getMemorySizeInMegabytes()
+{
+    return juce::SystemStats::getMemorySizeInMegabytes();
+}
+
+int getPageSize()
+{
+    return juce::SystemStats::getPageSize();
+}
+
+CpuFeatures getCpuFeatures()
+{
+    return {
+        juce::SystemStats::hasMMX(),
+        juce::SystemStats::hasSSE(),
+        juce::SystemStats::hasSSE2(),
+        juce::SystemStats::hasSSE3(),
+        juce::SystemStats::hasSSSE3(),
+        juce::SystemStats::hasSSE41(),
+        juce::SystemStats::hasSSE42(),
+        juce::SystemStats::hasAVX(),
+        juce::SystemStats::hasAVX2(),
+        juce::SystemStats::hasFMA3(),
+        juce::SystemStats::hasAVX512F(),
+        juce::SystemStats::hasNeon(),
+    };
+}
+} // namespace cxx_juce::system_stats
@@ -0,0 +1,56 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::thread_pool
+{
+namespace
+{
+    class ThreadPoolJob : public juce::ThreadPoolJob
+    {
+    public:
+        ThreadPoolJob (rust::Str name, rust::Box<BoxedThreadPoolJob> job)
+            : juce::ThreadPoolJob (static_cast<std::string> (name))
+            , _job (std::move (job))
+        {
+        }
+
+        JobStatus runJob() override
+        {
+            ::thread_pool_job::run (*_job, *this);
+            return jobHasFinished;
+        }
+
+    private:
+        rust::Box<BoxedThreadPoolJob> _job;
+    };
+} // namespace
+
+std::shared_ptr<juce::ThreadPool> createThreadPool (int numThreads)
+{
+    if (numThreads <= 0)
+    {
+        throw std::invalid_argument ("a thread pool needs at least one thread");
+    }
+
+    return std::make_shared<juce::ThreadPool> (numThreads);
+}
+
+void addJob (const std::shared_ptr<juce::ThreadPool>& pool, rust::Str name, rust::Box<BoxedThreadPoolJob> job)
+{
+    pool->addJob (new ThreadPoolJob (name, std::move (job)), true);
+}
+
+int getNumJobs (const std::shared_ptr<juce::ThreadPool>& pool)
+{
+    return pool->getNumJobs();
+}
+
+int getNumThreads (const std::shared_ptr<juce::ThreadPool>& pool)
+{
+    return pool->getNumThreads();
+}
+
+bool removeAllJobs (const std::shared_ptr<juce::ThreadPool>& pool, bool interruptRunningJobs, int timeoutMs)
+{
+    return pool->removeAllJobs (interruptRunningJobs, timeoutMs);
+}
+} // namespace cxx_juce::thread_pool
@@ -0,0 +1,61 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+WriterThread::WriterThread()
+    : juce::TimeSliceThread ("cxx-juce threaded writer")
+{
+    startThread();
+}
+
+WriterThread::~WriterThread()
+{
+    stopThread (1000);
+}
+
+WriterThreadHolder::WriterThreadHolder (std::shared_ptr<juce::TimeSliceThread> thread)
+    : writerThread (std::move (thread))
+{
+    if (writerThread == nullptr)
+    {
+        auto defaultThread = std::make_shared<juce::SharedResourcePointer<WriterThread>>();
+        writerThread = std::shared_ptr<juce::TimeSliceThread> (defaultThread, &defaultThread->getObject());
+    }
+}
+
+ThreadedWriter::ThreadedWriter (std::unique_ptr<juce::AudioFormatWriter> writer,
+                                std::shared_ptr<juce::TimeSliceThread> writerThread,
+                                int numSamplesToBuffer)
+    : WriterThreadHolder (std::move (writerThread))
+    , juce::AudioFormatWriter::ThreadedWriter (writer.get(), *WriterThreadHolder::writerThread, numSamplesToBuffer)
+    , _numChannels (writer->getNumChannels())
+{
+    // The threaded writer now owns the writer.
+    writer.release();
+}
+
+int ThreadedWriter::getNumChannels() const
+{
+    return _numChannels;
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::threaded_writer
+{
+std::unique_ptr<ThreadedWriter> createThreadedWriter (std::unique_ptr<juce::AudioFormatWriter> writer,
+                                                      std::shared_ptr<juce::TimeSliceThread> writerThread,
+                                                      int numSamplesToBuffer)
+{
+    return std::make_unique<ThreadedWriter> (std::move (writer), std::move (writerThread), numSamplesToBuffer);
+}
+
+bool write (ThreadedWriter& writer, const juce::AudioSampleBuffer& buffer)
+{
+    if (buffer.getNumChannels() < writer.getNumChannels())
+    {
+        throw std::invalid_argument ("buffer has fewer channels than the writer");
+    }
+
+    return writer.write (buffer.getArrayOfReadPointers(), buffer.getNumSamples());
+}
+} // namespace cxx_juce::threaded_writer
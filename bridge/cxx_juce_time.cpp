@@ -0,0 +1,77 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::time
+{
+double getMillisecondCounterHiRes()
+{
+    return juce::Time::getMillisecondCounterHiRes();
+}
+
+juce::uint32 getMillisecondCounter()
+{
+    return juce::Time::getMillisecondCounter();
+}
+
+juce::int64 currentTimeMillis()
+{
+    return juce::Time::currentTimeMillis();
+}
+
+juce::int64 getHighResolutionTicks()
+{
+    return juce::Time::getHighResolutionTicks();
+}
+
+juce::int64 getHighResolutionTicksPerSecond()
+{
+    return juce::Time::getHighResolutionTicksPerSecond();
+}
+
+double highResolutionTicksToSeconds (juce::int64 ticks)
+{
+    return juce::Time::highResolutionTicksToSeconds (ticks);
+}
+
+juce::int64 secondsToHighResolutionTicks (double seconds)
+{
+    return juce::Time::secondsToHighResolutionTicks (seconds);
+}
+
+std::array<int, 8> getLocalComponents (juce::int64 millisecondsSinceEpoch)
+{
+    const juce::Time time (millisecondsSinceEpoch);
+
+    return { time.getYear(),
+             time.getMonth(),
+             time.getDayOfMonth(),
+             time.getDayOfWeek(),
+             time.getHours(),
+             time.getMinutes(),
+             time.getSeconds(),
+             time.getMilliseconds() };
+}
+
+int getUtcOffsetSeconds (juce::int64 millisecondsSinceEpoch)
+{
+    return juce::Time (millisecondsSinceEpoch).getUTCOffsetSeconds();
+}
+
+rust::String getTimeZone (juce::int64 millisecondsSinceEpoch)
+{
+    return juce::Time (millisecondsSinceEpoch).getTimeZone().toStdString();
+}
+
+rust::String toString (juce::int64 millisecondsSinceEpoch,
+                       bool includeDate,
+                       bool includeTime,
+                       bool includeSeconds,
+                       bool use24HourClock)
+{
+    return juce::Time (millisecondsSinceEpoch).toString (includeDate, includeTime, includeSeconds, use24HourClock).toStdString();
+}
+
+rust::String toISO8601 (juce::int64 millisecondsSinceEpoch, bool includeDividerCharacters)
+{
+    return juce::Time (millisecondsSinceEpoch).toISO8601 (includeDividerCharacters).toStdString();
+}
+} // namespace cxx_juce::time
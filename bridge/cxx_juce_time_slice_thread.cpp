@@ -0,0 +1,43 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+TimeSliceClient::TimeSliceClient (std::shared_ptr<juce::TimeSliceThread> thread, rust::Box<BoxedTimeSliceClient> client)
+    : _thread (std::move (thread))
+    , _client (std::move (client))
+{
+    _thread->addTimeSliceClient (this);
+}
+
+TimeSliceClient::~TimeSliceClient()
+{
+    // Waits for the client to return if it's currently being called.
+    _thread->removeTimeSliceClient (this);
+}
+
+int TimeSliceClient::useTimeSlice()
+{
+    return ::time_slice_client::useTimeSlice (*_client);
+}
+} // namespace cxx_juce
+
+namespace cxx_juce::time_slice_thread
+{
+std::shared_ptr<juce::TimeSliceThread> createTimeSliceThread (rust::Str name)
+{
+    auto thread = std::make_shared<juce::TimeSliceThread> (static_cast<std::string> (name));
+    thread->startThread();
+    return thread;
+}
+
+int getNumClients (const std::shared_ptr<juce::TimeSliceThread>& thread)
+{
+    return thread->getNumClients();
+}
+
+std::unique_ptr<TimeSliceClient> addClient (std::shared_ptr<juce::TimeSliceThread> thread,
+                                            rust::Box<BoxedTimeSliceClient> client)
+{
+    return std::make_unique<TimeSliceClient> (std::move (thread), std::move (client));
+}
+} // namespace cxx_juce::time_slice_thread
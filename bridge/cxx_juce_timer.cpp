@@ -0,0 +1,27 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+TimerHandle::TimerHandle (rust::Box<BoxedTimerCallback> callback)
+    : _callback (std::move (callback))
+{
+}
+
+TimerHandle::~TimerHandle()
+{
+    stopTimer();
+}
+
+void TimerHandle::timerCallback()
+{
+    ::timer_callback::call (*_callback);
+}
+
+namespace timer
+{
+std::unique_ptr<TimerHandle> createTimer (rust::Box<BoxedTimerCallback> callback)
+{
+    return std::make_unique<TimerHandle> (std::move (callback));
+}
+} // namespace timer
+} // namespace cxx_juce
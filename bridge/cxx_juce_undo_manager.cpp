@@ -0,0 +1,24 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::undo_manager
+{
+std::unique_ptr<juce::UndoManager> createUndoManager()
+{
+    return std::make_unique<juce::UndoManager>();
+}
+
+void beginNewTransaction (juce::UndoManager& undoManager, rust::Str name)
+{
+    undoManager.beginNewTransaction (static_cast<std::string> (name));
+}
+
+rust::String getUndoDescription (const juce::UndoManager& undoManager)
+{
+    return undoManager.getUndoDescription().toStdString();
+}
+
+rust::String getRedoDescription (const juce::UndoManager& undoManager)
+{
+    return undoManager.getRedoDescription().toStdString();
+}
+} // namespace cxx_juce::undo_manager
@@ -0,0 +1,88 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::url
+{
+std::unique_ptr<juce::URL> createUrl (rust::Str url)
+{
+    return std::make_unique<juce::URL> (juce::String (static_cast<std::string> (url)));
+}
+
+std::unique_ptr<juce::URL> copy (const juce::URL& url)
+{
+    return std::make_unique<juce::URL> (url);
+}
+
+bool equals (const juce::URL& url, const juce::URL& other)
+{
+    return url == other;
+}
+
+rust::String toString (const juce::URL& url, bool includeGetParameters)
+{
+    return url.toString (includeGetParameters).toStdString();
+}
+
+rust::String getScheme (const juce::URL& url)
+{
+    return url.getScheme().toStdString();
+}
+
+rust::String getDomain (const juce::URL& url)
+{
+    return url.getDomain().toStdString();
+}
+
+rust::String getSubPath (const juce::URL& url)
+{
+    return url.getSubPath().toStdString();
+}
+
+rust::String getFileName (const juce::URL& url)
+{
+    return url.getFileName().toStdString();
+}
+
+rust::Vec<StringPair> getParameters (const juce::URL& url)
+{
+    rust::Vec<StringPair> parameters;
+
+    for (int i = 0; i < url.getParameterNames().size(); ++i)
+    {
+        parameters.push_back (StringPair { url.getParameterNames()[i].toStdString(),
+                                           url.getParameterValues()[i].toStdString() });
+    }
+
+    return parameters;
+}
+
+std::unique_ptr<juce::URL> withParameter (const juce::URL& url, rust::Str name, rust::Str value)
+{
+    return std::make_unique<juce::URL> (url.withParameter (static_cast<std::string> (name),
+                                                           static_cast<std::string> (value)));
+}
+
+std::unique_ptr<juce::URL> withPostData (const juce::URL& url, rust::Slice<const uint8_t> data)
+{
+    return std::make_unique<juce::URL> (url.withPOSTData (juce::MemoryBlock (data.data(), data.size())));
+}
+
+rust::Vec<uint8_t> getPostData (const juce::URL& url)
+{
+    const auto& block = url.getPostDataAsMemoryBlock();
+
+    rust::Vec<uint8_t> result;
+    result.reserve (block.getSize());
+    std::copy_n (static_cast<const uint8_t*> (block.getData()), block.getSize(), std::back_inserter (result));
+    return result;
+}
+
+std::unique_ptr<juce::URL> getChildUrl (const juce::URL& url, rust::Str subPath)
+{
+    return std::make_unique<juce::URL> (url.getChildURL (static_cast<std::string> (subPath)));
+}
+
+std::unique_ptr<juce::URL> getParentUrl (const juce::URL& url)
+{
+    return std::make_unique<juce::URL> (url.getParentURL());
+}
+} // namespace cxx_juce::url
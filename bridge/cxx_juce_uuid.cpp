@@ -0,0 +1,41 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::uuid
+{
+namespace
+{
+    std::array<juce::uint8, 16> toBytes (const juce::Uuid& uuid)
+    {
+        std::array<juce::uint8, 16> bytes {};
+        std::copy_n (uuid.getRawData(), bytes.size(), bytes.begin());
+        return bytes;
+    }
+} // namespace
+
+std::array<juce::uint8, 16> createUuid()
+{
+    return toBytes (juce::Uuid());
+}
+
+std::array<juce::uint8, 16> fromString (rust::Str string)
+{
+    const auto text = juce::String (static_cast<std::string> (string)).trim().removeCharacters ("-");
+
+    if (text.length() != 32 || ! text.containsOnly ("0123456789abcdefABCDEF"))
+    {
+        throw std::invalid_argument ("invalid UUID: " + static_cast<std::string> (string));
+    }
+
+    return toBytes (juce::Uuid (text));
+}
+
+rust::String toString (std::array<juce::uint8, 16> bytes)
+{
+    return juce::Uuid (bytes.data()).toString().toStdString();
+}
+
+rust::String toDashedString (std::array<juce::uint8, 16> bytes)
+{
+    return juce::Uuid (bytes.data()).toDashedString().toStdString();
+}
+} // namespace cxx_juce::uuid
@@ -0,0 +1,250 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce
+{
+namespace
+{
+    std::unique_ptr<juce::ValueTree> toUniquePtr (const juce::ValueTree& tree)
+    {
+        return std::make_unique<juce::ValueTree> (tree);
+    }
+
+    juce::Identifier toIdentifier (rust::Str name)
+    {
+        if (name.empty())
+        {
+            throw std::invalid_argument ("identifiers can't be empty");
+        }
+
+        return juce::Identifier (static_cast<std::string> (name));
+    }
+} // namespace
+
+ValueTreeListenerHandle::ValueTreeListenerHandle (const juce::ValueTree& tree,
+                                                  rust::Box<BoxedValueTreeListener> listener)
+    : _tree (tree)
+    , _listener (std::move (listener))
+{
+    _tree.addListener (this);
+}
+
+ValueTreeListenerHandle::~ValueTreeListenerHandle()
+{
+    _tree.removeListener (this);
+}
+
+void ValueTreeListenerHandle::valueTreePropertyChanged (juce::ValueTree& tree, const juce::Identifier& property)
+{
+    ::value_tree_listener::propertyChanged (*_listener, toUniquePtr (tree), property.toString().toStdString());
+}
+
+void ValueTreeListenerHandle::valueTreeChildAdded (juce::ValueTree& parent, juce::ValueTree& child)
+{
+    ::value_tree_listener::childAdded (*_listener, toUniquePtr (parent), toUniquePtr (child));
+}
+
+void ValueTreeListenerHandle::valueTreeChildRemoved (juce::ValueTree& parent, juce::ValueTree& child, int index)
+{
+    ::value_tree_listener::childRemoved (*_listener,
+                                         toUniquePtr (parent),
+                                         toUniquePtr (child),
+                                         static_cast<size_t> (index));
+}
+
+void ValueTreeListenerHandle::valueTreeChildOrderChanged (juce::ValueTree& parent, int oldIndex, int newIndex)
+{
+    ::value_tree_listener::childOrderChanged (*_listener,
+                                              toUniquePtr (parent),
+                                              static_cast<size_t> (oldIndex),
+                                              static_cast<size_t> (newIndex));
+}
+
+void ValueTreeListenerHandle::valueTreeParentChanged (juce::ValueTree& tree)
+{
+    ::value_tree_listener::parentChanged (*_listener, toUniquePtr (tree));
+}
+
+namespace value_tree
+{
+std::unique_ptr<juce::ValueTree> createValueTree (rust::Str type)
+{
+    return std::make_unique<juce::ValueTree> (toIdentifier (type));
+}
+
+std::unique_ptr<juce::ValueTree> copy (const juce::ValueTree& tree)
+{
+    return toUniquePtr (tree);
+}
+
+std::unique_ptr<juce::ValueTree> createCopy (const juce::ValueTree& tree)
+{
+    return toUniquePtr (tree.createCopy());
+}
+
+bool equals (const juce::ValueTree& tree, const juce::ValueTree& other)
+{
+    return tree == other;
+}
+
+rust::String getType (const juce::ValueTree& tree)
+{
+    return tree.getType().toString().toStdString();
+}
+
+rust::String getPropertyName (const juce::ValueTree& tree, int index)
+{
+    return tree.getPropertyName (index).toString().toStdString();
+}
+
+bool hasProperty (const juce::ValueTree& tree, rust::Str name)
+{
+    return !name.empty() && tree.hasProperty (toIdentifier (name));
+}
+
+std::unique_ptr<juce::var> getProperty (const juce::ValueTree& tree, rust::Str name)
+{
+    if (!hasProperty (tree, name))
+    {
+        return std::make_unique<juce::var>();
+    }
+
+    return std::make_unique<juce::var> (tree.getProperty (toIdentifier (name)));
+}
+
+void setProperty (juce::ValueTree& tree, rust::Str name, const juce::var& value, juce::UndoManager* undoManager)
+{
+    tree.setProperty (toIdentifier (name), value, undoManager);
+}
+
+void removeProperty (juce::ValueTree& tree, rust::Str name, juce::UndoManager* undoManager)
+{
+    tree.removeProperty (toIdentifier (name), undoManager);
+}
+
+void removeAllProperties (juce::ValueTree& tree, juce::UndoManager* undoManager)
+{
+    tree.removeAllProperties (undoManager);
+}
+
+void copyPropertiesFrom (juce::ValueTree& tree, const juce::ValueTree& source, juce::UndoManager* undoManager)
+{
+    tree.copyPropertiesFrom (source, undoManager);
+}
+
+std::unique_ptr<juce::ValueTree> getChild (const juce::ValueTree& tree, int index)
+{
+    return toUniquePtr (tree.getChild (index));
+}
+
+std::unique_ptr<juce::ValueTree> getChildWithName (const juce::ValueTree& tree, rust::Str type)
+{
+    return toUniquePtr (tree.getChildWithName (toIdentifier (type)));
+}
+
+std::unique_ptr<juce::ValueTree> getChildWithProperty (const juce::ValueTree& tree,
+                                                       rust::Str name,
+                                                       const juce::var& value)
+{
+    return toUniquePtr (tree.getChildWithProperty (toIdentifier (name), value));
+}
+
+std::unique_ptr<juce::ValueTree> getOrCreateChildWithName (juce::ValueTree& tree,
+                                                           rust::Str type,
+                                                           juce::UndoManager* undoManager)
+{
+    return toUniquePtr (tree.getOrCreateChildWithName (toIdentifier (type), undoManager));
+}
+
+void addChild (juce::ValueTree& tree, const juce::ValueTree& child, int index, juce::UndoManager* undoManager)
+{
+    if (!child.isValid())
+    {
+        throw std::invalid_argument ("the child is invalid");
+    }
+
+    if (child.getParent().isValid())
+    {
+        throw std::invalid_argument ("the child already has a parent");
+    }
+
+    if (child == tree || tree.isAChildOf (child))
+    {
+        throw std::invalid_argument ("a tree can't be added to itself");
+    }
+
+    tree.addChild (child, index, undoManager);
+}
+
+void removeChild (juce::ValueTree& tree, int index, juce::UndoManager* undoManager)
+{
+    tree.removeChild (index, undoManager);
+}
+
+void removeAllChildren (juce::ValueTree& tree, juce::UndoManager* undoManager)
+{
+    tree.removeAllChildren (undoManager);
+}
+
+void moveChild (juce::ValueTree& tree, int currentIndex, int newIndex, juce::UndoManager* undoManager)
+{
+    tree.moveChild (currentIndex, newIndex, undoManager);
+}
+
+int indexOf (const juce::ValueTree& tree, const juce::ValueTree& child)
+{
+    return tree.indexOf (child);
+}
+
+std::unique_ptr<juce::ValueTree> getParent (const juce::ValueTree& tree)
+{
+    return toUniquePtr (tree.getParent());
+}
+
+std::unique_ptr<juce::ValueTree> getRoot (const juce::ValueTree& tree)
+{
+    return toUniquePtr (tree.getRoot());
+}
+
+rust::String toXmlString (const juce::ValueTree& tree)
+{
+    return tree.toXmlString().toStdString();
+}
+
+std::unique_ptr<juce::ValueTree> fromXml (rust::Str xml)
+{
+    return toUniquePtr (juce::ValueTree::fromXml (juce::String (static_cast<std::string> (xml))));
+}
+
+std::unique_ptr<juce::XmlElement> createXml (const juce::ValueTree& tree)
+{
+    return tree.createXml();
+}
+
+std::unique_ptr<juce::ValueTree> fromXmlElement (const juce::XmlElement& xml)
+{
+    return toUniquePtr (juce::ValueTree::fromXml (xml));
+}
+
+rust::Vec<uint8_t> writeToBinary (const juce::ValueTree& tree)
+{
+    juce::MemoryOutputStream stream;
+    tree.writeToStream (stream);
+
+    rust::Vec<uint8_t> result;
+    result.reserve (stream.getDataSize());
+    std::copy_n (static_cast<const uint8_t*> (stream.getData()), stream.getDataSize(), std::back_inserter (result));
+    return result;
+}
+
+std::unique_ptr<juce::ValueTree> readFromData (rust::Slice<const uint8_t> data)
+{
+    return toUniquePtr (juce::ValueTree::readFromData (data.data(), data.size()));
+}
+
+std::unique_ptr<ValueTreeListenerHandle> addListener (const juce::ValueTree& tree,
+                                                      rust::Box<BoxedValueTreeListener> listener)
+{
+    return std::make_unique<ValueTreeListenerHandle> (tree, std::move (listener));
+}
+} // namespace value_tree
+} // namespace cxx_juce
@@ -0,0 +1,152 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::var
+{
+std::unique_ptr<juce::var> createVoid()
+{
+    return std::make_unique<juce::var>();
+}
+
+std::unique_ptr<juce::var> createUndefined()
+{
+    return std::make_unique<juce::var> (juce::var::undefined());
+}
+
+std::unique_ptr<juce::var> fromBool (bool value)
+{
+    return std::make_unique<juce::var> (value);
+}
+
+std::unique_ptr<juce::var> fromInt (int value)
+{
+    return std::make_unique<juce::var> (value);
+}
+
+std::unique_ptr<juce::var> fromInt64 (juce::int64 value)
+{
+    return std::make_unique<juce::var> (value);
+}
+
+std::unique_ptr<juce::var> fromDouble (double value)
+{
+    return std::make_unique<juce::var> (value);
+}
+
+std::unique_ptr<juce::var> fromString (rust::Str value)
+{
+    return std::make_unique<juce::var> (juce::String (static_cast<std::string> (value)));
+}
+
+std::unique_ptr<juce::var> fromBinary (rust::Slice<const uint8_t> data)
+{
+    return std::make_unique<juce::var> (data.data(), data.size());
+}
+
+std::unique_ptr<juce::var> createArray()
+{
+    return std::make_unique<juce::var> (juce::Array<juce::var>());
+}
+
+void append (juce::var& array, const juce::var& value)
+{
+    array.append (value);
+}
+
+std::unique_ptr<juce::var> createObject()
+{
+    return std::make_unique<juce::var> (new juce::DynamicObject());
+}
+
+void setProperty (juce::var& object, rust::Str name, const juce::var& value)
+{
+    if (auto* dynamicObject = object.getDynamicObject())
+    {
+        dynamicObject->setProperty (juce::String (static_cast<std::string> (name)), value);
+    }
+}
+
+size_t getNumProperties (const juce::var& object)
+{
+    const auto* dynamicObject = object.getDynamicObject();
+    return dynamicObject != nullptr ? static_cast<size_t> (dynamicObject->getProperties().size()) : 0;
+}
+
+rust::String getPropertyName (const juce::var& object, size_t index)
+{
+    return object.getDynamicObject()->getProperties().getName (static_cast<int> (index)).toString().toStdString();
+}
+
+std::unique_ptr<juce::var> getPropertyValue (const juce::var& object, size_t index)
+{
+    return std::make_unique<juce::var> (object.getDynamicObject()->getProperties().getValueAt (static_cast<int> (index)));
+}
+
+bool toBool (const juce::var& value)
+{
+    return static_cast<bool> (value);
+}
+
+int toInt (const juce::var& value)
+{
+    return static_cast<int> (value);
+}
+
+juce::int64 toInt64 (const juce::var& value)
+{
+    return static_cast<juce::int64> (value);
+}
+
+double toDouble (const juce::var& value)
+{
+    return static_cast<double> (value);
+}
+
+rust::String toString (const juce::var& value)
+{
+    return value.toString().toStdString();
+}
+
+rust::Vec<uint8_t> toBinary (const juce::var& value)
+{
+    rust::Vec<uint8_t> result;
+
+    if (const auto* block = value.getBinaryData())
+    {
+        result.reserve (block->getSize());
+        std::copy_n (static_cast<const uint8_t*> (block->getData()), block->getSize(), std::back_inserter (result));
+    }
+
+    return result;
+}
+
+size_t size (const juce::var& value)
+{
+    return static_cast<size_t> (value.size());
+}
+
+std::unique_ptr<juce::var> getArrayElement (const juce::var& array, size_t index)
+{
+    return std::make_unique<juce::var> (array[static_cast<int> (index)]);
+}
+} // namespace cxx_juce::var
+
+namespace cxx_juce::json
+{
+std::unique_ptr<juce::var> parse (rust::Str text)
+{
+    auto result = std::make_unique<juce::var>();
+    const auto parseResult = juce::JSON::parse (juce::String (static_cast<std::string> (text)), *result);
+
+    if (parseResult.failed())
+    {
+        throw std::runtime_error (parseResult.getErrorMessage().toStdString());
+    }
+
+    return result;
+}
+
+rust::String toString (const juce::var& value, bool allOnOneLine)
+{
+    return juce::JSON::toString (value, allOnOneLine).toStdString();
+}
+} // namespace cxx_juce::json
@@ -0,0 +1,40 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::wav_audio_format
+{
+std::unique_ptr<juce::WavAudioFormat> createWavAudioFormat()
+{
+    return std::make_unique<juce::WavAudioFormat>();
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::WavAudioFormat& format, rust::Str path)
+{
+    return cxx_juce::createReaderForFile (format, path);
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::WavAudioFormat& format, rust::Box<BoxedInputStream> stream)
+{
+    return cxx_juce::createReaderForStream (format, std::move (stream));
+}
+
+std::unique_ptr<juce::AudioFormatReader> createMemoryMappedReader (juce::WavAudioFormat& format, rust::Str path)
+{
+    return cxx_juce::createMemoryMappedReader (format, path);
+}
+
+std::unique_ptr<juce::AudioFormatWriter> createWriterForFile (juce::WavAudioFormat& format,
+                                                              rust::Str path,
+                                                              double sampleRate,
+                                                              juce::uint32 numChannels,
+                                                              int bitsPerSample,
+                                                              rust::Slice<const StringPair> metadata)
+{
+    return cxx_juce::createWriterForFile (format,
+                                          path,
+                                          sampleRate,
+                                          numChannels,
+                                          bitsPerSample,
+                                          toStringPairArray (metadata),
+                                          0);
+}
+} // namespace cxx_juce::wav_audio_format
@@ -0,0 +1,35 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::wave_shaper
+{
+std::unique_ptr<WaveShaper> createWaveShaper (rust::Box<BoxedWaveShaperFunction> function)
+{
+    // std::function must be copyable, so the boxed Rust function is shared between copies.
+    auto shared = std::make_shared<rust::Box<BoxedWaveShaperFunction>> (std::move (function));
+
+    return std::make_unique<WaveShaper> (WaveShaper { [shared] (float x)
+                                                      { return ::wave_shaper::call (**shared, x); } });
+}
+
+std::unique_ptr<WaveShaper> createLookupTableWaveShaper (const BoxedWaveShaperFunction& function,
+                                                         float minimumInput,
+                                                         float maximumInput,
+                                                         size_t numPoints)
+{
+    std::shared_ptr<LookupTableTransform> table =
+        lookup_table_transform::createLookupTableTransform (function, minimumInput, maximumInput, numPoints);
+
+    return std::make_unique<WaveShaper> (WaveShaper { [table] (float x)
+                                                      { return (*table) (x); } });
+}
+
+float processSample (const WaveShaper& shaper, float sample)
+{
+    return shaper.processSample (sample);
+}
+
+void process (WaveShaper& shaper, juce::AudioSampleBuffer& buffer, const ProcessSpec& spec)
+{
+    dsp::processReplacing (shaper, buffer, dsp::toProcessSpec (spec));
+}
+} // namespace cxx_juce::wave_shaper
@@ -0,0 +1,80 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::web_input_stream
+{
+namespace
+{
+    struct PostProgressListener : juce::WebInputStream::Listener
+    {
+        explicit PostProgressListener (BoxedPostProgressCallback& callback)
+            : _callback (callback)
+        {
+        }
+
+        bool postDataSendProgress (juce::WebInputStream&, int bytesSent, int totalBytes) override
+        {
+            return ::post_progress_callback::call (_callback,
+                                                   static_cast<size_t> (bytesSent),
+                                                   static_cast<size_t> (totalBytes));
+        }
+
+        BoxedPostProgressCallback& _callback;
+    };
+} // namespace
+
+std::unique_ptr<juce::WebInputStream> createWebInputStream (const juce::URL& url,
+                                                            bool usePost,
+                                                            rust::Str extraHeaders,
+                                                            rust::Str requestCommand,
+                                                            int timeoutMs,
+                                                            int numRedirectsToFollow,
+                                                            rust::Box<BoxedPostProgressCallback> onPostProgress)
+{
+    const auto urlString = url.toString (false).toStdString();
+
+    auto stream = std::make_unique<juce::WebInputStream> (url, usePost);
+    stream->withExtraHeaders (static_cast<std::string> (extraHeaders));
+    stream->withConnectionTimeout (timeoutMs);
+    stream->withNumRedirectsToFollow (numRedirectsToFollow);
+
+    if (!requestCommand.empty())
+    {
+        stream->withCustomRequestCommand (static_cast<std::string> (requestCommand));
+    }
+
+    PostProgressListener listener (*onPostProgress);
+
+    if (! stream->connect (&listener))
+    {
+        throw std::runtime_error ("failed to connect to " + urlString);
+    }
+
+    if (const auto statusCode = stream->getStatusCode(); statusCode >= 400)
+    {
+        throw std::runtime_error ("request to " + urlString + " failed with status " + std::to_string (statusCode));
+    }
+
+    return stream;
+}
+
+int read (juce::WebInputStream& stream, rust::Slice<juce::uint8> buffer)
+{
+    if (buffer.empty())
+    {
+        return 0;
+    }
+
+    return stream.read (buffer.data(), static_cast<int> (juce::jmin (buffer.size(), size_t { std::numeric_limits<int>::max() })));
+}
+
+rust::Vec<StringPair> getResponseHeaders (juce::WebInputStream& stream)
+{
+    return toStringPairs (stream.getResponseHeaders());
+}
+
+void cancel (const juce::WebInputStream& stream)
+{
+    // Called from another thread to interrupt a blocking read, which JUCE supports.
+    const_cast<juce::WebInputStream&> (stream).cancel();
+}
+} // namespace cxx_juce::web_input_stream
@@ -0,0 +1,37 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::windowing_function
+{
+namespace
+{
+    WindowingFunction::WindowingMethod toWindowingMethod (int method)
+    {
+        return static_cast<WindowingFunction::WindowingMethod> (method);
+    }
+} // namespace
+
+std::unique_ptr<WindowingFunction> createWindowingFunction (size_t size, int method, bool normalise, float beta)
+{
+    return std::make_unique<WindowingFunction> (size, toWindowingMethod (method), normalise, beta);
+}
+
+void fillWindowingTables (WindowingFunction& window, size_t size, int method, bool normalise, float beta)
+{
+    window.fillWindowingTables (size, toWindowingMethod (method), normalise, beta);
+}
+
+void multiplyWithWindowingTable (const WindowingFunction& window, rust::Slice<float> samples)
+{
+    window.multiplyWithWindowingTable (samples.data(), samples.size());
+}
+
+void fillWindowingTable (rust::Slice<float> samples, int method, bool normalise, float beta)
+{
+    if (samples.empty())
+    {
+        return;
+    }
+
+    WindowingFunction::fillWindowingTables (samples.data(), samples.size(), toWindowingMethod (method), normalise, beta);
+}
+} // namespace cxx_juce::windowing_function
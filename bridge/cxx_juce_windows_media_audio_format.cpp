@@ -0,0 +1,28 @@
+#include "cxx_juce_bindings.h"
+
+#if JUCE_WINDOWS
+
+namespace cxx_juce::windows_media_audio_format
+{
+std::unique_ptr<juce::WindowsMediaAudioFormat> createWindowsMediaAudioFormat()
+{
+    return std::make_unique<juce::WindowsMediaAudioFormat>();
+}
+
+rust::Vec<rust::String> getFileExtensions (const juce::WindowsMediaAudioFormat& format)
+{
+    return toRustStrings (format.getFileExtensions());
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForFile (juce::WindowsMediaAudioFormat& format, rust::Str path)
+{
+    return cxx_juce::createReaderForFile (format, path);
+}
+
+std::unique_ptr<juce::AudioFormatReader> createReaderForStream (juce::WindowsMediaAudioFormat& format, rust::Box<BoxedInputStream> stream)
+{
+    return cxx_juce::createReaderForStream (format, std::move (stream));
+}
+} // namespace cxx_juce::windows_media_audio_format
+
+#endif
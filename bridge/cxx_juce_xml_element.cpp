@@ -0,0 +1,192 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::xml_element
+{
+namespace
+{
+    juce::String toXmlName (rust::Str name)
+    {
+        const auto string = juce::String (static_cast<std::string> (name));
+
+        if (!juce::XmlElement::isValidXmlName (string))
+        {
+            throw std::invalid_argument ("'" + string.toStdString() + "' isn't a valid XML name");
+        }
+
+        return string;
+    }
+
+    juce::XmlElement::TextFormat toTextFormat (bool singleLine, bool includeHeader)
+    {
+        auto format = singleLine ? juce::XmlElement::TextFormat().singleLine()
+                                 : juce::XmlElement::TextFormat();
+
+        return includeHeader ? format : format.withoutHeader();
+    }
+
+    std::unique_ptr<juce::XmlElement> parseDocument (juce::XmlDocument& document)
+    {
+        auto element = document.getDocumentElement();
+
+        if (element == nullptr)
+        {
+            const auto error = document.getLastParseError();
+            throw std::runtime_error (error.isNotEmpty() ? error.toStdString() : "not an XML document");
+        }
+
+        return element;
+    }
+} // namespace
+
+std::unique_ptr<juce::XmlElement> createXmlElement (rust::Str tagName)
+{
+    return std::make_unique<juce::XmlElement> (toXmlName (tagName));
+}
+
+std::unique_ptr<juce::XmlElement> createTextElement (rust::Str text)
+{
+    return std::unique_ptr<juce::XmlElement> (juce::XmlElement::createTextElement (static_cast<std::string> (text)));
+}
+
+std::unique_ptr<juce::XmlElement> parse (rust::Str text)
+{
+    juce::XmlDocument document (static_cast<std::string> (text));
+    return parseDocument (document);
+}
+
+std::unique_ptr<juce::XmlElement> parseFile (rust::Str path)
+{
+    const auto file = toFile (path);
+
+    if (!file.existsAsFile())
+    {
+        throw std::runtime_error ("the file " + file.getFullPathName().toStdString() + " doesn't exist");
+    }
+
+    juce::XmlDocument document (file);
+    return parseDocument (document);
+}
+
+std::unique_ptr<juce::XmlElement> createCopy (const juce::XmlElement& element)
+{
+    return std::make_unique<juce::XmlElement> (element);
+}
+
+rust::String getTagName (const juce::XmlElement& element)
+{
+    return element.getTagName().toStdString();
+}
+
+void setTagName (juce::XmlElement& element, rust::Str tagName)
+{
+    element.setTagName (toXmlName (tagName));
+}
+
+rust::String getText (const juce::XmlElement& element)
+{
+    return element.getText().toStdString();
+}
+
+rust::String getAllSubText (const juce::XmlElement& element)
+{
+    return element.getAllSubText().toStdString();
+}
+
+void setText (juce::XmlElement& element, rust::Str text)
+{
+    if (!element.isTextElement())
+    {
+        throw std::runtime_error ("only text elements have their own text");
+    }
+
+    element.setText (static_cast<std::string> (text));
+}
+
+void addTextElement (juce::XmlElement& element, rust::Str text)
+{
+    element.addTextElement (static_cast<std::string> (text));
+}
+
+rust::String getAttributeName (const juce::XmlElement& element, int index)
+{
+    return element.getAttributeName (index).toStdString();
+}
+
+rust::String getAttributeValue (const juce::XmlElement& element, int index)
+{
+    return element.getAttributeValue (index).toStdString();
+}
+
+bool hasAttribute (const juce::XmlElement& element, rust::Str name)
+{
+    return element.hasAttribute (static_cast<std::string> (name));
+}
+
+rust::String getStringAttribute (const juce::XmlElement& element, rust::Str name)
+{
+    return element.getStringAttribute (static_cast<std::string> (name)).toStdString();
+}
+
+void setAttribute (juce::XmlElement& element, rust::Str name, rust::Str value)
+{
+    element.setAttribute (juce::Identifier (toXmlName (name)), juce::String (static_cast<std::string> (value)));
+}
+
+void removeAttribute (juce::XmlElement& element, rust::Str name)
+{
+    element.removeAttribute (static_cast<std::string> (name));
+}
+
+juce::XmlElement* getChildByName (const juce::XmlElement& element, rust::Str tagName)
+{
+    return element.getChildByName (static_cast<std::string> (tagName));
+}
+
+juce::XmlElement* getChildByAttribute (const juce::XmlElement& element, rust::Str name, rust::Str value)
+{
+    return element.getChildByAttribute (static_cast<std::string> (name), static_cast<std::string> (value));
+}
+
+void insertChildElement (juce::XmlElement& element, std::unique_ptr<juce::XmlElement> child, int index)
+{
+    element.insertChildElement (child.release(), index);
+}
+
+juce::XmlElement& createNewChildElement (juce::XmlElement& element, rust::Str tagName)
+{
+    return *element.createNewChildElement (toXmlName (tagName));
+}
+
+std::unique_ptr<juce::XmlElement> removeChildElement (juce::XmlElement& element, int index)
+{
+    auto* child = element.getChildElement (index);
+
+    if (child == nullptr)
+    {
+        return nullptr;
+    }
+
+    element.removeChildElement (child, false);
+    return std::unique_ptr<juce::XmlElement> (child);
+}
+
+bool isEquivalentTo (const juce::XmlElement& element, const juce::XmlElement& other, bool ignoreOrderOfAttributes)
+{
+    return element.isEquivalentTo (&other, ignoreOrderOfAttributes);
+}
+
+rust::String toString (const juce::XmlElement& element, bool singleLine, bool includeHeader)
+{
+    return element.toString (toTextFormat (singleLine, includeHeader)).toStdString();
+}
+
+void writeTo (const juce::XmlElement& element, rust::Str path, bool singleLine, bool includeHeader)
+{
+    const auto file = toFile (path);
+
+    if (!element.writeTo (file, toTextFormat (singleLine, includeHeader)))
+    {
+        throw std::runtime_error ("failed to write " + file.getFullPathName().toStdString());
+    }
+}
+} // namespace cxx_juce::xml_element
@@ -0,0 +1,153 @@
+#include "cxx_juce_bindings.h"
+
+namespace cxx_juce::zip_file
+{
+namespace
+{
+    void throwIfFailed (const juce::Result& result)
+    {
+        if (result.failed())
+        {
+            throw std::runtime_error (result.getErrorMessage().toStdString());
+        }
+    }
+
+    const juce::ZipFile::ZipEntry& getEntry (const juce::ZipFile& zip, int index)
+    {
+        const auto* entry = zip.getEntry (index);
+
+        if (entry == nullptr)
+        {
+            throw std::out_of_range ("zip entry index out of range");
+        }
+
+        return *entry;
+    }
+} // namespace
+
+std::unique_ptr<juce::ZipFile> openZipFile (rust::Str path)
+{
+    const auto file = toFile (path);
+
+    if (! file.existsAsFile())
+    {
+        throw std::invalid_argument ("zip file doesn't exist: " + file.getFullPathName().toStdString());
+    }
+
+    return std::make_unique<juce::ZipFile> (file);
+}
+
+std::unique_ptr<juce::ZipFile> createZipFileFromData (rust::Slice<const juce::uint8> data)
+{
+    return std::make_unique<juce::ZipFile> (new juce::MemoryInputStream (data.data(), data.size(), true), true);
+}
+
+ZipEntry getZipEntry (const juce::ZipFile& zip, int index)
+{
+    const auto& entry = getEntry (zip, index);
+
+    return { entry.filename.toStdString(),
+             entry.uncompressedSize,
+             entry.fileTime.toMilliseconds(),
+             entry.isSymbolicLink };
+}
+
+int getIndexOfFileName (const juce::ZipFile& zip, rust::Str fileName, bool ignoreCase)
+{
+    return zip.getIndexOfFileName (static_cast<std::string> (fileName), ignoreCase);
+}
+
+rust::Vec<juce::uint8> readEntry (juce::ZipFile& zip, int index)
+{
+    getEntry (zip, index);
+
+    const std::unique_ptr<juce::InputStream> stream (zip.createStreamForEntry (index));
+
+    if (stream == nullptr)
+    {
+        throw std::runtime_error ("failed to open zip entry " + std::to_string (index));
+    }
+
+    juce::MemoryBlock block;
+    stream->readIntoMemoryBlock (block);
+
+    rust::Vec<juce::uint8> data;
+    data.reserve (block.getSize());
+    std::copy_n (static_cast<const juce::uint8*> (block.getData()), block.getSize(), std::back_inserter (data));
+    return data;
+}
+
+void uncompressTo (juce::ZipFile& zip, rust::Str targetDirectory, bool shouldOverwriteFiles)
+{
+    throwIfFailed (zip.uncompressTo (toFile (targetDirectory), shouldOverwriteFiles));
+}
+
+void uncompressEntry (juce::ZipFile& zip, int index, rust::Str targetDirectory, bool shouldOverwriteFiles)
+{
+    getEntry (zip, index);
+    throwIfFailed (zip.uncompressEntry (index, toFile (targetDirectory), shouldOverwriteFiles));
+}
+} // namespace cxx_juce::zip_file
+
+namespace cxx_juce::zip_file_builder
+{
+std::unique_ptr<ZipFileBuilder> createZipFileBuilder()
+{
+    return std::make_unique<ZipFileBuilder>();
+}
+
+void addFile (ZipFileBuilder& builder, rust::Str path, int compressionLevel, rust::Str storedPath)
+{
+    const auto file = toFile (path);
+
+    if (! file.existsAsFile())
+    {
+        throw std::invalid_argument ("file doesn't exist: " + file.getFullPathName().toStdString());
+    }
+
+    builder.addFile (file, compressionLevel, static_cast<std::string> (storedPath));
+}
+
+void addEntry (ZipFileBuilder& builder,
+               rust::Slice<const juce::uint8> data,
+               int compressionLevel,
+               rust::Str storedPath,
+               juce::int64 modificationTime)
+{
+    builder.addEntry (new juce::MemoryInputStream (data.data(), data.size(), true),
+                      compressionLevel,
+                      static_cast<std::string> (storedPath),
+                      juce::Time (modificationTime));
+}
+
+void writeToFile (const ZipFileBuilder& builder, rust::Str path)
+{
+    const auto file = toFile (path);
+    juce::FileOutputStream stream (file);
+
+    if (! stream.openedOk() || ! stream.setPosition (0) || ! stream.truncate().wasOk())
+    {
+        throw std::runtime_error ("failed to open " + file.getFullPathName().toStdString());
+    }
+
+    if (! builder.writeToStream (stream, nullptr))
+    {
+        throw std::runtime_error ("failed to write " + file.getFullPathName().toStdString());
+    }
+}
+
+rust::Vec<juce::uint8> writeToMemory (const ZipFileBuilder& builder)
+{
+    juce::MemoryOutputStream stream;
+
+    if (! builder.writeToStream (stream, nullptr))
+    {
+        throw std::runtime_error ("failed to write zip file");
+    }
+
+    rust::Vec<juce::uint8> data;
+    data.reserve (stream.getDataSize());
+    std::copy_n (static_cast<const juce::uint8*> (stream.getData()), stream.getDataSize(), std::back_inserter (data));
+    return data;
+}
+} // namespace cxx_juce::zip_file_builder
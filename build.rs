@@ -25,6 +25,78 @@ fn main() {
         cmake.define("CXX_JUCE_USE_ASIO", "OFF");
     }
 
+    if cfg!(feature = "juce_audio_formats") {
+        cmake.define("CXX_JUCE_USE_AUDIO_FORMATS", "ON");
+    } else {
+        cmake.define("CXX_JUCE_USE_AUDIO_FORMATS", "OFF");
+    }
+
+    if cfg!(feature = "juce_audio_processors") {
+        cmake.define("CXX_JUCE_USE_AUDIO_PROCESSORS", "ON");
+    } else {
+        cmake.define("CXX_JUCE_USE_AUDIO_PROCESSORS", "OFF");
+    }
+
+    if cfg!(feature = "juce_audio_utils") {
+        cmake.define("CXX_JUCE_USE_AUDIO_UTILS", "ON");
+    } else {
+        cmake.define("CXX_JUCE_USE_AUDIO_UTILS", "OFF");
+    }
+
+    if cfg!(feature = "juce_data_structures") {
+        cmake.define("CXX_JUCE_USE_DATA_STRUCTURES", "ON");
+    } else {
+        cmake.define("CXX_JUCE_USE_DATA_STRUCTURES", "OFF");
+    }
+
+    if cfg!(feature = "juce_dsp") {
+        cmake.define("CXX_JUCE_USE_DSP", "ON");
+    } else {
+        cmake.define("CXX_JUCE_USE_DSP", "OFF");
+    }
+
+    if cfg!(feature = "flac") {
+        cmake.define("CXX_JUCE_USE_FLAC", "ON");
+    } else {
+        cmake.define("CXX_JUCE_USE_FLAC", "OFF");
+    }
+
+    if cfg!(feature = "lame") {
+        cmake.define("CXX_JUCE_USE_LAME_AUDIO_FORMAT", "ON");
+    } else {
+        cmake.define("CXX_JUCE_USE_LAME_AUDIO_FORMAT", "OFF");
+    }
+
+    if cfg!(feature = "mp3") {
+        cmake.define("CXX_JUCE_USE_MP3AUDIOFORMAT", "ON");
+    } else {
+        cmake.define("CXX_JUCE_USE_MP3AUDIOFORMAT", "OFF");
+    }
+
+    if cfg!(feature = "network") {
+        cmake.define("CXX_JUCE_USE_NETWORK", "ON");
+    } else {
+        cmake.define("CXX_JUCE_USE_NETWORK", "OFF");
+    }
+
+    if cfg!(feature = "ogg") {
+        cmake.define("CXX_JUCE_USE_OGGVORBIS", "ON");
+    } else {
+        cmake.define("CXX_JUCE_USE_OGGVORBIS", "OFF");
+    }
+
+    if cfg!(feature = "lv2") {
+        cmake.define("CXX_JUCE_PLUGINHOST_LV2", "ON");
+    } else {
+        cmake.define("CXX_JUCE_PLUGINHOST_LV2", "OFF");
+    }
+
+    if cfg!(feature = "vst3") {
+        cmake.define("CXX_JUCE_PLUGINHOST_VST3", "ON");
+    } else {
+        cmake.define("CXX_JUCE_PLUGINHOST_VST3", "OFF");
+    }
+
     if cfg!(target_os = "windows") && cmake.get_profile() == "Debug" {
         cmake.profile("RelWithDebInfo");
     }
@@ -51,15 +123,36 @@ fn main() {
         println!("cargo:rustc-link-lib=framework=CoreFoundation");
         println!("cargo:rustc-link-lib=framework=CoreMIDI");
         println!("cargo:rustc-link-lib=framework=IOKit");
+
+        if cfg!(feature = "juce_audio_processors") {
+            println!("cargo:rustc-link-lib=framework=AudioUnit");
+            println!("cargo:rustc-link-lib=framework=CoreAudioKit");
+            println!("cargo:rustc-link-lib=framework=Metal");
+            println!("cargo:rustc-link-lib=framework=MetalKit");
+            println!("cargo:rustc-link-lib=framework=QuartzCore");
+        }
     }
 
     if cfg!(target_os = "windows") {
         println!("cargo:rustc-link-lib=dylib=shell32");
         println!("cargo:rustc-link-lib=dylib=ole32");
+
+        if cfg!(feature = "network") {
+            println!("cargo:rustc-link-lib=dylib=wininet");
+        }
     }
 
     if cfg!(target_os = "linux") {
         println!("cargo:rustc-link-lib=asound");
+
+        if cfg!(feature = "network") {
+            println!("cargo:rustc-link-lib=curl");
+        }
+
         println!("cargo:rustc-link-lib=jack");
+
+        if cfg!(feature = "juce_audio_processors") {
+            println!("cargo:rustc-link-lib=freetype");
+        }
     }
 }
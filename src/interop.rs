@@ -0,0 +1,19 @@
+//! Adapters for using JUCE alongside other Rust crates.
+
+#[cfg(feature = "hound")]
+pub mod hound;
+
+#[cfg(feature = "log")]
+pub mod log;
+
+#[cfg(feature = "serde_json")]
+pub mod serde_json;
+
+#[cfg(feature = "symphonia")]
+pub mod symphonia;
+
+#[cfg(feature = "tracing")]
+pub mod tracing;
+
+#[cfg(feature = "uuid")]
+pub mod uuid;
@@ -0,0 +1,156 @@
+//! Interoperability with the [hound](https://docs.rs/hound) WAV reader and writer.
+
+use {
+    crate::{
+        juce_audio_basics::AudioBuffer,
+        juce_audio_devices::OutputAudioSampleBuffer,
+        juce_audio_formats::{AudioFormatReader, CustomAudioFormatReader},
+    },
+    hound::{SampleFormat, WavReader, WavWriter},
+    std::{
+        fs::File,
+        io::{self, BufReader, ErrorKind, Read, Seek, Write},
+        path::Path,
+    },
+};
+
+/// Reads a WAV file with hound, so it can be read through an [`AudioFormatReader`].
+pub struct HoundReader<R>(WavReader<R>);
+
+impl<R: Read + Seek + Send> HoundReader<R> {
+    /// Create a reader from a hound reader.
+    pub fn new(reader: WavReader<R>) -> Self {
+        Self(reader)
+    }
+
+    /// Wrap the reader in an [`AudioFormatReader`].
+    pub fn into_audio_format_reader(self) -> AudioFormatReader
+    where
+        R: 'static,
+    {
+        AudioFormatReader::from_custom(self)
+    }
+}
+
+impl HoundReader<BufReader<File>> {
+    /// Open a WAV file.
+    pub fn open(path: impl AsRef<Path>) -> hound::Result<Self> {
+        WavReader::open(path).map(Self)
+    }
+}
+
+impl<R: Read + Seek + Send> CustomAudioFormatReader for HoundReader<R> {
+    fn format_name(&self) -> &str {
+        "WAV file"
+    }
+
+    fn sample_rate(&self) -> f64 {
+        f64::from(self.0.spec().sample_rate)
+    }
+
+    fn num_channels(&self) -> usize {
+        usize::from(self.0.spec().channels)
+    }
+
+    fn length_in_samples(&self) -> u64 {
+        u64::from(self.0.duration())
+    }
+
+    fn bits_per_sample(&self) -> u32 {
+        u32::from(self.0.spec().bits_per_sample)
+    }
+
+    fn read_samples(
+        &mut self,
+        buffer: &mut OutputAudioSampleBuffer<'_>,
+        start_sample: u64,
+    ) -> bool {
+        let Some(remaining) = self.length_in_samples().checked_sub(start_sample) else {
+            return true;
+        };
+
+        if self.0.seek(start_sample as u32).is_err() {
+            return false;
+        }
+
+        let channels = self.num_channels();
+        let frames = buffer.samples().min(remaining as usize);
+
+        let Ok(samples) = read_interleaved(&mut self.0, frames * channels) else {
+            return false;
+        };
+
+        for (index, sample) in samples.into_iter().enumerate() {
+            let channel = index % channels;
+
+            if channel < buffer.channels() {
+                buffer[channel][index / channels] = sample;
+            }
+        }
+
+        true
+    }
+}
+
+fn read_interleaved<R: Read>(
+    reader: &mut WavReader<R>,
+    num_samples: usize,
+) -> hound::Result<Vec<f32>> {
+    let spec = reader.spec();
+
+    match spec.sample_format {
+        SampleFormat::Float => reader.samples::<f32>().take(num_samples).collect(),
+        SampleFormat::Int => {
+            let scale = 1.0 / (1_i64 << (spec.bits_per_sample - 1)) as f32;
+
+            reader
+                .samples::<i32>()
+                .take(num_samples)
+                .map(|sample| sample.map(|sample| sample as f32 * scale))
+                .collect()
+        }
+    }
+}
+
+impl AudioBuffer {
+    /// Read the remaining samples from a hound reader into a new buffer.
+    ///
+    /// Integer samples are scaled to the range -1 to 1.
+    pub fn from_hound<R: Read>(reader: &mut WavReader<R>) -> hound::Result<Self> {
+        let channels = usize::from(reader.spec().channels);
+        let samples = read_interleaved(reader, usize::MAX)?;
+
+        Ok(Self::from_interleaved(&samples, channels))
+    }
+
+    /// Write the samples in the buffer to a hound writer.
+    ///
+    /// The buffer must have the same number of channels as the writer. Samples are converted to
+    /// the writer's sample format, clipping them to the range -1 to 1 for integer formats.
+    pub fn write_to_hound<W: Write + Seek>(&self, writer: &mut WavWriter<W>) -> hound::Result<()> {
+        let spec = writer.spec();
+
+        if usize::from(spec.channels) != self.channels() {
+            return Err(hound::Error::IoError(io::Error::new(
+                ErrorKind::InvalidInput,
+                "the buffer and writer have different numbers of channels",
+            )));
+        }
+
+        let samples = self.to_interleaved();
+
+        match spec.sample_format {
+            SampleFormat::Float => samples
+                .into_iter()
+                .try_for_each(|sample| writer.write_sample(sample)),
+            SampleFormat::Int => {
+                let scale = (1_i64 << (spec.bits_per_sample - 1)) as f32;
+
+                samples.into_iter().try_for_each(|sample| {
+                    let sample = (sample * scale).round().clamp(-scale, scale - 1.0);
+                    writer.write_sample(sample as i32)
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Interoperability with the [log](https://docs.rs/log) logging facade.
+
+use crate::juce_core::{self, is_assertion_failure, Logger};
+
+/// A [`Logger`] that forwards JUCE's log messages to the `log` crate, with the `juce` target.
+///
+/// Failed assertions are logged as errors, and everything else as information.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogForwarder;
+
+impl LogForwarder {
+    /// Forward JUCE's log messages to the `log` crate, replacing any logger set with
+    /// [`juce_core::set_logger`].
+    pub fn install() {
+        juce_core::set_logger(Self);
+    }
+}
+
+impl Logger for LogForwarder {
+    fn log_message(&self, message: &str) {
+        let level = if is_assertion_failure(message) {
+            log::Level::Error
+        } else {
+            log::Level::Info
+        };
+
+        log::log!(target: "juce", level, "{message}");
+    }
+}
@@ -0,0 +1,61 @@
+//! Interoperability with [serde_json](https://docs.rs/serde_json).
+//!
+//! Values convert between [`Var`] and [`serde_json::Value`] in both directions. JSON has no
+//! equivalent of some variants, so converting a [`Var`] to JSON is lossy:
+//!
+//! - [`Var::Void`] and [`Var::Undefined`] become `null`.
+//! - [`Var::Binary`] becomes an array of bytes.
+//! - Infinite and NaN doubles become `null`.
+
+use {
+    crate::juce_core::{DynamicObject, Var},
+    serde_json::{Map, Number, Value},
+};
+
+impl From<Value> for Var {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => Var::Void,
+            Value::Bool(value) => Var::Bool(value),
+            Value::Number(number) => {
+                if let Some(value) = number.as_i64() {
+                    i32::try_from(value).map_or(Var::Int64(value), Var::Int)
+                } else if let Some(value) = number.as_u64().and_then(|value| value.try_into().ok())
+                {
+                    Var::Int64(value)
+                } else {
+                    Var::Double(number.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(value) => Var::String(value),
+            Value::Array(values) => Var::Array(values.into_iter().map(Var::from).collect()),
+            Value::Object(properties) => Var::Object(
+                properties
+                    .into_iter()
+                    .map(|(name, value)| (name, Var::from(value)))
+                    .collect::<DynamicObject>(),
+            ),
+        }
+    }
+}
+
+impl From<Var> for Value {
+    fn from(value: Var) -> Self {
+        match value {
+            Var::Void | Var::Undefined => Value::Null,
+            Var::Bool(value) => Value::Bool(value),
+            Var::Int(value) => Value::from(value),
+            Var::Int64(value) => Value::from(value),
+            Var::Double(value) => Number::from_f64(value).map_or(Value::Null, Value::Number),
+            Var::String(value) => Value::String(value),
+            Var::Array(values) => Value::Array(values.into_iter().map(Value::from).collect()),
+            Var::Binary(data) => Value::Array(data.into_iter().map(Value::from).collect()),
+            Var::Object(object) => Value::Object(
+                object
+                    .into_iter()
+                    .map(|(name, value)| (name, Value::from(value)))
+                    .collect::<Map<_, _>>(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Interoperability with the [symphonia](https://docs.rs/symphonia) decoders.
+
+use {
+    crate::{
+        invalid_argument,
+        juce_audio_basics::AudioBuffer,
+        juce_audio_devices::OutputAudioSampleBuffer,
+        juce_audio_formats::{AudioFormatReader, CustomAudioFormatReader},
+        Result,
+    },
+    std::{fs::File, path::Path},
+    symphonia::core::{
+        audio::{
+            AudioBuffer as SymphoniaAudioBuffer, AudioBufferRef, Channels, SampleBuffer, Signal,
+            SignalSpec,
+        },
+        codecs::{Decoder, DecoderOptions, CODEC_TYPE_NULL},
+        errors::Error,
+        formats::{FormatOptions, FormatReader, SeekMode, SeekTo},
+        io::MediaSourceStream,
+        meta::MetadataOptions,
+        probe::Hint,
+    },
+};
+
+/// Decodes a track with symphonia, so it can be read through an [`AudioFormatReader`].
+///
+/// Timestamps of the track are assumed to be measured in samples, which is the case for the
+/// audio formats supported by symphonia.
+pub struct SymphoniaReader {
+    format: Box<dyn FormatReader>,
+    decoder: Box<dyn Decoder>,
+    track_id: u32,
+    format_name: String,
+    sample_rate: f64,
+    num_channels: usize,
+    length_in_samples: u64,
+    bits_per_sample: u32,
+    decoded: Vec<Vec<f32>>,
+    decoded_start: u64,
+}
+
+impl SymphoniaReader {
+    /// Create a reader for the default track of a symphonia format reader.
+    ///
+    /// Returns [`None`] if there is no decodable track or its sample rate, channels or length are
+    /// unknown.
+    pub fn new(format: Box<dyn FormatReader>) -> Option<Self> {
+        let track = format
+            .default_track()
+            .filter(|track| track.codec_params.codec != CODEC_TYPE_NULL)?;
+
+        let params = &track.codec_params;
+        let codecs = symphonia::default::get_codecs();
+        let decoder = codecs.make(params, &DecoderOptions::default()).ok()?;
+
+        Some(Self {
+            track_id: track.id,
+            format_name: codecs.get_codec(params.codec)?.long_name.to_string(),
+            sample_rate: f64::from(params.sample_rate?),
+            num_channels: params.channels?.count(),
+            length_in_samples: params.n_frames?,
+            bits_per_sample: params.bits_per_sample.unwrap_or(32),
+            decoded: Vec::new(),
+            decoded_start: 0,
+            format,
+            decoder,
+        })
+    }
+
+    /// Open an audio file with any of symphonia's enabled formats and codecs.
+    pub fn open(path: impl AsRef<Path>) -> Option<Self> {
+        let path = path.as_ref();
+
+        let mut hint = Hint::new();
+        if let Some(extension) = path.extension().and_then(|extension| extension.to_str()) {
+            hint.with_extension(extension);
+        }
+
+        let stream = MediaSourceStream::new(Box::new(File::open(path).ok()?), Default::default());
+        let probed = symphonia::default::get_probe()
+            .format(
+                &hint,
+                stream,
+                &FormatOptions::default(),
+                &MetadataOptions::default(),
+            )
+            .ok()?;
+
+        Self::new(probed.format)
+    }
+
+    /// Wrap the reader in an [`AudioFormatReader`].
+    pub fn into_audio_format_reader(self) -> AudioFormatReader {
+        AudioFormatReader::from_custom(self)
+    }
+
+    fn decoded_end(&self) -> u64 {
+        self.decoded_start + self.decoded.first().map_or(0, Vec::len) as u64
+    }
+
+    fn seek(&mut self, sample: u64) -> bool {
+        let seek_to = SeekTo::TimeStamp {
+            ts: sample,
+            track_id: self.track_id,
+        };
+
+        if self.format.seek(SeekMode::Accurate, seek_to).is_err() {
+            return false;
+        }
+
+        self.decoder.reset();
+        self.decoded.clear();
+        self.decoded_start = sample;
+        true
+    }
+
+    fn decode_next_packet(&mut self) -> Option<()> {
+        loop {
+            let packet = self.format.next_packet().ok()?;
+
+            if packet.track_id() != self.track_id {
+                continue;
+            }
+
+            match self.decoder.decode(&packet) {
+                Ok(decoded) => {
+                    self.decoded = to_channels(decoded);
+                    self.decoded_start = packet.ts();
+                    return Some(());
+                }
+                Err(Error::DecodeError(_)) => continue,
+                Err(_) => return None,
+            }
+        }
+    }
+}
+
+impl CustomAudioFormatReader for SymphoniaReader {
+    fn format_name(&self) -> &str {
+        &self.format_name
+    }
+
+    fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    fn num_channels(&self) -> usize {
+        self.num_channels
+    }
+
+    fn length_in_samples(&self) -> u64 {
+        self.length_in_samples
+    }
+
+    fn bits_per_sample(&self) -> u32 {
+        self.bits_per_sample
+    }
+
+    fn read_samples(
+        &mut self,
+        buffer: &mut OutputAudioSampleBuffer<'_>,
+        start_sample: u64,
+    ) -> bool {
+        if (start_sample < self.decoded_start || start_sample > self.decoded_end())
+            && !self.seek(start_sample)
+        {
+            return false;
+        }
+
+        let end = start_sample + buffer.samples() as u64;
+        let mut position = start_sample;
+
+        while position < end {
+            if (self.decoded_start..self.decoded_end()).contains(&position) {
+                let offset = (position - self.decoded_start) as usize;
+                let dest_offset = (position - start_sample) as usize;
+                let count = (self.decoded_end() - position).min(end - position);
+
+                for (channel, decoded) in self.decoded.iter().enumerate().take(buffer.channels()) {
+                    buffer[channel][dest_offset..dest_offset + count as usize]
+                        .copy_from_slice(&decoded[offset..offset + count as usize]);
+                }
+
+                position += count;
+            } else if position < self.decoded_start {
+                // The track has no samples before the next packet, so the cleared buffer is left
+                // silent up to it.
+                position = self.decoded_start.min(end);
+            } else if self.decode_next_packet().is_none() {
+                break;
+            }
+        }
+
+        true
+    }
+}
+
+fn to_channels(decoded: AudioBufferRef<'_>) -> Vec<Vec<f32>> {
+    let num_channels = decoded.spec().channels.count();
+    let num_frames = decoded.frames();
+
+    let mut samples = SampleBuffer::<f32>::new(decoded.capacity() as u64, *decoded.spec());
+    samples.copy_planar_ref(decoded);
+
+    samples
+        .samples()
+        .chunks(num_frames.max(1))
+        .take(num_channels)
+        .map(<[f32]>::to_vec)
+        .collect()
+}
+
+impl From<AudioBufferRef<'_>> for AudioBuffer {
+    fn from(decoded: AudioBufferRef<'_>) -> Self {
+        let channels = to_channels(decoded);
+        let num_samples = channels.first().map_or(0, Vec::len);
+
+        let mut buffer = AudioBuffer::new(channels.len(), num_samples);
+        for (channel, samples) in channels.iter().enumerate() {
+            buffer[channel].copy_from_slice(samples);
+        }
+
+        buffer
+    }
+}
+
+impl AudioBuffer {
+    /// Copy the buffer into a symphonia buffer with the given sample rate.
+    ///
+    /// Returns an error if the buffer has more channels than symphonia has channel positions.
+    pub fn to_symphonia(&self, sample_rate: u32) -> Result<SymphoniaAudioBuffer<f32>> {
+        if self.channels() > Channels::all().count() {
+            return invalid_argument("the buffer has too many channels for symphonia");
+        }
+
+        let channels = Channels::from_bits_truncate(((1_u64 << self.channels()) - 1) as u32);
+        let mut buffer = SymphoniaAudioBuffer::new(
+            self.samples() as u64,
+            SignalSpec::new(sample_rate, channels),
+        );
+        buffer.render_reserved(Some(self.samples()));
+
+        for channel in 0..self.channels() {
+            buffer.chan_mut(channel).copy_from_slice(&self[channel]);
+        }
+
+        Ok(buffer)
+    }
+}
+
+impl AudioFormatReader {
+    /// Read samples from the reader into a new symphonia buffer.
+    pub fn read_symphonia(
+        &mut self,
+        reader_start_sample: i64,
+        num_samples: usize,
+    ) -> Result<SymphoniaAudioBuffer<f32>> {
+        let buffer = self.read(reader_start_sample, num_samples)?;
+        buffer.to_symphonia(self.sample_rate() as u32)
+    }
+}
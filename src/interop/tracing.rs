@@ -0,0 +1,27 @@
+//! Interoperability with the [tracing](https://docs.rs/tracing) framework.
+
+use crate::juce_core::{self, is_assertion_failure, Logger};
+
+/// A [`Logger`] that forwards JUCE's log messages to `tracing` as events, with the `juce` target.
+///
+/// Failed assertions are recorded as errors, and everything else as information.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingForwarder;
+
+impl TracingForwarder {
+    /// Forward JUCE's log messages to `tracing`, replacing any logger set with
+    /// [`juce_core::set_logger`].
+    pub fn install() {
+        juce_core::set_logger(Self);
+    }
+}
+
+impl Logger for TracingForwarder {
+    fn log_message(&self, message: &str) {
+        if is_assertion_failure(message) {
+            tracing::error!(target: "juce", "{message}");
+        } else {
+            tracing::info!(target: "juce", "{message}");
+        }
+    }
+}
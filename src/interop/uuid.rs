@@ -0,0 +1,17 @@
+//! Interoperability with [uuid](https://docs.rs/uuid).
+//!
+//! Both types store the same 16 bytes, so the conversions are lossless.
+
+use crate::juce_core::Uuid;
+
+impl From<uuid::Uuid> for Uuid {
+    fn from(uuid: uuid::Uuid) -> Self {
+        Uuid::from_bytes(uuid.into_bytes())
+    }
+}
+
+impl From<Uuid> for uuid::Uuid {
+    fn from(uuid: Uuid) -> Self {
+        uuid::Uuid::from_bytes(*uuid.as_bytes())
+    }
+}
@@ -1,8 +1,15 @@
 //! Classes for audio buffer manipulation, midi message handling, synthesis, etc.
 
 use {
-    std::f64::consts::FRAC_1_SQRT_2,
-    {crate::juce, cxx::UniquePtr},
+    crate::{invalid_argument, juce, juce_audio_devices::OutputAudioSampleBuffer, Result},
+    cxx::UniquePtr,
+    std::{
+        collections::VecDeque,
+        f64::consts::FRAC_1_SQRT_2,
+        fmt,
+        ops::{Index, IndexMut, RangeInclusive},
+        time::Duration,
+    },
 };
 
 /// An infinite impulse response (IIR) filter.
@@ -57,3 +64,1642 @@ impl IIRFilter {
         }
     }
 }
+
+fn to_buffer_size(channels: usize, samples: usize) -> Result<(i32, i32)> {
+    match (i32::try_from(channels), i32::try_from(samples)) {
+        (Ok(channels), Ok(samples)) => Ok((channels, samples)),
+        _ => invalid_argument("the buffer size doesn't fit in an i32"),
+    }
+}
+
+/// An owned multi-channel buffer of audio samples.
+pub struct AudioBuffer(pub(crate) UniquePtr<juce::AudioSampleBuffer>);
+
+unsafe impl Send for AudioBuffer {}
+
+impl AudioBuffer {
+    /// Create a buffer of silence with the given number of channels and samples per channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either size doesn't fit in an `i32`, see [`try_new`](Self::try_new).
+    pub fn new(channels: usize, samples: usize) -> Self {
+        Self::try_new(channels, samples).expect("buffer size must fit in an i32")
+    }
+
+    /// Create a buffer of silence with the given number of channels and samples per channel,
+    /// returning an error if either size doesn't fit in an `i32`.
+    pub fn try_new(channels: usize, samples: usize) -> Result<Self> {
+        let (channels, samples) = to_buffer_size(channels, samples)?;
+        Ok(Self(juce::create_audio_sample_buffer(channels, samples)))
+    }
+
+    /// Returns the numbers of channels in the buffer.
+    pub fn channels(&self) -> usize {
+        self.0.get_num_channels() as usize
+    }
+
+    /// Returns the number of samples for each channel.
+    pub fn samples(&self) -> usize {
+        self.0.get_num_samples() as usize
+    }
+
+    /// Clear all the samples for all the channels.
+    pub fn clear(&mut self) {
+        self.0.pin_mut().clear();
+    }
+
+    /// Change the number of channels and samples, keeping the existing samples and clearing any
+    /// new space.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either size doesn't fit in an `i32`, see [`try_set_size`](Self::try_set_size).
+    pub fn set_size(&mut self, channels: usize, samples: usize) {
+        self.try_set_size(channels, samples)
+            .expect("buffer size must fit in an i32");
+    }
+
+    /// Change the number of channels and samples like [`set_size`](Self::set_size), returning an
+    /// error and leaving the buffer unchanged if either size doesn't fit in an `i32`.
+    pub fn try_set_size(&mut self, channels: usize, samples: usize) -> Result<()> {
+        let (channels, samples) = to_buffer_size(channels, samples)?;
+        self.0
+            .pin_mut()
+            .set_size(channels, samples, true, true, true);
+        Ok(())
+    }
+
+    /// Create a buffer from interleaved samples, e.g. `[L0, R0, L1, R1, ...]`.
+    ///
+    /// Any trailing samples that don't make up a whole frame are ignored.
+    pub fn from_interleaved(samples: &[f32], channels: usize) -> Self {
+        if channels == 0 {
+            return Self::new(0, 0);
+        }
+
+        let mut buffer = Self::new(channels, samples.len() / channels);
+
+        for (frame, samples) in samples.chunks_exact(channels).enumerate() {
+            for (channel, sample) in samples.iter().enumerate() {
+                buffer[channel][frame] = *sample;
+            }
+        }
+
+        buffer
+    }
+
+    /// Returns a copy of the samples of each channel.
+    pub fn to_channels(&self) -> Vec<Vec<f32>> {
+        (0..self.channels())
+            .map(|channel| self[channel].to_vec())
+            .collect()
+    }
+
+    /// Returns the samples interleaved into a single vector, e.g. `[L0, R0, L1, R1, ...]`.
+    pub fn to_interleaved(&self) -> Vec<f32> {
+        let channels = self.channels();
+        let mut interleaved = vec![0.0; channels * self.samples()];
+
+        for channel in 0..channels {
+            for (frame, sample) in self[channel].iter().enumerate() {
+                interleaved[frame * channels + channel] = *sample;
+            }
+        }
+
+        interleaved
+    }
+}
+
+impl Clone for AudioBuffer {
+    fn clone(&self) -> Self {
+        Self(juce::copy_audio_sample_buffer(&self.0))
+    }
+}
+
+impl fmt::Debug for AudioBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AudioBuffer")
+            .field("channels", &self.channels())
+            .field("samples", &self.samples())
+            .finish()
+    }
+}
+
+impl Index<usize> for AudioBuffer {
+    type Output = [f32];
+
+    fn index(&self, channel: usize) -> &Self::Output {
+        if channel >= self.channels() {
+            panic!("channel out of bounds");
+        }
+
+        let ptr = self.0.get_read_pointer(channel as i32);
+        let len = self.samples();
+
+        unsafe { std::slice::from_raw_parts(ptr, len) }
+    }
+}
+
+impl IndexMut<usize> for AudioBuffer {
+    fn index_mut(&mut self, channel: usize) -> &mut Self::Output {
+        if channel >= self.channels() {
+            panic!("channel out of bounds");
+        }
+
+        let ptr = self.0.pin_mut().get_write_pointer(channel as i32);
+        let len = self.samples();
+
+        unsafe { std::slice::from_raw_parts_mut(ptr, len) }
+    }
+}
+
+/// Conversions between linear gains and decibels.
+///
+/// Levels at or below a threshold are treated as silence, which avoids the infinities of
+/// converting a gain of zero. The threshold is [`Decibels::DEFAULT_MINUS_INFINITY_DB`] unless
+/// one is given.
+pub struct Decibels;
+
+impl Decibels {
+    /// The default level in decibels below which a gain is treated as silence.
+    pub const DEFAULT_MINUS_INFINITY_DB: f32 = -100.0;
+
+    /// Convert decibels to a linear gain, where silence is a gain of zero.
+    pub fn to_gain(decibels: f32) -> f32 {
+        Self::to_gain_with_threshold(decibels, Self::DEFAULT_MINUS_INFINITY_DB)
+    }
+
+    /// Convert decibels to a linear gain, treating levels at or below `minus_infinity_db` as
+    /// silence.
+    pub fn to_gain_with_threshold(decibels: f32, minus_infinity_db: f32) -> f32 {
+        juce::decibels_to_gain(decibels, minus_infinity_db)
+    }
+
+    /// Convert a linear gain to decibels, where silence is the default threshold.
+    pub fn from_gain(gain: f32) -> f32 {
+        Self::from_gain_with_threshold(gain, Self::DEFAULT_MINUS_INFINITY_DB)
+    }
+
+    /// Convert a linear gain to decibels, returning `minus_infinity_db` for silence.
+    pub fn from_gain_with_threshold(gain: f32, minus_infinity_db: f32) -> f32 {
+        juce::gain_to_decibels(gain, minus_infinity_db)
+    }
+
+    /// Format a level for display, e.g. `"+3.00 dB"` or `"-INF dB"` for silence.
+    pub fn to_string(decibels: f32, decimal_places: usize, include_suffix: bool) -> String {
+        juce::decibels_to_string(
+            decibels,
+            decimal_places as i32,
+            Self::DEFAULT_MINUS_INFINITY_DB,
+            include_suffix,
+        )
+    }
+}
+
+/// The algorithm an [`Interpolator`] uses to read between input samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolatorType {
+    /// Repeat the most recent input sample.
+    ZeroOrderHold = 0,
+
+    /// Draw a straight line between neighbouring samples.
+    Linear = 1,
+
+    /// Fit a Catmull-Rom spline through four samples.
+    CatmullRom = 2,
+
+    /// Fit a 4-point Lagrange polynomial.
+    Lagrange = 3,
+
+    /// Use a windowed sinc kernel, which is the highest quality but most expensive.
+    WindowedSinc = 4,
+}
+
+/// Resamples a single channel by reading through its input at a given speed.
+///
+/// The interpolator keeps the last few input samples between calls, so a stream can be
+/// resampled one block at a time. Use one interpolator for each channel.
+///
+/// ```no_run
+/// # use cxx_juce::juce_audio_basics::{Interpolator, InterpolatorType};
+/// let mut interpolator = Interpolator::new(InterpolatorType::Lagrange);
+///
+/// // Resample from 44.1kHz to 48kHz.
+/// let input = [0.0; 441];
+/// let mut output = [0.0; 480];
+/// let used = interpolator.process(44100.0 / 48000.0, &input, &mut output);
+/// assert!(used <= input.len() + 1);
+/// ```
+pub struct Interpolator {
+    interpolator: UniquePtr<juce::Interpolator>,
+    interpolator_type: InterpolatorType,
+}
+
+unsafe impl Send for Interpolator {}
+
+impl Interpolator {
+    /// Create an interpolator.
+    pub fn new(interpolator_type: InterpolatorType) -> Self {
+        Self {
+            interpolator: juce::create_interpolator(interpolator_type as i32),
+            interpolator_type,
+        }
+    }
+
+    /// The algorithm used to read between input samples.
+    pub fn interpolator_type(&self) -> InterpolatorType {
+        self.interpolator_type
+    }
+
+    /// The latency of the interpolation, in input samples.
+    pub fn base_latency(&self) -> f32 {
+        self.interpolator.get_base_latency()
+    }
+
+    /// Fill `output` with samples read from `input`, advancing through the input by
+    /// `speed_ratio` input samples for each output sample.
+    ///
+    /// Returns the number of input samples consumed. If more input is needed than `input`
+    /// holds, silence is read instead, and the count includes those samples.
+    ///
+    /// Panics if the speed ratio isn't positive.
+    pub fn process(&mut self, speed_ratio: f64, input: &[f32], output: &mut [f32]) -> usize {
+        assert!(speed_ratio > 0.0, "speed ratio must be positive");
+        self.interpolator
+            .pin_mut()
+            .process(speed_ratio, input, output)
+    }
+
+    /// Like [`process`](Self::process), but adds the resampled samples to `output`,
+    /// multiplied by `gain`.
+    pub fn process_adding(
+        &mut self,
+        speed_ratio: f64,
+        input: &[f32],
+        output: &mut [f32],
+        gain: f32,
+    ) -> usize {
+        assert!(speed_ratio > 0.0, "speed ratio must be positive");
+        self.interpolator
+            .pin_mut()
+            .process_adding(speed_ratio, input, output, gain)
+    }
+
+    /// Clear the stored input samples.
+    pub fn reset(&mut self) {
+        self.interpolator.pin_mut().reset();
+    }
+}
+
+/// How a [`SmoothedValue`] ramps towards its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueSmoothing {
+    /// Ramp by the same amount each sample.
+    Linear = 0,
+
+    /// Ramp by the same ratio each sample, which sounds even for gains and frequencies.
+    Multiplicative = 1,
+}
+
+/// A value that ramps smoothly towards a target, for changing parameters without clicks.
+///
+/// Set the target whenever the parameter changes, e.g. after reading a value shared with the
+/// UI thread, then take the next value for each sample in the audio callback.
+///
+/// ```no_run
+/// # use {cxx_juce::juce_audio_basics::{Decibels, SmoothedValue}, std::time::Duration};
+/// let mut volume = SmoothedValue::multiplicative(1.0).with_ramp_length(48000.0, Duration::from_millis(20));
+/// volume.set_target_value(Decibels::to_gain(-12.0));
+///
+/// let mut samples = [0.5; 64];
+/// for sample in &mut samples {
+///     *sample *= volume.next_value();
+/// }
+/// ```
+pub struct SmoothedValue {
+    value: UniquePtr<juce::SmoothedValue>,
+    smoothing: ValueSmoothing,
+}
+
+unsafe impl Send for SmoothedValue {}
+
+impl SmoothedValue {
+    /// Create a value that starts at the given value, with no ramp.
+    pub fn new(smoothing: ValueSmoothing, initial_value: f32) -> Self {
+        let initial_value = limit_smoothed_value(smoothing, initial_value);
+
+        Self {
+            value: juce::create_smoothed_value(smoothing as i32, initial_value),
+            smoothing,
+        }
+    }
+
+    /// Create a value that ramps linearly.
+    pub fn linear(initial_value: f32) -> Self {
+        Self::new(ValueSmoothing::Linear, initial_value)
+    }
+
+    /// Create a value that ramps multiplicatively.
+    ///
+    /// Multiplicative ramps only work with positive values, so values are clamped to be above
+    /// zero.
+    pub fn multiplicative(initial_value: f32) -> Self {
+        Self::new(ValueSmoothing::Multiplicative, initial_value)
+    }
+
+    /// Set the length of the ramp.
+    pub fn with_ramp_length(mut self, sample_rate: f64, ramp_length: Duration) -> Self {
+        self.reset(sample_rate, ramp_length);
+        self
+    }
+
+    /// How the value ramps towards its target.
+    pub fn smoothing(&self) -> ValueSmoothing {
+        self.smoothing
+    }
+
+    /// Change the length of the ramp, and jump to the target value.
+    pub fn reset(&mut self, sample_rate: f64, ramp_length: Duration) {
+        self.value
+            .pin_mut()
+            .reset(sample_rate, ramp_length.as_secs_f64());
+    }
+
+    /// Change the length of the ramp to a number of steps, and jump to the target value.
+    pub fn reset_steps(&mut self, steps: usize) {
+        self.value.pin_mut().reset_steps(steps as i32);
+    }
+
+    /// Start ramping towards a new target value.
+    pub fn set_target_value(&mut self, value: f32) {
+        let value = limit_smoothed_value(self.smoothing, value);
+        self.value.pin_mut().set_target_value(value);
+    }
+
+    /// Jump to a new value without ramping.
+    pub fn set_current_and_target_value(&mut self, value: f32) {
+        let value = limit_smoothed_value(self.smoothing, value);
+        self.value.pin_mut().set_current_and_target_value(value);
+    }
+
+    /// The value being ramped towards.
+    pub fn target_value(&self) -> f32 {
+        self.value.get_target_value()
+    }
+
+    /// The value the ramp has reached.
+    pub fn current_value(&self) -> f32 {
+        self.value.get_current_value()
+    }
+
+    /// Returns true if the value hasn't reached its target yet.
+    pub fn is_smoothing(&self) -> bool {
+        self.value.is_smoothing()
+    }
+
+    /// Advance the ramp by one sample, returning the new value.
+    pub fn next_value(&mut self) -> f32 {
+        self.value.pin_mut().get_next_value()
+    }
+
+    /// Advance the ramp by a number of samples, returning the new value.
+    pub fn skip(&mut self, samples: usize) -> f32 {
+        self.value.pin_mut().skip(samples as i32)
+    }
+
+    /// Multiply every channel of a buffer by the value, advancing the ramp by its length.
+    pub fn apply_gain(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        self.value.pin_mut().apply_gain(buffer.buffer.as_mut());
+    }
+}
+
+fn limit_smoothed_value(smoothing: ValueSmoothing, value: f32) -> f32 {
+    match smoothing {
+        ValueSmoothing::Linear => value,
+        ValueSmoothing::Multiplicative => value.max(f32::EPSILON),
+    }
+}
+
+/// The stages of an [`ADSR`] envelope.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ADSRParameters {
+    /// The time to rise from silence to full level after a note starts.
+    pub attack: Duration,
+
+    /// The time to fall from full level to the sustain level.
+    pub decay: Duration,
+
+    /// The level held while the note is on, from 0 to 1.
+    pub sustain: f32,
+
+    /// The time to fall to silence after the note ends.
+    pub release: Duration,
+}
+
+impl Default for ADSRParameters {
+    fn default() -> Self {
+        Self {
+            attack: Duration::from_millis(100),
+            decay: Duration::from_millis(100),
+            sustain: 1.0,
+            release: Duration::from_millis(100),
+        }
+    }
+}
+
+/// An attack, decay, sustain, release envelope, for shaping the level of a synth voice.
+pub struct ADSR {
+    adsr: UniquePtr<juce::ADSR>,
+    parameters: ADSRParameters,
+    sample_rate: Option<f64>,
+}
+
+unsafe impl Send for ADSR {}
+
+impl Default for ADSR {
+    fn default() -> Self {
+        Self {
+            adsr: juce::create_adsr(),
+            parameters: ADSRParameters::default(),
+            sample_rate: None,
+        }
+    }
+}
+
+impl ADSR {
+    /// Create an envelope with the default parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the parameters.
+    pub fn with_parameters(mut self, parameters: ADSRParameters) -> Self {
+        self.set_parameters(parameters);
+        self
+    }
+
+    /// Change the parameters.
+    ///
+    /// The sustain level is clamped between 0 and 1.
+    pub fn set_parameters(&mut self, parameters: ADSRParameters) {
+        self.parameters = ADSRParameters {
+            sustain: parameters.sustain.clamp(0.0, 1.0),
+            ..parameters
+        };
+
+        if self.sample_rate.is_some() {
+            let ADSRParameters {
+                attack,
+                decay,
+                sustain,
+                release,
+            } = self.parameters;
+
+            juce::set_adsr_parameters(
+                self.adsr.pin_mut(),
+                attack.as_secs_f32(),
+                decay.as_secs_f32(),
+                sustain,
+                release.as_secs_f32(),
+            );
+        }
+    }
+
+    /// The parameters.
+    pub fn parameters(&self) -> ADSRParameters {
+        self.parameters
+    }
+
+    /// Set the sample rate the envelope will run at.
+    ///
+    /// The envelope stays silent until the sample rate has been set.
+    pub fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.adsr.pin_mut().set_sample_rate(sample_rate);
+        self.sample_rate = Some(sample_rate);
+        self.set_parameters(self.parameters);
+    }
+
+    /// The sample rate the envelope runs at.
+    pub fn sample_rate(&self) -> Option<f64> {
+        self.sample_rate
+    }
+
+    /// Start the attack stage.
+    pub fn note_on(&mut self) {
+        self.adsr.pin_mut().note_on();
+    }
+
+    /// Start the release stage.
+    pub fn note_off(&mut self) {
+        self.adsr.pin_mut().note_off();
+    }
+
+    /// Returns true until the release stage has finished.
+    pub fn is_active(&self) -> bool {
+        self.adsr.is_active()
+    }
+
+    /// Advance the envelope by one sample, returning its level.
+    pub fn next_sample(&mut self) -> f32 {
+        self.adsr.pin_mut().get_next_sample()
+    }
+
+    /// Multiply every channel of a buffer by the envelope, advancing it by the buffer's length.
+    pub fn apply_envelope_to_buffer(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        juce::apply_envelope_to_buffer(self.adsr.pin_mut(), buffer.buffer.as_mut());
+    }
+
+    /// Return the envelope to silence immediately.
+    pub fn reset(&mut self) {
+        self.adsr.pin_mut().reset();
+    }
+}
+
+/// A MIDI message.
+pub struct MidiMessage(pub(crate) UniquePtr<juce::MidiMessage>);
+
+unsafe impl Send for MidiMessage {}
+
+impl MidiMessage {
+    /// Create a MIDI message from its raw bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        juce::create_midi_message(bytes, 0.0).map(Self)
+    }
+
+    /// The raw bytes of the message.
+    pub fn bytes(&self) -> &[u8] {
+        juce::get_raw_data(&self.0)
+    }
+
+    /// The timestamp of the message in seconds.
+    ///
+    /// Messages received from a MIDI input are timestamped using JUCE's high resolution
+    /// millisecond counter, converted to seconds.
+    pub fn timestamp(&self) -> f64 {
+        self.0.get_time_stamp()
+    }
+
+    /// Set the timestamp of the message in seconds.
+    pub fn set_timestamp(&mut self, timestamp: f64) {
+        self.0.pin_mut().set_time_stamp(timestamp);
+    }
+
+    /// Set the timestamp of the message in seconds.
+    pub fn with_timestamp(mut self, timestamp: f64) -> Self {
+        self.set_timestamp(timestamp);
+        self
+    }
+
+    /// Create a controller message.
+    pub fn controller_event(channel: i32, controller_number: i32, value: i32) -> Self {
+        Self(juce::controller_event(channel, controller_number, value))
+    }
+
+    /// The MIDI channel (1-16), or zero if this isn't a channel message.
+    pub fn channel(&self) -> i32 {
+        self.0.get_channel()
+    }
+
+    /// The type of the message.
+    pub fn message_type(&self) -> Option<MidiMessageType> {
+        self.bytes()
+            .first()
+            .and_then(|status| MidiMessageType::from_status(*status))
+    }
+
+    /// Change the MIDI channel (1-16) of a channel message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` isn't a MIDI channel.
+    pub fn set_channel(&mut self, channel: i32) {
+        assert!((1..=16).contains(&channel), "MIDI channel must be 1-16");
+        self.0.pin_mut().set_channel(channel);
+    }
+
+    /// Returns the controller number and value if this is a controller message.
+    pub fn controller(&self) -> Option<(i32, i32)> {
+        self.0.is_controller().then(|| {
+            (
+                self.0.get_controller_number(),
+                self.0.get_controller_value(),
+            )
+        })
+    }
+
+    /// Create a MIDI clock message.
+    pub fn midi_clock() -> Self {
+        Self(juce::midi_clock())
+    }
+
+    /// Create a MIDI start message.
+    pub fn midi_start() -> Self {
+        Self(juce::midi_start())
+    }
+
+    /// Create a MIDI stop message.
+    pub fn midi_stop() -> Self {
+        Self(juce::midi_stop())
+    }
+
+    /// Create a MIDI continue message.
+    pub fn midi_continue() -> Self {
+        Self(juce::midi_continue())
+    }
+
+    /// Create a song position pointer message.
+    ///
+    /// The position is measured in MIDI beats, where one MIDI beat is a sixteenth note.
+    pub fn song_position_pointer(position_in_midi_beats: i32) -> Self {
+        Self(juce::song_position_pointer(position_in_midi_beats))
+    }
+
+    /// Create a MIDI timecode quarter frame message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sequence_number` isn't 0-7 or `value` isn't 0-15.
+    pub fn quarter_frame(sequence_number: i32, value: i32) -> Self {
+        assert!(
+            (0..=7).contains(&sequence_number),
+            "quarter frame sequence number must be 0-7"
+        );
+        assert!(
+            (0..=15).contains(&value),
+            "quarter frame value must be 0-15"
+        );
+        Self(juce::quarter_frame(sequence_number, value))
+    }
+
+    /// Create a MIDI timecode full frame message.
+    pub fn full_frame(timecode: Timecode) -> Self {
+        Self(juce::full_frame(
+            timecode.hours,
+            timecode.minutes,
+            timecode.seconds,
+            timecode.frames,
+            timecode.timecode_type as i32,
+        ))
+    }
+
+    /// Returns true if this is a MIDI clock message.
+    pub fn is_midi_clock(&self) -> bool {
+        self.0.is_midi_clock()
+    }
+
+    /// Returns true if this is a MIDI start message.
+    pub fn is_midi_start(&self) -> bool {
+        self.0.is_midi_start()
+    }
+
+    /// Returns true if this is a MIDI stop message.
+    pub fn is_midi_stop(&self) -> bool {
+        self.0.is_midi_stop()
+    }
+
+    /// Returns true if this is a MIDI continue message.
+    pub fn is_midi_continue(&self) -> bool {
+        self.0.is_midi_continue()
+    }
+
+    /// Returns the position in MIDI beats if this is a song position pointer message.
+    pub fn song_position(&self) -> Option<i32> {
+        self.0
+            .is_song_position_pointer()
+            .then(|| self.0.get_song_position_pointer_midi_beat())
+    }
+
+    /// Returns the sequence number and value if this is a quarter frame message.
+    pub fn quarter_frame_data(&self) -> Option<(i32, i32)> {
+        self.0.is_quarter_frame().then(|| {
+            (
+                self.0.get_quarter_frame_sequence_number(),
+                self.0.get_quarter_frame_value(),
+            )
+        })
+    }
+
+    /// Returns the timecode if this is a full frame message.
+    pub fn full_frame_timecode(&self) -> Option<Timecode> {
+        if !self.0.is_full_frame() {
+            return None;
+        }
+
+        let [hours, minutes, seconds, frames, timecode_type] =
+            juce::get_full_frame_parameters(&self.0);
+
+        Some(Timecode {
+            hours,
+            minutes,
+            seconds,
+            frames,
+            timecode_type: SmpteTimecodeType::from_i32(timecode_type),
+        })
+    }
+}
+
+impl Clone for MidiMessage {
+    fn clone(&self) -> Self {
+        Self(juce::copy_midi_message(&self.0))
+    }
+}
+
+impl fmt::Debug for MidiMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MidiMessage")
+            .field("bytes", &self.bytes())
+            .field("timestamp", &self.timestamp())
+            .finish()
+    }
+}
+
+/// The frame rate of a SMPTE timecode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmpteTimecodeType {
+    /// 24 frames per second.
+    Fps24 = 0,
+
+    /// 25 frames per second.
+    Fps25 = 1,
+
+    /// 29.97 frames per second (30 drop frame).
+    Fps30Drop = 2,
+
+    /// 30 frames per second.
+    Fps30 = 3,
+}
+
+impl SmpteTimecodeType {
+    fn from_i32(value: i32) -> Self {
+        match value & 0x3 {
+            0 => Self::Fps24,
+            1 => Self::Fps25,
+            2 => Self::Fps30Drop,
+            _ => Self::Fps30,
+        }
+    }
+
+    /// The number of frames per second.
+    pub fn frames_per_second(&self) -> f64 {
+        match self {
+            Self::Fps24 => 24.0,
+            Self::Fps25 => 25.0,
+            Self::Fps30Drop => 30000.0 / 1001.0,
+            Self::Fps30 => 30.0,
+        }
+    }
+
+    fn nominal_frames_per_second(&self) -> i32 {
+        match self {
+            Self::Fps24 => 24,
+            Self::Fps25 => 25,
+            Self::Fps30Drop | Self::Fps30 => 30,
+        }
+    }
+}
+
+/// A SMPTE timecode position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timecode {
+    /// The hours (0-23).
+    pub hours: i32,
+
+    /// The minutes (0-59).
+    pub minutes: i32,
+
+    /// The seconds (0-59).
+    pub seconds: i32,
+
+    /// The frames.
+    pub frames: i32,
+
+    /// The frame rate.
+    pub timecode_type: SmpteTimecodeType,
+}
+
+impl Timecode {
+    /// Convert a position in seconds into a timecode.
+    pub fn from_seconds(seconds: f64, timecode_type: SmpteTimecodeType) -> Self {
+        let fps = timecode_type.nominal_frames_per_second();
+        let total_frames = (seconds.max(0.0) * timecode_type.frames_per_second()) as i64;
+        let total_frames = if timecode_type == SmpteTimecodeType::Fps30Drop {
+            let frames_per_ten_minutes = 17982;
+            let frames_per_minute = 1798;
+            let tens = total_frames / frames_per_ten_minutes;
+            let remainder = total_frames % frames_per_ten_minutes;
+            let dropped = if remainder > 1 {
+                18 * tens + 2 * ((remainder - 2) / frames_per_minute)
+            } else {
+                18 * tens
+            };
+            total_frames + dropped
+        } else {
+            total_frames
+        };
+
+        let fps = fps as i64;
+        Self {
+            hours: ((total_frames / (fps * 3600)) % 24) as i32,
+            minutes: ((total_frames / (fps * 60)) % 60) as i32,
+            seconds: ((total_frames / fps) % 60) as i32,
+            frames: (total_frames % fps) as i32,
+            timecode_type,
+        }
+    }
+
+    /// Convert the timecode into a position in seconds.
+    pub fn to_seconds(&self) -> f64 {
+        let fps = self.timecode_type.nominal_frames_per_second() as i64;
+        let total_minutes = (self.hours as i64) * 60 + self.minutes as i64;
+        let mut total_frames =
+            (total_minutes * 60 + self.seconds as i64) * fps + self.frames as i64;
+
+        if self.timecode_type == SmpteTimecodeType::Fps30Drop {
+            total_frames -= 2 * (total_minutes - total_minutes / 10);
+        }
+
+        total_frames as f64 / self.timecode_type.frames_per_second()
+    }
+
+    /// The eight quarter frame messages that together describe this timecode.
+    pub fn quarter_frames(&self) -> [MidiMessage; 8] {
+        let values = [
+            self.frames & 0x0f,
+            (self.frames >> 4) & 0x01,
+            self.seconds & 0x0f,
+            (self.seconds >> 4) & 0x03,
+            self.minutes & 0x0f,
+            (self.minutes >> 4) & 0x03,
+            self.hours & 0x0f,
+            ((self.hours >> 4) & 0x01) | ((self.timecode_type as i32) << 1),
+        ];
+
+        std::array::from_fn(|i| MidiMessage::quarter_frame(i as i32, values[i]))
+    }
+}
+
+/// Reassembles MIDI timecode from quarter frame and full frame messages.
+#[derive(Debug, Default, Clone)]
+pub struct MidiTimecodeDecoder {
+    pieces: [i32; 8],
+    received: u8,
+}
+
+impl MidiTimecodeDecoder {
+    /// Create a new decoder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process a message, returning a timecode once one has been fully received.
+    ///
+    /// A timecode assembled from quarter frames describes the position at which its first
+    /// quarter frame was sent, so it lags the sender by two frames by the time it is returned.
+    pub fn process(&mut self, message: &MidiMessage) -> Option<Timecode> {
+        if let Some(timecode) = message.full_frame_timecode() {
+            self.reset();
+            return Some(timecode);
+        }
+
+        let (sequence_number, value) = message.quarter_frame_data()?;
+        let sequence_number = (sequence_number & 0x07) as usize;
+
+        if sequence_number == 0 {
+            self.received = 0;
+        }
+
+        self.pieces[sequence_number] = value & 0x0f;
+        self.received |= 1 << sequence_number;
+
+        if sequence_number != 7 || self.received != 0xff {
+            return None;
+        }
+
+        let p = &self.pieces;
+        Some(Timecode {
+            frames: p[0] | ((p[1] & 0x01) << 4),
+            seconds: p[2] | ((p[3] & 0x03) << 4),
+            minutes: p[4] | ((p[5] & 0x03) << 4),
+            hours: p[6] | ((p[7] & 0x01) << 4),
+            timecode_type: SmpteTimecodeType::from_i32(p[7] >> 1),
+        })
+    }
+
+    /// Discard any partially received timecode.
+    pub fn reset(&mut self) {
+        self.received = 0;
+    }
+}
+
+/// The number of MIDI clock messages sent per quarter note.
+pub const MIDI_CLOCKS_PER_QUARTER_NOTE: u32 = 24;
+
+/// Follows an incoming MIDI clock, tracking its tempo, transport state and position.
+#[derive(Debug, Clone)]
+pub struct MidiClockTracker {
+    intervals: VecDeque<f64>,
+    last_clock: Option<f64>,
+    playing: bool,
+    clocks: i64,
+}
+
+impl Default for MidiClockTracker {
+    fn default() -> Self {
+        Self {
+            intervals: VecDeque::with_capacity(MIDI_CLOCKS_PER_QUARTER_NOTE as usize),
+            last_clock: None,
+            playing: false,
+            clocks: 0,
+        }
+    }
+}
+
+impl MidiClockTracker {
+    /// Create a new tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process an incoming message, using its timestamp to measure the clock.
+    pub fn process(&mut self, message: &MidiMessage) {
+        if message.is_midi_clock() {
+            let timestamp = message.timestamp();
+
+            if let Some(last_clock) = self.last_clock.replace(timestamp) {
+                let interval = timestamp - last_clock;
+                if interval > 0.0 {
+                    if self.intervals.len() == MIDI_CLOCKS_PER_QUARTER_NOTE as usize {
+                        self.intervals.pop_front();
+                    }
+                    self.intervals.push_back(interval);
+                }
+            }
+
+            if self.playing {
+                self.clocks += 1;
+            }
+        } else if message.is_midi_start() {
+            self.playing = true;
+            self.clocks = 0;
+        } else if message.is_midi_continue() {
+            self.playing = true;
+        } else if message.is_midi_stop() {
+            self.playing = false;
+        } else if let Some(position) = message.song_position() {
+            self.clocks = position as i64 * 6;
+        }
+    }
+
+    /// The tempo of the clock in beats per minute, averaged over the last quarter note.
+    pub fn bpm(&self) -> Option<f64> {
+        if self.intervals.is_empty() {
+            return None;
+        }
+
+        let average = self.intervals.iter().sum::<f64>() / self.intervals.len() as f64;
+        Some(60.0 / (average * MIDI_CLOCKS_PER_QUARTER_NOTE as f64))
+    }
+
+    /// Returns true if the sender's transport is running.
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// The position of the sender's transport in quarter notes.
+    pub fn position_in_quarter_notes(&self) -> f64 {
+        self.clocks as f64 / MIDI_CLOCKS_PER_QUARTER_NOTE as f64
+    }
+
+    /// Return to the state of a new tracker, e.g. after the clock source has changed.
+    ///
+    /// This forgets the measured tempo, stops the transport and moves it back to the start.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Generates timestamped MIDI clock messages for a given tempo.
+#[derive(Debug, Clone)]
+pub struct MidiClockGenerator {
+    bpm: f64,
+    next_clock: f64,
+}
+
+impl MidiClockGenerator {
+    /// Create a generator whose first clock is at the given timestamp in seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bpm` isn't finite and positive.
+    pub fn new(bpm: f64, start_time: f64) -> Self {
+        assert_valid_bpm(bpm);
+        Self {
+            bpm,
+            next_clock: start_time,
+        }
+    }
+
+    /// The tempo in beats per minute.
+    pub fn bpm(&self) -> f64 {
+        self.bpm
+    }
+
+    /// Change the tempo, taking effect from the next clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bpm` isn't finite and positive.
+    pub fn set_bpm(&mut self, bpm: f64) {
+        assert_valid_bpm(bpm);
+        self.bpm = bpm;
+    }
+
+    /// The time in seconds between consecutive clock messages.
+    pub fn clock_interval(&self) -> f64 {
+        60.0 / (self.bpm * MIDI_CLOCKS_PER_QUARTER_NOTE as f64)
+    }
+
+    /// Returns the clock messages that are due before the given timestamp in seconds.
+    pub fn clocks_until(&mut self, end_time: f64) -> Vec<MidiMessage> {
+        let mut clocks = vec![];
+
+        while self.next_clock < end_time {
+            clocks.push(MidiMessage::midi_clock().with_timestamp(self.next_clock));
+            self.next_clock += self.clock_interval();
+        }
+
+        clocks
+    }
+}
+
+fn assert_valid_bpm(bpm: f64) {
+    assert!(
+        bpm.is_finite() && bpm > 0.0,
+        "MIDI clock tempo must be finite and positive"
+    );
+}
+
+/// A sequence of timestamped MIDI events.
+pub struct MidiBuffer(pub(crate) UniquePtr<juce::MidiBuffer>);
+
+unsafe impl Send for MidiBuffer {}
+
+impl Default for MidiBuffer {
+    fn default() -> Self {
+        Self(juce::create_midi_buffer())
+    }
+}
+
+impl MidiBuffer {
+    /// Create an empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a message at the given sample position.
+    pub fn add_event(&mut self, message: &MidiMessage, sample_position: i32) {
+        juce::add_midi_message(self.0.pin_mut(), &message.0, sample_position);
+    }
+
+    /// Add a message from its raw bytes at the given sample position.
+    pub fn add_raw_event(&mut self, bytes: &[u8], sample_position: i32) {
+        juce::add_raw_midi_event(self.0.pin_mut(), bytes, sample_position);
+    }
+
+    /// Remove all events from the buffer.
+    pub fn clear(&mut self) {
+        self.0.pin_mut().clear();
+    }
+
+    /// Returns true if the buffer contains no events.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The number of events in the buffer.
+    pub fn num_events(&self) -> usize {
+        self.0.get_num_events() as usize
+    }
+
+    /// Iterate over the events in the buffer in order of sample position.
+    pub fn iter(&self) -> MidiBufferIterator<'_> {
+        MidiBufferIterator {
+            data: juce::get_midi_buffer_data(&self.0),
+        }
+    }
+}
+
+impl Clone for MidiBuffer {
+    fn clone(&self) -> Self {
+        Self(juce::copy_midi_buffer(&self.0))
+    }
+}
+
+impl fmt::Debug for MidiBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a MidiBuffer {
+    type Item = MidiBufferEvent<'a>;
+    type IntoIter = MidiBufferIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An event in a [`MidiBuffer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MidiBufferEvent<'a> {
+    /// The position of the event in samples.
+    pub sample_position: i32,
+
+    /// The raw bytes of the message.
+    pub bytes: &'a [u8],
+}
+
+impl MidiBufferEvent<'_> {
+    /// Copy the event into a [`MidiMessage`], timestamped with its sample position.
+    pub fn to_message(&self) -> Result<MidiMessage> {
+        juce::create_midi_message(self.bytes, self.sample_position as f64).map(MidiMessage)
+    }
+}
+
+/// An iterator over the events in a [`MidiBuffer`].
+pub struct MidiBufferIterator<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for MidiBufferIterator<'a> {
+    type Item = MidiBufferEvent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Each event is stored as a native-endian i32 sample position, followed by a
+        // native-endian u16 byte count, followed by the message bytes.
+        const HEADER_SIZE: usize = 6;
+
+        if self.data.len() < HEADER_SIZE {
+            return None;
+        }
+
+        let (header, rest) = self.data.split_at(HEADER_SIZE);
+        let sample_position = i32::from_ne_bytes([header[0], header[1], header[2], header[3]]);
+        let size = u16::from_ne_bytes([header[4], header[5]]) as usize;
+
+        let (bytes, rest) = rest.split_at(size.min(rest.len()));
+        self.data = rest;
+
+        Some(MidiBufferEvent {
+            sample_position,
+            bytes,
+        })
+    }
+}
+
+pub use crate::juce::MidiRPNMessage;
+
+impl MidiRPNMessage {
+    /// The registered parameter number for pitch bend sensitivity.
+    pub const PITCH_BEND_SENSITIVITY: i32 = 0;
+
+    /// The registered parameter number for fine tuning.
+    pub const FINE_TUNING: i32 = 1;
+
+    /// The registered parameter number for coarse tuning.
+    pub const COARSE_TUNING: i32 = 2;
+
+    /// Create a message setting the pitch bend range of a channel.
+    pub fn pitch_bend_range(channel: i32, semitones: i32, cents: i32) -> Self {
+        Self {
+            channel,
+            parameter_number: Self::PITCH_BEND_SENSITIVITY,
+            value: (semitones << 7) | cents,
+            is_nrpn: false,
+            is_14_bit_value: true,
+        }
+    }
+}
+
+/// Parses RPN and NRPN messages from a stream of controller messages.
+pub struct MidiRPNDetector(UniquePtr<juce::MidiRPNDetector>);
+
+unsafe impl Send for MidiRPNDetector {}
+
+impl Default for MidiRPNDetector {
+    fn default() -> Self {
+        Self(juce::create_midi_rpn_detector())
+    }
+}
+
+impl MidiRPNDetector {
+    /// Create a new detector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process a controller message, returning an RPN/NRPN message once one is complete.
+    ///
+    /// Returns [`None`] if `channel` isn't a MIDI channel (1-16).
+    pub fn parse_controller_message(
+        &mut self,
+        channel: i32,
+        controller_number: i32,
+        controller_value: i32,
+    ) -> Option<MidiRPNMessage> {
+        if !(1..=16).contains(&channel) {
+            return None;
+        }
+
+        let mut result = MidiRPNMessage::default();
+
+        juce::parse_controller_message(
+            self.0.pin_mut(),
+            channel,
+            controller_number,
+            controller_value,
+            &mut result,
+        )
+        .then_some(result)
+    }
+
+    /// Process a message, returning an RPN/NRPN message once one is complete.
+    ///
+    /// Messages that aren't controller messages are ignored.
+    pub fn process(&mut self, message: &MidiMessage) -> Option<MidiRPNMessage> {
+        let (controller_number, controller_value) = message.controller()?;
+        self.parse_controller_message(message.channel(), controller_number, controller_value)
+    }
+
+    /// Discard any partially received messages.
+    pub fn reset(&mut self) {
+        self.0.pin_mut().reset();
+    }
+}
+
+/// Generates the controller messages that make up RPN and NRPN messages.
+pub struct MidiRPNGenerator;
+
+impl MidiRPNGenerator {
+    /// Generate the sequence of controller messages for the given RPN/NRPN message.
+    pub fn generate(message: MidiRPNMessage) -> MidiBuffer {
+        MidiBuffer(juce::generate_rpn(message))
+    }
+}
+
+/// Vectorised operations on slices of samples, using the SIMD instructions of the platform.
+///
+/// Operations on two slices only process as many samples as the shorter slice.
+pub struct FloatVectorOperations;
+
+impl FloatVectorOperations {
+    /// Add each sample of `src` to the matching sample of `dest`.
+    pub fn add(dest: &mut [f32], src: &[f32]) {
+        let len = dest.len().min(src.len());
+        juce::float_vector_add(&mut dest[..len], &src[..len]);
+    }
+
+    /// Add an amount to each sample.
+    pub fn add_value(dest: &mut [f32], amount: f32) {
+        juce::float_vector_add_value(dest, amount);
+    }
+
+    /// Multiply each sample of `dest` by the matching sample of `src`.
+    pub fn multiply(dest: &mut [f32], src: &[f32]) {
+        let len = dest.len().min(src.len());
+        juce::float_vector_multiply(&mut dest[..len], &src[..len]);
+    }
+
+    /// Multiply each sample by a value.
+    pub fn multiply_by_value(dest: &mut [f32], multiplier: f32) {
+        juce::float_vector_multiply_by_value(dest, multiplier);
+    }
+
+    /// Copy the samples of `src` into `dest`, multiplying each by a value.
+    pub fn copy_with_multiply(dest: &mut [f32], src: &[f32], multiplier: f32) {
+        let len = dest.len().min(src.len());
+        juce::float_vector_copy_with_multiply(&mut dest[..len], &src[..len], multiplier);
+    }
+
+    /// Find the smallest and largest samples, or `None` if there are no samples.
+    pub fn find_min_and_max(src: &[f32]) -> Option<(f32, f32)> {
+        if src.is_empty() {
+            return None;
+        }
+
+        let [min, max] = juce::float_vector_find_min_and_max(src);
+        Some((min, max))
+    }
+
+    /// Limit each sample to be between `low` and `high`.
+    ///
+    /// Panics if `low` is greater than `high`.
+    pub fn clip(samples: &mut [f32], low: f32, high: f32) {
+        assert!(low <= high, "low must be less than or equal to high");
+        juce::float_vector_clip(samples, low, high);
+    }
+
+    /// Convert fixed point samples in `src` to floating point samples in `dest`, multiplying
+    /// each by a value, e.g. `1.0 / i32::MAX as f32` to convert full scale 32-bit samples.
+    pub fn convert_fixed_to_float(dest: &mut [f32], src: &[i32], multiplier: f32) {
+        let len = dest.len().min(src.len());
+        juce::float_vector_convert_fixed_to_float(&mut dest[..len], &src[..len], multiplier);
+    }
+}
+
+/// The type of a MIDI message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MidiMessageType {
+    /// A note off message.
+    NoteOff,
+
+    /// A note on message.
+    NoteOn,
+
+    /// A polyphonic aftertouch message.
+    Aftertouch,
+
+    /// A controller message.
+    Controller,
+
+    /// A program change message.
+    ProgramChange,
+
+    /// A channel pressure message.
+    ChannelPressure,
+
+    /// A pitch wheel message.
+    PitchWheel,
+
+    /// A system exclusive message.
+    SysEx,
+
+    /// A system common message, e.g. song position pointer or MIDI timecode.
+    SystemCommon,
+
+    /// A system real-time message, e.g. clock, start and stop.
+    SystemRealtime,
+}
+
+impl MidiMessageType {
+    /// The type of message with the given status byte.
+    pub fn from_status(status: u8) -> Option<Self> {
+        Some(match status {
+            0x80..=0x8f => Self::NoteOff,
+            0x90..=0x9f => Self::NoteOn,
+            0xa0..=0xaf => Self::Aftertouch,
+            0xb0..=0xbf => Self::Controller,
+            0xc0..=0xcf => Self::ProgramChange,
+            0xd0..=0xdf => Self::ChannelPressure,
+            0xe0..=0xef => Self::PitchWheel,
+            0xf0 => Self::SysEx,
+            0xf1..=0xf7 => Self::SystemCommon,
+            0xf8..=0xff => Self::SystemRealtime,
+            _ => return None,
+        })
+    }
+
+    fn mask(self) -> u16 {
+        1 << self as u16
+    }
+
+    fn has_note_number(self) -> bool {
+        matches!(self, Self::NoteOff | Self::NoteOn | Self::Aftertouch)
+    }
+}
+
+/// A curve applied to the velocity of note on messages.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum VelocityCurve {
+    /// Leave velocities unchanged.
+    #[default]
+    Linear,
+
+    /// Replace every velocity with a fixed value (1-127).
+    Fixed(u8),
+
+    /// Raise the normalised velocity to the given power.
+    ///
+    /// Exponents less than one make the response softer, exponents greater than one make it harder.
+    Power(f32),
+
+    /// Map each velocity through a lookup table.
+    Table(Box<[u8; 128]>),
+}
+
+impl VelocityCurve {
+    /// Apply the curve to a velocity.
+    ///
+    /// The result is always between 1 and 127, so a note on never becomes a note off.
+    pub fn apply(&self, velocity: u8) -> u8 {
+        let velocity = velocity.min(127);
+
+        let result = match self {
+            Self::Linear => velocity,
+            Self::Fixed(value) => *value,
+            Self::Power(exponent) => {
+                ((velocity as f32 / 127.0).powf(*exponent) * 127.0).round() as u8
+            }
+            Self::Table(table) => table[velocity as usize],
+        };
+
+        result.clamp(1, 127)
+    }
+}
+
+/// Filters and remaps MIDI messages.
+///
+/// Messages are filtered by type, then by channel and note range, before being moved onto their
+/// output channel and having their velocity adjusted.
+#[derive(Debug, Clone)]
+pub struct MidiFilter {
+    blocked_types: u16,
+    input_channels: u16,
+    channel_map: [u8; 16],
+    note_range: RangeInclusive<u8>,
+    splits: Vec<(RangeInclusive<u8>, u8)>,
+    velocity_curve: VelocityCurve,
+}
+
+impl Default for MidiFilter {
+    fn default() -> Self {
+        Self {
+            blocked_types: 0,
+            input_channels: u16::MAX,
+            channel_map: std::array::from_fn(|channel| channel as u8),
+            note_range: 0..=127,
+            splits: vec![],
+            velocity_curve: VelocityCurve::default(),
+        }
+    }
+}
+
+fn channel_index(channel: i32) -> usize {
+    assert!((1..=16).contains(&channel), "MIDI channel must be 1-16");
+    (channel - 1) as usize
+}
+
+fn note_range(range: RangeInclusive<i32>) -> RangeInclusive<u8> {
+    let clamp = |note: i32| note.clamp(0, 127) as u8;
+    clamp(*range.start())..=clamp(*range.end())
+}
+
+impl MidiFilter {
+    /// Create a filter that passes every message unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Block messages of the given types.
+    pub fn with_blocked_types(mut self, types: impl IntoIterator<Item = MidiMessageType>) -> Self {
+        for message_type in types {
+            self.blocked_types |= message_type.mask();
+        }
+        self
+    }
+
+    /// Only pass messages of the given types.
+    pub fn with_allowed_types(mut self, types: impl IntoIterator<Item = MidiMessageType>) -> Self {
+        self.blocked_types = !types
+            .into_iter()
+            .fold(0, |mask, message_type| mask | message_type.mask());
+        self
+    }
+
+    /// Only pass channel messages on the given MIDI channels (1-16).
+    ///
+    /// Messages that aren't channel messages are unaffected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the channels isn't a MIDI channel.
+    pub fn with_input_channels(mut self, channels: impl IntoIterator<Item = i32>) -> Self {
+        self.input_channels = channels
+            .into_iter()
+            .map(channel_index)
+            .fold(0, |mask, index| mask | 1 << index);
+        self
+    }
+
+    /// Move channel messages on one MIDI channel (1-16) onto another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` isn't a MIDI channel.
+    pub fn with_channel_mapping(mut self, from: i32, to: i32) -> Self {
+        self.channel_map[channel_index(from)] = channel_index(to) as u8;
+        self
+    }
+
+    /// Move all channel messages onto the given MIDI channel (1-16).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` isn't a MIDI channel.
+    pub fn with_output_channel(mut self, channel: i32) -> Self {
+        self.channel_map = [channel_index(channel) as u8; 16];
+        self
+    }
+
+    /// Only pass note messages whose note number is in the given range.
+    pub fn with_note_range(mut self, range: RangeInclusive<i32>) -> Self {
+        self.note_range = note_range(range);
+        self
+    }
+
+    /// Send note messages in the given range to the given MIDI channel, splitting the keyboard.
+    ///
+    /// Splits take precedence over channel mappings. If ranges overlap, the first split added wins.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` isn't a MIDI channel.
+    pub fn with_split(mut self, range: RangeInclusive<i32>, channel: i32) -> Self {
+        self.splits
+            .push((note_range(range), channel_index(channel) as u8));
+        self
+    }
+
+    /// Apply a curve to the velocity of note on messages.
+    pub fn with_velocity_curve(mut self, curve: VelocityCurve) -> Self {
+        self.velocity_curve = curve;
+        self
+    }
+
+    /// Filter the raw bytes of a message, returning the bytes to pass on.
+    ///
+    /// The scratch space is used to hold the bytes of a modified channel message, so no allocation
+    /// takes place.
+    pub fn filter_bytes<'a>(&self, bytes: &'a [u8], scratch: &'a mut [u8; 3]) -> Option<&'a [u8]> {
+        let status = *bytes.first()?;
+        let Some(message_type) = MidiMessageType::from_status(status) else {
+            return Some(bytes);
+        };
+
+        if self.blocked_types & message_type.mask() != 0 {
+            return None;
+        }
+
+        if status >= 0xf0 {
+            return Some(bytes);
+        }
+
+        let channel = (status & 0x0f) as usize;
+        if self.input_channels & (1 << channel) == 0 {
+            return None;
+        }
+
+        let len = bytes.len().min(3);
+        scratch[..len].copy_from_slice(&bytes[..len]);
+
+        let mut output_channel = self.channel_map[channel];
+
+        if message_type.has_note_number() && len > 1 {
+            let note = scratch[1];
+            if !self.note_range.contains(&note) {
+                return None;
+            }
+
+            if let Some((_, channel)) = self.splits.iter().find(|(range, _)| range.contains(&note))
+            {
+                output_channel = *channel;
+            }
+        }
+
+        scratch[0] = (status & 0xf0) | output_channel;
+
+        if message_type == MidiMessageType::NoteOn && len == 3 && scratch[2] > 0 {
+            scratch[2] = self.velocity_curve.apply(scratch[2]);
+        }
+
+        Some(&scratch[..len])
+    }
+
+    /// Filter a message, returning the message to pass on.
+    pub fn process(&self, message: MidiMessage) -> Option<MidiMessage> {
+        let mut scratch = [0; 3];
+        let bytes = self.filter_bytes(message.bytes(), &mut scratch)?;
+
+        if bytes == message.bytes() {
+            return Some(message);
+        }
+
+        juce::create_midi_message(bytes, message.timestamp())
+            .ok()
+            .map(MidiMessage)
+    }
+
+    /// Filter every event in the input buffer, replacing the contents of the output buffer.
+    pub fn process_buffer(&self, input: &MidiBuffer, output: &mut MidiBuffer) {
+        output.clear();
+
+        let mut scratch = [0; 3];
+        for event in input {
+            if let Some(bytes) = self.filter_bytes(event.bytes, &mut scratch) {
+                output.add_raw_event(bytes, event.sample_position);
+            }
+        }
+    }
+}
@@ -1,7 +1,11 @@
 //! Play and record from audio and MIDI I/O devices.
 
 use {
-    crate::{juce, Result, JUCE},
+    crate::{
+        invalid_argument, juce,
+        juce_audio_basics::{AudioBuffer, MidiBuffer, MidiFilter, MidiMessage},
+        Result, JUCE,
+    },
     std::{
         marker::PhantomData,
         ops::{Index, IndexMut},
@@ -9,9 +13,12 @@ use {
     },
 };
 
+#[cfg(feature = "juce_audio_utils")]
+use crate::juce_audio_processors::{AudioProcessorPlayer, JuceAudioProcessor};
+
 /// A multi-channel buffer of read-only audio samples.
 pub struct InputAudioSampleBuffer<'a> {
-    buffer: &'a juce::AudioSampleBuffer,
+    pub(crate) buffer: &'a juce::AudioSampleBuffer,
 }
 
 impl<'a> InputAudioSampleBuffer<'a> {
@@ -30,6 +37,12 @@ impl<'a> InputAudioSampleBuffer<'a> {
     }
 }
 
+impl<'a> From<&'a AudioBuffer> for InputAudioSampleBuffer<'a> {
+    fn from(buffer: &'a AudioBuffer) -> Self {
+        Self::new(&buffer.0)
+    }
+}
+
 impl Index<usize> for InputAudioSampleBuffer<'_> {
     type Output = [f32];
 
@@ -47,7 +60,7 @@ impl Index<usize> for InputAudioSampleBuffer<'_> {
 
 /// A multi-channel buffer of read-write audio samples.
 pub struct OutputAudioSampleBuffer<'a> {
-    buffer: Pin<&'a mut juce::AudioSampleBuffer>,
+    pub(crate) buffer: Pin<&'a mut juce::AudioSampleBuffer>,
 }
 
 impl<'a> OutputAudioSampleBuffer<'a> {
@@ -69,6 +82,17 @@ impl<'a> OutputAudioSampleBuffer<'a> {
     pub fn clear(&mut self) {
         self.buffer.as_mut().clear();
     }
+
+    /// Borrow the buffer as a read-only buffer.
+    pub fn as_input(&self) -> InputAudioSampleBuffer<'_> {
+        InputAudioSampleBuffer::new(&self.buffer)
+    }
+}
+
+impl<'a> From<&'a mut AudioBuffer> for OutputAudioSampleBuffer<'a> {
+    fn from(buffer: &'a mut AudioBuffer) -> Self {
+        Self::new(buffer.0.pin_mut())
+    }
 }
 
 impl Index<usize> for OutputAudioSampleBuffer<'_> {
@@ -227,7 +251,7 @@ impl<'juce> AudioDeviceManager<'juce> {
     pub fn new(_juce: &'juce JUCE) -> Self {
         Self {
             device_manager: juce::create_audio_device_manager(),
-            _juce: PhantomData::default(),
+            _juce: PhantomData,
         }
     }
 
@@ -238,6 +262,30 @@ impl<'juce> AudioDeviceManager<'juce> {
             .initialise_with_default_devices(input_channels as i32, output_channels as i32)
     }
 
+    /// Resets to the device setup saved with [`AudioDeviceManager::state_xml`], falling back to
+    /// the default devices if the saved devices aren't available.
+    pub fn initialise_with_saved_state(
+        &mut self,
+        input_channels: usize,
+        output_channels: usize,
+        saved_state: &str,
+    ) -> Result<()> {
+        self.device_manager.pin_mut().initialise_with_saved_state(
+            input_channels as i32,
+            output_channels as i32,
+            saved_state,
+        )
+    }
+
+    /// The current device setup as an XML document that can be stored, e.g. in a
+    /// [`PropertiesFile`](crate::juce_data_structures::PropertiesFile), and restored with
+    /// [`AudioDeviceManager::initialise_with_saved_state`].
+    ///
+    /// Returns [`None`] if the default devices are being used.
+    pub fn state_xml(&self) -> Option<String> {
+        Some(self.device_manager.create_state_xml()).filter(|xml| !xml.is_empty())
+    }
+
     /// Get the current device setup.
     pub fn audio_device_setup(&self) -> AudioDeviceSetup {
         AudioDeviceSetup(self.device_manager.get_audio_device_setup())
@@ -293,11 +341,30 @@ impl<'juce> AudioDeviceManager<'juce> {
     ) -> AudioCallbackHandle<'_> {
         let callback = Box::new(callback);
 
-        AudioCallbackHandle(
-            self.device_manager
+        AudioCallbackHandle {
+            _handle: self
+                .device_manager
                 .pin_mut()
                 .add_audio_callback(Box::new(callback)),
-        )
+        }
+    }
+
+    /// Play a processor through the current audio device, passing it MIDI from the enabled MIDI
+    /// inputs.
+    ///
+    /// The returned [`AudioProcessorPlayerHandle`] owns the player; when it's dropped playback
+    /// stops.
+    #[cfg(feature = "juce_audio_utils")]
+    pub fn add_audio_processor_player<P: JuceAudioProcessor>(
+        &mut self,
+        mut player: AudioProcessorPlayer<P>,
+    ) -> AudioProcessorPlayerHandle<'_, P> {
+        let handle = self
+            .device_manager
+            .pin_mut()
+            .add_audio_processor_player(player.player.pin_mut());
+
+        AudioProcessorPlayerHandle { handle, player }
     }
 
     /// Registers an audio device type.
@@ -314,6 +381,155 @@ impl<'juce> AudioDeviceManager<'juce> {
             .pin_mut()
             .set_current_audio_device_type(device_type);
     }
+
+    /// Enable or disable the MIDI input device with the given identifier.
+    pub fn set_midi_input_device_enabled(&mut self, identifier: &str, enabled: bool) {
+        self.device_manager
+            .pin_mut()
+            .set_midi_input_device_enabled(identifier, enabled);
+    }
+
+    /// Returns true if the MIDI input device with the given identifier is enabled.
+    pub fn is_midi_input_device_enabled(&self, identifier: &str) -> bool {
+        self.device_manager.is_midi_input_device_enabled(identifier)
+    }
+
+    /// Set the default MIDI output device.
+    ///
+    /// An empty identifier closes the current default MIDI output device.
+    pub fn set_default_midi_output_device(&mut self, identifier: &str) {
+        self.device_manager
+            .pin_mut()
+            .set_default_midi_output_device(identifier);
+    }
+
+    /// The identifier of the default MIDI output device, or an empty string if there isn't one.
+    pub fn default_midi_output_identifier(&self) -> String {
+        self.device_manager.get_default_midi_output_identifier()
+    }
+
+    /// Route messages from all enabled MIDI inputs to the default MIDI output.
+    ///
+    /// When the returned [`MidiThruHandle`] is dropped the routing is removed.
+    pub fn add_midi_thru(&mut self, midi_thru: MidiThru) -> MidiThruHandle<'_> {
+        MidiThruHandle {
+            _handle: self
+                .device_manager
+                .pin_mut()
+                .add_midi_thru(Box::new(midi_thru)),
+        }
+    }
+}
+
+type MidiThruFilter = Box<dyn FnMut(&MidiMessage) -> bool + Send>;
+
+/// Routing options for forwarding incoming MIDI to the default MIDI output.
+///
+/// Register with [`AudioDeviceManager::add_midi_thru`].
+#[derive(Default)]
+pub struct MidiThru {
+    midi_filter: MidiFilter,
+    filter: Option<MidiThruFilter>,
+}
+
+impl MidiThru {
+    /// Create a MIDI thru that forwards every message unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only forward channel messages on the given MIDI channels (1-16).
+    ///
+    /// Messages that aren't channel messages, such as clock and sysex, are unaffected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the channels isn't a MIDI channel.
+    pub fn with_input_channels(mut self, channels: impl IntoIterator<Item = i32>) -> Self {
+        self.midi_filter = self.midi_filter.with_input_channels(channels);
+        self
+    }
+
+    /// Move all forwarded channel messages onto the given MIDI channel (1-16).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` isn't a MIDI channel.
+    pub fn with_output_channel(mut self, channel: i32) -> Self {
+        self.midi_filter = self.midi_filter.with_output_channel(channel);
+        self
+    }
+
+    /// Filter and remap forwarded messages using a [`MidiFilter`].
+    ///
+    /// This replaces any input or output channels set previously.
+    pub fn with_midi_filter(mut self, midi_filter: MidiFilter) -> Self {
+        self.midi_filter = midi_filter;
+        self
+    }
+
+    /// Only forward messages for which the filter returns true.
+    ///
+    /// The filter is called on the MIDI thread, after any [`MidiFilter`] has been applied.
+    pub fn with_filter(
+        mut self,
+        filter: impl FnMut(&MidiMessage) -> bool + Send + 'static,
+    ) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    fn process(&mut self, message: MidiMessage) -> Option<MidiMessage> {
+        let message = self.midi_filter.process(message)?;
+
+        if let Some(filter) = &mut self.filter {
+            if !filter(&message) {
+                return None;
+            }
+        }
+
+        Some(message)
+    }
+}
+
+/// A handle to a registered MIDI thru.
+///
+/// When this handle is dropped the routing is removed.
+#[must_use]
+pub struct MidiThruHandle<'a> {
+    _handle: cxx::UniquePtr<juce::MidiThruHandle<'a>>,
+}
+
+/// A handle to a player registered with an [`AudioDeviceManager`].
+///
+/// When this handle is dropped the player is removed.
+#[cfg(feature = "juce_audio_utils")]
+#[must_use]
+pub struct AudioProcessorPlayerHandle<'a, P> {
+    // Declared before the player so that it's dropped, and removes the player, first.
+    handle: cxx::UniquePtr<juce::AudioProcessorPlayerHandle<'a>>,
+    player: AudioProcessorPlayer<P>,
+}
+
+#[cfg(feature = "juce_audio_utils")]
+impl<P: JuceAudioProcessor> AudioProcessorPlayerHandle<'_, P> {
+    /// The player.
+    pub fn player(&self) -> &AudioProcessorPlayer<P> {
+        &self.player
+    }
+
+    /// The player, whose processor can be changed with [`AudioProcessorPlayer::with_processor`]
+    /// while it plays.
+    pub fn player_mut(&mut self) -> &mut AudioProcessorPlayer<P> {
+        &mut self.player
+    }
+
+    /// Remove the player from the device manager and return it.
+    pub fn into_player(self) -> AudioProcessorPlayer<P> {
+        let Self { handle, player } = self;
+        drop(handle);
+        player
+    }
 }
 
 /// A trait that can be implemented to receive audio callbacks.
@@ -344,7 +560,9 @@ pub(crate) type BoxedAudioIODevice = Box<dyn AudioIODevice>;
 ///
 /// When this handle is dropped the callback is removed.
 #[must_use]
-pub struct AudioCallbackHandle<'a>(cxx::UniquePtr<juce::AudioCallbackHandle<'a>>);
+pub struct AudioCallbackHandle<'a> {
+    _handle: cxx::UniquePtr<juce::AudioCallbackHandle<'a>>,
+}
 
 /// A trait representing a type of audio driver (e.g. CoreAudio, ASIO, etc.).
 pub trait AudioIODeviceType {
@@ -615,9 +833,245 @@ impl AudioIODevice for cxx::UniquePtr<juce::AudioIODevice> {
     }
 }
 
+pub use crate::juce::MidiDeviceInfo;
+
+/// A trait that can be implemented to receive incoming MIDI messages.
+///
+/// This trait requires that implementors are [`Send`] because the callbacks will occur on the MIDI thread.
+pub trait MidiInputCallback: Send {
+    /// Called for each incoming message.
+    ///
+    /// Messages are timestamped in seconds using JUCE's high resolution millisecond counter.
+    fn handle_incoming_midi_message(&mut self, message: MidiMessage);
+}
+
+pub(crate) type BoxedMidiInputCallback = Box<dyn MidiInputCallback>;
+
+/// A [`MidiInputCallback`] that passes incoming messages through a [`MidiFilter`].
+pub struct FilteredMidiInputCallback<C> {
+    filter: MidiFilter,
+    callback: C,
+}
+
+impl<C> FilteredMidiInputCallback<C> {
+    /// Wrap a callback so that it only receives messages that pass the filter.
+    pub fn new(filter: MidiFilter, callback: C) -> Self {
+        Self { filter, callback }
+    }
+}
+
+impl<C: MidiInputCallback> MidiInputCallback for FilteredMidiInputCallback<C> {
+    fn handle_incoming_midi_message(&mut self, message: MidiMessage) {
+        if let Some(message) = self.filter.process(message) {
+            self.callback.handle_incoming_midi_message(message);
+        }
+    }
+}
+
+/// An open MIDI input device.
+pub struct MidiInput<'juce> {
+    input: cxx::UniquePtr<juce::MidiInput>,
+    _juce: PhantomData<&'juce ()>,
+}
+
+impl<'juce> MidiInput<'juce> {
+    /// Returns the available MIDI input devices.
+    pub fn devices() -> Vec<MidiDeviceInfo> {
+        juce::get_available_midi_input_devices()
+    }
+
+    /// Returns the default MIDI input device.
+    pub fn default_device() -> MidiDeviceInfo {
+        juce::get_default_midi_input_device()
+    }
+
+    /// Open the MIDI input device with the given identifier.
+    ///
+    /// Messages are passed to the callback once the device has been started.
+    pub fn open(
+        _juce: &'juce JUCE,
+        identifier: &str,
+        callback: impl MidiInputCallback + 'static,
+    ) -> Result<Self> {
+        let callback = Box::new(callback);
+
+        Ok(Self {
+            input: juce::open_midi_input(identifier, Box::new(callback))?,
+            _juce: PhantomData,
+        })
+    }
+
+    /// Information about the device.
+    pub fn device_info(&self) -> MidiDeviceInfo {
+        self.input.get_device_info()
+    }
+
+    /// Start receiving messages.
+    pub fn start(&mut self) {
+        self.input.pin_mut().start();
+    }
+
+    /// Stop receiving messages.
+    pub fn stop(&mut self) {
+        self.input.pin_mut().stop();
+    }
+}
+
+/// An open MIDI output device.
+pub struct MidiOutput<'juce> {
+    output: cxx::UniquePtr<juce::MidiOutput>,
+    _juce: PhantomData<&'juce ()>,
+}
+
+unsafe impl Send for MidiOutput<'_> {}
+
+impl<'juce> MidiOutput<'juce> {
+    /// Returns the available MIDI output devices.
+    pub fn devices() -> Vec<MidiDeviceInfo> {
+        juce::get_available_midi_output_devices()
+    }
+
+    /// Returns the default MIDI output device.
+    pub fn default_device() -> MidiDeviceInfo {
+        juce::get_default_midi_output_device()
+    }
+
+    /// Open the MIDI output device with the given identifier.
+    pub fn open(_juce: &'juce JUCE, identifier: &str) -> Result<Self> {
+        Ok(Self {
+            output: juce::open_midi_output(identifier)?,
+            _juce: PhantomData,
+        })
+    }
+
+    /// Information about the device.
+    pub fn device_info(&self) -> MidiDeviceInfo {
+        juce::get_midi_output_device_info(&self.output)
+    }
+
+    /// Send a message immediately, ignoring its timestamp.
+    pub fn send_now(&mut self, message: &MidiMessage) {
+        self.output.pin_mut().send_message_now(&message.0);
+    }
+
+    /// Send a block of messages immediately, ignoring their sample positions.
+    pub fn send_block_now(&mut self, buffer: &MidiBuffer) {
+        self.output.pin_mut().send_block_of_messages_now(&buffer.0);
+    }
+
+    /// Queue a block of messages to be sent by the background thread.
+    ///
+    /// The sample positions of the events are converted into times relative to the start time,
+    /// which is given in milliseconds on the clock returned by [`Time::millisecond_counter_hi_res`].
+    /// Events that are more than 200 milliseconds late by the time they are due are dropped.
+    ///
+    /// The background thread must be running, see [`MidiOutput::start_background_thread`].
+    ///
+    /// Returns an error if the sample rate isn't finite and positive.
+    ///
+    /// [`Time::millisecond_counter_hi_res`]: crate::juce_core::Time::millisecond_counter_hi_res
+    pub fn send_block(
+        &mut self,
+        buffer: &MidiBuffer,
+        millisecond_counter_to_start_at: f64,
+        samples_per_second_for_buffer: f64,
+    ) -> Result<()> {
+        if !(samples_per_second_for_buffer.is_finite() && samples_per_second_for_buffer > 0.0) {
+            return invalid_argument("the sample rate must be finite and positive");
+        }
+
+        self.output.pin_mut().send_block_of_messages(
+            &buffer.0,
+            millisecond_counter_to_start_at,
+            samples_per_second_for_buffer,
+        );
+        Ok(())
+    }
+
+    /// Queue messages to be sent by the background thread at their timestamps.
+    ///
+    /// Timestamps are in seconds, on the same clock as the timestamps of incoming MIDI messages
+    /// (i.e. [`Time::millisecond_counter_hi_res`] divided by 1000). The background thread is
+    /// started if it isn't already running.
+    ///
+    /// [`Time::millisecond_counter_hi_res`]: crate::juce_core::Time::millisecond_counter_hi_res
+    pub fn schedule<'a>(&mut self, messages: impl IntoIterator<Item = &'a MidiMessage>) {
+        const SAMPLES_PER_SECOND: f64 = 10_000.0;
+
+        let messages: Vec<_> = messages.into_iter().collect();
+        let Some(start) = messages
+            .iter()
+            .map(|message| message.timestamp())
+            .min_by(f64::total_cmp)
+        else {
+            return;
+        };
+
+        let mut buffer = MidiBuffer::new();
+        for message in messages {
+            let offset = (message.timestamp() - start) * SAMPLES_PER_SECOND;
+            buffer.add_event(message, offset.round() as i32);
+        }
+
+        if !self.is_background_thread_running() {
+            self.start_background_thread();
+        }
+
+        self.output
+            .pin_mut()
+            .send_block_of_messages(&buffer.0, start * 1000.0, SAMPLES_PER_SECOND);
+    }
+
+    /// Discard any messages that have been queued but not yet sent.
+    pub fn clear_all_pending_messages(&mut self) {
+        self.output.pin_mut().clear_all_pending_messages();
+    }
+
+    /// Start the background thread that sends queued messages.
+    pub fn start_background_thread(&mut self) {
+        self.output.pin_mut().start_background_thread();
+    }
+
+    /// Stop the background thread that sends queued messages.
+    pub fn stop_background_thread(&mut self) {
+        self.output.pin_mut().stop_background_thread();
+    }
+
+    /// Returns true if the background thread is running.
+    pub fn is_background_thread_running(&self) -> bool {
+        self.output.is_background_thread_running()
+    }
+}
+
 pub(crate) mod ffi {
     use super::*;
 
+    pub mod midi_thru {
+        use super::*;
+
+        pub fn process_midi_thru(
+            self_: Pin<&mut MidiThru>,
+            message: cxx::UniquePtr<juce::MidiMessage>,
+        ) -> cxx::UniquePtr<juce::MidiMessage> {
+            self_
+                .get_mut()
+                .process(MidiMessage(message))
+                .map(|message| message.0)
+                .unwrap_or_else(cxx::UniquePtr::null)
+        }
+    }
+
+    pub mod midi_input_callback {
+        use super::*;
+
+        pub fn handle_incoming_midi_message(
+            mut self_: Pin<&mut BoxedMidiInputCallback>,
+            message: cxx::UniquePtr<juce::MidiMessage>,
+        ) {
+            self_.handle_incoming_midi_message(MidiMessage(message));
+        }
+    }
+
     pub mod audio_io_device_callback {
         use super::*;
 
@@ -680,7 +1134,7 @@ pub(crate) mod ffi {
                 return;
             }
 
-            unsafe { Box::from_raw(device) };
+            drop(unsafe { Box::from_raw(device) });
         }
     }
 
@@ -741,7 +1195,7 @@ impl SystemAudioVolume {
 
     /// Set the system volume.
     pub fn set_gain(gain: f32) {
-        juce::set_gain(gain.max(0.0).min(1.0))
+        juce::set_gain(gain.clamp(0.0, 1.0))
     }
 
     /// Returns true if the system audio output is muted.
@@ -0,0 +1,2324 @@
+//! Classes for reading and writing various audio file formats.
+
+use {
+    crate::{
+        invalid_argument,
+        juce::{self, StringPair},
+        juce_audio_basics::AudioBuffer,
+        juce_audio_devices::{InputAudioSampleBuffer, OutputAudioSampleBuffer},
+        juce_core::{
+            path_to_str, BoxedInputStream, JuceInputStream, JuceOutputStream, TimeSliceThread,
+        },
+        Result,
+    },
+    cxx::{SharedPtr, UniquePtr},
+    std::{
+        collections::HashMap,
+        io::{Read, Seek},
+        ops::{Deref, DerefMut, Range},
+        path::Path,
+        pin::Pin,
+        time::Duration,
+    },
+};
+
+#[cfg(feature = "network")]
+use crate::juce_core::{DownloadProgress, ProgressiveDownload};
+
+/// Keeps a list of available audio formats and decides which one to use to open a file.
+pub struct AudioFormatManager(UniquePtr<juce::AudioFormatManager>);
+
+unsafe impl Send for AudioFormatManager {}
+
+impl Default for AudioFormatManager {
+    fn default() -> Self {
+        Self(juce::create_audio_format_manager())
+    }
+}
+
+impl AudioFormatManager {
+    /// Create a format manager with no formats registered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a format manager with the basic formats registered.
+    pub fn with_basic_formats() -> Self {
+        let mut manager = Self::new();
+        manager.register_basic_formats();
+        manager
+    }
+
+    /// Register the formats that JUCE supports out of the box, e.g. WAV and AIFF.
+    pub fn register_basic_formats(&mut self) {
+        self.0.pin_mut().register_basic_formats();
+    }
+
+    /// The number of registered formats.
+    pub fn num_known_formats(&self) -> usize {
+        self.0.get_num_known_formats() as usize
+    }
+
+    /// The names of the registered formats.
+    pub fn known_format_names(&self) -> Vec<String> {
+        juce::get_known_format_names(&self.0)
+    }
+
+    /// A wildcard pattern matching the file extensions of all registered formats, e.g. `*.wav;*.aiff`.
+    pub fn wildcard_for_all_formats(&self) -> String {
+        juce::get_wildcard_for_all_formats(&self.0)
+    }
+
+    /// Create a reader for the file at the given path.
+    ///
+    /// Returns [`None`] if the file doesn't exist or none of the registered formats can read it.
+    pub fn create_reader_for_file(&mut self, path: impl AsRef<Path>) -> Option<AudioFormatReader> {
+        let path = path_to_str(path.as_ref()).ok()?;
+        AudioFormatReader::new(juce::create_reader_for_file(self.0.pin_mut(), path))
+    }
+
+    /// Create a reader for audio data read from a stream.
+    ///
+    /// Returns [`None`] if none of the registered formats can read the stream.
+    pub fn create_reader_for_stream(
+        &mut self,
+        stream: impl Read + Seek + Send + 'static,
+    ) -> Option<AudioFormatReader> {
+        let stream: BoxedInputStream = Box::new(stream);
+        AudioFormatReader::new(juce::create_reader_for_stream(
+            self.0.pin_mut(),
+            Box::new(stream),
+        ))
+    }
+
+    /// Create a reader for audio data streamed from an HTTP(S) URL.
+    ///
+    /// The URL is downloaded progressively on a background thread, and the callback is called
+    /// with the progress of the download each time more data arrives. Reads past the end of what
+    /// has been downloaded so far block until the data is available, so for playback the reader
+    /// can be wrapped in a [`BufferingAudioReader`].
+    ///
+    /// Returns [`None`] if the connection fails or none of the registered formats can read the
+    /// stream.
+    #[cfg(feature = "network")]
+    pub fn create_reader_for_url(
+        &mut self,
+        url: &str,
+        on_progress: impl FnMut(DownloadProgress) + Send + 'static,
+    ) -> Option<AudioFormatReader> {
+        let download = ProgressiveDownload::start(url, on_progress).ok()?;
+        self.create_reader_for_stream(download)
+    }
+
+    /// Register a format implemented in Rust.
+    ///
+    /// The format takes part in choosing a format for a file or stream like the built-in ones. If
+    /// `make_default` is true it becomes the default format, which is tried first when opening a
+    /// stream.
+    pub fn register_format(
+        &mut self,
+        format: impl CustomAudioFormat + 'static,
+        make_default: bool,
+    ) {
+        let format: BoxedCustomAudioFormat = Box::new(format);
+        juce::register_custom_audio_format(self.0.pin_mut(), Box::new(format), make_default);
+    }
+
+    /// Create a writer for the file at the given path, replacing any existing file.
+    ///
+    /// The format is chosen by the extension of the path. Returns an error if none of the
+    /// registered formats uses the extension or the format doesn't support the sample rate,
+    /// channel count or bit depth.
+    pub fn create_writer_for_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        sample_rate: f64,
+        num_channels: usize,
+        bits_per_sample: u32,
+    ) -> Result<AudioFormatWriter> {
+        let path = path_to_str(path.as_ref())?;
+        juce::create_writer_for_file(
+            self.0.pin_mut(),
+            path,
+            sample_rate,
+            num_channels as u32,
+            bits_per_sample as i32,
+            &[],
+        )
+        .map(AudioFormatWriter::new)
+    }
+}
+
+/// Reads samples from an audio file or stream.
+pub struct AudioFormatReader(pub(crate) UniquePtr<juce::AudioFormatReader>);
+
+unsafe impl Send for AudioFormatReader {}
+
+impl AudioFormatReader {
+    pub(crate) fn new(reader: UniquePtr<juce::AudioFormatReader>) -> Option<Self> {
+        (!reader.is_null()).then(|| Self(reader))
+    }
+
+    /// Create a reader that reads samples from a custom implementation.
+    pub fn from_custom(reader: impl CustomAudioFormatReader + 'static) -> Self {
+        let reader: BoxedCustomAudioFormatReader = Box::new(reader);
+        Self(juce::create_custom_audio_format_reader(Box::new(reader)))
+    }
+
+    /// The name of the format being read, e.g. "WAV file".
+    pub fn format_name(&self) -> &str {
+        juce::get_reader_format_name(&self.0)
+    }
+
+    /// The sample rate in Hertz.
+    pub fn sample_rate(&self) -> f64 {
+        juce::get_reader_sample_rate(&self.0)
+    }
+
+    /// The number of channels.
+    pub fn num_channels(&self) -> usize {
+        juce::get_reader_num_channels(&self.0) as usize
+    }
+
+    /// The total number of samples in each channel.
+    pub fn length_in_samples(&self) -> u64 {
+        juce::get_reader_length_in_samples(&self.0).max(0) as u64
+    }
+
+    /// The number of bits per sample.
+    pub fn bits_per_sample(&self) -> u32 {
+        juce::get_reader_bits_per_sample(&self.0)
+    }
+
+    /// Returns true if the samples are stored as floating point values.
+    pub fn uses_floating_point_data(&self) -> bool {
+        juce::reader_uses_floating_point_data(&self.0)
+    }
+
+    /// The metadata read from the file, e.g. the values of a WAV file's `bext` chunk.
+    ///
+    /// See [`WavMetadata`] and [`AiffMetadata`] for structured access to the metadata of those
+    /// formats.
+    pub fn metadata(&self) -> HashMap<String, String> {
+        juce::get_reader_metadata(&self.0)
+            .into_iter()
+            .map(|StringPair { key, value }| (key, value))
+            .collect()
+    }
+
+    /// The properties and metadata of the file being read.
+    pub fn info(&self) -> FileInfo {
+        FileInfo {
+            format_name: self.format_name().to_string(),
+            sample_rate: self.sample_rate(),
+            num_channels: self.num_channels(),
+            length_in_samples: self.length_in_samples(),
+            bits_per_sample: self.bits_per_sample(),
+            uses_floating_point_data: self.uses_floating_point_data(),
+            metadata: self.metadata(),
+        }
+    }
+
+    /// Read samples from the reader into a buffer.
+    ///
+    /// `num_samples` samples are read starting at `reader_start_sample` and written to the buffer
+    /// starting at `dest_start_sample`. Samples outside the bounds of the source are read as silence.
+    /// If the buffer has more channels than the reader the extra channels are cleared. Returns an
+    /// error if the destination range doesn't fit in the buffer.
+    pub fn read_into(
+        &mut self,
+        buffer: &mut AudioBuffer,
+        dest_start_sample: usize,
+        reader_start_sample: i64,
+        num_samples: usize,
+    ) -> Result<()> {
+        check_sample_range(dest_start_sample, num_samples, buffer.samples())?;
+
+        juce::read_audio_format_reader(
+            self.0.pin_mut(),
+            buffer.0.pin_mut(),
+            dest_start_sample as i32,
+            num_samples as i32,
+            reader_start_sample,
+        )
+    }
+
+    /// Read a subset of the reader's channels into a buffer.
+    ///
+    /// Reader channel `channels[i]` is written to channel `i` of the buffer, so channels can be
+    /// picked in any order or more than once. The buffer's remaining channels are filled with
+    /// copies of the last selected channel if `fill_leftover_channels_with_copies` is true, and
+    /// cleared otherwise. Returns an error if a channel is out of range, the destination range
+    /// doesn't fit in the buffer, or the buffer has fewer channels than were selected.
+    ///
+    /// The samples are read straight into the buffer, so this doesn't allocate for readers with up
+    /// to 64 channels.
+    pub fn read_channels_into(
+        &mut self,
+        buffer: &mut AudioBuffer,
+        dest_start_sample: usize,
+        reader_start_sample: i64,
+        num_samples: usize,
+        channels: &[usize],
+        fill_leftover_channels_with_copies: bool,
+    ) -> Result<()> {
+        check_sample_range(dest_start_sample, num_samples, buffer.samples())?;
+
+        juce::read_audio_format_reader_channels(
+            self.0.pin_mut(),
+            buffer.0.pin_mut(),
+            dest_start_sample as i32,
+            num_samples as i32,
+            reader_start_sample,
+            channels,
+            fill_leftover_channels_with_copies,
+        )
+    }
+
+    /// Read `num_samples` samples of a subset of the reader's channels into a new buffer with one
+    /// channel per selected channel, see [`read_channels_into`](Self::read_channels_into).
+    pub fn read_channels(
+        &mut self,
+        reader_start_sample: i64,
+        num_samples: usize,
+        channels: &[usize],
+    ) -> Result<AudioBuffer> {
+        let mut buffer = AudioBuffer::try_new(channels.len(), num_samples)?;
+        self.read_channels_into(
+            &mut buffer,
+            0,
+            reader_start_sample,
+            num_samples,
+            channels,
+            false,
+        )?;
+        Ok(buffer)
+    }
+
+    /// Read a range of samples into a buffer, resizing the buffer to fit.
+    ///
+    /// The buffer is resized to the reader's number of channels and the length of the range.
+    /// Samples outside the bounds of the source are read as silence.
+    pub fn read_range_into(&mut self, range: Range<i64>, buffer: &mut AudioBuffer) -> Result<()> {
+        let num_samples = range.end.saturating_sub(range.start).max(0) as usize;
+        buffer.try_set_size(self.num_channels(), num_samples)?;
+        self.read_into(buffer, 0, range.start, num_samples)
+    }
+
+    /// Read `num_samples` samples starting at `reader_start_sample` into a new buffer.
+    pub fn read(&mut self, reader_start_sample: i64, num_samples: usize) -> Result<AudioBuffer> {
+        let mut buffer = AudioBuffer::try_new(self.num_channels(), num_samples)?;
+        self.read_into(&mut buffer, 0, reader_start_sample, num_samples)?;
+        Ok(buffer)
+    }
+
+    /// Read `num_samples` samples starting at `reader_start_sample` into interleaved samples.
+    pub fn read_interleaved(
+        &mut self,
+        reader_start_sample: i64,
+        num_samples: usize,
+    ) -> Result<Vec<f32>> {
+        self.read(reader_start_sample, num_samples)
+            .map(|buffer| buffer.to_interleaved())
+    }
+}
+
+/// A custom source of samples that can be read through an [`AudioFormatReader`].
+pub trait CustomAudioFormatReader: Send {
+    /// The name of the format being read.
+    fn format_name(&self) -> &str;
+
+    /// The sample rate.
+    fn sample_rate(&self) -> f64;
+
+    /// The number of channels.
+    fn num_channels(&self) -> usize;
+
+    /// The total number of samples in each channel.
+    fn length_in_samples(&self) -> u64;
+
+    /// The number of bits per sample of the source data.
+    fn bits_per_sample(&self) -> u32 {
+        32
+    }
+
+    /// Fill a buffer with samples, starting at the given sample.
+    ///
+    /// The buffer has one channel per channel of the reader and is cleared beforehand, so samples
+    /// past the end of the source can be left untouched. Returns false if the read failed.
+    fn read_samples(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>, start_sample: u64)
+        -> bool;
+}
+
+/// An audio format implemented in Rust, which can be registered with an [`AudioFormatManager`].
+pub trait CustomAudioFormat: Send {
+    /// The name of the format, e.g. "WAV file".
+    fn format_name(&self) -> &str;
+
+    /// The file extensions used by the format, including the dot, e.g. `[".wav"]`.
+    fn file_extensions(&self) -> Vec<String>;
+
+    /// Returns true if the format can probably read the file at the given path.
+    ///
+    /// By default this checks the file extension.
+    fn can_handle_file(&self, path: &Path) -> bool {
+        let Some(extension) = path.extension() else {
+            return false;
+        };
+
+        let Some(extension) = extension.to_str() else {
+            return false;
+        };
+
+        let extension = format!(".{extension}");
+        self.file_extensions()
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(&extension))
+    }
+
+    /// The sample rates that can be written.
+    fn possible_sample_rates(&self) -> Vec<u32> {
+        vec![
+            8000, 11025, 12000, 16000, 22050, 32000, 44100, 48000, 88200, 96000, 176400, 192000,
+        ]
+    }
+
+    /// The bit depths that can be written.
+    fn possible_bit_depths(&self) -> Vec<u32> {
+        vec![16, 24, 32]
+    }
+
+    /// Returns true if the format can write stereo files.
+    fn can_do_stereo(&self) -> bool {
+        true
+    }
+
+    /// Returns true if the format can write mono files.
+    fn can_do_mono(&self) -> bool {
+        true
+    }
+
+    /// Create a reader for the data in a stream.
+    ///
+    /// Returns [`None`] if the stream doesn't contain data in this format.
+    fn create_reader(&mut self, stream: JuceInputStream) -> Option<BoxedCustomAudioFormatReader>;
+
+    /// Create a writer that writes data in this format to a stream.
+    ///
+    /// Returns [`None`] if the format can't be written with the given options, which is the
+    /// default for read-only formats.
+    fn create_writer(
+        &mut self,
+        stream: JuceOutputStream,
+        options: &AudioFormatWriterOptions,
+    ) -> Option<BoxedCustomAudioFormatWriter> {
+        let _ = (stream, options);
+        None
+    }
+}
+
+pub(crate) type BoxedCustomAudioFormat = Box<dyn CustomAudioFormat>;
+
+/// The options a [`CustomAudioFormat`] is asked to create a writer with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioFormatWriterOptions {
+    /// The sample rate in Hertz.
+    pub sample_rate: f64,
+
+    /// The number of channels.
+    pub num_channels: usize,
+
+    /// The number of bits per sample.
+    pub bits_per_sample: u32,
+
+    /// The metadata to write to the file.
+    pub metadata: HashMap<String, String>,
+
+    /// The index of the quality option to use, for formats that have them.
+    pub quality_option_index: usize,
+}
+
+/// A custom destination for samples written through an [`AudioFormatWriter`].
+pub trait CustomAudioFormatWriter: Send {
+    /// Write a block of samples.
+    ///
+    /// The buffer has one channel per channel of the writer. Returns false if the write failed.
+    fn write(&mut self, buffer: &InputAudioSampleBuffer<'_>) -> bool;
+
+    /// Flush any buffered data, so that what has been written so far is valid if the
+    /// application stops unexpectedly.
+    ///
+    /// Returns false if flushing isn't supported, which is the default.
+    fn flush(&mut self) -> bool {
+        false
+    }
+}
+
+/// A boxed [`CustomAudioFormatReader`], as created by a [`CustomAudioFormat`].
+pub type BoxedCustomAudioFormatReader = Box<dyn CustomAudioFormatReader>;
+
+/// A boxed [`CustomAudioFormatWriter`], as created by a [`CustomAudioFormat`].
+pub type BoxedCustomAudioFormatWriter = Box<dyn CustomAudioFormatWriter>;
+
+/// The properties and metadata of an audio file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileInfo {
+    /// The name of the format, e.g. "WAV file".
+    pub format_name: String,
+
+    /// The sample rate in Hertz.
+    pub sample_rate: f64,
+
+    /// The number of channels.
+    pub num_channels: usize,
+
+    /// The total number of samples in each channel.
+    pub length_in_samples: u64,
+
+    /// The number of bits per sample.
+    pub bits_per_sample: u32,
+
+    /// True if the samples are stored as floating point values.
+    pub uses_floating_point_data: bool,
+
+    /// The metadata read from the file.
+    pub metadata: HashMap<String, String>,
+}
+
+impl FileInfo {
+    /// The duration of the file.
+    pub fn duration(&self) -> Duration {
+        if self.sample_rate > 0.0 {
+            Duration::from_secs_f64(self.length_in_samples as f64 / self.sample_rate)
+        } else {
+            Duration::ZERO
+        }
+    }
+}
+
+/// Read an entire audio file, returning the samples of each channel and the file's properties.
+///
+/// Any of the basic formats can be read. Returns [`None`] if the file can't be opened or read.
+pub fn read_file_to_vec(path: impl AsRef<Path>) -> Option<(Vec<Vec<f32>>, FileInfo)> {
+    let mut reader = AudioFormatManager::with_basic_formats().create_reader_for_file(path)?;
+    let info = reader.info();
+    let buffer = reader.read(0, info.length_in_samples as usize).ok()?;
+
+    Some((buffer.to_channels(), info))
+}
+
+/// Read a range of samples from an audio file into a buffer, resizing the buffer to fit.
+///
+/// Any of the basic formats can be read. Returns the file's properties, or [`None`] if the file
+/// can't be opened or read.
+pub fn read_file_range_into(
+    path: impl AsRef<Path>,
+    range: Range<i64>,
+    buffer: &mut AudioBuffer,
+) -> Option<FileInfo> {
+    let mut reader = AudioFormatManager::with_basic_formats().create_reader_for_file(path)?;
+    reader.read_range_into(range, buffer).ok()?;
+
+    Some(reader.info())
+}
+
+/// Read an entire audio file, resampling it to the given sample rate.
+///
+/// The returned [`FileInfo`] describes the file itself, so its sample rate and length are those of
+/// the file rather than the resampled channels. Returns [`None`] if the file can't be opened or
+/// read.
+pub fn read_file_to_vec_at_sample_rate(
+    path: impl AsRef<Path>,
+    sample_rate: f64,
+) -> Option<(Vec<Vec<f32>>, FileInfo)> {
+    let reader = AudioFormatManager::with_basic_formats().create_reader_for_file(path)?;
+    let info = reader.info();
+    let mut reader = ResamplingAudioFormatReader::new(reader, sample_rate).ok()?;
+    let buffer = reader.read(reader.length_in_samples() as usize).ok()?;
+
+    Some((buffer.to_channels(), info))
+}
+
+/// The formats that [`transcode`] can write.
+///
+/// The available formats depend on the enabled features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OutputFormat {
+    /// A WAV file.
+    Wav,
+
+    /// An AIFF file.
+    Aiff,
+
+    /// A FLAC file.
+    #[cfg(feature = "flac")]
+    Flac,
+
+    /// An Ogg Vorbis file.
+    #[cfg(feature = "ogg")]
+    OggVorbis,
+}
+
+/// The format of the output of [`transcode`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputSpec {
+    format: OutputFormat,
+    sample_rate: Option<f64>,
+    bits_per_sample: u32,
+    quality: usize,
+}
+
+impl OutputSpec {
+    /// Create a spec for 16-bit output in a format, at the input's sample rate.
+    pub fn new(format: OutputFormat) -> Self {
+        Self {
+            format,
+            sample_rate: None,
+            bits_per_sample: 16,
+            quality: 0,
+        }
+    }
+
+    /// The output format.
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// The output sample rate, or [`None`] to use the input's sample rate.
+    pub fn sample_rate(&self) -> Option<f64> {
+        self.sample_rate
+    }
+
+    /// Resample the output to a sample rate.
+    pub fn with_sample_rate(mut self, sample_rate: f64) -> Self {
+        self.sample_rate = Some(sample_rate);
+        self
+    }
+
+    /// The number of bits per sample, which is ignored by Ogg Vorbis.
+    pub fn bits_per_sample(&self) -> u32 {
+        self.bits_per_sample
+    }
+
+    /// Set the number of bits per sample.
+    pub fn with_bits_per_sample(mut self, bits_per_sample: u32) -> Self {
+        self.bits_per_sample = bits_per_sample;
+        self
+    }
+
+    /// The compression level for FLAC, or the index of the quality option for Ogg Vorbis.
+    pub fn quality(&self) -> usize {
+        self.quality
+    }
+
+    /// Set the compression level for FLAC, or the index of the quality option for Ogg Vorbis.
+    pub fn with_quality(mut self, quality: usize) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    fn create_writer(
+        &self,
+        path: &Path,
+        sample_rate: f64,
+        num_channels: usize,
+        metadata: &HashMap<String, String>,
+    ) -> Result<AudioFormatWriter> {
+        match self.format {
+            OutputFormat::Wav => WavAudioFormat::new().create_writer_for_file_with_metadata(
+                path,
+                sample_rate,
+                num_channels,
+                self.bits_per_sample,
+                metadata,
+            ),
+            OutputFormat::Aiff => AiffAudioFormat::new().create_writer_for_file_with_metadata(
+                path,
+                sample_rate,
+                num_channels,
+                self.bits_per_sample,
+                metadata,
+            ),
+            #[cfg(feature = "flac")]
+            OutputFormat::Flac => match u32::try_from(self.quality) {
+                Ok(compression_level) => FlacAudioFormat::new().create_writer_for_file(
+                    path,
+                    sample_rate,
+                    num_channels,
+                    self.bits_per_sample,
+                    compression_level,
+                ),
+                Err(_) => invalid_argument("compression level is out of range"),
+            },
+            #[cfg(feature = "ogg")]
+            OutputFormat::OggVorbis => OggVorbisAudioFormat::new()
+                .create_writer_for_file_with_metadata(
+                    path,
+                    sample_rate,
+                    num_channels,
+                    self.quality,
+                    metadata,
+                ),
+        }
+    }
+}
+
+/// Convert an audio file to another format.
+///
+/// Any of the basic formats can be read, and the input's metadata is copied to formats that
+/// support it. The callback is called with the proportion of the file that has been converted,
+/// between 0 and 1.
+///
+/// Returns an error if the input can't be read or the output can't be written, in which case no
+/// output file is left behind.
+pub fn transcode(
+    input_path: impl AsRef<Path>,
+    output_path: impl AsRef<Path>,
+    spec: &OutputSpec,
+    on_progress: impl FnMut(f64),
+) -> Result<()> {
+    let input_path = path_to_str(input_path.as_ref())?;
+    let output_path = output_path.as_ref();
+
+    let reader = AudioFormatReader(juce::open_reader_for_file(
+        AudioFormatManager::with_basic_formats().0.pin_mut(),
+        input_path,
+    )?);
+
+    let metadata = reader.metadata();
+    let reader = match spec.sample_rate {
+        Some(sample_rate) if sample_rate != reader.sample_rate() => {
+            ResamplingAudioFormatReader::new(reader, sample_rate)?.into_audio_format_reader()
+        }
+        _ => reader,
+    };
+
+    let writer = spec.create_writer(
+        output_path,
+        reader.sample_rate(),
+        reader.num_channels(),
+        &metadata,
+    )?;
+
+    transcode_reader(reader, writer, on_progress).inspect_err(|_| {
+        let _ = std::fs::remove_file(output_path);
+    })
+}
+
+/// Write all the samples from a reader to a writer, finalising the writer when done.
+///
+/// The callback is called with the proportion of the samples that have been written, between 0
+/// and 1.
+pub fn transcode_reader(
+    mut reader: AudioFormatReader,
+    mut writer: AudioFormatWriter,
+    mut on_progress: impl FnMut(f64),
+) -> Result<()> {
+    const BLOCK_SIZE: usize = 8192;
+
+    let length = reader.length_in_samples();
+    let mut buffer = AudioBuffer::new(reader.num_channels(), BLOCK_SIZE);
+    let mut position = 0;
+
+    while position < length {
+        let num_samples = (length - position).min(BLOCK_SIZE as u64) as usize;
+
+        reader.read_into(&mut buffer, 0, position as i64, num_samples)?;
+        writer.write(&buffer, 0, num_samples)?;
+
+        position += num_samples as u64;
+        on_progress(position as f64 / length as f64);
+    }
+
+    Ok(())
+}
+
+/// Reads samples from a memory-mapped audio file.
+///
+/// Once a section of the file has been mapped, reads from that section are served directly from
+/// memory, which makes it possible to read from the audio thread without blocking on disk I/O.
+/// Reads from outside the mapped section produce silence.
+pub struct MemoryMappedAudioFormatReader(AudioFormatReader);
+
+impl MemoryMappedAudioFormatReader {
+    pub(crate) fn new(reader: UniquePtr<juce::AudioFormatReader>) -> Option<Self> {
+        AudioFormatReader::new(reader).map(Self)
+    }
+
+    /// Map the entire file into memory, returning true if successful.
+    pub fn map_entire_file(&mut self) -> bool {
+        juce::map_entire_file(self.0 .0.pin_mut())
+    }
+
+    /// Map a range of samples into memory, returning true if successful.
+    pub fn map_section_of_file(&mut self, samples: Range<i64>) -> bool {
+        juce::map_section_of_file(self.0 .0.pin_mut(), samples.start, samples.end)
+    }
+
+    /// The range of samples that are currently mapped.
+    pub fn mapped_section(&self) -> Range<i64> {
+        let [start, end] = juce::get_mapped_section(&self.0 .0);
+        start..end
+    }
+
+    /// Touch the page of memory containing the given sample, so that reading it later doesn't
+    /// cause a page fault.
+    ///
+    /// Samples outside the mapped section are ignored.
+    pub fn touch_sample(&self, sample: i64) {
+        juce::touch_sample(&self.0 .0, sample);
+    }
+
+    /// The number of bytes currently mapped.
+    pub fn num_bytes_used(&self) -> usize {
+        juce::get_num_bytes_used(&self.0 .0)
+    }
+
+    /// Read a single sample of each channel into `result`.
+    ///
+    /// Returns an error if `result` is shorter than the number of channels or the sample isn't
+    /// in the mapped section.
+    pub fn sample(&self, sample: i64, result: &mut [f32]) -> Result<()> {
+        juce::get_mapped_sample(&self.0 .0, sample, result)
+    }
+}
+
+impl Deref for MemoryMappedAudioFormatReader {
+    type Target = AudioFormatReader;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for MemoryMappedAudioFormatReader {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Reads samples from another reader, pre-reading them on a background thread.
+///
+/// Reading from a buffering reader doesn't block on disk I/O, which makes it suitable for
+/// streaming audio files from the audio thread.
+pub struct BufferingAudioReader(AudioFormatReader);
+
+impl BufferingAudioReader {
+    /// Create a buffering reader that keeps `samples_to_buffer` samples ahead of the read
+    /// position buffered.
+    ///
+    /// The samples are read on a background thread shared by all the buffering readers that
+    /// weren't given a thread.
+    pub fn new(source: AudioFormatReader, samples_to_buffer: usize) -> Self {
+        Self::create(source, SharedPtr::null(), samples_to_buffer)
+    }
+
+    /// Create a buffering reader that reads ahead on the given thread.
+    pub fn with_thread(
+        source: AudioFormatReader,
+        thread: &TimeSliceThread,
+        samples_to_buffer: usize,
+    ) -> Self {
+        Self::create(source, thread.as_juce(), samples_to_buffer)
+    }
+
+    fn create(
+        source: AudioFormatReader,
+        thread: SharedPtr<juce::TimeSliceThread>,
+        samples_to_buffer: usize,
+    ) -> Self {
+        Self(AudioFormatReader(juce::create_buffering_audio_reader(
+            source.0,
+            thread,
+            samples_to_buffer.min(i32::MAX as usize) as i32,
+        )))
+    }
+
+    /// Set how long a read can block while waiting for samples to be buffered.
+    ///
+    /// The default is zero, so reads never block.
+    pub fn set_read_timeout(&mut self, timeout: Duration) {
+        juce::set_buffering_read_timeout(
+            self.0 .0.pin_mut(),
+            timeout.as_millis().min(i32::MAX as u128) as i32,
+        );
+    }
+
+    /// Read samples from the reader into a buffer, see [`AudioFormatReader::read_into`].
+    ///
+    /// Returns false if some of the samples hadn't been buffered before the read timed out, in
+    /// which case those samples are silent.
+    pub fn read_buffered(
+        &mut self,
+        buffer: &mut AudioBuffer,
+        dest_start_sample: usize,
+        reader_start_sample: i64,
+        num_samples: usize,
+    ) -> Result<bool> {
+        check_sample_range(dest_start_sample, num_samples, buffer.samples())?;
+
+        juce::read_buffered_samples(
+            self.0 .0.pin_mut(),
+            buffer.0.pin_mut(),
+            dest_start_sample as i32,
+            num_samples as i32,
+            reader_start_sample,
+        )
+    }
+}
+
+impl Deref for BufferingAudioReader {
+    type Target = AudioFormatReader;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for BufferingAudioReader {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Reads samples from another reader, resampling them to a different sample rate on the fly.
+///
+/// Samples are read sequentially from the current position, which is measured in samples at the
+/// target sample rate.
+pub struct ResamplingAudioFormatReader {
+    reader: UniquePtr<juce::ResamplingAudioFormatReader>,
+    format_name: String,
+    bits_per_sample: u32,
+}
+
+unsafe impl Send for ResamplingAudioFormatReader {}
+
+impl ResamplingAudioFormatReader {
+    /// Create a reader that resamples `source` to `sample_rate`.
+    ///
+    /// Returns an error if either sample rate isn't positive.
+    pub fn new(source: AudioFormatReader, sample_rate: f64) -> Result<Self> {
+        let format_name = source.format_name().to_string();
+        let bits_per_sample = source.bits_per_sample();
+
+        juce::create_resampling_audio_format_reader(source.0, sample_rate).map(|reader| Self {
+            reader,
+            format_name,
+            bits_per_sample,
+        })
+    }
+
+    /// The sample rate the samples are resampled to.
+    pub fn sample_rate(&self) -> f64 {
+        self.reader.get_sample_rate()
+    }
+
+    /// The number of channels.
+    pub fn num_channels(&self) -> usize {
+        self.reader.get_num_channels() as usize
+    }
+
+    /// The total number of samples in each channel at the target sample rate.
+    pub fn length_in_samples(&self) -> u64 {
+        self.reader.get_length_in_samples() as u64
+    }
+
+    /// The position of the next sample to be read.
+    pub fn position(&self) -> i64 {
+        self.reader.get_position()
+    }
+
+    /// Set the position of the next sample to be read.
+    pub fn set_position(&mut self, position: i64) {
+        self.reader.pin_mut().set_position(position);
+    }
+
+    /// Fill a buffer with the next samples, advancing the position by the length of the buffer.
+    ///
+    /// Samples past the end of the source are silent. Returns an error if the buffer has fewer
+    /// channels than the reader.
+    pub fn read_into(&mut self, buffer: &mut AudioBuffer) -> Result<()> {
+        self.reader.pin_mut().read(buffer.0.pin_mut())
+    }
+
+    /// Read the next `num_samples` samples into a new buffer.
+    pub fn read(&mut self, num_samples: usize) -> Result<AudioBuffer> {
+        let mut buffer = AudioBuffer::try_new(self.num_channels(), num_samples)?;
+        self.read_into(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Wrap the reader in an [`AudioFormatReader`] that reads at the target sample rate.
+    pub fn into_audio_format_reader(self) -> AudioFormatReader {
+        AudioFormatReader::from_custom(self)
+    }
+}
+
+impl CustomAudioFormatReader for ResamplingAudioFormatReader {
+    fn format_name(&self) -> &str {
+        &self.format_name
+    }
+
+    fn sample_rate(&self) -> f64 {
+        self.sample_rate()
+    }
+
+    fn num_channels(&self) -> usize {
+        self.num_channels()
+    }
+
+    fn length_in_samples(&self) -> u64 {
+        self.length_in_samples()
+    }
+
+    fn bits_per_sample(&self) -> u32 {
+        self.bits_per_sample
+    }
+
+    fn read_samples(
+        &mut self,
+        buffer: &mut OutputAudioSampleBuffer<'_>,
+        start_sample: u64,
+    ) -> bool {
+        if self.position() != start_sample as i64 {
+            self.set_position(start_sample as i64);
+        }
+
+        self.reader.pin_mut().read(buffer.buffer.as_mut()).is_ok()
+    }
+}
+
+/// Reads and writes WAV files.
+pub struct WavAudioFormat(UniquePtr<juce::WavAudioFormat>);
+
+unsafe impl Send for WavAudioFormat {}
+
+impl Default for WavAudioFormat {
+    fn default() -> Self {
+        Self(juce::create_wav_audio_format())
+    }
+}
+
+impl WavAudioFormat {
+    /// Metadata key for the description in a broadcast WAV `bext` chunk.
+    pub const BWAV_DESCRIPTION: &'static str = "bwav description";
+
+    /// Metadata key for the originator in a broadcast WAV `bext` chunk.
+    pub const BWAV_ORIGINATOR: &'static str = "bwav originator";
+
+    /// Metadata key for the originator reference in a broadcast WAV `bext` chunk.
+    pub const BWAV_ORIGINATOR_REF: &'static str = "bwav originator ref";
+
+    /// Metadata key for the origination date in a broadcast WAV `bext` chunk, `yyyy-mm-dd`.
+    pub const BWAV_ORIGINATION_DATE: &'static str = "bwav origination date";
+
+    /// Metadata key for the origination time in a broadcast WAV `bext` chunk, `hh:mm:ss`.
+    pub const BWAV_ORIGINATION_TIME: &'static str = "bwav origination time";
+
+    /// Metadata key for the time reference in samples in a broadcast WAV `bext` chunk.
+    pub const BWAV_TIME_REFERENCE: &'static str = "bwav time reference";
+
+    /// Metadata key for the coding history in a broadcast WAV `bext` chunk.
+    pub const BWAV_CODING_HISTORY: &'static str = "bwav coding history";
+
+    /// Metadata key for the artist in a RIFF `INFO` chunk.
+    pub const RIFF_INFO_ARTIST: &'static str = "IART";
+
+    /// Metadata key for the comment in a RIFF `INFO` chunk.
+    pub const RIFF_INFO_COMMENT: &'static str = "ICMT";
+
+    /// Metadata key for the copyright in a RIFF `INFO` chunk.
+    pub const RIFF_INFO_COPYRIGHT: &'static str = "ICOP";
+
+    /// Metadata key for the creation date in a RIFF `INFO` chunk.
+    pub const RIFF_INFO_CREATION_DATE: &'static str = "ICRD";
+
+    /// Metadata key for the genre in a RIFF `INFO` chunk.
+    pub const RIFF_INFO_GENRE: &'static str = "IGNR";
+
+    /// Metadata key for the keywords in a RIFF `INFO` chunk.
+    pub const RIFF_INFO_KEYWORDS: &'static str = "IKEY";
+
+    /// Metadata key for the software used to create the file in a RIFF `INFO` chunk.
+    pub const RIFF_INFO_SOFTWARE: &'static str = "ISFT";
+
+    /// Metadata key for the title in a RIFF `INFO` chunk.
+    pub const RIFF_INFO_TITLE: &'static str = "INAM";
+
+    /// Create the WAV format.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a reader for the WAV file at the given path.
+    ///
+    /// Returns [`None`] if the file can't be opened or isn't a valid WAV file.
+    pub fn create_reader_for_file(&mut self, path: impl AsRef<Path>) -> Option<AudioFormatReader> {
+        let path = path_to_str(path.as_ref()).ok()?;
+        AudioFormatReader::new(juce::create_wav_reader_for_file(self.0.pin_mut(), path))
+    }
+
+    /// Create a memory-mapped reader for the WAV file at the given path.
+    ///
+    /// Nothing is mapped until [`MemoryMappedAudioFormatReader::map_entire_file`] or
+    /// [`MemoryMappedAudioFormatReader::map_section_of_file`] is called. Returns [`None`] if the
+    /// file can't be opened or isn't a valid WAV file.
+    pub fn create_memory_mapped_reader(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Option<MemoryMappedAudioFormatReader> {
+        let path = path_to_str(path.as_ref()).ok()?;
+        MemoryMappedAudioFormatReader::new(juce::create_wav_memory_mapped_reader(
+            self.0.pin_mut(),
+            path,
+        ))
+    }
+
+    /// Create a writer that writes a WAV file at the given path, replacing any existing file.
+    ///
+    /// Supported bit depths are 8, 16, 24 and 32.
+    pub fn create_writer_for_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        sample_rate: f64,
+        num_channels: usize,
+        bits_per_sample: u32,
+    ) -> Result<AudioFormatWriter> {
+        self.create_writer_for_file_with_metadata(
+            path,
+            sample_rate,
+            num_channels,
+            bits_per_sample,
+            &HashMap::new(),
+        )
+    }
+
+    /// Create a writer that writes a WAV file with the given metadata, see [`WavMetadata`].
+    pub fn create_writer_for_file_with_metadata(
+        &mut self,
+        path: impl AsRef<Path>,
+        sample_rate: f64,
+        num_channels: usize,
+        bits_per_sample: u32,
+        metadata: &HashMap<String, String>,
+    ) -> Result<AudioFormatWriter> {
+        let path = path_to_str(path.as_ref())?;
+        juce::create_wav_writer_for_file(
+            self.0.pin_mut(),
+            path,
+            sample_rate,
+            num_channels as u32,
+            bits_per_sample as i32,
+            &to_string_pairs(metadata),
+        )
+        .map(AudioFormatWriter::new)
+    }
+
+    /// Create a reader for WAV data read from a stream.
+    ///
+    /// Returns [`None`] if the stream doesn't contain valid WAV data.
+    pub fn create_reader_for_stream(
+        &mut self,
+        stream: impl Read + Seek + Send + 'static,
+    ) -> Option<AudioFormatReader> {
+        let stream: BoxedInputStream = Box::new(stream);
+        AudioFormatReader::new(juce::create_wav_reader_for_stream(
+            self.0.pin_mut(),
+            Box::new(stream),
+        ))
+    }
+}
+
+/// The broadcast WAV metadata stored in a `bext` chunk.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BwavMetadata {
+    /// A description of the recording.
+    pub description: String,
+
+    /// The name of the originator, e.g. the recorder.
+    pub originator: String,
+
+    /// A unique reference for the recording.
+    pub originator_ref: String,
+
+    /// The date the recording was created, `yyyy-mm-dd`.
+    pub origination_date: String,
+
+    /// The time the recording was created, `hh:mm:ss`.
+    pub origination_time: String,
+
+    /// The timecode of the first sample, in samples since midnight.
+    pub time_reference: u64,
+
+    /// The coding history.
+    pub coding_history: String,
+}
+
+/// A marker at a position in a WAV file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CuePoint {
+    /// A unique identifier for the cue point.
+    pub identifier: u32,
+
+    /// The position of the cue point in samples.
+    pub offset: u32,
+
+    /// The cue point's label.
+    pub label: Option<String>,
+}
+
+/// How a sample loop is played.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LoopType {
+    /// Play forwards.
+    #[default]
+    Forward,
+
+    /// Alternate between playing forwards and backwards.
+    PingPong,
+
+    /// Play backwards.
+    Backward,
+}
+
+/// A loop stored in the `smpl` chunk of a WAV file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SampleLoop {
+    /// A unique identifier for the loop.
+    pub identifier: u32,
+
+    /// How the loop is played.
+    pub loop_type: LoopType,
+
+    /// The position of the first sample of the loop.
+    pub start: u32,
+
+    /// The position of the last sample of the loop.
+    pub end: u32,
+
+    /// The number of times to play the loop, or zero to loop forever.
+    pub play_count: u32,
+}
+
+/// The sampler metadata stored in the `smpl` chunk of a WAV file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SamplerMetadata {
+    /// The MIDI note that plays the sample at its original pitch.
+    pub midi_unity_note: u32,
+
+    /// The sample loops.
+    pub loops: Vec<SampleLoop>,
+}
+
+impl Default for SamplerMetadata {
+    fn default() -> Self {
+        Self {
+            midi_unity_note: 60,
+            loops: Vec::new(),
+        }
+    }
+}
+
+/// The structured metadata stored in a WAV file.
+///
+/// Other metadata, such as the tags in a RIFF `INFO` chunk, can be accessed through the raw
+/// metadata using keys like [`WavAudioFormat::RIFF_INFO_TITLE`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WavMetadata {
+    /// The broadcast WAV metadata.
+    pub bwav: Option<BwavMetadata>,
+
+    /// The cue points.
+    pub cue_points: Vec<CuePoint>,
+
+    /// The sampler metadata.
+    pub sampler: Option<SamplerMetadata>,
+}
+
+impl WavMetadata {
+    /// Extract the WAV metadata from a reader's metadata.
+    pub fn from_metadata(metadata: &HashMap<String, String>) -> Self {
+        let string = |key: &str| metadata.get(key).cloned().unwrap_or_default();
+        let int = |key: &str| -> i64 {
+            metadata
+                .get(key)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or_default()
+        };
+
+        let bwav = metadata
+            .keys()
+            .any(|key| key.starts_with("bwav "))
+            .then(|| BwavMetadata {
+                description: string(WavAudioFormat::BWAV_DESCRIPTION),
+                originator: string(WavAudioFormat::BWAV_ORIGINATOR),
+                originator_ref: string(WavAudioFormat::BWAV_ORIGINATOR_REF),
+                origination_date: string(WavAudioFormat::BWAV_ORIGINATION_DATE),
+                origination_time: string(WavAudioFormat::BWAV_ORIGINATION_TIME),
+                time_reference: int(WavAudioFormat::BWAV_TIME_REFERENCE) as u64,
+                coding_history: string(WavAudioFormat::BWAV_CODING_HISTORY),
+            });
+
+        let labels: HashMap<u32, String> = (0..int("NumCueLabels"))
+            .map(|i| {
+                (
+                    int(&format!("CueLabel{i}Identifier")) as u32,
+                    string(&format!("CueLabel{i}Text")),
+                )
+            })
+            .collect();
+
+        let cue_points = (0..int("NumCuePoints"))
+            .map(|i| {
+                let identifier = int(&format!("Cue{i}Identifier")) as u32;
+                CuePoint {
+                    identifier,
+                    offset: int(&format!("Cue{i}Offset")) as u32,
+                    label: labels.get(&identifier).cloned(),
+                }
+            })
+            .collect();
+
+        let sampler = metadata
+            .contains_key("MidiUnityNote")
+            .then(|| SamplerMetadata {
+                midi_unity_note: int("MidiUnityNote") as u32,
+                loops: (0..int("NumSampleLoops"))
+                    .map(|i| SampleLoop {
+                        identifier: int(&format!("Loop{i}Identifier")) as u32,
+                        loop_type: match int(&format!("Loop{i}Type")) {
+                            1 => LoopType::PingPong,
+                            2 => LoopType::Backward,
+                            _ => LoopType::Forward,
+                        },
+                        start: int(&format!("Loop{i}Start")) as u32,
+                        end: int(&format!("Loop{i}End")) as u32,
+                        play_count: int(&format!("Loop{i}PlayCount")) as u32,
+                    })
+                    .collect(),
+            });
+
+        Self {
+            bwav,
+            cue_points,
+            sampler,
+        }
+    }
+
+    /// Convert to metadata that can be passed to
+    /// [`WavAudioFormat::create_writer_for_file_with_metadata`].
+    pub fn to_metadata(&self) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+
+        let mut insert = |key: &str, value: String| {
+            metadata.insert(key.to_string(), value);
+        };
+
+        if let Some(bwav) = &self.bwav {
+            insert(WavAudioFormat::BWAV_DESCRIPTION, bwav.description.clone());
+            insert(WavAudioFormat::BWAV_ORIGINATOR, bwav.originator.clone());
+            insert(
+                WavAudioFormat::BWAV_ORIGINATOR_REF,
+                bwav.originator_ref.clone(),
+            );
+            insert(
+                WavAudioFormat::BWAV_ORIGINATION_DATE,
+                bwav.origination_date.clone(),
+            );
+            insert(
+                WavAudioFormat::BWAV_ORIGINATION_TIME,
+                bwav.origination_time.clone(),
+            );
+            insert(
+                WavAudioFormat::BWAV_TIME_REFERENCE,
+                bwav.time_reference.to_string(),
+            );
+            insert(
+                WavAudioFormat::BWAV_CODING_HISTORY,
+                bwav.coding_history.clone(),
+            );
+        }
+
+        if !self.cue_points.is_empty() {
+            insert("NumCuePoints", self.cue_points.len().to_string());
+
+            for (i, cue_point) in self.cue_points.iter().enumerate() {
+                insert(
+                    &format!("Cue{i}Identifier"),
+                    cue_point.identifier.to_string(),
+                );
+                insert(&format!("Cue{i}Order"), i.to_string());
+                insert(&format!("Cue{i}Offset"), cue_point.offset.to_string());
+            }
+
+            let labelled = self
+                .cue_points
+                .iter()
+                .filter_map(|cue_point| Some((cue_point.identifier, cue_point.label.as_ref()?)));
+
+            let mut num_labels = 0;
+            for (i, (identifier, label)) in labelled.enumerate() {
+                insert(&format!("CueLabel{i}Identifier"), identifier.to_string());
+                insert(&format!("CueLabel{i}Text"), label.clone());
+                num_labels += 1;
+            }
+
+            insert("NumCueLabels", num_labels.to_string());
+        }
+
+        if let Some(sampler) = &self.sampler {
+            insert("MidiUnityNote", sampler.midi_unity_note.to_string());
+            insert("NumSampleLoops", sampler.loops.len().to_string());
+
+            for (i, sample_loop) in sampler.loops.iter().enumerate() {
+                let loop_type = match sample_loop.loop_type {
+                    LoopType::Forward => 0,
+                    LoopType::PingPong => 1,
+                    LoopType::Backward => 2,
+                };
+
+                insert(
+                    &format!("Loop{i}Identifier"),
+                    sample_loop.identifier.to_string(),
+                );
+                insert(&format!("Loop{i}Type"), loop_type.to_string());
+                insert(&format!("Loop{i}Start"), sample_loop.start.to_string());
+                insert(&format!("Loop{i}End"), sample_loop.end.to_string());
+                insert(
+                    &format!("Loop{i}PlayCount"),
+                    sample_loop.play_count.to_string(),
+                );
+            }
+        }
+
+        metadata
+    }
+}
+
+/// Reads and writes AIFF files.
+pub struct AiffAudioFormat(UniquePtr<juce::AiffAudioFormat>);
+
+unsafe impl Send for AiffAudioFormat {}
+
+impl Default for AiffAudioFormat {
+    fn default() -> Self {
+        Self(juce::create_aiff_audio_format())
+    }
+}
+
+impl AiffAudioFormat {
+    /// Metadata key for whether the file is a one-shot, `"1"` or `"0"`.
+    pub const APPLE_ONE_SHOT: &'static str = "apple one shot";
+
+    /// Metadata key for whether the root note is set, `"1"` or `"0"`.
+    pub const APPLE_ROOT_SET: &'static str = "apple root set";
+
+    /// Metadata key for the MIDI root note.
+    pub const APPLE_ROOT_NOTE: &'static str = "apple root note";
+
+    /// Metadata key for the number of beats.
+    pub const APPLE_BEATS: &'static str = "apple beats";
+
+    /// Metadata key for the time signature denominator.
+    pub const APPLE_DENOMINATOR: &'static str = "apple denominator";
+
+    /// Metadata key for the time signature numerator.
+    pub const APPLE_NUMERATOR: &'static str = "apple numerator";
+
+    /// Metadata key for the category tags.
+    pub const APPLE_TAG: &'static str = "apple tag";
+
+    /// Metadata key for the musical key.
+    pub const APPLE_KEY: &'static str = "apple key";
+
+    /// Create the AIFF format.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a reader for the AIFF file at the given path.
+    ///
+    /// Returns [`None`] if the file can't be opened or isn't a valid AIFF file.
+    pub fn create_reader_for_file(&mut self, path: impl AsRef<Path>) -> Option<AudioFormatReader> {
+        let path = path_to_str(path.as_ref()).ok()?;
+        AudioFormatReader::new(juce::create_aiff_reader_for_file(self.0.pin_mut(), path))
+    }
+
+    /// Create a memory-mapped reader for the AIFF file at the given path.
+    ///
+    /// Nothing is mapped until [`MemoryMappedAudioFormatReader::map_entire_file`] or
+    /// [`MemoryMappedAudioFormatReader::map_section_of_file`] is called. Returns [`None`] if the
+    /// file can't be opened or isn't a valid AIFF file.
+    pub fn create_memory_mapped_reader(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Option<MemoryMappedAudioFormatReader> {
+        let path = path_to_str(path.as_ref()).ok()?;
+        MemoryMappedAudioFormatReader::new(juce::create_aiff_memory_mapped_reader(
+            self.0.pin_mut(),
+            path,
+        ))
+    }
+
+    /// Create a writer that writes an AIFF file at the given path, replacing any existing file.
+    ///
+    /// Supported bit depths are 8, 16, 24 and 32.
+    pub fn create_writer_for_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        sample_rate: f64,
+        num_channels: usize,
+        bits_per_sample: u32,
+    ) -> Result<AudioFormatWriter> {
+        self.create_writer_for_file_with_metadata(
+            path,
+            sample_rate,
+            num_channels,
+            bits_per_sample,
+            &HashMap::new(),
+        )
+    }
+
+    /// Create a writer that writes an AIFF file with the given metadata.
+    ///
+    /// Instrument and marker metadata is written using the same keys that the reader produces,
+    /// e.g. `MidiUnityNote`, `NumCueNames` and `CueName0`.
+    pub fn create_writer_for_file_with_metadata(
+        &mut self,
+        path: impl AsRef<Path>,
+        sample_rate: f64,
+        num_channels: usize,
+        bits_per_sample: u32,
+        metadata: &HashMap<String, String>,
+    ) -> Result<AudioFormatWriter> {
+        let path = path_to_str(path.as_ref())?;
+        juce::create_aiff_writer_for_file(
+            self.0.pin_mut(),
+            path,
+            sample_rate,
+            num_channels as u32,
+            bits_per_sample as i32,
+            &to_string_pairs(metadata),
+        )
+        .map(AudioFormatWriter::new)
+    }
+
+    /// Create a reader for AIFF data read from a stream.
+    ///
+    /// Returns [`None`] if the stream doesn't contain valid AIFF data.
+    pub fn create_reader_for_stream(
+        &mut self,
+        stream: impl Read + Seek + Send + 'static,
+    ) -> Option<AudioFormatReader> {
+        let stream: BoxedInputStream = Box::new(stream);
+        AudioFormatReader::new(juce::create_aiff_reader_for_stream(
+            self.0.pin_mut(),
+            Box::new(stream),
+        ))
+    }
+}
+
+/// The Apple loop metadata read from the `basc` chunk of an AIFF file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AiffMetadata {
+    /// True if the file should be played as a one-shot rather than looped.
+    pub one_shot: bool,
+
+    /// The MIDI root note.
+    pub root_note: Option<i32>,
+
+    /// The number of beats.
+    pub beats: Option<i32>,
+
+    /// The time signature numerator.
+    pub numerator: Option<i32>,
+
+    /// The time signature denominator.
+    pub denominator: Option<i32>,
+
+    /// The category tags.
+    pub tag: Option<String>,
+
+    /// The musical key.
+    pub key: Option<String>,
+}
+
+impl AiffMetadata {
+    /// Extract the AIFF metadata from a reader's metadata.
+    pub fn from_metadata(metadata: &HashMap<String, String>) -> Self {
+        let int = |key| metadata.get(key).and_then(|value| value.parse().ok());
+        let string = |key| metadata.get(key).filter(|value| !value.is_empty()).cloned();
+
+        Self {
+            one_shot: int(AiffAudioFormat::APPLE_ONE_SHOT) == Some(1),
+            root_note: if int(AiffAudioFormat::APPLE_ROOT_SET) == Some(1) {
+                int(AiffAudioFormat::APPLE_ROOT_NOTE)
+            } else {
+                None
+            },
+            beats: int(AiffAudioFormat::APPLE_BEATS),
+            numerator: int(AiffAudioFormat::APPLE_NUMERATOR),
+            denominator: int(AiffAudioFormat::APPLE_DENOMINATOR),
+            tag: string(AiffAudioFormat::APPLE_TAG),
+            key: string(AiffAudioFormat::APPLE_KEY),
+        }
+    }
+}
+
+/// Check that `num_samples` samples starting at `start` fit in a buffer of `len` samples, which
+/// also keeps them within the range of JUCE's `int` sample positions.
+fn check_sample_range(start: usize, num_samples: usize, len: usize) -> Result<()> {
+    match start.checked_add(num_samples) {
+        Some(end) if end <= len && end <= i32::MAX as usize => Ok(()),
+        _ => invalid_argument("sample range is out of bounds for the buffer"),
+    }
+}
+
+fn to_string_pairs(metadata: &HashMap<String, String>) -> Vec<StringPair> {
+    metadata
+        .iter()
+        .map(|(key, value)| StringPair {
+            key: key.clone(),
+            value: value.clone(),
+        })
+        .collect()
+}
+
+/// Reads the formats supported by the system's CoreAudio codecs, e.g. AAC, M4A and CAF.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+pub struct CoreAudioFormat(UniquePtr<juce::CoreAudioFormat>);
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+unsafe impl Send for CoreAudioFormat {}
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+impl Default for CoreAudioFormat {
+    fn default() -> Self {
+        Self(juce::create_core_audio_format())
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+impl CoreAudioFormat {
+    /// Create the CoreAudio format.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The file extensions that can be read, e.g. `.m4a`.
+    pub fn file_extensions(&self) -> Vec<String> {
+        juce::get_core_audio_file_extensions(&self.0)
+    }
+
+    /// Create a reader for the file at the given path.
+    ///
+    /// Returns [`None`] if the file can't be opened or isn't supported by the system.
+    pub fn create_reader_for_file(&mut self, path: impl AsRef<Path>) -> Option<AudioFormatReader> {
+        let path = path_to_str(path.as_ref()).ok()?;
+        AudioFormatReader::new(juce::create_core_audio_reader_for_file(
+            self.0.pin_mut(),
+            path,
+        ))
+    }
+
+    /// Create a reader for audio data read from a stream.
+    ///
+    /// Returns [`None`] if the stream's contents aren't supported by the system.
+    pub fn create_reader_for_stream(
+        &mut self,
+        stream: impl Read + Seek + Send + 'static,
+    ) -> Option<AudioFormatReader> {
+        let stream: BoxedInputStream = Box::new(stream);
+        AudioFormatReader::new(juce::create_core_audio_reader_for_stream(
+            self.0.pin_mut(),
+            Box::new(stream),
+        ))
+    }
+}
+
+/// Reads and writes FLAC files.
+#[cfg(feature = "flac")]
+pub struct FlacAudioFormat(UniquePtr<juce::FlacAudioFormat>);
+
+#[cfg(feature = "flac")]
+unsafe impl Send for FlacAudioFormat {}
+
+#[cfg(feature = "flac")]
+impl Default for FlacAudioFormat {
+    fn default() -> Self {
+        Self(juce::create_flac_audio_format())
+    }
+}
+
+#[cfg(feature = "flac")]
+impl FlacAudioFormat {
+    /// The fastest compression level.
+    pub const FASTEST_COMPRESSION_LEVEL: u32 = 0;
+
+    /// The compression level that produces the smallest files.
+    pub const SMALLEST_COMPRESSION_LEVEL: u32 = 8;
+
+    /// Create the FLAC format.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a reader for the FLAC file at the given path.
+    ///
+    /// Returns [`None`] if the file can't be opened or isn't a valid FLAC file.
+    pub fn create_reader_for_file(&mut self, path: impl AsRef<Path>) -> Option<AudioFormatReader> {
+        let path = path_to_str(path.as_ref()).ok()?;
+        AudioFormatReader::new(juce::create_flac_reader_for_file(self.0.pin_mut(), path))
+    }
+
+    /// Create a writer that writes a FLAC file at the given path, replacing any existing file.
+    ///
+    /// Supported bit depths are 16 and 24. The compression level ranges from
+    /// [`FASTEST_COMPRESSION_LEVEL`](Self::FASTEST_COMPRESSION_LEVEL) to
+    /// [`SMALLEST_COMPRESSION_LEVEL`](Self::SMALLEST_COMPRESSION_LEVEL).
+    pub fn create_writer_for_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        sample_rate: f64,
+        num_channels: usize,
+        bits_per_sample: u32,
+        compression_level: u32,
+    ) -> Result<AudioFormatWriter> {
+        let path = path_to_str(path.as_ref())?;
+        let Ok(compression_level) = i32::try_from(compression_level) else {
+            return invalid_argument("compression level is out of range");
+        };
+
+        juce::create_flac_writer_for_file(
+            self.0.pin_mut(),
+            path,
+            sample_rate,
+            num_channels as u32,
+            bits_per_sample as i32,
+            compression_level,
+        )
+        .map(AudioFormatWriter::new)
+    }
+
+    /// Create a reader for FLAC data read from a stream.
+    ///
+    /// Returns [`None`] if the stream doesn't contain valid FLAC data.
+    pub fn create_reader_for_stream(
+        &mut self,
+        stream: impl Read + Seek + Send + 'static,
+    ) -> Option<AudioFormatReader> {
+        let stream: BoxedInputStream = Box::new(stream);
+        AudioFormatReader::new(juce::create_flac_reader_for_stream(
+            self.0.pin_mut(),
+            Box::new(stream),
+        ))
+    }
+}
+
+/// Writes MP3 files by encoding them with an external LAME executable.
+///
+/// The samples are written to a temporary WAV file which is encoded when the writer is dropped.
+#[cfg(feature = "lame")]
+pub struct LAMEEncoderAudioFormat(UniquePtr<juce::LAMEEncoderAudioFormat>);
+
+#[cfg(feature = "lame")]
+unsafe impl Send for LAMEEncoderAudioFormat {}
+
+#[cfg(feature = "lame")]
+impl LAMEEncoderAudioFormat {
+    /// Create the format using the LAME executable at the given path.
+    ///
+    /// Returns an error if the executable doesn't exist.
+    pub fn new(lame_executable: impl AsRef<Path>) -> Result<Self> {
+        let path = path_to_str(lame_executable.as_ref())?;
+        juce::create_lame_encoder_audio_format(path).map(Self)
+    }
+
+    /// The descriptions of the available encoding qualities, e.g. "VBR quality 4" or
+    /// "128 Kb/s CBR".
+    ///
+    /// The index of an option is passed to [`create_writer_for_file`](Self::create_writer_for_file).
+    pub fn quality_options(&mut self) -> Vec<String> {
+        juce::get_lame_quality_options(self.0.pin_mut())
+    }
+
+    /// Create a writer that writes an MP3 file at the given path, replacing any existing file.
+    ///
+    /// The quality is an index into [`quality_options`](Self::quality_options).
+    pub fn create_writer_for_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        sample_rate: f64,
+        num_channels: usize,
+        quality: usize,
+    ) -> Result<AudioFormatWriter> {
+        let path = path_to_str(path.as_ref())?;
+        let Ok(quality) = i32::try_from(quality) else {
+            return invalid_argument("quality option index is out of range");
+        };
+
+        juce::create_lame_writer_for_file(
+            self.0.pin_mut(),
+            path,
+            sample_rate,
+            num_channels as u32,
+            quality,
+        )
+        .map(AudioFormatWriter::new)
+    }
+}
+
+/// Reads MP3 files.
+#[cfg(feature = "mp3")]
+pub struct MP3AudioFormat(UniquePtr<juce::MP3AudioFormat>);
+
+#[cfg(feature = "mp3")]
+unsafe impl Send for MP3AudioFormat {}
+
+#[cfg(feature = "mp3")]
+impl Default for MP3AudioFormat {
+    fn default() -> Self {
+        Self(juce::create_mp3_audio_format())
+    }
+}
+
+#[cfg(feature = "mp3")]
+impl MP3AudioFormat {
+    /// Create the MP3 format.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a reader for the MP3 file at the given path.
+    ///
+    /// Returns [`None`] if the file can't be opened or isn't a valid MP3 file.
+    pub fn create_reader_for_file(&mut self, path: impl AsRef<Path>) -> Option<AudioFormatReader> {
+        let path = path_to_str(path.as_ref()).ok()?;
+        AudioFormatReader::new(juce::create_mp3_reader_for_file(self.0.pin_mut(), path))
+    }
+
+    /// Create a reader for MP3 data read from a stream.
+    ///
+    /// Returns [`None`] if the stream doesn't contain valid MP3 data.
+    pub fn create_reader_for_stream(
+        &mut self,
+        stream: impl Read + Seek + Send + 'static,
+    ) -> Option<AudioFormatReader> {
+        let stream: BoxedInputStream = Box::new(stream);
+        AudioFormatReader::new(juce::create_mp3_reader_for_stream(
+            self.0.pin_mut(),
+            Box::new(stream),
+        ))
+    }
+}
+
+/// Reads and writes Ogg Vorbis files.
+#[cfg(feature = "ogg")]
+pub struct OggVorbisAudioFormat(UniquePtr<juce::OggVorbisAudioFormat>);
+
+#[cfg(feature = "ogg")]
+unsafe impl Send for OggVorbisAudioFormat {}
+
+#[cfg(feature = "ogg")]
+impl Default for OggVorbisAudioFormat {
+    fn default() -> Self {
+        Self(juce::create_ogg_vorbis_audio_format())
+    }
+}
+
+#[cfg(feature = "ogg")]
+impl OggVorbisAudioFormat {
+    /// Metadata key for the name of the encoder.
+    pub const ENCODER_NAME: &'static str = "encoder";
+
+    /// Metadata key for the title.
+    pub const TITLE: &'static str = "title";
+
+    /// Metadata key for the artist.
+    pub const ARTIST: &'static str = "artist";
+
+    /// Metadata key for the album.
+    pub const ALBUM: &'static str = "album";
+
+    /// Metadata key for the comment.
+    pub const COMMENT: &'static str = "comment";
+
+    /// Metadata key for the date.
+    pub const DATE: &'static str = "date";
+
+    /// Metadata key for the genre.
+    pub const GENRE: &'static str = "genre";
+
+    /// Metadata key for the track number.
+    pub const TRACK_NUMBER: &'static str = "tracknumber";
+
+    /// Create the Ogg Vorbis format.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The descriptions of the available encoding qualities, e.g. "128 kbps", from lowest to
+    /// highest.
+    ///
+    /// The index of an option is passed to [`create_writer_for_file`](Self::create_writer_for_file).
+    pub fn quality_options(&mut self) -> Vec<String> {
+        juce::get_ogg_vorbis_quality_options(self.0.pin_mut())
+    }
+
+    /// Create a reader for the Ogg Vorbis file at the given path.
+    ///
+    /// Returns [`None`] if the file can't be opened or isn't a valid Ogg Vorbis file.
+    pub fn create_reader_for_file(&mut self, path: impl AsRef<Path>) -> Option<AudioFormatReader> {
+        let path = path_to_str(path.as_ref()).ok()?;
+        AudioFormatReader::new(juce::create_ogg_vorbis_reader_for_file(
+            self.0.pin_mut(),
+            path,
+        ))
+    }
+
+    /// Create a writer that writes an Ogg Vorbis file at the given path, replacing any existing
+    /// file.
+    ///
+    /// The quality is an index into [`quality_options`](Self::quality_options).
+    pub fn create_writer_for_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        sample_rate: f64,
+        num_channels: usize,
+        quality: usize,
+    ) -> Result<AudioFormatWriter> {
+        self.create_writer_for_file_with_metadata(
+            path,
+            sample_rate,
+            num_channels,
+            quality,
+            &HashMap::new(),
+        )
+    }
+
+    /// Create a writer that writes an Ogg Vorbis file with the given tags, e.g.
+    /// [`TITLE`](Self::TITLE) and [`ARTIST`](Self::ARTIST).
+    pub fn create_writer_for_file_with_metadata(
+        &mut self,
+        path: impl AsRef<Path>,
+        sample_rate: f64,
+        num_channels: usize,
+        quality: usize,
+        metadata: &HashMap<String, String>,
+    ) -> Result<AudioFormatWriter> {
+        let path = path_to_str(path.as_ref())?;
+        let Ok(quality) = i32::try_from(quality) else {
+            return invalid_argument("quality option index is out of range");
+        };
+
+        juce::create_ogg_vorbis_writer_for_file(
+            self.0.pin_mut(),
+            path,
+            sample_rate,
+            num_channels as u32,
+            quality,
+            &to_string_pairs(metadata),
+        )
+        .map(AudioFormatWriter::new)
+    }
+
+    /// Create a reader for Ogg Vorbis data read from a stream.
+    ///
+    /// Returns [`None`] if the stream doesn't contain valid Ogg Vorbis data.
+    pub fn create_reader_for_stream(
+        &mut self,
+        stream: impl Read + Seek + Send + 'static,
+    ) -> Option<AudioFormatReader> {
+        let stream: BoxedInputStream = Box::new(stream);
+        AudioFormatReader::new(juce::create_ogg_vorbis_reader_for_stream(
+            self.0.pin_mut(),
+            Box::new(stream),
+        ))
+    }
+}
+
+/// Reads the formats supported by the Windows Media codecs, e.g. WMA.
+#[cfg(target_os = "windows")]
+pub struct WindowsMediaAudioFormat(UniquePtr<juce::WindowsMediaAudioFormat>);
+
+#[cfg(target_os = "windows")]
+unsafe impl Send for WindowsMediaAudioFormat {}
+
+#[cfg(target_os = "windows")]
+impl Default for WindowsMediaAudioFormat {
+    fn default() -> Self {
+        Self(juce::create_windows_media_audio_format())
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl WindowsMediaAudioFormat {
+    /// Create the Windows Media format.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The file extensions that can be read, e.g. `.wma`.
+    pub fn file_extensions(&self) -> Vec<String> {
+        juce::get_windows_media_file_extensions(&self.0)
+    }
+
+    /// Create a reader for the file at the given path.
+    ///
+    /// Returns [`None`] if the file can't be opened or isn't supported by the system.
+    pub fn create_reader_for_file(&mut self, path: impl AsRef<Path>) -> Option<AudioFormatReader> {
+        let path = path_to_str(path.as_ref()).ok()?;
+        AudioFormatReader::new(juce::create_windows_media_reader_for_file(
+            self.0.pin_mut(),
+            path,
+        ))
+    }
+
+    /// Create a reader for audio data read from a stream.
+    ///
+    /// Returns [`None`] if the stream's contents aren't supported by the system.
+    pub fn create_reader_for_stream(
+        &mut self,
+        stream: impl Read + Seek + Send + 'static,
+    ) -> Option<AudioFormatReader> {
+        let stream: BoxedInputStream = Box::new(stream);
+        AudioFormatReader::new(juce::create_windows_media_reader_for_stream(
+            self.0.pin_mut(),
+            Box::new(stream),
+        ))
+    }
+}
+
+/// Writes samples to an audio file.
+///
+/// Samples are written straight to the destination, which is finalised when the writer is dropped.
+/// An existing file is only truncated once the format has accepted the writer's settings, so it's
+/// left untouched if creating the writer fails.
+pub struct AudioFormatWriter {
+    writer: UniquePtr<juce::AudioFormatWriter>,
+    // Interleaved samples are split into channels here, a chunk at a time.
+    deinterleaved: AudioBuffer,
+}
+
+unsafe impl Send for AudioFormatWriter {}
+
+impl AudioFormatWriter {
+    /// The number of frames deinterleaved at a time by [`write_interleaved`](Self::write_interleaved).
+    const INTERLEAVED_CHUNK_SIZE: usize = 4096;
+
+    pub(crate) fn new(writer: UniquePtr<juce::AudioFormatWriter>) -> Self {
+        Self {
+            writer,
+            deinterleaved: AudioBuffer::new(0, 0),
+        }
+    }
+
+    /// The sample rate in Hertz.
+    pub fn sample_rate(&self) -> f64 {
+        juce::get_writer_sample_rate(&self.writer)
+    }
+
+    /// The number of channels.
+    pub fn num_channels(&self) -> usize {
+        juce::get_writer_num_channels(&self.writer) as usize
+    }
+
+    /// The number of bits per sample.
+    pub fn bits_per_sample(&self) -> u32 {
+        juce::get_writer_bits_per_sample(&self.writer)
+    }
+
+    /// Write `num_samples` samples from the buffer, starting at `start_sample`.
+    ///
+    /// The buffer must have at least as many channels as the writer. Returns an error if the range
+    /// doesn't fit in the buffer.
+    pub fn write(
+        &mut self,
+        buffer: &AudioBuffer,
+        start_sample: usize,
+        num_samples: usize,
+    ) -> Result<()> {
+        check_sample_range(start_sample, num_samples, buffer.samples())?;
+
+        juce::write_audio_format_writer(
+            self.writer.pin_mut(),
+            &buffer.0,
+            start_sample as i32,
+            num_samples as i32,
+        )
+    }
+
+    /// Write a whole buffer.
+    pub fn write_buffer(&mut self, buffer: &AudioBuffer) -> Result<()> {
+        self.write(buffer, 0, buffer.samples())
+    }
+
+    /// Write interleaved samples, e.g. `[L0, R0, L1, R1, ...]`.
+    ///
+    /// Returns an error if the samples don't make up a whole number of frames.
+    pub fn write_interleaved(&mut self, samples: &[f32]) -> Result<()> {
+        let channels = self.num_channels();
+        if channels == 0 || !samples.len().is_multiple_of(channels) {
+            return invalid_argument("samples don't make up a whole number of frames");
+        }
+
+        if self.deinterleaved.channels() != channels {
+            self.deinterleaved
+                .set_size(channels, Self::INTERLEAVED_CHUNK_SIZE);
+        }
+
+        for chunk in samples.chunks(channels * Self::INTERLEAVED_CHUNK_SIZE) {
+            for (frame, samples) in chunk.chunks_exact(channels).enumerate() {
+                for (channel, sample) in samples.iter().enumerate() {
+                    self.deinterleaved[channel][frame] = *sample;
+                }
+            }
+
+            juce::write_audio_format_writer(
+                self.writer.pin_mut(),
+                &self.deinterleaved.0,
+                0,
+                (chunk.len() / channels) as i32,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Flush any buffered data to the file, updating its header so that the samples written so far
+    /// can be read back if the application stops unexpectedly.
+    pub fn flush(&mut self) -> Result<()> {
+        juce::flush_audio_format_writer(self.writer.pin_mut())
+    }
+}
+
+/// Writes samples to an audio file on a background thread.
+///
+/// Samples are pushed into a FIFO which is flushed to the file on a background thread, so writing
+/// doesn't block on disk I/O and is safe to do from the audio thread. If the FIFO is full the
+/// samples are dropped and counted as an overrun.
+pub struct ThreadedWriter {
+    writer: UniquePtr<juce::ThreadedWriter>,
+    overruns: usize,
+    dropped_samples: usize,
+}
+
+unsafe impl Send for ThreadedWriter {}
+
+impl ThreadedWriter {
+    /// Create a threaded writer with a FIFO that holds `fifo_size` samples per channel.
+    ///
+    /// The file is finalised when the threaded writer is dropped.
+    ///
+    /// The FIFO is flushed on a background thread shared by all the threaded writers that weren't
+    /// given a thread.
+    pub fn new(writer: AudioFormatWriter, fifo_size: usize) -> Self {
+        Self::create(writer, SharedPtr::null(), fifo_size)
+    }
+
+    /// Create a threaded writer that flushes its FIFO on the given thread.
+    pub fn with_thread(
+        writer: AudioFormatWriter,
+        thread: &TimeSliceThread,
+        fifo_size: usize,
+    ) -> Self {
+        Self::create(writer, thread.as_juce(), fifo_size)
+    }
+
+    fn create(
+        writer: AudioFormatWriter,
+        thread: SharedPtr<juce::TimeSliceThread>,
+        fifo_size: usize,
+    ) -> Self {
+        Self {
+            writer: juce::create_threaded_writer(
+                writer.writer,
+                thread,
+                fifo_size.min(i32::MAX as usize) as i32,
+            ),
+            overruns: 0,
+            dropped_samples: 0,
+        }
+    }
+
+    /// Set how many samples are written to the file between flushes of the file's header.
+    ///
+    /// Flushing keeps the file readable, up to the last flush, if the application stops
+    /// unexpectedly. Zero, the default, disables flushing.
+    pub fn set_flush_interval(&mut self, samples_per_flush: usize) {
+        self.writer
+            .pin_mut()
+            .set_flush_interval(samples_per_flush.min(i32::MAX as usize) as i32);
+    }
+
+    /// Push the samples in a buffer into the FIFO.
+    ///
+    /// Returns false if there wasn't enough space in the FIFO, in which case none of the samples
+    /// are written. The buffer must have at least as many channels as the writer.
+    pub fn write(&mut self, buffer: &AudioBuffer) -> Result<bool> {
+        self.write_samples(&buffer.0, buffer.samples())
+    }
+
+    /// Push the samples from an audio device's input into the FIFO, see [`write`](Self::write).
+    pub fn write_input(&mut self, input: &InputAudioSampleBuffer<'_>) -> Result<bool> {
+        self.write_samples(input.buffer, input.samples())
+    }
+
+    /// The number of writes that failed because the FIFO was full.
+    pub fn overruns(&self) -> usize {
+        self.overruns
+    }
+
+    /// The number of samples per channel that were dropped because the FIFO was full.
+    pub fn dropped_samples(&self) -> usize {
+        self.dropped_samples
+    }
+
+    fn write_samples(&mut self, buffer: &juce::AudioSampleBuffer, samples: usize) -> Result<bool> {
+        let written = juce::write_threaded_writer(self.writer.pin_mut(), buffer)?;
+
+        if !written {
+            self.overruns += 1;
+            self.dropped_samples += samples;
+        }
+
+        Ok(written)
+    }
+}
+
+pub(crate) mod ffi {
+    use super::*;
+
+    pub mod custom_audio_format_reader {
+        use super::*;
+
+        pub fn custom_reader_format_name(self_: &BoxedCustomAudioFormatReader) -> String {
+            self_.format_name().to_string()
+        }
+
+        pub fn custom_reader_sample_rate(self_: &BoxedCustomAudioFormatReader) -> f64 {
+            self_.sample_rate()
+        }
+
+        pub fn custom_reader_num_channels(self_: &BoxedCustomAudioFormatReader) -> u32 {
+            self_.num_channels() as u32
+        }
+
+        pub fn custom_reader_length_in_samples(self_: &BoxedCustomAudioFormatReader) -> i64 {
+            self_.length_in_samples() as i64
+        }
+
+        pub fn custom_reader_bits_per_sample(self_: &BoxedCustomAudioFormatReader) -> u32 {
+            self_.bits_per_sample()
+        }
+
+        pub fn custom_reader_read_samples(
+            mut self_: Pin<&mut BoxedCustomAudioFormatReader>,
+            buffer: Pin<&mut juce::AudioSampleBuffer>,
+            start_sample: i64,
+        ) -> bool {
+            let Ok(start_sample) = u64::try_from(start_sample) else {
+                return true;
+            };
+
+            self_.read_samples(&mut OutputAudioSampleBuffer::new(buffer), start_sample)
+        }
+    }
+
+    pub mod custom_audio_format {
+        use super::*;
+
+        pub fn custom_format_name(self_: &BoxedCustomAudioFormat) -> String {
+            self_.format_name().to_string()
+        }
+
+        pub fn custom_format_file_extensions(self_: &BoxedCustomAudioFormat) -> Vec<String> {
+            self_.file_extensions()
+        }
+
+        pub fn custom_format_can_handle_file(self_: &BoxedCustomAudioFormat, path: &str) -> bool {
+            self_.can_handle_file(Path::new(path))
+        }
+
+        pub fn custom_format_possible_sample_rates(self_: &BoxedCustomAudioFormat) -> Vec<i32> {
+            to_i32s(self_.possible_sample_rates())
+        }
+
+        pub fn custom_format_possible_bit_depths(self_: &BoxedCustomAudioFormat) -> Vec<i32> {
+            to_i32s(self_.possible_bit_depths())
+        }
+
+        pub fn custom_format_can_do_stereo(self_: &BoxedCustomAudioFormat) -> bool {
+            self_.can_do_stereo()
+        }
+
+        pub fn custom_format_can_do_mono(self_: &BoxedCustomAudioFormat) -> bool {
+            self_.can_do_mono()
+        }
+
+        pub fn custom_format_create_reader(
+            mut self_: Pin<&mut BoxedCustomAudioFormat>,
+            stream: UniquePtr<juce::InputStream>,
+        ) -> UniquePtr<juce::AudioFormatReader> {
+            match self_.create_reader(JuceInputStream::new(stream)) {
+                Some(reader) => juce::create_custom_audio_format_reader(Box::new(reader)),
+                None => UniquePtr::null(),
+            }
+        }
+
+        pub fn custom_format_create_writer(
+            mut self_: Pin<&mut BoxedCustomAudioFormat>,
+            stream: UniquePtr<juce::OutputStream>,
+            sample_rate: f64,
+            num_channels: u32,
+            bits_per_sample: i32,
+            metadata: Vec<StringPair>,
+            quality_option_index: i32,
+        ) -> UniquePtr<juce::AudioFormatWriter> {
+            let options = AudioFormatWriterOptions {
+                sample_rate,
+                num_channels: num_channels as usize,
+                bits_per_sample: bits_per_sample.max(0) as u32,
+                metadata: metadata
+                    .into_iter()
+                    .map(|StringPair { key, value }| (key, value))
+                    .collect(),
+                quality_option_index: quality_option_index.max(0) as usize,
+            };
+
+            match self_.create_writer(JuceOutputStream::new(stream), &options) {
+                Some(writer) => juce::create_custom_audio_format_writer(
+                    Box::new(writer),
+                    self_.format_name(),
+                    options.sample_rate,
+                    num_channels,
+                    options.bits_per_sample,
+                ),
+                None => UniquePtr::null(),
+            }
+        }
+
+        fn to_i32s(values: Vec<u32>) -> Vec<i32> {
+            values
+                .into_iter()
+                .map(|value| value.min(i32::MAX as u32) as i32)
+                .collect()
+        }
+    }
+
+    pub mod custom_audio_format_writer {
+        use super::*;
+
+        pub fn custom_writer_write(
+            mut self_: Pin<&mut BoxedCustomAudioFormatWriter>,
+            buffer: &juce::AudioSampleBuffer,
+        ) -> bool {
+            self_.write(&InputAudioSampleBuffer::new(buffer))
+        }
+
+        pub fn custom_writer_flush(mut self_: Pin<&mut BoxedCustomAudioFormatWriter>) -> bool {
+            self_.flush()
+        }
+    }
+}
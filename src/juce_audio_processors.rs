@@ -0,0 +1,2866 @@
+//! Classes for loading and playing VST, AU, LADSPA, or LV2 plugins and managing plugin parameters.
+
+use {
+    crate::{
+        invalid_argument,
+        juce::{self, ParameterGroupEntry},
+        juce_audio_basics::{AudioBuffer, MidiBuffer},
+        juce_audio_devices::OutputAudioSampleBuffer,
+        juce_audio_formats::{AudioFormatReader, AudioFormatWriter},
+        juce_core::path_to_str,
+        Result, JUCE,
+    },
+    cxx::UniquePtr,
+    std::{
+        fmt,
+        marker::PhantomData,
+        path::{Path, PathBuf},
+        pin::Pin,
+        sync::{
+            atomic::{AtomicPtr, AtomicU32, AtomicU64, AtomicUsize, Ordering},
+            Arc, Mutex, PoisonError, RwLock, RwLockReadGuard,
+        },
+        time::Duration,
+    },
+};
+
+pub use crate::juce::{
+    AudioChannelSet, BusInfo, BusesLayout, Connection, NodeAndChannel, NodeId, ParameterInfo,
+    PlayHeadPosition, PluginDescription,
+};
+
+/// Keeps a list of the available plugin formats, and uses them to find and instantiate plugins.
+pub struct AudioPluginFormatManager<'juce> {
+    manager: UniquePtr<juce::AudioPluginFormatManager>,
+    _juce: PhantomData<&'juce ()>,
+}
+
+unsafe impl Send for AudioPluginFormatManager<'_> {}
+
+impl<'juce> AudioPluginFormatManager<'juce> {
+    /// Create a format manager with no formats registered.
+    pub fn new(_juce: &'juce JUCE) -> Self {
+        Self {
+            manager: juce::create_audio_plugin_format_manager(),
+            _juce: PhantomData,
+        }
+    }
+
+    /// Create a format manager with the default formats registered.
+    pub fn with_default_formats(juce: &'juce JUCE) -> Self {
+        let mut manager = Self::new(juce);
+        manager.add_default_formats();
+        manager
+    }
+
+    /// Register the formats enabled for this build: AudioUnit on macOS, and LV2 and VST3 when the
+    /// `lv2` and `vst3` features are enabled.
+    pub fn add_default_formats(&mut self) {
+        self.manager.pin_mut().add_default_formats();
+    }
+
+    /// The number of registered formats.
+    pub fn num_formats(&self) -> usize {
+        self.manager.get_num_formats() as usize
+    }
+
+    /// The names of the registered formats.
+    pub fn format_names(&self) -> Vec<String> {
+        juce::get_plugin_format_names(&self.manager)
+    }
+
+    /// Find the plugins at the given path.
+    ///
+    /// The path may be a plugin itself, or a directory to search for plugins. Plugins are loaded to
+    /// query their details, so scanning a misbehaving plugin can bring down the process. Returns an
+    /// error if the path isn't valid UTF-8.
+    pub fn scan(
+        &mut self,
+        path: impl AsRef<Path>,
+        recursive: bool,
+    ) -> Result<Vec<PluginDescription>> {
+        let path = path_to_str(path.as_ref())?;
+        Ok(juce::scan_path_for_plugins(
+            self.manager.pin_mut(),
+            path,
+            recursive,
+        ))
+    }
+
+    /// Find the plugins in each format's default install locations.
+    ///
+    /// For AudioUnit and LV2 this finds every plugin installed on the system, as those formats
+    /// don't locate plugins by path.
+    pub fn scan_default_locations(&mut self) -> Vec<PluginDescription> {
+        juce::scan_default_plugin_locations(self.manager.pin_mut())
+    }
+
+    /// Create an instance of the plugin matching the description.
+    ///
+    /// Returns an error if none of the registered formats can load the plugin. Plugins that can
+    /// only be created asynchronously, such as some AUv3s, fail when this is called on the message
+    /// thread.
+    pub fn create_plugin_instance(
+        &mut self,
+        description: &PluginDescription,
+        sample_rate: f64,
+        block_size: usize,
+    ) -> Result<PluginInstance<'juce>> {
+        juce::create_plugin_instance(self.manager.pin_mut(), description, sample_rate, block_size)
+            .map(|instance| PluginInstance {
+                listeners: vec![],
+                next_listener_id: 0,
+                play_head: None,
+                parameters: PluginParameterHandle::new(
+                    instance
+                        .as_ref()
+                        .map_or(std::ptr::null(), |instance| instance),
+                ),
+                instance,
+                _juce: PhantomData,
+            })
+    }
+}
+
+/// A group of a processor's parameters, which can contain further groups.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParameterGroup {
+    /// The group's ID, which is unique within the processor.
+    pub id: String,
+
+    /// The group's name, to show to users.
+    pub name: String,
+
+    /// The indices of the parameters directly in the group.
+    pub parameters: Vec<usize>,
+
+    /// The groups in the group.
+    pub subgroups: Vec<ParameterGroup>,
+}
+
+impl ParameterGroup {
+    fn from_entries(entries: Vec<ParameterGroupEntry>) -> Self {
+        // Each entry follows its parent, so the groups that haven't been finished yet form a stack.
+        let mut stack: Vec<Self> = vec![];
+
+        for entry in entries {
+            while stack.len() > entry.depth {
+                let group = stack.pop().unwrap();
+                stack.last_mut().unwrap().subgroups.push(group);
+            }
+
+            stack.push(Self {
+                id: entry.id,
+                name: entry.name,
+                parameters: entry.parameters,
+                subgroups: vec![],
+            });
+        }
+
+        while stack.len() > 1 {
+            let group = stack.pop().unwrap();
+            stack.last_mut().unwrap().subgroups.push(group);
+        }
+
+        stack.pop().unwrap_or_default()
+    }
+
+    /// The indices of the parameters in the group and all of its subgroups.
+    pub fn all_parameters(&self) -> Vec<usize> {
+        let mut parameters = self.parameters.clone();
+
+        for subgroup in &self.subgroups {
+            parameters.extend(subgroup.all_parameters());
+        }
+
+        parameters
+    }
+
+    /// The group, or one of its subgroups, with the given ID.
+    pub fn find(&self, id: &str) -> Option<&Self> {
+        if self.id == id {
+            return Some(self);
+        }
+
+        self.subgroups.iter().find_map(|subgroup| subgroup.find(id))
+    }
+}
+
+/// Receives notifications of changes to an audio processor.
+///
+/// Notifications can arrive on any thread, including the audio thread, so implementations should
+/// return quickly.
+pub trait AudioProcessorListener: Send + Sync {
+    /// Called when the normalised value of the parameter at the given index changes.
+    fn parameter_changed(&self, index: usize, value: f32);
+
+    /// Called when something other than a parameter's value changes, such as the latency or the
+    /// parameter list.
+    fn processor_changed(&self) {}
+
+    /// Called when the user starts changing the parameter at the given index, such as by pressing
+    /// the mouse on a slider in the plugin's editor.
+    fn parameter_change_gesture_began(&self, _index: usize) {}
+
+    /// Called when the user stops changing the parameter at the given index.
+    fn parameter_change_gesture_ended(&self, _index: usize) {}
+}
+
+pub(crate) type BoxedAudioProcessorListener = Box<dyn AudioProcessorListener>;
+
+/// Identifies a listener added with [`PluginInstance::add_listener`] or
+/// [`AudioParameter::add_listener`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerId(u64);
+
+impl AudioChannelSet {
+    /// A set with no channels, used for disabled buses.
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// A single centre channel.
+    pub fn mono() -> Self {
+        Self::canonical(1)
+    }
+
+    /// Left and right channels.
+    pub fn stereo() -> Self {
+        Self::canonical(2)
+    }
+
+    /// The usual set for a number of channels, such as stereo for two channels or 5.1 for six,
+    /// falling back to discrete channels if there's no usual set.
+    pub fn canonical(num_channels: usize) -> Self {
+        juce::canonical_channel_set(num_channels)
+    }
+
+    /// A number of channels with no particular speaker positions.
+    pub fn discrete(num_channels: usize) -> Self {
+        juce::discrete_channel_set(num_channels)
+    }
+
+    /// The number of channels in the set.
+    pub fn size(&self) -> usize {
+        self.channel_types.len()
+    }
+
+    /// Whether the set has no channels.
+    pub fn is_disabled(&self) -> bool {
+        self.channel_types.is_empty()
+    }
+
+    /// A description of the set, such as "Stereo".
+    pub fn description(&self) -> String {
+        juce::get_channel_set_description(self)
+    }
+
+    /// The abbreviated names of the set's speakers, such as "L R".
+    pub fn speaker_arrangement(&self) -> String {
+        juce::get_speaker_arrangement(self)
+    }
+}
+
+/// Provides hosted plugins with the position of the host's transport, so that tempo-synced features
+/// such as delays and arpeggiators follow it.
+///
+/// The position is requested on the audio thread, from within the processing of each block, so
+/// implementations should return quickly and avoid locking.
+pub trait AudioPlayHead: Send + Sync {
+    /// The current position, or [`None`] if the transport's position is unknown.
+    fn position(&self) -> Option<PlayHeadPosition>;
+}
+
+pub(crate) type BoxedAudioPlayHead = Box<dyn AudioPlayHead>;
+
+impl Default for PlayHeadPosition {
+    /// A stopped transport at the start of the timeline, at 120 BPM in 4/4.
+    fn default() -> Self {
+        Self {
+            bpm: 120.0,
+            time_signature_numerator: 4,
+            time_signature_denominator: 4,
+            time_in_samples: 0,
+            time_in_seconds: 0.0,
+            ppq_position: 0.0,
+            ppq_position_of_last_bar_start: 0.0,
+            is_playing: false,
+            is_recording: false,
+            is_looping: false,
+            ppq_loop_start: 0.0,
+            ppq_loop_end: 0.0,
+        }
+    }
+}
+
+/// An instance of a hosted plugin.
+///
+/// Use a [`PluginParameterHandle`] to read and set parameters from other threads, including while
+/// the plugin is being played by an [`AudioProcessorPlayer`].
+pub struct PluginInstance<'juce> {
+    // Declared before the instance so that they're removed from it before it's dropped.
+    listeners: Vec<(ListenerId, UniquePtr<juce::AudioProcessorListenerHandle>)>,
+    next_listener_id: u64,
+    play_head: Option<UniquePtr<juce::AudioPlayHeadHandle>>,
+    parameters: PluginParameterHandle,
+    instance: UniquePtr<juce::AudioPluginInstance>,
+    _juce: PhantomData<&'juce ()>,
+}
+
+unsafe impl Send for PluginInstance<'_> {}
+
+impl Drop for PluginInstance<'_> {
+    fn drop(&mut self) {
+        self.parameters.detach();
+    }
+}
+
+/// Reads and sets the parameters of a [`PluginInstance`] from any thread.
+///
+/// Handles keep working when the instance is added to an [`AudioProcessorGraph`]. Once the
+/// instance has been dropped, or its node removed from the graph, every method returns an error.
+#[derive(Clone)]
+pub struct PluginParameterHandle(Arc<ParameterAccess>);
+
+// Null once the instance has been deleted. The lock stops the instance from being deleted while a
+// parameter is being used.
+struct ParameterAccess(RwLock<*const juce::AudioPluginInstance>);
+
+// JUCE's parameters can be read and set from any thread.
+unsafe impl Send for ParameterAccess {}
+unsafe impl Sync for ParameterAccess {}
+
+impl PluginParameterHandle {
+    fn new(instance: *const juce::AudioPluginInstance) -> Self {
+        Self(Arc::new(ParameterAccess(RwLock::new(instance))))
+    }
+
+    fn instance(&self) -> RwLockReadGuard<'_, *const juce::AudioPluginInstance> {
+        self.0 .0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn detach(&self) {
+        *self.0 .0.write().unwrap_or_else(PoisonError::into_inner) = std::ptr::null();
+    }
+
+    /// The normalised value of the parameter at the given index.
+    pub fn value(&self, index: usize) -> Result<f32> {
+        unsafe { juce::get_parameter_value(*self.instance(), index) }
+    }
+
+    /// Set the normalised value of the parameter at the given index, clamped to the range 0 to 1,
+    /// and notify the plugin's listeners.
+    pub fn set_value(&self, index: usize, value: f32) -> Result<()> {
+        unsafe { juce::set_parameter_value(*self.instance(), index, value) }
+    }
+
+    /// Tell the plugin that the user has started changing the parameter at the given index, such
+    /// as by touching a control on a hardware controller.
+    ///
+    /// Each call should be followed by a call to [`PluginParameterHandle::end_change_gesture`]
+    /// once the user lets go.
+    pub fn begin_change_gesture(&self, index: usize) -> Result<()> {
+        unsafe { juce::begin_parameter_change_gesture(*self.instance(), index) }
+    }
+
+    /// Tell the plugin that the user has stopped changing the parameter at the given index.
+    pub fn end_change_gesture(&self, index: usize) -> Result<()> {
+        unsafe { juce::end_parameter_change_gesture(*self.instance(), index) }
+    }
+
+    /// The text the plugin displays for a normalised value of the parameter at the given index,
+    /// e.g. `-6.0 dB`.
+    pub fn text_for_value(&self, index: usize, value: f32) -> Result<String> {
+        unsafe { juce::get_parameter_text(*self.instance(), index, value) }
+    }
+
+    /// The normalised value of the parameter at the given index that corresponds to the text.
+    pub fn value_for_text(&self, index: usize, text: &str) -> Result<f32> {
+        unsafe { juce::get_parameter_value_for_text(*self.instance(), index, text) }
+    }
+}
+
+impl PluginInstance<'_> {
+    /// The name of the plugin.
+    pub fn name(&self) -> String {
+        juce::get_plugin_name(&self.instance)
+    }
+
+    /// A description of the plugin, which can be used to create further instances of it.
+    pub fn description(&self) -> PluginDescription {
+        juce::get_plugin_description(&self.instance)
+    }
+
+    /// The number of input channels across all of the plugin's input buses.
+    pub fn num_input_channels(&self) -> usize {
+        self.instance.get_total_num_input_channels() as usize
+    }
+
+    /// The number of output channels across all of the plugin's output buses.
+    pub fn num_output_channels(&self) -> usize {
+        self.instance.get_total_num_output_channels() as usize
+    }
+
+    /// The sample rate the plugin was last prepared with.
+    pub fn sample_rate(&self) -> f64 {
+        self.instance.get_sample_rate()
+    }
+
+    /// The maximum block size the plugin was last prepared with.
+    pub fn block_size(&self) -> usize {
+        self.instance.get_block_size().max(0) as usize
+    }
+
+    /// Whether the plugin wants MIDI input, as instruments and MIDI effects do.
+    pub fn accepts_midi(&self) -> bool {
+        self.instance.accepts_midi()
+    }
+
+    /// Whether the plugin produces MIDI output, as arpeggiators do.
+    pub fn produces_midi(&self) -> bool {
+        self.instance.produces_midi()
+    }
+
+    /// Whether the plugin only processes MIDI, in which case it can be processed with a buffer
+    /// that has no channels.
+    pub fn is_midi_effect(&self) -> bool {
+        self.instance.is_midi_effect()
+    }
+
+    /// How long the plugin keeps producing output after its input stops.
+    ///
+    /// Plugins with an infinite tail, such as some synths and freeze reverbs, report
+    /// [`Duration::MAX`].
+    pub fn tail_length(&self) -> Duration {
+        tail_length_from_seconds(self.instance.get_tail_length_seconds())
+    }
+
+    /// The number of samples the plugin delays its output by.
+    ///
+    /// Plugins can change their latency when they're prepared or when their parameters change, in
+    /// which case they notify their listeners with [`AudioProcessorListener::processor_changed`].
+    pub fn latency_samples(&self) -> usize {
+        self.instance.get_latency_samples().max(0) as usize
+    }
+
+    /// Tell the plugin whether it's being rendered offline rather than played in real time.
+    ///
+    /// Plugins may use higher quality algorithms or wait for background work when rendering
+    /// offline. See [`OfflineRenderer`].
+    pub fn set_non_realtime(&mut self, non_realtime: bool) {
+        self.instance.pin_mut().set_non_realtime(non_realtime);
+    }
+
+    /// Whether the plugin is being rendered offline, see [`PluginInstance::set_non_realtime`].
+    pub fn is_non_realtime(&self) -> bool {
+        self.instance.is_non_realtime()
+    }
+
+    /// Prepare the plugin for playback.
+    pub fn prepare(&mut self, sample_rate: f64, block_size: usize) {
+        juce::prepare_plugin_instance(self.instance.pin_mut(), sample_rate, block_size);
+    }
+
+    /// Process a block of audio and MIDI in place.
+    ///
+    /// The buffer must have at least as many channels as the plugin's inputs or outputs, whichever
+    /// is greater, and no more samples than the block size the plugin was prepared with. Channels
+    /// beyond the plugin's inputs are cleared before processing, so an instrument's output replaces
+    /// them.
+    ///
+    /// The MIDI buffer is passed to plugins that [accept MIDI](PluginInstance::accepts_midi), and on
+    /// return holds any MIDI the plugin [produced](PluginInstance::produces_midi).
+    pub fn process(
+        &mut self,
+        buffer: &mut OutputAudioSampleBuffer<'_>,
+        midi: &mut MidiBuffer,
+    ) -> Result<()> {
+        juce::process_plugin_instance(
+            self.instance.pin_mut(),
+            buffer.buffer.as_mut(),
+            midi.0.pin_mut(),
+        )
+    }
+
+    /// Release any resources allocated by [`PluginInstance::prepare`].
+    pub fn release_resources(&mut self) {
+        self.instance.pin_mut().release_resources();
+    }
+
+    /// The number of parameters the plugin has.
+    pub fn num_parameters(&self) -> usize {
+        juce::get_num_parameters(&self.instance)
+    }
+
+    /// Information about each of the plugin's parameters.
+    ///
+    /// Together with [`PluginInstance::parameter_groups`], this describes the plugin's parameters
+    /// well enough to generate controls for them, for plugins without an editor or hosts without a
+    /// display.
+    pub fn parameters(&self) -> Vec<ParameterInfo> {
+        juce::get_parameter_infos(&self.instance)
+    }
+
+    /// The groups the plugin arranges its parameters in, such as a synth's oscillator and filter
+    /// sections.
+    ///
+    /// The root group, which has an empty ID and name, holds the parameters and groups that aren't
+    /// in another group.
+    pub fn parameter_groups(&self) -> ParameterGroup {
+        ParameterGroup::from_entries(juce::get_parameter_groups(&self.instance))
+    }
+
+    /// A handle for reading and setting the plugin's parameters from any thread.
+    pub fn parameter_handle(&self) -> PluginParameterHandle {
+        self.parameters.clone()
+    }
+
+    /// Save the plugin's full state, so that it can be restored with [`PluginInstance::set_state`].
+    pub fn state(&mut self) -> Vec<u8> {
+        juce::get_plugin_state_information(self.instance.pin_mut())
+    }
+
+    /// Restore state saved with [`PluginInstance::state`].
+    ///
+    /// Returns an error if the state is larger than JUCE can pass to the plugin.
+    pub fn set_state(&mut self, state: &[u8]) -> Result<()> {
+        check_state_size(state)?;
+        juce::set_plugin_state_information(self.instance.pin_mut(), state);
+        Ok(())
+    }
+
+    /// Save the state of the current program only, which for most plugins is the same as
+    /// [`PluginInstance::state`].
+    pub fn current_program_state(&mut self) -> Vec<u8> {
+        juce::get_plugin_current_program_state_information(self.instance.pin_mut())
+    }
+
+    /// Restore state saved with [`PluginInstance::current_program_state`].
+    ///
+    /// Returns an error if the state is larger than JUCE can pass to the plugin.
+    pub fn set_current_program_state(&mut self, state: &[u8]) -> Result<()> {
+        check_state_size(state)?;
+        juce::set_plugin_current_program_state_information(self.instance.pin_mut(), state);
+        Ok(())
+    }
+
+    /// The number of programs, or presets, the plugin provides.
+    pub fn num_programs(&mut self) -> usize {
+        self.instance.pin_mut().get_num_programs().max(0) as usize
+    }
+
+    /// The index of the current program.
+    pub fn current_program(&mut self) -> usize {
+        self.instance.pin_mut().get_current_program().max(0) as usize
+    }
+
+    /// Select a program. Returns an error if the index is out of range.
+    pub fn set_current_program(&mut self, index: usize) -> Result<()> {
+        juce::set_plugin_current_program(self.instance.pin_mut(), index)
+    }
+
+    /// The name of the program at the given index.
+    pub fn program_name(&mut self, index: usize) -> Result<String> {
+        juce::get_plugin_program_name(self.instance.pin_mut(), index)
+    }
+
+    /// The names of all of the plugin's programs.
+    pub fn program_names(&mut self) -> Vec<String> {
+        (0..self.num_programs())
+            .filter_map(|index| self.program_name(index).ok())
+            .collect()
+    }
+
+    /// Listen for changes to the plugin, such as parameter changes made by its editor or by
+    /// automation.
+    ///
+    /// Listeners are removed when the instance is dropped or added to an [`AudioProcessorGraph`].
+    pub fn add_listener(&mut self, listener: impl AudioProcessorListener + 'static) -> ListenerId {
+        let listener: BoxedAudioProcessorListener = Box::new(listener);
+        let handle =
+            juce::add_plugin_instance_listener(self.instance.pin_mut(), Box::new(listener));
+
+        let id = ListenerId(self.next_listener_id);
+        self.next_listener_id += 1;
+        self.listeners.push((id, handle));
+        id
+    }
+
+    /// Remove a listener. Returns `false` if there's no such listener.
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        let count = self.listeners.len();
+        self.listeners.retain(|(listener_id, _)| *listener_id != id);
+        self.listeners.len() != count
+    }
+
+    /// Clear any internal state, such as reverb tails.
+    pub fn reset(&mut self) {
+        self.instance.pin_mut().reset();
+    }
+
+    /// The current layout of the plugin's buses.
+    pub fn buses_layout(&self) -> BusesLayout {
+        juce::get_buses_layout(&self.instance)
+    }
+
+    /// Whether the plugin supports a layout, which must have an entry for each of its buses.
+    pub fn check_buses_layout_supported(&self, layout: &BusesLayout) -> bool {
+        juce::check_buses_layout_supported(&self.instance, layout)
+    }
+
+    /// Change the layout of the plugin's buses, failing if the plugin doesn't support the layout.
+    ///
+    /// The plugin must be prepared again before it's processed.
+    pub fn set_buses_layout(&mut self, layout: &BusesLayout) -> Result<()> {
+        juce::set_buses_layout(self.instance.pin_mut(), layout)
+    }
+
+    /// The plugin's input buses, starting with the main input.
+    pub fn input_buses(&self) -> Vec<BusInfo> {
+        juce::get_buses(&self.instance, true)
+    }
+
+    /// The plugin's output buses, starting with the main output.
+    pub fn output_buses(&self) -> Vec<BusInfo> {
+        juce::get_buses(&self.instance, false)
+    }
+
+    /// Enable or disable an input bus, such as a sidechain, with its default layout.
+    ///
+    /// This fails if there's no such bus or the plugin doesn't allow the change. The plugin must
+    /// be prepared again before it's processed.
+    pub fn set_input_bus_enabled(&mut self, index: usize, enabled: bool) -> Result<()> {
+        juce::set_bus_enabled(self.instance.pin_mut(), true, index, enabled)
+    }
+
+    /// Enable or disable an output bus with its default layout.
+    ///
+    /// This fails if there's no such bus or the plugin doesn't allow the change. The plugin must
+    /// be prepared again before it's processed.
+    pub fn set_output_bus_enabled(&mut self, index: usize, enabled: bool) -> Result<()> {
+        juce::set_bus_enabled(self.instance.pin_mut(), false, index, enabled)
+    }
+
+    /// Provide the plugin with the position of the host's transport, replacing any previous play
+    /// head.
+    ///
+    /// Without a play head, an [`AudioProcessorPlayer`] provides the plugin with the time from the
+    /// audio device. The play head is removed when the instance is added to an
+    /// [`AudioProcessorGraph`], which passes its own play head to its nodes.
+    pub fn set_play_head(&mut self, play_head: impl AudioPlayHead + 'static) {
+        let play_head: BoxedAudioPlayHead = Box::new(play_head);
+        self.play_head = Some(juce::set_plugin_instance_play_head(
+            self.instance.pin_mut(),
+            Box::new(play_head),
+        ));
+    }
+
+    /// Remove the play head set with [`PluginInstance::set_play_head`].
+    pub fn clear_play_head(&mut self) {
+        self.play_head = None;
+    }
+
+    /// Whether the plugin has an editor that can be opened with
+    /// [`PluginInstance::open_editor_window`].
+    pub fn has_editor(&self) -> bool {
+        self.instance.has_editor()
+    }
+
+    /// Open the plugin's editor in a new window.
+    ///
+    /// This fails if it isn't called on the message thread, the plugin has no editor, or its editor
+    /// is already open.
+    pub fn open_editor_window(
+        &self,
+        title: &str,
+        listener: impl PluginEditorWindowListener + 'static,
+    ) -> Result<PluginEditorWindow<'_>> {
+        let listener: BoxedPluginEditorWindowListener = Box::new(listener);
+        let window =
+            juce::create_plugin_instance_editor_window(&self.instance, title, Box::new(listener))?;
+
+        Ok(PluginEditorWindow {
+            window,
+            _owner: PhantomData,
+            _not_send: PhantomData,
+        })
+    }
+}
+
+/// Receives notifications from a [`PluginEditorWindow`].
+///
+/// Notifications are delivered on the message thread.
+pub trait PluginEditorWindowListener: Send {
+    /// Called when the window's close button is pressed, just before the window is hidden.
+    fn close_button_pressed(&mut self) {}
+
+    /// Called when the window is shown or hidden.
+    fn visibility_changed(&mut self, _visible: bool) {}
+
+    /// Called when the window is moved, with the new position of its top-left corner.
+    fn moved(&mut self, _x: i32, _y: i32) {}
+}
+
+impl PluginEditorWindowListener for () {}
+
+pub(crate) type BoxedPluginEditorWindowListener = Box<dyn PluginEditorWindowListener>;
+
+/// A window showing the editor of a hosted plugin.
+///
+/// The window is shown when it's opened, and pressing its close button hides it. Dropping the
+/// window closes it and deletes the editor.
+///
+/// Windows must be opened on the message thread, which is the thread that initialised [`JUCE`],
+/// and that thread needs to run the message loop for the window to respond to the user. Opening a
+/// window on another thread returns an error, and windows can't be sent to other threads, so
+/// they're also dropped on the message thread.
+pub struct PluginEditorWindow<'a> {
+    window: UniquePtr<juce::PluginEditorWindow>,
+    _owner: PhantomData<&'a ()>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl PluginEditorWindow<'_> {
+    /// Show or hide the window.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.window.pin_mut().set_visible(visible);
+    }
+
+    /// Whether the window is visible.
+    pub fn is_visible(&self) -> bool {
+        self.window.is_visible()
+    }
+
+    /// Move the top-left corner of the window to a position on the screen.
+    pub fn set_position(&mut self, x: i32, y: i32) {
+        self.window.pin_mut().set_top_left_position(x, y);
+    }
+
+    /// The position of the top-left corner of the window on the screen.
+    pub fn position(&self) -> (i32, i32) {
+        (self.window.get_x(), self.window.get_y())
+    }
+
+    /// The width and height of the window.
+    pub fn size(&self) -> (i32, i32) {
+        (self.window.get_width(), self.window.get_height())
+    }
+
+    /// Bring the window in front of other windows and give it keyboard focus.
+    pub fn to_front(&mut self) {
+        self.window.pin_mut().to_front(true);
+    }
+}
+
+/// A list of known plugins, and of files that shouldn't be scanned because they failed or crashed.
+///
+/// The list can be saved as XML so that hosts don't need to rescan on every launch.
+pub struct KnownPluginList<'juce> {
+    list: UniquePtr<juce::KnownPluginList>,
+    _juce: PhantomData<&'juce ()>,
+}
+
+unsafe impl Send for KnownPluginList<'_> {}
+
+impl<'juce> KnownPluginList<'juce> {
+    /// Create an empty list.
+    pub fn new(_juce: &'juce JUCE) -> Self {
+        Self {
+            list: juce::create_known_plugin_list(),
+            _juce: PhantomData,
+        }
+    }
+
+    /// The number of plugins in the list.
+    pub fn num_types(&self) -> usize {
+        self.list.get_num_types() as usize
+    }
+
+    /// The plugins in the list.
+    pub fn types(&self) -> Vec<PluginDescription> {
+        juce::get_known_plugin_types(&self.list)
+    }
+
+    /// Add a plugin to the list.
+    ///
+    /// Returns `false` if the plugin was already in the list, in which case its details are updated.
+    pub fn add_type(&mut self, description: &PluginDescription) -> bool {
+        juce::add_known_plugin_type(self.list.pin_mut(), description)
+    }
+
+    /// Remove a plugin from the list.
+    pub fn remove_type(&mut self, description: &PluginDescription) {
+        juce::remove_known_plugin_type(self.list.pin_mut(), description);
+    }
+
+    /// Remove all plugins from the list. The blacklist is left as is.
+    pub fn clear(&mut self) {
+        self.list.pin_mut().clear();
+    }
+
+    /// Find a plugin by the identifier string returned by JUCE's `PluginDescription::createIdentifierString`,
+    /// e.g. `VST3-Name-1a2b3c4d-5e6f7a8b`.
+    pub fn type_for_identifier_string(&self, identifier: &str) -> Option<PluginDescription> {
+        let mut description = PluginDescription::default();
+        juce::get_known_plugin_type_for_identifier_string(&self.list, identifier, &mut description)
+            .then_some(description)
+    }
+
+    /// Prevent a file or identifier from being scanned.
+    pub fn add_to_blacklist(&mut self, file_or_identifier: &str) {
+        juce::add_to_blacklist(self.list.pin_mut(), file_or_identifier);
+    }
+
+    /// Allow a blacklisted file or identifier to be scanned again.
+    pub fn remove_from_blacklist(&mut self, file_or_identifier: &str) {
+        juce::remove_from_blacklist(self.list.pin_mut(), file_or_identifier);
+    }
+
+    /// Allow all blacklisted files and identifiers to be scanned again.
+    pub fn clear_blacklisted_files(&mut self) {
+        self.list.pin_mut().clear_blacklisted_files();
+    }
+
+    /// The files and identifiers that won't be scanned.
+    pub fn blacklisted_files(&self) -> Vec<String> {
+        juce::get_blacklisted_files(&self.list)
+    }
+
+    /// Serialize the list, including the blacklist, as XML.
+    pub fn to_xml(&self) -> String {
+        juce::known_plugin_list_to_xml(&self.list)
+    }
+
+    /// Replace the contents of the list with XML created by [`KnownPluginList::to_xml`].
+    ///
+    /// Returns an error, leaving the list unchanged, if the XML isn't a plugin list.
+    pub fn restore_from_xml(&mut self, xml: &str) -> Result<()> {
+        juce::known_plugin_list_from_xml(self.list.pin_mut(), xml)
+    }
+
+    /// Scan plugins in a child process rather than in this one, so that a plugin that crashes or
+    /// hangs while being scanned is blacklisted instead of taking down the host.
+    ///
+    /// Pass [`None`] to go back to scanning in this process. Returns an error if the scanner's
+    /// program path isn't valid UTF-8.
+    pub fn set_out_of_process_scanner(
+        &mut self,
+        scanner: Option<&OutOfProcessScanner>,
+    ) -> Result<()> {
+        match scanner {
+            Some(scanner) => {
+                let mut command = vec![path_to_str(&scanner.program)?.to_owned()];
+                command.extend(scanner.args.iter().cloned());
+                command.push(OutOfProcessScanner::SCAN_ARGUMENT.to_string());
+
+                juce::set_child_process_plugin_scanner(
+                    self.list.pin_mut(),
+                    &command,
+                    scanner.timeout.as_millis().min(i32::MAX as u128) as i32,
+                );
+            }
+            None => juce::clear_custom_plugin_scanner(self.list.pin_mut()),
+        }
+
+        Ok(())
+    }
+}
+
+/// The result of scanning one file with [`PluginDirectoryScanner::scan_next_file`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginScanResult {
+    /// A file was scanned, with the name of the plugin it was expected to contain. Files that
+    /// couldn't be loaded are listed by [`PluginDirectoryScanner::failed_files`].
+    Scanned(String),
+
+    /// A file was skipped because it's already in the list.
+    Skipped,
+
+    /// There are no files left to scan.
+    Finished,
+}
+
+/// Scans directories for plugins of one format, adding the plugins it finds to a [`KnownPluginList`].
+///
+/// Before each file is scanned its path is written to the optional dead man's pedal file, and it's
+/// removed again once the scan completes. If a plugin crashes the process while being scanned, the
+/// next scanner created with the same pedal file adds it to the list's blacklist.
+pub struct PluginDirectoryScanner<'a> {
+    scanner: UniquePtr<juce::PluginDirectoryScanner>,
+    finished: bool,
+    _list: PhantomData<&'a mut ()>,
+}
+
+unsafe impl Send for PluginDirectoryScanner<'_> {}
+
+impl<'a> PluginDirectoryScanner<'a> {
+    /// Create a scanner for the registered format with the given name, e.g. `VST3`.
+    ///
+    /// Returns an error if no format with that name is registered, or if a path isn't valid UTF-8.
+    pub fn new(
+        list: &'a mut KnownPluginList<'_>,
+        manager: &'a AudioPluginFormatManager<'_>,
+        format_name: &str,
+        paths: &[impl AsRef<Path>],
+        recursive: bool,
+        dead_mans_pedal_file: Option<&Path>,
+    ) -> Result<Self> {
+        let paths = paths
+            .iter()
+            .map(|path| path_to_str(path.as_ref()).map(str::to_owned))
+            .collect::<Result<Vec<_>>>()?;
+        let dead_mans_pedal_file = dead_mans_pedal_file
+            .map(path_to_str)
+            .transpose()?
+            .unwrap_or_default();
+
+        juce::create_plugin_directory_scanner(
+            list.list.pin_mut(),
+            &manager.manager,
+            format_name,
+            &paths,
+            recursive,
+            dead_mans_pedal_file,
+        )
+        .map(|scanner| Self {
+            scanner,
+            finished: false,
+            _list: PhantomData,
+        })
+    }
+
+    /// The proportion of files scanned so far, from 0 to 1.
+    pub fn progress(&self) -> f32 {
+        self.scanner.get_progress()
+    }
+
+    /// The file or identifier that will be scanned next.
+    pub fn next_plugin_file(&self) -> String {
+        juce::get_next_plugin_file_that_will_be_scanned(&self.scanner)
+    }
+
+    /// Scan the next file.
+    ///
+    /// When `dont_rescan_if_already_in_list` is set, files already in the list that haven't been
+    /// modified since they were scanned are skipped.
+    pub fn scan_next_file(&mut self, dont_rescan_if_already_in_list: bool) -> PluginScanResult {
+        if self.finished || self.next_plugin_file().is_empty() {
+            self.finished = true;
+            return PluginScanResult::Finished;
+        }
+
+        let mut name = String::new();
+        self.finished = !juce::scan_next_plugin_file(
+            self.scanner.pin_mut(),
+            dont_rescan_if_already_in_list,
+            &mut name,
+        );
+
+        if name.is_empty() {
+            PluginScanResult::Skipped
+        } else {
+            PluginScanResult::Scanned(name)
+        }
+    }
+
+    /// Scan all remaining files, calling `on_progress` with the name of each plugin and the overall
+    /// progress once it has been scanned.
+    pub fn scan(
+        &mut self,
+        dont_rescan_if_already_in_list: bool,
+        mut on_progress: impl FnMut(&str, f32),
+    ) {
+        loop {
+            match self.scan_next_file(dont_rescan_if_already_in_list) {
+                PluginScanResult::Scanned(name) => on_progress(&name, self.progress()),
+                PluginScanResult::Skipped => {}
+                PluginScanResult::Finished => break,
+            }
+        }
+    }
+
+    /// The files that couldn't be loaded during the scan.
+    pub fn failed_files(&self) -> Vec<String> {
+        juce::get_failed_plugin_files(&self.scanner)
+    }
+}
+
+/// Describes how to launch a child process that scans plugins on behalf of a host.
+///
+/// The child is usually the host's own executable, which should call
+/// [`OutOfProcessScanner::run_child_if_requested`] at the start of `main`:
+///
+/// ```no_run
+/// # use cxx_juce::{juce_audio_processors::OutOfProcessScanner, JUCE};
+/// let juce = JUCE::initialise();
+///
+/// if let Some(result) = OutOfProcessScanner::run_child_if_requested(&juce) {
+///     std::process::exit(if result.is_ok() { 0 } else { 1 });
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OutOfProcessScanner {
+    program: PathBuf,
+    args: Vec<String>,
+    timeout: Duration,
+}
+
+impl OutOfProcessScanner {
+    /// The argument that tells a child process to scan a plugin. It's followed by the format name,
+    /// the file or identifier to scan, and the file to write the results to.
+    pub const SCAN_ARGUMENT: &'static str = "--cxx-juce-scan-plugin";
+
+    /// Scan plugins by running the given program.
+    pub fn new(program: impl Into<PathBuf>) -> Self {
+        Self {
+            program: program.into(),
+            args: vec![],
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Scan plugins by running the current executable.
+    pub fn current_exe() -> std::io::Result<Self> {
+        std::env::current_exe().map(Self::new)
+    }
+
+    /// Pass extra arguments to the program, before the scan arguments.
+    pub fn with_args(mut self, args: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.args = args.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set how long a scan may take before the child is killed and the plugin blacklisted.
+    /// Defaults to 30 seconds.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The program that's run to scan plugins.
+    pub fn program(&self) -> &Path {
+        &self.program
+    }
+
+    /// How long a scan may take before the child is killed.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// If this process was launched to scan a plugin, scan it with the default formats and write the
+    /// results for the host to read.
+    ///
+    /// Returns [`None`] if this process wasn't launched as a scanner, otherwise the result of the
+    /// scan, after which the process should exit with a non-zero status on failure.
+    pub fn run_child_if_requested(juce: &JUCE) -> Option<Result<()>> {
+        let args: Vec<String> = std::env::args().collect();
+        let position = args.iter().position(|arg| arg == Self::SCAN_ARGUMENT)?;
+
+        let manager = AudioPluginFormatManager::with_default_formats(juce);
+        Some(juce::run_child_plugin_scan(
+            &manager.manager,
+            &args[position + 1..],
+        ))
+    }
+}
+
+/// Describes how to launch child processes that host plugins on behalf of a host, so that a plugin
+/// that crashes or hangs takes down its child rather than the host.
+///
+/// Audio is passed to and from the child through shared memory. The plugin's editor, parameters,
+/// state, and MIDI aren't available, which makes this best suited to processing audio on a server.
+///
+/// Like [`OutOfProcessScanner`], the child is usually the host's own executable, which should call
+/// [`OutOfProcessHost::run_child_if_requested`] at the start of `main`:
+///
+/// ```no_run
+/// # use cxx_juce::{juce_audio_processors::OutOfProcessHost, JUCE};
+/// let juce = JUCE::initialise();
+///
+/// if let Some(result) = OutOfProcessHost::run_child_if_requested(&juce) {
+///     std::process::exit(if result.is_ok() { 0 } else { 1 });
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OutOfProcessHost {
+    program: PathBuf,
+    args: Vec<String>,
+    timeout: Duration,
+}
+
+impl OutOfProcessHost {
+    /// The argument that tells a child process to host a plugin. It's followed by the shared memory
+    /// file, the file holding the plugin's description, and the name of a lock held by the host.
+    pub const HOST_ARGUMENT: &'static str = "--cxx-juce-host-plugin";
+
+    /// Host plugins by running the given program.
+    pub fn new(program: impl Into<PathBuf>) -> Self {
+        Self {
+            program: program.into(),
+            args: vec![],
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Host plugins by running the current executable.
+    pub fn current_exe() -> std::io::Result<Self> {
+        std::env::current_exe().map(Self::new)
+    }
+
+    /// Pass extra arguments to the program, before the host arguments.
+    pub fn with_args(mut self, args: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.args = args.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set how long the child may take to respond to a request, such as loading the plugin or
+    /// processing a block, before it's killed. Defaults to 30 seconds.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The program that's run to host plugins.
+    pub fn program(&self) -> &Path {
+        &self.program
+    }
+
+    /// How long the child may take to respond before it's killed.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Launch a child process and create an instance of the plugin matching the description in it.
+    ///
+    /// The block size is the largest that the instance can be prepared with. Returns an error if
+    /// the child couldn't be started or couldn't load the plugin, or if the program path isn't
+    /// valid UTF-8.
+    pub fn create_plugin_instance<'juce>(
+        &self,
+        _juce: &'juce JUCE,
+        description: &PluginDescription,
+        sample_rate: f64,
+        block_size: usize,
+    ) -> Result<OutOfProcessPluginInstance<'juce>> {
+        let mut command = vec![path_to_str(&self.program)?.to_owned()];
+        command.extend(self.args.iter().cloned());
+        command.push(Self::HOST_ARGUMENT.to_string());
+
+        juce::create_out_of_process_plugin(
+            &command,
+            description,
+            sample_rate,
+            block_size,
+            self.timeout.as_millis().min(i32::MAX as u128) as i32,
+        )
+        .map(|plugin| OutOfProcessPluginInstance {
+            plugin,
+            _juce: PhantomData,
+        })
+    }
+
+    /// If this process was launched to host a plugin, load it with the default formats and process
+    /// audio for the host until it's dropped or the host exits.
+    ///
+    /// Returns [`None`] if this process wasn't launched to host a plugin, otherwise the result of
+    /// hosting it, after which the process should exit with a non-zero status on failure.
+    pub fn run_child_if_requested(juce: &JUCE) -> Option<Result<()>> {
+        let args: Vec<String> = std::env::args().collect();
+        let position = args.iter().position(|arg| arg == Self::HOST_ARGUMENT)?;
+
+        let mut manager = AudioPluginFormatManager::with_default_formats(juce);
+        Some(juce::run_child_plugin_host(
+            manager.manager.pin_mut(),
+            &args[position + 1..],
+        ))
+    }
+}
+
+/// A plugin hosted in a child process, created with [`OutOfProcessHost::create_plugin_instance`].
+///
+/// If the child crashes or stops responding it's killed, and every call that needs it returns an
+/// error from then on. Processing then outputs silence, so audio keeps flowing.
+pub struct OutOfProcessPluginInstance<'juce> {
+    plugin: UniquePtr<juce::OutOfProcessPlugin>,
+    _juce: PhantomData<&'juce ()>,
+}
+
+unsafe impl Send for OutOfProcessPluginInstance<'_> {}
+
+impl OutOfProcessPluginInstance<'_> {
+    /// The name of the plugin.
+    pub fn name(&self) -> String {
+        self.plugin.get_name()
+    }
+
+    /// The total number of input channels across the plugin's enabled input buses.
+    pub fn num_input_channels(&self) -> usize {
+        self.plugin.get_num_input_channels()
+    }
+
+    /// The total number of output channels across the plugin's enabled output buses.
+    pub fn num_output_channels(&self) -> usize {
+        self.plugin.get_num_output_channels()
+    }
+
+    /// The largest block size the plugin can be prepared with, which was set when it was created.
+    pub fn maximum_block_size(&self) -> usize {
+        self.plugin.get_maximum_block_size()
+    }
+
+    /// Whether the child process has crashed or been killed for not responding.
+    pub fn has_crashed(&self) -> bool {
+        self.plugin.has_crashed()
+    }
+
+    /// Prepare the plugin for playback.
+    ///
+    /// Returns an error if the block size is larger than the
+    /// [`maximum_block_size`](Self::maximum_block_size) or the child has crashed.
+    pub fn prepare(&mut self, sample_rate: f64, block_size: usize) -> Result<()> {
+        self.plugin.pin_mut().prepare(sample_rate, block_size)
+    }
+
+    /// Process a block of audio in place, waiting for the child to process it.
+    ///
+    /// The buffer must have at least as many channels as the plugin's inputs or outputs, whichever
+    /// is greater, and no more samples than the block size the plugin was prepared with. If the
+    /// child has crashed the buffer is cleared and an error is returned.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) -> Result<()> {
+        self.plugin.pin_mut().process(buffer.buffer.as_mut())
+    }
+
+    /// Release any resources allocated by [`OutOfProcessPluginInstance::prepare`].
+    pub fn release_resources(&mut self) -> Result<()> {
+        self.plugin.pin_mut().release_resources()
+    }
+}
+
+/// An audio processor implemented in Rust, which can be added to an [`AudioProcessorGraph`] with
+/// [`AudioProcessorGraph::add_processor`] to run alongside hosted plugins.
+///
+/// The processor's name, channels, MIDI support, tail length, and parameters are queried once,
+/// when it's added to the graph.
+pub trait AudioProcessor: Send {
+    /// The name of the processor.
+    fn name(&self) -> String;
+
+    /// The number of channels of the processor's input bus, which may be zero.
+    fn num_input_channels(&self) -> usize;
+
+    /// The number of channels of the processor's output bus, which may be zero.
+    fn num_output_channels(&self) -> usize;
+
+    /// Whether the processor wants MIDI input.
+    fn accepts_midi(&self) -> bool {
+        false
+    }
+
+    /// Whether the processor produces MIDI output.
+    fn produces_midi(&self) -> bool {
+        false
+    }
+
+    /// How long the processor keeps producing output after its input stops.
+    ///
+    /// Return [`Duration::MAX`] for an infinite tail.
+    fn tail_length(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    /// The processor's parameters, which the host can read and change.
+    fn parameters(&self) -> Vec<AudioParameter> {
+        vec![]
+    }
+
+    /// Called before playback starts, with the maximum number of samples in each block.
+    fn prepare_to_play(&mut self, sample_rate: f64, maximum_block_size: usize);
+
+    /// Called after playback stops, to free any resources allocated by
+    /// [`AudioProcessor::prepare_to_play`].
+    fn release_resources(&mut self) {}
+
+    /// Process a block of audio and MIDI in place.
+    ///
+    /// The buffer has as many channels as the processor's inputs or outputs, whichever is greater.
+    /// Any MIDI left in the MIDI buffer is the processor's MIDI output.
+    fn process_block(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>, midi: &mut MidiBuffer);
+
+    /// Clear any internal state, such as reverb tails.
+    fn reset(&mut self) {}
+
+    /// The processor's state, to be restored with [`AudioProcessor::set_state`].
+    ///
+    /// The values of the processor's parameters are part of its state, so a processor with a
+    /// [`ParameterStore`] would usually include [`ParameterStore::to_xml`].
+    fn state(&self) -> Vec<u8> {
+        vec![]
+    }
+
+    /// Restore state returned by [`AudioProcessor::state`].
+    fn set_state(&mut self, _state: &[u8]) {}
+}
+
+pub(crate) struct BoxedAudioProcessor {
+    processor: Box<dyn AudioProcessor>,
+
+    // Swapped with the MIDI buffer being processed, so that it can be lent to the processor.
+    midi: MidiBuffer,
+}
+
+/// A range of plain parameter values, and how it maps to the normalised range 0 to 1 that hosts
+/// use.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NormalisableRange {
+    /// The lowest value.
+    pub start: f32,
+
+    /// The highest value.
+    pub end: f32,
+
+    /// The step between values, or zero for a continuous range.
+    pub interval: f32,
+
+    /// How the range is skewed, where values below one give more of the normalised range to
+    /// lower values and one is linear.
+    pub skew: f32,
+}
+
+impl NormalisableRange {
+    /// A linear, continuous range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` isn't greater than `start`.
+    pub fn new(start: f32, end: f32) -> Self {
+        assert!(
+            end > start,
+            "the end of a range must be greater than its start"
+        );
+
+        Self {
+            start,
+            end,
+            interval: 0.0,
+            skew: 1.0,
+        }
+    }
+
+    /// Snap values to multiples of an interval from the start of the range.
+    pub fn with_interval(mut self, interval: f32) -> Self {
+        self.interval = interval.max(0.0);
+        self
+    }
+
+    /// Skew the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `skew` isn't finite and positive.
+    pub fn with_skew(mut self, skew: f32) -> Self {
+        assert!(
+            skew.is_finite() && skew > 0.0,
+            "the skew of a range must be finite and positive"
+        );
+
+        self.skew = skew;
+        self
+    }
+
+    /// Skew the range so that a value is in the middle of the normalised range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `centre` isn't between the start and end of the range.
+    pub fn with_centre(self, centre: f32) -> Self {
+        assert!(
+            centre > self.start && centre < self.end,
+            "the centre of a range must be between its start and end"
+        );
+
+        let skew = 0.5f32.ln() / ((centre - self.start) / (self.end - self.start)).ln();
+        self.with_skew(skew)
+    }
+
+    /// Convert a plain value to a normalised value. NaN is treated as the start of the range.
+    pub fn to_normalised(&self, value: f32) -> f32 {
+        let proportion =
+            ((self.snap(value) - self.start) / (self.end - self.start)).clamp(0.0, 1.0);
+
+        if self.skew == 1.0 {
+            proportion
+        } else {
+            proportion.powf(self.skew)
+        }
+    }
+
+    /// Convert a normalised value to a plain value. NaN is treated as zero.
+    pub fn from_normalised(&self, normalised: f32) -> f32 {
+        let mut proportion = if normalised.is_nan() {
+            0.0
+        } else {
+            normalised.clamp(0.0, 1.0)
+        };
+
+        if self.skew != 1.0 && proportion > 0.0 {
+            proportion = (proportion.ln() / self.skew).exp();
+        }
+
+        self.snap(self.start + (self.end - self.start) * proportion)
+    }
+
+    /// Snap a value to the range and its interval. NaN is snapped to the start of the range.
+    pub fn snap(&self, value: f32) -> f32 {
+        if value.is_nan() {
+            return self.start;
+        }
+
+        let value = if self.interval > 0.0 {
+            self.start + self.interval * ((value - self.start) / self.interval).round()
+        } else {
+            value
+        };
+
+        value.clamp(self.start, self.end)
+    }
+
+    fn num_steps(&self) -> Option<i32> {
+        (self.interval > 0.0).then(|| ((self.end - self.start) / self.interval) as i32 + 1)
+    }
+}
+
+/// Receives notifications of changes to an [`AudioParameter`].
+///
+/// Notifications are delivered on the thread that changed the value, which may be the audio
+/// thread, so implementations should return quickly, and shouldn't change the parameter that
+/// notified them or its listeners.
+pub trait ParameterListener: Send + Sync {
+    /// Called when the parameter's plain value changes.
+    fn parameter_changed(&self, id: &str, value: f32);
+
+    /// Called when the user starts changing the parameter, through the host or
+    /// [`AudioParameter::begin_change_gesture`].
+    fn gesture_began(&self, _id: &str) {}
+
+    /// Called when the user stops changing the parameter.
+    fn gesture_ended(&self, _id: &str) {}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ParameterKind {
+    Float,
+    Choice(Vec<String>),
+    Bool,
+}
+
+struct ParameterState {
+    value: AtomicU32,
+    listeners: ParameterListeners,
+    next_listener_id: AtomicU64,
+    host: Mutex<Option<HostParameter>>,
+}
+
+type ParameterListenerList = Vec<(ListenerId, Arc<dyn ParameterListener>)>;
+
+// The listeners of a parameter, which are notified without taking a lock so that the parameter can
+// be changed on the audio thread.
+//
+// Adding or removing a listener replaces the list, then waits until no notification can still be
+// using the old list before freeing it.
+struct ParameterListeners {
+    list: AtomicPtr<ParameterListenerList>,
+    readers: AtomicUsize,
+    writer: Mutex<()>,
+}
+
+impl ParameterListeners {
+    fn new() -> Self {
+        Self {
+            list: AtomicPtr::new(Box::into_raw(Box::default())),
+            readers: AtomicUsize::new(0),
+            writer: Mutex::new(()),
+        }
+    }
+
+    fn for_each(&self, mut f: impl FnMut(&dyn ParameterListener)) {
+        struct Reader<'a>(&'a AtomicUsize);
+
+        impl Drop for Reader<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+
+        // Counted before loading the list, so that a writer that replaced it waits for this, and
+        // uncounted even if a listener panics.
+        self.readers.fetch_add(1, Ordering::SeqCst);
+        let _reader = Reader(&self.readers);
+        let list = unsafe { &*self.list.load(Ordering::SeqCst) };
+
+        for (_, listener) in list {
+            f(listener.as_ref());
+        }
+    }
+
+    fn update<R>(&self, f: impl FnOnce(&mut ParameterListenerList) -> R) -> R {
+        let _writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let mut list = unsafe { (*self.list.load(Ordering::SeqCst)).clone() };
+        let result = f(&mut list);
+        let previous = self
+            .list
+            .swap(Box::into_raw(Box::new(list)), Ordering::SeqCst);
+
+        while self.readers.load(Ordering::SeqCst) != 0 {
+            std::thread::yield_now();
+        }
+
+        drop(unsafe { Box::from_raw(previous) });
+        result
+    }
+}
+
+impl Drop for ParameterListeners {
+    fn drop(&mut self) {
+        drop(unsafe { Box::from_raw(*self.list.get_mut()) });
+    }
+}
+
+// The JUCE parameter that exposes an `AudioParameter` to a host, which sets and removes itself when
+// it's created and destroyed.
+struct HostParameter(*mut juce::RustAudioProcessorParameter);
+
+unsafe impl Send for HostParameter {}
+
+/// A parameter of an [`AudioProcessor`] implemented in Rust.
+///
+/// Hosts see the parameter's value normalised to the range 0 to 1, and the parameter maps it to
+/// its plain value with its [`NormalisableRange`]. Clones share the value and listeners, so a
+/// processor can keep a clone to read on the audio thread while the host changes it.
+#[derive(Clone)]
+pub struct AudioParameter {
+    id: String,
+    name: String,
+    label: String,
+    kind: ParameterKind,
+    range: NormalisableRange,
+    default_value: f32,
+    is_automatable: bool,
+    state: Arc<ParameterState>,
+}
+
+impl AudioParameter {
+    /// Create a parameter with a normalised value, an ID that's unique within its processor, a
+    /// name to show to users, and a default value.
+    pub fn new(id: &str, name: &str, default_value: f32) -> Self {
+        Self::float(id, name, NormalisableRange::new(0.0, 1.0), default_value)
+    }
+
+    /// Create a parameter with a plain value in a range.
+    pub fn float(id: &str, name: &str, range: NormalisableRange, default_value: f32) -> Self {
+        Self::with_kind(id, name, ParameterKind::Float, range, default_value)
+    }
+
+    /// Create a parameter that chooses one of a list of values, with the index of the chosen value
+    /// as its plain value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two choices.
+    pub fn choice(id: &str, name: &str, choices: &[&str], default_index: usize) -> Self {
+        let range =
+            NormalisableRange::new(0.0, (choices.len() as f32 - 1.0).max(0.0)).with_interval(1.0);
+        let choices = choices.iter().map(ToString::to_string).collect();
+
+        Self::with_kind(
+            id,
+            name,
+            ParameterKind::Choice(choices),
+            range,
+            default_index as f32,
+        )
+    }
+
+    /// Create a parameter that's on or off, with a plain value of one or zero.
+    pub fn boolean(id: &str, name: &str, default_value: bool) -> Self {
+        let range = NormalisableRange::new(0.0, 1.0).with_interval(1.0);
+        let default_value = if default_value { 1.0 } else { 0.0 };
+        Self::with_kind(id, name, ParameterKind::Bool, range, default_value)
+    }
+
+    fn with_kind(
+        id: &str,
+        name: &str,
+        kind: ParameterKind,
+        range: NormalisableRange,
+        default_value: f32,
+    ) -> Self {
+        let default_value = range.snap(default_value);
+
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            label: String::new(),
+            kind,
+            range,
+            default_value,
+            is_automatable: true,
+            state: Arc::new(ParameterState {
+                value: AtomicU32::new(range.to_normalised(default_value).to_bits()),
+                listeners: ParameterListeners::new(),
+                next_listener_id: AtomicU64::new(0),
+                host: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Set the label of the parameter's units, such as "dB".
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// Set whether hosts can automate the parameter, which they can by default.
+    pub fn with_automatable(mut self, is_automatable: bool) -> Self {
+        self.is_automatable = is_automatable;
+        self
+    }
+
+    /// The parameter's ID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The parameter's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The label of the parameter's units.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The parameter's range.
+    pub fn range(&self) -> NormalisableRange {
+        self.range
+    }
+
+    /// The values a choice parameter chooses between.
+    pub fn choices(&self) -> &[String] {
+        match &self.kind {
+            ParameterKind::Choice(choices) => choices,
+            _ => &[],
+        }
+    }
+
+    /// The parameter's default plain value.
+    pub fn default_value(&self) -> f32 {
+        self.default_value
+    }
+
+    /// The parameter's current normalised value.
+    ///
+    /// This is a single atomic read, so it can be called on the audio thread.
+    pub fn normalised_value(&self) -> f32 {
+        f32::from_bits(self.state.value.load(Ordering::Relaxed))
+    }
+
+    /// Set the parameter's normalised value without notifying the host, and notify its listeners
+    /// if the value changed.
+    pub fn set_normalised_value(&self, value: f32) {
+        let value = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+        let previous = self.state.value.swap(value.to_bits(), Ordering::Relaxed);
+
+        if previous != value.to_bits() {
+            let plain_value = self.range.from_normalised(value);
+
+            self.state
+                .listeners
+                .for_each(|listener| listener.parameter_changed(&self.id, plain_value));
+        }
+    }
+
+    /// The parameter's current plain value.
+    ///
+    /// This is a single atomic read, so it can be called on the audio thread.
+    pub fn value(&self) -> f32 {
+        self.range.from_normalised(self.normalised_value())
+    }
+
+    /// Set the parameter's plain value without notifying the host, and notify its listeners if the
+    /// value changed.
+    pub fn set_value(&self, value: f32) {
+        self.set_normalised_value(self.range.to_normalised(value));
+    }
+
+    /// Set the parameter's plain value as if the user changed it, notifying the host so that it
+    /// can record automation, as well as the parameter's listeners.
+    ///
+    /// Changes the user makes by dragging a control should be surrounded by calls to
+    /// [`AudioParameter::begin_change_gesture`] and [`AudioParameter::end_change_gesture`].
+    pub fn set_value_notifying_host(&self, value: f32) {
+        let normalised = self.range.to_normalised(value);
+
+        if !self.with_host(|host| host.set_value_notifying_host(normalised)) {
+            self.set_normalised_value(normalised);
+        }
+    }
+
+    /// Tell the host and the parameter's listeners that the user has started changing the
+    /// parameter.
+    pub fn begin_change_gesture(&self) {
+        // The host passes the gesture back through `gesture_changed`.
+        if !self.with_host(|host| host.begin_change_gesture()) {
+            self.gesture_changed(true);
+        }
+    }
+
+    /// Tell the host and the parameter's listeners that the user has stopped changing the
+    /// parameter.
+    pub fn end_change_gesture(&self) {
+        if !self.with_host(|host| host.end_change_gesture()) {
+            self.gesture_changed(false);
+        }
+    }
+
+    fn gesture_changed(&self, gesture_is_starting: bool) {
+        self.state.listeners.for_each(|listener| {
+            if gesture_is_starting {
+                listener.gesture_began(&self.id);
+            } else {
+                listener.gesture_ended(&self.id);
+            }
+        });
+    }
+
+    fn with_host(&self, f: impl FnOnce(Pin<&mut juce::RustAudioProcessorParameter>)) -> bool {
+        let host = self
+            .state
+            .host
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        // The host removes itself, under the same lock, before it's destroyed.
+        host.as_ref()
+            .map(|host| f(unsafe { Pin::new_unchecked(&mut *host.0) }))
+            .is_some()
+    }
+
+    /// The text to show for a normalised value.
+    pub fn text_for_value(&self, normalised: f32) -> String {
+        let value = self.range.from_normalised(normalised);
+
+        match &self.kind {
+            ParameterKind::Float => {
+                let decimals = if self.range.interval > 0.0 {
+                    (-self.range.interval.log10().floor()).max(0.0) as usize
+                } else {
+                    2
+                };
+
+                format!("{value:.decimals$}")
+            }
+            ParameterKind::Choice(choices) => choices[value as usize].clone(),
+            ParameterKind::Bool => if value >= 0.5 { "On" } else { "Off" }.to_string(),
+        }
+    }
+
+    /// The normalised value for some text, or [`None`] if the text doesn't describe a value.
+    pub fn value_for_text(&self, text: &str) -> Option<f32> {
+        let text = text.trim();
+
+        let value = match &self.kind {
+            ParameterKind::Float => text.parse().ok()?,
+            ParameterKind::Choice(choices) => {
+                choices.iter().position(|choice| choice == text)? as f32
+            }
+            ParameterKind::Bool => match text.to_ascii_lowercase().as_str() {
+                "on" | "yes" | "true" | "1" => 1.0,
+                "off" | "no" | "false" | "0" => 0.0,
+                _ => return None,
+            },
+        };
+
+        Some(self.range.to_normalised(value))
+    }
+
+    /// Listen for changes to the parameter's value.
+    pub fn add_listener(&self, listener: impl ParameterListener + 'static) -> ListenerId {
+        let id = ListenerId(self.state.next_listener_id.fetch_add(1, Ordering::Relaxed));
+        self.state
+            .listeners
+            .update(|listeners| listeners.push((id, Arc::new(listener))));
+        id
+    }
+
+    /// Remove a listener. Returns `false` if there's no such listener.
+    pub fn remove_listener(&self, id: ListenerId) -> bool {
+        self.state.listeners.update(|listeners| {
+            let count = listeners.len();
+            listeners.retain(|(listener_id, _)| *listener_id != id);
+            listeners.len() != count
+        })
+    }
+
+    fn info(&self) -> ParameterInfo {
+        ParameterInfo {
+            index: 0,
+            id: self.id.clone(),
+            name: self.name.clone(),
+            label: self.label.clone(),
+            default_value: self.range.to_normalised(self.default_value),
+            num_steps: self.range.num_steps().unwrap_or(i32::MAX),
+            is_discrete: self.kind != ParameterKind::Float,
+            is_boolean: self.kind == ParameterKind::Bool,
+            is_automatable: self.is_automatable,
+            is_meta_parameter: false,
+            value_strings: self.choices().to_vec(),
+        }
+    }
+}
+
+impl fmt::Debug for AudioParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AudioParameter")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("value", &self.value())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A set of parameters with unique IDs, whose values can be saved and restored together, like
+/// JUCE's `AudioProcessorValueTreeState`.
+///
+/// Clones share their parameters, so a store can be shared between a processor, which returns its
+/// [`ParameterStore::parameters`] from [`AudioProcessor::parameters`], and the rest of a program.
+#[derive(Debug, Clone)]
+pub struct ParameterStore {
+    state_type: String,
+    parameters: Vec<AudioParameter>,
+}
+
+impl ParameterStore {
+    /// Create an empty store, whose state is saved in an XML element with the given tag.
+    pub fn new(state_type: &str) -> Self {
+        Self {
+            state_type: state_type.to_string(),
+            parameters: vec![],
+        }
+    }
+
+    /// Add a parameter to the store. Returns `false`, without adding the parameter, if the store
+    /// already has a parameter with the same ID.
+    pub fn add(&mut self, parameter: AudioParameter) -> bool {
+        if self.parameter(parameter.id()).is_some() {
+            return false;
+        }
+
+        self.parameters.push(parameter);
+        true
+    }
+
+    /// Add a parameter to the store, as with [`ParameterStore::add`], and return the store.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the store already has a parameter with the same ID.
+    pub fn with(mut self, parameter: AudioParameter) -> Self {
+        let id = parameter.id().to_string();
+        assert!(self.add(parameter), "duplicate parameter ID: {id}");
+        self
+    }
+
+    /// The parameters in the store, in the order they were added.
+    pub fn parameters(&self) -> &[AudioParameter] {
+        &self.parameters
+    }
+
+    /// The parameter with the given ID.
+    ///
+    /// Processors should look up the parameters they read on the audio thread in advance, and
+    /// keep clones of them.
+    pub fn parameter(&self, id: &str) -> Option<&AudioParameter> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.id() == id)
+    }
+
+    /// The plain value of the parameter with the given ID.
+    pub fn value(&self, id: &str) -> Option<f32> {
+        self.parameter(id).map(AudioParameter::value)
+    }
+
+    /// Set the plain value of the parameter with the given ID. Returns `false` if there's no such
+    /// parameter.
+    pub fn set_value(&self, id: &str, value: f32) -> bool {
+        self.parameter(id)
+            .map(|parameter| parameter.set_value(value))
+            .is_some()
+    }
+
+    /// Listen for changes to the parameter with the given ID, or return [`None`] if there's no such
+    /// parameter.
+    pub fn add_listener(
+        &self,
+        id: &str,
+        listener: impl ParameterListener + 'static,
+    ) -> Option<ListenerId> {
+        self.parameter(id)
+            .map(|parameter| parameter.add_listener(listener))
+    }
+
+    /// Tell the host and listeners that the user has started changing the parameter with the given
+    /// ID. Returns `false` if there's no such parameter.
+    pub fn begin_change_gesture(&self, id: &str) -> bool {
+        self.parameter(id)
+            .map(AudioParameter::begin_change_gesture)
+            .is_some()
+    }
+
+    /// Tell the host and listeners that the user has stopped changing the parameter with the given
+    /// ID. Returns `false` if there's no such parameter.
+    pub fn end_change_gesture(&self, id: &str) -> bool {
+        self.parameter(id)
+            .map(AudioParameter::end_change_gesture)
+            .is_some()
+    }
+
+    /// Remove a listener from the parameter with the given ID. Returns `false` if there's no such
+    /// parameter or listener.
+    pub fn remove_listener(&self, id: &str, listener: ListenerId) -> bool {
+        self.parameter(id)
+            .is_some_and(|parameter| parameter.remove_listener(listener))
+    }
+
+    /// Save the plain values of the parameters as XML.
+    pub fn to_xml(&self) -> String {
+        let ids: Vec<_> = self
+            .parameters
+            .iter()
+            .map(|parameter| parameter.id().to_string())
+            .collect();
+        let values: Vec<_> = self.parameters.iter().map(AudioParameter::value).collect();
+
+        juce::create_parameter_state(&self.state_type, &ids, &values)
+    }
+
+    /// Restore values saved with [`ParameterStore::to_xml`], notifying listeners of any changes.
+    ///
+    /// Values for unknown parameters are ignored, and parameters without a saved value keep their
+    /// current value.
+    pub fn restore_from_xml(&self, xml: &str) -> Result<()> {
+        let mut ids = vec![];
+        let mut values = vec![];
+        juce::parse_parameter_state(xml, &self.state_type, &mut ids, &mut values)?;
+
+        for (id, value) in ids.iter().zip(values) {
+            self.set_value(id, value);
+        }
+
+        Ok(())
+    }
+}
+
+mod sealed {
+    #[cfg(feature = "juce_audio_utils")]
+    use {crate::juce, std::pin::Pin};
+    use {
+        crate::{
+            juce_audio_basics::MidiBuffer, juce_audio_devices::OutputAudioSampleBuffer, Result,
+        },
+        std::time::Duration,
+    };
+
+    pub trait Sealed {
+        #[cfg(feature = "juce_audio_utils")]
+        fn set_as_processor_of(&mut self, player: Pin<&mut juce::AudioProcessorPlayer>);
+
+        fn set_non_realtime(&mut self, non_realtime: bool);
+
+        /// Prepare to render from a source with `num_input_channels` to a destination with
+        /// `num_output_channels`, returning the number of channels the processor uses.
+        fn prepare_to_render(
+            &mut self,
+            num_input_channels: usize,
+            num_output_channels: usize,
+            sample_rate: f64,
+            block_size: usize,
+        ) -> usize;
+
+        fn render_block(
+            &mut self,
+            buffer: &mut OutputAudioSampleBuffer<'_>,
+            midi: &mut MidiBuffer,
+        ) -> Result<()>;
+
+        fn num_output_channels(&self) -> usize;
+
+        fn tail_length(&self) -> Duration;
+
+        fn release_resources(&mut self);
+    }
+}
+
+/// A type backed by a JUCE `AudioProcessor`, which can be played by an [`AudioProcessorPlayer`].
+pub trait JuceAudioProcessor: sealed::Sealed + Send {}
+
+/// The kinds of input and output node in an [`AudioProcessorGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioGraphIOProcessorType {
+    /// Passes the graph's audio input into the graph.
+    AudioInput = 0,
+
+    /// Passes audio from the graph to the graph's output.
+    AudioOutput = 1,
+
+    /// Passes the graph's MIDI input into the graph.
+    MidiInput = 2,
+
+    /// Passes MIDI from the graph to the graph's output.
+    MidiOutput = 3,
+}
+
+impl NodeAndChannel {
+    /// The channel index that refers to a node's MIDI rather than one of its audio channels.
+    pub const MIDI_CHANNEL_INDEX: i32 = 0x1000;
+
+    /// An audio channel of a node.
+    pub fn audio(node_id: NodeId, channel_index: usize) -> Self {
+        Self {
+            node_id,
+            channel_index: channel_index as i32,
+        }
+    }
+
+    /// The MIDI of a node.
+    pub fn midi(node_id: NodeId) -> Self {
+        Self {
+            node_id,
+            channel_index: Self::MIDI_CHANNEL_INDEX,
+        }
+    }
+
+    /// Whether this refers to a node's MIDI.
+    pub fn is_midi(&self) -> bool {
+        self.channel_index == Self::MIDI_CHANNEL_INDEX
+    }
+}
+
+impl Connection {
+    /// Create a connection from an output to an input.
+    pub fn new(source: NodeAndChannel, destination: NodeAndChannel) -> Self {
+        Self {
+            source,
+            destination,
+        }
+    }
+}
+
+/// A processor made up of a graph of other processors, such as plugins, and the connections
+/// between them.
+///
+/// Audio and MIDI enter and leave the graph through input and output nodes added with
+/// [`AudioProcessorGraph::add_io_processor`].
+pub struct AudioProcessorGraph<'juce> {
+    // Declared before the graph so that it's removed from it before it's dropped.
+    play_head: Option<UniquePtr<juce::AudioPlayHeadHandle>>,
+    plugin_parameters: Vec<(NodeId, PluginParameterHandle)>,
+    graph: UniquePtr<juce::AudioProcessorGraph>,
+    _juce: PhantomData<&'juce ()>,
+}
+
+unsafe impl Send for AudioProcessorGraph<'_> {}
+
+impl Drop for AudioProcessorGraph<'_> {
+    fn drop(&mut self) {
+        self.detach_plugin_parameters(|_| true);
+    }
+}
+
+impl<'juce> AudioProcessorGraph<'juce> {
+    /// Create an empty graph.
+    pub fn new(_juce: &'juce JUCE) -> Self {
+        Self {
+            play_head: None,
+            plugin_parameters: vec![],
+            graph: juce::create_audio_processor_graph(),
+            _juce: PhantomData,
+        }
+    }
+
+    /// Add a plugin to the graph, which takes ownership of it.
+    ///
+    /// The plugin's [`PluginParameterHandle`]s keep working until its node is removed, see
+    /// [`AudioProcessorGraph::node_parameter_handle`].
+    pub fn add_plugin(&mut self, mut plugin: PluginInstance<'juce>) -> NodeId {
+        let instance = std::mem::replace(&mut plugin.instance, UniquePtr::null());
+        let parameters = std::mem::replace(
+            &mut plugin.parameters,
+            PluginParameterHandle::new(std::ptr::null()),
+        );
+
+        let node_id = juce::add_plugin_node(self.graph.pin_mut(), instance);
+        self.plugin_parameters.push((node_id, parameters));
+        node_id
+    }
+
+    /// Add a processor implemented in Rust to the graph, which takes ownership of it.
+    pub fn add_processor(&mut self, processor: impl AudioProcessor + 'static) -> NodeId {
+        let parameters = processor.parameters();
+
+        let mut processor = juce::create_rust_audio_processor(Box::new(BoxedAudioProcessor {
+            processor: Box::new(processor),
+            midi: MidiBuffer::new(),
+        }));
+
+        for parameter in parameters {
+            juce::add_rust_audio_parameter(processor.pin_mut(), Box::new(parameter));
+        }
+
+        juce::add_rust_processor_node(self.graph.pin_mut(), processor)
+    }
+
+    /// Add a node that passes audio or MIDI into or out of the graph.
+    pub fn add_io_processor(&mut self, io_type: AudioGraphIOProcessorType) -> NodeId {
+        juce::add_io_processor_node(self.graph.pin_mut(), io_type as i32)
+    }
+
+    /// Remove a node and its connections. Returns `false` if there's no such node.
+    pub fn remove_node(&mut self, node_id: NodeId) -> bool {
+        self.detach_plugin_parameters(|id| id == node_id);
+        juce::remove_node(self.graph.pin_mut(), node_id)
+    }
+
+    /// A handle for reading and setting the parameters of the plugin in a node from any thread, or
+    /// [`None`] if the node doesn't hold a plugin added with [`AudioProcessorGraph::add_plugin`].
+    ///
+    /// The handle stops working once the node is removed or the graph is cleared or dropped.
+    pub fn node_parameter_handle(&self, node_id: NodeId) -> Option<PluginParameterHandle> {
+        self.plugin_parameters
+            .iter()
+            .find(|(id, _)| *id == node_id)
+            .map(|(_, parameters)| parameters.clone())
+    }
+
+    // Called before the graph can delete the plugins, so that their handles don't outlive them.
+    fn detach_plugin_parameters(&mut self, mut should_detach: impl FnMut(NodeId) -> bool) {
+        self.plugin_parameters.retain(|(node_id, parameters)| {
+            if should_detach(*node_id) {
+                parameters.detach();
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// The number of nodes in the graph.
+    pub fn num_nodes(&self) -> usize {
+        self.graph.get_num_nodes() as usize
+    }
+
+    /// The nodes in the graph.
+    pub fn nodes(&self) -> Vec<NodeId> {
+        juce::get_node_ids(&self.graph)
+    }
+
+    /// The name of the processor in a node, or [`None`] if there's no such node.
+    pub fn node_name(&self, node_id: NodeId) -> Option<String> {
+        let mut name = String::new();
+        juce::get_node_name(&self.graph, node_id, &mut name).then_some(name)
+    }
+
+    /// Bypass a node, so that its input is passed through unprocessed. Returns `false` if there's
+    /// no such node.
+    pub fn set_node_bypassed(&mut self, node_id: NodeId, bypassed: bool) -> bool {
+        juce::set_node_bypassed(self.graph.pin_mut(), node_id, bypassed)
+    }
+
+    /// Whether a node is bypassed.
+    pub fn is_node_bypassed(&self, node_id: NodeId) -> bool {
+        juce::is_node_bypassed(&self.graph, node_id)
+    }
+
+    /// The number of samples the processor in a node delays its output by, or [`None`] if there's
+    /// no such node.
+    pub fn node_latency_samples(&self, node_id: NodeId) -> Option<usize> {
+        usize::try_from(juce::get_node_latency_samples(&self.graph, node_id)).ok()
+    }
+
+    /// Save the full state of the processor in a node, so that it can be restored with
+    /// [`AudioProcessorGraph::set_node_state`]. Returns an error if there's no such node.
+    pub fn node_state(&mut self, node_id: NodeId) -> Result<Vec<u8>> {
+        juce::get_node_state_information(self.graph.pin_mut(), node_id)
+    }
+
+    /// Restore state saved with [`AudioProcessorGraph::node_state`].
+    ///
+    /// Returns an error if there's no such node or the state is larger than JUCE can pass to the
+    /// processor.
+    pub fn set_node_state(&mut self, node_id: NodeId, state: &[u8]) -> Result<()> {
+        check_state_size(state)?;
+        juce::set_node_state_information(self.graph.pin_mut(), node_id, state)
+    }
+
+    /// Save the state of the current program of the processor in a node, see
+    /// [`PluginInstance::current_program_state`]. Returns an error if there's no such node.
+    pub fn node_current_program_state(&mut self, node_id: NodeId) -> Result<Vec<u8>> {
+        juce::get_node_current_program_state_information(self.graph.pin_mut(), node_id)
+    }
+
+    /// Restore state saved with [`AudioProcessorGraph::node_current_program_state`].
+    ///
+    /// Returns an error if there's no such node or the state is larger than JUCE can pass to the
+    /// processor.
+    pub fn set_node_current_program_state(&mut self, node_id: NodeId, state: &[u8]) -> Result<()> {
+        check_state_size(state)?;
+        juce::set_node_current_program_state_information(self.graph.pin_mut(), node_id, state)
+    }
+
+    /// The number of samples the graph delays its output by, which is the latency of its slowest
+    /// path from input to output.
+    ///
+    /// The graph compensates for the latency of its nodes by delaying every other path to an input
+    /// so that parallel paths stay time-aligned.
+    pub fn latency_samples(&self) -> usize {
+        self.graph.get_latency_samples().max(0) as usize
+    }
+
+    /// Rebuild the graph's rendering sequence, updating its latency compensation.
+    ///
+    /// The graph rebuilds itself when its nodes or connections change, but a node changing its
+    /// latency, for example when a plugin is reconfigured, requires a call to this method. It must
+    /// be called on the message thread.
+    pub fn rebuild(&mut self) {
+        self.graph.pin_mut().rebuild();
+    }
+
+    /// Open the editor of the processor in a node in a new window.
+    ///
+    /// This fails if it isn't called on the message thread, there's no such node, the processor has
+    /// no editor, or its editor is already open.
+    pub fn open_editor_window(
+        &self,
+        node_id: NodeId,
+        title: &str,
+        listener: impl PluginEditorWindowListener + 'static,
+    ) -> Result<PluginEditorWindow<'_>> {
+        let listener: BoxedPluginEditorWindowListener = Box::new(listener);
+        let window =
+            juce::create_node_editor_window(&self.graph, node_id, title, Box::new(listener))?;
+
+        Ok(PluginEditorWindow {
+            window,
+            _owner: PhantomData,
+            _not_send: PhantomData,
+        })
+    }
+
+    /// Add a connection. Returns `false` if the connection isn't valid or already exists.
+    pub fn add_connection(&mut self, connection: &Connection) -> bool {
+        juce::add_connection(self.graph.pin_mut(), connection)
+    }
+
+    /// Remove a connection. Returns `false` if there's no such connection.
+    pub fn remove_connection(&mut self, connection: &Connection) -> bool {
+        juce::remove_connection(self.graph.pin_mut(), connection)
+    }
+
+    /// Whether the connection exists.
+    pub fn is_connected(&self, connection: &Connection) -> bool {
+        juce::is_connected(&self.graph, connection)
+    }
+
+    /// Whether the connection could be added.
+    pub fn can_connect(&self, connection: &Connection) -> bool {
+        juce::can_connect(&self.graph, connection)
+    }
+
+    /// All connections in the graph.
+    pub fn connections(&self) -> Vec<Connection> {
+        juce::get_connections(&self.graph)
+    }
+
+    /// Remove all of a node's connections. Returns `false` if it had none.
+    pub fn disconnect_node(&mut self, node_id: NodeId) -> bool {
+        juce::disconnect_node(self.graph.pin_mut(), node_id)
+    }
+
+    /// Remove all nodes and connections.
+    pub fn clear(&mut self) {
+        self.detach_plugin_parameters(|_| true);
+        juce::clear_graph(self.graph.pin_mut());
+    }
+
+    /// The number of channels passed in by the graph's audio input node.
+    pub fn num_input_channels(&self) -> usize {
+        self.graph.get_total_num_input_channels() as usize
+    }
+
+    /// The number of channels passed out by the graph's audio output node.
+    pub fn num_output_channels(&self) -> usize {
+        self.graph.get_total_num_output_channels() as usize
+    }
+
+    /// Tell the graph and its nodes whether they're being rendered offline rather than played in
+    /// real time. See [`OfflineRenderer`].
+    pub fn set_non_realtime(&mut self, non_realtime: bool) {
+        self.graph.pin_mut().set_non_realtime(non_realtime);
+    }
+
+    /// Whether the graph is being rendered offline, see [`AudioProcessorGraph::set_non_realtime`].
+    pub fn is_non_realtime(&self) -> bool {
+        self.graph.is_non_realtime()
+    }
+
+    /// Prepare the graph and its nodes for playback with the given number of channels.
+    pub fn prepare(
+        &mut self,
+        num_input_channels: usize,
+        num_output_channels: usize,
+        sample_rate: f64,
+        block_size: usize,
+    ) {
+        juce::prepare_graph(
+            self.graph.pin_mut(),
+            num_input_channels,
+            num_output_channels,
+            sample_rate,
+            block_size,
+        );
+    }
+
+    /// Process a block of audio and MIDI in place.
+    ///
+    /// The buffer must have at least as many channels as the graph's inputs or outputs, whichever
+    /// is greater, and no more samples than the block size the graph was prepared with.
+    pub fn process(
+        &mut self,
+        buffer: &mut OutputAudioSampleBuffer<'_>,
+        midi: &mut MidiBuffer,
+    ) -> Result<()> {
+        juce::process_graph(
+            self.graph.pin_mut(),
+            buffer.buffer.as_mut(),
+            midi.0.pin_mut(),
+        )
+    }
+
+    /// Release any resources allocated by [`AudioProcessorGraph::prepare`].
+    pub fn release_resources(&mut self) {
+        self.graph.pin_mut().release_resources();
+    }
+
+    /// Provide the graph's nodes with the position of the host's transport, replacing any previous
+    /// play head.
+    pub fn set_play_head(&mut self, play_head: impl AudioPlayHead + 'static) {
+        let play_head: BoxedAudioPlayHead = Box::new(play_head);
+        self.play_head = Some(juce::set_graph_play_head(
+            self.graph.pin_mut(),
+            Box::new(play_head),
+        ));
+    }
+
+    /// Remove the play head set with [`AudioProcessorGraph::set_play_head`].
+    pub fn clear_play_head(&mut self) {
+        self.play_head = None;
+    }
+
+    /// Clear the internal state of every node.
+    pub fn reset(&mut self) {
+        self.graph.pin_mut().reset();
+    }
+}
+
+impl sealed::Sealed for PluginInstance<'_> {
+    #[cfg(feature = "juce_audio_utils")]
+    fn set_as_processor_of(&mut self, player: Pin<&mut juce::AudioProcessorPlayer>) {
+        juce::set_plugin_instance(player, self.instance.pin_mut());
+    }
+
+    fn set_non_realtime(&mut self, non_realtime: bool) {
+        PluginInstance::set_non_realtime(self, non_realtime);
+    }
+
+    fn prepare_to_render(
+        &mut self,
+        _num_input_channels: usize,
+        _num_output_channels: usize,
+        sample_rate: f64,
+        block_size: usize,
+    ) -> usize {
+        self.prepare(sample_rate, block_size);
+        self.num_input_channels().max(self.num_output_channels())
+    }
+
+    fn render_block(
+        &mut self,
+        buffer: &mut OutputAudioSampleBuffer<'_>,
+        midi: &mut MidiBuffer,
+    ) -> Result<()> {
+        self.process(buffer, midi)
+    }
+
+    fn num_output_channels(&self) -> usize {
+        PluginInstance::num_output_channels(self)
+    }
+
+    fn tail_length(&self) -> Duration {
+        PluginInstance::tail_length(self)
+    }
+
+    fn release_resources(&mut self) {
+        PluginInstance::release_resources(self);
+    }
+}
+
+impl JuceAudioProcessor for PluginInstance<'_> {}
+
+impl sealed::Sealed for AudioProcessorGraph<'_> {
+    #[cfg(feature = "juce_audio_utils")]
+    fn set_as_processor_of(&mut self, player: Pin<&mut juce::AudioProcessorPlayer>) {
+        juce::set_graph(player, self.graph.pin_mut());
+    }
+
+    fn set_non_realtime(&mut self, non_realtime: bool) {
+        AudioProcessorGraph::set_non_realtime(self, non_realtime);
+    }
+
+    fn prepare_to_render(
+        &mut self,
+        num_input_channels: usize,
+        num_output_channels: usize,
+        sample_rate: f64,
+        block_size: usize,
+    ) -> usize {
+        self.prepare(
+            num_input_channels,
+            num_output_channels,
+            sample_rate,
+            block_size,
+        );
+        num_input_channels.max(num_output_channels)
+    }
+
+    fn render_block(
+        &mut self,
+        buffer: &mut OutputAudioSampleBuffer<'_>,
+        midi: &mut MidiBuffer,
+    ) -> Result<()> {
+        self.process(buffer, midi)
+    }
+
+    fn num_output_channels(&self) -> usize {
+        AudioProcessorGraph::num_output_channels(self)
+    }
+
+    fn tail_length(&self) -> Duration {
+        tail_length_from_seconds(self.graph.get_tail_length_seconds())
+    }
+
+    fn release_resources(&mut self) {
+        AudioProcessorGraph::release_resources(self);
+    }
+}
+
+impl JuceAudioProcessor for AudioProcessorGraph<'_> {}
+
+/// JUCE passes state to processors with an `int` size.
+fn check_state_size(state: &[u8]) -> Result<()> {
+    if i32::try_from(state.len()).is_err() {
+        return invalid_argument("state is too large");
+    }
+
+    Ok(())
+}
+
+/// Converts a tail length reported by JUCE, which reports an infinite tail as infinity.
+fn tail_length_from_seconds(seconds: f64) -> Duration {
+    Duration::try_from_secs_f64(seconds.max(0.0)).unwrap_or(Duration::MAX)
+}
+
+/// Renders audio through a processor, such as a [`PluginInstance`] or an [`AudioProcessorGraph`],
+/// as fast as possible rather than in real time.
+///
+/// The processor is told it's rendering offline and prepared at the reader's sample rate. The
+/// reader's samples are then processed block by block and written to the writer, followed by the
+/// processor's tail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OfflineRenderer {
+    block_size: usize,
+    tail_length: Option<Duration>,
+}
+
+impl Default for OfflineRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OfflineRenderer {
+    /// Create a renderer that processes 512 samples at a time and renders the processor's tail.
+    pub fn new() -> Self {
+        Self {
+            block_size: 512,
+            tail_length: None,
+        }
+    }
+
+    /// Set the number of samples to process at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is zero.
+    pub fn with_block_size(mut self, block_size: usize) -> Self {
+        assert!(block_size > 0, "the block size must be greater than zero");
+        self.block_size = block_size;
+        self
+    }
+
+    /// Render `tail_length` after the end of the input, rather than the tail length the processor
+    /// reports.
+    ///
+    /// Without this, no tail is rendered for processors that report an infinite tail.
+    pub fn with_tail_length(mut self, tail_length: Duration) -> Self {
+        self.tail_length = Some(tail_length);
+        self
+    }
+
+    /// The number of samples processed at a time.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Render the reader's samples through the processor into the writer.
+    ///
+    /// `on_progress` is called after each block with the fraction of the render that's complete,
+    /// between 0 and 1. The writer's channels that the processor doesn't output are written as
+    /// silence. Afterwards the processor's resources are released and it's returned to real-time
+    /// processing, even if rendering failed.
+    pub fn render(
+        &self,
+        processor: &mut impl JuceAudioProcessor,
+        reader: &mut AudioFormatReader,
+        writer: &mut AudioFormatWriter,
+        mut on_progress: impl FnMut(f32),
+    ) -> Result<()> {
+        let sample_rate = reader.sample_rate();
+        let tail_length = self
+            .tail_length
+            .unwrap_or_else(|| match processor.tail_length() {
+                Duration::MAX => Duration::ZERO,
+                tail_length => tail_length,
+            });
+        let length =
+            reader.length_in_samples() + (tail_length.as_secs_f64() * sample_rate).ceil() as u64;
+
+        processor.set_non_realtime(true);
+        let channels = processor.prepare_to_render(
+            reader.num_channels(),
+            writer.num_channels(),
+            sample_rate,
+            self.block_size,
+        );
+
+        let mut buffer = AudioBuffer::new(
+            channels
+                .max(reader.num_channels())
+                .max(writer.num_channels()),
+            self.block_size,
+        );
+        let mut midi = MidiBuffer::new();
+        let mut render_block = |position: u64| -> Result<usize> {
+            let num_samples = (length - position).min(self.block_size as u64) as usize;
+
+            reader.read_into(&mut buffer, 0, position as i64, self.block_size)?;
+            midi.clear();
+            processor.render_block(&mut OutputAudioSampleBuffer::from(&mut buffer), &mut midi)?;
+
+            for channel in processor.num_output_channels()..buffer.channels() {
+                buffer[channel].fill(0.0);
+            }
+
+            writer.write(&buffer, 0, num_samples)?;
+            Ok(num_samples)
+        };
+
+        let mut position = 0;
+        let mut result = Ok(());
+        while position < length {
+            match render_block(position) {
+                Ok(num_samples) => position += num_samples as u64,
+                Err(error) => {
+                    result = Err(error);
+                    break;
+                }
+            }
+            on_progress(position as f32 / length as f32);
+        }
+
+        processor.release_resources();
+        processor.set_non_realtime(false);
+        result
+    }
+}
+
+/// Plays a processor, such as a [`PluginInstance`] or an [`AudioProcessorGraph`], through an
+/// audio device, preparing it to match the device.
+///
+/// The player owns the processor, which can still be changed while it plays with
+/// [`with_processor`](Self::with_processor), e.g. by adding nodes to a graph. Once added to an
+/// [`AudioDeviceManager`](crate::juce_audio_devices::AudioDeviceManager) it also receives MIDI
+/// from the device manager's enabled MIDI inputs.
+#[cfg(feature = "juce_audio_utils")]
+pub struct AudioProcessorPlayer<P> {
+    // Declared before the processor so that it's dropped, and stops using the processor, first.
+    pub(crate) player: UniquePtr<juce::AudioProcessorPlayer>,
+    processor: P,
+}
+
+#[cfg(feature = "juce_audio_utils")]
+unsafe impl<P: Send> Send for AudioProcessorPlayer<P> {}
+
+#[cfg(feature = "juce_audio_utils")]
+impl<P: JuceAudioProcessor> AudioProcessorPlayer<P> {
+    /// Create a player for the processor.
+    pub fn new(mut processor: P) -> Self {
+        let mut player = juce::create_audio_processor_player();
+        processor.set_as_processor_of(player.pin_mut());
+        Self { player, processor }
+    }
+
+    /// The processor being played.
+    pub fn processor(&self) -> &P {
+        &self.processor
+    }
+
+    /// Call a function with the processor being played, preventing the player from processing
+    /// until it returns.
+    ///
+    /// The audio device is stalled while the function runs, so it should return quickly.
+    pub fn with_processor<R>(&mut self, f: impl FnOnce(&mut P) -> R) -> R {
+        let _lock = juce::lock_audio_processor_player(&self.player);
+        f(&mut self.processor)
+    }
+
+    /// Stop playing and return the processor.
+    pub fn into_processor(self) -> P {
+        let Self { player, processor } = self;
+        drop(player);
+        processor
+    }
+}
+
+pub(crate) mod ffi {
+    use super::*;
+
+    pub mod audio_processor_listener {
+        use super::*;
+
+        pub fn audio_processor_listener_parameter_changed(
+            self_: &BoxedAudioProcessorListener,
+            index: usize,
+            value: f32,
+        ) {
+            self_.parameter_changed(index, value);
+        }
+
+        pub fn audio_processor_listener_processor_changed(self_: &BoxedAudioProcessorListener) {
+            self_.processor_changed();
+        }
+
+        pub fn audio_processor_listener_parameter_change_gesture_begin(
+            self_: &BoxedAudioProcessorListener,
+            index: usize,
+        ) {
+            self_.parameter_change_gesture_began(index);
+        }
+
+        pub fn audio_processor_listener_parameter_change_gesture_end(
+            self_: &BoxedAudioProcessorListener,
+            index: usize,
+        ) {
+            self_.parameter_change_gesture_ended(index);
+        }
+    }
+
+    pub mod audio_play_head {
+        use super::*;
+
+        pub fn audio_play_head_get_position(
+            self_: &BoxedAudioPlayHead,
+            position: &mut PlayHeadPosition,
+        ) -> bool {
+            self_
+                .position()
+                .map(|current| *position = current)
+                .is_some()
+        }
+    }
+
+    pub mod rust_audio_processor {
+        use super::*;
+
+        pub fn rust_audio_processor_name(self_: &BoxedAudioProcessor) -> String {
+            self_.processor.name()
+        }
+
+        pub fn rust_audio_processor_num_input_channels(self_: &BoxedAudioProcessor) -> usize {
+            self_.processor.num_input_channels()
+        }
+
+        pub fn rust_audio_processor_num_output_channels(self_: &BoxedAudioProcessor) -> usize {
+            self_.processor.num_output_channels()
+        }
+
+        pub fn rust_audio_processor_tail_length_seconds(self_: &BoxedAudioProcessor) -> f64 {
+            match self_.processor.tail_length() {
+                Duration::MAX => f64::INFINITY,
+                tail_length => tail_length.as_secs_f64(),
+            }
+        }
+
+        pub fn rust_audio_processor_accepts_midi(self_: &BoxedAudioProcessor) -> bool {
+            self_.processor.accepts_midi()
+        }
+
+        pub fn rust_audio_processor_produces_midi(self_: &BoxedAudioProcessor) -> bool {
+            self_.processor.produces_midi()
+        }
+
+        pub fn rust_audio_processor_prepare_to_play(
+            mut self_: Pin<&mut BoxedAudioProcessor>,
+            sample_rate: f64,
+            maximum_block_size: usize,
+        ) {
+            self_
+                .processor
+                .prepare_to_play(sample_rate, maximum_block_size);
+        }
+
+        pub fn rust_audio_processor_release_resources(mut self_: Pin<&mut BoxedAudioProcessor>) {
+            self_.processor.release_resources();
+        }
+
+        pub fn rust_audio_processor_process_block(
+            mut self_: Pin<&mut BoxedAudioProcessor>,
+            buffer: Pin<&mut juce::AudioSampleBuffer>,
+            mut midi: Pin<&mut juce::MidiBuffer>,
+        ) {
+            let BoxedAudioProcessor {
+                processor,
+                midi: lent,
+            } = &mut *self_;
+
+            lent.0.pin_mut().swap_with(midi.as_mut());
+            processor.process_block(&mut OutputAudioSampleBuffer::new(buffer), lent);
+            lent.0.pin_mut().swap_with(midi);
+        }
+
+        pub fn rust_audio_processor_reset(mut self_: Pin<&mut BoxedAudioProcessor>) {
+            self_.processor.reset();
+        }
+
+        pub fn rust_audio_processor_state(self_: &BoxedAudioProcessor) -> Vec<u8> {
+            self_.processor.state()
+        }
+
+        pub fn rust_audio_processor_set_state(
+            mut self_: Pin<&mut BoxedAudioProcessor>,
+            state: &[u8],
+        ) {
+            self_.processor.set_state(state);
+        }
+    }
+
+    pub mod rust_audio_parameter {
+        use super::*;
+
+        pub fn rust_audio_parameter_info(self_: &AudioParameter) -> ParameterInfo {
+            self_.info()
+        }
+
+        pub fn rust_audio_parameter_value(self_: &AudioParameter) -> f32 {
+            self_.normalised_value()
+        }
+
+        pub fn rust_audio_parameter_set_value(self_: &AudioParameter, value: f32) {
+            self_.set_normalised_value(value);
+        }
+
+        pub fn rust_audio_parameter_text(self_: &AudioParameter, value: f32) -> String {
+            self_.text_for_value(value)
+        }
+
+        pub fn rust_audio_parameter_gesture_changed(
+            self_: &AudioParameter,
+            gesture_is_starting: bool,
+        ) {
+            self_.gesture_changed(gesture_is_starting);
+        }
+
+        pub unsafe fn rust_audio_parameter_set_host(
+            self_: &AudioParameter,
+            host: *mut juce::RustAudioProcessorParameter,
+        ) {
+            *self_
+                .state
+                .host
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(HostParameter(host));
+        }
+
+        pub unsafe fn rust_audio_parameter_remove_host(
+            self_: &AudioParameter,
+            host: *mut juce::RustAudioProcessorParameter,
+        ) {
+            let mut current = self_
+                .state
+                .host
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            // A clone of the parameter may have been added to another processor since.
+            if current.as_ref().is_some_and(|current| current.0 == host) {
+                *current = None;
+            }
+        }
+
+        pub fn rust_audio_parameter_value_for_text(self_: &AudioParameter, text: &str) -> f32 {
+            self_
+                .value_for_text(text)
+                .unwrap_or_else(|| self_.range.to_normalised(self_.default_value))
+        }
+    }
+
+    pub mod plugin_editor_window_listener {
+        use super::*;
+
+        pub fn plugin_editor_window_close_button_pressed(
+            mut self_: Pin<&mut BoxedPluginEditorWindowListener>,
+        ) {
+            self_.close_button_pressed();
+        }
+
+        pub fn plugin_editor_window_visibility_changed(
+            mut self_: Pin<&mut BoxedPluginEditorWindowListener>,
+            visible: bool,
+        ) {
+            self_.visibility_changed(visible);
+        }
+
+        pub fn plugin_editor_window_moved(
+            mut self_: Pin<&mut BoxedPluginEditorWindowListener>,
+            x: i32,
+            y: i32,
+        ) {
+            self_.moved(x, y);
+        }
+    }
+}
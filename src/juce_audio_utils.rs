@@ -0,0 +1,708 @@
+//! Classes for audio-related GUI and miscellaneous tasks.
+
+use {
+    crate::{
+        juce,
+        juce_audio_basics::AudioBuffer,
+        juce_audio_devices::{
+            AudioCallbackHandle, AudioDeviceManager, AudioIODevice, AudioIODeviceCallback,
+            InputAudioSampleBuffer, OutputAudioSampleBuffer,
+        },
+        juce_audio_formats::{
+            AudioFormatManager, AudioFormatReader, AudioFormatWriter, ThreadedWriter,
+        },
+        juce_core::{path_to_str, TimeSliceThread},
+        Result, JUCE,
+    },
+    cxx::{SharedPtr, UniquePtr},
+    std::{
+        marker::PhantomData,
+        ops::Range,
+        path::Path,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::Duration,
+    },
+};
+
+/// The system dialogue for pairing Bluetooth LE MIDI devices.
+///
+/// Once paired, a device shows up alongside the other MIDI devices, e.g. in
+/// [`MidiInput::devices`](crate::juce_audio_devices::MidiInput::devices).
+pub struct BluetoothMidiDevicePairingDialogue;
+
+impl BluetoothMidiDevicePairingDialogue {
+    /// Returns true if Bluetooth MIDI pairing is available on this platform.
+    ///
+    /// This is currently only the case on iOS, macOS and Android.
+    pub fn is_available() -> bool {
+        juce::is_bluetooth_midi_available()
+    }
+
+    /// Open the pairing dialogue.
+    ///
+    /// The callback is called on the message thread when the dialogue is closed. Returns false
+    /// if the dialogue couldn't be opened, in which case the callback is dropped without being called.
+    ///
+    /// This fails if it isn't called on the message thread.
+    pub fn open(_juce: &JUCE, on_close: impl FnOnce() + Send + 'static) -> Result<bool> {
+        let callback = BoxedPairingDialogueCallback(Box::new(on_close));
+        juce::open_bluetooth_midi_device_pairing_dialogue(Box::new(callback))
+    }
+}
+
+/// A cache of the most recently used [`AudioThumbnail`]s.
+///
+/// The cache owns the background thread that scans thumbnail sources, and can be shared between
+/// any number of thumbnails.
+#[derive(Clone)]
+pub struct AudioThumbnailCache(SharedPtr<juce::AudioThumbnailCache>);
+
+unsafe impl Send for AudioThumbnailCache {}
+unsafe impl Sync for AudioThumbnailCache {}
+
+impl AudioThumbnailCache {
+    /// Create a cache that stores up to `max_thumbnails` thumbnails.
+    pub fn new(max_thumbnails: usize) -> Self {
+        Self(juce::create_audio_thumbnail_cache(max_thumbnails as i32))
+    }
+
+    /// Remove all the thumbnails from the cache.
+    pub fn clear(&self) {
+        juce::clear_audio_thumbnail_cache(&self.0);
+    }
+}
+
+/// A low-resolution overview of an audio source, for drawing waveforms.
+///
+/// The thumbnail stores the minimum and maximum sample values of each block of source samples.
+/// Sources set with [`set_source_file`](Self::set_source_file) or
+/// [`set_reader`](Self::set_reader) are scanned incrementally on the cache's background thread,
+/// and the peaks can be read while the scan is in progress.
+pub struct AudioThumbnail<'juce> {
+    thumbnail: UniquePtr<juce::AudioThumbnail>,
+    _juce: PhantomData<&'juce ()>,
+}
+
+unsafe impl Send for AudioThumbnail<'_> {}
+
+impl<'juce> AudioThumbnail<'juce> {
+    /// Create a thumbnail that stores one minimum and maximum value for every
+    /// `samples_per_thumbnail_sample` source samples.
+    pub fn new(
+        _juce: &'juce JUCE,
+        samples_per_thumbnail_sample: usize,
+        cache: &AudioThumbnailCache,
+    ) -> Self {
+        Self {
+            thumbnail: juce::create_audio_thumbnail(
+                samples_per_thumbnail_sample as i32,
+                cache.0.clone(),
+            ),
+            _juce: PhantomData,
+        }
+    }
+
+    /// Start scanning an audio file, returning false if the file can't be opened.
+    ///
+    /// Any of the basic formats can be read.
+    pub fn set_source_file(&mut self, path: impl AsRef<Path>) -> bool {
+        let Ok(path) = path_to_str(path.as_ref()) else {
+            return false;
+        };
+
+        self.thumbnail.pin_mut().set_source_file(path)
+    }
+
+    /// Start scanning the samples from a reader.
+    ///
+    /// The hash is used to identify the source in the cache.
+    pub fn set_reader(&mut self, reader: AudioFormatReader, hash: i64) {
+        self.thumbnail.pin_mut().set_reader(reader.0, hash);
+    }
+
+    /// Clear the thumbnail and stop scanning its source.
+    pub fn clear(&mut self) {
+        self.thumbnail.pin_mut().clear();
+    }
+
+    /// Clear the thumbnail and prepare it for samples to be added with
+    /// [`add_block`](Self::add_block).
+    pub fn reset(&mut self, num_channels: usize, sample_rate: f64, total_samples: u64) {
+        self.thumbnail
+            .pin_mut()
+            .reset(num_channels as i32, sample_rate, total_samples as i64);
+    }
+
+    /// Add a block of samples, starting at the given sample in the source.
+    ///
+    /// Returns an error if the buffer has fewer channels than the thumbnail.
+    pub fn add_block(&mut self, sample_number_in_source: u64, buffer: &AudioBuffer) -> Result<()> {
+        self.thumbnail
+            .pin_mut()
+            .add_block(sample_number_in_source as i64, &buffer.0)
+    }
+
+    /// The number of channels.
+    pub fn num_channels(&self) -> usize {
+        self.thumbnail.get_num_channels() as usize
+    }
+
+    /// The length of the source.
+    pub fn total_length(&self) -> Duration {
+        Duration::from_secs_f64(self.thumbnail.get_total_length().max(0.0))
+    }
+
+    /// Returns true once the whole source has been scanned.
+    pub fn is_fully_loaded(&self) -> bool {
+        self.thumbnail.is_fully_loaded()
+    }
+
+    /// The proportion of the source that has been scanned, between 0 and 1.
+    pub fn proportion_complete(&self) -> f64 {
+        self.thumbnail.get_proportion_complete()
+    }
+
+    /// The number of source samples that have been scanned.
+    pub fn num_samples_finished(&self) -> u64 {
+        self.thumbnail.get_num_samples_finished() as u64
+    }
+
+    /// The highest absolute sample value in the thumbnail.
+    pub fn approximate_peak(&self) -> f32 {
+        self.thumbnail.get_approximate_peak()
+    }
+
+    /// The hash of the source.
+    pub fn hash_code(&self) -> i64 {
+        self.thumbnail.get_hash_code()
+    }
+
+    /// Divide a time range into `num_points` equal sections, returning the minimum and maximum
+    /// sample values of each section of a channel.
+    ///
+    /// Sections that haven't been scanned yet are zero. Returns an error if the channel is out of
+    /// range.
+    pub fn peaks(
+        &self,
+        channel: usize,
+        range: Range<Duration>,
+        num_points: usize,
+    ) -> Result<Vec<(f32, f32)>> {
+        let mut min_values = vec![0.0; num_points];
+        let mut max_values = vec![0.0; num_points];
+
+        self.thumbnail.get_peaks(
+            channel as i32,
+            range.start.as_secs_f64(),
+            range.end.as_secs_f64(),
+            &mut min_values,
+            &mut max_values,
+        )?;
+
+        Ok(min_values.into_iter().zip(max_values).collect())
+    }
+}
+
+/// Records the input of an audio device to an audio file.
+///
+/// The recorder's callback writes the input to a [`ThreadedWriter`], so the file is written on a
+/// background thread. While the recorder isn't recording it keeps the most recent input as a
+/// pre-roll, which is written to the start of the next recording.
+///
+/// The recorder can be cloned to control it from several threads.
+#[derive(Clone)]
+pub struct AudioRecorder(Arc<Recorder>);
+
+struct Recorder {
+    pre_roll: Duration,
+    state: Mutex<RecorderState>,
+    dropouts: AtomicUsize,
+    dropped_samples: AtomicUsize,
+}
+
+impl Recorder {
+    fn pre_roll_samples(&self, sample_rate: f64) -> usize {
+        (self.pre_roll.as_secs_f64() * sample_rate).round() as usize
+    }
+}
+
+#[derive(Default)]
+struct RecorderState {
+    writer: Option<ThreadedWriter>,
+    paused: bool,
+    recorded_samples: u64,
+    pre_roll: PreRoll,
+    peak_levels: Vec<f32>,
+}
+
+#[derive(Default)]
+struct PreRoll {
+    buffer: Option<AudioBuffer>,
+    capacity: usize,
+    position: usize,
+    len: usize,
+}
+
+impl PreRoll {
+    fn prepare(&mut self, channels: usize, capacity: usize) {
+        let matches = self
+            .buffer
+            .as_ref()
+            .is_some_and(|buffer| buffer.channels() == channels);
+
+        if !matches || self.capacity != capacity {
+            self.buffer = (capacity > 0).then(|| AudioBuffer::new(channels, capacity));
+            self.capacity = capacity;
+        }
+
+        self.position = 0;
+        self.len = 0;
+    }
+
+    /// Called on the audio thread, so this mustn't allocate. Channels that the buffer wasn't
+    /// prepared for are ignored.
+    fn push(&mut self, input: &InputAudioSampleBuffer<'_>) {
+        let Some(buffer) = &mut self.buffer else {
+            return;
+        };
+
+        let samples = input.samples();
+        let skip = samples.saturating_sub(self.capacity);
+
+        for sample in skip..samples {
+            for channel in 0..buffer.channels() {
+                buffer[channel][self.position] = if channel < input.channels() {
+                    input[channel][sample]
+                } else {
+                    0.0
+                };
+            }
+
+            self.position = (self.position + 1) % self.capacity;
+        }
+
+        self.len = (self.len + samples - skip).min(self.capacity);
+    }
+
+    /// Copies the pre-roll out of the ring, leaving it in place until [`PreRoll::clear`].
+    fn to_buffer(&self) -> Option<AudioBuffer> {
+        let buffer = self.buffer.as_ref()?;
+        let start = (self.position + self.capacity - self.len) % self.capacity;
+
+        let mut pre_roll = AudioBuffer::new(buffer.channels(), self.len);
+        for channel in 0..buffer.channels() {
+            for sample in 0..self.len {
+                pre_roll[channel][sample] = buffer[channel][(start + sample) % self.capacity];
+            }
+        }
+
+        (pre_roll.samples() > 0).then_some(pre_roll)
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl AudioRecorder {
+    /// Create a recorder that keeps `pre_roll` of input to write to the start of each recording.
+    pub fn new(pre_roll: Duration) -> Self {
+        Self(Arc::new(Recorder {
+            pre_roll,
+            state: Mutex::new(RecorderState::default()),
+            dropouts: AtomicUsize::new(0),
+            dropped_samples: AtomicUsize::new(0),
+        }))
+    }
+
+    /// Register the recorder's callback with a device manager.
+    ///
+    /// The callback is removed when the returned handle is dropped.
+    pub fn register<'a>(
+        &self,
+        device_manager: &'a mut AudioDeviceManager<'_>,
+    ) -> AudioCallbackHandle<'a> {
+        device_manager.add_audio_callback(self.callback())
+    }
+
+    /// The recorder's audio callback, for registering with a device manually.
+    pub fn callback(&self) -> AudioRecorderCallback {
+        AudioRecorderCallback(Arc::clone(&self.0))
+    }
+
+    /// Start recording to a writer, stopping any recording in progress.
+    ///
+    /// The writer should have the same sample rate and number of channels as the device's input.
+    /// The pre-roll is written first. Returns an error if the pre-roll has fewer channels than the
+    /// writer, in which case the pre-roll is kept for the next recording.
+    pub fn start(&self, writer: AudioFormatWriter) -> Result<()> {
+        let fifo_size =
+            writer.sample_rate() as usize + self.0.pre_roll_samples(writer.sample_rate());
+        let mut writer = ThreadedWriter::new(writer, fifo_size);
+
+        let previous = {
+            let mut state = self.0.state.lock().unwrap();
+
+            // The pre-roll is only discarded once it has been written, so it can still be used by
+            // another recording if this one fails to start.
+            if let Some(pre_roll) = state.pre_roll.to_buffer() {
+                writer.write(&pre_roll)?;
+                state.pre_roll.clear();
+                state.recorded_samples = pre_roll.samples() as u64;
+            } else {
+                state.recorded_samples = 0;
+            }
+
+            state.paused = false;
+            state.writer.replace(writer)
+        };
+
+        // Dropping a writer waits for its pending samples to be written, which mustn't block the
+        // audio callback.
+        drop(previous);
+        Ok(())
+    }
+
+    /// Stop recording, finalising the file once the pending samples have been written.
+    pub fn stop(&self) {
+        let writer = self.0.state.lock().unwrap().writer.take();
+        drop(writer);
+    }
+
+    /// Pause recording. The input isn't written or kept as pre-roll until recording is resumed.
+    pub fn pause(&self) {
+        self.0.state.lock().unwrap().paused = true;
+    }
+
+    /// Resume a paused recording.
+    pub fn resume(&self) {
+        self.0.state.lock().unwrap().paused = false;
+    }
+
+    /// Returns true if recording, including while paused.
+    pub fn is_recording(&self) -> bool {
+        self.0.state.lock().unwrap().writer.is_some()
+    }
+
+    /// Returns true if paused.
+    pub fn is_paused(&self) -> bool {
+        self.0.state.lock().unwrap().paused
+    }
+
+    /// The number of samples per channel written to the current recording, including pre-roll.
+    pub fn recorded_samples(&self) -> u64 {
+        self.0.state.lock().unwrap().recorded_samples
+    }
+
+    /// The peak level of each input channel since the last call.
+    pub fn peak_levels(&self) -> Vec<f32> {
+        let mut state = self.0.state.lock().unwrap();
+        let levels = state.peak_levels.clone();
+        state.peak_levels.fill(0.0);
+        levels
+    }
+
+    /// The number of input blocks that couldn't be recorded, either because the writer's FIFO was
+    /// full or because the recorder was busy being started or stopped.
+    pub fn dropouts(&self) -> usize {
+        self.0.dropouts.load(Ordering::Relaxed)
+    }
+
+    /// The number of samples per channel lost to dropouts.
+    pub fn dropped_samples(&self) -> usize {
+        self.0.dropped_samples.load(Ordering::Relaxed)
+    }
+}
+
+/// The audio callback of an [`AudioRecorder`].
+pub struct AudioRecorderCallback(Arc<Recorder>);
+
+impl AudioRecorderCallback {
+    fn record_dropout(&self, samples: usize) {
+        self.0.dropouts.fetch_add(1, Ordering::Relaxed);
+        self.0.dropped_samples.fetch_add(samples, Ordering::Relaxed);
+    }
+}
+
+impl AudioIODeviceCallback for AudioRecorderCallback {
+    fn about_to_start(&mut self, device: &mut dyn AudioIODevice) {
+        let channels = device.input_channels().max(0) as usize;
+        let pre_roll = self.0.pre_roll_samples(device.sample_rate());
+
+        let mut state = self.0.state.lock().unwrap();
+        state.pre_roll.prepare(channels, pre_roll);
+        state.peak_levels = vec![0.0; channels];
+    }
+
+    fn process_block(
+        &mut self,
+        input: &InputAudioSampleBuffer<'_>,
+        _output: &mut OutputAudioSampleBuffer<'_>,
+    ) {
+        let Ok(mut state) = self.0.state.try_lock() else {
+            self.record_dropout(input.samples());
+            return;
+        };
+
+        for channel in 0..input.channels().min(state.peak_levels.len()) {
+            let peak = input[channel]
+                .iter()
+                .fold(0.0_f32, |peak, sample| peak.max(sample.abs()));
+            state.peak_levels[channel] = state.peak_levels[channel].max(peak);
+        }
+
+        if state.paused {
+            return;
+        }
+
+        let state = &mut *state;
+        match &mut state.writer {
+            Some(writer) => {
+                if let Ok(true) = writer.write_input(input) {
+                    state.recorded_samples += input.samples() as u64;
+                } else {
+                    self.record_dropout(input.samples());
+                }
+            }
+            None => state.pre_roll.push(input),
+        }
+    }
+
+    fn stopped(&mut self) {}
+}
+
+/// Plays audio files through an audio device.
+///
+/// The player reads the file ahead on a background thread and resamples it to the device's
+/// sample rate. The player can be cloned to control it from several threads.
+#[derive(Clone)]
+pub struct AudioFilePlayer(Arc<Player>);
+
+struct Player {
+    format_manager: Mutex<AudioFormatManager>,
+    state: Mutex<PlayerState>,
+}
+
+struct PlayerState {
+    player: UniquePtr<juce::AudioFilePlayer>,
+    on_finished: Option<Box<dyn FnMut() + Send>>,
+}
+
+unsafe impl Send for PlayerState {}
+
+impl Default for AudioFilePlayer {
+    fn default() -> Self {
+        Self::create(SharedPtr::null())
+    }
+}
+
+impl AudioFilePlayer {
+    /// Create a player that can play any of the basic formats.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a player that reads ahead on the given thread.
+    pub fn with_thread(thread: &TimeSliceThread) -> Self {
+        Self::create(thread.as_juce())
+    }
+
+    fn create(thread: SharedPtr<juce::TimeSliceThread>) -> Self {
+        Self(Arc::new(Player {
+            format_manager: Mutex::new(AudioFormatManager::with_basic_formats()),
+            state: Mutex::new(PlayerState {
+                player: juce::create_audio_file_player(thread),
+                on_finished: None,
+            }),
+        }))
+    }
+
+    /// Register the player's callback with a device manager.
+    ///
+    /// The callback is removed when the returned handle is dropped.
+    pub fn register<'a>(
+        &self,
+        device_manager: &'a mut AudioDeviceManager<'_>,
+    ) -> AudioCallbackHandle<'a> {
+        device_manager.add_audio_callback(self.callback())
+    }
+
+    /// The player's audio callback, for registering with a device manually.
+    pub fn callback(&self) -> AudioFilePlayerCallback {
+        AudioFilePlayerCallback(Arc::clone(&self.0))
+    }
+
+    /// Load an audio file, stopping the file that was playing.
+    ///
+    /// Returns false if the file can't be opened.
+    pub fn load(&self, path: impl AsRef<Path>) -> bool {
+        let reader = self
+            .0
+            .format_manager
+            .lock()
+            .unwrap()
+            .create_reader_for_file(path);
+
+        match reader {
+            Some(reader) => {
+                self.load_reader(reader);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Load the samples from a reader, stopping the file that was playing.
+    pub fn load_reader(&self, reader: AudioFormatReader) {
+        self.0
+            .state
+            .lock()
+            .unwrap()
+            .player
+            .pin_mut()
+            .set_reader(reader.0);
+    }
+
+    /// Start playing from the current position, or from the start if the file has finished.
+    pub fn play(&self) {
+        let mut state = self.0.state.lock().unwrap();
+
+        if state.player.has_stream_finished() {
+            state.player.pin_mut().set_position(0.0);
+        }
+
+        state.player.pin_mut().start();
+    }
+
+    /// Stop playing, keeping the current position.
+    pub fn pause(&self) {
+        self.0.state.lock().unwrap().player.pin_mut().stop();
+    }
+
+    /// Returns true if playing.
+    pub fn is_playing(&self) -> bool {
+        self.0.state.lock().unwrap().player.is_playing()
+    }
+
+    /// Move the playback position.
+    pub fn seek(&self, position: Duration) {
+        self.0
+            .state
+            .lock()
+            .unwrap()
+            .player
+            .pin_mut()
+            .set_position(position.as_secs_f64());
+    }
+
+    /// The playback position.
+    pub fn position(&self) -> Duration {
+        let position = self.0.state.lock().unwrap().player.get_current_position();
+        Duration::from_secs_f64(position.max(0.0))
+    }
+
+    /// The length of the loaded file.
+    pub fn length(&self) -> Duration {
+        let length = self.0.state.lock().unwrap().player.get_length_in_seconds();
+        Duration::from_secs_f64(length.max(0.0))
+    }
+
+    /// Set the gain applied to the file, where 1.0 is unity gain.
+    pub fn set_gain(&self, gain: f32) {
+        self.0.state.lock().unwrap().player.pin_mut().set_gain(gain);
+    }
+
+    /// The gain applied to the file.
+    pub fn gain(&self) -> f32 {
+        self.0.state.lock().unwrap().player.get_gain()
+    }
+
+    /// Set whether files loop when they reach the end.
+    ///
+    /// The setting applies to the loaded file and any file loaded later.
+    pub fn set_looping(&self, looping: bool) {
+        self.0
+            .state
+            .lock()
+            .unwrap()
+            .player
+            .pin_mut()
+            .set_looping(looping);
+    }
+
+    /// Set a callback that is called when a file finishes playing.
+    ///
+    /// The callback is called on the audio thread, so it should return quickly without blocking.
+    pub fn on_finished(&self, callback: impl FnMut() + Send + 'static) {
+        self.0.state.lock().unwrap().on_finished = Some(Box::new(callback));
+    }
+}
+
+/// The audio callback of an [`AudioFilePlayer`].
+pub struct AudioFilePlayerCallback(Arc<Player>);
+
+impl AudioIODeviceCallback for AudioFilePlayerCallback {
+    fn about_to_start(&mut self, device: &mut dyn AudioIODevice) {
+        let buffer_size = device.buffer_size() as i32;
+        let sample_rate = device.sample_rate();
+
+        self.0
+            .state
+            .lock()
+            .unwrap()
+            .player
+            .pin_mut()
+            .prepare_to_play(buffer_size, sample_rate);
+    }
+
+    fn process_block(
+        &mut self,
+        _input: &InputAudioSampleBuffer<'_>,
+        output: &mut OutputAudioSampleBuffer<'_>,
+    ) {
+        let Ok(mut state) = self.0.state.try_lock() else {
+            output.clear();
+            return;
+        };
+
+        let was_playing = state.player.is_playing();
+        state
+            .player
+            .pin_mut()
+            .get_next_audio_block(output.buffer.as_mut());
+
+        if was_playing && state.player.has_stream_finished() {
+            if let Some(on_finished) = &mut state.on_finished {
+                on_finished();
+            }
+        }
+    }
+
+    fn stopped(&mut self) {
+        self.0
+            .state
+            .lock()
+            .unwrap()
+            .player
+            .pin_mut()
+            .release_resources();
+    }
+}
+
+pub(crate) struct BoxedPairingDialogueCallback(Box<dyn FnOnce() + Send>);
+
+pub(crate) mod ffi {
+    use super::*;
+
+    pub mod bluetooth_midi_device_pairing_dialogue {
+        use super::*;
+
+        #[allow(clippy::boxed_local)]
+        pub fn pairing_dialogue_closed(callback: Box<BoxedPairingDialogueCallback>) {
+            (callback.0)();
+        }
+    }
+}
@@ -0,0 +1,2758 @@
+//! The essential set of basic JUCE classes, as required by all the other JUCE modules.
+
+use {
+    crate::{invalid_argument, juce, juce::StringPair, Result},
+    cxx::{SharedPtr, UniquePtr},
+    std::{
+        io::{self, ErrorKind, Read, Seek, SeekFrom, Write},
+        ops::Range,
+        path::{Path, PathBuf},
+        pin::Pin,
+        sync::{Mutex, Once, RwLock},
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    },
+};
+
+#[cfg(feature = "network")]
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Condvar},
+    thread,
+};
+
+/// An absolute time, stored as milliseconds since midnight on 1st January 1970 UTC.
+///
+/// Also provides JUCE's clocks: the millisecond counters used to timestamp MIDI messages, and the
+/// high resolution tick counter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time(i64);
+
+impl Time {
+    /// The current time.
+    pub fn now() -> Self {
+        Self(juce::current_time_millis())
+    }
+
+    /// Create a time from a number of milliseconds since the epoch.
+    pub const fn from_milliseconds(milliseconds_since_epoch: i64) -> Self {
+        Self(milliseconds_since_epoch)
+    }
+
+    /// The number of milliseconds since the epoch.
+    pub const fn to_milliseconds(self) -> i64 {
+        self.0
+    }
+
+    /// The date and time in the local time zone.
+    pub fn local_components(self) -> TimeComponents {
+        let [year, month, day_of_month, day_of_week, hours, minutes, seconds, milliseconds] =
+            juce::get_local_time_components(self.0);
+
+        TimeComponents {
+            year,
+            month: month as u32,
+            day_of_month: day_of_month as u32,
+            day_of_week: day_of_week as u32,
+            hours: hours as u32,
+            minutes: minutes as u32,
+            seconds: seconds as u32,
+            milliseconds: milliseconds as u32,
+        }
+    }
+
+    /// The offset of the local time zone from UTC in seconds, at this time.
+    pub fn utc_offset_seconds(self) -> i32 {
+        juce::get_utc_offset_seconds(self.0)
+    }
+
+    /// The name of the local time zone at this time, e.g. `GMT` or `BST`.
+    pub fn time_zone(self) -> String {
+        juce::get_time_zone(self.0)
+    }
+
+    /// Format the time in the local time zone, e.g. `1 Jan 2024 9:30:00pm`.
+    pub fn to_formatted_string(
+        self,
+        include_date: bool,
+        include_time: bool,
+        include_seconds: bool,
+        use_24_hour_clock: bool,
+    ) -> String {
+        juce::time_to_string(
+            self.0,
+            include_date,
+            include_time,
+            include_seconds,
+            use_24_hour_clock,
+        )
+    }
+
+    /// Format the time as ISO 8601 in the local time zone, e.g. `2024-01-01T21:30:00.000+01:00`.
+    pub fn to_iso8601(self, include_dividers: bool) -> String {
+        juce::time_to_iso8601(self.0, include_dividers)
+    }
+
+    /// Returns the number of milliseconds since the system started.
+    ///
+    /// The counter wraps around after about 49 days.
+    pub fn millisecond_counter() -> u32 {
+        juce::get_millisecond_counter()
+    }
+
+    /// Returns the number of milliseconds since the system started, with sub-millisecond accuracy.
+    ///
+    /// This is the clock used for the timestamps of MIDI messages.
+    pub fn millisecond_counter_hi_res() -> f64 {
+        juce::get_millisecond_counter_hi_res()
+    }
+
+    /// Returns the current value of the most accurate clock available.
+    ///
+    /// The ticks are only meaningful relative to each other, and can be converted to seconds with
+    /// [`Time::high_resolution_ticks_to_seconds`].
+    pub fn high_resolution_ticks() -> i64 {
+        juce::get_high_resolution_ticks()
+    }
+
+    /// The number of high resolution ticks per second.
+    pub fn high_resolution_ticks_per_second() -> i64 {
+        juce::get_high_resolution_ticks_per_second()
+    }
+
+    /// Convert a number of high resolution ticks to seconds.
+    pub fn high_resolution_ticks_to_seconds(ticks: i64) -> f64 {
+        juce::high_resolution_ticks_to_seconds(ticks)
+    }
+
+    /// Convert a number of seconds to high resolution ticks.
+    pub fn seconds_to_high_resolution_ticks(seconds: f64) -> i64 {
+        juce::seconds_to_high_resolution_ticks(seconds)
+    }
+}
+
+impl std::ops::Add<Duration> for Time {
+    type Output = Self;
+
+    fn add(self, duration: Duration) -> Self {
+        Self(self.0 + duration.as_millis() as i64)
+    }
+}
+
+impl std::ops::Sub<Duration> for Time {
+    type Output = Self;
+
+    fn sub(self, duration: Duration) -> Self {
+        Self(self.0 - duration.as_millis() as i64)
+    }
+}
+
+impl From<SystemTime> for Time {
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => Self(after.as_millis() as i64),
+            Err(error) => Self(-(error.duration().as_millis() as i64)),
+        }
+    }
+}
+
+impl From<Time> for SystemTime {
+    fn from(time: Time) -> Self {
+        let offset = Duration::from_millis(time.0.unsigned_abs());
+
+        if time.0 >= 0 {
+            UNIX_EPOCH + offset
+        } else {
+            UNIX_EPOCH - offset
+        }
+    }
+}
+
+impl std::fmt::Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_iso8601(true))
+    }
+}
+
+/// The date and time of a [`Time`] in the local time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeComponents {
+    /// The year, e.g. 2024.
+    pub year: i32,
+
+    /// The month, from 0 for January to 11 for December.
+    pub month: u32,
+
+    /// The day of the month, from 1 to 31.
+    pub day_of_month: u32,
+
+    /// The day of the week, from 0 for Sunday to 6 for Saturday.
+    pub day_of_week: u32,
+
+    /// The hour, from 0 to 23.
+    pub hours: u32,
+
+    /// The minute, from 0 to 59.
+    pub minutes: u32,
+
+    /// The second, from 0 to 59.
+    pub seconds: u32,
+
+    /// The millisecond, from 0 to 999.
+    pub milliseconds: u32,
+}
+
+pub use crate::juce::CpuFeatures;
+
+/// Information about the computer and operating system the application is running on.
+pub struct SystemStats;
+
+impl SystemStats {
+    /// The name and version of the operating system, e.g. `Mac OSX 14.5.0` or `Windows 11`.
+    pub fn operating_system_name() -> String {
+        juce::get_operating_system_name()
+    }
+
+    /// Whether the operating system is 64-bit.
+    pub fn is_operating_system_64_bit() -> bool {
+        juce::is_operating_system_64_bit()
+    }
+
+    /// A description of the device, e.g. the model of a phone. Often empty on desktop computers.
+    pub fn device_description() -> String {
+        juce::get_device_description()
+    }
+
+    /// The manufacturer of the device, if it's known.
+    pub fn device_manufacturer() -> String {
+        juce::get_device_manufacturer()
+    }
+
+    /// The host name of the computer.
+    pub fn computer_name() -> String {
+        juce::get_computer_name()
+    }
+
+    /// The name the current user logged in with.
+    pub fn user_login_name() -> String {
+        juce::get_logon_name()
+    }
+
+    /// The full name of the current user, if it's known, otherwise their login name.
+    pub fn user_full_name() -> String {
+        juce::get_full_user_name()
+    }
+
+    /// The user's language as a two or three letter ISO 639 code, e.g. `en`.
+    pub fn user_language() -> String {
+        juce::get_user_language()
+    }
+
+    /// The user's region as a two letter ISO 3166 code, e.g. `GB`.
+    pub fn user_region() -> String {
+        juce::get_user_region()
+    }
+
+    /// The language of the user interface, which may include a region, e.g. `en-GB`.
+    pub fn display_language() -> String {
+        juce::get_display_language()
+    }
+
+    /// The CPU vendor, e.g. `GenuineIntel`, if it's known.
+    pub fn cpu_vendor() -> String {
+        juce::get_cpu_vendor()
+    }
+
+    /// The CPU model, if it's known.
+    pub fn cpu_model() -> String {
+        juce::get_cpu_model()
+    }
+
+    /// The approximate CPU speed in MHz, or [`None`] if it's unknown.
+    pub fn cpu_speed_in_megahertz() -> Option<u32> {
+        u32::try_from(juce::get_cpu_speed_in_megahertz())
+            .ok()
+            .filter(|speed| *speed > 0)
+    }
+
+    /// The number of logical CPU cores.
+    pub fn num_cpus() -> usize {
+        juce::get_num_cpus() as usize
+    }
+
+    /// The number of physical CPU cores.
+    pub fn num_physical_cpus() -> usize {
+        juce::get_num_physical_cpus() as usize
+    }
+
+    /// The amount of physical memory in megabytes.
+    pub fn memory_size_in_megabytes() -> usize {
+        juce::get_memory_size_in_megabytes() as usize
+    }
+
+    /// The size of a memory page in bytes.
+    pub fn page_size() -> usize {
+        juce::get_page_size() as usize
+    }
+
+    /// The SIMD instruction sets supported by the CPU.
+    pub fn cpu_features() -> CpuFeatures {
+        juce::get_cpu_features()
+    }
+}
+
+/// A well-known location on the file system, found with [`File::special_location`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecialLocation {
+    /// The user's home directory.
+    UserHomeDirectory,
+    /// The user's documents directory.
+    UserDocumentsDirectory,
+    /// The user's desktop directory.
+    UserDesktopDirectory,
+    /// The user's music directory.
+    UserMusicDirectory,
+    /// The user's movies directory.
+    UserMoviesDirectory,
+    /// The user's pictures directory.
+    UserPicturesDirectory,
+    /// The directory where applications store per-user settings, e.g. `~/Library` on macOS or
+    /// `%APPDATA%` on Windows.
+    UserApplicationDataDirectory,
+    /// The directory where applications store settings shared by all users.
+    CommonApplicationDataDirectory,
+    /// A documents directory shared by all users.
+    CommonDocumentsDirectory,
+    /// The directory for temporary files.
+    TempDirectory,
+    /// The executable or shared library that this code is running in.
+    CurrentExecutableFile,
+    /// The application bundle or executable that this code is running in.
+    CurrentApplicationFile,
+    /// The file that was invoked to launch this process, which may be a symlink.
+    InvokedExecutableFile,
+    /// The host application when this code is running as a plugin.
+    HostApplicationPath,
+    /// The directory where applications are installed, e.g. `/Applications` on macOS.
+    GlobalApplicationsDirectory,
+}
+
+/// Which kind of children [`File::child_files`] looks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChildFileType {
+    /// Only directories.
+    Directories = 1,
+    /// Only files.
+    Files = 2,
+    /// Both files and directories.
+    FilesAndDirectories = 3,
+}
+
+/// Convert a path to the UTF-8 string JUCE expects, rather than passing JUCE a lossy copy that
+/// names a different file.
+pub(crate) fn path_to_str(path: &Path) -> Result<&str> {
+    match path.to_str() {
+        Some(path) => Ok(path),
+        None => invalid_argument("path is not valid UTF-8"),
+    }
+}
+
+/// An absolute path to a file or directory, as understood by JUCE.
+///
+/// Paths are resolved the same way for every JUCE API in this crate: relative paths are taken
+/// relative to the current working directory, and the platform's separators are used. A `File`
+/// implements `AsRef<Path>`, so it can be passed anywhere a path is expected.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct File(String);
+
+impl File {
+    /// The file at `path`, resolved relative to the current working directory if it's relative.
+    ///
+    /// Returns an error if the path isn't valid UTF-8.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self(juce::get_full_path_name(path_to_str(path.as_ref())?)))
+    }
+
+    #[cfg(feature = "juce_data_structures")]
+    pub(crate) fn from_full_path(path: String) -> Self {
+        Self(path)
+    }
+
+    #[cfg(feature = "juce_data_structures")]
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// A well-known location such as the user's documents directory.
+    pub fn special_location(location: SpecialLocation) -> Self {
+        Self(juce::get_special_location(location as i32))
+    }
+
+    /// The current working directory.
+    pub fn current_working_directory() -> Self {
+        Self(juce::get_current_working_directory())
+    }
+
+    /// A file in the temporary directory with a random name ending in `suffix`, which doesn't
+    /// exist yet.
+    pub fn temp_file(suffix: &str) -> Self {
+        Self(juce::create_temp_file(suffix))
+    }
+
+    /// The absolute path of the file.
+    pub fn path(&self) -> &Path {
+        Path::new(&self.0)
+    }
+
+    /// The name of the file, including its extension.
+    pub fn file_name(&self) -> String {
+        juce::get_file_name(&self.0)
+    }
+
+    /// The name of the file without its extension.
+    pub fn file_name_without_extension(&self) -> String {
+        juce::get_file_name_without_extension(&self.0)
+    }
+
+    /// The extension of the file including the leading `.`, or an empty string if it has none.
+    pub fn extension(&self) -> String {
+        juce::get_file_extension(&self.0)
+    }
+
+    /// The directory containing this file.
+    pub fn parent_directory(&self) -> Self {
+        Self(juce::get_parent_directory(&self.0))
+    }
+
+    /// A file inside this directory. `relative_path` may contain separators and `..`.
+    pub fn child_file(&self, relative_path: &str) -> Self {
+        Self(juce::get_child_file(&self.0, relative_path))
+    }
+
+    /// A file in the same directory as this one.
+    pub fn sibling_file(&self, file_name: &str) -> Self {
+        Self(juce::get_sibling_file(&self.0, file_name))
+    }
+
+    /// This file with its extension replaced by `extension`, with or without the leading `.`.
+    pub fn with_extension(&self, extension: &str) -> Self {
+        Self(juce::with_file_extension(&self.0, extension))
+    }
+
+    /// A file inside this directory named `<prefix><suffix>` that doesn't exist yet, adding a
+    /// number to the name if needed.
+    pub fn nonexistent_child_file(&self, prefix: &str, suffix: &str) -> Self {
+        Self(juce::get_nonexistent_child_file(&self.0, prefix, suffix))
+    }
+
+    /// A file next to this one that doesn't exist yet, adding a number to this file's name if
+    /// needed.
+    pub fn nonexistent_sibling(&self) -> Self {
+        Self(juce::get_nonexistent_sibling(&self.0))
+    }
+
+    /// Whether a file or directory exists at this path.
+    pub fn exists(&self) -> bool {
+        juce::file_exists(&self.0)
+    }
+
+    /// Whether a file, rather than a directory, exists at this path.
+    pub fn exists_as_file(&self) -> bool {
+        juce::exists_as_file(&self.0)
+    }
+
+    /// Whether a directory exists at this path.
+    pub fn is_directory(&self) -> bool {
+        juce::is_directory(&self.0)
+    }
+
+    /// The size of the file in bytes, or zero if it doesn't exist.
+    pub fn size(&self) -> u64 {
+        u64::try_from(juce::get_file_size(&self.0)).unwrap_or_default()
+    }
+
+    /// Create an empty file, along with any missing parent directories. Does nothing if the file
+    /// already exists.
+    pub fn create(&self) -> Result<()> {
+        juce::create_file(&self.0)
+    }
+
+    /// Create a directory, along with any missing parent directories. Does nothing if the
+    /// directory already exists.
+    pub fn create_directory(&self) -> Result<()> {
+        juce::create_directory(&self.0)
+    }
+
+    /// Delete the file, or the directory if it's empty. Returns `true` if nothing exists at this
+    /// path afterwards.
+    pub fn delete_file(&self) -> bool {
+        juce::delete_file(&self.0)
+    }
+
+    /// Delete the file, or the directory and everything in it. Returns `true` if nothing exists
+    /// at this path afterwards.
+    pub fn delete_recursively(&self) -> bool {
+        juce::delete_recursively(&self.0)
+    }
+
+    /// Move the file or directory to the platform's trash, returning `true` if it was moved.
+    pub fn move_to_trash(&self) -> bool {
+        juce::move_to_trash(&self.0)
+    }
+
+    /// The children of this directory whose names match `wildcard`, e.g. `"*.wav"`.
+    pub fn child_files(
+        &self,
+        file_type: ChildFileType,
+        search_recursively: bool,
+        wildcard: &str,
+    ) -> Vec<Self> {
+        juce::find_child_files(&self.0, file_type as i32, search_recursively, wildcard)
+            .into_iter()
+            .map(Self)
+            .collect()
+    }
+}
+
+impl AsRef<Path> for File {
+    fn as_ref(&self) -> &Path {
+        self.path()
+    }
+}
+
+impl From<File> for PathBuf {
+    fn from(file: File) -> Self {
+        PathBuf::from(file.0)
+    }
+}
+
+/// An entry in a [`ZipFile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZipEntry {
+    /// The path of the entry within the zip file, using forward slashes.
+    pub file_name: String,
+
+    /// The size of the entry once it's uncompressed, in bytes.
+    pub uncompressed_size: u64,
+
+    /// When the entry was last modified.
+    pub modification_time: Time,
+
+    /// Whether the entry is a symbolic link.
+    pub is_symbolic_link: bool,
+}
+
+/// A zip file that can be read from.
+///
+/// The entries are read from the file or data when they're needed, rather than all at once when
+/// the zip file is opened.
+pub struct ZipFile(UniquePtr<juce::ZipFile>);
+
+unsafe impl Send for ZipFile {}
+
+impl ZipFile {
+    /// The best compression level, and the slowest.
+    pub const BEST_COMPRESSION: u32 = 9;
+
+    /// The compression level that just stores files without compressing them.
+    pub const NO_COMPRESSION: u32 = 0;
+
+    /// Open a zip file.
+    ///
+    /// Returns an error if the file doesn't exist. A file that isn't a zip file opens as an empty
+    /// zip file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        juce::open_zip_file(path_to_str(path.as_ref())?).map(Self)
+    }
+
+    /// Read a zip file held in memory.
+    pub fn from_bytes(data: impl AsRef<[u8]>) -> Self {
+        Self(juce::create_zip_file_from_data(data.as_ref()))
+    }
+
+    /// The number of entries in the zip file.
+    pub fn num_entries(&self) -> usize {
+        self.0.get_num_entries() as usize
+    }
+
+    /// Get an entry, or [`None`] if the index is out of range.
+    pub fn entry(&self, index: usize) -> Option<ZipEntry> {
+        let entry = juce::get_zip_entry(&self.0, i32::try_from(index).ok()?).ok()?;
+
+        Some(ZipEntry {
+            file_name: entry.file_name,
+            uncompressed_size: entry.uncompressed_size as u64,
+            modification_time: Time::from_milliseconds(entry.modification_time),
+            is_symbolic_link: entry.is_symbolic_link,
+        })
+    }
+
+    /// All the entries in the zip file.
+    pub fn entries(&self) -> Vec<ZipEntry> {
+        (0..self.num_entries())
+            .filter_map(|index| self.entry(index))
+            .collect()
+    }
+
+    /// The index of the entry with the given path, or [`None`] if there isn't one.
+    pub fn index_of(&self, file_name: &str, ignore_case: bool) -> Option<usize> {
+        usize::try_from(juce::get_zip_entry_index(&self.0, file_name, ignore_case)).ok()
+    }
+
+    /// Read and uncompress the contents of an entry.
+    ///
+    /// Returns an error if the index is out of range or the entry can't be read.
+    pub fn read_entry(&mut self, index: usize) -> Result<Vec<u8>> {
+        juce::read_zip_entry(self.0.pin_mut(), index.min(i32::MAX as usize) as i32)
+    }
+
+    /// Uncompress every entry into a directory, creating any subdirectories that are needed.
+    ///
+    /// If `overwrite` is false, existing files are left as they are.
+    pub fn uncompress_to(&mut self, directory: impl AsRef<Path>, overwrite: bool) -> Result<()> {
+        juce::uncompress_zip_file(
+            self.0.pin_mut(),
+            path_to_str(directory.as_ref())?,
+            overwrite,
+        )
+    }
+
+    /// Uncompress one entry into a directory, creating any subdirectories that are needed.
+    ///
+    /// If `overwrite` is false, an existing file is left as it is.
+    pub fn uncompress_entry(
+        &mut self,
+        index: usize,
+        directory: impl AsRef<Path>,
+        overwrite: bool,
+    ) -> Result<()> {
+        juce::uncompress_zip_entry(
+            self.0.pin_mut(),
+            index.min(i32::MAX as usize) as i32,
+            path_to_str(directory.as_ref())?,
+            overwrite,
+        )
+    }
+}
+
+/// Builds a zip file from files and data.
+///
+/// Nothing is compressed until the zip file is written.
+pub struct ZipFileBuilder(UniquePtr<juce::ZipFileBuilder>);
+
+unsafe impl Send for ZipFileBuilder {}
+
+impl Default for ZipFileBuilder {
+    fn default() -> Self {
+        Self(juce::create_zip_file_builder())
+    }
+}
+
+impl ZipFileBuilder {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file, stored at `stored_path` within the zip file.
+    ///
+    /// The compression level is from [`ZipFile::NO_COMPRESSION`] to
+    /// [`ZipFile::BEST_COMPRESSION`]. Returns an error if the file doesn't exist.
+    pub fn add_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        compression_level: u32,
+        stored_path: &str,
+    ) -> Result<()> {
+        juce::add_file_to_zip(
+            self.0.pin_mut(),
+            path_to_str(path.as_ref())?,
+            compression_level.min(ZipFile::BEST_COMPRESSION) as i32,
+            stored_path,
+        )
+    }
+
+    /// Add some data, stored at `stored_path` within the zip file.
+    ///
+    /// The compression level is from [`ZipFile::NO_COMPRESSION`] to
+    /// [`ZipFile::BEST_COMPRESSION`].
+    pub fn add_entry(
+        &mut self,
+        data: impl AsRef<[u8]>,
+        compression_level: u32,
+        stored_path: &str,
+        modification_time: Time,
+    ) {
+        juce::add_entry_to_zip(
+            self.0.pin_mut(),
+            data.as_ref(),
+            compression_level.min(ZipFile::BEST_COMPRESSION) as i32,
+            stored_path,
+            modification_time.to_milliseconds(),
+        );
+    }
+
+    /// Write the zip file, replacing the file if it exists.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        juce::write_zip_to_file(&self.0, path_to_str(path.as_ref())?)
+    }
+
+    /// Write the zip file to memory.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        juce::write_zip_to_memory(&self.0)
+    }
+}
+
+/// A dynamically typed value, such as a property of a
+/// [`ValueTree`](crate::juce_data_structures::ValueTree).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Var {
+    /// No value.
+    #[default]
+    Void,
+
+    /// An undefined value, as distinct from [`Var::Void`] in JavaScript-like contexts.
+    Undefined,
+
+    /// A boolean.
+    Bool(bool),
+
+    /// A 32-bit integer.
+    Int(i32),
+
+    /// A 64-bit integer.
+    Int64(i64),
+
+    /// A floating point number.
+    Double(f64),
+
+    /// A string.
+    String(String),
+
+    /// An array of values.
+    Array(Vec<Var>),
+
+    /// A block of binary data.
+    Binary(Vec<u8>),
+
+    /// An object with named properties.
+    Object(DynamicObject),
+}
+
+impl Var {
+    pub(crate) fn from_juce(var: &juce::Var) -> Self {
+        if var.is_void() {
+            Self::Void
+        } else if var.is_undefined() {
+            Self::Undefined
+        } else if var.is_bool() {
+            Self::Bool(juce::var_to_bool(var))
+        } else if var.is_int() {
+            Self::Int(juce::var_to_int(var))
+        } else if var.is_int64() {
+            Self::Int64(juce::var_to_int64(var))
+        } else if var.is_double() {
+            Self::Double(juce::var_to_double(var))
+        } else if var.is_string() {
+            Self::String(juce::var_to_string(var))
+        } else if var.is_array() {
+            Self::Array(
+                (0..juce::var_size(var))
+                    .map(|index| Self::from_juce(&juce::get_var_array_element(var, index)))
+                    .collect(),
+            )
+        } else if var.is_binary_data() {
+            Self::Binary(juce::var_to_binary(var))
+        } else if var.is_object() {
+            Self::Object(
+                (0..juce::get_var_num_properties(var))
+                    .map(|index| {
+                        (
+                            juce::get_var_property_name(var, index),
+                            Self::from_juce(&juce::get_var_property_value(var, index)),
+                        )
+                    })
+                    .collect(),
+            )
+        } else {
+            // Native methods can't be called from Rust.
+            Self::Void
+        }
+    }
+
+    pub(crate) fn to_juce(&self) -> UniquePtr<juce::Var> {
+        match self {
+            Self::Void => juce::create_void_var(),
+            Self::Undefined => juce::create_undefined_var(),
+            Self::Bool(value) => juce::var_from_bool(*value),
+            Self::Int(value) => juce::var_from_int(*value),
+            Self::Int64(value) => juce::var_from_int64(*value),
+            Self::Double(value) => juce::var_from_double(*value),
+            Self::String(value) => juce::var_from_string(value),
+            Self::Array(values) => {
+                let mut array = juce::create_array_var();
+                for value in values {
+                    juce::var_append(array.pin_mut(), &value.to_juce());
+                }
+                array
+            }
+            Self::Binary(data) => juce::var_from_binary(data),
+            Self::Object(object) => {
+                let mut var = juce::create_object_var();
+                for (name, value) in object.iter() {
+                    juce::set_var_property(var.pin_mut(), name, &value.to_juce());
+                }
+                var
+            }
+        }
+    }
+}
+
+impl Var {
+    /// The value of the property `name` if this is an object, or [`None`] otherwise.
+    pub fn property(&self, name: &str) -> Option<&Var> {
+        match self {
+            Self::Object(object) => object.get(name),
+            _ => None,
+        }
+    }
+
+    /// The element at `index` if this is an array, or [`None`] otherwise.
+    pub fn element(&self, index: usize) -> Option<&Var> {
+        match self {
+            Self::Array(values) => values.get(index),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for Var {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for Var {
+    fn from(value: i32) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<i64> for Var {
+    fn from(value: i64) -> Self {
+        Self::Int64(value)
+    }
+}
+
+impl From<f32> for Var {
+    fn from(value: f32) -> Self {
+        Self::Double(value.into())
+    }
+}
+
+impl From<f64> for Var {
+    fn from(value: f64) -> Self {
+        Self::Double(value)
+    }
+}
+
+impl From<&str> for Var {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for Var {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Vec<Var>> for Var {
+    fn from(values: Vec<Var>) -> Self {
+        Self::Array(values)
+    }
+}
+
+impl From<Vec<u8>> for Var {
+    fn from(data: Vec<u8>) -> Self {
+        Self::Binary(data)
+    }
+}
+
+impl From<DynamicObject> for Var {
+    fn from(object: DynamicObject) -> Self {
+        Self::Object(object)
+    }
+}
+
+/// The named properties of an object [`Var`], which keep the order they were added in.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DynamicObject {
+    properties: Vec<(String, Var)>,
+}
+
+impl DynamicObject {
+    /// Create an object with no properties.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of properties.
+    pub fn len(&self) -> usize {
+        self.properties.len()
+    }
+
+    /// Whether the object has no properties.
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    /// Whether the property `name` is set.
+    pub fn has_property(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// The value of the property `name`, or [`None`] if it isn't set.
+    pub fn get(&self, name: &str) -> Option<&Var> {
+        self.properties
+            .iter()
+            .find(|(property, _)| property == name)
+            .map(|(_, value)| value)
+    }
+
+    /// The value of the property `name`, or [`None`] if it isn't set.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Var> {
+        self.properties
+            .iter_mut()
+            .find(|(property, _)| property == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Set the property `name`, replacing its value if it's already set or adding it after the
+    /// other properties if it isn't.
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<Var>) {
+        let name = name.into();
+        let value = value.into();
+
+        match self.get_mut(&name) {
+            Some(existing) => *existing = value,
+            None => self.properties.push((name, value)),
+        }
+    }
+
+    /// Remove the property `name`, returning its value, or [`None`] if it isn't set.
+    pub fn remove(&mut self, name: &str) -> Option<Var> {
+        let index = self
+            .properties
+            .iter()
+            .position(|(property, _)| property == name)?;
+
+        Some(self.properties.remove(index).1)
+    }
+
+    /// The names and values of the properties, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Var)> + '_ {
+        self.properties
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+}
+
+impl<K: Into<String>, V: Into<Var>> FromIterator<(K, V)> for DynamicObject {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut object = Self::new();
+        for (name, value) in iter {
+            object.set(name, value);
+        }
+        object
+    }
+}
+
+impl IntoIterator for DynamicObject {
+    type Item = (String, Var);
+    type IntoIter = std::vec::IntoIter<(String, Var)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.properties.into_iter()
+    }
+}
+
+/// Reading and writing JSON, as used by JUCE.
+pub struct Json;
+
+impl Json {
+    /// Parse a JSON document. Objects become [`Var::Object`]s and arrays become [`Var::Array`]s.
+    ///
+    /// Returns an error describing where the document is invalid.
+    pub fn parse(text: &str) -> Result<Var> {
+        juce::parse_json(text).map(|var| Var::from_juce(&var))
+    }
+
+    /// Write `value` as a JSON document, either indented or on a single line.
+    pub fn to_string(value: &Var, all_on_one_line: bool) -> String {
+        juce::json_to_string(&value.to_juce(), all_on_one_line)
+    }
+}
+
+/// How an [`XmlElementRef`] is written as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XmlTextFormat {
+    /// Whether the document is written on a single line rather than indented.
+    pub single_line: bool,
+
+    /// Whether the document starts with an `<?xml ...?>` header.
+    pub include_header: bool,
+}
+
+impl Default for XmlTextFormat {
+    fn default() -> Self {
+        Self {
+            single_line: false,
+            include_header: true,
+        }
+    }
+}
+
+/// An XML element that owns its attributes and children, such as a parsed document.
+///
+/// Dereferences to an [`XmlElementRef`], which provides access to the element's contents.
+pub struct XmlElement(UniquePtr<juce::XmlElement>);
+
+unsafe impl Send for XmlElement {}
+
+impl XmlElement {
+    /// Create an element with no attributes or children. Returns an error if `tag_name` isn't a
+    /// valid XML name.
+    pub fn new(tag_name: &str) -> Result<Self> {
+        juce::create_xml_element(tag_name).map(Self)
+    }
+
+    /// Create a text element, which holds the text between tags.
+    pub fn new_text(text: &str) -> Self {
+        Self(juce::create_xml_text_element(text))
+    }
+
+    /// Parse an XML document, returning its root element or an error describing why it couldn't
+    /// be parsed.
+    pub fn parse(text: &str) -> Result<Self> {
+        juce::parse_xml(text).map(Self)
+    }
+
+    /// Parse the XML document in `file`.
+    pub fn parse_file(file: impl AsRef<Path>) -> Result<Self> {
+        juce::parse_xml_file(path_to_str(file.as_ref())?).map(Self)
+    }
+
+    pub(crate) fn from_juce(element: UniquePtr<juce::XmlElement>) -> Option<Self> {
+        (!element.is_null()).then_some(Self(element))
+    }
+
+    pub(crate) fn into_juce(self) -> UniquePtr<juce::XmlElement> {
+        self.0
+    }
+}
+
+impl Clone for XmlElement {
+    fn clone(&self) -> Self {
+        Self(juce::copy_xml_element(&self.0))
+    }
+}
+
+impl std::ops::Deref for XmlElement {
+    type Target = XmlElementRef;
+
+    fn deref(&self) -> &Self::Target {
+        XmlElementRef::from_juce(&self.0)
+    }
+}
+
+impl std::ops::DerefMut for XmlElement {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        XmlElementRef::from_juce_mut(self.0.pin_mut())
+    }
+}
+
+impl std::fmt::Debug for XmlElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl std::str::FromStr for XmlElement {
+    type Err = cxx::Exception;
+
+    fn from_str(text: &str) -> Result<Self> {
+        Self::parse(text)
+    }
+}
+
+/// An XML element inside a document, which is owned by its parent or by an [`XmlElement`].
+///
+/// An element is either a tagged element with attributes and children, or a text element holding
+/// the text between tags.
+#[repr(transparent)]
+pub struct XmlElementRef(juce::XmlElement);
+
+impl XmlElementRef {
+    fn from_juce(element: &juce::XmlElement) -> &Self {
+        // SAFETY: `XmlElementRef` is a transparent wrapper.
+        unsafe { &*(element as *const juce::XmlElement as *const Self) }
+    }
+
+    fn from_juce_mut(element: Pin<&mut juce::XmlElement>) -> &mut Self {
+        // SAFETY: `XmlElementRef` is a transparent wrapper around a zero-sized opaque type, so
+        // nothing can be moved out of the returned reference.
+        unsafe { &mut *(element.get_unchecked_mut() as *mut juce::XmlElement as *mut Self) }
+    }
+
+    fn from_ptr<'a>(element: *mut juce::XmlElement) -> Option<&'a Self> {
+        unsafe { element.as_ref() }.map(Self::from_juce)
+    }
+
+    fn from_ptr_mut<'a>(element: *mut juce::XmlElement) -> Option<&'a mut Self> {
+        unsafe { element.as_mut() }
+            .map(|element| Self::from_juce_mut(unsafe { Pin::new_unchecked(element) }))
+    }
+
+    fn pin_mut(&mut self) -> Pin<&mut juce::XmlElement> {
+        unsafe { Pin::new_unchecked(&mut self.0) }
+    }
+
+    /// The name of the tag, or an empty string for a text element.
+    pub fn tag_name(&self) -> String {
+        juce::get_xml_tag_name(&self.0)
+    }
+
+    /// Whether the tag is `tag_name`.
+    pub fn has_tag_name(&self, tag_name: &str) -> bool {
+        self.tag_name() == tag_name
+    }
+
+    /// Rename the tag. Returns an error if `tag_name` isn't a valid XML name.
+    pub fn set_tag_name(&mut self, tag_name: &str) -> Result<()> {
+        juce::set_xml_tag_name(self.pin_mut(), tag_name)
+    }
+
+    /// Whether this is a text element.
+    pub fn is_text_element(&self) -> bool {
+        self.0.is_text_element()
+    }
+
+    /// The text of a text element, or an empty string for a tagged element.
+    pub fn text(&self) -> String {
+        juce::get_xml_text(&self.0)
+    }
+
+    /// Replace the text of a text element. Returns an error for a tagged element.
+    pub fn set_text(&mut self, text: &str) -> Result<()> {
+        juce::set_xml_text(self.pin_mut(), text)
+    }
+
+    /// All the text inside the element and its descendants, concatenated.
+    pub fn all_sub_text(&self) -> String {
+        juce::get_all_xml_sub_text(&self.0)
+    }
+
+    /// Append a text element.
+    pub fn add_text_element(&mut self, text: &str) {
+        juce::add_xml_text_element(self.pin_mut(), text);
+    }
+
+    /// The number of attributes.
+    pub fn num_attributes(&self) -> usize {
+        self.0.get_num_attributes() as usize
+    }
+
+    /// The names and values of the attributes, in order.
+    pub fn attributes(&self) -> Vec<(String, String)> {
+        (0..self.0.get_num_attributes())
+            .map(|index| {
+                (
+                    juce::get_xml_attribute_name(&self.0, index),
+                    juce::get_xml_attribute_value(&self.0, index),
+                )
+            })
+            .collect()
+    }
+
+    /// Whether the attribute `name` is set.
+    pub fn has_attribute(&self, name: &str) -> bool {
+        juce::xml_has_attribute(&self.0, name)
+    }
+
+    /// The value of the attribute `name`, or [`None`] if it isn't set.
+    pub fn attribute(&self, name: &str) -> Option<String> {
+        self.has_attribute(name)
+            .then(|| juce::get_xml_attribute(&self.0, name))
+    }
+
+    /// Set the attribute `name`. Returns an error if `name` isn't a valid XML name.
+    pub fn set_attribute(&mut self, name: &str, value: impl ToString) -> Result<()> {
+        juce::set_xml_attribute(self.pin_mut(), name, &value.to_string())
+    }
+
+    /// Remove the attribute `name`.
+    pub fn remove_attribute(&mut self, name: &str) {
+        juce::remove_xml_attribute(self.pin_mut(), name);
+    }
+
+    /// Remove all the attributes.
+    pub fn remove_all_attributes(&mut self) {
+        self.pin_mut().remove_all_attributes();
+    }
+
+    /// The number of children, including text elements.
+    pub fn num_children(&self) -> usize {
+        self.0.get_num_child_elements() as usize
+    }
+
+    /// The child at `index`, or [`None`] if it's out of range.
+    pub fn child(&self, index: usize) -> Option<&Self> {
+        Self::from_ptr(self.0.get_child_element(i32::try_from(index).ok()?))
+    }
+
+    /// The child at `index`, or [`None`] if it's out of range.
+    pub fn child_mut(&mut self, index: usize) -> Option<&mut Self> {
+        Self::from_ptr_mut(self.0.get_child_element(i32::try_from(index).ok()?))
+    }
+
+    /// The children, in order.
+    pub fn children(&self) -> impl Iterator<Item = &Self> + '_ {
+        (0..self.num_children()).filter_map(|index| self.child(index))
+    }
+
+    /// The first child with the tag `tag_name`, or [`None`] if there isn't one.
+    pub fn child_by_name(&self, tag_name: &str) -> Option<&Self> {
+        Self::from_ptr(juce::get_xml_child_by_name(&self.0, tag_name))
+    }
+
+    /// The first child with the tag `tag_name`, or [`None`] if there isn't one.
+    pub fn child_by_name_mut(&mut self, tag_name: &str) -> Option<&mut Self> {
+        Self::from_ptr_mut(juce::get_xml_child_by_name(&self.0, tag_name))
+    }
+
+    /// The first child whose attribute `name` is `value`, or [`None`] if there isn't one.
+    pub fn child_by_attribute(&self, name: &str, value: &str) -> Option<&Self> {
+        Self::from_ptr(juce::get_xml_child_by_attribute(&self.0, name, value))
+    }
+
+    /// Append `child`.
+    pub fn add_child(&mut self, child: XmlElement) {
+        self.insert_child(child, usize::MAX);
+    }
+
+    /// Insert `child` at `index`, or at the end if `index` is out of range.
+    pub fn insert_child(&mut self, child: XmlElement, index: usize) {
+        juce::insert_xml_child(
+            self.pin_mut(),
+            child.into_juce(),
+            i32::try_from(index).unwrap_or(-1),
+        );
+    }
+
+    /// Append a new element with the tag `tag_name`, returning it so that it can be filled in.
+    /// Returns an error if `tag_name` isn't a valid XML name.
+    pub fn create_new_child(&mut self, tag_name: &str) -> Result<&mut Self> {
+        juce::create_new_xml_child(self.pin_mut(), tag_name).map(Self::from_juce_mut)
+    }
+
+    /// Remove the child at `index`, returning it, or [`None`] if `index` is out of range.
+    pub fn remove_child(&mut self, index: usize) -> Option<XmlElement> {
+        XmlElement::from_juce(juce::remove_xml_child(
+            self.pin_mut(),
+            i32::try_from(index).ok()?,
+        ))
+    }
+
+    /// Remove all the children.
+    pub fn remove_all_children(&mut self) {
+        self.pin_mut().delete_all_child_elements();
+    }
+
+    /// Whether the two elements have the same tags, attributes, and text, and equivalent
+    /// children.
+    pub fn is_equivalent_to(
+        &self,
+        other: &XmlElementRef,
+        ignore_order_of_attributes: bool,
+    ) -> bool {
+        juce::xml_is_equivalent_to(&self.0, &other.0, ignore_order_of_attributes)
+    }
+
+    /// Copy the element and its descendants.
+    pub fn create_copy(&self) -> XmlElement {
+        XmlElement(juce::copy_xml_element(&self.0))
+    }
+
+    /// Write the element and its descendants as an XML document.
+    pub fn to_xml_string(&self, format: XmlTextFormat) -> String {
+        juce::xml_to_string(&self.0, format.single_line, format.include_header)
+    }
+
+    /// Write the element and its descendants as an XML document to `file`, replacing it.
+    pub fn write_to(&self, file: impl AsRef<Path>, format: XmlTextFormat) -> Result<()> {
+        juce::write_xml_to(
+            &self.0,
+            path_to_str(file.as_ref())?,
+            format.single_line,
+            format.include_header,
+        )
+    }
+
+    #[cfg(feature = "juce_data_structures")]
+    pub(crate) fn as_juce(&self) -> &juce::XmlElement {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for XmlElementRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_xml_string(XmlTextFormat {
+            single_line: true,
+            include_header: false,
+        }))
+    }
+}
+
+impl std::fmt::Display for XmlElementRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_xml_string(XmlTextFormat::default()))
+    }
+}
+
+/// A stream owned by JUCE that can be read from, e.g. a file being opened by a
+/// [`CustomAudioFormat`](crate::juce_audio_formats::CustomAudioFormat).
+#[cfg(feature = "juce_audio_formats")]
+pub struct JuceInputStream(UniquePtr<juce::InputStream>);
+
+#[cfg(feature = "juce_audio_formats")]
+unsafe impl Send for JuceInputStream {}
+
+#[cfg(feature = "juce_audio_formats")]
+impl JuceInputStream {
+    pub(crate) fn new(stream: UniquePtr<juce::InputStream>) -> Self {
+        Self(stream)
+    }
+
+    /// The length of the stream in bytes, or [`None`] if it's unknown.
+    pub fn total_length(&mut self) -> Option<u64> {
+        u64::try_from(self.0.pin_mut().get_total_length()).ok()
+    }
+}
+
+#[cfg(feature = "juce_audio_formats")]
+impl Read for JuceInputStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = juce::read_input_stream(self.0.pin_mut(), buf);
+        usize::try_from(read).map_err(|_| io::Error::other("failed to read"))
+    }
+}
+
+#[cfg(feature = "juce_audio_formats")]
+impl Seek for JuceInputStream {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset as i64),
+            SeekFrom::Current(offset) => self.0.pin_mut().get_position().checked_add(offset),
+            SeekFrom::End(offset) => self
+                .total_length()
+                .and_then(|length| (length as i64).checked_add(offset)),
+        }
+        .filter(|position| *position >= 0)
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, "invalid seek position"))?;
+
+        if self.0.pin_mut().set_position(position) {
+            Ok(position as u64)
+        } else {
+            Err(io::Error::other("failed to seek"))
+        }
+    }
+}
+
+/// A stream owned by JUCE that can be written to, e.g. a file being written by a
+/// [`CustomAudioFormat`](crate::juce_audio_formats::CustomAudioFormat).
+#[cfg(feature = "juce_audio_formats")]
+pub struct JuceOutputStream(UniquePtr<juce::OutputStream>);
+
+#[cfg(feature = "juce_audio_formats")]
+unsafe impl Send for JuceOutputStream {}
+
+#[cfg(feature = "juce_audio_formats")]
+impl JuceOutputStream {
+    pub(crate) fn new(stream: UniquePtr<juce::OutputStream>) -> Self {
+        Self(stream)
+    }
+}
+
+#[cfg(feature = "juce_audio_formats")]
+impl Write for JuceOutputStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if juce::write_output_stream(self.0.pin_mut(), buf) {
+            Ok(buf.len())
+        } else {
+            Err(io::Error::other("failed to write"))
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.pin_mut().flush();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "juce_audio_formats")]
+impl Seek for JuceOutputStream {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset as i64),
+            SeekFrom::Current(offset) => self.0.pin_mut().get_position().checked_add(offset),
+            SeekFrom::End(_) => {
+                return Err(io::Error::new(
+                    ErrorKind::Unsupported,
+                    "can't seek relative to the end of an output stream",
+                ))
+            }
+        }
+        .filter(|position| *position >= 0)
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, "invalid seek position"))?;
+
+        if self.0.pin_mut().set_position(position) {
+            Ok(position as u64)
+        } else {
+            Err(io::Error::other("failed to seek"))
+        }
+    }
+}
+
+/// A resizable block of memory owned by JUCE.
+///
+/// Dereferences to a byte slice, and converts to and from `Vec<u8>`.
+pub struct MemoryBlock(UniquePtr<juce::MemoryBlock>);
+
+unsafe impl Send for MemoryBlock {}
+unsafe impl Sync for MemoryBlock {}
+
+impl MemoryBlock {
+    /// Create an empty block.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a block of `size` zeroed bytes.
+    pub fn with_size(size: usize) -> Self {
+        Self(juce::create_memory_block_with_size(size))
+    }
+
+    /// Create a block holding a copy of some data.
+    pub fn from_slice(data: &[u8]) -> Self {
+        Self(juce::create_memory_block(data))
+    }
+
+    /// Resize the block, keeping its contents up to the new size and zeroing any new bytes.
+    pub fn resize(&mut self, size: usize) {
+        self.0.pin_mut().set_size(size, true);
+    }
+
+    /// Append some data to the end of the block.
+    pub fn append(&mut self, data: &[u8]) {
+        juce::append_to_memory_block(self.0.pin_mut(), data);
+    }
+
+    /// Free the block's memory, leaving it empty.
+    pub fn clear(&mut self) {
+        self.0.pin_mut().reset();
+    }
+
+    /// Encode the block as text using JUCE's own base64 variant, which begins with the size of
+    /// the data.
+    ///
+    /// This is the format JUCE uses to store binary data in XML and `ValueTree`s. For standard
+    /// base64, use [`Base64::encode`].
+    pub fn to_base64_encoding(&self) -> String {
+        juce::memory_block_to_base64_encoding(&self.0)
+    }
+
+    /// Decode a block encoded with [`to_base64_encoding`](Self::to_base64_encoding).
+    pub fn from_base64_encoding(encoded: &str) -> Result<Self> {
+        juce::memory_block_from_base64_encoding(encoded).map(Self)
+    }
+}
+
+impl Default for MemoryBlock {
+    fn default() -> Self {
+        Self::from_slice(&[])
+    }
+}
+
+impl std::ops::Deref for MemoryBlock {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        let size = self.0.get_size();
+
+        if size == 0 {
+            return &[];
+        }
+
+        // SAFETY: the block owns `size` bytes at the pointer, which live as long as the borrow.
+        unsafe { std::slice::from_raw_parts(juce::get_memory_block_data(&self.0), size) }
+    }
+}
+
+impl std::ops::DerefMut for MemoryBlock {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        let size = self.0.get_size();
+
+        if size == 0 {
+            return &mut [];
+        }
+
+        // SAFETY: the block owns `size` bytes at the pointer, which live as long as the borrow.
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                juce::get_mutable_memory_block_data(self.0.pin_mut()),
+                size,
+            )
+        }
+    }
+}
+
+impl AsRef<[u8]> for MemoryBlock {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Clone for MemoryBlock {
+    fn clone(&self) -> Self {
+        Self(juce::copy_memory_block(&self.0))
+    }
+}
+
+impl PartialEq for MemoryBlock {
+    fn eq(&self, other: &Self) -> bool {
+        juce::memory_block_equals(&self.0, &other.0)
+    }
+}
+
+impl Eq for MemoryBlock {}
+
+impl std::fmt::Debug for MemoryBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("MemoryBlock").field(&&**self).finish()
+    }
+}
+
+impl From<&[u8]> for MemoryBlock {
+    fn from(data: &[u8]) -> Self {
+        Self::from_slice(data)
+    }
+}
+
+impl From<Vec<u8>> for MemoryBlock {
+    fn from(data: Vec<u8>) -> Self {
+        Self::from_slice(&data)
+    }
+}
+
+impl From<MemoryBlock> for Vec<u8> {
+    fn from(block: MemoryBlock) -> Self {
+        block.to_vec()
+    }
+}
+
+impl Write for MemoryBlock {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.append(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Standard base64 encoding, as described in RFC 4648.
+pub struct Base64;
+
+impl Base64 {
+    /// Encode some data as base64.
+    pub fn encode(data: impl AsRef<[u8]>) -> String {
+        juce::to_base64(data.as_ref())
+    }
+
+    /// Decode base64 text.
+    ///
+    /// Returns an error if the text isn't valid base64.
+    pub fn decode(encoded: &str) -> Result<Vec<u8>> {
+        juce::convert_from_base64(encoded)
+    }
+}
+
+/// A pseudo-random number generator.
+///
+/// Generators created with the same seed produce the same sequence of numbers on every platform,
+/// which makes them useful for reproducible tests and for porting JUCE code that relies on a seed.
+/// They aren't suitable for cryptography.
+pub struct Random(RandomInstance);
+
+enum RandomInstance {
+    Owned(UniquePtr<juce::Random>),
+    System,
+}
+
+unsafe impl Send for Random {}
+
+/// Serialises access to JUCE's system generator, which isn't thread safe.
+static SYSTEM_RANDOM: Mutex<()> = Mutex::new(());
+
+impl Random {
+    /// Create a generator with a seed based on the current time.
+    pub fn new() -> Self {
+        Self(RandomInstance::Owned(juce::create_random()))
+    }
+
+    /// Create a generator with the given seed.
+    pub fn with_seed(seed: i64) -> Self {
+        Self(RandomInstance::Owned(juce::create_random_with_seed(seed)))
+    }
+
+    /// A handle to JUCE's shared system generator, which is seeded randomly at startup.
+    ///
+    /// All the handles share the same generator, which is also used by JUCE internally.
+    pub fn system() -> Self {
+        Self(RandomInstance::System)
+    }
+
+    fn with_juce<R>(&mut self, f: impl FnOnce(Pin<&mut juce::Random>) -> R) -> R {
+        match &mut self.0 {
+            RandomInstance::Owned(random) => f(random.pin_mut()),
+            RandomInstance::System => {
+                let _lock = SYSTEM_RANDOM
+                    .lock()
+                    .unwrap_or_else(|error| error.into_inner());
+                // SAFETY: the system generator lives for the lifetime of the program, and the lock
+                // prevents other handles from using it at the same time.
+                f(unsafe { Pin::new_unchecked(&mut *juce::get_system_random()) })
+            }
+        }
+    }
+
+    /// The current seed.
+    pub fn seed(&self) -> i64 {
+        match &self.0 {
+            RandomInstance::Owned(random) => random.get_seed(),
+            RandomInstance::System => {
+                let _lock = SYSTEM_RANDOM
+                    .lock()
+                    .unwrap_or_else(|error| error.into_inner());
+                // SAFETY: see `with_juce`.
+                unsafe { &*juce::get_system_random() }.get_seed()
+            }
+        }
+    }
+
+    /// Reset the generator with a new seed.
+    ///
+    /// Has no effect on the [system](Self::system) generator, because reseeding it would make
+    /// JUCE's own use of it predictable. Use [`combine_seed`](Self::combine_seed) instead.
+    pub fn set_seed(&mut self, seed: i64) {
+        if let RandomInstance::Owned(random) = &mut self.0 {
+            random.pin_mut().set_seed(seed);
+        }
+    }
+
+    /// Merge a value into the current seed.
+    pub fn combine_seed(&mut self, seed: i64) {
+        self.with_juce(|random| random.combine_seed(seed));
+    }
+
+    /// Reset the generator with a seed based on the current time and other sources of entropy.
+    pub fn set_seed_randomly(&mut self) {
+        self.with_juce(|random| random.set_seed_randomly());
+    }
+
+    /// A random integer, which may be negative.
+    pub fn next_int(&mut self) -> i32 {
+        self.with_juce(|random| random.next_int())
+    }
+
+    /// A random integer in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn next_int_in_range(&mut self, range: Range<i32>) -> i32 {
+        assert!(!range.is_empty(), "range must not be empty");
+
+        // Scales a random 32-bit value to the range like JUCE does, but in 64 bits so that ranges
+        // wider than `i32::MAX` don't overflow.
+        let width = (i64::from(range.end) - i64::from(range.start)) as u64;
+        let offset = (u64::from(self.next_int() as u32) * width) >> 32;
+        (i64::from(range.start) + offset as i64) as i32
+    }
+
+    /// A random 64-bit integer, which may be negative.
+    pub fn next_int64(&mut self) -> i64 {
+        self.with_juce(|random| random.next_int64())
+    }
+
+    /// A random number between 0.0 and 1.0, excluding 1.0.
+    pub fn next_float(&mut self) -> f32 {
+        self.with_juce(|random| random.next_float())
+    }
+
+    /// A random number between 0.0 and 1.0, excluding 1.0.
+    pub fn next_double(&mut self) -> f64 {
+        self.with_juce(|random| random.next_double())
+    }
+
+    /// A random boolean.
+    pub fn next_bool(&mut self) -> bool {
+        self.with_juce(|random| random.next_bool())
+    }
+
+    /// Fill a buffer with random bytes.
+    pub fn fill_bytes(&mut self, buffer: &mut [u8]) {
+        self.with_juce(|random| juce::fill_bits_randomly(random, buffer));
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Random {
+    /// For an owned generator, creates a generator with the same seed, which produces the same
+    /// sequence of numbers. For the system generator, creates another handle to it.
+    fn clone(&self) -> Self {
+        match &self.0 {
+            RandomInstance::Owned(random) => Self(RandomInstance::Owned(juce::copy_random(random))),
+            RandomInstance::System => Self::system(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Random {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            RandomInstance::Owned(_) => f
+                .debug_struct("Random")
+                .field("seed", &self.seed())
+                .finish(),
+            RandomInstance::System => f.write_str("Random::System"),
+        }
+    }
+}
+
+/// A 128-bit universally unique identifier.
+///
+/// UUIDs are written as 32 hexadecimal digits, optionally with dashes in the standard
+/// `8-4-4-4-12` places.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uuid([u8; 16]);
+
+impl Uuid {
+    /// Generate a new random UUID.
+    pub fn new() -> Self {
+        Self(juce::create_uuid())
+    }
+
+    /// The null UUID, with every byte set to zero.
+    pub const fn null() -> Self {
+        Self([0; 16])
+    }
+
+    /// Create a UUID from its raw bytes.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    /// Parse a UUID from a string of 32 hexadecimal digits, with or without dashes.
+    pub fn parse(text: &str) -> Result<Self> {
+        juce::uuid_from_string(text).map(Self)
+    }
+
+    /// The raw bytes of the UUID.
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Whether this is the null UUID.
+    pub fn is_null(&self) -> bool {
+        self.0 == [0; 16]
+    }
+
+    /// The UUID as 32 hexadecimal digits without dashes.
+    pub fn to_simple_string(&self) -> String {
+        juce::uuid_to_string(self.0)
+    }
+}
+
+impl std::fmt::Debug for Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Uuid").field(&self.to_string()).finish()
+    }
+}
+
+impl std::fmt::Display for Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&juce::uuid_to_dashed_string(self.0))
+    }
+}
+
+impl std::str::FromStr for Uuid {
+    type Err = cxx::Exception;
+
+    fn from_str(text: &str) -> Result<Self> {
+        Self::parse(text)
+    }
+}
+
+impl From<[u8; 16]> for Uuid {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Uuid> for [u8; 16] {
+    fn from(uuid: Uuid) -> Self {
+        uuid.0
+    }
+}
+
+/// A uniform resource locator, e.g. `https://example.com/path?key=value`.
+///
+/// GET parameters and POST data are stored separately from the rest of the address, so they can
+/// be added without worrying about escaping.
+pub struct Url(UniquePtr<juce::Url>);
+
+unsafe impl Send for Url {}
+
+impl Url {
+    /// Parse a URL from a string.
+    ///
+    /// Any GET parameters in the string are split out and unescaped. Use [`Url::is_well_formed`]
+    /// to check whether the string was a valid URL.
+    pub fn new(url: &str) -> Self {
+        Self(juce::create_url(url))
+    }
+
+    /// Whether the URL appears to be valid.
+    pub fn is_well_formed(&self) -> bool {
+        self.0.is_well_formed()
+    }
+
+    /// Whether the URL refers to a local file, i.e. uses the `file` scheme.
+    pub fn is_local_file(&self) -> bool {
+        self.0.is_local_file()
+    }
+
+    /// The scheme, e.g. `https`.
+    pub fn scheme(&self) -> String {
+        juce::get_url_scheme(&self.0)
+    }
+
+    /// The domain, e.g. `example.com`.
+    pub fn domain(&self) -> String {
+        juce::get_url_domain(&self.0)
+    }
+
+    /// The path after the domain, without a leading slash or any GET parameters.
+    pub fn sub_path(&self) -> String {
+        juce::get_url_sub_path(&self.0)
+    }
+
+    /// The last section of the path.
+    pub fn file_name(&self) -> String {
+        juce::get_url_file_name(&self.0)
+    }
+
+    /// The explicit port number, or [`None`] if the URL doesn't specify one.
+    pub fn port(&self) -> Option<u16> {
+        u16::try_from(self.0.get_port())
+            .ok()
+            .filter(|port| *port != 0)
+    }
+
+    /// The GET parameters, in the order they were added.
+    pub fn parameters(&self) -> Vec<(String, String)> {
+        juce::get_url_parameters(&self.0)
+            .into_iter()
+            .map(|StringPair { key, value }| (key, value))
+            .collect()
+    }
+
+    /// A copy of this URL with a GET parameter added.
+    pub fn with_parameter(&self, name: &str, value: &str) -> Self {
+        Self(juce::url_with_parameter(&self.0, name, value))
+    }
+
+    /// A copy of this URL with some data to send in the body of a POST request.
+    pub fn with_post_data(&self, data: impl AsRef<[u8]>) -> Self {
+        Self(juce::url_with_post_data(&self.0, data.as_ref()))
+    }
+
+    /// The data that will be sent in the body of a POST request.
+    pub fn post_data(&self) -> Vec<u8> {
+        juce::get_url_post_data(&self.0)
+    }
+
+    /// A URL with a path appended to this one's.
+    pub fn child(&self, sub_path: &str) -> Self {
+        Self(juce::get_child_url(&self.0, sub_path))
+    }
+
+    /// A URL with the last section of the path removed.
+    pub fn parent(&self) -> Self {
+        Self(juce::get_parent_url(&self.0))
+    }
+
+    /// The URL as a string, optionally including the GET parameters.
+    pub fn to_string_with_parameters(&self, include_parameters: bool) -> String {
+        juce::url_to_string(&self.0, include_parameters)
+    }
+}
+
+impl Clone for Url {
+    fn clone(&self) -> Self {
+        Self(juce::copy_url(&self.0))
+    }
+}
+
+impl PartialEq for Url {
+    fn eq(&self, other: &Self) -> bool {
+        juce::url_equals(&self.0, &other.0)
+    }
+}
+
+impl std::fmt::Debug for Url {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Url").field(&self.to_string()).finish()
+    }
+}
+
+impl std::fmt::Display for Url {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_string_with_parameters(true))
+    }
+}
+
+impl From<&str> for Url {
+    fn from(url: &str) -> Self {
+        Self::new(url)
+    }
+}
+
+/// An HTTP(S) request, used to open a [`WebInputStream`].
+///
+/// GET requests send the URL's parameters in the address. POST requests send them, or the URL's
+/// POST data if it has any, in the body.
+#[cfg(feature = "network")]
+#[derive(Debug, Clone)]
+pub struct WebRequest {
+    url: Url,
+    use_post: bool,
+    headers: Vec<(String, String)>,
+    request_command: Option<String>,
+    timeout: Option<Duration>,
+    max_redirects: usize,
+}
+
+#[cfg(feature = "network")]
+impl WebRequest {
+    const DEFAULT_MAX_REDIRECTS: usize = 5;
+
+    /// A GET request.
+    pub fn get(url: impl Into<Url>) -> Self {
+        Self::new(url.into(), false)
+    }
+
+    /// A POST request.
+    pub fn post(url: impl Into<Url>) -> Self {
+        Self::new(url.into(), true)
+    }
+
+    fn new(url: Url, use_post: bool) -> Self {
+        Self {
+            url,
+            use_post,
+            headers: vec![],
+            request_command: None,
+            timeout: None,
+            max_redirects: Self::DEFAULT_MAX_REDIRECTS,
+        }
+    }
+
+    /// Add a header to send with the request.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Use a request command other than `GET` or `POST`, e.g. `PUT` or `DELETE`.
+    pub fn with_request_command(mut self, command: impl Into<String>) -> Self {
+        self.request_command = Some(command.into());
+        self
+    }
+
+    /// How long to wait for the connection before giving up.
+    ///
+    /// Defaults to the operating system's timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The maximum number of redirects to follow, defaults to 5.
+    pub fn with_max_redirects(mut self, max_redirects: usize) -> Self {
+        self.max_redirects = max_redirects;
+        self
+    }
+
+    /// Connect and wait for the response headers.
+    ///
+    /// Returns an error if the connection fails or the server responds with an error status.
+    pub fn connect(&self) -> Result<WebInputStream> {
+        self.connect_with_progress(|_, _| true)
+    }
+
+    /// Connect and wait for the response headers, reporting the progress of sending the body of a
+    /// POST request.
+    ///
+    /// The callback is called with the number of bytes sent so far and the total number of bytes
+    /// to send. Return `false` from the callback to cancel the request.
+    pub fn connect_with_progress(
+        &self,
+        on_progress: impl FnMut(usize, usize) -> bool + 'static,
+    ) -> Result<WebInputStream> {
+        let headers: String = self
+            .headers
+            .iter()
+            .map(|(name, value)| format!("{name}: {value}\r\n"))
+            .collect();
+
+        let timeout_ms = self
+            .timeout
+            .map(|timeout| i32::try_from(timeout.as_millis().max(1)).unwrap_or(i32::MAX))
+            .unwrap_or(0);
+
+        juce::create_web_input_stream(
+            &self.url.0,
+            self.use_post,
+            &headers,
+            self.request_command.as_deref().unwrap_or_default(),
+            timeout_ms,
+            i32::try_from(self.max_redirects).unwrap_or(i32::MAX),
+            Box::new(BoxedPostProgressCallback(Box::new(on_progress))),
+        )
+        .map(WebInputStream)
+    }
+}
+
+/// A stream for reading the response to an HTTP(S) request.
+#[cfg(feature = "network")]
+pub struct WebInputStream(UniquePtr<juce::WebInputStream>);
+
+#[cfg(feature = "network")]
+unsafe impl Send for WebInputStream {}
+
+#[cfg(feature = "network")]
+impl WebInputStream {
+    /// Connect to a URL with a GET request.
+    ///
+    /// Returns an error if the connection fails or the server responds with an error status. Use
+    /// a [`WebRequest`] for more control over the request.
+    pub fn connect(url: &str) -> Result<Self> {
+        WebRequest::get(url).connect()
+    }
+
+    /// The headers of the response.
+    pub fn response_headers(&mut self) -> HashMap<String, String> {
+        juce::get_response_headers(self.0.pin_mut())
+            .into_iter()
+            .map(|StringPair { key, value }| (key, value))
+            .collect()
+    }
+
+    /// The length of the response in bytes, or [`None`] if the server didn't provide it.
+    pub fn total_length(&mut self) -> Option<u64> {
+        u64::try_from(self.0.pin_mut().get_total_length()).ok()
+    }
+
+    /// The HTTP status code of the response.
+    pub fn status_code(&mut self) -> i32 {
+        self.0.pin_mut().get_status_code()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Read for WebInputStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = juce::read_web_input_stream(self.0.pin_mut(), buf);
+        usize::try_from(read).map_err(|_| io::Error::other("failed to read"))
+    }
+}
+
+/// The progress of a [`ProgressiveDownload`].
+#[cfg(feature = "network")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// The number of bytes downloaded so far.
+    pub downloaded_bytes: u64,
+
+    /// The total number of bytes, or [`None`] if the server didn't provide it.
+    pub total_bytes: Option<u64>,
+
+    /// True once the download has finished or failed.
+    pub finished: bool,
+
+    /// True if the download failed before all of the data was received.
+    pub failed: bool,
+}
+
+/// A seekable stream over a URL that is downloaded on a background thread.
+///
+/// Reads return the downloaded bytes as soon as they're available, blocking only when they read
+/// past the end of what has been downloaded so far. Up to [`BUFFER_SIZE`](Self::BUFFER_SIZE) of
+/// the most recently downloaded bytes are kept in memory so the stream can be seeked backwards,
+/// which is required by most audio format readers. Reading from before the buffered bytes is an
+/// error, and the download pauses while the buffer is full of bytes that haven't been read yet.
+///
+/// Seeking relative to the end is an [`ErrorKind::Unsupported`] error if the server didn't provide
+/// the length of the data, e.g. for an internet radio stream.
+///
+/// If the download fails, reads return the bytes downloaded before the failure followed by the
+/// error. Dropping the stream cancels the download.
+#[cfg(feature = "network")]
+pub struct ProgressiveDownload {
+    download: Arc<Download>,
+    position: u64,
+}
+
+#[cfg(feature = "network")]
+#[derive(Default)]
+struct Download {
+    state: Mutex<DownloadState>,
+    changed: Condvar,
+}
+
+#[cfg(feature = "network")]
+#[derive(Default)]
+struct DownloadState {
+    data: VecDeque<u8>,
+    data_start: u64,
+    read_position: u64,
+    total_bytes: Option<u64>,
+    finished: bool,
+    error: Option<io::Error>,
+    cancelled: bool,
+    stream: Option<CancellableStream>,
+}
+
+/// The stream being read by the download thread, so that dropping the download can interrupt a
+/// read that's waiting for the server.
+#[cfg(feature = "network")]
+struct CancellableStream(*const juce::WebInputStream);
+
+#[cfg(feature = "network")]
+unsafe impl Send for CancellableStream {}
+
+#[cfg(feature = "network")]
+impl DownloadState {
+    fn downloaded_bytes(&self) -> u64 {
+        self.data_start + self.data.len() as u64
+    }
+
+    fn progress(&self) -> DownloadProgress {
+        DownloadProgress {
+            downloaded_bytes: self.downloaded_bytes(),
+            total_bytes: self.total_bytes,
+            finished: self.finished,
+            failed: self.error.is_some(),
+        }
+    }
+
+    /// Drops bytes that have already been read until there's space for another chunk, returning
+    /// whether there is.
+    fn make_space(&mut self, chunk_size: usize, buffer_size: usize) -> bool {
+        let excess = (self.data.len() + chunk_size).saturating_sub(buffer_size);
+        let read = self.read_position.saturating_sub(self.data_start);
+        let dropped = excess.min(usize::try_from(read).unwrap_or(usize::MAX));
+
+        self.data.drain(..dropped);
+        self.data_start += dropped as u64;
+
+        self.data.len() + chunk_size <= buffer_size
+    }
+}
+
+#[cfg(feature = "network")]
+impl ProgressiveDownload {
+    const CHUNK_SIZE: usize = 16 * 1024;
+
+    /// The maximum number of downloaded bytes that are kept in memory.
+    pub const BUFFER_SIZE: usize = 4 * 1024 * 1024;
+
+    /// Connect to a URL and start downloading it.
+    ///
+    /// The callback is called on the download thread each time more data has been downloaded.
+    /// Returns an error if the connection fails.
+    pub fn start(
+        url: &str,
+        on_progress: impl FnMut(DownloadProgress) + Send + 'static,
+    ) -> Result<Self> {
+        let mut stream = WebInputStream::connect(url)?;
+
+        let download = Arc::new(Download::default());
+
+        {
+            let mut state = download.state.lock().unwrap();
+            state.total_bytes = stream.total_length();
+            state.stream = Some(CancellableStream(&*stream.0));
+        }
+
+        thread::spawn({
+            let download = Arc::clone(&download);
+
+            move || {
+                Self::download(&mut stream, &download, on_progress);
+
+                // The stream mustn't be cancelled once it's been destroyed.
+                download.state.lock().unwrap().stream = None;
+                drop(stream);
+            }
+        });
+
+        Ok(Self {
+            download,
+            position: 0,
+        })
+    }
+
+    /// The progress of the download.
+    pub fn progress(&self) -> DownloadProgress {
+        self.download.state.lock().unwrap().progress()
+    }
+
+    fn download(
+        stream: &mut WebInputStream,
+        download: &Download,
+        mut on_progress: impl FnMut(DownloadProgress),
+    ) {
+        let mut chunk = vec![0; Self::CHUNK_SIZE];
+
+        loop {
+            let state = download
+                .changed
+                .wait_while(download.state.lock().unwrap(), |state| {
+                    !state.cancelled && !state.make_space(Self::CHUNK_SIZE, Self::BUFFER_SIZE)
+                })
+                .unwrap();
+
+            if state.cancelled {
+                return;
+            }
+
+            drop(state);
+
+            let result = stream.read(&mut chunk);
+
+            let progress = {
+                let mut state = download.state.lock().unwrap();
+
+                if state.cancelled {
+                    return;
+                }
+
+                match result {
+                    Ok(0) => {
+                        state.finished = true;
+
+                        if state
+                            .total_bytes
+                            .is_some_and(|total| state.downloaded_bytes() < total)
+                        {
+                            state.error = Some(io::Error::new(
+                                ErrorKind::UnexpectedEof,
+                                "the download ended before all of the data was received",
+                            ));
+                        }
+                    }
+                    Ok(read) => state.data.extend(&chunk[..read]),
+                    Err(error) => {
+                        state.finished = true;
+                        state.error = Some(error);
+                    }
+                }
+
+                download.changed.notify_all();
+                state.progress()
+            };
+
+            on_progress(progress);
+
+            if progress.finished {
+                return;
+            }
+        }
+    }
+
+    fn set_read_position(&self, state: &mut DownloadState) {
+        state.read_position = self.position;
+        self.download.changed.notify_all();
+    }
+}
+
+#[cfg(feature = "network")]
+impl Read for ProgressiveDownload {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut state = self
+            .download
+            .changed
+            .wait_while(self.download.state.lock().unwrap(), |state| {
+                !state.finished && state.downloaded_bytes() <= self.position
+            })
+            .unwrap();
+
+        let Some(offset) = self.position.checked_sub(state.data_start) else {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "the data at this position is no longer buffered",
+            ));
+        };
+
+        let (front, back) = state.data.as_slices();
+        let offset = usize::try_from(offset).unwrap_or(usize::MAX);
+        let available = match offset.checked_sub(front.len()) {
+            None => &front[offset..],
+            Some(offset) => back.get(offset..).unwrap_or_default(),
+        };
+
+        if available.is_empty() && !buf.is_empty() {
+            if let Some(error) = &state.error {
+                return Err(io::Error::new(error.kind(), error.to_string()));
+            }
+        }
+
+        let read = available.len().min(buf.len());
+        buf[..read].copy_from_slice(&available[..read]);
+        self.position += read as u64;
+        self.set_read_position(&mut state);
+
+        Ok(read)
+    }
+}
+
+#[cfg(feature = "network")]
+impl Seek for ProgressiveDownload {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let mut state = self.download.state.lock().unwrap();
+
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+            SeekFrom::End(offset) => {
+                let Some(length) = state.total_bytes else {
+                    return Err(io::Error::new(
+                        ErrorKind::Unsupported,
+                        "the server didn't provide the length of the data",
+                    ));
+                };
+
+                length.checked_add_signed(offset)
+            }
+        };
+
+        self.position = position.ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            )
+        })?;
+
+        self.set_read_position(&mut state);
+
+        Ok(self.position)
+    }
+}
+
+#[cfg(feature = "network")]
+impl Drop for ProgressiveDownload {
+    fn drop(&mut self) {
+        let mut state = self.download.state.lock().unwrap();
+        state.cancelled = true;
+
+        if let Some(CancellableStream(stream)) = state.stream {
+            juce::cancel_web_input_stream(unsafe { &*stream });
+        }
+
+        self.download.changed.notify_all();
+    }
+}
+
+/// A background thread that calls a list of [`TimeSliceClient`]s in turn.
+///
+/// A thread can be shared between any number of clients, e.g. the buffering readers, threaded
+/// writers and file players of an application, instead of each of them using its own thread.
+/// Cloning a thread returns another handle to the same thread, which stops once every handle and
+/// client has been dropped.
+#[derive(Clone)]
+pub struct TimeSliceThread(SharedPtr<juce::TimeSliceThread>);
+
+unsafe impl Send for TimeSliceThread {}
+unsafe impl Sync for TimeSliceThread {}
+
+impl TimeSliceThread {
+    /// Create and start a thread with the given name.
+    pub fn new(name: &str) -> Self {
+        Self(juce::create_time_slice_thread(name))
+    }
+
+    /// The number of clients registered with the thread.
+    pub fn num_clients(&self) -> usize {
+        juce::get_num_time_slice_clients(&self.0) as usize
+    }
+
+    /// Register a client to be called on the thread.
+    ///
+    /// The client is removed when the returned handle is dropped. Dropping the handle waits for
+    /// the client to return if it is being called.
+    #[must_use]
+    pub fn add_client(&self, client: impl TimeSliceClient + 'static) -> TimeSliceClientHandle {
+        let client: BoxedTimeSliceClient = Box::new(client);
+        TimeSliceClientHandle {
+            _handle: juce::add_time_slice_client(self.0.clone(), Box::new(client)),
+        }
+    }
+
+    #[cfg(feature = "juce_audio_formats")]
+    pub(crate) fn as_juce(&self) -> SharedPtr<juce::TimeSliceThread> {
+        self.0.clone()
+    }
+}
+
+/// A task that is called repeatedly on a [`TimeSliceThread`].
+pub trait TimeSliceClient: Send {
+    /// Do a small amount of work, and return how long to wait before being called again.
+    ///
+    /// Long-running work should be split across several calls so the thread's other clients get
+    /// a turn.
+    fn use_time_slice(&mut self) -> Duration;
+}
+
+impl<F> TimeSliceClient for F
+where
+    F: FnMut() -> Duration + Send,
+{
+    fn use_time_slice(&mut self) -> Duration {
+        self()
+    }
+}
+
+/// A handle to a [`TimeSliceClient`] registered with a [`TimeSliceThread`].
+///
+/// The client is removed from the thread when the handle is dropped.
+pub struct TimeSliceClientHandle {
+    _handle: UniquePtr<juce::TimeSliceClient>,
+}
+
+unsafe impl Send for TimeSliceClientHandle {}
+
+/// A pool of threads that run jobs as threads become available.
+///
+/// Cloning a pool returns another handle to the same pool. When the last handle is dropped, jobs
+/// that haven't started are discarded, and running jobs are asked to exit and waited for.
+#[derive(Clone)]
+pub struct ThreadPool(SharedPtr<juce::ThreadPool>);
+
+unsafe impl Send for ThreadPool {}
+unsafe impl Sync for ThreadPool {}
+
+impl ThreadPool {
+    /// Create a pool with the given number of threads.
+    ///
+    /// Returns an error if `num_threads` is zero.
+    pub fn new(num_threads: usize) -> Result<Self> {
+        juce::create_thread_pool(num_threads.min(i32::MAX as usize) as i32).map(Self)
+    }
+
+    /// Add a job to the end of the queue.
+    ///
+    /// The job is called on one of the pool's threads, with a [`ThreadPoolJob`] that can be used
+    /// to check whether the job should exit early.
+    pub fn add_job(&self, name: &str, job: impl FnOnce(&ThreadPoolJob) + Send + 'static) {
+        juce::add_thread_pool_job(
+            &self.0,
+            name,
+            Box::new(BoxedThreadPoolJob(Some(Box::new(job)))),
+        );
+    }
+
+    /// The number of jobs that are queued or running.
+    pub fn num_jobs(&self) -> usize {
+        juce::get_num_thread_pool_jobs(&self.0) as usize
+    }
+
+    /// The number of threads in the pool.
+    pub fn num_threads(&self) -> usize {
+        juce::get_num_thread_pool_threads(&self.0) as usize
+    }
+
+    /// Discard any jobs that haven't started, and wait for the running jobs to finish.
+    ///
+    /// If `interrupt_running_jobs` is true, running jobs are asked to exit. Returns false if the
+    /// running jobs didn't finish before the timeout.
+    pub fn remove_all_jobs(&self, interrupt_running_jobs: bool, timeout: Duration) -> bool {
+        juce::remove_all_thread_pool_jobs(
+            &self.0,
+            interrupt_running_jobs,
+            timeout.as_millis().min(i32::MAX as u128) as i32,
+        )
+    }
+}
+
+/// A job that is running on a [`ThreadPool`].
+#[repr(transparent)]
+pub struct ThreadPoolJob(juce::ThreadPoolJob);
+
+impl ThreadPoolJob {
+    fn from_juce(job: &juce::ThreadPoolJob) -> &Self {
+        // SAFETY: `ThreadPoolJob` is a transparent wrapper.
+        unsafe { &*(job as *const juce::ThreadPoolJob as *const Self) }
+    }
+
+    /// Whether the job has been asked to exit, e.g. because the pool is being dropped.
+    ///
+    /// Long-running jobs should check this regularly and return as soon as it is true.
+    pub fn should_exit(&self) -> bool {
+        self.0.should_exit()
+    }
+}
+
+/// Receives the messages written to JUCE's log, including JUCE's own diagnostics and failed
+/// assertions.
+pub trait Logger: Send + Sync {
+    /// Handle a message, which can be written from any thread.
+    fn log_message(&self, message: &str);
+}
+
+impl<F> Logger for F
+where
+    F: Fn(&str) + Send + Sync,
+{
+    fn log_message(&self, message: &str) {
+        self(message)
+    }
+}
+
+// JUCE only ever sees a single forwarder that reads this, so that the logger can be replaced while
+// other threads are writing to the log.
+static CURRENT_LOGGER: RwLock<Option<Box<dyn Logger>>> = RwLock::new(None);
+static INSTALL_FORWARDER: Once = Once::new();
+
+/// Send the messages written to JUCE's log to `logger`, replacing any logger set before.
+///
+/// Without a logger, JUCE writes its messages to standard error or the debugger. The logger can
+/// be replaced at any time, but not from inside [`Logger::log_message`].
+pub fn set_logger(logger: impl Logger + 'static) {
+    let previous = CURRENT_LOGGER
+        .write()
+        .unwrap_or_else(|error| error.into_inner())
+        .replace(Box::new(logger));
+
+    INSTALL_FORWARDER.call_once(juce::install_logger_forwarder);
+
+    drop(previous);
+}
+
+/// Remove the logger set with [`set_logger`], going back to JUCE's default output.
+pub fn clear_logger() {
+    let previous = CURRENT_LOGGER
+        .write()
+        .unwrap_or_else(|error| error.into_inner())
+        .take();
+
+    drop(previous);
+}
+
+/// Write a message to JUCE's log, so that it's handled alongside JUCE's own messages.
+pub fn write_to_log(message: &str) {
+    juce::write_to_log(message);
+}
+
+/// A [`Logger`] that appends messages to a file, with a timestamp and a welcome message at the
+/// start of each session.
+pub struct FileLogger(UniquePtr<juce::FileLogger>);
+
+unsafe impl Send for FileLogger {}
+unsafe impl Sync for FileLogger {}
+
+impl FileLogger {
+    /// Log to the file at `path`, creating it if needed.
+    ///
+    /// If the file is larger than `max_initial_file_size_bytes`, older messages are removed from
+    /// the start of the file first. Returns an error if the path isn't valid UTF-8.
+    pub fn new(
+        path: impl AsRef<Path>,
+        welcome_message: &str,
+        max_initial_file_size_bytes: u64,
+    ) -> Result<Self> {
+        Ok(Self(juce::create_file_logger(
+            path_to_str(path.as_ref())?,
+            welcome_message,
+            max_initial_file_size_bytes.min(i64::MAX as u64) as i64,
+        )))
+    }
+
+    /// Log to a file in the platform's usual location for application logs, e.g.
+    /// `~/Library/Logs/<sub_directory>/<file_name>` on macOS or
+    /// `%APPDATA%\<sub_directory>\<file_name>` on Windows.
+    pub fn default_app_logger(
+        sub_directory: &str,
+        file_name: &str,
+        welcome_message: &str,
+        max_initial_file_size_bytes: u64,
+    ) -> Self {
+        Self(juce::create_default_app_logger(
+            sub_directory,
+            file_name,
+            welcome_message,
+            max_initial_file_size_bytes.min(i64::MAX as u64) as i64,
+        ))
+    }
+
+    /// The file being written to.
+    pub fn log_file(&self) -> PathBuf {
+        PathBuf::from(juce::get_log_file(&self.0))
+    }
+}
+
+impl Logger for FileLogger {
+    fn log_message(&self, message: &str) {
+        juce::file_logger_log_message(&self.0, message);
+    }
+}
+
+/// Whether a log message reports a failed assertion rather than being informational.
+#[cfg_attr(not(any(feature = "log", feature = "tracing")), allow(dead_code))]
+pub(crate) fn is_assertion_failure(message: &str) -> bool {
+    message.starts_with("JUCE Assertion failure")
+}
+
+pub(crate) trait InputStream: Read + Seek + Send {}
+
+impl<T: Read + Seek + Send> InputStream for T {}
+
+pub(crate) type BoxedInputStream = Box<dyn InputStream>;
+
+#[cfg(feature = "network")]
+pub(crate) struct BoxedPostProgressCallback(Box<dyn FnMut(usize, usize) -> bool>);
+
+pub(crate) type BoxedTimeSliceClient = Box<dyn TimeSliceClient>;
+
+type ThreadPoolJobFn = Box<dyn FnOnce(&ThreadPoolJob) + Send>;
+
+pub(crate) struct BoxedThreadPoolJob(Option<ThreadPoolJobFn>);
+
+pub(crate) mod ffi {
+    use super::*;
+
+    pub mod input_stream {
+        use super::*;
+
+        pub fn input_stream_read(mut self_: Pin<&mut BoxedInputStream>, buffer: &mut [u8]) -> i32 {
+            let mut total = 0;
+
+            while total < buffer.len() {
+                match self_.read(&mut buffer[total..]) {
+                    Ok(0) => break,
+                    Ok(read) => total += read,
+                    Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                    Err(_) => break,
+                }
+            }
+
+            total as i32
+        }
+
+        pub fn input_stream_position(mut self_: Pin<&mut BoxedInputStream>) -> i64 {
+            self_
+                .stream_position()
+                .map(|position| position as i64)
+                .unwrap_or(-1)
+        }
+
+        pub fn input_stream_set_position(
+            mut self_: Pin<&mut BoxedInputStream>,
+            position: i64,
+        ) -> bool {
+            position >= 0 && self_.seek(SeekFrom::Start(position as u64)).is_ok()
+        }
+
+        pub fn input_stream_total_length(mut self_: Pin<&mut BoxedInputStream>) -> i64 {
+            let Ok(position) = self_.stream_position() else {
+                return -1;
+            };
+
+            let length = self_.seek(SeekFrom::End(0));
+            let _ = self_.seek(SeekFrom::Start(position));
+
+            length.map(|length| length as i64).unwrap_or(-1)
+        }
+    }
+
+    pub mod logger {
+        use super::*;
+
+        pub fn logger_log_message(message: &str) -> bool {
+            let logger = CURRENT_LOGGER
+                .read()
+                .unwrap_or_else(|error| error.into_inner());
+
+            match logger.as_ref() {
+                Some(logger) => {
+                    logger.log_message(message);
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    #[cfg(feature = "network")]
+    pub mod post_progress_callback {
+        use super::*;
+
+        pub fn post_progress_callback_call(
+            mut callback: Pin<&mut BoxedPostProgressCallback>,
+            bytes_sent: usize,
+            total_bytes: usize,
+        ) -> bool {
+            (callback.0)(bytes_sent, total_bytes)
+        }
+    }
+
+    pub mod time_slice_client {
+        use super::*;
+
+        pub fn time_slice_client_use_time_slice(mut client: Pin<&mut BoxedTimeSliceClient>) -> i32 {
+            client.use_time_slice().as_millis().min(i32::MAX as u128) as i32
+        }
+    }
+
+    pub mod thread_pool_job {
+        use super::*;
+
+        pub fn thread_pool_job_run(
+            mut job: Pin<&mut BoxedThreadPoolJob>,
+            context: &juce::ThreadPoolJob,
+        ) {
+            if let Some(job) = job.0.take() {
+                job(ThreadPoolJob::from_juce(context));
+            }
+        }
+    }
+}
@@ -0,0 +1,813 @@
+//! Classes for storing and persisting application state.
+
+use {
+    crate::{
+        juce,
+        juce_core::{File, Var, XmlElement, XmlElementRef},
+        Result, JUCE,
+    },
+    cxx::UniquePtr,
+    std::{fmt, marker::PhantomData, ptr::null_mut, time::Duration},
+};
+
+/// How a [`PropertiesFile`] is written to disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StorageFormat {
+    /// A binary format.
+    Binary = 0,
+
+    /// A binary format compressed with zlib.
+    CompressedBinary = 1,
+
+    /// Human readable XML.
+    #[default]
+    Xml = 2,
+}
+
+/// Where a [`PropertiesFile`] is stored and how it's saved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertiesFileOptions {
+    /// The name of the application, used for the file name.
+    pub application_name: String,
+
+    /// The extension of the file, e.g. `.settings`.
+    pub filename_suffix: String,
+
+    /// The name of the directory the file is stored in, or an empty string to use the
+    /// application name. May contain separators to create nested directories.
+    pub folder_name: String,
+
+    /// Where the file is stored inside `~/Library` on macOS: either `Application Support` or
+    /// `Preferences`.
+    pub osx_library_sub_folder: String,
+
+    /// Whether the file is shared by all users rather than belonging to the current one.
+    pub common_to_all_users: bool,
+
+    /// Whether keys are looked up without case sensitivity.
+    pub ignore_case_of_key_names: bool,
+
+    /// Whether changes are kept in memory only and never written to disk.
+    pub do_not_save: bool,
+
+    /// How long after a change the file is saved, or [`None`] to only save it when asked to or
+    /// when it's dropped. Delayed saves happen on the message thread.
+    pub save_delay: Option<Duration>,
+
+    /// The format of the file.
+    pub storage_format: StorageFormat,
+}
+
+impl Default for PropertiesFileOptions {
+    fn default() -> Self {
+        Self {
+            application_name: String::new(),
+            filename_suffix: ".settings".to_string(),
+            folder_name: String::new(),
+            osx_library_sub_folder: "Application Support".to_string(),
+            common_to_all_users: false,
+            ignore_case_of_key_names: false,
+            do_not_save: false,
+            save_delay: Some(Duration::from_secs(3)),
+            storage_format: StorageFormat::default(),
+        }
+    }
+}
+
+impl PropertiesFileOptions {
+    /// Options for storing the settings of the application named `application_name`.
+    pub fn new(application_name: impl Into<String>) -> Self {
+        Self {
+            application_name: application_name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// The platform-appropriate file these options describe, e.g.
+    /// `~/Library/Application Support/<folder>/<application>.settings` on macOS or
+    /// `%APPDATA%\<folder>\<application>.settings` on Windows.
+    pub fn default_file(&self) -> File {
+        File::from_full_path(juce::get_default_properties_file(&self.to_juce()))
+    }
+
+    fn to_juce(&self) -> UniquePtr<juce::PropertiesFileOptions> {
+        juce::create_properties_file_options(
+            &self.application_name,
+            &self.filename_suffix,
+            &self.folder_name,
+            &self.osx_library_sub_folder,
+            self.common_to_all_users,
+            self.ignore_case_of_key_names,
+            self.do_not_save,
+            self.save_delay.map_or(-1, |delay| {
+                i32::try_from(delay.as_millis()).unwrap_or(i32::MAX)
+            }),
+            self.storage_format as i32,
+        )
+    }
+}
+
+/// A file of key-value pairs, such as an application's settings.
+///
+/// Unsaved changes are saved when the file is dropped. The file stays on the thread that opened
+/// it, as delayed saves are made from a timer on the message thread.
+pub struct PropertiesFile<'juce> {
+    file: UniquePtr<juce::PropertiesFile>,
+    _juce: PhantomData<&'juce ()>,
+}
+
+impl<'juce> PropertiesFile<'juce> {
+    /// Open the file described by `options`, see [`PropertiesFileOptions::default_file`].
+    pub fn new(_juce: &'juce JUCE, options: &PropertiesFileOptions) -> Self {
+        Self::open_at("", options)
+    }
+
+    /// Open the file at `file`, using `options` for everything but its location.
+    pub fn open(_juce: &'juce JUCE, file: &File, options: &PropertiesFileOptions) -> Self {
+        Self::open_at(file.as_str(), options)
+    }
+
+    fn open_at(path: &str, options: &PropertiesFileOptions) -> Self {
+        Self {
+            file: juce::create_properties_file(path, &options.to_juce()),
+            _juce: PhantomData,
+        }
+    }
+
+    /// The file the properties are stored in.
+    pub fn file(&self) -> File {
+        File::from_full_path(juce::get_properties_file(&self.file))
+    }
+
+    /// Whether the file could be read, or didn't exist yet. Returns false if it exists but
+    /// couldn't be parsed, in which case saving would overwrite it.
+    pub fn is_valid_file(&self) -> bool {
+        self.file.is_valid_file()
+    }
+
+    /// Whether a value has been set for `key` in this file, ignoring any fallback.
+    pub fn contains_key(&self, key: &str) -> bool {
+        juce::properties_contain_key(&self.file, key)
+    }
+
+    /// The value for `key`, or [`None`] if it hasn't been set.
+    pub fn value(&self, key: &str) -> Option<String> {
+        let mut value = String::new();
+        juce::get_property_value(&self.file, key, &mut value).then_some(value)
+    }
+
+    /// The value for `key` parsed as an integer, or [`None`] if it hasn't been set or isn't a
+    /// number.
+    pub fn int_value(&self, key: &str) -> Option<i64> {
+        self.value(key)?.trim().parse().ok()
+    }
+
+    /// The value for `key` parsed as a floating point number, or [`None`] if it hasn't been set or
+    /// isn't a number.
+    pub fn double_value(&self, key: &str) -> Option<f64> {
+        self.value(key)?.trim().parse().ok()
+    }
+
+    /// The value for `key` as a boolean, or [`None`] if it hasn't been set. Values are true if
+    /// they're `true` or a non-zero number, like JUCE reads them.
+    pub fn bool_value(&self, key: &str) -> Option<bool> {
+        let value = self.value(key)?;
+        let value = value.trim();
+
+        Some(
+            value.eq_ignore_ascii_case("true")
+                || value.parse::<f64>().is_ok_and(|value| value != 0.0),
+        )
+    }
+
+    /// The XML document stored for `key`, or [`None`] if it hasn't been set or isn't XML.
+    pub fn xml_value(&self, key: &str) -> Option<String> {
+        Some(juce::get_property_xml_value(&self.file, key)).filter(|xml| !xml.is_empty())
+    }
+
+    /// The keys of all the values that have been set.
+    pub fn keys(&self) -> Vec<String> {
+        juce::get_all_property_keys(&self.file)
+    }
+
+    /// Set the value for `key`. Numbers and booleans can be stored with their string
+    /// representation.
+    pub fn set_value(&mut self, key: &str, value: impl ToString) {
+        juce::set_property_value(self.file.pin_mut(), key, &value.to_string());
+    }
+
+    /// Store an XML document for `key`, such as
+    /// [`AudioDeviceManager::state_xml`](crate::juce_audio_devices::AudioDeviceManager::state_xml).
+    ///
+    /// Returns an error if `xml` can't be parsed.
+    pub fn set_xml_value(&mut self, key: &str, xml: &str) -> Result<()> {
+        juce::set_property_xml_value(self.file.pin_mut(), key, xml)
+    }
+
+    /// Remove the value for `key`.
+    pub fn remove_value(&mut self, key: &str) {
+        juce::remove_property_value(self.file.pin_mut(), key);
+    }
+
+    /// Remove all the values.
+    pub fn clear(&mut self) {
+        self.file.pin_mut().clear();
+    }
+
+    /// Whether there are changes that haven't been saved.
+    pub fn needs_to_be_saved(&self) -> bool {
+        self.file.needs_to_be_saved()
+    }
+
+    /// Write the file, even if nothing has changed.
+    pub fn save(&mut self) -> Result<()> {
+        juce::save_properties_file(self.file.pin_mut())
+    }
+
+    /// Write the file if there are unsaved changes.
+    pub fn save_if_needed(&mut self) -> Result<()> {
+        juce::save_properties_file_if_needed(self.file.pin_mut())
+    }
+
+    /// Discard the values in memory and read the file again, returning false if it couldn't be
+    /// parsed.
+    pub fn reload(&mut self) -> bool {
+        self.file.pin_mut().reload()
+    }
+}
+
+/// The settings of an application: one [`PropertiesFile`] for the current user and one shared by
+/// all users, opened when they're first needed.
+///
+/// Values that haven't been set in the user settings are looked up in the common settings.
+pub struct ApplicationProperties<'juce> {
+    options: PropertiesFileOptions,
+    // Declared before `common` so that it's dropped first, as it falls back to the common
+    // settings.
+    user: Option<PropertiesFile<'juce>>,
+    common: Option<PropertiesFile<'juce>>,
+    common_is_read_only: Option<bool>,
+    _juce: PhantomData<&'juce ()>,
+}
+
+impl<'juce> ApplicationProperties<'juce> {
+    /// Store the settings as described by `options`. Whether the files are common to all users is
+    /// ignored.
+    pub fn new(_juce: &'juce JUCE, options: PropertiesFileOptions) -> Self {
+        Self {
+            options,
+            user: None,
+            common: None,
+            common_is_read_only: None,
+            _juce: PhantomData,
+        }
+    }
+
+    /// The options used to open the settings files.
+    pub fn storage_parameters(&self) -> &PropertiesFileOptions {
+        &self.options
+    }
+
+    /// Change where the settings are stored, closing any files that are open.
+    pub fn set_storage_parameters(&mut self, options: PropertiesFileOptions) {
+        self.close_files();
+        self.options = options;
+    }
+
+    /// The settings for the current user.
+    pub fn user_settings(&mut self) -> &mut PropertiesFile<'juce> {
+        self.open_files();
+        self.user.as_mut().unwrap()
+    }
+
+    /// The settings shared by all users.
+    ///
+    /// Unprivileged users often can't write to the common settings. In that case, if
+    /// `return_user_settings_if_read_only` is true, the user settings are returned instead.
+    pub fn common_settings(
+        &mut self,
+        return_user_settings_if_read_only: bool,
+    ) -> &mut PropertiesFile<'juce> {
+        self.open_files();
+
+        if return_user_settings_if_read_only {
+            let common = self.common.as_mut().unwrap();
+            let is_read_only = *self
+                .common_is_read_only
+                .get_or_insert_with(|| common.save().is_err());
+
+            if is_read_only {
+                return self.user.as_mut().unwrap();
+            }
+        }
+
+        self.common.as_mut().unwrap()
+    }
+
+    /// Save any unsaved changes to both files.
+    pub fn save_if_needed(&mut self) -> Result<()> {
+        let user = self.user.as_mut().map(PropertiesFile::save_if_needed);
+        let common = self.common.as_mut().map(PropertiesFile::save_if_needed);
+        user.unwrap_or(Ok(())).and(common.unwrap_or(Ok(())))
+    }
+
+    /// Save and close the files. They're opened again when they're next needed.
+    pub fn close_files(&mut self) {
+        self.user = None;
+        self.common = None;
+        self.common_is_read_only = None;
+    }
+
+    fn open_files(&mut self) {
+        let open = |common_to_all_users| {
+            PropertiesFile::open_at(
+                "",
+                &PropertiesFileOptions {
+                    common_to_all_users,
+                    ..self.options.clone()
+                },
+            )
+        };
+
+        if self.common.is_none() {
+            self.common = Some(open(true));
+        }
+
+        if self.user.is_none() {
+            let mut user = open(false);
+            juce::set_fallback_property_set(
+                user.file.pin_mut(),
+                self.common.as_mut().unwrap().file.pin_mut(),
+            );
+            self.user = Some(user);
+        }
+    }
+}
+
+/// Records changes so that they can be undone and redone, such as changes made to a [`ValueTree`].
+///
+/// Changes are grouped into transactions, which are undone and redone as a whole.
+pub struct UndoManager(UniquePtr<juce::UndoManager>);
+
+impl Default for UndoManager {
+    fn default() -> Self {
+        Self(juce::create_undo_manager())
+    }
+}
+
+impl UndoManager {
+    /// Create an [`UndoManager`] with an empty history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new transaction, so that following changes are undone separately from the
+    /// previous ones. `name` describes the transaction, e.g. for an "Undo" menu item.
+    pub fn begin_new_transaction(&mut self, name: &str) {
+        juce::begin_new_transaction(self.0.pin_mut(), name);
+    }
+
+    /// Undo the last transaction, returning `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.0.pin_mut().undo()
+    }
+
+    /// Redo the last transaction that was undone, returning `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        self.0.pin_mut().redo()
+    }
+
+    /// Whether there's a transaction to undo.
+    pub fn can_undo(&self) -> bool {
+        self.0.can_undo()
+    }
+
+    /// Whether there's a transaction to redo.
+    pub fn can_redo(&self) -> bool {
+        self.0.can_redo()
+    }
+
+    /// The name of the transaction that would be undone.
+    pub fn undo_description(&self) -> String {
+        juce::get_undo_description(&self.0)
+    }
+
+    /// The name of the transaction that would be redone.
+    pub fn redo_description(&self) -> String {
+        juce::get_redo_description(&self.0)
+    }
+
+    /// Forget all the transactions.
+    pub fn clear_undo_history(&mut self) {
+        self.0.pin_mut().clear_undo_history();
+    }
+}
+
+fn undo_manager_ptr(undo_manager: Option<&mut UndoManager>) -> *mut juce::UndoManager {
+    undo_manager.map_or(null_mut(), |undo_manager| unsafe {
+        undo_manager.0.pin_mut().get_unchecked_mut()
+    })
+}
+
+/// A tree of named nodes with properties, used by JUCE applications to store their state.
+///
+/// A `ValueTree` is a reference to a node: clones refer to the same node, so changes made through
+/// one are seen by all of them and by any [`ValueTreeListener`]s. Use [`ValueTree::create_copy`]
+/// to copy the data instead.
+///
+/// Changes can be undone by passing an [`UndoManager`] to the methods that make them.
+pub struct ValueTree(UniquePtr<juce::ValueTree>);
+
+impl ValueTree {
+    /// Create a node with no properties or children. Returns an error if `type_name` is empty.
+    pub fn new(type_name: &str) -> Result<Self> {
+        juce::create_value_tree(type_name).map(Self)
+    }
+
+    fn from_juce(tree: UniquePtr<juce::ValueTree>) -> Option<Self> {
+        tree.is_valid().then_some(Self(tree))
+    }
+
+    /// The type of the node, as passed to [`ValueTree::new`].
+    pub fn type_name(&self) -> String {
+        juce::get_value_tree_type(&self.0)
+    }
+
+    /// Copy the node along with its properties and children. Listeners aren't copied.
+    pub fn create_copy(&self) -> Self {
+        Self(juce::create_value_tree_copy(&self.0))
+    }
+
+    /// Whether the two nodes have the same type, properties, and equivalent children, even if
+    /// they're different nodes.
+    pub fn is_equivalent_to(&self, other: &ValueTree) -> bool {
+        self.0.is_equivalent_to(&other.0)
+    }
+
+    /// The number of properties.
+    pub fn num_properties(&self) -> usize {
+        self.0.get_num_properties() as usize
+    }
+
+    /// The names of the properties, in the order they were added.
+    pub fn property_names(&self) -> Vec<String> {
+        (0..self.0.get_num_properties())
+            .map(|index| juce::get_value_tree_property_name(&self.0, index))
+            .collect()
+    }
+
+    /// Whether the property `name` is set.
+    pub fn has_property(&self, name: &str) -> bool {
+        juce::value_tree_has_property(&self.0, name)
+    }
+
+    /// The value of the property `name`, or [`None`] if it isn't set.
+    pub fn property(&self, name: &str) -> Option<Var> {
+        self.has_property(name)
+            .then(|| Var::from_juce(&juce::get_value_tree_property(&self.0, name)))
+    }
+
+    /// Set the property `name`. Returns an error if `name` is empty.
+    pub fn set_property(
+        &mut self,
+        name: &str,
+        value: impl Into<Var>,
+        undo_manager: Option<&mut UndoManager>,
+    ) -> Result<()> {
+        unsafe {
+            juce::set_value_tree_property(
+                self.0.pin_mut(),
+                name,
+                &value.into().to_juce(),
+                undo_manager_ptr(undo_manager),
+            )
+        }
+    }
+
+    /// Remove the property `name`. Returns an error if `name` is empty.
+    pub fn remove_property(
+        &mut self,
+        name: &str,
+        undo_manager: Option<&mut UndoManager>,
+    ) -> Result<()> {
+        unsafe {
+            juce::remove_value_tree_property(self.0.pin_mut(), name, undo_manager_ptr(undo_manager))
+        }
+    }
+
+    /// Remove all the properties.
+    pub fn remove_all_properties(&mut self, undo_manager: Option<&mut UndoManager>) {
+        unsafe {
+            juce::remove_all_value_tree_properties(self.0.pin_mut(), undo_manager_ptr(undo_manager))
+        }
+    }
+
+    /// Replace the properties with those of `source`.
+    pub fn copy_properties_from(
+        &mut self,
+        source: &ValueTree,
+        undo_manager: Option<&mut UndoManager>,
+    ) {
+        unsafe {
+            juce::copy_value_tree_properties_from(
+                self.0.pin_mut(),
+                &source.0,
+                undo_manager_ptr(undo_manager),
+            )
+        }
+    }
+
+    /// The number of children.
+    pub fn num_children(&self) -> usize {
+        self.0.get_num_children() as usize
+    }
+
+    /// The child at `index`, or [`None`] if it's out of range.
+    pub fn child(&self, index: usize) -> Option<Self> {
+        Self::from_juce(juce::get_value_tree_child(
+            &self.0,
+            i32::try_from(index).ok()?,
+        ))
+    }
+
+    /// The children, in order.
+    pub fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        (0..self.num_children()).filter_map(|index| self.child(index))
+    }
+
+    /// The first child of type `type_name`, or [`None`] if there isn't one.
+    pub fn child_with_name(&self, type_name: &str) -> Option<Self> {
+        Self::from_juce(juce::get_value_tree_child_with_name(&self.0, type_name).ok()?)
+    }
+
+    /// The first child whose property `name` is `value`, or [`None`] if there isn't one.
+    pub fn child_with_property(&self, name: &str, value: impl Into<Var>) -> Option<Self> {
+        Self::from_juce(
+            juce::get_value_tree_child_with_property(&self.0, name, &value.into().to_juce())
+                .ok()?,
+        )
+    }
+
+    /// The first child of type `type_name`, which is added if there isn't one. Returns an error if
+    /// `type_name` is empty.
+    pub fn get_or_create_child_with_name(
+        &mut self,
+        type_name: &str,
+        undo_manager: Option<&mut UndoManager>,
+    ) -> Result<Self> {
+        unsafe {
+            juce::get_or_create_value_tree_child_with_name(
+                self.0.pin_mut(),
+                type_name,
+                undo_manager_ptr(undo_manager),
+            )
+        }
+        .map(Self)
+    }
+
+    /// Insert `child` at `index`, or at the end if `index` is out of range.
+    ///
+    /// Returns an error if `child` already has a parent, or if it's this node or one of its
+    /// ancestors.
+    pub fn add_child(
+        &mut self,
+        child: &ValueTree,
+        index: usize,
+        undo_manager: Option<&mut UndoManager>,
+    ) -> Result<()> {
+        unsafe {
+            juce::add_value_tree_child(
+                self.0.pin_mut(),
+                &child.0,
+                i32::try_from(index).unwrap_or(-1),
+                undo_manager_ptr(undo_manager),
+            )
+        }
+    }
+
+    /// Add `child` after the other children, see [`ValueTree::add_child`].
+    pub fn append_child(
+        &mut self,
+        child: &ValueTree,
+        undo_manager: Option<&mut UndoManager>,
+    ) -> Result<()> {
+        self.add_child(child, usize::MAX, undo_manager)
+    }
+
+    /// Remove the child at `index`, returning it, or [`None`] if `index` is out of range.
+    pub fn remove_child(
+        &mut self,
+        index: usize,
+        undo_manager: Option<&mut UndoManager>,
+    ) -> Option<Self> {
+        let child = self.child(index)?;
+        unsafe {
+            juce::remove_value_tree_child(
+                self.0.pin_mut(),
+                index as i32,
+                undo_manager_ptr(undo_manager),
+            )
+        }
+        Some(child)
+    }
+
+    /// Remove all the children.
+    pub fn remove_all_children(&mut self, undo_manager: Option<&mut UndoManager>) {
+        unsafe {
+            juce::remove_all_value_tree_children(self.0.pin_mut(), undo_manager_ptr(undo_manager))
+        }
+    }
+
+    /// Move the child at `current_index` to `new_index`, or to the end if `new_index` is out of
+    /// range. Does nothing if `current_index` is out of range.
+    pub fn move_child(
+        &mut self,
+        current_index: usize,
+        new_index: usize,
+        undo_manager: Option<&mut UndoManager>,
+    ) {
+        let Ok(current_index) = i32::try_from(current_index) else {
+            return;
+        };
+
+        unsafe {
+            juce::move_value_tree_child(
+                self.0.pin_mut(),
+                current_index,
+                i32::try_from(new_index).unwrap_or(-1),
+                undo_manager_ptr(undo_manager),
+            )
+        }
+    }
+
+    /// The index of `child` among the children, or [`None`] if it isn't a child of this node.
+    pub fn index_of(&self, child: &ValueTree) -> Option<usize> {
+        usize::try_from(juce::value_tree_index_of(&self.0, &child.0)).ok()
+    }
+
+    /// The parent of the node, or [`None`] if it hasn't been added to one.
+    pub fn parent(&self) -> Option<Self> {
+        Self::from_juce(juce::get_value_tree_parent(&self.0))
+    }
+
+    /// The topmost ancestor of the node, which is the node itself if it has no parent.
+    pub fn root(&self) -> Self {
+        Self(juce::get_value_tree_root(&self.0))
+    }
+
+    /// Whether the node is a descendant of `possible_ancestor`.
+    pub fn is_a_child_of(&self, possible_ancestor: &ValueTree) -> bool {
+        self.0.is_a_child_of(&possible_ancestor.0)
+    }
+
+    /// Notify `listener` of changes to this node and its descendants, until the returned handle
+    /// is dropped.
+    pub fn add_listener(
+        &self,
+        listener: impl ValueTreeListener + 'static,
+    ) -> ValueTreeListenerHandle {
+        let listener: BoxedValueTreeListener = Box::new(listener);
+        ValueTreeListenerHandle {
+            _handle: juce::add_value_tree_listener(&self.0, Box::new(listener)),
+        }
+    }
+
+    /// Serialise the tree as an XML document.
+    pub fn to_xml_string(&self) -> String {
+        juce::value_tree_to_xml_string(&self.0)
+    }
+
+    /// Read a tree serialised with [`ValueTree::to_xml_string`], or [`None`] if `xml` isn't a
+    /// valid document.
+    pub fn from_xml(xml: &str) -> Option<Self> {
+        Self::from_juce(juce::value_tree_from_xml(xml))
+    }
+
+    /// Convert the tree to an XML element, with a child element for each child node.
+    pub fn to_xml(&self) -> XmlElement {
+        XmlElement::from_juce(juce::create_value_tree_xml(&self.0))
+            .expect("valid trees can always be converted to XML")
+    }
+
+    /// Read a tree from an XML element created with [`ValueTree::to_xml`], or [`None`] if it
+    /// isn't a valid tree.
+    pub fn from_xml_element(xml: &XmlElementRef) -> Option<Self> {
+        Self::from_juce(juce::value_tree_from_xml_element(xml.as_juce()))
+    }
+
+    /// Serialise the tree in JUCE's compact binary format, which preserves the types of the
+    /// properties.
+    pub fn to_binary(&self) -> Vec<u8> {
+        juce::write_value_tree_to_binary(&self.0)
+    }
+
+    /// Read a tree serialised with [`ValueTree::to_binary`], or [`None`] if `data` isn't a valid
+    /// tree.
+    pub fn from_binary(data: &[u8]) -> Option<Self> {
+        Self::from_juce(juce::read_value_tree_from_data(data))
+    }
+}
+
+impl Clone for ValueTree {
+    /// Create another reference to the same node.
+    fn clone(&self) -> Self {
+        Self(juce::copy_value_tree(&self.0))
+    }
+}
+
+impl PartialEq for ValueTree {
+    /// Whether the two trees refer to the same node. Use [`ValueTree::is_equivalent_to`] to
+    /// compare their contents.
+    fn eq(&self, other: &Self) -> bool {
+        juce::value_trees_are_equal(&self.0, &other.0)
+    }
+}
+
+impl Eq for ValueTree {}
+
+impl fmt::Debug for ValueTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValueTree")
+            .field("type_name", &self.type_name())
+            .field("num_properties", &self.num_properties())
+            .field("num_children", &self.num_children())
+            .finish()
+    }
+}
+
+/// Receives notifications of changes to a [`ValueTree`] and its descendants.
+///
+/// Listeners are called synchronously on the thread that made the change.
+pub trait ValueTreeListener {
+    /// Called when a property of `tree` is set or removed.
+    fn property_changed(&self, _tree: &ValueTree, _property: &str) {}
+
+    /// Called when `child` is added to `parent`.
+    fn child_added(&self, _parent: &ValueTree, _child: &ValueTree) {}
+
+    /// Called when `child` is removed from `parent`, where it was at `index`.
+    fn child_removed(&self, _parent: &ValueTree, _child: &ValueTree, _index: usize) {}
+
+    /// Called when a child of `parent` moves from `old_index` to `new_index`.
+    fn child_order_changed(&self, _parent: &ValueTree, _old_index: usize, _new_index: usize) {}
+
+    /// Called when `tree` is added to or removed from a parent.
+    fn parent_changed(&self, _tree: &ValueTree) {}
+}
+
+pub(crate) type BoxedValueTreeListener = Box<dyn ValueTreeListener>;
+
+/// Keeps a [`ValueTreeListener`] registered until it's dropped.
+#[must_use]
+pub struct ValueTreeListenerHandle {
+    _handle: UniquePtr<juce::ValueTreeListenerHandle>,
+}
+
+pub(crate) mod ffi {
+    use super::*;
+
+    pub mod value_tree_listener {
+        use super::*;
+
+        pub fn value_tree_listener_property_changed(
+            self_: &BoxedValueTreeListener,
+            tree: UniquePtr<juce::ValueTree>,
+            property: &str,
+        ) {
+            self_.property_changed(&ValueTree(tree), property);
+        }
+
+        pub fn value_tree_listener_child_added(
+            self_: &BoxedValueTreeListener,
+            parent: UniquePtr<juce::ValueTree>,
+            child: UniquePtr<juce::ValueTree>,
+        ) {
+            self_.child_added(&ValueTree(parent), &ValueTree(child));
+        }
+
+        pub fn value_tree_listener_child_removed(
+            self_: &BoxedValueTreeListener,
+            parent: UniquePtr<juce::ValueTree>,
+            child: UniquePtr<juce::ValueTree>,
+            index: usize,
+        ) {
+            self_.child_removed(&ValueTree(parent), &ValueTree(child), index);
+        }
+
+        pub fn value_tree_listener_child_order_changed(
+            self_: &BoxedValueTreeListener,
+            parent: UniquePtr<juce::ValueTree>,
+            old_index: usize,
+            new_index: usize,
+        ) {
+            self_.child_order_changed(&ValueTree(parent), old_index, new_index);
+        }
+
+        pub fn value_tree_listener_parent_changed(
+            self_: &BoxedValueTreeListener,
+            tree: UniquePtr<juce::ValueTree>,
+        ) {
+            self_.parent_changed(&ValueTree(tree));
+        }
+    }
+}
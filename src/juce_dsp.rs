@@ -0,0 +1,3680 @@
+//! Classes for audio buffer manipulation, digital audio processing, filtering, oversampling, fast
+//! math functions etc.
+
+use {
+    crate::{
+        juce,
+        juce_audio_basics::{AudioBuffer, Q},
+        juce_audio_devices::{InputAudioSampleBuffer, OutputAudioSampleBuffer},
+        juce_core::path_to_str,
+        Result,
+    },
+    cxx::UniquePtr,
+    std::{any::Any, path::Path, time::Duration},
+};
+
+pub use crate::juce::{Complex, ProcessSpec, ReverbParameters};
+
+impl Complex {
+    /// Create a complex number from its real and imaginary parts.
+    pub fn new(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+
+    /// The magnitude of the number.
+    pub fn norm(&self) -> f32 {
+        self.re.hypot(self.im)
+    }
+
+    /// The phase angle of the number, in radians.
+    pub fn arg(&self) -> f32 {
+        self.im.atan2(self.re)
+    }
+}
+
+/// Performs fast Fourier transforms.
+///
+/// The transforms use the fastest engine available on the platform, e.g. vDSP on macOS, so the
+/// layout of the data matches JUCE rather than any particular Rust FFT crate.
+pub struct FFT(UniquePtr<juce::FFT>);
+
+unsafe impl Send for FFT {}
+
+impl FFT {
+    /// Create an FFT that operates on blocks of `2^order` points.
+    ///
+    /// Returns an error if the order is greater than 30.
+    pub fn new(order: u32) -> Result<Self> {
+        juce::create_fft(order.min(i32::MAX as u32) as i32).map(Self)
+    }
+
+    /// The number of points the FFT operates on.
+    pub fn size(&self) -> usize {
+        self.0.get_size() as usize
+    }
+
+    /// Perform a complex forward or inverse transform.
+    ///
+    /// The input and output must each hold at least [`FFT::size`] values. The inverse transform is
+    /// scaled so that a forward transform followed by an inverse transform returns the input.
+    pub fn perform(&self, input: &[Complex], output: &mut [Complex], inverse: bool) -> Result<()> {
+        juce::perform_fft(&self.0, input, output, inverse)
+    }
+
+    /// Perform an in-place forward transform of real-valued data.
+    ///
+    /// The data must hold at least twice [`FFT::size`] values, with the input in the first half.
+    /// The output is [`FFT::size`] complex values stored as interleaved real and imaginary parts.
+    /// If `only_calculate_non_negative_frequencies` is true, only the first `size / 2 + 1` values
+    /// are calculated.
+    pub fn perform_real_only_forward_transform(
+        &self,
+        data: &mut [f32],
+        only_calculate_non_negative_frequencies: bool,
+    ) -> Result<()> {
+        juce::perform_real_only_forward_transform(
+            &self.0,
+            data,
+            only_calculate_non_negative_frequencies,
+        )
+    }
+
+    /// Perform an in-place inverse transform to real-valued data.
+    ///
+    /// The data must hold at least twice [`FFT::size`] values, laid out as the output of
+    /// [`FFT::perform_real_only_forward_transform`]. The real output is stored in the first half.
+    pub fn perform_real_only_inverse_transform(&self, data: &mut [f32]) -> Result<()> {
+        juce::perform_real_only_inverse_transform(&self.0, data)
+    }
+
+    /// Perform an in-place forward transform of real-valued data, keeping only the magnitude of
+    /// each frequency bin.
+    ///
+    /// The data must hold at least twice [`FFT::size`] values, with the input in the first half.
+    /// The magnitudes are stored in the first half. If `ignore_negative_frequencies` is true, only
+    /// the first `size / 2 + 1` magnitudes are calculated.
+    pub fn perform_frequency_only_forward_transform(
+        &self,
+        data: &mut [f32],
+        ignore_negative_frequencies: bool,
+    ) -> Result<()> {
+        juce::perform_frequency_only_forward_transform(&self.0, data, ignore_negative_frequencies)
+    }
+}
+
+/// The shape of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowingMethod {
+    /// A rectangular window, which leaves the samples unchanged.
+    Rectangular = 0,
+
+    /// A triangular window.
+    Triangular = 1,
+
+    /// A Hann window.
+    Hann = 2,
+
+    /// A Hamming window.
+    Hamming = 3,
+
+    /// A Blackman window.
+    Blackman = 4,
+
+    /// A 4-term Blackman-Harris window.
+    BlackmanHarris = 5,
+
+    /// A flat top window.
+    FlatTop = 6,
+
+    /// A Kaiser window, shaped by the beta parameter.
+    Kaiser = 7,
+}
+
+/// Applies a window to blocks of samples, e.g. before an [`FFT`].
+///
+/// The window is calculated once and stored in a table, so applying it is cheap.
+pub struct WindowingFunction {
+    window: UniquePtr<juce::WindowingFunction>,
+    size: usize,
+    method: WindowingMethod,
+    normalise: bool,
+    beta: f32,
+}
+
+unsafe impl Send for WindowingFunction {}
+
+impl WindowingFunction {
+    /// Create a normalised window of the given size.
+    pub fn new(size: usize, method: WindowingMethod) -> Self {
+        Self {
+            window: juce::create_windowing_function(size, method as i32, true, 0.0),
+            size,
+            method,
+            normalise: true,
+            beta: 0.0,
+        }
+    }
+
+    /// Whether the window is normalised so that its samples sum to the size of the window.
+    pub fn with_normalisation(mut self, normalise: bool) -> Self {
+        self.normalise = normalise;
+        self.fill();
+        self
+    }
+
+    /// The beta parameter of a [`WindowingMethod::Kaiser`] window.
+    pub fn with_beta(mut self, beta: f32) -> Self {
+        self.beta = beta;
+        self.fill();
+        self
+    }
+
+    /// The size of the window.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The shape of the window.
+    pub fn method(&self) -> WindowingMethod {
+        self.method
+    }
+
+    /// Recalculate the window with a new size and shape.
+    pub fn set_window(&mut self, size: usize, method: WindowingMethod) {
+        self.size = size;
+        self.method = method;
+        self.fill();
+    }
+
+    /// Multiply the samples with the window.
+    ///
+    /// If there are more samples than the size of the window, the remaining samples are left
+    /// unchanged.
+    pub fn multiply_with_windowing_table(&self, samples: &mut [f32]) {
+        juce::multiply_with_windowing_table(&self.window, samples);
+    }
+
+    /// Fill a slice with a window the size of the slice.
+    pub fn fill_windowing_table(
+        samples: &mut [f32],
+        method: WindowingMethod,
+        normalise: bool,
+        beta: f32,
+    ) {
+        juce::fill_windowing_table(samples, method as i32, normalise, beta);
+    }
+
+    fn fill(&mut self) {
+        juce::fill_windowing_tables(
+            self.window.pin_mut(),
+            self.size,
+            self.method as i32,
+            self.normalise,
+            self.beta,
+        );
+    }
+}
+
+/// How an impulse response is loaded into a [`Convolution`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImpulseResponseOptions {
+    stereo: bool,
+    trim: bool,
+    normalise: bool,
+    max_length: Option<usize>,
+}
+
+impl Default for ImpulseResponseOptions {
+    fn default() -> Self {
+        Self {
+            stereo: true,
+            trim: false,
+            normalise: true,
+            max_length: None,
+        }
+    }
+}
+
+impl ImpulseResponseOptions {
+    /// Create the default options, which load a normalised stereo impulse response.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the first two channels of the impulse response are used, rather than only the
+    /// first.
+    pub fn stereo(&self) -> bool {
+        self.stereo
+    }
+
+    /// Set whether the first two channels of the impulse response are used.
+    pub fn with_stereo(mut self, stereo: bool) -> Self {
+        self.stereo = stereo;
+        self
+    }
+
+    /// Whether silence is trimmed from the start and end of the impulse response.
+    pub fn trim(&self) -> bool {
+        self.trim
+    }
+
+    /// Set whether silence is trimmed from the start and end of the impulse response.
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Whether the impulse response is normalised.
+    pub fn normalise(&self) -> bool {
+        self.normalise
+    }
+
+    /// Set whether the impulse response is normalised.
+    pub fn with_normalise(mut self, normalise: bool) -> Self {
+        self.normalise = normalise;
+        self
+    }
+
+    /// The maximum number of samples of a file to load, or [`None`] to load the whole file.
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Set the maximum number of samples of a file to load.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+}
+
+/// Convolves audio with an impulse response, e.g. for reverb or cabinet simulation.
+///
+/// Impulse responses are prepared on a background thread and crossfaded in while processing, so
+/// loading a new one doesn't interrupt the audio.
+pub struct Convolution {
+    convolution: UniquePtr<juce::Convolution>,
+    spec: Option<ProcessSpec>,
+}
+
+unsafe impl Send for Convolution {}
+
+impl Default for Convolution {
+    fn default() -> Self {
+        Self::with_latency(0)
+    }
+}
+
+impl Convolution {
+    /// Create a convolution engine with no latency.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a convolution engine that uses uniform partitioning with a fixed latency.
+    ///
+    /// Larger latencies are cheaper to process. A latency of zero uses partitions the size of the
+    /// prepared block size.
+    pub fn with_latency(latency_in_samples: usize) -> Self {
+        Self::create(latency_in_samples, 0)
+    }
+
+    /// Create a convolution engine with no latency that uses non-uniform partitioning.
+    ///
+    /// The head of the impulse response is processed in partitions of `head_size_in_samples` and
+    /// the rest in larger partitions, which is cheaper for long impulse responses.
+    pub fn with_non_uniform_partitioning(head_size_in_samples: usize) -> Self {
+        Self::create(0, head_size_in_samples.max(1))
+    }
+
+    fn create(latency: usize, head_size: usize) -> Self {
+        Self {
+            convolution: juce::create_convolution(
+                latency.min(i32::MAX as usize) as i32,
+                head_size.min(i32::MAX as usize) as i32,
+            ),
+            spec: None,
+        }
+    }
+
+    /// Load an impulse response from an audio file.
+    ///
+    /// Returns false if the file couldn't be read.
+    pub fn load_impulse_response_from_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        options: &ImpulseResponseOptions,
+    ) -> bool {
+        let Ok(path) = path_to_str(path.as_ref()) else {
+            return false;
+        };
+        juce::load_impulse_response_from_file(
+            self.convolution.pin_mut(),
+            path,
+            options.stereo,
+            options.trim,
+            options.max_length.unwrap_or(0),
+            options.normalise,
+        )
+    }
+
+    /// Load an impulse response from the contents of an audio file, e.g. one embedded with
+    /// [`include_bytes`].
+    ///
+    /// Returns false if the data couldn't be read.
+    pub fn load_impulse_response_from_data(
+        &mut self,
+        data: &[u8],
+        options: &ImpulseResponseOptions,
+    ) -> bool {
+        juce::load_impulse_response_from_data(
+            self.convolution.pin_mut(),
+            data,
+            options.stereo,
+            options.trim,
+            options.max_length.unwrap_or(0),
+            options.normalise,
+        )
+    }
+
+    /// Load an impulse response from a buffer recorded at the given sample rate.
+    ///
+    /// The impulse response is resampled if the sample rate differs from the one the engine is
+    /// prepared for. The maximum length of the options is ignored.
+    pub fn load_impulse_response(
+        &mut self,
+        buffer: &AudioBuffer,
+        sample_rate: f64,
+        options: &ImpulseResponseOptions,
+    ) {
+        juce::load_impulse_response_from_buffer(
+            self.convolution.pin_mut(),
+            &buffer.0,
+            sample_rate,
+            options.stereo,
+            options.trim,
+            options.normalise,
+        );
+    }
+
+    /// Prepare to process audio.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        juce::prepare_convolution(self.convolution.pin_mut(), spec);
+        self.spec = Some(*spec);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the engine has been prepared. Only as many channels as the engine was
+    /// prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_convolution(self.convolution.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Clear the processing state.
+    pub fn reset(&mut self) {
+        self.convolution.pin_mut().reset();
+    }
+
+    /// The length of the impulse response currently in use, in samples.
+    pub fn current_ir_size(&self) -> usize {
+        self.convolution.get_current_ir_size().max(0) as usize
+    }
+
+    /// The latency of the engine in samples.
+    pub fn latency(&self) -> usize {
+        self.convolution.get_latency().max(0) as usize
+    }
+}
+
+#[derive(Clone, Copy)]
+enum IIRDesign {
+    FirstOrderLowPass = 0,
+    FirstOrderHighPass = 1,
+    FirstOrderAllPass = 2,
+    LowPass = 3,
+    HighPass = 4,
+    BandPass = 5,
+    Notch = 6,
+    AllPass = 7,
+    LowShelf = 8,
+    HighShelf = 9,
+    PeakFilter = 10,
+}
+
+/// The coefficients of an [`IIRFilter`].
+///
+/// The coefficients are stored normalised by `a0`, e.g. `[b0, b1, b2, a1, a2]` for a second order
+/// filter. The design methods return an error if the frequency isn't between zero and half the
+/// sample rate, or the Q or gain isn't positive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IIRCoefficients(Vec<f32>);
+
+impl IIRCoefficients {
+    /// Create second order coefficients, which are normalised by `a0`.
+    pub fn from_biquad(b0: f32, b1: f32, b2: f32, a0: f32, a1: f32, a2: f32) -> Self {
+        Self(vec![b0 / a0, b1 / a0, b2 / a0, a1 / a0, a2 / a0])
+    }
+
+    /// Create first order coefficients, which are normalised by `a0`.
+    pub fn from_first_order(b0: f32, b1: f32, a0: f32, a1: f32) -> Self {
+        Self(vec![b0 / a0, b1 / a0, a1 / a0])
+    }
+
+    /// A first order low-pass filter.
+    pub fn first_order_low_pass(sample_rate: f64, frequency: f64) -> Result<Self> {
+        Self::design(
+            IIRDesign::FirstOrderLowPass,
+            sample_rate,
+            frequency,
+            Q::default(),
+            1.0,
+        )
+    }
+
+    /// A first order high-pass filter.
+    pub fn first_order_high_pass(sample_rate: f64, frequency: f64) -> Result<Self> {
+        Self::design(
+            IIRDesign::FirstOrderHighPass,
+            sample_rate,
+            frequency,
+            Q::default(),
+            1.0,
+        )
+    }
+
+    /// A first order all-pass filter.
+    pub fn first_order_all_pass(sample_rate: f64, frequency: f64) -> Result<Self> {
+        Self::design(
+            IIRDesign::FirstOrderAllPass,
+            sample_rate,
+            frequency,
+            Q::default(),
+            1.0,
+        )
+    }
+
+    /// A second order low-pass filter.
+    pub fn low_pass(sample_rate: f64, frequency: f64, q: Q) -> Result<Self> {
+        Self::design(IIRDesign::LowPass, sample_rate, frequency, q, 1.0)
+    }
+
+    /// A second order high-pass filter.
+    pub fn high_pass(sample_rate: f64, frequency: f64, q: Q) -> Result<Self> {
+        Self::design(IIRDesign::HighPass, sample_rate, frequency, q, 1.0)
+    }
+
+    /// A second order band-pass filter.
+    pub fn band_pass(sample_rate: f64, frequency: f64, q: Q) -> Result<Self> {
+        Self::design(IIRDesign::BandPass, sample_rate, frequency, q, 1.0)
+    }
+
+    /// A second order notch filter.
+    pub fn notch(sample_rate: f64, frequency: f64, q: Q) -> Result<Self> {
+        Self::design(IIRDesign::Notch, sample_rate, frequency, q, 1.0)
+    }
+
+    /// A second order all-pass filter.
+    pub fn all_pass(sample_rate: f64, frequency: f64, q: Q) -> Result<Self> {
+        Self::design(IIRDesign::AllPass, sample_rate, frequency, q, 1.0)
+    }
+
+    /// A low shelf filter, which multiplies frequencies below the cutoff by the gain factor.
+    pub fn low_shelf(sample_rate: f64, cutoff: f64, q: Q, gain_factor: f32) -> Result<Self> {
+        Self::design(IIRDesign::LowShelf, sample_rate, cutoff, q, gain_factor)
+    }
+
+    /// A high shelf filter, which multiplies frequencies above the cutoff by the gain factor.
+    pub fn high_shelf(sample_rate: f64, cutoff: f64, q: Q, gain_factor: f32) -> Result<Self> {
+        Self::design(IIRDesign::HighShelf, sample_rate, cutoff, q, gain_factor)
+    }
+
+    /// A peak filter, which multiplies frequencies around the centre frequency by the gain factor.
+    pub fn peak_filter(sample_rate: f64, frequency: f64, q: Q, gain_factor: f32) -> Result<Self> {
+        Self::design(
+            IIRDesign::PeakFilter,
+            sample_rate,
+            frequency,
+            q,
+            gain_factor,
+        )
+    }
+
+    fn design(
+        design: IIRDesign,
+        sample_rate: f64,
+        frequency: f64,
+        Q(q): Q,
+        gain_factor: f32,
+    ) -> Result<Self> {
+        juce::design_iir_coefficients(
+            design as i32,
+            sample_rate,
+            frequency as f32,
+            q as f32,
+            gain_factor,
+        )
+        .map(Self)
+    }
+
+    /// The normalised coefficients.
+    pub fn raw(&self) -> &[f32] {
+        &self.0
+    }
+
+    /// The order of the filter.
+    pub fn filter_order(&self) -> usize {
+        (self.0.len() - 1) / 2
+    }
+
+    /// The magnitude of the filter's response at a frequency.
+    pub fn magnitude_for_frequency(&self, frequency: f64, sample_rate: f64) -> f64 {
+        juce::get_iir_magnitude_for_frequency(&self.0, frequency, sample_rate)
+    }
+
+    /// The phase of the filter's response at a frequency, in radians.
+    pub fn phase_for_frequency(&self, frequency: f64, sample_rate: f64) -> f64 {
+        juce::get_iir_phase_for_frequency(&self.0, frequency, sample_rate)
+    }
+}
+
+/// An infinite impulse response filter of any order, e.g. a biquad for EQ.
+///
+/// Unlike [`juce_audio_basics::IIRFilter`](crate::juce_audio_basics::IIRFilter), this processes
+/// multi-channel buffers. Each channel has its own filter state, and all channels share the same
+/// coefficients.
+pub struct IIRFilter {
+    filter: UniquePtr<juce::IIRFilter>,
+    coefficients: IIRCoefficients,
+}
+
+unsafe impl Send for IIRFilter {}
+
+impl IIRFilter {
+    /// Create a filter with the given coefficients.
+    pub fn new(coefficients: IIRCoefficients) -> Self {
+        Self {
+            filter: juce::create_dsp_iir_filter(coefficients.raw()),
+            coefficients,
+        }
+    }
+
+    /// The coefficients of the filter.
+    pub fn coefficients(&self) -> &IIRCoefficients {
+        &self.coefficients
+    }
+
+    /// Change the coefficients of the filter, keeping its state.
+    ///
+    /// Changing to coefficients of the same order doesn't allocate, so it's safe to do while
+    /// processing.
+    pub fn set_coefficients(&mut self, coefficients: IIRCoefficients) {
+        self.filter.pin_mut().set_coefficients(coefficients.raw());
+        self.coefficients = coefficients;
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.filter.pin_mut().prepare(spec);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the filter has been prepared. Only as many channels as the filter was
+    /// prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        self.filter.pin_mut().process(buffer.buffer.as_mut());
+    }
+
+    /// Process a single sample of a channel.
+    ///
+    /// The sample is returned unchanged if the filter wasn't prepared for the channel.
+    pub fn process_sample(&mut self, channel: usize, sample: f32) -> f32 {
+        self.filter.pin_mut().process_sample(channel, sample)
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.filter.pin_mut().reset();
+    }
+}
+
+/// The coefficients of an [`FIRFilter`], i.e. its impulse response.
+///
+/// The design methods create linear-phase low-pass filters, and return an error if their
+/// parameters are out of range: the frequency must be between zero and half the sample rate, the
+/// order positive, the normalised transition width between 0 and 0.5, and the stop band
+/// attenuation between -300 and -10 dB.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FIRCoefficients(Vec<f32>);
+
+impl FIRCoefficients {
+    /// Create coefficients from an impulse response.
+    pub fn new(coefficients: Vec<f32>) -> Self {
+        Self(coefficients)
+    }
+
+    /// A low-pass filter of the given order designed with the window method.
+    ///
+    /// The beta parameter is used by [`WindowingMethod::Kaiser`] windows.
+    pub fn low_pass_window_method(
+        sample_rate: f64,
+        frequency: f64,
+        order: usize,
+        method: WindowingMethod,
+        beta: f32,
+    ) -> Result<Self> {
+        juce::design_fir_lowpass_window_method(
+            sample_rate,
+            frequency as f32,
+            order,
+            method as i32,
+            beta,
+        )
+        .map(Self)
+    }
+
+    /// A low-pass filter designed with the window method and a Kaiser window, which picks the
+    /// order needed for the given transition width and stop band attenuation.
+    pub fn low_pass_kaiser_method(
+        sample_rate: f64,
+        frequency: f64,
+        normalised_transition_width: f32,
+        amplitude_db: f32,
+    ) -> Result<Self> {
+        juce::design_fir_lowpass_kaiser_method(
+            sample_rate,
+            frequency as f32,
+            normalised_transition_width,
+            amplitude_db,
+        )
+        .map(Self)
+    }
+
+    /// A low-pass filter of the given order with a spline transition band.
+    ///
+    /// The spline must be between 1 and 4.
+    pub fn low_pass_transition_method(
+        sample_rate: f64,
+        frequency: f64,
+        order: usize,
+        normalised_transition_width: f32,
+        spline: f32,
+    ) -> Result<Self> {
+        juce::design_fir_lowpass_transition_method(
+            sample_rate,
+            frequency as f32,
+            order,
+            normalised_transition_width,
+            spline,
+        )
+        .map(Self)
+    }
+
+    /// A low-pass filter of the given order designed with the least squares method.
+    ///
+    /// The stop band weight must be between 1 and 100.
+    pub fn low_pass_least_squares_method(
+        sample_rate: f64,
+        frequency: f64,
+        order: usize,
+        normalised_transition_width: f32,
+        stop_band_weight: f32,
+    ) -> Result<Self> {
+        juce::design_fir_lowpass_least_squares_method(
+            sample_rate,
+            frequency as f32,
+            order,
+            normalised_transition_width,
+            stop_band_weight,
+        )
+        .map(Self)
+    }
+
+    /// A half band low-pass filter, with its cutoff at a quarter of the sample rate, designed
+    /// with the equiripple method.
+    pub fn half_band_equiripple_method(
+        normalised_transition_width: f32,
+        amplitude_db: f32,
+    ) -> Result<Self> {
+        juce::design_fir_lowpass_half_band_equiripple_method(
+            normalised_transition_width,
+            amplitude_db,
+        )
+        .map(Self)
+    }
+
+    /// The coefficients.
+    pub fn raw(&self) -> &[f32] {
+        &self.0
+    }
+
+    /// The order of the filter, which is one less than the number of coefficients.
+    pub fn filter_order(&self) -> usize {
+        self.0.len().saturating_sub(1)
+    }
+
+    /// The magnitude of the filter's response at a frequency.
+    pub fn magnitude_for_frequency(&self, frequency: f64, sample_rate: f64) -> f64 {
+        juce::get_fir_magnitude_for_frequency(&self.0, frequency, sample_rate)
+    }
+
+    /// The phase of the filter's response at a frequency, in radians.
+    pub fn phase_for_frequency(&self, frequency: f64, sample_rate: f64) -> f64 {
+        juce::get_fir_phase_for_frequency(&self.0, frequency, sample_rate)
+    }
+}
+
+/// A finite impulse response filter.
+///
+/// Each channel has its own filter state, and all channels share the same coefficients. Filters
+/// made by the [`FIRCoefficients`] design methods are linear-phase, so they delay the signal by
+/// half their order.
+pub struct FIRFilter {
+    filter: UniquePtr<juce::FIRFilter>,
+    coefficients: FIRCoefficients,
+}
+
+unsafe impl Send for FIRFilter {}
+
+impl FIRFilter {
+    /// Create a filter with the given coefficients.
+    pub fn new(coefficients: FIRCoefficients) -> Self {
+        Self {
+            filter: juce::create_dsp_fir_filter(coefficients.raw()),
+            coefficients,
+        }
+    }
+
+    /// The coefficients of the filter.
+    pub fn coefficients(&self) -> &FIRCoefficients {
+        &self.coefficients
+    }
+
+    /// Change the coefficients of the filter.
+    ///
+    /// Changing to coefficients of the same length doesn't allocate, so it's safe to do while
+    /// processing.
+    pub fn set_coefficients(&mut self, coefficients: FIRCoefficients) {
+        self.filter.pin_mut().set_coefficients(coefficients.raw());
+        self.coefficients = coefficients;
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.filter.pin_mut().prepare(spec);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the filter has been prepared. Only as many channels as the filter was
+    /// prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        self.filter.pin_mut().process(buffer.buffer.as_mut());
+    }
+
+    /// Process a single sample of a channel.
+    ///
+    /// The sample is returned unchanged if the filter wasn't prepared for the channel.
+    pub fn process_sample(&mut self, channel: usize, sample: f32) -> f32 {
+        self.filter.pin_mut().process_sample(channel, sample)
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.filter.pin_mut().reset();
+    }
+}
+
+/// The highest cutoff frequency a filter prepared with `spec` accepts, which is just below half
+/// the sample rate. Filters default to a sample rate of 44.1kHz until they are prepared.
+fn max_cutoff_frequency(spec: Option<&ProcessSpec>) -> f64 {
+    let sample_rate = spec.map_or(44100.0, |spec| spec.sample_rate);
+    sample_rate * 0.5 * (1.0 - f64::from(f32::EPSILON))
+}
+
+/// The response of a [`StateVariableTPTFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateVariableTPTFilterType {
+    /// A low-pass response.
+    LowPass = 0,
+
+    /// A band-pass response.
+    BandPass = 1,
+
+    /// A high-pass response.
+    HighPass = 2,
+}
+
+/// A state variable filter using the topology-preserving transform.
+///
+/// The cutoff frequency and resonance can be changed between blocks without clicks or
+/// allocation, which makes it well suited to modulation. Each channel has its own state.
+pub struct StateVariableTPTFilter {
+    filter: UniquePtr<juce::StateVariableTPTFilter>,
+    spec: Option<ProcessSpec>,
+}
+
+unsafe impl Send for StateVariableTPTFilter {}
+
+impl Default for StateVariableTPTFilter {
+    fn default() -> Self {
+        Self {
+            filter: juce::create_state_variable_tpt_filter(),
+            spec: None,
+        }
+    }
+}
+
+impl StateVariableTPTFilter {
+    /// Create a low-pass filter with a cutoff of 1kHz and a Q of 1/√2.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the response of the filter.
+    pub fn with_type(mut self, filter_type: StateVariableTPTFilterType) -> Self {
+        self.set_type(filter_type);
+        self
+    }
+
+    /// Set the cutoff frequency of the filter.
+    pub fn with_cutoff_frequency(mut self, frequency: f64) -> Self {
+        self.set_cutoff_frequency(frequency);
+        self
+    }
+
+    /// Set the resonance of the filter.
+    pub fn with_resonance(mut self, resonance: Q) -> Self {
+        self.set_resonance(resonance);
+        self
+    }
+
+    /// Change the response of the filter.
+    pub fn set_type(&mut self, filter_type: StateVariableTPTFilterType) {
+        juce::set_state_variable_tpt_filter_type(self.filter.pin_mut(), filter_type as i32);
+    }
+
+    /// The response of the filter.
+    pub fn filter_type(&self) -> StateVariableTPTFilterType {
+        match juce::get_state_variable_tpt_filter_type(&self.filter) {
+            1 => StateVariableTPTFilterType::BandPass,
+            2 => StateVariableTPTFilterType::HighPass,
+            _ => StateVariableTPTFilterType::LowPass,
+        }
+    }
+
+    /// Change the cutoff frequency of the filter.
+    ///
+    /// The frequency is clamped to be above zero and below half the sample rate the filter was
+    /// prepared with (or 44.1kHz if it hasn't been prepared yet).
+    pub fn set_cutoff_frequency(&mut self, frequency: f64) {
+        let frequency = frequency.clamp(
+            f64::from(f32::EPSILON),
+            max_cutoff_frequency(self.spec.as_ref()),
+        );
+        self.filter.pin_mut().set_cutoff_frequency(frequency as f32);
+    }
+
+    /// The cutoff frequency of the filter.
+    pub fn cutoff_frequency(&self) -> f64 {
+        f64::from(self.filter.get_cutoff_frequency())
+    }
+
+    /// Change the resonance of the filter.
+    ///
+    /// A Q of 1/√2 gives a flat response, and higher values make the filter resonate around its
+    /// cutoff frequency. Values that aren't positive are clamped to a small positive value.
+    pub fn set_resonance(&mut self, Q(resonance): Q) {
+        self.filter
+            .pin_mut()
+            .set_resonance(resonance.max(f64::from(f32::EPSILON)) as f32);
+    }
+
+    /// The resonance of the filter.
+    pub fn resonance(&self) -> Q {
+        Q(f64::from(self.filter.get_resonance()))
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.spec = Some(*spec);
+        juce::prepare_state_variable_tpt_filter(self.filter.pin_mut(), spec);
+        self.set_cutoff_frequency(self.cutoff_frequency());
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the filter has been prepared. Only as many channels as the filter was
+    /// prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_state_variable_tpt_filter(
+                self.filter.pin_mut(),
+                buffer.buffer.as_mut(),
+                spec,
+            );
+        }
+    }
+
+    /// Process a single sample of a channel.
+    ///
+    /// The sample is returned unchanged if the filter wasn't prepared for the channel.
+    pub fn process_sample(&mut self, channel: usize, sample: f32) -> f32 {
+        match &self.spec {
+            Some(spec) if channel < spec.num_channels => {
+                juce::process_state_variable_tpt_filter_sample(
+                    self.filter.pin_mut(),
+                    channel,
+                    sample,
+                )
+            }
+            _ => sample,
+        }
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.filter.pin_mut().reset();
+    }
+}
+
+/// The response of a [`LinkwitzRileyFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkwitzRileyFilterType {
+    /// A low-pass response.
+    LowPass = 0,
+
+    /// A high-pass response.
+    HighPass = 1,
+
+    /// An all-pass response, with the same phase response as the low and high-pass outputs.
+    AllPass = 2,
+}
+
+/// A fourth order Linkwitz-Riley filter, for building crossovers.
+///
+/// The low and high-pass outputs sum to an all-pass response with a flat magnitude, so splitting
+/// a signal into bands and summing them back doesn't colour it. To keep the bands of a
+/// multiband crossover in phase, pass the lower bands through an all-pass filter at each of the
+/// higher crossover frequencies.
+pub struct LinkwitzRileyFilter {
+    filter: UniquePtr<juce::LinkwitzRileyFilter>,
+    spec: Option<ProcessSpec>,
+}
+
+unsafe impl Send for LinkwitzRileyFilter {}
+
+impl Default for LinkwitzRileyFilter {
+    fn default() -> Self {
+        Self {
+            filter: juce::create_linkwitz_riley_filter(),
+            spec: None,
+        }
+    }
+}
+
+impl LinkwitzRileyFilter {
+    /// Create a low-pass filter with a cutoff of 2kHz.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the response of the filter.
+    pub fn with_type(mut self, filter_type: LinkwitzRileyFilterType) -> Self {
+        self.set_type(filter_type);
+        self
+    }
+
+    /// Set the cutoff frequency of the filter.
+    pub fn with_cutoff_frequency(mut self, frequency: f64) -> Self {
+        self.set_cutoff_frequency(frequency);
+        self
+    }
+
+    /// Change the response of the filter.
+    ///
+    /// This only affects [`LinkwitzRileyFilter::process`] and
+    /// [`LinkwitzRileyFilter::process_sample`].
+    pub fn set_type(&mut self, filter_type: LinkwitzRileyFilterType) {
+        juce::set_linkwitz_riley_filter_type(self.filter.pin_mut(), filter_type as i32);
+    }
+
+    /// The response of the filter.
+    pub fn filter_type(&self) -> LinkwitzRileyFilterType {
+        match juce::get_linkwitz_riley_filter_type(&self.filter) {
+            1 => LinkwitzRileyFilterType::HighPass,
+            2 => LinkwitzRileyFilterType::AllPass,
+            _ => LinkwitzRileyFilterType::LowPass,
+        }
+    }
+
+    /// Change the cutoff frequency of the filter.
+    ///
+    /// The frequency is clamped to be above zero and below half the sample rate the filter was
+    /// prepared with (or 44.1kHz if it hasn't been prepared yet).
+    pub fn set_cutoff_frequency(&mut self, frequency: f64) {
+        let frequency = frequency.clamp(
+            f64::from(f32::EPSILON),
+            max_cutoff_frequency(self.spec.as_ref()),
+        );
+        self.filter.pin_mut().set_cutoff_frequency(frequency as f32);
+    }
+
+    /// The cutoff frequency of the filter.
+    pub fn cutoff_frequency(&self) -> f64 {
+        f64::from(self.filter.get_cutoff_frequency())
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.spec = Some(*spec);
+        juce::prepare_linkwitz_riley_filter(self.filter.pin_mut(), spec);
+        self.set_cutoff_frequency(self.cutoff_frequency());
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the filter has been prepared. Only as many channels as the filter was
+    /// prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_linkwitz_riley_filter(
+                self.filter.pin_mut(),
+                buffer.buffer.as_mut(),
+                spec,
+            );
+        }
+    }
+
+    /// Process a single sample of a channel.
+    ///
+    /// The sample is returned unchanged if the filter wasn't prepared for the channel.
+    pub fn process_sample(&mut self, channel: usize, sample: f32) -> f32 {
+        match &self.spec {
+            Some(spec) if channel < spec.num_channels => {
+                juce::process_linkwitz_riley_filter_sample(self.filter.pin_mut(), channel, sample)
+            }
+            _ => sample,
+        }
+    }
+
+    /// Split a single sample of a channel into its low and high bands, ignoring the type of the
+    /// filter.
+    ///
+    /// The sample is returned as the low band if the filter wasn't prepared for the channel.
+    pub fn process_sample_bands(&mut self, channel: usize, sample: f32) -> (f32, f32) {
+        match &self.spec {
+            Some(spec) if channel < spec.num_channels => {
+                let (mut low, mut high) = (0.0, 0.0);
+                juce::process_linkwitz_riley_filter_sample_bands(
+                    self.filter.pin_mut(),
+                    channel,
+                    sample,
+                    &mut low,
+                    &mut high,
+                );
+                (low, high)
+            }
+            _ => (sample, 0.0),
+        }
+    }
+
+    /// Split a buffer into its low and high bands, ignoring the type of the filter.
+    ///
+    /// The low band replaces the contents of `low`, and the high band is written to `high`. Does
+    /// nothing until the filter has been prepared. Only as many channels and samples as both
+    /// buffers have, and as many channels as the filter was prepared for, are processed.
+    pub fn split(
+        &mut self,
+        low: &mut OutputAudioSampleBuffer<'_>,
+        high: &mut OutputAudioSampleBuffer<'_>,
+    ) {
+        if let Some(spec) = &self.spec {
+            juce::split_linkwitz_riley_filter(
+                self.filter.pin_mut(),
+                low.buffer.as_mut(),
+                high.buffer.as_mut(),
+                spec,
+            );
+        }
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.filter.pin_mut().reset();
+    }
+}
+
+/// The response of a [`FirstOrderTPTFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirstOrderTPTFilterType {
+    /// A low-pass response.
+    LowPass = 0,
+
+    /// A high-pass response.
+    HighPass = 1,
+
+    /// An all-pass response.
+    AllPass = 2,
+}
+
+/// A first order filter using the topology-preserving transform.
+///
+/// It's cheap enough to smooth control signals or shape the tone of many channels, and its
+/// cutoff frequency can be changed between blocks without clicks or allocation. Each channel has
+/// its own state.
+pub struct FirstOrderTPTFilter {
+    filter: UniquePtr<juce::FirstOrderTPTFilter>,
+    spec: Option<ProcessSpec>,
+}
+
+unsafe impl Send for FirstOrderTPTFilter {}
+
+impl Default for FirstOrderTPTFilter {
+    fn default() -> Self {
+        Self {
+            filter: juce::create_first_order_tpt_filter(),
+            spec: None,
+        }
+    }
+}
+
+impl FirstOrderTPTFilter {
+    /// Create a low-pass filter with a cutoff of 1kHz.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the response of the filter.
+    pub fn with_type(mut self, filter_type: FirstOrderTPTFilterType) -> Self {
+        self.set_type(filter_type);
+        self
+    }
+
+    /// Set the cutoff frequency of the filter.
+    pub fn with_cutoff_frequency(mut self, frequency: f64) -> Self {
+        self.set_cutoff_frequency(frequency);
+        self
+    }
+
+    /// Change the response of the filter.
+    pub fn set_type(&mut self, filter_type: FirstOrderTPTFilterType) {
+        juce::set_first_order_tpt_filter_type(self.filter.pin_mut(), filter_type as i32);
+    }
+
+    /// The response of the filter.
+    pub fn filter_type(&self) -> FirstOrderTPTFilterType {
+        match juce::get_first_order_tpt_filter_type(&self.filter) {
+            1 => FirstOrderTPTFilterType::HighPass,
+            2 => FirstOrderTPTFilterType::AllPass,
+            _ => FirstOrderTPTFilterType::LowPass,
+        }
+    }
+
+    /// Change the cutoff frequency of the filter.
+    ///
+    /// The frequency is clamped to be above zero and below half the sample rate the filter was
+    /// prepared with (or 44.1kHz if it hasn't been prepared yet).
+    pub fn set_cutoff_frequency(&mut self, frequency: f64) {
+        let frequency = frequency.clamp(
+            f64::from(f32::EPSILON),
+            max_cutoff_frequency(self.spec.as_ref()),
+        );
+        self.filter.pin_mut().set_cutoff_frequency(frequency as f32);
+    }
+
+    /// The cutoff frequency of the filter.
+    pub fn cutoff_frequency(&self) -> f64 {
+        f64::from(self.filter.get_cutoff_frequency())
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.spec = Some(*spec);
+        juce::prepare_first_order_tpt_filter(self.filter.pin_mut(), spec);
+        self.set_cutoff_frequency(self.cutoff_frequency());
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the filter has been prepared. Only as many channels as the filter was
+    /// prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_first_order_tpt_filter(
+                self.filter.pin_mut(),
+                buffer.buffer.as_mut(),
+                spec,
+            );
+        }
+    }
+
+    /// Process a single sample of a channel.
+    ///
+    /// The sample is returned unchanged if the filter wasn't prepared for the channel.
+    pub fn process_sample(&mut self, channel: usize, sample: f32) -> f32 {
+        match &self.spec {
+            Some(spec) if channel < spec.num_channels => {
+                juce::process_first_order_tpt_filter_sample(self.filter.pin_mut(), channel, sample)
+            }
+            _ => sample,
+        }
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.filter.pin_mut().reset();
+    }
+}
+
+/// A waveform for an [`Oscillator`] to generate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Waveform {
+    /// A sine wave.
+    Sine = 0,
+
+    /// A triangle wave.
+    Triangle = 1,
+
+    /// A rising sawtooth wave.
+    Saw = 2,
+
+    /// A square wave.
+    Square = 3,
+}
+
+/// An oscillator that generates a periodic waveform.
+///
+/// The waveform is either a function, which can be approximated with a lookup table for
+/// speed, or a wavetable holding a single period. Neither is band-limited, so harmonics above
+/// half the sample rate will alias.
+pub struct Oscillator {
+    oscillator: UniquePtr<juce::Oscillator>,
+    spec: Option<ProcessSpec>,
+}
+
+unsafe impl Send for Oscillator {}
+
+impl Oscillator {
+    /// Create an oscillator for a waveform, approximated with a lookup table of the given size.
+    ///
+    /// A lookup table size of zero evaluates the waveform exactly for every sample.
+    pub fn new(waveform: Waveform, lookup_table_size: usize) -> Self {
+        Self {
+            oscillator: juce::create_oscillator(waveform as i32, lookup_table_size),
+            spec: None,
+        }
+    }
+
+    /// Create an oscillator that plays a single period of a wavetable, linearly interpolating
+    /// between its samples.
+    ///
+    /// Returns an error if the wavetable is empty.
+    pub fn from_wavetable(wavetable: &[f32]) -> Result<Self> {
+        juce::create_wavetable_oscillator(wavetable).map(|oscillator| Self {
+            oscillator,
+            spec: None,
+        })
+    }
+
+    /// Set the frequency of the oscillator.
+    pub fn with_frequency(mut self, frequency: f64) -> Self {
+        self.set_frequency_immediately(frequency);
+        self
+    }
+
+    /// Change the frequency of the oscillator.
+    ///
+    /// Once prepared, the oscillator ramps to the new frequency over 50ms. This doesn't allocate,
+    /// so it's safe to do while processing.
+    pub fn set_frequency(&mut self, frequency: f64) {
+        self.oscillator
+            .pin_mut()
+            .set_frequency(frequency as f32, false);
+    }
+
+    /// Change the frequency of the oscillator without ramping.
+    pub fn set_frequency_immediately(&mut self, frequency: f64) {
+        self.oscillator
+            .pin_mut()
+            .set_frequency(frequency as f32, true);
+    }
+
+    /// The frequency the oscillator is playing, or ramping to.
+    pub fn frequency(&self) -> f64 {
+        f64::from(self.oscillator.get_frequency())
+    }
+
+    /// Prepare to generate audio.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        juce::prepare_oscillator(self.oscillator.pin_mut(), spec);
+        self.spec = Some(*spec);
+    }
+
+    /// Add the output of the oscillator to a buffer.
+    ///
+    /// Every channel receives the same signal. Does nothing until the oscillator has been
+    /// prepared. Only as many channels as the oscillator was prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_oscillator(self.oscillator.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Generate the next sample and add it to `input`.
+    pub fn process_sample(&mut self, input: f32) -> f32 {
+        self.oscillator.pin_mut().process_sample(input)
+    }
+
+    /// Reset the phase of the oscillator.
+    pub fn reset(&mut self) {
+        self.oscillator.pin_mut().reset();
+    }
+}
+
+/// The response of a [`LadderFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LadderFilterMode {
+    /// A 12dB/octave low-pass response.
+    LowPass12 = 0,
+
+    /// A 12dB/octave high-pass response.
+    HighPass12 = 1,
+
+    /// A 12dB/octave band-pass response.
+    BandPass12 = 2,
+
+    /// A 24dB/octave low-pass response.
+    LowPass24 = 3,
+
+    /// A 24dB/octave high-pass response.
+    HighPass24 = 4,
+
+    /// A 24dB/octave band-pass response.
+    BandPass24 = 5,
+}
+
+/// A multi-mode filter based on the Moog ladder filter, with saturation.
+///
+/// The cutoff frequency, resonance and drive are smoothed, so they can be changed between blocks
+/// without clicks or allocation. Each channel has its own state.
+pub struct LadderFilter {
+    filter: UniquePtr<juce::LadderFilter>,
+    spec: Option<ProcessSpec>,
+    mode: LadderFilterMode,
+    cutoff_frequency: f64,
+    resonance: f32,
+    drive: f32,
+    enabled: bool,
+}
+
+unsafe impl Send for LadderFilter {}
+
+impl Default for LadderFilter {
+    fn default() -> Self {
+        let mut filter = Self {
+            filter: juce::create_ladder_filter(),
+            spec: None,
+            mode: LadderFilterMode::LowPass12,
+            cutoff_frequency: 200.0,
+            resonance: 0.0,
+            drive: 1.2,
+            enabled: true,
+        };
+
+        filter.set_mode(filter.mode);
+        filter.set_cutoff_frequency(filter.cutoff_frequency);
+        filter.set_resonance(filter.resonance);
+        filter.set_drive(filter.drive);
+        filter
+    }
+}
+
+impl LadderFilter {
+    /// Create a 12dB/octave low-pass filter with a cutoff of 200Hz, no resonance and a drive of
+    /// 1.2.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the response of the filter.
+    pub fn with_mode(mut self, mode: LadderFilterMode) -> Self {
+        self.set_mode(mode);
+        self
+    }
+
+    /// Set the cutoff frequency of the filter.
+    pub fn with_cutoff_frequency(mut self, frequency: f64) -> Self {
+        self.set_cutoff_frequency(frequency);
+        self
+    }
+
+    /// Set the resonance of the filter.
+    pub fn with_resonance(mut self, resonance: f32) -> Self {
+        self.set_resonance(resonance);
+        self
+    }
+
+    /// Set the drive of the filter.
+    pub fn with_drive(mut self, drive: f32) -> Self {
+        self.set_drive(drive);
+        self
+    }
+
+    /// Change the response of the filter.
+    pub fn set_mode(&mut self, mode: LadderFilterMode) {
+        juce::set_ladder_filter_mode(self.filter.pin_mut(), mode as i32);
+        self.mode = mode;
+    }
+
+    /// The response of the filter.
+    pub fn mode(&self) -> LadderFilterMode {
+        self.mode
+    }
+
+    /// Change the cutoff frequency of the filter.
+    ///
+    /// The frequency is clamped to be above zero and below half the sample rate the filter was
+    /// prepared with (or 44.1kHz if it hasn't been prepared yet).
+    pub fn set_cutoff_frequency(&mut self, frequency: f64) {
+        self.cutoff_frequency = frequency.clamp(
+            f64::from(f32::EPSILON),
+            max_cutoff_frequency(self.spec.as_ref()),
+        );
+        self.filter
+            .pin_mut()
+            .set_cutoff_frequency_hz(self.cutoff_frequency as f32);
+    }
+
+    /// The cutoff frequency of the filter.
+    pub fn cutoff_frequency(&self) -> f64 {
+        self.cutoff_frequency
+    }
+
+    /// Change the resonance of the filter, clamped between 0 and 1.
+    pub fn set_resonance(&mut self, resonance: f32) {
+        self.resonance = resonance.clamp(0.0, 1.0);
+        self.filter.pin_mut().set_resonance(self.resonance);
+    }
+
+    /// The resonance of the filter.
+    pub fn resonance(&self) -> f32 {
+        self.resonance
+    }
+
+    /// Change the amount of saturation, where 1 is the least.
+    ///
+    /// Values below 1 are clamped to 1.
+    pub fn set_drive(&mut self, drive: f32) {
+        self.drive = drive.max(1.0);
+        self.filter.pin_mut().set_drive(self.drive);
+    }
+
+    /// The amount of saturation.
+    pub fn drive(&self) -> f32 {
+        self.drive
+    }
+
+    /// Enable or bypass the filter.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.filter.pin_mut().set_enabled(enabled);
+        self.enabled = enabled;
+    }
+
+    /// Whether the filter is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.spec = Some(*spec);
+        juce::prepare_ladder_filter(self.filter.pin_mut(), spec);
+        self.set_cutoff_frequency(self.cutoff_frequency);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the filter has been prepared, or while it's bypassed. Only as many
+    /// channels as the filter was prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_ladder_filter(self.filter.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.filter.pin_mut().reset();
+    }
+}
+
+/// How a [`DelayLine`] interpolates between samples for fractional delays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelayLineInterpolation {
+    /// No interpolation, so delays are rounded down to a whole number of samples.
+    None = 0,
+
+    /// Linear interpolation, which is cheap but low-passes the signal slightly for fractional
+    /// delays.
+    Linear = 1,
+
+    /// Third order Lagrange interpolation, which is more accurate than linear interpolation.
+    Lagrange3rd = 2,
+
+    /// An all-pass Thiran interpolation, which has a flat magnitude response but is only suitable
+    /// for delays that change slowly.
+    Thiran = 3,
+}
+
+/// A delay line, for building echoes, choruses, comb filters and the like.
+///
+/// Each channel has its own buffer and read position. A single delay is used by
+/// [`DelayLine::process`], and reading samples with [`DelayLine::pop_sample_with_delay`] gives
+/// each channel, or each tap, its own delay.
+pub struct DelayLine {
+    delay_line: UniquePtr<juce::DelayLine>,
+}
+
+unsafe impl Send for DelayLine {}
+
+/// JUCE keeps two samples more than the maximum delay, and counts them in an `int`.
+const MAXIMUM_DELAY_IN_SAMPLES: usize = i32::MAX as usize - 2;
+
+impl DelayLine {
+    /// Create a delay line that can delay by up to the given number of samples.
+    ///
+    /// The maximum delay is clamped to `i32::MAX - 2` samples.
+    pub fn new(interpolation: DelayLineInterpolation, maximum_delay_in_samples: usize) -> Self {
+        Self {
+            delay_line: juce::create_delay_line(
+                interpolation as i32,
+                maximum_delay_in_samples.min(MAXIMUM_DELAY_IN_SAMPLES),
+            ),
+        }
+    }
+
+    /// Set the delay.
+    pub fn with_delay(mut self, delay_in_samples: f32) -> Self {
+        self.set_delay(delay_in_samples);
+        self
+    }
+
+    /// Change the delay, clamped between zero and the maximum delay.
+    ///
+    /// A NaN delay is treated as zero.
+    pub fn set_delay(&mut self, delay_in_samples: f32) {
+        self.delay_line
+            .pin_mut()
+            .set_delay(delay_in_samples.max(0.0));
+    }
+
+    /// The delay in samples.
+    pub fn delay(&self) -> f32 {
+        self.delay_line.get_delay()
+    }
+
+    /// Change the maximum delay, clamped to `i32::MAX - 2` samples.
+    ///
+    /// This reallocates the buffers and clears their contents, so shouldn't be done while
+    /// processing.
+    pub fn set_maximum_delay_in_samples(&mut self, maximum_delay_in_samples: usize) {
+        self.delay_line
+            .pin_mut()
+            .set_maximum_delay_in_samples(maximum_delay_in_samples.min(MAXIMUM_DELAY_IN_SAMPLES));
+    }
+
+    /// The maximum delay in samples.
+    pub fn maximum_delay_in_samples(&self) -> usize {
+        self.delay_line.get_maximum_delay_in_samples()
+    }
+
+    /// Prepare to process audio, creating the buffer for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.delay_line.pin_mut().prepare(spec);
+    }
+
+    /// Delay a buffer in place.
+    ///
+    /// Does nothing until the delay line has been prepared. Only as many channels as the delay
+    /// line was prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        self.delay_line.pin_mut().process(buffer.buffer.as_mut());
+    }
+
+    /// Write a sample to a channel.
+    ///
+    /// The sample is ignored if the delay line wasn't prepared for the channel.
+    pub fn push_sample(&mut self, channel: usize, sample: f32) {
+        self.delay_line.pin_mut().push_sample(channel, sample);
+    }
+
+    /// Read the next sample of a channel at the current delay.
+    ///
+    /// Returns zero if the delay line wasn't prepared for the channel.
+    pub fn pop_sample(&mut self, channel: usize) -> f32 {
+        self.delay_line.pin_mut().pop_sample(channel, -1.0, true)
+    }
+
+    /// Read the next sample of a channel at a different delay, which becomes the current delay.
+    ///
+    /// Returns zero if the delay line wasn't prepared for the channel.
+    pub fn pop_sample_with_delay(&mut self, channel: usize, delay_in_samples: f32) -> f32 {
+        self.delay_line
+            .pin_mut()
+            .pop_sample(channel, delay_in_samples.max(0.0), true)
+    }
+
+    /// Read a sample of a channel at a different delay without advancing its read position, so
+    /// that several taps can be read for each sample pushed.
+    ///
+    /// The delay becomes the current delay. Returns zero if the delay line wasn't prepared for
+    /// the channel.
+    pub fn read_sample_with_delay(&mut self, channel: usize, delay_in_samples: f32) -> f32 {
+        self.delay_line
+            .pin_mut()
+            .pop_sample(channel, delay_in_samples.max(0.0), false)
+    }
+
+    /// Clear the buffer of each channel.
+    pub fn reset(&mut self) {
+        self.delay_line.pin_mut().reset();
+    }
+}
+
+impl Default for ReverbParameters {
+    fn default() -> Self {
+        Self {
+            room_size: 0.5,
+            damping: 0.5,
+            wet_level: 0.33,
+            dry_level: 0.4,
+            width: 1.0,
+            freeze_mode: false,
+        }
+    }
+}
+
+/// A simple reverb, based on the public domain Freeverb algorithm.
+///
+/// The reverb assumes a sample rate of 44.1kHz until it's prepared.
+pub struct Reverb {
+    reverb: UniquePtr<juce::Reverb>,
+    spec: Option<ProcessSpec>,
+}
+
+unsafe impl Send for Reverb {}
+
+impl Default for Reverb {
+    fn default() -> Self {
+        Self {
+            reverb: juce::create_reverb(),
+            spec: None,
+        }
+    }
+}
+
+impl Reverb {
+    /// Create a reverb with the default parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the parameters of the reverb.
+    pub fn with_parameters(mut self, parameters: &ReverbParameters) -> Self {
+        self.set_parameters(parameters);
+        self
+    }
+
+    /// Change the parameters of the reverb, clamping each level between 0 and 1.
+    ///
+    /// Changes to the levels are smoothed, so this is safe to do while processing.
+    pub fn set_parameters(&mut self, parameters: &ReverbParameters) {
+        juce::set_reverb_parameters(self.reverb.pin_mut(), parameters);
+    }
+
+    /// The parameters of the reverb.
+    pub fn parameters(&self) -> ReverbParameters {
+        juce::get_reverb_parameters(&self.reverb)
+    }
+
+    /// Prepare to process audio.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.reverb.pin_mut().set_sample_rate(spec.sample_rate);
+        self.spec = Some(*spec);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// A single channel is processed in mono, otherwise the first two channels are processed in
+    /// stereo. Does nothing until the reverb has been prepared. Only as many channels as the
+    /// reverb was prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_reverb(self.reverb.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Process a pair of channels in place.
+    ///
+    /// Only as many samples as the shorter channel has are processed.
+    pub fn process_stereo(&mut self, left: &mut [f32], right: &mut [f32]) {
+        juce::process_reverb_stereo(self.reverb.pin_mut(), left, right);
+    }
+
+    /// Process a single channel in place.
+    pub fn process_mono(&mut self, samples: &mut [f32]) {
+        juce::process_reverb_mono(self.reverb.pin_mut(), samples);
+    }
+
+    /// Clear the reverb's buffers.
+    pub fn reset(&mut self) {
+        self.reverb.pin_mut().reset();
+    }
+}
+
+/// A compressor, which reduces the level of a signal above a threshold.
+///
+/// The parameters can be changed between blocks without allocation. Each channel is compressed
+/// independently.
+pub struct Compressor {
+    compressor: UniquePtr<juce::Compressor>,
+    spec: Option<ProcessSpec>,
+    threshold: f32,
+    ratio: f32,
+    attack: Duration,
+    release: Duration,
+}
+
+unsafe impl Send for Compressor {}
+
+impl Default for Compressor {
+    fn default() -> Self {
+        let mut compressor = Self {
+            compressor: juce::create_compressor(),
+            spec: None,
+            threshold: 0.0,
+            ratio: 1.0,
+            attack: Duration::from_millis(1),
+            release: Duration::from_millis(100),
+        };
+
+        compressor.set_threshold(compressor.threshold);
+        compressor.set_ratio(compressor.ratio);
+        compressor.set_attack(compressor.attack);
+        compressor.set_release(compressor.release);
+        compressor
+    }
+}
+
+impl Compressor {
+    /// Create a compressor with a threshold of 0dB, a ratio of 1:1, an attack of 1ms and a
+    /// release of 100ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the threshold in decibels.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.set_threshold(threshold);
+        self
+    }
+
+    /// Set the ratio.
+    pub fn with_ratio(mut self, ratio: f32) -> Self {
+        self.set_ratio(ratio);
+        self
+    }
+
+    /// Set the attack time.
+    pub fn with_attack(mut self, attack: Duration) -> Self {
+        self.set_attack(attack);
+        self
+    }
+
+    /// Set the release time.
+    pub fn with_release(mut self, release: Duration) -> Self {
+        self.set_release(release);
+        self
+    }
+
+    /// Change the threshold in decibels, above which the signal is compressed.
+    pub fn set_threshold(&mut self, threshold: f32) {
+        self.threshold = threshold;
+        self.compressor.pin_mut().set_threshold(threshold);
+    }
+
+    /// The threshold in decibels.
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Change the ratio of the compression, e.g. 4 for a 4:1 ratio.
+    ///
+    /// Ratios below 1 are clamped to 1, which doesn't compress at all.
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio.max(1.0);
+        self.compressor.pin_mut().set_ratio(self.ratio);
+    }
+
+    /// The ratio of the compression.
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Change how quickly the compressor responds to the level rising.
+    pub fn set_attack(&mut self, attack: Duration) {
+        self.attack = attack;
+        self.compressor
+            .pin_mut()
+            .set_attack(attack.as_secs_f32() * 1000.0);
+    }
+
+    /// The attack time.
+    pub fn attack(&self) -> Duration {
+        self.attack
+    }
+
+    /// Change how quickly the compressor responds to the level falling.
+    pub fn set_release(&mut self, release: Duration) {
+        self.release = release;
+        self.compressor
+            .pin_mut()
+            .set_release(release.as_secs_f32() * 1000.0);
+    }
+
+    /// The release time.
+    pub fn release(&self) -> Duration {
+        self.release
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        juce::prepare_compressor(self.compressor.pin_mut(), spec);
+        self.spec = Some(*spec);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the compressor has been prepared. Only as many channels as the
+    /// compressor was prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_compressor(self.compressor.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Process a single sample of a channel.
+    ///
+    /// The sample is returned unchanged if the compressor wasn't prepared for the channel.
+    pub fn process_sample(&mut self, channel: usize, sample: f32) -> f32 {
+        match &self.spec {
+            Some(spec) if channel < spec.num_channels => self
+                .compressor
+                .pin_mut()
+                .process_sample(channel as i32, sample),
+            _ => sample,
+        }
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.compressor.pin_mut().reset();
+    }
+}
+
+/// A brickwall limiter, for protecting an output from clipping.
+///
+/// The signal is made louder so that the threshold becomes full scale, then limited with two
+/// compressors in series, and finally clipped to ±1 to catch any remaining overshoot.
+pub struct Limiter {
+    limiter: UniquePtr<juce::Limiter>,
+    spec: Option<ProcessSpec>,
+    threshold: f32,
+    release: Duration,
+}
+
+unsafe impl Send for Limiter {}
+
+impl Default for Limiter {
+    fn default() -> Self {
+        let mut limiter = Self {
+            limiter: juce::create_limiter(),
+            spec: None,
+            threshold: -10.0,
+            release: Duration::from_millis(100),
+        };
+
+        limiter.set_threshold(limiter.threshold);
+        limiter.set_release(limiter.release);
+        limiter
+    }
+}
+
+impl Limiter {
+    /// Create a limiter with a threshold of -10dB and a release of 100ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the threshold in decibels.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.set_threshold(threshold);
+        self
+    }
+
+    /// Set the release time.
+    pub fn with_release(mut self, release: Duration) -> Self {
+        self.set_release(release);
+        self
+    }
+
+    /// Change the threshold in decibels.
+    ///
+    /// Signals at the threshold come out at full scale, so a threshold of 0dB limits without
+    /// making the signal louder.
+    pub fn set_threshold(&mut self, threshold: f32) {
+        self.threshold = threshold;
+        self.limiter.pin_mut().set_threshold(threshold);
+    }
+
+    /// The threshold in decibels.
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Change how quickly the limiter recovers once the level falls.
+    pub fn set_release(&mut self, release: Duration) {
+        self.release = release;
+        self.limiter
+            .pin_mut()
+            .set_release(release.as_secs_f32() * 1000.0);
+    }
+
+    /// The release time.
+    pub fn release(&self) -> Duration {
+        self.release
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        juce::prepare_limiter(self.limiter.pin_mut(), spec);
+        self.spec = Some(*spec);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the limiter has been prepared. Only as many channels as the limiter
+    /// was prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_limiter(self.limiter.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.limiter.pin_mut().reset();
+    }
+}
+
+/// A noise gate, which reduces the level of a signal below a threshold, e.g. to remove
+/// background noise from a microphone.
+///
+/// The parameters can be changed between blocks without allocation. Each channel is gated
+/// independently.
+pub struct NoiseGate {
+    noise_gate: UniquePtr<juce::NoiseGate>,
+    spec: Option<ProcessSpec>,
+    threshold: f32,
+    ratio: f32,
+    attack: Duration,
+    release: Duration,
+}
+
+unsafe impl Send for NoiseGate {}
+
+impl Default for NoiseGate {
+    fn default() -> Self {
+        let mut noise_gate = Self {
+            noise_gate: juce::create_noise_gate(),
+            spec: None,
+            threshold: -100.0,
+            ratio: 10.0,
+            attack: Duration::from_millis(1),
+            release: Duration::from_millis(100),
+        };
+
+        noise_gate.set_threshold(noise_gate.threshold);
+        noise_gate.set_ratio(noise_gate.ratio);
+        noise_gate.set_attack(noise_gate.attack);
+        noise_gate.set_release(noise_gate.release);
+        noise_gate
+    }
+}
+
+impl NoiseGate {
+    /// Create a noise gate with a threshold of -100dB, a ratio of 10:1, an attack of 1ms and a
+    /// release of 100ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the threshold in decibels.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.set_threshold(threshold);
+        self
+    }
+
+    /// Set the ratio.
+    pub fn with_ratio(mut self, ratio: f32) -> Self {
+        self.set_ratio(ratio);
+        self
+    }
+
+    /// Set the attack time.
+    pub fn with_attack(mut self, attack: Duration) -> Self {
+        self.set_attack(attack);
+        self
+    }
+
+    /// Set the release time.
+    pub fn with_release(mut self, release: Duration) -> Self {
+        self.set_release(release);
+        self
+    }
+
+    /// Change the threshold in decibels, below which the signal is attenuated.
+    pub fn set_threshold(&mut self, threshold: f32) {
+        self.threshold = threshold;
+        self.noise_gate.pin_mut().set_threshold(threshold);
+    }
+
+    /// The threshold in decibels.
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Change the ratio of the expansion below the threshold, e.g. 4 for a 1:4 ratio.
+    ///
+    /// Ratios below 1 are clamped to 1, which doesn't attenuate at all.
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio.max(1.0);
+        self.noise_gate.pin_mut().set_ratio(self.ratio);
+    }
+
+    /// The ratio of the expansion.
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Change how quickly the gate opens once the level rises above the threshold.
+    pub fn set_attack(&mut self, attack: Duration) {
+        self.attack = attack;
+        self.noise_gate
+            .pin_mut()
+            .set_attack(attack.as_secs_f32() * 1000.0);
+    }
+
+    /// The attack time.
+    pub fn attack(&self) -> Duration {
+        self.attack
+    }
+
+    /// Change how quickly the gate closes once the level falls below the threshold.
+    pub fn set_release(&mut self, release: Duration) {
+        self.release = release;
+        self.noise_gate
+            .pin_mut()
+            .set_release(release.as_secs_f32() * 1000.0);
+    }
+
+    /// The release time.
+    pub fn release(&self) -> Duration {
+        self.release
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        juce::prepare_noise_gate(self.noise_gate.pin_mut(), spec);
+        self.spec = Some(*spec);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the gate has been prepared. Only as many channels as the
+    /// gate was prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_noise_gate(self.noise_gate.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Process a single sample of a channel.
+    ///
+    /// The sample is returned unchanged if the gate wasn't prepared for the channel.
+    pub fn process_sample(&mut self, channel: usize, sample: f32) -> f32 {
+        match &self.spec {
+            Some(spec) if channel < spec.num_channels => self
+                .noise_gate
+                .pin_mut()
+                .process_sample(channel as i32, sample),
+            _ => sample,
+        }
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.noise_gate.pin_mut().reset();
+    }
+}
+
+/// A phaser, which sweeps notches through the spectrum of a signal using a chain of all-pass
+/// filters modulated by an LFO.
+///
+/// The parameters are smoothed, so they can be changed between blocks without clicks or
+/// allocation.
+pub struct Phaser {
+    phaser: UniquePtr<juce::Phaser>,
+    spec: Option<ProcessSpec>,
+    rate: f64,
+    depth: f32,
+    centre_frequency: f64,
+    feedback: f32,
+    mix: f32,
+}
+
+unsafe impl Send for Phaser {}
+
+impl Default for Phaser {
+    fn default() -> Self {
+        let mut phaser = Self {
+            phaser: juce::create_phaser(),
+            spec: None,
+            rate: 1.0,
+            depth: 0.5,
+            centre_frequency: 1300.0,
+            feedback: 0.0,
+            mix: 0.5,
+        };
+
+        phaser.set_rate(phaser.rate);
+        phaser.set_depth(phaser.depth);
+        phaser.set_centre_frequency(phaser.centre_frequency);
+        phaser.set_feedback(phaser.feedback);
+        phaser.set_mix(phaser.mix);
+        phaser
+    }
+}
+
+impl Phaser {
+    /// Create a phaser with a rate of 1Hz, a depth of 0.5, a centre frequency of 1.3kHz, no
+    /// feedback and an even mix.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the rate of the LFO in Hertz.
+    pub fn with_rate(mut self, rate: f64) -> Self {
+        self.set_rate(rate);
+        self
+    }
+
+    /// Set the depth of the modulation.
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.set_depth(depth);
+        self
+    }
+
+    /// Set the centre frequency of the all-pass filters.
+    pub fn with_centre_frequency(mut self, frequency: f64) -> Self {
+        self.set_centre_frequency(frequency);
+        self
+    }
+
+    /// Set the feedback.
+    pub fn with_feedback(mut self, feedback: f32) -> Self {
+        self.set_feedback(feedback);
+        self
+    }
+
+    /// Set the mix between the dry and wet signals.
+    pub fn with_mix(mut self, mix: f32) -> Self {
+        self.set_mix(mix);
+        self
+    }
+
+    /// Change the rate of the LFO in Hertz, clamped between 0 and 99Hz.
+    pub fn set_rate(&mut self, rate: f64) {
+        self.rate = rate.clamp(0.0, 99.0);
+        self.phaser.pin_mut().set_rate(self.rate as f32);
+    }
+
+    /// The rate of the LFO in Hertz.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Change the depth of the modulation, clamped between 0 and 1.
+    pub fn set_depth(&mut self, depth: f32) {
+        self.depth = depth.clamp(0.0, 1.0);
+        self.phaser.pin_mut().set_depth(self.depth);
+    }
+
+    /// The depth of the modulation.
+    pub fn depth(&self) -> f32 {
+        self.depth
+    }
+
+    /// Change the centre frequency of the all-pass filters.
+    ///
+    /// The frequency is clamped to be above zero and below half the sample rate the phaser was
+    /// prepared with (or 44.1kHz if it hasn't been prepared yet).
+    pub fn set_centre_frequency(&mut self, frequency: f64) {
+        self.centre_frequency = frequency.clamp(
+            f64::from(f32::EPSILON),
+            max_cutoff_frequency(self.spec.as_ref()),
+        );
+        self.phaser
+            .pin_mut()
+            .set_centre_frequency(self.centre_frequency as f32);
+    }
+
+    /// The centre frequency of the all-pass filters.
+    pub fn centre_frequency(&self) -> f64 {
+        self.centre_frequency
+    }
+
+    /// Change the feedback, clamped between -1 and 1.
+    pub fn set_feedback(&mut self, feedback: f32) {
+        self.feedback = feedback.clamp(-1.0, 1.0);
+        self.phaser.pin_mut().set_feedback(self.feedback);
+    }
+
+    /// The feedback.
+    pub fn feedback(&self) -> f32 {
+        self.feedback
+    }
+
+    /// Change the mix between the dry and wet signals, from 0 (fully dry) to 1 (fully wet).
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix = mix.clamp(0.0, 1.0);
+        self.phaser.pin_mut().set_mix(self.mix);
+    }
+
+    /// The mix between the dry and wet signals.
+    pub fn mix(&self) -> f32 {
+        self.mix
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.spec = Some(*spec);
+        juce::prepare_phaser(self.phaser.pin_mut(), spec);
+        self.set_centre_frequency(self.centre_frequency);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the phaser has been prepared. Only as many channels as the phaser was
+    /// prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_phaser(self.phaser.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.phaser.pin_mut().reset();
+    }
+}
+
+/// A chorus, which thickens a signal by mixing it with a copy whose delay is modulated by an
+/// LFO.
+///
+/// The parameters are smoothed, so they can be changed between blocks without clicks or
+/// allocation.
+pub struct Chorus {
+    chorus: UniquePtr<juce::Chorus>,
+    spec: Option<ProcessSpec>,
+    rate: f64,
+    depth: f32,
+    centre_delay: Duration,
+    feedback: f32,
+    mix: f32,
+}
+
+unsafe impl Send for Chorus {}
+
+impl Default for Chorus {
+    fn default() -> Self {
+        let mut chorus = Self {
+            chorus: juce::create_chorus(),
+            spec: None,
+            rate: 1.0,
+            depth: 0.25,
+            centre_delay: Duration::from_millis(7),
+            feedback: 0.0,
+            mix: 0.5,
+        };
+
+        chorus.set_rate(chorus.rate);
+        chorus.set_depth(chorus.depth);
+        chorus.set_centre_delay(chorus.centre_delay);
+        chorus.set_feedback(chorus.feedback);
+        chorus.set_mix(chorus.mix);
+        chorus
+    }
+}
+
+impl Chorus {
+    /// Create a chorus with a rate of 1Hz, a depth of 0.25, a centre delay of 7ms, no feedback
+    /// and an even mix.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the rate of the LFO in Hertz.
+    pub fn with_rate(mut self, rate: f64) -> Self {
+        self.set_rate(rate);
+        self
+    }
+
+    /// Set the depth of the modulation.
+    pub fn with_depth(mut self, depth: f32) -> Self {
+        self.set_depth(depth);
+        self
+    }
+
+    /// Set the centre delay.
+    pub fn with_centre_delay(mut self, centre_delay: Duration) -> Self {
+        self.set_centre_delay(centre_delay);
+        self
+    }
+
+    /// Set the feedback.
+    pub fn with_feedback(mut self, feedback: f32) -> Self {
+        self.set_feedback(feedback);
+        self
+    }
+
+    /// Set the mix between the dry and wet signals.
+    pub fn with_mix(mut self, mix: f32) -> Self {
+        self.set_mix(mix);
+        self
+    }
+
+    /// Change the rate of the LFO in Hertz, clamped between 0 and 99Hz.
+    pub fn set_rate(&mut self, rate: f64) {
+        self.rate = rate.clamp(0.0, 99.0);
+        self.chorus.pin_mut().set_rate(self.rate as f32);
+    }
+
+    /// The rate of the LFO in Hertz.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Change the depth of the modulation, clamped between 0 and 1.
+    pub fn set_depth(&mut self, depth: f32) {
+        self.depth = depth.clamp(0.0, 1.0);
+        self.chorus.pin_mut().set_depth(self.depth);
+    }
+
+    /// The depth of the modulation.
+    pub fn depth(&self) -> f32 {
+        self.depth
+    }
+
+    /// Change the delay the modulation is centred on, clamped to at least 1ms and just under 100ms.
+    pub fn set_centre_delay(&mut self, centre_delay: Duration) {
+        // JUCE requires the delay to be strictly below 100ms.
+        self.centre_delay =
+            centre_delay.clamp(Duration::from_millis(1), Duration::from_micros(99_999));
+        self.chorus
+            .pin_mut()
+            .set_centre_delay(self.centre_delay.as_secs_f32() * 1000.0);
+    }
+
+    /// The delay the modulation is centred on.
+    pub fn centre_delay(&self) -> Duration {
+        self.centre_delay
+    }
+
+    /// Change the feedback, clamped between -1 and 1.
+    pub fn set_feedback(&mut self, feedback: f32) {
+        self.feedback = feedback.clamp(-1.0, 1.0);
+        self.chorus.pin_mut().set_feedback(self.feedback);
+    }
+
+    /// The feedback.
+    pub fn feedback(&self) -> f32 {
+        self.feedback
+    }
+
+    /// Change the mix between the dry and wet signals, from 0 (fully dry) to 1 (fully wet).
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix = mix.clamp(0.0, 1.0);
+        self.chorus.pin_mut().set_mix(self.mix);
+    }
+
+    /// The mix between the dry and wet signals.
+    pub fn mix(&self) -> f32 {
+        self.mix
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        juce::prepare_chorus(self.chorus.pin_mut(), spec);
+        self.spec = Some(*spec);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the chorus has been prepared. Only as many channels as the chorus was
+    /// prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_chorus(self.chorus.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.chorus.pin_mut().reset();
+    }
+}
+
+/// A transfer function for a [`WaveShaper`] or [`LookupTableTransform`], boxed so it can be
+/// passed to C++.
+pub(crate) struct BoxedWaveShaperFunction(Box<dyn Fn(f32) -> f32 + Send>);
+
+/// A wave shaper, which applies a transfer function to each sample, e.g. for distortion or
+/// saturation.
+///
+/// The transfer function can be called directly for each sample, or sampled into a lookup table
+/// when the wave shaper is created, which is cheaper for expensive functions.
+///
+/// ```no_run
+/// # use cxx_juce::juce_dsp::WaveShaper;
+/// let saturation = WaveShaper::new(f32::tanh);
+/// assert!(saturation.process_sample(10.0) < 1.0);
+/// ```
+pub struct WaveShaper {
+    shaper: UniquePtr<juce::WaveShaper>,
+    spec: Option<ProcessSpec>,
+}
+
+unsafe impl Send for WaveShaper {}
+
+impl WaveShaper {
+    /// Create a wave shaper that calls a transfer function for each sample.
+    pub fn new(function: impl Fn(f32) -> f32 + Send + 'static) -> Self {
+        Self {
+            shaper: juce::create_wave_shaper(Box::new(BoxedWaveShaperFunction(Box::new(function)))),
+            spec: None,
+        }
+    }
+
+    /// Create a wave shaper that approximates a transfer function with a lookup table.
+    ///
+    /// The function is sampled at `num_points` evenly spaced inputs between `minimum_input` and
+    /// `maximum_input`, and inputs outside that range are clamped to it. Returns an error if the
+    /// range is empty or the table has fewer than two points.
+    pub fn with_lookup_table(
+        function: impl Fn(f32) -> f32 + Send + 'static,
+        minimum_input: f32,
+        maximum_input: f32,
+        num_points: usize,
+    ) -> Result<Self> {
+        let function = BoxedWaveShaperFunction(Box::new(function));
+
+        juce::create_lookup_table_wave_shaper(&function, minimum_input, maximum_input, num_points)
+            .map(|shaper| Self { shaper, spec: None })
+    }
+
+    /// Prepare to process audio.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.spec = Some(*spec);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the wave shaper has been prepared. Only as many channels as the wave
+    /// shaper was prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_wave_shaper(self.shaper.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Process a single sample.
+    pub fn process_sample(&self, sample: f32) -> f32 {
+        juce::process_wave_shaper_sample(&self.shaper, sample)
+    }
+}
+
+/// How a [`DryWetMixer`] balances the dry and wet signals as the mix changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DryWetMixingRule {
+    /// The levels change linearly, so there is a 6dB dip in the middle for uncorrelated signals.
+    Linear = 0,
+
+    /// Both signals are at full level in the middle, and the mix only fades out one signal at
+    /// a time.
+    Balanced = 1,
+
+    /// A sine law with a 3dB dip in the middle.
+    Sin3dB = 2,
+
+    /// A sine law with a 4.5dB dip in the middle.
+    Sin4p5dB = 3,
+
+    /// A sine law with a 6dB dip in the middle.
+    Sin6dB = 4,
+
+    /// A square root law with a 3dB dip in the middle.
+    SquareRoot3dB = 5,
+
+    /// A square root law with a 4.5dB dip in the middle.
+    SquareRoot4p5dB = 6,
+}
+
+/// Mixes a dry signal with a processed (wet) version of it, delaying the dry signal to match any
+/// latency in the wet path.
+///
+/// For each block, push the dry samples before processing the wet signal in place, then mix the
+/// dry samples back in:
+///
+/// ```no_run
+/// # use cxx_juce::{juce_audio_devices::OutputAudioSampleBuffer, juce_dsp::DryWetMixer};
+/// # fn process_wet(_: &mut OutputAudioSampleBuffer<'_>) {}
+/// # fn process(mixer: &mut DryWetMixer, buffer: &mut OutputAudioSampleBuffer<'_>) {
+/// mixer.push_dry_samples(&buffer.as_input());
+/// process_wet(buffer);
+/// mixer.mix_wet_samples(buffer);
+/// # }
+/// ```
+pub struct DryWetMixer {
+    mixer: UniquePtr<juce::DryWetMixer>,
+    spec: Option<ProcessSpec>,
+    mixing_rule: DryWetMixingRule,
+    wet_mix_proportion: f32,
+    wet_latency: f32,
+    maximum_wet_latency: usize,
+}
+
+unsafe impl Send for DryWetMixer {}
+
+impl Default for DryWetMixer {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl DryWetMixer {
+    /// Create a fully wet mixer with a linear mixing rule, that can compensate for up to the
+    /// given latency in the wet path.
+    pub fn new(maximum_wet_latency_in_samples: usize) -> Self {
+        Self {
+            mixer: juce::create_dry_wet_mixer(maximum_wet_latency_in_samples),
+            spec: None,
+            mixing_rule: DryWetMixingRule::Linear,
+            wet_mix_proportion: 1.0,
+            wet_latency: 0.0,
+            maximum_wet_latency: maximum_wet_latency_in_samples,
+        }
+    }
+
+    /// Set the mixing rule.
+    pub fn with_mixing_rule(mut self, rule: DryWetMixingRule) -> Self {
+        self.set_mixing_rule(rule);
+        self
+    }
+
+    /// Set the proportion of the wet signal.
+    pub fn with_wet_mix_proportion(mut self, proportion: f32) -> Self {
+        self.set_wet_mix_proportion(proportion);
+        self
+    }
+
+    /// Set the latency of the wet path.
+    pub fn with_wet_latency(mut self, latency_in_samples: f32) -> Self {
+        self.set_wet_latency(latency_in_samples);
+        self
+    }
+
+    /// Change the mixing rule.
+    pub fn set_mixing_rule(&mut self, rule: DryWetMixingRule) {
+        juce::set_dry_wet_mixing_rule(self.mixer.pin_mut(), rule as i32);
+        self.mixing_rule = rule;
+    }
+
+    /// The mixing rule.
+    pub fn mixing_rule(&self) -> DryWetMixingRule {
+        self.mixing_rule
+    }
+
+    /// Change the proportion of the wet signal, from 0 (fully dry) to 1 (fully wet).
+    ///
+    /// Changes are smoothed, so this is safe to do while processing.
+    pub fn set_wet_mix_proportion(&mut self, proportion: f32) {
+        self.wet_mix_proportion = proportion.clamp(0.0, 1.0);
+        self.mixer
+            .pin_mut()
+            .set_wet_mix_proportion(self.wet_mix_proportion);
+    }
+
+    /// The proportion of the wet signal.
+    pub fn wet_mix_proportion(&self) -> f32 {
+        self.wet_mix_proportion
+    }
+
+    /// Change the latency of the wet path, which the dry signal is delayed by.
+    ///
+    /// The latency is clamped to the maximum the mixer was created with.
+    pub fn set_wet_latency(&mut self, latency_in_samples: f32) {
+        self.wet_latency = latency_in_samples.clamp(0.0, self.maximum_wet_latency as f32);
+        self.mixer.pin_mut().set_wet_latency(self.wet_latency);
+    }
+
+    /// The latency of the wet path.
+    pub fn wet_latency(&self) -> f32 {
+        self.wet_latency
+    }
+
+    /// The maximum latency of the wet path the mixer can compensate for.
+    pub fn maximum_wet_latency(&self) -> usize {
+        self.maximum_wet_latency
+    }
+
+    /// Prepare to process audio.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        juce::prepare_dry_wet_mixer(self.mixer.pin_mut(), spec);
+        self.spec = Some(*spec);
+    }
+
+    /// Store the dry samples of a block, before it's processed.
+    ///
+    /// Does nothing until the mixer has been prepared. Only as many channels and samples as
+    /// the mixer was prepared for are stored.
+    pub fn push_dry_samples(&mut self, buffer: &InputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::push_dry_samples(self.mixer.pin_mut(), buffer.buffer, spec);
+        }
+    }
+
+    /// Mix the stored dry samples into a processed block.
+    ///
+    /// Does nothing until the mixer has been prepared. Only as many channels and samples as
+    /// the mixer was prepared for are mixed.
+    pub fn mix_wet_samples(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::mix_wet_samples(self.mixer.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Clear the stored dry samples.
+    pub fn reset(&mut self) {
+        self.mixer.pin_mut().reset();
+    }
+}
+
+/// How a [`Panner`] sets the level of each side as the pan changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PannerRule {
+    /// The levels change linearly, so there is a 6dB dip in the centre.
+    Linear = 0,
+
+    /// Both sides are at full level in the centre, and panning only turns down the other side.
+    Balanced = 1,
+
+    /// A sine law with a 3dB dip in the centre.
+    Sin3dB = 2,
+
+    /// A sine law with a 4.5dB dip in the centre.
+    Sin4p5dB = 3,
+
+    /// A sine law with a 6dB dip in the centre.
+    Sin6dB = 4,
+
+    /// A square root law with a 3dB dip in the centre.
+    SquareRoot3dB = 5,
+
+    /// A square root law with a 4.5dB dip in the centre.
+    SquareRoot4p5dB = 6,
+}
+
+/// Places a signal in the stereo field.
+///
+/// The panner works on the first two channels of a buffer. A mono signal should be copied to
+/// both channels before panning.
+pub struct Panner {
+    panner: UniquePtr<juce::Panner>,
+    spec: Option<ProcessSpec>,
+    rule: PannerRule,
+    pan: f32,
+}
+
+unsafe impl Send for Panner {}
+
+impl Default for Panner {
+    fn default() -> Self {
+        let mut panner = Self {
+            panner: juce::create_panner(),
+            spec: None,
+            rule: PannerRule::Balanced,
+            pan: 0.0,
+        };
+
+        panner.set_rule(panner.rule);
+        panner.set_pan(panner.pan);
+        panner
+    }
+}
+
+impl Panner {
+    /// Create a centred panner with a balanced rule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the pan rule.
+    pub fn with_rule(mut self, rule: PannerRule) -> Self {
+        self.set_rule(rule);
+        self
+    }
+
+    /// Set the pan position.
+    pub fn with_pan(mut self, pan: f32) -> Self {
+        self.set_pan(pan);
+        self
+    }
+
+    /// Change the pan rule.
+    pub fn set_rule(&mut self, rule: PannerRule) {
+        juce::set_panner_rule(self.panner.pin_mut(), rule as i32);
+        self.rule = rule;
+    }
+
+    /// The pan rule.
+    pub fn rule(&self) -> PannerRule {
+        self.rule
+    }
+
+    /// Change the pan position, from -1 (fully left) to 1 (fully right).
+    pub fn set_pan(&mut self, pan: f32) {
+        self.pan = pan.clamp(-1.0, 1.0);
+        self.panner.pin_mut().set_pan(self.pan);
+    }
+
+    /// The pan position.
+    pub fn pan(&self) -> f32 {
+        self.pan
+    }
+
+    /// Prepare to process audio.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        juce::prepare_panner(self.panner.pin_mut(), spec);
+        self.spec = Some(*spec);
+    }
+
+    /// Pan the first two channels of a buffer in place.
+    ///
+    /// Does nothing until the panner has been prepared, or if the buffer has fewer than two
+    /// channels.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_panner(self.panner.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Reset the pan position smoothing.
+    pub fn reset(&mut self) {
+        self.panner.pin_mut().reset();
+    }
+}
+
+/// How a [`BallisticsFilter`] measures the level of a signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BallisticsLevelCalculationType {
+    /// Follow the absolute value of the signal.
+    Peak = 0,
+
+    /// Follow the root mean square of the signal.
+    Rms = 1,
+}
+
+/// An envelope follower with separate attack and release times.
+///
+/// Processing replaces the signal with its envelope, which makes it suitable for level meters
+/// and for the sidechain of dynamics processors.
+pub struct BallisticsFilter {
+    filter: UniquePtr<juce::BallisticsFilter>,
+    spec: Option<ProcessSpec>,
+    level_calculation_type: BallisticsLevelCalculationType,
+    attack: Duration,
+    release: Duration,
+}
+
+unsafe impl Send for BallisticsFilter {}
+
+impl Default for BallisticsFilter {
+    fn default() -> Self {
+        let mut filter = Self {
+            filter: juce::create_ballistics_filter(),
+            spec: None,
+            level_calculation_type: BallisticsLevelCalculationType::Peak,
+            attack: Duration::from_millis(1),
+            release: Duration::from_millis(100),
+        };
+
+        filter.set_level_calculation_type(filter.level_calculation_type);
+        filter.set_attack(filter.attack);
+        filter.set_release(filter.release);
+        filter
+    }
+}
+
+impl BallisticsFilter {
+    /// Create a peak envelope follower with an attack of 1ms and a release of 100ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how the level is measured.
+    pub fn with_level_calculation_type(
+        mut self,
+        level_calculation_type: BallisticsLevelCalculationType,
+    ) -> Self {
+        self.set_level_calculation_type(level_calculation_type);
+        self
+    }
+
+    /// Set the attack time.
+    pub fn with_attack(mut self, attack: Duration) -> Self {
+        self.set_attack(attack);
+        self
+    }
+
+    /// Set the release time.
+    pub fn with_release(mut self, release: Duration) -> Self {
+        self.set_release(release);
+        self
+    }
+
+    /// Change how the level is measured.
+    pub fn set_level_calculation_type(
+        &mut self,
+        level_calculation_type: BallisticsLevelCalculationType,
+    ) {
+        juce::set_ballistics_filter_level_calculation_type(
+            self.filter.pin_mut(),
+            level_calculation_type as i32,
+        );
+        self.level_calculation_type = level_calculation_type;
+    }
+
+    /// How the level is measured.
+    pub fn level_calculation_type(&self) -> BallisticsLevelCalculationType {
+        self.level_calculation_type
+    }
+
+    /// Change how quickly the envelope rises to meet a louder signal.
+    pub fn set_attack(&mut self, attack: Duration) {
+        self.attack = attack;
+        self.filter
+            .pin_mut()
+            .set_attack_time(attack.as_secs_f32() * 1000.0);
+    }
+
+    /// The attack time.
+    pub fn attack(&self) -> Duration {
+        self.attack
+    }
+
+    /// Change how quickly the envelope falls to meet a quieter signal.
+    pub fn set_release(&mut self, release: Duration) {
+        self.release = release;
+        self.filter
+            .pin_mut()
+            .set_release_time(release.as_secs_f32() * 1000.0);
+    }
+
+    /// The release time.
+    pub fn release(&self) -> Duration {
+        self.release
+    }
+
+    /// Prepare to process audio, creating the state for each channel.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        juce::prepare_ballistics_filter(self.filter.pin_mut(), spec);
+        self.spec = Some(*spec);
+    }
+
+    /// Replace a buffer with its envelope.
+    ///
+    /// Does nothing until the filter has been prepared. Only as many channels as the filter was
+    /// prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_ballistics_filter(self.filter.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Process a single sample of a channel, returning the envelope.
+    ///
+    /// The sample is returned unchanged if the filter wasn't prepared for the channel.
+    pub fn process_sample(&mut self, channel: usize, sample: f32) -> f32 {
+        match &self.spec {
+            Some(spec) if channel < spec.num_channels => {
+                self.filter.pin_mut().process_sample(channel as i32, sample)
+            }
+            _ => sample,
+        }
+    }
+
+    /// Flush denormals from the state of each channel.
+    ///
+    /// Call this after processing samples one at a time.
+    pub fn snap_to_zero(&mut self) {
+        self.filter.pin_mut().snap_to_zero();
+    }
+
+    /// Clear the state of each channel.
+    pub fn reset(&mut self) {
+        self.filter.pin_mut().reset();
+    }
+}
+
+/// How many times higher than the original sample rate an [`Oversampling`] processes at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OversamplingFactor {
+    /// Twice the sample rate.
+    X2 = 1,
+
+    /// Four times the sample rate.
+    X4 = 2,
+
+    /// Eight times the sample rate.
+    X8 = 3,
+
+    /// Sixteen times the sample rate.
+    X16 = 4,
+}
+
+/// The filters an [`Oversampling`] uses to remove aliasing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OversamplingFilterType {
+    /// Linear phase half-band FIR filters, which add more latency.
+    HalfBandFIREquiripple = 0,
+
+    /// Minimum phase half-band IIR filters, which add less latency.
+    HalfBandPolyphaseIIR = 1,
+}
+
+/// Runs part of a signal chain at a higher sample rate, so nonlinear processing doesn't alias.
+///
+/// For each block, upsample the input, process the upsampled buffer, then downsample the result
+/// back into the output:
+///
+/// ```no_run
+/// # use cxx_juce::{
+/// #     juce_audio_devices::OutputAudioSampleBuffer,
+/// #     juce_dsp::{Oversampling, WaveShaper},
+/// #     Result,
+/// # };
+/// # fn process(
+/// #     oversampling: &mut Oversampling,
+/// #     shaper: &mut WaveShaper,
+/// #     buffer: &mut OutputAudioSampleBuffer<'_>,
+/// # ) -> Result<()> {
+/// let mut upsampled = oversampling.process_samples_up(&buffer.as_input())?;
+/// shaper.process(&mut upsampled);
+/// oversampling.process_samples_down(buffer)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Processors working on the upsampled buffer should be prepared with a sample rate and block
+/// size multiplied by the [`factor`](Self::factor).
+pub struct Oversampling {
+    oversampling: UniquePtr<juce::Oversampling>,
+    factor: OversamplingFactor,
+    filter_type: OversamplingFilterType,
+    maximum_quality: bool,
+    using_integer_latency: bool,
+}
+
+unsafe impl Send for Oversampling {}
+
+impl Oversampling {
+    /// Create an oversampling with maximum quality filters and fractional latency.
+    pub fn new(factor: OversamplingFactor, filter_type: OversamplingFilterType) -> Self {
+        Self {
+            oversampling: juce::create_oversampling(factor as usize, filter_type as i32),
+            factor,
+            filter_type,
+            maximum_quality: true,
+            using_integer_latency: false,
+        }
+    }
+
+    /// Set whether to use the highest quality filters, or cheaper filters with more aliasing.
+    pub fn with_maximum_quality(mut self, maximum_quality: bool) -> Self {
+        self.maximum_quality = maximum_quality;
+        self.oversampling
+            .pin_mut()
+            .set_maximum_quality(maximum_quality);
+        self
+    }
+
+    /// Set whether the latency is rounded up to a whole number of samples.
+    pub fn with_integer_latency(mut self, using_integer_latency: bool) -> Self {
+        self.set_using_integer_latency(using_integer_latency);
+        self
+    }
+
+    /// The oversampling factor.
+    pub fn factor(&self) -> OversamplingFactor {
+        self.factor
+    }
+
+    /// The number of times higher than the original sample rate the processing happens at.
+    pub fn oversampling_factor(&self) -> usize {
+        self.oversampling.get_oversampling_factor()
+    }
+
+    /// The filters used to remove aliasing.
+    pub fn filter_type(&self) -> OversamplingFilterType {
+        self.filter_type
+    }
+
+    /// Whether the highest quality filters are used.
+    pub fn is_maximum_quality(&self) -> bool {
+        self.maximum_quality
+    }
+
+    /// Change whether the latency is rounded up to a whole number of samples, which makes it
+    /// easier to compensate for elsewhere.
+    ///
+    /// This clears the state of the filters.
+    pub fn set_using_integer_latency(&mut self, using_integer_latency: bool) {
+        self.using_integer_latency = using_integer_latency;
+        self.oversampling
+            .pin_mut()
+            .set_using_integer_latency(using_integer_latency);
+    }
+
+    /// Whether the latency is rounded up to a whole number of samples.
+    pub fn is_using_integer_latency(&self) -> bool {
+        self.using_integer_latency
+    }
+
+    /// The latency added by upsampling and downsampling, in samples at the original sample
+    /// rate.
+    pub fn latency_in_samples(&self) -> f32 {
+        self.oversampling.get_latency_in_samples()
+    }
+
+    /// Prepare to process audio, allocating the upsampled buffer.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        self.oversampling.pin_mut().prepare(spec);
+    }
+
+    /// Upsample a block, returning the upsampled buffer to be processed.
+    ///
+    /// Only as many channels as the oversampling was prepared for are upsampled. Returns an
+    /// error if the oversampling hasn't been prepared, or the block is larger than it was
+    /// prepared for.
+    pub fn process_samples_up(
+        &mut self,
+        input: &InputAudioSampleBuffer<'_>,
+    ) -> Result<OutputAudioSampleBuffer<'_>> {
+        self.oversampling
+            .pin_mut()
+            .process_samples_up(input.buffer)
+            .map(OutputAudioSampleBuffer::new)
+    }
+
+    /// Downsample the upsampled buffer into a block.
+    ///
+    /// The block should have the same size as the block that was last upsampled. Returns an
+    /// error if the oversampling hasn't been prepared, or the block is larger than it was
+    /// prepared for.
+    pub fn process_samples_down(&mut self, output: &mut OutputAudioSampleBuffer<'_>) -> Result<()> {
+        self.oversampling
+            .pin_mut()
+            .process_samples_down(output.buffer.as_mut())
+    }
+
+    /// Clear the state of the filters.
+    pub fn reset(&mut self) {
+        self.oversampling.pin_mut().reset();
+    }
+}
+
+/// Applies a gain to a signal, ramping smoothly whenever the gain changes.
+pub struct Gain {
+    gain: UniquePtr<juce::Gain>,
+    spec: Option<ProcessSpec>,
+}
+
+unsafe impl Send for Gain {}
+
+impl Default for Gain {
+    fn default() -> Self {
+        let mut gain = Self {
+            gain: juce::create_gain(),
+            spec: None,
+        };
+
+        gain.set_gain_linear(1.0);
+        gain
+    }
+}
+
+impl Gain {
+    /// Create a gain of 1, with no ramp.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the gain as a linear multiplier.
+    pub fn with_gain_linear(mut self, gain: f32) -> Self {
+        self.set_gain_linear(gain);
+        self
+    }
+
+    /// Set the gain in decibels.
+    pub fn with_gain_decibels(mut self, gain: f32) -> Self {
+        self.set_gain_decibels(gain);
+        self
+    }
+
+    /// Set how long it takes to ramp to a new gain.
+    pub fn with_ramp_duration(mut self, ramp_duration: Duration) -> Self {
+        self.set_ramp_duration(ramp_duration);
+        self
+    }
+
+    /// Change the gain as a linear multiplier.
+    pub fn set_gain_linear(&mut self, gain: f32) {
+        self.gain.pin_mut().set_gain_linear(gain);
+    }
+
+    /// The gain being ramped towards, as a linear multiplier.
+    pub fn gain_linear(&self) -> f32 {
+        self.gain.get_gain_linear()
+    }
+
+    /// Change the gain in decibels.
+    ///
+    /// Gains of -100dB or lower are treated as silence.
+    pub fn set_gain_decibels(&mut self, gain: f32) {
+        self.gain.pin_mut().set_gain_decibels(gain);
+    }
+
+    /// The gain being ramped towards, in decibels.
+    pub fn gain_decibels(&self) -> f32 {
+        self.gain.get_gain_decibels()
+    }
+
+    /// Change how long it takes to ramp to a new gain.
+    pub fn set_ramp_duration(&mut self, ramp_duration: Duration) {
+        self.gain
+            .pin_mut()
+            .set_ramp_duration_seconds(ramp_duration.as_secs_f64());
+    }
+
+    /// How long it takes to ramp to a new gain.
+    pub fn ramp_duration(&self) -> Duration {
+        Duration::from_secs_f64(self.gain.get_ramp_duration_seconds())
+    }
+
+    /// Returns true if the gain is ramping towards a new value.
+    pub fn is_smoothing(&self) -> bool {
+        self.gain.is_smoothing()
+    }
+
+    /// Prepare to process audio.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        juce::prepare_gain(self.gain.pin_mut(), spec);
+        self.spec = Some(*spec);
+    }
+
+    /// Process a buffer in place.
+    ///
+    /// Does nothing until the gain has been prepared. Only as many channels as the gain was
+    /// prepared for are processed.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        if let Some(spec) = &self.spec {
+            juce::process_gain(self.gain.pin_mut(), buffer.buffer.as_mut(), spec);
+        }
+    }
+
+    /// Process a single sample, advancing the ramp by one sample.
+    pub fn process_sample(&mut self, sample: f32) -> f32 {
+        self.gain.pin_mut().process_sample(sample)
+    }
+
+    /// Jump to the gain being ramped towards.
+    pub fn reset(&mut self) {
+        self.gain.pin_mut().reset();
+    }
+}
+
+/// An approximation of a function, sampled into a table once so that it's cheap to evaluate on
+/// the audio thread.
+///
+/// ```no_run
+/// # use cxx_juce::juce_dsp::LookupTableTransform;
+/// let decibels_to_gain =
+///     LookupTableTransform::new(|db| 10.0_f32.powf(db / 20.0), -100.0, 0.0, 1024).unwrap();
+/// assert!((decibels_to_gain.process_sample(-6.0) - 0.501).abs() < 0.001);
+/// ```
+pub struct LookupTableTransform {
+    transform: UniquePtr<juce::LookupTableTransform>,
+    minimum_input: f32,
+    maximum_input: f32,
+    num_points: usize,
+}
+
+unsafe impl Send for LookupTableTransform {}
+
+impl LookupTableTransform {
+    /// Sample a function at `num_points` evenly spaced inputs between `minimum_input` and
+    /// `maximum_input`.
+    ///
+    /// Returns an error if the range is empty or the table has fewer than two points.
+    pub fn new(
+        function: impl Fn(f32) -> f32 + Send + 'static,
+        minimum_input: f32,
+        maximum_input: f32,
+        num_points: usize,
+    ) -> Result<Self> {
+        let function = BoxedWaveShaperFunction(Box::new(function));
+
+        juce::create_lookup_table_transform(&function, minimum_input, maximum_input, num_points)
+            .map(|transform| Self {
+                transform,
+                minimum_input,
+                maximum_input,
+                num_points,
+            })
+    }
+
+    /// The smallest input in the table.
+    pub fn minimum_input(&self) -> f32 {
+        self.minimum_input
+    }
+
+    /// The largest input in the table.
+    pub fn maximum_input(&self) -> f32 {
+        self.maximum_input
+    }
+
+    /// The number of points in the table.
+    pub fn num_points(&self) -> usize {
+        self.num_points
+    }
+
+    /// Evaluate the function by interpolating between the nearest points in the table.
+    ///
+    /// Inputs outside the range of the table are clamped to it, and NaN is treated as the
+    /// minimum input.
+    pub fn process_sample(&self, input: f32) -> f32 {
+        self.transform.process_sample(self.clamp_input(input))
+    }
+
+    /// Evaluate the function for each sample in place.
+    pub fn process(&self, samples: &mut [f32]) {
+        for sample in samples.iter_mut() {
+            *sample = self.clamp_input(*sample);
+        }
+
+        juce::process_lookup_table_transform(&self.transform, samples);
+    }
+
+    fn clamp_input(&self, input: f32) -> f32 {
+        if input >= self.minimum_input {
+            input.min(self.maximum_input)
+        } else {
+            self.minimum_input
+        }
+    }
+}
+
+/// A block of audio to be processed in place.
+pub struct ProcessContextReplacing<'a, 'b> {
+    buffer: &'a mut OutputAudioSampleBuffer<'b>,
+    bypassed: bool,
+}
+
+impl<'a, 'b> ProcessContextReplacing<'a, 'b> {
+    /// Create a context that processes a buffer in place.
+    pub fn new(buffer: &'a mut OutputAudioSampleBuffer<'b>) -> Self {
+        Self {
+            buffer,
+            bypassed: false,
+        }
+    }
+
+    /// Set whether processing should be skipped.
+    pub fn with_bypassed(mut self, bypassed: bool) -> Self {
+        self.set_bypassed(bypassed);
+        self
+    }
+
+    /// Change whether processing should be skipped.
+    pub fn set_bypassed(&mut self, bypassed: bool) {
+        self.bypassed = bypassed;
+    }
+
+    /// Returns true if processing should be skipped.
+    pub fn is_bypassed(&self) -> bool {
+        self.bypassed
+    }
+
+    /// The samples to be processed.
+    pub fn input_buffer(&self) -> InputAudioSampleBuffer<'_> {
+        self.buffer.as_input()
+    }
+
+    /// The buffer to write the processed samples to, which is also the input.
+    pub fn output_buffer(&mut self) -> &mut OutputAudioSampleBuffer<'b> {
+        self.buffer
+    }
+}
+
+/// A block of audio to be processed from an input buffer into a separate output buffer.
+pub struct ProcessContextNonReplacing<'a, 'b> {
+    input: InputAudioSampleBuffer<'a>,
+    output: &'a mut OutputAudioSampleBuffer<'b>,
+    bypassed: bool,
+}
+
+impl<'a, 'b> ProcessContextNonReplacing<'a, 'b> {
+    /// Create a context that processes an input buffer into an output buffer.
+    pub fn new(
+        input: InputAudioSampleBuffer<'a>,
+        output: &'a mut OutputAudioSampleBuffer<'b>,
+    ) -> Self {
+        Self {
+            input,
+            output,
+            bypassed: false,
+        }
+    }
+
+    /// Set whether processing should be skipped.
+    pub fn with_bypassed(mut self, bypassed: bool) -> Self {
+        self.set_bypassed(bypassed);
+        self
+    }
+
+    /// Change whether processing should be skipped.
+    ///
+    /// A bypassed context still expects the input to be copied to the output.
+    pub fn set_bypassed(&mut self, bypassed: bool) {
+        self.bypassed = bypassed;
+    }
+
+    /// Returns true if processing should be skipped.
+    pub fn is_bypassed(&self) -> bool {
+        self.bypassed
+    }
+
+    /// The samples to be processed.
+    pub fn input_buffer(&self) -> &InputAudioSampleBuffer<'a> {
+        &self.input
+    }
+
+    /// The buffer to write the processed samples to.
+    pub fn output_buffer(&mut self) -> &mut OutputAudioSampleBuffer<'b> {
+        self.output
+    }
+
+    /// Copy the input to the output, for as many channels and samples as both buffers have.
+    pub fn copy_input_to_output(&mut self) {
+        let channels = self.input.channels().min(self.output.channels());
+        let samples = self.input.samples().min(self.output.samples());
+
+        for channel in 0..channels {
+            self.output[channel][..samples].copy_from_slice(&self.input[channel][..samples]);
+        }
+    }
+}
+
+/// A processor that can be prepared, process blocks of audio, and be reset.
+///
+/// This is implemented by the processors in this module, so they share one interface and can
+/// be composed in a [`ProcessorChain`].
+pub trait Processor: Send {
+    /// Prepare to process audio.
+    fn prepare(&mut self, spec: &ProcessSpec);
+
+    /// Process a block in place.
+    ///
+    /// The block should be left untouched if the context is bypassed.
+    fn process(&mut self, context: &mut ProcessContextReplacing<'_, '_>);
+
+    /// Process an input block into a separate output block.
+    ///
+    /// By default the input is copied to the output, which is then processed in place.
+    fn process_non_replacing(&mut self, context: &mut ProcessContextNonReplacing<'_, '_>) {
+        context.copy_input_to_output();
+
+        let bypassed = context.is_bypassed();
+        self.process(
+            &mut ProcessContextReplacing::new(context.output_buffer()).with_bypassed(bypassed),
+        );
+    }
+
+    /// Clear any state.
+    ///
+    /// Processors without any state don't need to implement this.
+    fn reset(&mut self) {}
+}
+
+macro_rules! impl_processor {
+    ($($processor:ty),* $(,)?) => {
+        $(
+            impl Processor for $processor {
+                fn prepare(&mut self, spec: &ProcessSpec) {
+                    <$processor>::prepare(self, spec);
+                }
+
+                fn process(&mut self, context: &mut ProcessContextReplacing<'_, '_>) {
+                    if !context.is_bypassed() {
+                        <$processor>::process(self, context.output_buffer());
+                    }
+                }
+
+                fn reset(&mut self) {
+                    <$processor>::reset(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_processor!(
+    BallisticsFilter,
+    Chorus,
+    Compressor,
+    Convolution,
+    DelayLine,
+    FIRFilter,
+    FirstOrderTPTFilter,
+    Gain,
+    IIRFilter,
+    LadderFilter,
+    Limiter,
+    LinkwitzRileyFilter,
+    NoiseGate,
+    Oscillator,
+    Panner,
+    Phaser,
+    ProcessorChain,
+    Reverb,
+    StateVariableTPTFilter,
+);
+
+impl Processor for WaveShaper {
+    fn prepare(&mut self, spec: &ProcessSpec) {
+        WaveShaper::prepare(self, spec);
+    }
+
+    fn process(&mut self, context: &mut ProcessContextReplacing<'_, '_>) {
+        if !context.is_bypassed() {
+            WaveShaper::process(self, context.output_buffer());
+        }
+    }
+}
+
+/// A [`Processor`] that can be downcast to its concrete type.
+trait ChainedProcessor: Processor {
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Processor + 'static> ChainedProcessor for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+struct ChainSlot {
+    processor: Box<dyn ChainedProcessor>,
+    bypassed: bool,
+}
+
+/// Processes a buffer with a sequence of processors, one after another.
+///
+/// ```no_run
+/// # use {
+/// #     cxx_juce::juce_dsp::{Gain, Limiter, ProcessorChain, Reverb, StateVariableTPTFilter},
+/// #     std::time::Duration,
+/// # };
+/// let mut chain = ProcessorChain::new()
+///     .with(Gain::new().with_gain_decibels(6.0))
+///     .with(StateVariableTPTFilter::new().with_cutoff_frequency(2000.0))
+///     .with(Reverb::new())
+///     .with(Limiter::new().with_threshold(-1.0));
+///
+/// chain
+///     .get_mut::<Gain>(0)
+///     .unwrap()
+///     .set_ramp_duration(Duration::from_millis(50));
+/// chain.set_bypassed(2, true);
+/// ```
+///
+/// Bypassed processors are still prepared and reset along with the rest of the chain, so they
+/// are ready to process as soon as they're enabled.
+#[derive(Default)]
+pub struct ProcessorChain {
+    processors: Vec<ChainSlot>,
+}
+
+impl ProcessorChain {
+    /// Create an empty chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a processor to the end of the chain.
+    pub fn with(mut self, processor: impl Processor + 'static) -> Self {
+        self.push(processor);
+        self
+    }
+
+    /// Add a processor to the end of the chain.
+    ///
+    /// The processor should be prepared before processing if the rest of the chain already has
+    /// been.
+    pub fn push(&mut self, processor: impl Processor + 'static) {
+        self.processors.push(ChainSlot {
+            processor: Box::new(processor),
+            bypassed: false,
+        });
+    }
+
+    /// The number of processors in the chain.
+    pub fn len(&self) -> usize {
+        self.processors.len()
+    }
+
+    /// Returns true if the chain has no processors.
+    pub fn is_empty(&self) -> bool {
+        self.processors.is_empty()
+    }
+
+    /// Get the processor at an index, if it has the given type.
+    pub fn get<T: Processor + 'static>(&self, index: usize) -> Option<&T> {
+        self.processors
+            .get(index)
+            .and_then(|slot| slot.processor.as_any().downcast_ref())
+    }
+
+    /// Get the processor at an index mutably, if it has the given type.
+    pub fn get_mut<T: Processor + 'static>(&mut self, index: usize) -> Option<&mut T> {
+        self.processors
+            .get_mut(index)
+            .and_then(|slot| slot.processor.as_any_mut().downcast_mut())
+    }
+
+    /// Change whether the processor at an index is skipped when processing.
+    pub fn set_bypassed(&mut self, index: usize, bypassed: bool) {
+        if let Some(slot) = self.processors.get_mut(index) {
+            slot.bypassed = bypassed;
+        }
+    }
+
+    /// Returns true if the processor at an index is skipped when processing.
+    pub fn is_bypassed(&self, index: usize) -> bool {
+        self.processors.get(index).is_some_and(|slot| slot.bypassed)
+    }
+
+    /// Prepare every processor in the chain.
+    pub fn prepare(&mut self, spec: &ProcessSpec) {
+        for slot in &mut self.processors {
+            slot.processor.prepare(spec);
+        }
+    }
+
+    /// Process a buffer in place with each processor that isn't bypassed, in order.
+    pub fn process(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>) {
+        for slot in &mut self.processors {
+            let mut context = ProcessContextReplacing::new(buffer).with_bypassed(slot.bypassed);
+            slot.processor.process(&mut context);
+        }
+    }
+
+    /// Reset every processor in the chain.
+    pub fn reset(&mut self) {
+        for slot in &mut self.processors {
+            slot.processor.reset();
+        }
+    }
+}
+
+pub(crate) mod ffi {
+    use super::*;
+
+    pub mod wave_shaper {
+        use super::*;
+
+        pub fn wave_shaper_call(self_: &BoxedWaveShaperFunction, x: f32) -> f32 {
+            (self_.0)(x)
+        }
+    }
+}
@@ -0,0 +1,469 @@
+//! The message thread, its event loop, and timers.
+
+use {
+    crate::{juce, Result, JUCE},
+    cxx::UniquePtr,
+    std::{marker::PhantomData, pin::Pin, time::Duration},
+};
+
+/// Controls the message thread, which runs the event loop that delivers asynchronous callbacks,
+/// timers, and window events.
+///
+/// The message thread is the thread that called [`JUCE::initialise`], or for
+/// [`JUCE::initialise_headless`], the first thread that needed one. Whoever owns that thread decides
+/// how its messages are dispatched: by running the loop until it's stopped, or by dispatching
+/// messages for a while at a time from their own event loop.
+pub struct MessageManager;
+
+impl MessageManager {
+    /// Run the message loop on the current thread until [`MessageManager::stop_dispatch_loop`] is
+    /// called.
+    ///
+    /// Returns an error if the current thread isn't the message thread.
+    pub fn run_dispatch_loop(_juce: &JUCE) -> Result<()> {
+        juce::run_dispatch_loop()
+    }
+
+    /// Dispatch messages on the current thread for up to `duration`. Pass [`Duration::ZERO`] to
+    /// dispatch at most one pending message without waiting.
+    ///
+    /// Returns false if [`MessageManager::stop_dispatch_loop`] has been called, or an error if the
+    /// current thread isn't the message thread.
+    pub fn run_dispatch_loop_for(_juce: &JUCE, duration: Duration) -> Result<bool> {
+        juce::run_dispatch_loop_until(duration.as_millis().min(i32::MAX as u128) as i32)
+    }
+
+    /// Stop the message loop. This can be called from any thread.
+    ///
+    /// Once stopped, the loop can't be run again.
+    pub fn stop_dispatch_loop() {
+        juce::stop_dispatch_loop();
+    }
+
+    /// Whether [`MessageManager::stop_dispatch_loop`] has been called.
+    pub fn has_stop_been_called() -> bool {
+        juce::has_stop_been_called()
+    }
+
+    /// Whether the current thread is the message thread.
+    pub fn is_this_the_message_thread() -> bool {
+        juce::is_this_the_message_thread()
+    }
+}
+
+/// Call a function on the message thread, which many JUCE classes require, e.g. to change audio
+/// devices or open windows. This can be called from any thread.
+///
+/// The function runs the next time the message thread dispatches messages. Returns false, dropping
+/// the function without calling it, if there's no message thread or its loop has been stopped.
+pub fn call_async(f: impl FnOnce() + Send + 'static) -> bool {
+    juce::call_async(Box::new(BoxedAsyncCallback(Box::new(f))))
+}
+
+pub(crate) struct BoxedAsyncCallback(Box<dyn FnOnce() + Send>);
+
+/// Calls a function periodically on the message thread.
+///
+/// Timers should be created and dropped on the message thread, and the function is only called
+/// while the message thread is dispatching messages. The interval isn't exact, as the function is
+/// called late if the message thread is busy.
+pub struct Timer<'juce> {
+    timer: UniquePtr<juce::TimerHandle>,
+    interval: Duration,
+    _juce: PhantomData<&'juce ()>,
+}
+
+impl<'juce> Timer<'juce> {
+    /// Create a stopped timer that calls the function every `interval` once it's started.
+    pub fn new(
+        _juce: &'juce JUCE,
+        interval: Duration,
+        callback: impl FnMut() + Send + 'static,
+    ) -> Self {
+        Self {
+            timer: juce::create_timer(Box::new(BoxedTimerCallback(Box::new(callback)))),
+            interval,
+            _juce: PhantomData,
+        }
+    }
+
+    /// Start calling the function, the first time after one interval. Restarts the countdown if
+    /// the timer is already running.
+    pub fn start(&mut self) {
+        let milliseconds = self.interval.as_millis().clamp(1, i32::MAX as u128) as i32;
+        self.timer.pin_mut().start_timer(milliseconds);
+    }
+
+    /// Stop calling the function.
+    pub fn stop(&mut self) {
+        self.timer.pin_mut().stop_timer();
+    }
+
+    /// Whether the timer is running.
+    pub fn is_running(&self) -> bool {
+        self.timer.is_timer_running()
+    }
+
+    /// How often the function is called.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Change how often the function is called, restarting the countdown if the timer is running.
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+
+        if self.is_running() {
+            self.start();
+        }
+    }
+}
+
+pub(crate) struct BoxedTimerCallback(Box<dyn FnMut() + Send>);
+
+/// Calls a function periodically on its own high priority thread, with more accurate timing than
+/// a [`Timer`], e.g. to send MIDI clock messages.
+///
+/// The interval is a whole number of milliseconds, and the function should return quickly so that
+/// it doesn't delay the next call.
+pub struct HighResolutionTimer<'juce> {
+    timer: UniquePtr<juce::HighResolutionTimerHandle>,
+    interval: Duration,
+    _juce: PhantomData<&'juce ()>,
+}
+
+unsafe impl Send for HighResolutionTimer<'_> {}
+
+impl<'juce> HighResolutionTimer<'juce> {
+    /// Create a stopped timer that calls the function every `interval` once it's started.
+    pub fn new(
+        _juce: &'juce JUCE,
+        interval: Duration,
+        callback: impl FnMut() + Send + 'static,
+    ) -> Self {
+        Self {
+            timer: juce::create_high_resolution_timer(Box::new(BoxedHighResolutionTimerCallback(
+                Box::new(callback),
+            ))),
+            interval,
+            _juce: PhantomData,
+        }
+    }
+
+    /// Start calling the function, the first time after one interval. Restarts the countdown if
+    /// the timer is already running.
+    pub fn start(&mut self) {
+        let milliseconds = self.interval.as_millis().clamp(1, i32::MAX as u128) as i32;
+        self.timer.pin_mut().start_timer(milliseconds);
+    }
+
+    /// Stop calling the function, waiting for a call that's in progress to finish.
+    pub fn stop(&mut self) {
+        self.timer.pin_mut().stop_timer();
+    }
+
+    /// Whether the timer is running.
+    pub fn is_running(&self) -> bool {
+        self.timer.is_timer_running()
+    }
+
+    /// How often the function is called.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Change how often the function is called, restarting the countdown if the timer is running.
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+
+        if self.is_running() {
+            self.start();
+        }
+    }
+}
+
+pub(crate) struct BoxedHighResolutionTimerCallback(Box<dyn FnMut() + Send>);
+
+/// The magic number JUCE uses to mark the start of each message, unless another one is given.
+const DEFAULT_MAGIC_MESSAGE_HEADER: u32 = 0xf2b4_9e2c;
+
+fn timeout_ms(timeout: Option<Duration>) -> i32 {
+    timeout.map_or(-1, |timeout| {
+        timeout.as_millis().min(i32::MAX as u128) as i32
+    })
+}
+
+/// Receives the events of an [`InterprocessConnection`].
+///
+/// The callbacks are called on the connection's own thread.
+pub trait InterprocessConnectionListener: Send {
+    /// Called when the connection is established.
+    fn connection_made(&mut self, _connection: &InterprocessConnectionRef) {}
+
+    /// Called when the connection is closed, either by the other end or by a call to
+    /// [`InterprocessConnection::disconnect`].
+    fn connection_lost(&mut self) {}
+
+    /// Called when a message is received.
+    fn message_received(&mut self, connection: &InterprocessConnectionRef, message: &[u8]);
+}
+
+impl<F> InterprocessConnectionListener for F
+where
+    F: FnMut(&InterprocessConnectionRef, &[u8]) + Send,
+{
+    fn message_received(&mut self, connection: &InterprocessConnectionRef, message: &[u8]) {
+        self(connection, message)
+    }
+}
+
+/// A connection to another process, over a socket or a named pipe.
+///
+/// Messages are sent as blocks of bytes, which arrive in one piece at the other end. Both ends must
+/// use JUCE's message framing, i.e. be an [`InterprocessConnection`] or a JUCE
+/// `InterprocessConnection` with the same magic message header.
+///
+/// Dereferences to an [`InterprocessConnectionRef`], which is used to send messages.
+pub struct InterprocessConnection(UniquePtr<juce::InterprocessConnection>);
+
+unsafe impl Send for InterprocessConnection {}
+unsafe impl Sync for InterprocessConnection {}
+
+impl InterprocessConnection {
+    /// Create an unconnected connection.
+    pub fn new(listener: impl InterprocessConnectionListener + 'static) -> Self {
+        Self::with_magic_message_header(DEFAULT_MAGIC_MESSAGE_HEADER, listener)
+    }
+
+    /// Create an unconnected connection that marks its messages with a custom magic number.
+    ///
+    /// Both ends of the connection must use the same number.
+    pub fn with_magic_message_header(
+        magic_message_header: u32,
+        listener: impl InterprocessConnectionListener + 'static,
+    ) -> Self {
+        let listener: BoxedInterprocessConnectionListener = Box::new(listener);
+        Self(juce::create_interprocess_connection(
+            magic_message_header,
+            Box::new(listener),
+        ))
+    }
+
+    /// Connect to a socket on another computer, or on this one if `host_name` is `localhost`.
+    ///
+    /// Returns an error if the connection couldn't be made before the timeout, or if no timeout
+    /// is given, before the operating system gives up.
+    pub fn connect_to_socket(
+        &mut self,
+        host_name: &str,
+        port: u16,
+        timeout: Option<Duration>,
+    ) -> Result<()> {
+        juce::connect_to_socket(
+            self.0.pin_mut(),
+            host_name,
+            i32::from(port),
+            timeout_ms(timeout).max(0),
+        )
+    }
+
+    /// Connect to a named pipe created by another process with
+    /// [`create_pipe`](Self::create_pipe).
+    ///
+    /// Reading a message times out after `timeout`, which closes the connection. If no timeout
+    /// is given, reads wait forever.
+    pub fn connect_to_pipe(&mut self, pipe_name: &str, timeout: Option<Duration>) -> Result<()> {
+        juce::connect_to_pipe(self.0.pin_mut(), pipe_name, timeout_ms(timeout))
+    }
+
+    /// Create a named pipe for another process to connect to.
+    ///
+    /// Returns an error if the pipe couldn't be created, or if `must_not_exist` is true and the
+    /// pipe already exists.
+    pub fn create_pipe(
+        &mut self,
+        pipe_name: &str,
+        timeout: Option<Duration>,
+        must_not_exist: bool,
+    ) -> Result<()> {
+        juce::create_pipe(
+            self.0.pin_mut(),
+            pipe_name,
+            timeout_ms(timeout),
+            must_not_exist,
+        )
+    }
+
+    /// Close the connection.
+    ///
+    /// The listener's [`connection_lost`](InterprocessConnectionListener::connection_lost) is
+    /// called if the connection was open. This is also done when the connection is dropped.
+    pub fn disconnect(&mut self) {
+        juce::disconnect_interprocess_connection(self.0.pin_mut());
+    }
+}
+
+impl std::ops::Deref for InterprocessConnection {
+    type Target = InterprocessConnectionRef;
+
+    fn deref(&self) -> &Self::Target {
+        InterprocessConnectionRef::from_juce(&self.0)
+    }
+}
+
+/// A connection that can be used to send messages, see [`InterprocessConnection`].
+#[repr(transparent)]
+pub struct InterprocessConnectionRef(juce::InterprocessConnection);
+
+impl InterprocessConnectionRef {
+    fn from_juce(connection: &juce::InterprocessConnection) -> &Self {
+        // SAFETY: `InterprocessConnectionRef` is a transparent wrapper.
+        unsafe { &*(connection as *const juce::InterprocessConnection as *const Self) }
+    }
+
+    /// Whether the connection is open.
+    pub fn is_connected(&self) -> bool {
+        self.0.is_connected()
+    }
+
+    /// The name of the host at the other end of a socket, or `localhost` for a named pipe.
+    pub fn connected_host_name(&self) -> String {
+        juce::get_connected_host_name(&self.0)
+    }
+
+    /// Send a message to the other end of the connection.
+    ///
+    /// Returns false if the connection isn't open or the message couldn't be sent. Messages can be
+    /// sent from any thread, including from the listener's callbacks.
+    pub fn send_message(&self, message: &[u8]) -> bool {
+        juce::send_interprocess_message(&self.0, message)
+    }
+}
+
+/// Listens on a socket for other processes to connect to.
+///
+/// Each incoming connection gets its own [`InterprocessConnectionListener`], created by the
+/// server's factory function. The connections are owned by the server, and are closed when it's
+/// dropped.
+pub struct InterprocessConnectionServer(UniquePtr<juce::InterprocessConnectionServer>);
+
+unsafe impl Send for InterprocessConnectionServer {}
+
+impl InterprocessConnectionServer {
+    /// Create a server that creates a listener for each incoming connection.
+    pub fn new<L>(create_listener: impl FnMut() -> L + Send + 'static) -> Self
+    where
+        L: InterprocessConnectionListener + 'static,
+    {
+        Self::with_magic_message_header(DEFAULT_MAGIC_MESSAGE_HEADER, create_listener)
+    }
+
+    /// Create a server whose connections mark their messages with a custom magic number.
+    pub fn with_magic_message_header<L>(
+        magic_message_header: u32,
+        mut create_listener: impl FnMut() -> L + Send + 'static,
+    ) -> Self
+    where
+        L: InterprocessConnectionListener + 'static,
+    {
+        let factory = BoxedInterprocessConnectionFactory(Box::new(move || {
+            Box::new(create_listener()) as BoxedInterprocessConnectionListener
+        }));
+
+        Self(juce::create_interprocess_connection_server(
+            magic_message_header,
+            Box::new(factory),
+        ))
+    }
+
+    /// Start listening for connections on a port, or on any free port if `port` is zero.
+    ///
+    /// If `bind_address` is empty the server listens on all the network interfaces. Returns an
+    /// error if the port couldn't be opened.
+    pub fn start(&mut self, port: u16, bind_address: &str) -> Result<()> {
+        juce::begin_waiting_for_socket(self.0.pin_mut(), i32::from(port), bind_address)
+    }
+
+    /// Stop listening for new connections. Existing connections stay open.
+    pub fn stop(&mut self) {
+        self.0.pin_mut().stop();
+    }
+
+    /// The port the server is listening on, or [`None`] if it isn't listening.
+    pub fn port(&self) -> Option<u16> {
+        u16::try_from(self.0.get_bound_port()).ok()
+    }
+}
+
+pub(crate) type BoxedInterprocessConnectionListener = Box<dyn InterprocessConnectionListener>;
+
+type InterprocessConnectionFactoryFn =
+    Box<dyn FnMut() -> BoxedInterprocessConnectionListener + Send>;
+
+pub(crate) struct BoxedInterprocessConnectionFactory(InterprocessConnectionFactoryFn);
+
+pub(crate) mod ffi {
+    use super::*;
+
+    pub mod async_callback {
+        use super::*;
+
+        #[allow(clippy::boxed_local)]
+        pub fn async_callback_call(callback: Box<BoxedAsyncCallback>) {
+            (callback.0)();
+        }
+    }
+
+    pub mod timer_callback {
+        use super::*;
+
+        pub fn timer_callback_call(mut callback: Pin<&mut BoxedTimerCallback>) {
+            (callback.0)();
+        }
+    }
+
+    pub mod high_resolution_timer_callback {
+        use super::*;
+
+        pub fn high_resolution_timer_callback_call(
+            mut callback: Pin<&mut BoxedHighResolutionTimerCallback>,
+        ) {
+            (callback.0)();
+        }
+    }
+
+    pub mod interprocess_connection_listener {
+        use super::*;
+
+        pub fn interprocess_connection_listener_connection_made(
+            mut listener: Pin<&mut BoxedInterprocessConnectionListener>,
+            connection: &juce::InterprocessConnection,
+        ) {
+            listener.connection_made(InterprocessConnectionRef::from_juce(connection));
+        }
+
+        pub fn interprocess_connection_listener_connection_lost(
+            mut listener: Pin<&mut BoxedInterprocessConnectionListener>,
+        ) {
+            listener.connection_lost();
+        }
+
+        pub fn interprocess_connection_listener_message_received(
+            mut listener: Pin<&mut BoxedInterprocessConnectionListener>,
+            connection: &juce::InterprocessConnection,
+            message: &[u8],
+        ) {
+            listener.message_received(InterprocessConnectionRef::from_juce(connection), message);
+        }
+    }
+
+    pub mod interprocess_connection_factory {
+        use super::*;
+
+        pub fn interprocess_connection_factory_create_listener(
+            mut factory: Pin<&mut BoxedInterprocessConnectionFactory>,
+        ) -> Box<BoxedInterprocessConnectionListener> {
+            Box::new((factory.0)())
+        }
+    }
+}
@@ -2,6 +2,28 @@
 
 pub mod juce_audio_basics;
 pub mod juce_audio_devices;
+#[cfg(feature = "juce_audio_formats")]
+pub mod juce_audio_formats;
+#[cfg(feature = "juce_audio_processors")]
+pub mod juce_audio_processors;
+#[cfg(feature = "juce_audio_utils")]
+pub mod juce_audio_utils;
+pub mod juce_core;
+#[cfg(feature = "juce_data_structures")]
+pub mod juce_data_structures;
+#[cfg(feature = "juce_dsp")]
+pub mod juce_dsp;
+pub mod juce_events;
+
+#[cfg(any(
+    feature = "hound",
+    feature = "log",
+    feature = "serde_json",
+    feature = "symphonia",
+    feature = "tracing",
+    feature = "uuid"
+))]
+pub mod interop;
 
 use {
     juce_audio_devices::{
@@ -14,12 +36,109 @@ use {
             audio_io_device_type::{
                 create_device, destroy_device, get_device_names, name, scan_for_devices,
             },
+            midi_input_callback::handle_incoming_midi_message,
+            midi_thru::process_midi_thru,
         },
         BoxedAudioIODevice, BoxedAudioIODeviceCallback, BoxedAudioIODeviceType,
+        BoxedMidiInputCallback, MidiThru,
+    },
+    juce_core::{
+        ffi::input_stream::{
+            input_stream_position, input_stream_read, input_stream_set_position,
+            input_stream_total_length,
+        },
+        ffi::logger::logger_log_message,
+        ffi::thread_pool_job::thread_pool_job_run,
+        ffi::time_slice_client::time_slice_client_use_time_slice,
+        BoxedInputStream, BoxedThreadPoolJob, BoxedTimeSliceClient,
+    },
+    juce_events::{
+        ffi::{
+            async_callback::async_callback_call,
+            high_resolution_timer_callback::high_resolution_timer_callback_call,
+            interprocess_connection_factory::interprocess_connection_factory_create_listener,
+            interprocess_connection_listener::{
+                interprocess_connection_listener_connection_lost,
+                interprocess_connection_listener_connection_made,
+                interprocess_connection_listener_message_received,
+            },
+            timer_callback::timer_callback_call,
+        },
+        BoxedAsyncCallback, BoxedHighResolutionTimerCallback, BoxedInterprocessConnectionFactory,
+        BoxedInterprocessConnectionListener, BoxedTimerCallback,
     },
     std::sync::{Mutex, MutexGuard},
 };
 
+#[cfg(feature = "network")]
+use juce_core::{
+    ffi::post_progress_callback::post_progress_callback_call, BoxedPostProgressCallback,
+};
+
+#[cfg(feature = "juce_audio_formats")]
+use juce_audio_formats::{
+    ffi::custom_audio_format::{
+        custom_format_can_do_mono, custom_format_can_do_stereo, custom_format_can_handle_file,
+        custom_format_create_reader, custom_format_create_writer, custom_format_file_extensions,
+        custom_format_name, custom_format_possible_bit_depths, custom_format_possible_sample_rates,
+    },
+    ffi::custom_audio_format_reader::{
+        custom_reader_bits_per_sample, custom_reader_format_name, custom_reader_length_in_samples,
+        custom_reader_num_channels, custom_reader_read_samples, custom_reader_sample_rate,
+    },
+    ffi::custom_audio_format_writer::{custom_writer_flush, custom_writer_write},
+    BoxedCustomAudioFormat, BoxedCustomAudioFormatReader, BoxedCustomAudioFormatWriter,
+};
+
+#[cfg(feature = "juce_audio_processors")]
+use juce_audio_processors::{
+    ffi::audio_play_head::audio_play_head_get_position,
+    ffi::audio_processor_listener::{
+        audio_processor_listener_parameter_change_gesture_begin,
+        audio_processor_listener_parameter_change_gesture_end,
+        audio_processor_listener_parameter_changed, audio_processor_listener_processor_changed,
+    },
+    ffi::plugin_editor_window_listener::{
+        plugin_editor_window_close_button_pressed, plugin_editor_window_moved,
+        plugin_editor_window_visibility_changed,
+    },
+    ffi::rust_audio_parameter::{
+        rust_audio_parameter_gesture_changed, rust_audio_parameter_info,
+        rust_audio_parameter_remove_host, rust_audio_parameter_set_host,
+        rust_audio_parameter_set_value, rust_audio_parameter_text, rust_audio_parameter_value,
+        rust_audio_parameter_value_for_text,
+    },
+    ffi::rust_audio_processor::{
+        rust_audio_processor_accepts_midi, rust_audio_processor_name,
+        rust_audio_processor_num_input_channels, rust_audio_processor_num_output_channels,
+        rust_audio_processor_prepare_to_play, rust_audio_processor_process_block,
+        rust_audio_processor_produces_midi, rust_audio_processor_release_resources,
+        rust_audio_processor_reset, rust_audio_processor_set_state, rust_audio_processor_state,
+        rust_audio_processor_tail_length_seconds,
+    },
+    AudioParameter, BoxedAudioPlayHead, BoxedAudioProcessor, BoxedAudioProcessorListener,
+    BoxedPluginEditorWindowListener,
+};
+
+#[cfg(feature = "juce_audio_utils")]
+use juce_audio_utils::{
+    ffi::bluetooth_midi_device_pairing_dialogue::pairing_dialogue_closed,
+    BoxedPairingDialogueCallback,
+};
+
+#[cfg(feature = "juce_data_structures")]
+use juce_data_structures::{
+    ffi::value_tree_listener::{
+        value_tree_listener_child_added, value_tree_listener_child_order_changed,
+        value_tree_listener_child_removed, value_tree_listener_parent_changed,
+        value_tree_listener_property_changed,
+    },
+    BoxedValueTreeListener,
+};
+
+#[cfg(feature = "juce_dsp")]
+use juce_dsp::{ffi::wave_shaper::wave_shaper_call, BoxedWaveShaperFunction};
+
 /// Returns the version of the JUCE library.
 pub fn juce_version() -> String {
     juce::version()
@@ -27,28 +146,51 @@ pub fn juce_version() -> String {
 
 /// An RAII guard for JUCE. Required for certain JUCE classes.
 #[must_use]
-pub struct JUCE<'juce>(MutexGuard<'juce, ()>);
+pub struct JUCE<'juce> {
+    _guard: MutexGuard<'juce, ()>,
+}
 
 static JUCE_INSTANCE: Mutex<()> = Mutex::new(());
 
 impl<'juce> JUCE<'juce> {
-    /// Initialise JUCE. Panics if JUCE is already initialised.
+    /// Initialise JUCE, making the current thread the message thread. Panics if JUCE is already
+    /// initialised.
+    ///
+    /// Messages are only delivered while the thread runs the message loop, see
+    /// [`MessageManager`](juce_events::MessageManager).
     pub fn initialise() -> Self {
-        Self::new(JUCE_INSTANCE.try_lock().expect("JUCE already initialised"))
+        Self::new(
+            JUCE_INSTANCE.try_lock().expect("JUCE already initialised"),
+            true,
+        )
+    }
+
+    /// Initialise JUCE without creating the message thread. Panics if JUCE is already initialised.
+    ///
+    /// This suits servers and code embedded in another application's event loop. The message
+    /// thread is created later by whichever thread first needs it, e.g. by calling
+    /// [`MessageManager::run_dispatch_loop`](juce_events::MessageManager::run_dispatch_loop).
+    pub fn initialise_headless() -> Self {
+        Self::new(
+            JUCE_INSTANCE.try_lock().expect("JUCE already initialised"),
+            false,
+        )
     }
 
     #[doc(hidden)]
     pub fn wait_to_initialise_in_test_context() -> Self {
-        Self::new(JUCE_INSTANCE.lock().unwrap())
+        Self::new(JUCE_INSTANCE.lock().unwrap(), true)
     }
 
-    fn new(guard: MutexGuard<'juce, ()>) -> Self {
-        juce::initialise_juce();
+    fn new(guard: MutexGuard<'juce, ()>, create_message_thread: bool) -> Self {
+        if create_message_thread {
+            juce::initialise_juce();
 
-        #[cfg(target_os = "macos")]
-        juce::initialise_ns_application();
+            #[cfg(target_os = "macos")]
+            juce::initialise_ns_application();
+        }
 
-        Self(guard)
+        Self { _guard: guard }
     }
 }
 
@@ -61,8 +203,355 @@ impl Drop for JUCE<'_> {
 pub type Exception = cxx::Exception;
 pub type Result<T> = std::result::Result<T, Exception>;
 
+/// An error for an argument that's rejected before it reaches JUCE.
+pub(crate) fn invalid_argument<T>(message: &str) -> Result<T> {
+    Err(juce::throw_invalid_argument(message).expect_err("always throws"))
+}
+
 #[cxx::bridge(namespace = "cxx_juce")]
 pub(crate) mod juce {
+    /// Information about a MIDI device.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct MidiDeviceInfo {
+        /// The name of the device.
+        pub name: String,
+
+        /// The identifier used to open the device.
+        pub identifier: String,
+    }
+
+    /// A registered or non-registered parameter number (RPN/NRPN) message.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct MidiRPNMessage {
+        /// The MIDI channel (1-16).
+        pub channel: i32,
+
+        /// The 14-bit parameter number.
+        pub parameter_number: i32,
+
+        /// The parameter value, either 7-bit or 14-bit.
+        pub value: i32,
+
+        /// True if this is a non-registered parameter number.
+        pub is_nrpn: bool,
+
+        /// True if the value is 14-bit.
+        pub is_14_bit_value: bool,
+    }
+
+    /// An entry in a zip file.
+    #[derive(Debug, Clone)]
+    pub struct ZipEntry {
+        pub file_name: String,
+        pub uncompressed_size: i64,
+        pub modification_time: i64,
+        pub is_symbolic_link: bool,
+    }
+
+    /// The SIMD instruction sets supported by the CPU, as detected at runtime.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct CpuFeatures {
+        /// MMX, on x86.
+        pub mmx: bool,
+
+        /// SSE, on x86.
+        pub sse: bool,
+
+        /// SSE2, on x86.
+        pub sse2: bool,
+
+        /// SSE3, on x86.
+        pub sse3: bool,
+
+        /// SSSE3, on x86.
+        pub ssse3: bool,
+
+        /// SSE4.1, on x86.
+        pub sse41: bool,
+
+        /// SSE4.2, on x86.
+        pub sse42: bool,
+
+        /// AVX, on x86.
+        pub avx: bool,
+
+        /// AVX2, on x86.
+        pub avx2: bool,
+
+        /// FMA3, on x86.
+        pub fma3: bool,
+
+        /// The AVX-512 foundation instructions, on x86.
+        pub avx512f: bool,
+
+        /// NEON, on ARM.
+        pub neon: bool,
+    }
+
+    /// A key-value pair of audio file metadata.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct StringPair {
+        pub key: String,
+        pub value: String,
+    }
+
+    /// The context a DSP processor is prepared for.
+    #[cfg(feature = "juce_dsp")]
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    pub struct ProcessSpec {
+        /// The sample rate in Hertz.
+        pub sample_rate: f64,
+
+        /// The maximum number of samples in each block that will be processed.
+        pub max_block_size: usize,
+
+        /// The number of channels that will be processed.
+        pub num_channels: usize,
+    }
+
+    /// A complex number, laid out like `std::complex<float>`.
+    #[cfg(feature = "juce_dsp")]
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    pub struct Complex {
+        /// The real part.
+        pub re: f32,
+
+        /// The imaginary part.
+        pub im: f32,
+    }
+
+    /// The parameters of a [`Reverb`](crate::juce_dsp::Reverb).
+    #[cfg(feature = "juce_dsp")]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct ReverbParameters {
+        /// The size of the room, from 0 (small) to 1 (big).
+        pub room_size: f32,
+
+        /// How much high frequencies are damped, from 0 (not at all) to 1 (fully).
+        pub damping: f32,
+
+        /// The level of the reverberated signal, from 0 to 1.
+        pub wet_level: f32,
+
+        /// The level of the original signal, from 0 to 1.
+        pub dry_level: f32,
+
+        /// The stereo width of the reverb, from 0 (narrow) to 1 (wide).
+        pub width: f32,
+
+        /// Whether the reverb sustains its current tail indefinitely, ignoring new input.
+        pub freeze_mode: bool,
+    }
+
+    /// A description of a plugin, used to identify and instantiate it.
+    #[cfg(feature = "juce_audio_processors")]
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct PluginDescription {
+        /// The name of the plugin.
+        pub name: String,
+
+        /// A longer, more descriptive name, if the plugin provides one.
+        pub descriptive_name: String,
+
+        /// The name of the format that hosts the plugin, e.g. `VST3`.
+        pub plugin_format_name: String,
+
+        /// The category the plugin belongs to, e.g. `Fx` or `Instrument`.
+        pub category: String,
+
+        /// The name of the plugin's manufacturer.
+        pub manufacturer_name: String,
+
+        /// The version of the plugin.
+        pub version: String,
+
+        /// The path to the plugin, or an identifier the format uses to locate it.
+        pub file_or_identifier: String,
+
+        /// An identifier that, together with the name and format, uniquely identifies the plugin.
+        pub unique_id: i32,
+
+        /// The identifier used by older versions of JUCE, kept for compatibility with saved lists.
+        pub deprecated_uid: i32,
+
+        /// Whether the plugin is a synth or other sound generator.
+        pub is_instrument: bool,
+
+        /// The number of input channels in the plugin's default layout.
+        pub num_input_channels: usize,
+
+        /// The number of output channels in the plugin's default layout.
+        pub num_output_channels: usize,
+
+        /// Whether the plugin's file contains more than one plugin.
+        pub has_shared_container: bool,
+    }
+
+    /// Information about a parameter of an audio processor.
+    ///
+    /// Parameter values are normalised to the range 0 to 1.
+    #[cfg(feature = "juce_audio_processors")]
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct ParameterInfo {
+        /// The index of the parameter in the processor's parameter list.
+        pub index: usize,
+
+        /// An identifier for the parameter that stays the same between versions of the plugin.
+        pub id: String,
+
+        /// The name of the parameter.
+        pub name: String,
+
+        /// The units of the parameter's value, e.g. `dB` or `Hz`.
+        pub label: String,
+
+        /// The value the parameter is reset to.
+        pub default_value: f32,
+
+        /// The number of discrete steps in the parameter's range.
+        pub num_steps: i32,
+
+        /// Whether the parameter only takes on discrete values.
+        pub is_discrete: bool,
+
+        /// Whether the parameter is a switch that's either on or off.
+        pub is_boolean: bool,
+
+        /// Whether the parameter can be automated.
+        pub is_automatable: bool,
+
+        /// Whether the parameter changes other parameters.
+        pub is_meta_parameter: bool,
+
+        /// The text for each of a discrete parameter's values.
+        pub value_strings: Vec<String>,
+    }
+
+    /// The channels of an audio bus.
+    #[cfg(feature = "juce_audio_processors")]
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+    pub struct AudioChannelSet {
+        /// The type of each channel, as a JUCE `AudioChannelSet::ChannelType`, in ascending order.
+        pub channel_types: Vec<i32>,
+    }
+
+    /// The channels of each of a processor's buses.
+    #[cfg(feature = "juce_audio_processors")]
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+    pub struct BusesLayout {
+        /// The layout of each input bus. Disabled buses have no channels.
+        pub inputs: Vec<AudioChannelSet>,
+
+        /// The layout of each output bus. Disabled buses have no channels.
+        pub outputs: Vec<AudioChannelSet>,
+    }
+
+    /// Information about one of a processor's buses.
+    #[cfg(feature = "juce_audio_processors")]
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct BusInfo {
+        /// The name of the bus, such as "Sidechain".
+        pub name: String,
+
+        /// The bus's current layout, which has no channels if the bus is disabled.
+        pub layout: AudioChannelSet,
+
+        /// Whether the bus is enabled.
+        pub is_enabled: bool,
+
+        /// Whether this is the processor's main bus, which is the first bus in each direction.
+        pub is_main: bool,
+    }
+
+    /// The position of a host's transport, passed to hosted plugins by an
+    /// [`AudioPlayHead`](crate::juce_audio_processors::AudioPlayHead).
+    #[cfg(feature = "juce_audio_processors")]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct PlayHeadPosition {
+        /// The tempo in beats per minute.
+        pub bpm: f64,
+
+        /// The number of beats in a bar.
+        pub time_signature_numerator: i32,
+
+        /// The length of a beat, as a fraction of a whole note.
+        pub time_signature_denominator: i32,
+
+        /// The position from the start of the timeline, in samples.
+        pub time_in_samples: i64,
+
+        /// The position from the start of the timeline, in seconds.
+        pub time_in_seconds: f64,
+
+        /// The position from the start of the timeline, in quarter notes.
+        pub ppq_position: f64,
+
+        /// The position of the start of the current bar, in quarter notes.
+        pub ppq_position_of_last_bar_start: f64,
+
+        /// Whether the transport is playing.
+        pub is_playing: bool,
+
+        /// Whether the transport is recording.
+        pub is_recording: bool,
+
+        /// Whether the transport is looping.
+        pub is_looping: bool,
+
+        /// The start of the loop, in quarter notes.
+        pub ppq_loop_start: f64,
+
+        /// The end of the loop, in quarter notes.
+        pub ppq_loop_end: f64,
+    }
+
+    /// A group of a processor's parameters, in a list of groups visited depth-first.
+    #[cfg(feature = "juce_audio_processors")]
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct ParameterGroupEntry {
+        /// The group's ID, which is empty for the root group.
+        pub id: String,
+
+        /// The group's name, which is empty for the root group.
+        pub name: String,
+
+        /// How many groups contain this group.
+        pub depth: usize,
+
+        /// The indices of the parameters directly in this group.
+        pub parameters: Vec<usize>,
+    }
+
+    /// Identifies a node in an [`AudioProcessorGraph`](crate::juce_audio_processors::AudioProcessorGraph).
+    #[cfg(feature = "juce_audio_processors")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct NodeId {
+        /// The identifier's value, unique within the graph.
+        pub uid: u32,
+    }
+
+    /// One end of a connection: a node and one of its channels.
+    #[cfg(feature = "juce_audio_processors")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct NodeAndChannel {
+        /// The node.
+        pub node_id: NodeId,
+
+        /// The audio channel, or [`NodeAndChannel::MIDI_CHANNEL_INDEX`](crate::juce_audio_processors::NodeAndChannel::MIDI_CHANNEL_INDEX) for the node's MIDI.
+        pub channel_index: i32,
+    }
+
+    /// A connection from an output channel of one node to an input channel of another.
+    #[cfg(feature = "juce_audio_processors")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Connection {
+        /// The output the connection comes from.
+        pub source: NodeAndChannel,
+
+        /// The input the connection goes to.
+        pub destination: NodeAndChannel,
+    }
+
     extern "Rust" {
         type BoxedAudioIODeviceCallback;
 
@@ -85,6 +574,24 @@ pub(crate) mod juce {
         #[cxx_name = "stopped"]
         fn stopped(callback: Pin<&mut BoxedAudioIODeviceCallback>);
 
+        type BoxedMidiInputCallback;
+
+        #[namespace = "midi_input_callback"]
+        #[cxx_name = "handleIncomingMidiMessage"]
+        fn handle_incoming_midi_message(
+            callback: Pin<&mut BoxedMidiInputCallback>,
+            message: UniquePtr<MidiMessage>,
+        );
+
+        type MidiThru;
+
+        #[namespace = "midi_thru"]
+        #[cxx_name = "process"]
+        fn process_midi_thru(
+            thru: Pin<&mut MidiThru>,
+            message: UniquePtr<MidiMessage>,
+        ) -> UniquePtr<MidiMessage>;
+
         type BoxedAudioIODeviceType;
 
         #[namespace = "audio_io_device_type"]
@@ -148,273 +655,4862 @@ pub(crate) mod juce {
         #[namespace = "audio_io_device"]
         #[cxx_name = "close"]
         pub fn device_close(self_: Pin<&mut BoxedAudioIODevice>);
-    }
 
-    unsafe extern "C++" {
-        include!("cxx-juce/bridge/cxx_juce.h");
+        type BoxedInputStream;
 
-        #[rust_name = "version"]
-        pub fn juceVersion() -> String;
+        #[namespace = "input_stream"]
+        #[cxx_name = "read"]
+        fn input_stream_read(self_: Pin<&mut BoxedInputStream>, buffer: &mut [u8]) -> i32;
 
-        #[rust_name = "initialise_juce"]
-        pub fn initialiseJuce();
+        #[namespace = "input_stream"]
+        #[cxx_name = "getPosition"]
+        fn input_stream_position(self_: Pin<&mut BoxedInputStream>) -> i64;
 
-        #[rust_name = "shutdown_juce"]
-        pub fn shutdownJuce();
+        #[namespace = "input_stream"]
+        #[cxx_name = "setPosition"]
+        fn input_stream_set_position(self_: Pin<&mut BoxedInputStream>, position: i64) -> bool;
 
-        #[cfg(target_os = "macos")]
-        #[namespace = "juce"]
-        #[rust_name = "initialise_ns_application"]
-        pub fn initialiseNSApplication();
+        #[namespace = "input_stream"]
+        #[cxx_name = "getTotalLength"]
+        fn input_stream_total_length(self_: Pin<&mut BoxedInputStream>) -> i64;
 
-        #[namespace = "juce"]
-        pub type MessageManager;
+        type BoxedAsyncCallback;
 
-        #[namespace = "juce"]
-        pub type AudioIODeviceTypeArray;
+        #[namespace = "async_callback"]
+        #[cxx_name = "call"]
+        fn async_callback_call(callback: Box<BoxedAsyncCallback>);
 
-        pub fn size(self: &AudioIODeviceTypeArray) -> i32;
+        type BoxedTimerCallback;
 
-        #[rust_name = "get_unchecked"]
-        pub fn getUnchecked(self: &AudioIODeviceTypeArray, index: i32) -> *mut AudioIODeviceType;
+        #[namespace = "timer_callback"]
+        #[cxx_name = "call"]
+        fn timer_callback_call(callback: Pin<&mut BoxedTimerCallback>);
 
-        pub type AudioDeviceSetup;
+        type BoxedHighResolutionTimerCallback;
 
-        #[rust_name = "create_audio_device_setup"]
-        pub fn createAudioDeviceSetup() -> UniquePtr<AudioDeviceSetup>;
+        #[namespace = "high_resolution_timer_callback"]
+        #[cxx_name = "call"]
+        fn high_resolution_timer_callback_call(
+            callback: Pin<&mut BoxedHighResolutionTimerCallback>,
+        );
 
-        #[rust_name = "output_device_name"]
-        pub fn outputDeviceName(self: &AudioDeviceSetup) -> &str;
+        type BoxedInterprocessConnectionListener;
 
-        #[rust_name = "input_device_name"]
-        pub fn inputDeviceName(self: &AudioDeviceSetup) -> &str;
+        #[namespace = "interprocess_connection_listener"]
+        #[cxx_name = "connectionMade"]
+        fn interprocess_connection_listener_connection_made(
+            listener: Pin<&mut BoxedInterprocessConnectionListener>,
+            connection: &InterprocessConnection,
+        );
 
-        #[rust_name = "sample_rate"]
-        pub fn sampleRate(self: &AudioDeviceSetup) -> f64;
+        #[namespace = "interprocess_connection_listener"]
+        #[cxx_name = "connectionLost"]
+        fn interprocess_connection_listener_connection_lost(
+            listener: Pin<&mut BoxedInterprocessConnectionListener>,
+        );
 
-        #[rust_name = "buffer_size"]
-        pub fn bufferSize(self: &AudioDeviceSetup) -> i32;
+        #[namespace = "interprocess_connection_listener"]
+        #[cxx_name = "messageReceived"]
+        fn interprocess_connection_listener_message_received(
+            listener: Pin<&mut BoxedInterprocessConnectionListener>,
+            connection: &InterprocessConnection,
+            message: &[u8],
+        );
 
-        #[rust_name = "set_output_device_name"]
-        pub fn setOutputDeviceName(self: Pin<&mut AudioDeviceSetup>, name: &str);
+        type BoxedInterprocessConnectionFactory;
 
-        #[rust_name = "set_input_device_name"]
-        pub fn setInputDeviceName(self: Pin<&mut AudioDeviceSetup>, name: &str);
+        #[namespace = "interprocess_connection_factory"]
+        #[cxx_name = "createListener"]
+        fn interprocess_connection_factory_create_listener(
+            factory: Pin<&mut BoxedInterprocessConnectionFactory>,
+        ) -> Box<BoxedInterprocessConnectionListener>;
 
-        #[rust_name = "set_sample_rate"]
-        pub fn setSampleRate(self: Pin<&mut AudioDeviceSetup>, sample_rate: f64);
+        #[namespace = "logger"]
+        #[cxx_name = "logMessage"]
+        fn logger_log_message(message: &str) -> bool;
 
-        #[rust_name = "set_buffer_size"]
-        pub fn setBufferSize(self: Pin<&mut AudioDeviceSetup>, buffer_size: i32);
+        type BoxedTimeSliceClient;
 
-        #[rust_name = "number_of_input_channels"]
-        pub fn numberOfInputChannels(self: &AudioDeviceSetup) -> i32;
+        #[namespace = "time_slice_client"]
+        #[cxx_name = "useTimeSlice"]
+        fn time_slice_client_use_time_slice(client: Pin<&mut BoxedTimeSliceClient>) -> i32;
 
-        #[rust_name = "set_number_of_input_channels"]
-        pub fn setNumberOfInputChannels(
-            self: Pin<&mut AudioDeviceSetup>,
-            number_of_input_channels: i32,
-        );
+        type BoxedThreadPoolJob;
 
-        #[rust_name = "use_default_input_channels"]
-        pub fn useDefaultInputChannels(self: Pin<&mut AudioDeviceSetup>, use_default: bool);
+        #[namespace = "thread_pool_job"]
+        #[cxx_name = "run"]
+        fn thread_pool_job_run(job: Pin<&mut BoxedThreadPoolJob>, context: &ThreadPoolJob);
+    }
 
-        #[rust_name = "using_default_input_channels"]
-        pub fn usingDefaultInputChannels(self: &AudioDeviceSetup) -> bool;
+    #[cfg(feature = "network")]
+    extern "Rust" {
+        type BoxedPostProgressCallback;
 
-        #[rust_name = "number_of_output_channels"]
-        pub fn numberOfOutputChannels(self: &AudioDeviceSetup) -> i32;
+        #[namespace = "post_progress_callback"]
+        #[cxx_name = "call"]
+        fn post_progress_callback_call(
+            callback: Pin<&mut BoxedPostProgressCallback>,
+            bytes_sent: usize,
+            total_bytes: usize,
+        ) -> bool;
+    }
 
-        #[rust_name = "set_number_of_output_channels"]
-        pub fn setNumberOfOutputChannels(
-            self: Pin<&mut AudioDeviceSetup>,
-            number_of_output_channels: i32,
-        );
+    #[cfg(feature = "juce_audio_formats")]
+    extern "Rust" {
+        type BoxedCustomAudioFormatReader;
 
-        #[rust_name = "use_default_output_channels"]
-        pub fn useDefaultOutputChannels(self: Pin<&mut AudioDeviceSetup>, use_default: bool);
+        #[namespace = "custom_audio_format_reader"]
+        #[cxx_name = "getFormatName"]
+        fn custom_reader_format_name(self_: &BoxedCustomAudioFormatReader) -> String;
 
-        #[rust_name = "using_default_output_channels"]
-        pub fn usingDefaultOutputChannels(self: &AudioDeviceSetup) -> bool;
+        #[namespace = "custom_audio_format_reader"]
+        #[cxx_name = "getSampleRate"]
+        fn custom_reader_sample_rate(self_: &BoxedCustomAudioFormatReader) -> f64;
 
-        pub type AudioDeviceManager;
+        #[namespace = "custom_audio_format_reader"]
+        #[cxx_name = "getNumChannels"]
+        fn custom_reader_num_channels(self_: &BoxedCustomAudioFormatReader) -> u32;
 
-        #[rust_name = "create_audio_device_manager"]
-        pub fn createAudioDeviceManager() -> UniquePtr<AudioDeviceManager>;
+        #[namespace = "custom_audio_format_reader"]
+        #[cxx_name = "getLengthInSamples"]
+        fn custom_reader_length_in_samples(self_: &BoxedCustomAudioFormatReader) -> i64;
 
-        #[rust_name = "initialise_with_default_devices"]
-        pub fn initialiseWithDefaultDevices(
-            self: Pin<&mut AudioDeviceManager>,
-            num_input_channels: i32,
-            num_output_channels: i32,
-        ) -> Result<()>;
+        #[namespace = "custom_audio_format_reader"]
+        #[cxx_name = "getBitsPerSample"]
+        fn custom_reader_bits_per_sample(self_: &BoxedCustomAudioFormatReader) -> u32;
 
-        #[rust_name = "get_audio_device_setup"]
-        pub fn getAudioDeviceSetup(self: &AudioDeviceManager) -> UniquePtr<AudioDeviceSetup>;
+        #[namespace = "custom_audio_format_reader"]
+        #[cxx_name = "readSamples"]
+        fn custom_reader_read_samples(
+            self_: Pin<&mut BoxedCustomAudioFormatReader>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            start_sample: i64,
+        ) -> bool;
 
-        #[rust_name = "set_audio_device_setup"]
-        pub fn setAudioDeviceSetup(self: Pin<&mut AudioDeviceManager>, setup: &AudioDeviceSetup);
+        type BoxedCustomAudioFormat;
 
-        #[rust_name = "get_current_audio_device"]
-        pub fn getCurrentAudioDevice(self: &AudioDeviceManager) -> *mut AudioIODevice;
+        #[namespace = "custom_audio_format"]
+        #[cxx_name = "getFormatName"]
+        fn custom_format_name(self_: &BoxedCustomAudioFormat) -> String;
 
-        #[rust_name = "get_available_device_types"]
-        pub fn getAvailableDeviceTypes(
-            self: Pin<&mut AudioDeviceManager>,
-        ) -> &AudioIODeviceTypeArray;
+        #[namespace = "custom_audio_format"]
+        #[cxx_name = "getFileExtensions"]
+        fn custom_format_file_extensions(self_: &BoxedCustomAudioFormat) -> Vec<String>;
 
-        #[rust_name = "get_current_device_type_object"]
-        pub fn getCurrentDeviceTypeObject(self: &AudioDeviceManager) -> *mut AudioIODeviceType;
+        #[namespace = "custom_audio_format"]
+        #[cxx_name = "canHandleFile"]
+        fn custom_format_can_handle_file(self_: &BoxedCustomAudioFormat, path: &str) -> bool;
 
-        #[rust_name = "play_test_sound"]
-        pub fn playTestSound(self: Pin<&mut AudioDeviceManager>);
+        #[namespace = "custom_audio_format"]
+        #[cxx_name = "getPossibleSampleRates"]
+        fn custom_format_possible_sample_rates(self_: &BoxedCustomAudioFormat) -> Vec<i32>;
 
-        #[rust_name = "add_audio_callback"]
-        pub fn addAudioCallback<'a>(
-            self: Pin<&mut AudioDeviceManager>,
-            callback: Box<BoxedAudioIODeviceCallback>,
-        ) -> UniquePtr<AudioCallbackHandle<'a>>;
+        #[namespace = "custom_audio_format"]
+        #[cxx_name = "getPossibleBitDepths"]
+        fn custom_format_possible_bit_depths(self_: &BoxedCustomAudioFormat) -> Vec<i32>;
 
-        #[rust_name = "add_audio_device_type"]
-        pub fn addAudioDeviceType(
-            self: Pin<&mut AudioDeviceManager>,
-            device_type: Box<BoxedAudioIODeviceType>,
-        );
+        #[namespace = "custom_audio_format"]
+        #[cxx_name = "canDoStereo"]
+        fn custom_format_can_do_stereo(self_: &BoxedCustomAudioFormat) -> bool;
 
-        #[rust_name = "set_current_audio_device_type"]
-        pub fn setCurrentAudioDeviceType(self: Pin<&mut AudioDeviceManager>, device_type: &str);
+        #[namespace = "custom_audio_format"]
+        #[cxx_name = "canDoMono"]
+        fn custom_format_can_do_mono(self_: &BoxedCustomAudioFormat) -> bool;
 
-        #[namespace = "juce"]
-        pub type AudioIODevice;
+        #[namespace = "custom_audio_format"]
+        #[cxx_name = "createReader"]
+        fn custom_format_create_reader(
+            self_: Pin<&mut BoxedCustomAudioFormat>,
+            stream: UniquePtr<InputStream>,
+        ) -> UniquePtr<AudioFormatReader>;
 
-        #[namespace = "cxx_juce::audio_io_device"]
-        #[rust_name = "get_device_name"]
-        pub fn getDeviceName(self_: &AudioIODevice) -> &str;
+        #[namespace = "custom_audio_format"]
+        #[cxx_name = "createWriter"]
+        fn custom_format_create_writer(
+            self_: Pin<&mut BoxedCustomAudioFormat>,
+            stream: UniquePtr<OutputStream>,
+            sample_rate: f64,
+            num_channels: u32,
+            bits_per_sample: i32,
+            metadata: Vec<StringPair>,
+            quality_option_index: i32,
+        ) -> UniquePtr<AudioFormatWriter>;
 
-        #[namespace = "cxx_juce::audio_io_device"]
-        #[rust_name = "get_device_type_name"]
-        pub fn getDeviceTypeName(self_: &AudioIODevice) -> &str;
+        type BoxedCustomAudioFormatWriter;
 
-        #[rust_name = "get_current_sample_rate"]
-        pub fn getCurrentSampleRate(self: Pin<&mut AudioIODevice>) -> f64;
+        #[namespace = "custom_audio_format_writer"]
+        #[cxx_name = "write"]
+        fn custom_writer_write(
+            self_: Pin<&mut BoxedCustomAudioFormatWriter>,
+            buffer: &AudioSampleBuffer,
+        ) -> bool;
 
-        #[rust_name = "get_current_buffer_size_samples"]
-        pub fn getCurrentBufferSizeSamples(self: Pin<&mut AudioIODevice>) -> i32;
+        #[namespace = "custom_audio_format_writer"]
+        #[cxx_name = "flush"]
+        fn custom_writer_flush(self_: Pin<&mut BoxedCustomAudioFormatWriter>) -> bool;
+    }
 
-        #[namespace = "cxx_juce::audio_io_device"]
-        #[rust_name = "get_available_sample_rates"]
-        pub fn getAvailableSampleRates(self_: Pin<&mut AudioIODevice>) -> Vec<f64>;
+    #[cfg(feature = "juce_audio_processors")]
+    extern "Rust" {
+        type BoxedAudioProcessorListener;
 
-        #[namespace = "cxx_juce::audio_io_device"]
-        #[rust_name = "get_available_buffer_sizes"]
-        pub fn getAvailableBufferSizes(self_: Pin<&mut AudioIODevice>) -> Vec<usize>;
+        #[namespace = "audio_processor_listener"]
+        #[cxx_name = "parameterChanged"]
+        fn audio_processor_listener_parameter_changed(
+            self_: &BoxedAudioProcessorListener,
+            index: usize,
+            value: f32,
+        );
 
-        #[namespace = "cxx_juce::audio_io_device"]
-        #[rust_name = "open"]
-        pub fn open(
-            self_: Pin<&mut AudioIODevice>,
+        #[namespace = "audio_processor_listener"]
+        #[cxx_name = "processorChanged"]
+        fn audio_processor_listener_processor_changed(self_: &BoxedAudioProcessorListener);
+
+        #[namespace = "audio_processor_listener"]
+        #[cxx_name = "parameterChangeGestureBegin"]
+        fn audio_processor_listener_parameter_change_gesture_begin(
+            self_: &BoxedAudioProcessorListener,
+            index: usize,
+        );
+
+        #[namespace = "audio_processor_listener"]
+        #[cxx_name = "parameterChangeGestureEnd"]
+        fn audio_processor_listener_parameter_change_gesture_end(
+            self_: &BoxedAudioProcessorListener,
+            index: usize,
+        );
+
+        type BoxedAudioPlayHead;
+
+        #[namespace = "audio_play_head"]
+        #[cxx_name = "getPosition"]
+        fn audio_play_head_get_position(
+            self_: &BoxedAudioPlayHead,
+            position: &mut PlayHeadPosition,
+        ) -> bool;
+
+        type BoxedAudioProcessor;
+
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "getName"]
+        fn rust_audio_processor_name(self_: &BoxedAudioProcessor) -> String;
+
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "getNumInputChannels"]
+        fn rust_audio_processor_num_input_channels(self_: &BoxedAudioProcessor) -> usize;
+
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "getNumOutputChannels"]
+        fn rust_audio_processor_num_output_channels(self_: &BoxedAudioProcessor) -> usize;
+
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "getTailLengthSeconds"]
+        fn rust_audio_processor_tail_length_seconds(self_: &BoxedAudioProcessor) -> f64;
+
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "acceptsMidi"]
+        fn rust_audio_processor_accepts_midi(self_: &BoxedAudioProcessor) -> bool;
+
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "producesMidi"]
+        fn rust_audio_processor_produces_midi(self_: &BoxedAudioProcessor) -> bool;
+
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "prepareToPlay"]
+        fn rust_audio_processor_prepare_to_play(
+            self_: Pin<&mut BoxedAudioProcessor>,
             sample_rate: f64,
-            buffer_size: usize,
-        ) -> Result<()>;
+            maximum_block_size: usize,
+        );
 
-        #[rust_name = "close"]
-        pub fn close(self: Pin<&mut AudioIODevice>);
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "releaseResources"]
+        fn rust_audio_processor_release_resources(self_: Pin<&mut BoxedAudioProcessor>);
 
-        #[namespace = "cxx_juce::audio_io_device"]
-        #[rust_name = "count_active_input_channels"]
-        pub fn countActiveInputChannels(self_: &AudioIODevice) -> i32;
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "processBlock"]
+        fn rust_audio_processor_process_block(
+            self_: Pin<&mut BoxedAudioProcessor>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            midi: Pin<&mut MidiBuffer>,
+        );
 
-        #[namespace = "cxx_juce::audio_io_device"]
-        #[rust_name = "count_active_output_channels"]
-        pub fn countActiveOutputChannels(self_: &AudioIODevice) -> i32;
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "reset"]
+        fn rust_audio_processor_reset(self_: Pin<&mut BoxedAudioProcessor>);
+
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "getState"]
+        fn rust_audio_processor_state(self_: &BoxedAudioProcessor) -> Vec<u8>;
+
+        #[namespace = "rust_audio_processor"]
+        #[cxx_name = "setState"]
+        fn rust_audio_processor_set_state(self_: Pin<&mut BoxedAudioProcessor>, state: &[u8]);
+
+        type AudioParameter;
+
+        #[namespace = "rust_audio_parameter"]
+        #[cxx_name = "getInfo"]
+        fn rust_audio_parameter_info(self_: &AudioParameter) -> ParameterInfo;
+
+        #[namespace = "rust_audio_parameter"]
+        #[cxx_name = "getValue"]
+        fn rust_audio_parameter_value(self_: &AudioParameter) -> f32;
+
+        #[namespace = "rust_audio_parameter"]
+        #[cxx_name = "setValue"]
+        fn rust_audio_parameter_set_value(self_: &AudioParameter, value: f32);
+
+        #[namespace = "rust_audio_parameter"]
+        #[cxx_name = "getText"]
+        fn rust_audio_parameter_text(self_: &AudioParameter, value: f32) -> String;
+
+        #[namespace = "rust_audio_parameter"]
+        #[cxx_name = "getValueForText"]
+        fn rust_audio_parameter_value_for_text(self_: &AudioParameter, text: &str) -> f32;
+
+        #[namespace = "rust_audio_parameter"]
+        #[cxx_name = "gestureChanged"]
+        fn rust_audio_parameter_gesture_changed(self_: &AudioParameter, gesture_is_starting: bool);
+
+        #[namespace = "rust_audio_parameter"]
+        #[cxx_name = "setHost"]
+        unsafe fn rust_audio_parameter_set_host(
+            self_: &AudioParameter,
+            host: *mut RustAudioProcessorParameter,
+        );
+
+        #[namespace = "rust_audio_parameter"]
+        #[cxx_name = "removeHost"]
+        unsafe fn rust_audio_parameter_remove_host(
+            self_: &AudioParameter,
+            host: *mut RustAudioProcessorParameter,
+        );
+
+        type BoxedPluginEditorWindowListener;
+
+        #[namespace = "plugin_editor_window_listener"]
+        #[cxx_name = "closeButtonPressed"]
+        fn plugin_editor_window_close_button_pressed(
+            self_: Pin<&mut BoxedPluginEditorWindowListener>,
+        );
+
+        #[namespace = "plugin_editor_window_listener"]
+        #[cxx_name = "visibilityChanged"]
+        fn plugin_editor_window_visibility_changed(
+            self_: Pin<&mut BoxedPluginEditorWindowListener>,
+            visible: bool,
+        );
+
+        #[namespace = "plugin_editor_window_listener"]
+        #[cxx_name = "moved"]
+        fn plugin_editor_window_moved(
+            self_: Pin<&mut BoxedPluginEditorWindowListener>,
+            x: i32,
+            y: i32,
+        );
+    }
+
+    #[cfg(feature = "juce_audio_utils")]
+    extern "Rust" {
+        type BoxedPairingDialogueCallback;
+
+        #[namespace = "bluetooth_midi_device_pairing_dialogue"]
+        #[cxx_name = "pairingDialogueClosed"]
+        fn pairing_dialogue_closed(callback: Box<BoxedPairingDialogueCallback>);
+    }
 
+    #[cfg(feature = "juce_data_structures")]
+    extern "Rust" {
+        type BoxedValueTreeListener;
+
+        #[namespace = "value_tree_listener"]
+        #[cxx_name = "propertyChanged"]
+        fn value_tree_listener_property_changed(
+            self_: &BoxedValueTreeListener,
+            tree: UniquePtr<ValueTree>,
+            property: &str,
+        );
+
+        #[namespace = "value_tree_listener"]
+        #[cxx_name = "childAdded"]
+        fn value_tree_listener_child_added(
+            self_: &BoxedValueTreeListener,
+            parent: UniquePtr<ValueTree>,
+            child: UniquePtr<ValueTree>,
+        );
+
+        #[namespace = "value_tree_listener"]
+        #[cxx_name = "childRemoved"]
+        fn value_tree_listener_child_removed(
+            self_: &BoxedValueTreeListener,
+            parent: UniquePtr<ValueTree>,
+            child: UniquePtr<ValueTree>,
+            index: usize,
+        );
+
+        #[namespace = "value_tree_listener"]
+        #[cxx_name = "childOrderChanged"]
+        fn value_tree_listener_child_order_changed(
+            self_: &BoxedValueTreeListener,
+            parent: UniquePtr<ValueTree>,
+            old_index: usize,
+            new_index: usize,
+        );
+
+        #[namespace = "value_tree_listener"]
+        #[cxx_name = "parentChanged"]
+        fn value_tree_listener_parent_changed(
+            self_: &BoxedValueTreeListener,
+            tree: UniquePtr<ValueTree>,
+        );
+    }
+
+    #[cfg(feature = "juce_dsp")]
+    extern "Rust" {
+        type BoxedWaveShaperFunction;
+
+        #[namespace = "wave_shaper"]
+        #[cxx_name = "call"]
+        fn wave_shaper_call(self_: &BoxedWaveShaperFunction, x: f32) -> f32;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-juce/bridge/cxx_juce.h");
+
+        #[rust_name = "version"]
+        pub fn juceVersion() -> String;
+
+        #[rust_name = "initialise_juce"]
+        pub fn initialiseJuce();
+
+        #[rust_name = "shutdown_juce"]
+        pub fn shutdownJuce();
+
+        #[rust_name = "throw_invalid_argument"]
+        pub fn throwInvalidArgument(message: &str) -> Result<()>;
+
+        #[cfg(target_os = "macos")]
         #[namespace = "juce"]
-        pub type AudioIODeviceType;
+        #[rust_name = "initialise_ns_application"]
+        pub fn initialiseNSApplication();
 
-        #[namespace = "cxx_juce::audio_io_device_type"]
-        #[rust_name = "get_type_name"]
-        pub fn getTypeName(self_: &AudioIODeviceType) -> String;
+        #[namespace = "juce"]
+        pub type MessageManager;
 
-        #[rust_name = "scan_for_devices"]
-        pub fn scanForDevices(self: Pin<&mut AudioIODeviceType>);
+        #[namespace = "cxx_juce::message_manager"]
+        #[rust_name = "run_dispatch_loop"]
+        pub fn runDispatchLoop() -> Result<()>;
 
-        #[namespace = "cxx_juce::audio_io_device_type"]
-        #[rust_name = "get_input_device_names"]
-        pub fn getInputDeviceNames(self_: &AudioIODeviceType) -> Vec<String>;
+        #[namespace = "cxx_juce::message_manager"]
+        #[rust_name = "run_dispatch_loop_until"]
+        pub fn runDispatchLoopUntil(milliseconds_to_run_for: i32) -> Result<bool>;
 
-        #[namespace = "cxx_juce::audio_io_device_type"]
-        #[rust_name = "get_output_device_names"]
-        pub fn getOutputDeviceNames(self_: &AudioIODeviceType) -> Vec<String>;
+        #[namespace = "cxx_juce::message_manager"]
+        #[rust_name = "stop_dispatch_loop"]
+        pub fn stopDispatchLoop();
 
-        #[namespace = "cxx_juce::audio_io_device_type"]
-        #[rust_name = "new_device"]
-        pub fn createDevice(
-            self_: Pin<&mut AudioIODeviceType>,
-            input_device_name: &str,
-            output_device_name: &str,
-        ) -> UniquePtr<AudioIODevice>;
+        #[namespace = "cxx_juce::message_manager"]
+        #[rust_name = "has_stop_been_called"]
+        pub fn hasStopBeenCalled() -> bool;
+
+        #[namespace = "cxx_juce::message_manager"]
+        #[rust_name = "is_this_the_message_thread"]
+        pub fn isThisTheMessageThread() -> bool;
+
+        #[namespace = "cxx_juce::message_manager"]
+        #[rust_name = "call_async"]
+        pub fn callAsync(callback: Box<BoxedAsyncCallback>) -> bool;
+
+        pub type TimerHandle;
+
+        #[namespace = "cxx_juce::timer"]
+        #[rust_name = "create_timer"]
+        pub fn createTimer(callback: Box<BoxedTimerCallback>) -> UniquePtr<TimerHandle>;
+
+        #[rust_name = "start_timer"]
+        pub fn startTimer(self: Pin<&mut TimerHandle>, interval_in_milliseconds: i32);
+
+        #[rust_name = "stop_timer"]
+        pub fn stopTimer(self: Pin<&mut TimerHandle>);
+
+        #[rust_name = "is_timer_running"]
+        pub fn isTimerRunning(self: &TimerHandle) -> bool;
+
+        pub type HighResolutionTimerHandle;
+
+        #[namespace = "cxx_juce::high_resolution_timer"]
+        #[rust_name = "create_high_resolution_timer"]
+        pub fn createHighResolutionTimer(
+            callback: Box<BoxedHighResolutionTimerCallback>,
+        ) -> UniquePtr<HighResolutionTimerHandle>;
+
+        #[rust_name = "start_timer"]
+        pub fn startTimer(self: Pin<&mut HighResolutionTimerHandle>, interval_in_milliseconds: i32);
+
+        #[rust_name = "stop_timer"]
+        pub fn stopTimer(self: Pin<&mut HighResolutionTimerHandle>);
+
+        #[rust_name = "is_timer_running"]
+        pub fn isTimerRunning(self: &HighResolutionTimerHandle) -> bool;
+
+        pub type InterprocessConnection;
+
+        #[namespace = "cxx_juce::interprocess_connection"]
+        #[rust_name = "create_interprocess_connection"]
+        pub fn createInterprocessConnection(
+            magic_message_header: u32,
+            listener: Box<BoxedInterprocessConnectionListener>,
+        ) -> UniquePtr<InterprocessConnection>;
+
+        #[namespace = "cxx_juce::interprocess_connection"]
+        #[rust_name = "connect_to_socket"]
+        pub fn connectToSocket(
+            connection: Pin<&mut InterprocessConnection>,
+            host_name: &str,
+            port: i32,
+            timeout_ms: i32,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::interprocess_connection"]
+        #[rust_name = "connect_to_pipe"]
+        pub fn connectToPipe(
+            connection: Pin<&mut InterprocessConnection>,
+            pipe_name: &str,
+            timeout_ms: i32,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::interprocess_connection"]
+        #[rust_name = "create_pipe"]
+        pub fn createPipe(
+            connection: Pin<&mut InterprocessConnection>,
+            pipe_name: &str,
+            timeout_ms: i32,
+            must_not_exist: bool,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::interprocess_connection"]
+        #[rust_name = "disconnect_interprocess_connection"]
+        pub fn disconnect(connection: Pin<&mut InterprocessConnection>);
+
+        #[namespace = "cxx_juce::interprocess_connection"]
+        #[rust_name = "send_interprocess_message"]
+        pub fn sendMessage(connection: &InterprocessConnection, message: &[u8]) -> bool;
+
+        #[namespace = "cxx_juce::interprocess_connection"]
+        #[rust_name = "get_connected_host_name"]
+        pub fn getConnectedHostName(connection: &InterprocessConnection) -> String;
+
+        #[rust_name = "is_connected"]
+        pub fn isConnected(self: &InterprocessConnection) -> bool;
+
+        pub type InterprocessConnectionServer;
+
+        #[namespace = "cxx_juce::interprocess_connection_server"]
+        #[rust_name = "create_interprocess_connection_server"]
+        pub fn createInterprocessConnectionServer(
+            magic_message_header: u32,
+            factory: Box<BoxedInterprocessConnectionFactory>,
+        ) -> UniquePtr<InterprocessConnectionServer>;
+
+        #[namespace = "cxx_juce::interprocess_connection_server"]
+        #[rust_name = "begin_waiting_for_socket"]
+        pub fn beginWaitingForSocket(
+            server: Pin<&mut InterprocessConnectionServer>,
+            port: i32,
+            bind_address: &str,
+        ) -> Result<()>;
+
+        #[rust_name = "stop"]
+        pub fn stop(self: Pin<&mut InterprocessConnectionServer>);
+
+        #[rust_name = "get_bound_port"]
+        pub fn getBoundPort(self: &InterprocessConnectionServer) -> i32;
+
+        #[namespace = "cxx_juce::logger"]
+        #[rust_name = "install_logger_forwarder"]
+        pub fn installLoggerForwarder();
+
+        #[namespace = "cxx_juce::logger"]
+        #[rust_name = "write_to_log"]
+        pub fn writeToLog(message: &str);
 
         #[namespace = "juce"]
-        pub type AudioSampleBuffer;
+        pub type FileLogger;
 
-        #[rust_name = "get_num_channels"]
-        pub fn getNumChannels(self: &AudioSampleBuffer) -> i32;
+        #[namespace = "cxx_juce::file_logger"]
+        #[rust_name = "create_file_logger"]
+        pub fn createFileLogger(
+            path: &str,
+            welcome_message: &str,
+            max_initial_file_size_bytes: i64,
+        ) -> UniquePtr<FileLogger>;
 
-        #[rust_name = "get_num_samples"]
-        pub fn getNumSamples(self: &AudioSampleBuffer) -> i32;
+        #[namespace = "cxx_juce::file_logger"]
+        #[rust_name = "create_default_app_logger"]
+        pub fn createDefaultAppLogger(
+            log_file_sub_directory_name: &str,
+            log_file_name: &str,
+            welcome_message: &str,
+            max_initial_file_size_bytes: i64,
+        ) -> UniquePtr<FileLogger>;
 
-        #[rust_name = "get_read_pointer"]
-        pub fn getReadPointer(self: &AudioSampleBuffer, channel: i32) -> *const f32;
+        #[namespace = "cxx_juce::file_logger"]
+        #[rust_name = "file_logger_log_message"]
+        pub fn logMessage(logger: &FileLogger, message: &str);
 
-        #[rust_name = "get_write_pointer"]
-        pub fn getWritePointer(self: Pin<&mut AudioSampleBuffer>, channel: i32) -> *mut f32;
+        #[namespace = "cxx_juce::file_logger"]
+        #[rust_name = "get_log_file"]
+        pub fn getLogFile(logger: &FileLogger) -> String;
 
-        #[rust_name = "clear"]
-        pub fn clear(self: Pin<&mut AudioSampleBuffer>);
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_special_location"]
+        pub fn getSpecialLocation(location: i32) -> String;
 
-        pub type AudioCallbackHandle<'a>;
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_current_working_directory"]
+        pub fn getCurrentWorkingDirectory() -> String;
 
-        #[namespace = "cxx_juce::system_audio_volume"]
-        #[rust_name = "set_muted"]
-        pub fn setMuted(muted: bool);
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "create_temp_file"]
+        pub fn createTempFile(suffix: &str) -> String;
 
-        #[namespace = "cxx_juce::system_audio_volume"]
-        #[rust_name = "is_muted"]
-        pub fn isMuted() -> bool;
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_full_path_name"]
+        pub fn getFullPathName(path: &str) -> String;
 
-        #[namespace = "cxx_juce::system_audio_volume"]
-        #[rust_name = "set_gain"]
-        pub fn setGain(gain: f32);
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_file_name"]
+        pub fn getFileName(path: &str) -> String;
 
-        #[namespace = "cxx_juce::system_audio_volume"]
-        #[rust_name = "get_gain"]
-        pub fn getGain() -> f32;
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_file_name_without_extension"]
+        pub fn getFileNameWithoutExtension(path: &str) -> String;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_file_extension"]
+        pub fn getFileExtension(path: &str) -> String;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_parent_directory"]
+        pub fn getParentDirectory(path: &str) -> String;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_child_file"]
+        pub fn getChildFile(path: &str, relative_path: &str) -> String;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_sibling_file"]
+        pub fn getSiblingFile(path: &str, file_name: &str) -> String;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "with_file_extension"]
+        pub fn withFileExtension(path: &str, extension: &str) -> String;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_nonexistent_child_file"]
+        pub fn getNonexistentChildFile(path: &str, prefix: &str, suffix: &str) -> String;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_nonexistent_sibling"]
+        pub fn getNonexistentSibling(path: &str) -> String;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "file_exists"]
+        pub fn exists(path: &str) -> bool;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "exists_as_file"]
+        pub fn existsAsFile(path: &str) -> bool;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "is_directory"]
+        pub fn isDirectory(path: &str) -> bool;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "get_file_size"]
+        pub fn getSize(path: &str) -> i64;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "create_file"]
+        pub fn create(path: &str) -> Result<()>;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "create_directory"]
+        pub fn createDirectory(path: &str) -> Result<()>;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "delete_file"]
+        pub fn deleteFile(path: &str) -> bool;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "delete_recursively"]
+        pub fn deleteRecursively(path: &str) -> bool;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "move_to_trash"]
+        pub fn moveToTrash(path: &str) -> bool;
+
+        #[namespace = "cxx_juce::file"]
+        #[rust_name = "find_child_files"]
+        pub fn findChildFiles(
+            path: &str,
+            what_to_look_for: i32,
+            search_recursively: bool,
+            wildcard: &str,
+        ) -> Vec<String>;
 
         #[namespace = "juce"]
-        pub type SingleThreadedIIRFilter;
+        pub type ZipFile;
 
-        #[namespace = "cxx_juce::iir_filter"]
-        #[rust_name = "create_iir_filter"]
-        pub fn createIIRFilter(coefficients: [f32; 5]) -> UniquePtr<SingleThreadedIIRFilter>;
+        #[namespace = "cxx_juce::zip_file"]
+        #[rust_name = "open_zip_file"]
+        pub fn openZipFile(path: &str) -> Result<UniquePtr<ZipFile>>;
+
+        #[namespace = "cxx_juce::zip_file"]
+        #[rust_name = "create_zip_file_from_data"]
+        pub fn createZipFileFromData(data: &[u8]) -> UniquePtr<ZipFile>;
+
+        #[rust_name = "get_num_entries"]
+        pub fn getNumEntries(self: &ZipFile) -> i32;
+
+        #[namespace = "cxx_juce::zip_file"]
+        #[rust_name = "get_zip_entry"]
+        pub fn getZipEntry(zip: &ZipFile, index: i32) -> Result<ZipEntry>;
+
+        #[namespace = "cxx_juce::zip_file"]
+        #[rust_name = "get_zip_entry_index"]
+        pub fn getIndexOfFileName(zip: &ZipFile, file_name: &str, ignore_case: bool) -> i32;
+
+        #[namespace = "cxx_juce::zip_file"]
+        #[rust_name = "read_zip_entry"]
+        pub fn readEntry(zip: Pin<&mut ZipFile>, index: i32) -> Result<Vec<u8>>;
+
+        #[namespace = "cxx_juce::zip_file"]
+        #[rust_name = "uncompress_zip_file"]
+        pub fn uncompressTo(
+            zip: Pin<&mut ZipFile>,
+            target_directory: &str,
+            should_overwrite_files: bool,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::zip_file"]
+        #[rust_name = "uncompress_zip_entry"]
+        pub fn uncompressEntry(
+            zip: Pin<&mut ZipFile>,
+            index: i32,
+            target_directory: &str,
+            should_overwrite_files: bool,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce"]
+        pub type ZipFileBuilder;
+
+        #[namespace = "cxx_juce::zip_file_builder"]
+        #[rust_name = "create_zip_file_builder"]
+        pub fn createZipFileBuilder() -> UniquePtr<ZipFileBuilder>;
+
+        #[namespace = "cxx_juce::zip_file_builder"]
+        #[rust_name = "add_file_to_zip"]
+        pub fn addFile(
+            builder: Pin<&mut ZipFileBuilder>,
+            path: &str,
+            compression_level: i32,
+            stored_path: &str,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::zip_file_builder"]
+        #[rust_name = "add_entry_to_zip"]
+        pub fn addEntry(
+            builder: Pin<&mut ZipFileBuilder>,
+            data: &[u8],
+            compression_level: i32,
+            stored_path: &str,
+            modification_time: i64,
+        );
+
+        #[namespace = "cxx_juce::zip_file_builder"]
+        #[rust_name = "write_zip_to_file"]
+        pub fn writeToFile(builder: &ZipFileBuilder, path: &str) -> Result<()>;
+
+        #[namespace = "cxx_juce::zip_file_builder"]
+        #[rust_name = "write_zip_to_memory"]
+        pub fn writeToMemory(builder: &ZipFileBuilder) -> Result<Vec<u8>>;
 
         #[namespace = "juce"]
-        #[rust_name = "process_samples"]
-        pub unsafe fn processSamples(
-            self: Pin<&mut SingleThreadedIIRFilter>,
-            samples: *mut f32,
-            num_samples: i32,
+        pub type XmlElement;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "create_xml_element"]
+        pub fn createXmlElement(tag_name: &str) -> Result<UniquePtr<XmlElement>>;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "create_xml_text_element"]
+        pub fn createTextElement(text: &str) -> UniquePtr<XmlElement>;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "parse_xml"]
+        pub fn parse(text: &str) -> Result<UniquePtr<XmlElement>>;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "parse_xml_file"]
+        pub fn parseFile(path: &str) -> Result<UniquePtr<XmlElement>>;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "copy_xml_element"]
+        pub fn createCopy(element: &XmlElement) -> UniquePtr<XmlElement>;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "get_xml_tag_name"]
+        pub fn getTagName(element: &XmlElement) -> String;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "set_xml_tag_name"]
+        pub fn setTagName(element: Pin<&mut XmlElement>, tag_name: &str) -> Result<()>;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "get_xml_text"]
+        pub fn getText(element: &XmlElement) -> String;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "get_all_xml_sub_text"]
+        pub fn getAllSubText(element: &XmlElement) -> String;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "set_xml_text"]
+        pub fn setText(element: Pin<&mut XmlElement>, text: &str) -> Result<()>;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "add_xml_text_element"]
+        pub fn addTextElement(element: Pin<&mut XmlElement>, text: &str);
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "get_xml_attribute_name"]
+        pub fn getAttributeName(element: &XmlElement, index: i32) -> String;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "get_xml_attribute_value"]
+        pub fn getAttributeValue(element: &XmlElement, index: i32) -> String;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "xml_has_attribute"]
+        pub fn hasAttribute(element: &XmlElement, name: &str) -> bool;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "get_xml_attribute"]
+        pub fn getStringAttribute(element: &XmlElement, name: &str) -> String;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "set_xml_attribute"]
+        pub fn setAttribute(element: Pin<&mut XmlElement>, name: &str, value: &str) -> Result<()>;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "remove_xml_attribute"]
+        pub fn removeAttribute(element: Pin<&mut XmlElement>, name: &str);
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "get_xml_child_by_name"]
+        pub fn getChildByName(element: &XmlElement, tag_name: &str) -> *mut XmlElement;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "get_xml_child_by_attribute"]
+        pub fn getChildByAttribute(
+            element: &XmlElement,
+            name: &str,
+            value: &str,
+        ) -> *mut XmlElement;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "insert_xml_child"]
+        pub fn insertChildElement(
+            element: Pin<&mut XmlElement>,
+            child: UniquePtr<XmlElement>,
+            index: i32,
         );
 
-        #[namespace = "cxx_juce::iir_filter"]
-        #[rust_name = "make_low_pass"]
-        pub fn makeLowPass(sample_rate: f64, frequency: f64, q: f64) -> [f32; 5];
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "create_new_xml_child"]
+        pub fn createNewChildElement<'a>(
+            element: Pin<&'a mut XmlElement>,
+            tag_name: &str,
+        ) -> Result<Pin<&'a mut XmlElement>>;
 
-        #[namespace = "cxx_juce::iir_filter"]
-        #[rust_name = "make_high_pass"]
-        pub fn makeHighPass(sample_rate: f64, frequency: f64, q: f64) -> [f32; 5];
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "remove_xml_child"]
+        pub fn removeChildElement(
+            element: Pin<&mut XmlElement>,
+            index: i32,
+        ) -> UniquePtr<XmlElement>;
 
-        #[namespace = "cxx_juce::iir_filter"]
-        #[rust_name = "make_notch_filter"]
-        pub fn makeNotchFilter(sample_rate: f64, frequency: f64, q: f64) -> [f32; 5];
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "xml_is_equivalent_to"]
+        pub fn isEquivalentTo(
+            element: &XmlElement,
+            other: &XmlElement,
+            ignore_order_of_attributes: bool,
+        ) -> bool;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "xml_to_string"]
+        pub fn toString(element: &XmlElement, single_line: bool, include_header: bool) -> String;
+
+        #[namespace = "cxx_juce::xml_element"]
+        #[rust_name = "write_xml_to"]
+        pub fn writeTo(
+            element: &XmlElement,
+            path: &str,
+            single_line: bool,
+            include_header: bool,
+        ) -> Result<()>;
+
+        #[rust_name = "is_text_element"]
+        pub fn isTextElement(self: &XmlElement) -> bool;
+
+        #[rust_name = "get_num_attributes"]
+        pub fn getNumAttributes(self: &XmlElement) -> i32;
+
+        #[rust_name = "remove_all_attributes"]
+        pub fn removeAllAttributes(self: Pin<&mut XmlElement>);
+
+        #[rust_name = "get_num_child_elements"]
+        pub fn getNumChildElements(self: &XmlElement) -> i32;
+
+        #[rust_name = "get_child_element"]
+        pub fn getChildElement(self: &XmlElement, index: i32) -> *mut XmlElement;
+
+        #[rust_name = "delete_all_child_elements"]
+        pub fn deleteAllChildElements(self: Pin<&mut XmlElement>);
+
+        #[namespace = "juce"]
+        #[cxx_name = "var"]
+        pub type Var;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "create_void_var"]
+        pub fn createVoid() -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "create_undefined_var"]
+        pub fn createUndefined() -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_from_bool"]
+        pub fn fromBool(value: bool) -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_from_int"]
+        pub fn fromInt(value: i32) -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_from_int64"]
+        pub fn fromInt64(value: i64) -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_from_double"]
+        pub fn fromDouble(value: f64) -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_from_string"]
+        pub fn fromString(value: &str) -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_from_binary"]
+        pub fn fromBinary(data: &[u8]) -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "create_array_var"]
+        pub fn createArray() -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_append"]
+        pub fn append(array: Pin<&mut Var>, value: &Var);
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_to_bool"]
+        pub fn toBool(value: &Var) -> bool;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_to_int"]
+        pub fn toInt(value: &Var) -> i32;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_to_int64"]
+        pub fn toInt64(value: &Var) -> i64;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_to_double"]
+        pub fn toDouble(value: &Var) -> f64;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_to_string"]
+        pub fn toString(value: &Var) -> String;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_to_binary"]
+        pub fn toBinary(value: &Var) -> Vec<u8>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "var_size"]
+        pub fn size(value: &Var) -> usize;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "get_var_array_element"]
+        pub fn getArrayElement(array: &Var, index: usize) -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "create_object_var"]
+        pub fn createObject() -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "set_var_property"]
+        pub fn setProperty(object: Pin<&mut Var>, name: &str, value: &Var);
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "get_var_num_properties"]
+        pub fn getNumProperties(object: &Var) -> usize;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "get_var_property_name"]
+        pub fn getPropertyName(object: &Var, index: usize) -> String;
+
+        #[namespace = "cxx_juce::var"]
+        #[rust_name = "get_var_property_value"]
+        pub fn getPropertyValue(object: &Var, index: usize) -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::json"]
+        #[rust_name = "parse_json"]
+        pub fn parse(text: &str) -> Result<UniquePtr<Var>>;
+
+        #[namespace = "cxx_juce::json"]
+        #[rust_name = "json_to_string"]
+        pub fn toString(value: &Var, all_on_one_line: bool) -> String;
+
+        #[rust_name = "is_void"]
+        pub fn isVoid(self: &Var) -> bool;
+
+        #[rust_name = "is_undefined"]
+        pub fn isUndefined(self: &Var) -> bool;
+
+        #[rust_name = "is_bool"]
+        pub fn isBool(self: &Var) -> bool;
+
+        #[rust_name = "is_int"]
+        pub fn isInt(self: &Var) -> bool;
+
+        #[rust_name = "is_int64"]
+        pub fn isInt64(self: &Var) -> bool;
+
+        #[rust_name = "is_double"]
+        pub fn isDouble(self: &Var) -> bool;
+
+        #[rust_name = "is_string"]
+        pub fn isString(self: &Var) -> bool;
+
+        #[rust_name = "is_array"]
+        pub fn isArray(self: &Var) -> bool;
+
+        #[rust_name = "is_binary_data"]
+        pub fn isBinaryData(self: &Var) -> bool;
+
+        #[rust_name = "is_object"]
+        pub fn isObject(self: &Var) -> bool;
+
+        #[namespace = "juce"]
+        pub type AudioIODeviceTypeArray;
+
+        pub fn size(self: &AudioIODeviceTypeArray) -> i32;
+
+        #[rust_name = "get_unchecked"]
+        pub fn getUnchecked(self: &AudioIODeviceTypeArray, index: i32) -> *mut AudioIODeviceType;
+
+        pub type AudioDeviceSetup;
+
+        #[rust_name = "create_audio_device_setup"]
+        pub fn createAudioDeviceSetup() -> UniquePtr<AudioDeviceSetup>;
+
+        #[rust_name = "output_device_name"]
+        pub fn outputDeviceName(self: &AudioDeviceSetup) -> &str;
+
+        #[rust_name = "input_device_name"]
+        pub fn inputDeviceName(self: &AudioDeviceSetup) -> &str;
+
+        #[rust_name = "sample_rate"]
+        pub fn sampleRate(self: &AudioDeviceSetup) -> f64;
+
+        #[rust_name = "buffer_size"]
+        pub fn bufferSize(self: &AudioDeviceSetup) -> i32;
+
+        #[rust_name = "set_output_device_name"]
+        pub fn setOutputDeviceName(self: Pin<&mut AudioDeviceSetup>, name: &str);
+
+        #[rust_name = "set_input_device_name"]
+        pub fn setInputDeviceName(self: Pin<&mut AudioDeviceSetup>, name: &str);
+
+        #[rust_name = "set_sample_rate"]
+        pub fn setSampleRate(self: Pin<&mut AudioDeviceSetup>, sample_rate: f64);
+
+        #[rust_name = "set_buffer_size"]
+        pub fn setBufferSize(self: Pin<&mut AudioDeviceSetup>, buffer_size: i32);
+
+        #[rust_name = "number_of_input_channels"]
+        pub fn numberOfInputChannels(self: &AudioDeviceSetup) -> i32;
+
+        #[rust_name = "set_number_of_input_channels"]
+        pub fn setNumberOfInputChannels(
+            self: Pin<&mut AudioDeviceSetup>,
+            number_of_input_channels: i32,
+        );
+
+        #[rust_name = "use_default_input_channels"]
+        pub fn useDefaultInputChannels(self: Pin<&mut AudioDeviceSetup>, use_default: bool);
+
+        #[rust_name = "using_default_input_channels"]
+        pub fn usingDefaultInputChannels(self: &AudioDeviceSetup) -> bool;
+
+        #[rust_name = "number_of_output_channels"]
+        pub fn numberOfOutputChannels(self: &AudioDeviceSetup) -> i32;
+
+        #[rust_name = "set_number_of_output_channels"]
+        pub fn setNumberOfOutputChannels(
+            self: Pin<&mut AudioDeviceSetup>,
+            number_of_output_channels: i32,
+        );
+
+        #[rust_name = "use_default_output_channels"]
+        pub fn useDefaultOutputChannels(self: Pin<&mut AudioDeviceSetup>, use_default: bool);
+
+        #[rust_name = "using_default_output_channels"]
+        pub fn usingDefaultOutputChannels(self: &AudioDeviceSetup) -> bool;
+
+        pub type AudioDeviceManager;
+
+        #[rust_name = "create_audio_device_manager"]
+        pub fn createAudioDeviceManager() -> UniquePtr<AudioDeviceManager>;
+
+        #[rust_name = "initialise_with_default_devices"]
+        pub fn initialiseWithDefaultDevices(
+            self: Pin<&mut AudioDeviceManager>,
+            num_input_channels: i32,
+            num_output_channels: i32,
+        ) -> Result<()>;
+
+        #[rust_name = "initialise_with_saved_state"]
+        pub fn initialiseWithSavedState(
+            self: Pin<&mut AudioDeviceManager>,
+            num_input_channels: i32,
+            num_output_channels: i32,
+            saved_state: &str,
+        ) -> Result<()>;
+
+        #[rust_name = "create_state_xml"]
+        pub fn createStateXml(self: &AudioDeviceManager) -> String;
+
+        #[rust_name = "get_audio_device_setup"]
+        pub fn getAudioDeviceSetup(self: &AudioDeviceManager) -> UniquePtr<AudioDeviceSetup>;
+
+        #[rust_name = "set_audio_device_setup"]
+        pub fn setAudioDeviceSetup(self: Pin<&mut AudioDeviceManager>, setup: &AudioDeviceSetup);
+
+        #[rust_name = "get_current_audio_device"]
+        pub fn getCurrentAudioDevice(self: &AudioDeviceManager) -> *mut AudioIODevice;
+
+        #[rust_name = "get_available_device_types"]
+        pub fn getAvailableDeviceTypes(
+            self: Pin<&mut AudioDeviceManager>,
+        ) -> &AudioIODeviceTypeArray;
+
+        #[rust_name = "get_current_device_type_object"]
+        pub fn getCurrentDeviceTypeObject(self: &AudioDeviceManager) -> *mut AudioIODeviceType;
+
+        #[rust_name = "play_test_sound"]
+        pub fn playTestSound(self: Pin<&mut AudioDeviceManager>);
+
+        #[rust_name = "add_audio_callback"]
+        pub fn addAudioCallback<'a>(
+            self: Pin<&mut AudioDeviceManager>,
+            callback: Box<BoxedAudioIODeviceCallback>,
+        ) -> UniquePtr<AudioCallbackHandle<'a>>;
+
+        #[rust_name = "add_audio_device_type"]
+        pub fn addAudioDeviceType(
+            self: Pin<&mut AudioDeviceManager>,
+            device_type: Box<BoxedAudioIODeviceType>,
+        );
+
+        #[rust_name = "set_current_audio_device_type"]
+        pub fn setCurrentAudioDeviceType(self: Pin<&mut AudioDeviceManager>, device_type: &str);
+
+        #[rust_name = "set_midi_input_device_enabled"]
+        pub fn setMidiInputDeviceEnabled(
+            self: Pin<&mut AudioDeviceManager>,
+            identifier: &str,
+            enabled: bool,
+        );
+
+        #[rust_name = "is_midi_input_device_enabled"]
+        pub fn isMidiInputDeviceEnabled(self: &AudioDeviceManager, identifier: &str) -> bool;
+
+        #[rust_name = "set_default_midi_output_device"]
+        pub fn setDefaultMidiOutputDevice(self: Pin<&mut AudioDeviceManager>, identifier: &str);
+
+        #[rust_name = "get_default_midi_output_identifier"]
+        pub fn getDefaultMidiOutputIdentifier(self: &AudioDeviceManager) -> String;
+
+        #[rust_name = "add_midi_thru"]
+        pub fn addMidiThru<'a>(
+            self: Pin<&mut AudioDeviceManager>,
+            midi_thru: Box<MidiThru>,
+        ) -> UniquePtr<MidiThruHandle<'a>>;
+
+        #[namespace = "juce"]
+        pub type AudioIODevice;
+
+        #[namespace = "cxx_juce::audio_io_device"]
+        #[rust_name = "get_device_name"]
+        pub fn getDeviceName(self_: &AudioIODevice) -> &str;
+
+        #[namespace = "cxx_juce::audio_io_device"]
+        #[rust_name = "get_device_type_name"]
+        pub fn getDeviceTypeName(self_: &AudioIODevice) -> &str;
+
+        #[rust_name = "get_current_sample_rate"]
+        pub fn getCurrentSampleRate(self: Pin<&mut AudioIODevice>) -> f64;
+
+        #[rust_name = "get_current_buffer_size_samples"]
+        pub fn getCurrentBufferSizeSamples(self: Pin<&mut AudioIODevice>) -> i32;
+
+        #[namespace = "cxx_juce::audio_io_device"]
+        #[rust_name = "get_available_sample_rates"]
+        pub fn getAvailableSampleRates(self_: Pin<&mut AudioIODevice>) -> Vec<f64>;
+
+        #[namespace = "cxx_juce::audio_io_device"]
+        #[rust_name = "get_available_buffer_sizes"]
+        pub fn getAvailableBufferSizes(self_: Pin<&mut AudioIODevice>) -> Vec<usize>;
+
+        #[namespace = "cxx_juce::audio_io_device"]
+        #[rust_name = "open"]
+        pub fn open(
+            self_: Pin<&mut AudioIODevice>,
+            sample_rate: f64,
+            buffer_size: usize,
+        ) -> Result<()>;
+
+        #[rust_name = "close"]
+        pub fn close(self: Pin<&mut AudioIODevice>);
+
+        #[namespace = "cxx_juce::audio_io_device"]
+        #[rust_name = "count_active_input_channels"]
+        pub fn countActiveInputChannels(self_: &AudioIODevice) -> i32;
+
+        #[namespace = "cxx_juce::audio_io_device"]
+        #[rust_name = "count_active_output_channels"]
+        pub fn countActiveOutputChannels(self_: &AudioIODevice) -> i32;
+
+        #[namespace = "juce"]
+        pub type AudioIODeviceType;
+
+        #[namespace = "cxx_juce::audio_io_device_type"]
+        #[rust_name = "get_type_name"]
+        pub fn getTypeName(self_: &AudioIODeviceType) -> String;
+
+        #[rust_name = "scan_for_devices"]
+        pub fn scanForDevices(self: Pin<&mut AudioIODeviceType>);
+
+        #[namespace = "cxx_juce::audio_io_device_type"]
+        #[rust_name = "get_input_device_names"]
+        pub fn getInputDeviceNames(self_: &AudioIODeviceType) -> Vec<String>;
+
+        #[namespace = "cxx_juce::audio_io_device_type"]
+        #[rust_name = "get_output_device_names"]
+        pub fn getOutputDeviceNames(self_: &AudioIODeviceType) -> Vec<String>;
+
+        #[namespace = "cxx_juce::audio_io_device_type"]
+        #[rust_name = "new_device"]
+        pub fn createDevice(
+            self_: Pin<&mut AudioIODeviceType>,
+            input_device_name: &str,
+            output_device_name: &str,
+        ) -> UniquePtr<AudioIODevice>;
+
+        #[namespace = "juce"]
+        pub type AudioSampleBuffer;
+
+        #[rust_name = "get_num_channels"]
+        pub fn getNumChannels(self: &AudioSampleBuffer) -> i32;
+
+        #[rust_name = "get_num_samples"]
+        pub fn getNumSamples(self: &AudioSampleBuffer) -> i32;
+
+        #[rust_name = "get_read_pointer"]
+        pub fn getReadPointer(self: &AudioSampleBuffer, channel: i32) -> *const f32;
+
+        #[rust_name = "get_write_pointer"]
+        pub fn getWritePointer(self: Pin<&mut AudioSampleBuffer>, channel: i32) -> *mut f32;
+
+        #[rust_name = "clear"]
+        pub fn clear(self: Pin<&mut AudioSampleBuffer>);
+
+        #[rust_name = "set_size"]
+        pub fn setSize(
+            self: Pin<&mut AudioSampleBuffer>,
+            new_num_channels: i32,
+            new_num_samples: i32,
+            keep_existing_content: bool,
+            clear_extra_space: bool,
+            avoid_reallocating: bool,
+        );
+
+        #[namespace = "cxx_juce::audio_sample_buffer"]
+        #[rust_name = "create_audio_sample_buffer"]
+        pub fn createAudioSampleBuffer(
+            num_channels: i32,
+            num_samples: i32,
+        ) -> UniquePtr<AudioSampleBuffer>;
+
+        #[namespace = "cxx_juce::audio_sample_buffer"]
+        #[rust_name = "copy_audio_sample_buffer"]
+        pub fn copyAudioSampleBuffer(buffer: &AudioSampleBuffer) -> UniquePtr<AudioSampleBuffer>;
+
+        pub type AudioCallbackHandle<'a>;
+
+        pub type MidiThruHandle<'a>;
+
+        #[namespace = "cxx_juce::system_audio_volume"]
+        #[rust_name = "set_muted"]
+        pub fn setMuted(muted: bool);
+
+        #[namespace = "cxx_juce::system_audio_volume"]
+        #[rust_name = "is_muted"]
+        pub fn isMuted() -> bool;
+
+        #[namespace = "cxx_juce::system_audio_volume"]
+        #[rust_name = "set_gain"]
+        pub fn setGain(gain: f32);
+
+        #[namespace = "cxx_juce::system_audio_volume"]
+        #[rust_name = "get_gain"]
+        pub fn getGain() -> f32;
+
+        #[namespace = "juce"]
+        pub type SingleThreadedIIRFilter;
+
+        #[namespace = "cxx_juce::iir_filter"]
+        #[rust_name = "create_iir_filter"]
+        pub fn createIIRFilter(coefficients: [f32; 5]) -> UniquePtr<SingleThreadedIIRFilter>;
+
+        #[namespace = "juce"]
+        #[rust_name = "process_samples"]
+        pub unsafe fn processSamples(
+            self: Pin<&mut SingleThreadedIIRFilter>,
+            samples: *mut f32,
+            num_samples: i32,
+        );
+
+        #[namespace = "cxx_juce::iir_filter"]
+        #[rust_name = "make_low_pass"]
+        pub fn makeLowPass(sample_rate: f64, frequency: f64, q: f64) -> [f32; 5];
+
+        #[namespace = "cxx_juce::iir_filter"]
+        #[rust_name = "make_high_pass"]
+        pub fn makeHighPass(sample_rate: f64, frequency: f64, q: f64) -> [f32; 5];
+
+        #[namespace = "cxx_juce::iir_filter"]
+        #[rust_name = "make_notch_filter"]
+        pub fn makeNotchFilter(sample_rate: f64, frequency: f64, q: f64) -> [f32; 5];
+
+        #[namespace = "juce"]
+        pub type MidiMessage;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "create_midi_message"]
+        pub fn createMidiMessage(data: &[u8], timestamp: f64) -> Result<UniquePtr<MidiMessage>>;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "copy_midi_message"]
+        pub fn copyMidiMessage(message: &MidiMessage) -> UniquePtr<MidiMessage>;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "get_raw_data"]
+        pub fn getRawData(message: &MidiMessage) -> &[u8];
+
+        #[rust_name = "get_time_stamp"]
+        pub fn getTimeStamp(self: &MidiMessage) -> f64;
+
+        #[rust_name = "set_time_stamp"]
+        pub fn setTimeStamp(self: Pin<&mut MidiMessage>, timestamp: f64);
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "midi_clock"]
+        pub fn midiClock() -> UniquePtr<MidiMessage>;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "midi_start"]
+        pub fn midiStart() -> UniquePtr<MidiMessage>;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "midi_stop"]
+        pub fn midiStop() -> UniquePtr<MidiMessage>;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "midi_continue"]
+        pub fn midiContinue() -> UniquePtr<MidiMessage>;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "song_position_pointer"]
+        pub fn songPositionPointer(position_in_midi_beats: i32) -> UniquePtr<MidiMessage>;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "quarter_frame"]
+        pub fn quarterFrame(sequence_number: i32, value: i32) -> UniquePtr<MidiMessage>;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "full_frame"]
+        pub fn fullFrame(
+            hours: i32,
+            minutes: i32,
+            seconds: i32,
+            frames: i32,
+            timecode_type: i32,
+        ) -> UniquePtr<MidiMessage>;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "get_full_frame_parameters"]
+        pub fn getFullFrameParameters(message: &MidiMessage) -> [i32; 5];
+
+        #[rust_name = "is_midi_clock"]
+        pub fn isMidiClock(self: &MidiMessage) -> bool;
+
+        #[rust_name = "is_midi_start"]
+        pub fn isMidiStart(self: &MidiMessage) -> bool;
+
+        #[rust_name = "is_midi_stop"]
+        pub fn isMidiStop(self: &MidiMessage) -> bool;
+
+        #[rust_name = "is_midi_continue"]
+        pub fn isMidiContinue(self: &MidiMessage) -> bool;
+
+        #[rust_name = "is_song_position_pointer"]
+        pub fn isSongPositionPointer(self: &MidiMessage) -> bool;
+
+        #[rust_name = "get_song_position_pointer_midi_beat"]
+        pub fn getSongPositionPointerMidiBeat(self: &MidiMessage) -> i32;
+
+        #[rust_name = "is_quarter_frame"]
+        pub fn isQuarterFrame(self: &MidiMessage) -> bool;
+
+        #[rust_name = "get_quarter_frame_sequence_number"]
+        pub fn getQuarterFrameSequenceNumber(self: &MidiMessage) -> i32;
+
+        #[rust_name = "get_quarter_frame_value"]
+        pub fn getQuarterFrameValue(self: &MidiMessage) -> i32;
+
+        #[rust_name = "is_full_frame"]
+        pub fn isFullFrame(self: &MidiMessage) -> bool;
+
+        #[namespace = "cxx_juce::midi_message"]
+        #[rust_name = "controller_event"]
+        pub fn controllerEvent(
+            channel: i32,
+            controller_type: i32,
+            value: i32,
+        ) -> UniquePtr<MidiMessage>;
+
+        #[rust_name = "get_channel"]
+        pub fn getChannel(self: &MidiMessage) -> i32;
+
+        #[rust_name = "is_controller"]
+        pub fn isController(self: &MidiMessage) -> bool;
+
+        #[rust_name = "get_controller_number"]
+        pub fn getControllerNumber(self: &MidiMessage) -> i32;
+
+        #[rust_name = "get_controller_value"]
+        pub fn getControllerValue(self: &MidiMessage) -> i32;
+
+        #[rust_name = "set_channel"]
+        pub fn setChannel(self: Pin<&mut MidiMessage>, channel: i32);
+
+        #[namespace = "juce"]
+        pub type MidiBuffer;
+
+        #[namespace = "cxx_juce::midi_buffer"]
+        #[rust_name = "create_midi_buffer"]
+        pub fn createMidiBuffer() -> UniquePtr<MidiBuffer>;
+
+        #[namespace = "cxx_juce::midi_buffer"]
+        #[rust_name = "copy_midi_buffer"]
+        pub fn copyMidiBuffer(buffer: &MidiBuffer) -> UniquePtr<MidiBuffer>;
+
+        #[namespace = "cxx_juce::midi_buffer"]
+        #[rust_name = "add_midi_message"]
+        pub fn addEvent(buffer: Pin<&mut MidiBuffer>, message: &MidiMessage, sample_number: i32);
+
+        #[namespace = "cxx_juce::midi_buffer"]
+        #[rust_name = "add_raw_midi_event"]
+        pub fn addRawEvent(buffer: Pin<&mut MidiBuffer>, data: &[u8], sample_number: i32);
+
+        #[namespace = "cxx_juce::midi_buffer"]
+        #[rust_name = "get_midi_buffer_data"]
+        pub fn getRawData(buffer: &MidiBuffer) -> &[u8];
+
+        #[rust_name = "clear"]
+        pub fn clear(self: Pin<&mut MidiBuffer>);
+
+        #[rust_name = "is_empty"]
+        pub fn isEmpty(self: &MidiBuffer) -> bool;
+
+        #[rust_name = "get_num_events"]
+        pub fn getNumEvents(self: &MidiBuffer) -> i32;
+
+        #[namespace = "juce"]
+        pub type MidiRPNDetector;
+
+        #[namespace = "cxx_juce::midi_rpn"]
+        #[rust_name = "create_midi_rpn_detector"]
+        pub fn createMidiRPNDetector() -> UniquePtr<MidiRPNDetector>;
+
+        #[namespace = "cxx_juce::midi_rpn"]
+        #[rust_name = "parse_controller_message"]
+        pub fn parseControllerMessage(
+            detector: Pin<&mut MidiRPNDetector>,
+            channel: i32,
+            controller_number: i32,
+            controller_value: i32,
+            result: &mut MidiRPNMessage,
+        ) -> bool;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut MidiRPNDetector>);
+
+        #[namespace = "cxx_juce::midi_rpn"]
+        #[rust_name = "generate_rpn"]
+        pub fn generate(message: MidiRPNMessage) -> UniquePtr<MidiBuffer>;
+
+        pub type MidiInput;
+
+        #[namespace = "cxx_juce::midi_input"]
+        #[rust_name = "get_available_midi_input_devices"]
+        pub fn getAvailableDevices() -> Vec<MidiDeviceInfo>;
+
+        #[namespace = "cxx_juce::midi_input"]
+        #[rust_name = "get_default_midi_input_device"]
+        pub fn getDefaultDevice() -> MidiDeviceInfo;
+
+        #[namespace = "cxx_juce::midi_input"]
+        #[rust_name = "open_midi_input"]
+        pub fn openDevice(
+            identifier: &str,
+            callback: Box<BoxedMidiInputCallback>,
+        ) -> Result<UniquePtr<MidiInput>>;
+
+        #[rust_name = "get_device_info"]
+        pub fn getDeviceInfo(self: &MidiInput) -> MidiDeviceInfo;
+
+        pub fn start(self: Pin<&mut MidiInput>);
+
+        pub fn stop(self: Pin<&mut MidiInput>);
+
+        #[namespace = "juce"]
+        pub type MidiOutput;
+
+        #[namespace = "cxx_juce::midi_output"]
+        #[rust_name = "get_available_midi_output_devices"]
+        pub fn getAvailableDevices() -> Vec<MidiDeviceInfo>;
+
+        #[namespace = "cxx_juce::midi_output"]
+        #[rust_name = "get_default_midi_output_device"]
+        pub fn getDefaultDevice() -> MidiDeviceInfo;
+
+        #[namespace = "cxx_juce::midi_output"]
+        #[rust_name = "open_midi_output"]
+        pub fn openDevice(identifier: &str) -> Result<UniquePtr<MidiOutput>>;
+
+        #[namespace = "cxx_juce::midi_output"]
+        #[rust_name = "get_midi_output_device_info"]
+        pub fn getDeviceInfo(output: &MidiOutput) -> MidiDeviceInfo;
+
+        #[rust_name = "send_message_now"]
+        pub fn sendMessageNow(self: Pin<&mut MidiOutput>, message: &MidiMessage);
+
+        #[rust_name = "send_block_of_messages_now"]
+        pub fn sendBlockOfMessagesNow(self: Pin<&mut MidiOutput>, buffer: &MidiBuffer);
+
+        #[rust_name = "send_block_of_messages"]
+        pub fn sendBlockOfMessages(
+            self: Pin<&mut MidiOutput>,
+            buffer: &MidiBuffer,
+            millisecond_counter_to_start_at: f64,
+            samples_per_second_for_buffer: f64,
+        );
+
+        #[rust_name = "clear_all_pending_messages"]
+        pub fn clearAllPendingMessages(self: Pin<&mut MidiOutput>);
+
+        #[rust_name = "start_background_thread"]
+        pub fn startBackgroundThread(self: Pin<&mut MidiOutput>);
+
+        #[rust_name = "stop_background_thread"]
+        pub fn stopBackgroundThread(self: Pin<&mut MidiOutput>);
+
+        #[rust_name = "is_background_thread_running"]
+        pub fn isBackgroundThreadRunning(self: &MidiOutput) -> bool;
+
+        #[namespace = "juce"]
+        pub type MemoryBlock;
+
+        #[namespace = "cxx_juce::memory_block"]
+        #[rust_name = "create_memory_block"]
+        pub fn createMemoryBlock(data: &[u8]) -> UniquePtr<MemoryBlock>;
+
+        #[namespace = "cxx_juce::memory_block"]
+        #[rust_name = "create_memory_block_with_size"]
+        pub fn createMemoryBlockWithSize(size: usize) -> UniquePtr<MemoryBlock>;
+
+        #[namespace = "cxx_juce::memory_block"]
+        #[rust_name = "copy_memory_block"]
+        pub fn copy(block: &MemoryBlock) -> UniquePtr<MemoryBlock>;
+
+        #[namespace = "cxx_juce::memory_block"]
+        #[rust_name = "memory_block_equals"]
+        pub fn equals(block: &MemoryBlock, other: &MemoryBlock) -> bool;
+
+        #[namespace = "cxx_juce::memory_block"]
+        #[rust_name = "get_memory_block_data"]
+        pub fn getData(block: &MemoryBlock) -> *const u8;
+
+        #[namespace = "cxx_juce::memory_block"]
+        #[rust_name = "get_mutable_memory_block_data"]
+        pub fn getMutableData(block: Pin<&mut MemoryBlock>) -> *mut u8;
+
+        #[rust_name = "get_size"]
+        pub fn getSize(self: &MemoryBlock) -> usize;
+
+        #[rust_name = "set_size"]
+        pub fn setSize(
+            self: Pin<&mut MemoryBlock>,
+            new_size: usize,
+            initialise_new_space_to_zero: bool,
+        );
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut MemoryBlock>);
+
+        #[namespace = "cxx_juce::memory_block"]
+        #[rust_name = "append_to_memory_block"]
+        pub fn append(block: Pin<&mut MemoryBlock>, data: &[u8]);
+
+        #[namespace = "cxx_juce::memory_block"]
+        #[rust_name = "memory_block_to_base64_encoding"]
+        pub fn toBase64Encoding(block: &MemoryBlock) -> String;
+
+        #[namespace = "cxx_juce::memory_block"]
+        #[rust_name = "memory_block_from_base64_encoding"]
+        pub fn fromBase64Encoding(encoded: &str) -> Result<UniquePtr<MemoryBlock>>;
+
+        #[namespace = "cxx_juce::base64"]
+        #[rust_name = "to_base64"]
+        pub fn toBase64(data: &[u8]) -> String;
+
+        #[namespace = "cxx_juce::base64"]
+        #[rust_name = "convert_from_base64"]
+        pub fn convertFromBase64(encoded: &str) -> Result<Vec<u8>>;
+
+        #[namespace = "juce"]
+        pub type Random;
+
+        #[namespace = "cxx_juce::random"]
+        #[rust_name = "create_random"]
+        pub fn createRandom() -> UniquePtr<Random>;
+
+        #[namespace = "cxx_juce::random"]
+        #[rust_name = "create_random_with_seed"]
+        pub fn createRandomWithSeed(seed: i64) -> UniquePtr<Random>;
+
+        #[namespace = "cxx_juce::random"]
+        #[rust_name = "copy_random"]
+        pub fn copy(random: &Random) -> UniquePtr<Random>;
+
+        #[namespace = "cxx_juce::random"]
+        #[rust_name = "get_system_random"]
+        pub fn getSystemRandom() -> *mut Random;
+
+        #[rust_name = "set_seed"]
+        pub fn setSeed(self: Pin<&mut Random>, seed: i64);
+
+        #[rust_name = "get_seed"]
+        pub fn getSeed(self: &Random) -> i64;
+
+        #[rust_name = "combine_seed"]
+        pub fn combineSeed(self: Pin<&mut Random>, seed: i64);
+
+        #[rust_name = "set_seed_randomly"]
+        pub fn setSeedRandomly(self: Pin<&mut Random>);
+
+        #[rust_name = "next_int"]
+        pub fn nextInt(self: Pin<&mut Random>) -> i32;
+
+        #[rust_name = "next_int64"]
+        pub fn nextInt64(self: Pin<&mut Random>) -> i64;
+
+        #[rust_name = "next_float"]
+        pub fn nextFloat(self: Pin<&mut Random>) -> f32;
+
+        #[rust_name = "next_double"]
+        pub fn nextDouble(self: Pin<&mut Random>) -> f64;
+
+        #[rust_name = "next_bool"]
+        pub fn nextBool(self: Pin<&mut Random>) -> bool;
+
+        #[namespace = "cxx_juce::random"]
+        #[rust_name = "fill_bits_randomly"]
+        pub fn fillBitsRandomly(random: Pin<&mut Random>, buffer: &mut [u8]);
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_operating_system_name"]
+        pub fn getOperatingSystemName() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "is_operating_system_64_bit"]
+        pub fn isOperatingSystem64Bit() -> bool;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_device_description"]
+        pub fn getDeviceDescription() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_device_manufacturer"]
+        pub fn getDeviceManufacturer() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_computer_name"]
+        pub fn getComputerName() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_logon_name"]
+        pub fn getLogonName() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_full_user_name"]
+        pub fn getFullUserName() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_user_language"]
+        pub fn getUserLanguage() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_user_region"]
+        pub fn getUserRegion() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_display_language"]
+        pub fn getDisplayLanguage() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_cpu_vendor"]
+        pub fn getCpuVendor() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_cpu_model"]
+        pub fn getCpuModel() -> String;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_cpu_speed_in_megahertz"]
+        pub fn getCpuSpeedInMegahertz() -> i32;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_num_cpus"]
+        pub fn getNumCpus() -> i32;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_num_physical_cpus"]
+        pub fn getNumPhysicalCpus() -> i32;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_memory_size_in_megabytes"]
+        pub fn getMemorySizeInMegabytes() -> i32;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_page_size"]
+        pub fn getPageSize() -> i32;
+
+        #[namespace = "cxx_juce::system_stats"]
+        #[rust_name = "get_cpu_features"]
+        pub fn getCpuFeatures() -> CpuFeatures;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "get_millisecond_counter_hi_res"]
+        pub fn getMillisecondCounterHiRes() -> f64;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "get_millisecond_counter"]
+        pub fn getMillisecondCounter() -> u32;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "current_time_millis"]
+        pub fn currentTimeMillis() -> i64;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "get_high_resolution_ticks"]
+        pub fn getHighResolutionTicks() -> i64;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "get_high_resolution_ticks_per_second"]
+        pub fn getHighResolutionTicksPerSecond() -> i64;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "high_resolution_ticks_to_seconds"]
+        pub fn highResolutionTicksToSeconds(ticks: i64) -> f64;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "seconds_to_high_resolution_ticks"]
+        pub fn secondsToHighResolutionTicks(seconds: f64) -> i64;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "get_local_time_components"]
+        pub fn getLocalComponents(milliseconds_since_epoch: i64) -> [i32; 8];
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "get_utc_offset_seconds"]
+        pub fn getUtcOffsetSeconds(milliseconds_since_epoch: i64) -> i32;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "get_time_zone"]
+        pub fn getTimeZone(milliseconds_since_epoch: i64) -> String;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "time_to_string"]
+        pub fn toString(
+            milliseconds_since_epoch: i64,
+            include_date: bool,
+            include_time: bool,
+            include_seconds: bool,
+            use_24_hour_clock: bool,
+        ) -> String;
+
+        #[namespace = "cxx_juce::time"]
+        #[rust_name = "time_to_iso8601"]
+        pub fn toISO8601(milliseconds_since_epoch: i64, include_divider_characters: bool)
+            -> String;
+
+        #[namespace = "cxx_juce::uuid"]
+        #[rust_name = "create_uuid"]
+        pub fn createUuid() -> [u8; 16];
+
+        #[namespace = "cxx_juce::uuid"]
+        #[rust_name = "uuid_from_string"]
+        pub fn fromString(string: &str) -> Result<[u8; 16]>;
+
+        #[namespace = "cxx_juce::uuid"]
+        #[rust_name = "uuid_to_string"]
+        pub fn toString(bytes: [u8; 16]) -> String;
+
+        #[namespace = "cxx_juce::uuid"]
+        #[rust_name = "uuid_to_dashed_string"]
+        pub fn toDashedString(bytes: [u8; 16]) -> String;
+
+        #[namespace = "juce"]
+        #[cxx_name = "URL"]
+        pub type Url;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "create_url"]
+        pub fn createUrl(url: &str) -> UniquePtr<Url>;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "copy_url"]
+        pub fn copy(url: &Url) -> UniquePtr<Url>;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "url_equals"]
+        pub fn equals(url: &Url, other: &Url) -> bool;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "url_to_string"]
+        pub fn toString(url: &Url, include_get_parameters: bool) -> String;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "get_url_scheme"]
+        pub fn getScheme(url: &Url) -> String;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "get_url_domain"]
+        pub fn getDomain(url: &Url) -> String;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "get_url_sub_path"]
+        pub fn getSubPath(url: &Url) -> String;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "get_url_file_name"]
+        pub fn getFileName(url: &Url) -> String;
+
+        #[rust_name = "get_port"]
+        pub fn getPort(self: &Url) -> i32;
+
+        #[rust_name = "is_well_formed"]
+        pub fn isWellFormed(self: &Url) -> bool;
+
+        #[rust_name = "is_local_file"]
+        pub fn isLocalFile(self: &Url) -> bool;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "get_url_parameters"]
+        pub fn getParameters(url: &Url) -> Vec<StringPair>;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "url_with_parameter"]
+        pub fn withParameter(url: &Url, name: &str, value: &str) -> UniquePtr<Url>;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "url_with_post_data"]
+        pub fn withPostData(url: &Url, data: &[u8]) -> UniquePtr<Url>;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "get_url_post_data"]
+        pub fn getPostData(url: &Url) -> Vec<u8>;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "get_child_url"]
+        pub fn getChildUrl(url: &Url, sub_path: &str) -> UniquePtr<Url>;
+
+        #[namespace = "cxx_juce::url"]
+        #[rust_name = "get_parent_url"]
+        pub fn getParentUrl(url: &Url) -> UniquePtr<Url>;
+
+        #[namespace = "juce"]
+        pub type TimeSliceThread;
+
+        #[namespace = "cxx_juce::time_slice_thread"]
+        #[rust_name = "create_time_slice_thread"]
+        pub fn createTimeSliceThread(name: &str) -> SharedPtr<TimeSliceThread>;
+
+        #[namespace = "cxx_juce::time_slice_thread"]
+        #[rust_name = "get_num_time_slice_clients"]
+        pub fn getNumClients(thread: &SharedPtr<TimeSliceThread>) -> i32;
+
+        pub type TimeSliceClient;
+
+        #[namespace = "cxx_juce::time_slice_thread"]
+        #[rust_name = "add_time_slice_client"]
+        pub fn addClient(
+            thread: SharedPtr<TimeSliceThread>,
+            client: Box<BoxedTimeSliceClient>,
+        ) -> UniquePtr<TimeSliceClient>;
+
+        #[namespace = "juce"]
+        pub type ThreadPool;
+
+        #[namespace = "juce"]
+        pub type ThreadPoolJob;
+
+        #[rust_name = "should_exit"]
+        pub fn shouldExit(self: &ThreadPoolJob) -> bool;
+
+        #[namespace = "cxx_juce::thread_pool"]
+        #[rust_name = "create_thread_pool"]
+        pub fn createThreadPool(num_threads: i32) -> Result<SharedPtr<ThreadPool>>;
+
+        #[namespace = "cxx_juce::thread_pool"]
+        #[rust_name = "add_thread_pool_job"]
+        pub fn addJob(pool: &SharedPtr<ThreadPool>, name: &str, job: Box<BoxedThreadPoolJob>);
+
+        #[namespace = "cxx_juce::thread_pool"]
+        #[rust_name = "get_num_thread_pool_jobs"]
+        pub fn getNumJobs(pool: &SharedPtr<ThreadPool>) -> i32;
+
+        #[namespace = "cxx_juce::thread_pool"]
+        #[rust_name = "get_num_thread_pool_threads"]
+        pub fn getNumThreads(pool: &SharedPtr<ThreadPool>) -> i32;
+
+        #[namespace = "cxx_juce::thread_pool"]
+        #[rust_name = "remove_all_thread_pool_jobs"]
+        pub fn removeAllJobs(
+            pool: &SharedPtr<ThreadPool>,
+            interrupt_running_jobs: bool,
+            timeout_ms: i32,
+        ) -> bool;
+
+        pub type SmoothedValue;
+
+        #[namespace = "cxx_juce::smoothed_value"]
+        #[rust_name = "create_smoothed_value"]
+        pub fn createSmoothedValue(smoothing: i32, initial_value: f32) -> UniquePtr<SmoothedValue>;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut SmoothedValue>, sample_rate: f64, ramp_length_in_seconds: f64);
+
+        #[rust_name = "reset_steps"]
+        pub fn resetSteps(self: Pin<&mut SmoothedValue>, num_steps: i32);
+
+        #[rust_name = "set_target_value"]
+        pub fn setTargetValue(self: Pin<&mut SmoothedValue>, value: f32);
+
+        #[rust_name = "set_current_and_target_value"]
+        pub fn setCurrentAndTargetValue(self: Pin<&mut SmoothedValue>, value: f32);
+
+        #[rust_name = "get_target_value"]
+        pub fn getTargetValue(self: &SmoothedValue) -> f32;
+
+        #[rust_name = "get_current_value"]
+        pub fn getCurrentValue(self: &SmoothedValue) -> f32;
+
+        #[rust_name = "is_smoothing"]
+        pub fn isSmoothing(self: &SmoothedValue) -> bool;
+
+        #[rust_name = "get_next_value"]
+        pub fn getNextValue(self: Pin<&mut SmoothedValue>) -> f32;
+
+        #[rust_name = "skip"]
+        pub fn skip(self: Pin<&mut SmoothedValue>, num_samples: i32) -> f32;
+
+        #[rust_name = "apply_gain"]
+        pub fn applyGain(self: Pin<&mut SmoothedValue>, buffer: Pin<&mut AudioSampleBuffer>);
+
+        #[namespace = "juce"]
+        pub type ADSR;
+
+        #[namespace = "cxx_juce::adsr"]
+        #[rust_name = "create_adsr"]
+        pub fn createADSR() -> UniquePtr<ADSR>;
+
+        #[namespace = "cxx_juce::adsr"]
+        #[rust_name = "set_adsr_parameters"]
+        pub fn setParameters(
+            adsr: Pin<&mut ADSR>,
+            attack: f32,
+            decay: f32,
+            sustain: f32,
+            release: f32,
+        );
+
+        #[namespace = "cxx_juce::adsr"]
+        #[rust_name = "apply_envelope_to_buffer"]
+        pub fn applyEnvelopeToBuffer(adsr: Pin<&mut ADSR>, buffer: Pin<&mut AudioSampleBuffer>);
+
+        #[rust_name = "set_sample_rate"]
+        pub fn setSampleRate(self: Pin<&mut ADSR>, sample_rate: f64);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut ADSR>);
+
+        #[rust_name = "note_on"]
+        pub fn noteOn(self: Pin<&mut ADSR>);
+
+        #[rust_name = "note_off"]
+        pub fn noteOff(self: Pin<&mut ADSR>);
+
+        #[rust_name = "is_active"]
+        pub fn isActive(self: &ADSR) -> bool;
+
+        #[rust_name = "get_next_sample"]
+        pub fn getNextSample(self: Pin<&mut ADSR>) -> f32;
+
+        #[namespace = "cxx_juce::float_vector_operations"]
+        #[rust_name = "float_vector_add"]
+        pub fn add(dest: &mut [f32], src: &[f32]);
+
+        #[namespace = "cxx_juce::float_vector_operations"]
+        #[rust_name = "float_vector_add_value"]
+        pub fn addValue(dest: &mut [f32], amount: f32);
+
+        #[namespace = "cxx_juce::float_vector_operations"]
+        #[rust_name = "float_vector_multiply"]
+        pub fn multiply(dest: &mut [f32], src: &[f32]);
+
+        #[namespace = "cxx_juce::float_vector_operations"]
+        #[rust_name = "float_vector_multiply_by_value"]
+        pub fn multiplyByValue(dest: &mut [f32], multiplier: f32);
+
+        #[namespace = "cxx_juce::float_vector_operations"]
+        #[rust_name = "float_vector_copy_with_multiply"]
+        pub fn copyWithMultiply(dest: &mut [f32], src: &[f32], multiplier: f32);
+
+        #[namespace = "cxx_juce::float_vector_operations"]
+        #[rust_name = "float_vector_find_min_and_max"]
+        pub fn findMinAndMax(src: &[f32]) -> [f32; 2];
+
+        #[namespace = "cxx_juce::float_vector_operations"]
+        #[rust_name = "float_vector_clip"]
+        pub fn clip(samples: &mut [f32], low: f32, high: f32);
+
+        #[namespace = "cxx_juce::float_vector_operations"]
+        #[rust_name = "float_vector_convert_fixed_to_float"]
+        pub fn convertFixedToFloat(dest: &mut [f32], src: &[i32], multiplier: f32);
+
+        #[namespace = "cxx_juce::decibels"]
+        #[rust_name = "decibels_to_gain"]
+        pub fn decibelsToGain(decibels: f32, minus_infinity_db: f32) -> f32;
+
+        #[namespace = "cxx_juce::decibels"]
+        #[rust_name = "gain_to_decibels"]
+        pub fn gainToDecibels(gain: f32, minus_infinity_db: f32) -> f32;
+
+        #[namespace = "cxx_juce::decibels"]
+        #[rust_name = "decibels_to_string"]
+        pub fn toString(
+            decibels: f32,
+            decimal_places: i32,
+            minus_infinity_db: f32,
+            should_include_suffix: bool,
+        ) -> String;
+
+        pub type Interpolator;
+
+        #[namespace = "cxx_juce::interpolator"]
+        #[rust_name = "create_interpolator"]
+        pub fn createInterpolator(interpolator_type: i32) -> UniquePtr<Interpolator>;
+
+        #[rust_name = "get_base_latency"]
+        pub fn getBaseLatency(self: &Interpolator) -> f32;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Interpolator>);
+
+        #[rust_name = "process"]
+        pub fn process(
+            self: Pin<&mut Interpolator>,
+            speed_ratio: f64,
+            input: &[f32],
+            output: &mut [f32],
+        ) -> usize;
+
+        #[rust_name = "process_adding"]
+        pub fn processAdding(
+            self: Pin<&mut Interpolator>,
+            speed_ratio: f64,
+            input: &[f32],
+            output: &mut [f32],
+            gain: f32,
+        ) -> usize;
+    }
+
+    #[cfg(feature = "network")]
+    unsafe extern "C++" {
+        #[namespace = "juce"]
+        pub type WebInputStream;
+
+        #[namespace = "cxx_juce::web_input_stream"]
+        #[rust_name = "create_web_input_stream"]
+        #[allow(clippy::too_many_arguments)]
+        pub fn createWebInputStream(
+            url: &Url,
+            use_post: bool,
+            extra_headers: &str,
+            request_command: &str,
+            timeout_ms: i32,
+            num_redirects_to_follow: i32,
+            on_post_progress: Box<BoxedPostProgressCallback>,
+        ) -> Result<UniquePtr<WebInputStream>>;
+
+        #[namespace = "cxx_juce::web_input_stream"]
+        #[rust_name = "get_response_headers"]
+        pub fn getResponseHeaders(stream: Pin<&mut WebInputStream>) -> Vec<StringPair>;
+
+        #[namespace = "cxx_juce::web_input_stream"]
+        #[rust_name = "read_web_input_stream"]
+        pub fn read(stream: Pin<&mut WebInputStream>, buffer: &mut [u8]) -> i32;
+
+        #[rust_name = "get_total_length"]
+        pub fn getTotalLength(self: Pin<&mut WebInputStream>) -> i64;
+
+        #[rust_name = "get_status_code"]
+        pub fn getStatusCode(self: Pin<&mut WebInputStream>) -> i32;
+
+        #[namespace = "cxx_juce::web_input_stream"]
+        #[rust_name = "cancel_web_input_stream"]
+        pub fn cancel(stream: &WebInputStream);
+    }
+
+    #[cfg(feature = "juce_audio_formats")]
+    unsafe extern "C++" {
+        #[namespace = "juce"]
+        pub type InputStream;
+
+        #[namespace = "cxx_juce::input_stream"]
+        #[rust_name = "read_input_stream"]
+        pub fn read(stream: Pin<&mut InputStream>, buffer: &mut [u8]) -> i32;
+
+        #[rust_name = "get_position"]
+        pub fn getPosition(self: Pin<&mut InputStream>) -> i64;
+
+        #[rust_name = "set_position"]
+        pub fn setPosition(self: Pin<&mut InputStream>, position: i64) -> bool;
+
+        #[rust_name = "get_total_length"]
+        pub fn getTotalLength(self: Pin<&mut InputStream>) -> i64;
+
+        #[namespace = "juce"]
+        pub type OutputStream;
+
+        #[namespace = "cxx_juce::output_stream"]
+        #[rust_name = "write_output_stream"]
+        pub fn write(stream: Pin<&mut OutputStream>, buffer: &[u8]) -> bool;
+
+        #[rust_name = "get_position"]
+        pub fn getPosition(self: Pin<&mut OutputStream>) -> i64;
+
+        #[rust_name = "set_position"]
+        pub fn setPosition(self: Pin<&mut OutputStream>, position: i64) -> bool;
+
+        #[rust_name = "flush"]
+        pub fn flush(self: Pin<&mut OutputStream>);
+
+        #[namespace = "juce"]
+        pub type AudioFormatManager;
+
+        #[namespace = "cxx_juce::audio_format_manager"]
+        #[rust_name = "create_audio_format_manager"]
+        pub fn createAudioFormatManager() -> UniquePtr<AudioFormatManager>;
+
+        #[rust_name = "register_basic_formats"]
+        pub fn registerBasicFormats(self: Pin<&mut AudioFormatManager>);
+
+        #[rust_name = "get_num_known_formats"]
+        pub fn getNumKnownFormats(self: &AudioFormatManager) -> i32;
+
+        #[namespace = "cxx_juce::audio_format_manager"]
+        #[rust_name = "get_known_format_names"]
+        pub fn getKnownFormatNames(manager: &AudioFormatManager) -> Vec<String>;
+
+        #[namespace = "cxx_juce::audio_format_manager"]
+        #[rust_name = "get_wildcard_for_all_formats"]
+        pub fn getWildcardForAllFormats(manager: &AudioFormatManager) -> String;
+
+        #[namespace = "cxx_juce::audio_format_manager"]
+        #[rust_name = "create_reader_for_file"]
+        pub fn createReaderForFile(
+            manager: Pin<&mut AudioFormatManager>,
+            path: &str,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[namespace = "cxx_juce::audio_format_manager"]
+        #[rust_name = "open_reader_for_file"]
+        pub fn openReaderForFile(
+            manager: Pin<&mut AudioFormatManager>,
+            path: &str,
+        ) -> Result<UniquePtr<AudioFormatReader>>;
+
+        #[namespace = "cxx_juce::audio_format_manager"]
+        #[rust_name = "create_reader_for_stream"]
+        pub fn createReaderForStream(
+            manager: Pin<&mut AudioFormatManager>,
+            stream: Box<BoxedInputStream>,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[namespace = "cxx_juce::audio_format_manager"]
+        #[rust_name = "create_writer_for_file"]
+        pub fn createWriterForFile(
+            manager: Pin<&mut AudioFormatManager>,
+            path: &str,
+            sample_rate: f64,
+            num_channels: u32,
+            bits_per_sample: i32,
+            metadata: &[StringPair],
+        ) -> Result<UniquePtr<AudioFormatWriter>>;
+
+        #[namespace = "cxx_juce::custom_audio_format"]
+        #[rust_name = "register_custom_audio_format"]
+        pub fn registerFormat(
+            manager: Pin<&mut AudioFormatManager>,
+            format: Box<BoxedCustomAudioFormat>,
+            make_this_the_default_format: bool,
+        );
+
+        #[namespace = "cxx_juce::custom_audio_format"]
+        #[rust_name = "create_custom_audio_format_writer"]
+        pub fn createCustomAudioFormatWriter(
+            writer: Box<BoxedCustomAudioFormatWriter>,
+            format_name: &str,
+            sample_rate: f64,
+            num_channels: u32,
+            bits_per_sample: u32,
+        ) -> UniquePtr<AudioFormatWriter>;
+
+        #[namespace = "juce"]
+        pub type AudioFormatReader;
+
+        #[namespace = "cxx_juce::audio_format_reader"]
+        #[rust_name = "get_reader_format_name"]
+        pub fn getFormatName(reader: &AudioFormatReader) -> &str;
+
+        #[namespace = "cxx_juce::audio_format_reader"]
+        #[rust_name = "get_reader_sample_rate"]
+        pub fn getSampleRate(reader: &AudioFormatReader) -> f64;
+
+        #[namespace = "cxx_juce::audio_format_reader"]
+        #[rust_name = "get_reader_num_channels"]
+        pub fn getNumChannels(reader: &AudioFormatReader) -> u32;
+
+        #[namespace = "cxx_juce::audio_format_reader"]
+        #[rust_name = "get_reader_length_in_samples"]
+        pub fn getLengthInSamples(reader: &AudioFormatReader) -> i64;
+
+        #[namespace = "cxx_juce::audio_format_reader"]
+        #[rust_name = "get_reader_bits_per_sample"]
+        pub fn getBitsPerSample(reader: &AudioFormatReader) -> u32;
+
+        #[namespace = "cxx_juce::audio_format_reader"]
+        #[rust_name = "reader_uses_floating_point_data"]
+        pub fn usesFloatingPointData(reader: &AudioFormatReader) -> bool;
+
+        #[namespace = "cxx_juce::audio_format_reader"]
+        #[rust_name = "get_reader_metadata"]
+        pub fn getMetadataValues(reader: &AudioFormatReader) -> Vec<StringPair>;
+
+        #[namespace = "cxx_juce::audio_format_reader"]
+        #[rust_name = "read_audio_format_reader"]
+        pub fn read(
+            reader: Pin<&mut AudioFormatReader>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            dest_start_sample: i32,
+            num_samples: i32,
+            reader_start_sample: i64,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::audio_format_reader"]
+        #[rust_name = "read_audio_format_reader_channels"]
+        pub fn readChannels(
+            reader: Pin<&mut AudioFormatReader>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            dest_start_sample: i32,
+            num_samples: i32,
+            reader_start_sample: i64,
+            channels: &[usize],
+            fill_leftover_channels_with_copies: bool,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::memory_mapped_audio_format_reader"]
+        #[rust_name = "map_entire_file"]
+        pub fn mapEntireFile(reader: Pin<&mut AudioFormatReader>) -> bool;
+
+        #[namespace = "cxx_juce::memory_mapped_audio_format_reader"]
+        #[rust_name = "map_section_of_file"]
+        pub fn mapSectionOfFile(
+            reader: Pin<&mut AudioFormatReader>,
+            start_sample: i64,
+            end_sample: i64,
+        ) -> bool;
+
+        #[namespace = "cxx_juce::memory_mapped_audio_format_reader"]
+        #[rust_name = "get_mapped_section"]
+        pub fn getMappedSection(reader: &AudioFormatReader) -> [i64; 2];
+
+        #[namespace = "cxx_juce::memory_mapped_audio_format_reader"]
+        #[rust_name = "touch_sample"]
+        pub fn touchSample(reader: &AudioFormatReader, sample: i64);
+
+        #[namespace = "cxx_juce::memory_mapped_audio_format_reader"]
+        #[rust_name = "get_num_bytes_used"]
+        pub fn getNumBytesUsed(reader: &AudioFormatReader) -> usize;
+
+        #[namespace = "cxx_juce::memory_mapped_audio_format_reader"]
+        #[rust_name = "get_mapped_sample"]
+        pub fn getSample(reader: &AudioFormatReader, sample: i64, result: &mut [f32])
+            -> Result<()>;
+
+        #[namespace = "cxx_juce::buffering_audio_reader"]
+        #[rust_name = "create_buffering_audio_reader"]
+        pub fn createBufferingAudioReader(
+            source: UniquePtr<AudioFormatReader>,
+            buffering_thread: SharedPtr<TimeSliceThread>,
+            samples_to_buffer: i32,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[namespace = "cxx_juce::buffering_audio_reader"]
+        #[rust_name = "set_buffering_read_timeout"]
+        pub fn setReadTimeout(reader: Pin<&mut AudioFormatReader>, timeout_milliseconds: i32);
+
+        #[namespace = "cxx_juce::buffering_audio_reader"]
+        #[rust_name = "read_buffered_samples"]
+        pub fn readSamples(
+            reader: Pin<&mut AudioFormatReader>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            dest_start_sample: i32,
+            num_samples: i32,
+            reader_start_sample: i64,
+        ) -> Result<bool>;
+
+        pub type ThreadedWriter;
+
+        #[namespace = "cxx_juce::threaded_writer"]
+        #[rust_name = "create_threaded_writer"]
+        pub fn createThreadedWriter(
+            writer: UniquePtr<AudioFormatWriter>,
+            writer_thread: SharedPtr<TimeSliceThread>,
+            num_samples_to_buffer: i32,
+        ) -> UniquePtr<ThreadedWriter>;
+
+        #[namespace = "cxx_juce::threaded_writer"]
+        #[rust_name = "write_threaded_writer"]
+        pub fn write(writer: Pin<&mut ThreadedWriter>, buffer: &AudioSampleBuffer) -> Result<bool>;
+
+        #[rust_name = "set_flush_interval"]
+        pub fn setFlushInterval(self: Pin<&mut ThreadedWriter>, num_samples_per_flush: i32);
+
+        #[namespace = "cxx_juce::custom_audio_format_reader"]
+        #[rust_name = "create_custom_audio_format_reader"]
+        pub fn createCustomAudioFormatReader(
+            reader: Box<BoxedCustomAudioFormatReader>,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        pub type ResamplingAudioFormatReader;
+
+        #[namespace = "cxx_juce::resampling_audio_format_reader"]
+        #[rust_name = "create_resampling_audio_format_reader"]
+        pub fn createResamplingAudioFormatReader(
+            reader: UniquePtr<AudioFormatReader>,
+            sample_rate: f64,
+        ) -> Result<UniquePtr<ResamplingAudioFormatReader>>;
+
+        #[rust_name = "get_sample_rate"]
+        pub fn getSampleRate(self: &ResamplingAudioFormatReader) -> f64;
+
+        #[rust_name = "get_num_channels"]
+        pub fn getNumChannels(self: &ResamplingAudioFormatReader) -> i32;
+
+        #[rust_name = "get_length_in_samples"]
+        pub fn getLengthInSamples(self: &ResamplingAudioFormatReader) -> i64;
+
+        #[rust_name = "get_position"]
+        pub fn getPosition(self: &ResamplingAudioFormatReader) -> i64;
+
+        #[rust_name = "set_position"]
+        pub fn setPosition(self: Pin<&mut ResamplingAudioFormatReader>, position: i64);
+
+        #[rust_name = "read"]
+        pub fn read(
+            self: Pin<&mut ResamplingAudioFormatReader>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+        ) -> Result<()>;
+
+        #[namespace = "juce"]
+        pub type WavAudioFormat;
+
+        #[namespace = "cxx_juce::wav_audio_format"]
+        #[rust_name = "create_wav_audio_format"]
+        pub fn createWavAudioFormat() -> UniquePtr<WavAudioFormat>;
+
+        #[namespace = "cxx_juce::wav_audio_format"]
+        #[rust_name = "create_wav_reader_for_file"]
+        pub fn createReaderForFile(
+            format: Pin<&mut WavAudioFormat>,
+            path: &str,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[namespace = "cxx_juce::wav_audio_format"]
+        #[rust_name = "create_wav_reader_for_stream"]
+        pub fn createReaderForStream(
+            format: Pin<&mut WavAudioFormat>,
+            stream: Box<BoxedInputStream>,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[namespace = "cxx_juce::wav_audio_format"]
+        #[rust_name = "create_wav_memory_mapped_reader"]
+        pub fn createMemoryMappedReader(
+            format: Pin<&mut WavAudioFormat>,
+            path: &str,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[namespace = "cxx_juce::wav_audio_format"]
+        #[rust_name = "create_wav_writer_for_file"]
+        pub fn createWriterForFile(
+            format: Pin<&mut WavAudioFormat>,
+            path: &str,
+            sample_rate: f64,
+            num_channels: u32,
+            bits_per_sample: i32,
+            metadata: &[StringPair],
+        ) -> Result<UniquePtr<AudioFormatWriter>>;
+
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        #[namespace = "juce"]
+        pub type CoreAudioFormat;
+
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        #[namespace = "cxx_juce::core_audio_format"]
+        #[rust_name = "create_core_audio_format"]
+        pub fn createCoreAudioFormat() -> UniquePtr<CoreAudioFormat>;
+
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        #[namespace = "cxx_juce::core_audio_format"]
+        #[rust_name = "get_core_audio_file_extensions"]
+        pub fn getFileExtensions(format: &CoreAudioFormat) -> Vec<String>;
+
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        #[namespace = "cxx_juce::core_audio_format"]
+        #[rust_name = "create_core_audio_reader_for_file"]
+        pub fn createReaderForFile(
+            format: Pin<&mut CoreAudioFormat>,
+            path: &str,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        #[namespace = "cxx_juce::core_audio_format"]
+        #[rust_name = "create_core_audio_reader_for_stream"]
+        pub fn createReaderForStream(
+            format: Pin<&mut CoreAudioFormat>,
+            stream: Box<BoxedInputStream>,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[cfg(feature = "flac")]
+        #[namespace = "juce"]
+        pub type FlacAudioFormat;
+
+        #[cfg(feature = "flac")]
+        #[namespace = "cxx_juce::flac_audio_format"]
+        #[rust_name = "create_flac_audio_format"]
+        pub fn createFlacAudioFormat() -> UniquePtr<FlacAudioFormat>;
+
+        #[cfg(feature = "flac")]
+        #[namespace = "cxx_juce::flac_audio_format"]
+        #[rust_name = "create_flac_reader_for_file"]
+        pub fn createReaderForFile(
+            format: Pin<&mut FlacAudioFormat>,
+            path: &str,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[cfg(feature = "flac")]
+        #[namespace = "cxx_juce::flac_audio_format"]
+        #[rust_name = "create_flac_reader_for_stream"]
+        pub fn createReaderForStream(
+            format: Pin<&mut FlacAudioFormat>,
+            stream: Box<BoxedInputStream>,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[cfg(feature = "flac")]
+        #[namespace = "cxx_juce::flac_audio_format"]
+        #[rust_name = "create_flac_writer_for_file"]
+        pub fn createWriterForFile(
+            format: Pin<&mut FlacAudioFormat>,
+            path: &str,
+            sample_rate: f64,
+            num_channels: u32,
+            bits_per_sample: i32,
+            compression_level: i32,
+        ) -> Result<UniquePtr<AudioFormatWriter>>;
+
+        #[cfg(feature = "lame")]
+        #[namespace = "juce"]
+        pub type LAMEEncoderAudioFormat;
+
+        #[cfg(feature = "lame")]
+        #[namespace = "cxx_juce::lame_encoder_audio_format"]
+        #[rust_name = "create_lame_encoder_audio_format"]
+        pub fn createLAMEEncoderAudioFormat(
+            lame_executable: &str,
+        ) -> Result<UniquePtr<LAMEEncoderAudioFormat>>;
+
+        #[cfg(feature = "lame")]
+        #[namespace = "cxx_juce::lame_encoder_audio_format"]
+        #[rust_name = "get_lame_quality_options"]
+        pub fn getQualityOptions(format: Pin<&mut LAMEEncoderAudioFormat>) -> Vec<String>;
+
+        #[cfg(feature = "lame")]
+        #[namespace = "cxx_juce::lame_encoder_audio_format"]
+        #[rust_name = "create_lame_writer_for_file"]
+        pub fn createWriterForFile(
+            format: Pin<&mut LAMEEncoderAudioFormat>,
+            path: &str,
+            sample_rate: f64,
+            num_channels: u32,
+            quality_option_index: i32,
+        ) -> Result<UniquePtr<AudioFormatWriter>>;
+
+        #[cfg(feature = "mp3")]
+        #[namespace = "juce"]
+        pub type MP3AudioFormat;
+
+        #[cfg(feature = "mp3")]
+        #[namespace = "cxx_juce::mp3_audio_format"]
+        #[rust_name = "create_mp3_audio_format"]
+        pub fn createMP3AudioFormat() -> UniquePtr<MP3AudioFormat>;
+
+        #[cfg(feature = "mp3")]
+        #[namespace = "cxx_juce::mp3_audio_format"]
+        #[rust_name = "create_mp3_reader_for_file"]
+        pub fn createReaderForFile(
+            format: Pin<&mut MP3AudioFormat>,
+            path: &str,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[cfg(feature = "mp3")]
+        #[namespace = "cxx_juce::mp3_audio_format"]
+        #[rust_name = "create_mp3_reader_for_stream"]
+        pub fn createReaderForStream(
+            format: Pin<&mut MP3AudioFormat>,
+            stream: Box<BoxedInputStream>,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[cfg(feature = "ogg")]
+        #[namespace = "juce"]
+        pub type OggVorbisAudioFormat;
+
+        #[cfg(feature = "ogg")]
+        #[namespace = "cxx_juce::ogg_vorbis_audio_format"]
+        #[rust_name = "create_ogg_vorbis_audio_format"]
+        pub fn createOggVorbisAudioFormat() -> UniquePtr<OggVorbisAudioFormat>;
+
+        #[cfg(feature = "ogg")]
+        #[namespace = "cxx_juce::ogg_vorbis_audio_format"]
+        #[rust_name = "get_ogg_vorbis_quality_options"]
+        pub fn getQualityOptions(format: Pin<&mut OggVorbisAudioFormat>) -> Vec<String>;
+
+        #[cfg(feature = "ogg")]
+        #[namespace = "cxx_juce::ogg_vorbis_audio_format"]
+        #[rust_name = "create_ogg_vorbis_reader_for_file"]
+        pub fn createReaderForFile(
+            format: Pin<&mut OggVorbisAudioFormat>,
+            path: &str,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[cfg(feature = "ogg")]
+        #[namespace = "cxx_juce::ogg_vorbis_audio_format"]
+        #[rust_name = "create_ogg_vorbis_reader_for_stream"]
+        pub fn createReaderForStream(
+            format: Pin<&mut OggVorbisAudioFormat>,
+            stream: Box<BoxedInputStream>,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[cfg(feature = "ogg")]
+        #[namespace = "cxx_juce::ogg_vorbis_audio_format"]
+        #[rust_name = "create_ogg_vorbis_writer_for_file"]
+        pub fn createWriterForFile(
+            format: Pin<&mut OggVorbisAudioFormat>,
+            path: &str,
+            sample_rate: f64,
+            num_channels: u32,
+            quality_option_index: i32,
+            metadata: &[StringPair],
+        ) -> Result<UniquePtr<AudioFormatWriter>>;
+
+        #[namespace = "juce"]
+        pub type AiffAudioFormat;
+
+        #[namespace = "cxx_juce::aiff_audio_format"]
+        #[rust_name = "create_aiff_audio_format"]
+        pub fn createAiffAudioFormat() -> UniquePtr<AiffAudioFormat>;
+
+        #[namespace = "cxx_juce::aiff_audio_format"]
+        #[rust_name = "create_aiff_reader_for_file"]
+        pub fn createReaderForFile(
+            format: Pin<&mut AiffAudioFormat>,
+            path: &str,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[namespace = "cxx_juce::aiff_audio_format"]
+        #[rust_name = "create_aiff_reader_for_stream"]
+        pub fn createReaderForStream(
+            format: Pin<&mut AiffAudioFormat>,
+            stream: Box<BoxedInputStream>,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[namespace = "cxx_juce::aiff_audio_format"]
+        #[rust_name = "create_aiff_memory_mapped_reader"]
+        pub fn createMemoryMappedReader(
+            format: Pin<&mut AiffAudioFormat>,
+            path: &str,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[namespace = "cxx_juce::aiff_audio_format"]
+        #[rust_name = "create_aiff_writer_for_file"]
+        pub fn createWriterForFile(
+            format: Pin<&mut AiffAudioFormat>,
+            path: &str,
+            sample_rate: f64,
+            num_channels: u32,
+            bits_per_sample: i32,
+            metadata: &[StringPair],
+        ) -> Result<UniquePtr<AudioFormatWriter>>;
+
+        #[cfg(target_os = "windows")]
+        #[namespace = "juce"]
+        pub type WindowsMediaAudioFormat;
+
+        #[cfg(target_os = "windows")]
+        #[namespace = "cxx_juce::windows_media_audio_format"]
+        #[rust_name = "create_windows_media_audio_format"]
+        pub fn createWindowsMediaAudioFormat() -> UniquePtr<WindowsMediaAudioFormat>;
+
+        #[cfg(target_os = "windows")]
+        #[namespace = "cxx_juce::windows_media_audio_format"]
+        #[rust_name = "get_windows_media_file_extensions"]
+        pub fn getFileExtensions(format: &WindowsMediaAudioFormat) -> Vec<String>;
+
+        #[cfg(target_os = "windows")]
+        #[namespace = "cxx_juce::windows_media_audio_format"]
+        #[rust_name = "create_windows_media_reader_for_file"]
+        pub fn createReaderForFile(
+            format: Pin<&mut WindowsMediaAudioFormat>,
+            path: &str,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[cfg(target_os = "windows")]
+        #[namespace = "cxx_juce::windows_media_audio_format"]
+        #[rust_name = "create_windows_media_reader_for_stream"]
+        pub fn createReaderForStream(
+            format: Pin<&mut WindowsMediaAudioFormat>,
+            stream: Box<BoxedInputStream>,
+        ) -> UniquePtr<AudioFormatReader>;
+
+        #[namespace = "juce"]
+        pub type AudioFormatWriter;
+
+        #[namespace = "cxx_juce::audio_format_writer"]
+        #[rust_name = "write_audio_format_writer"]
+        pub fn write(
+            writer: Pin<&mut AudioFormatWriter>,
+            buffer: &AudioSampleBuffer,
+            start_sample: i32,
+            num_samples: i32,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::audio_format_writer"]
+        #[rust_name = "flush_audio_format_writer"]
+        pub fn flush(writer: Pin<&mut AudioFormatWriter>) -> Result<()>;
+
+        #[namespace = "cxx_juce::audio_format_writer"]
+        #[rust_name = "get_writer_sample_rate"]
+        pub fn getSampleRate(writer: &AudioFormatWriter) -> f64;
+
+        #[namespace = "cxx_juce::audio_format_writer"]
+        #[rust_name = "get_writer_num_channels"]
+        pub fn getNumChannels(writer: &AudioFormatWriter) -> u32;
+
+        #[namespace = "cxx_juce::audio_format_writer"]
+        #[rust_name = "get_writer_bits_per_sample"]
+        pub fn getBitsPerSample(writer: &AudioFormatWriter) -> u32;
+    }
+
+    #[cfg(feature = "juce_audio_processors")]
+    unsafe extern "C++" {
+        #[namespace = "juce"]
+        pub type AudioPluginFormatManager;
+
+        #[namespace = "cxx_juce::audio_plugin_format_manager"]
+        #[rust_name = "create_audio_plugin_format_manager"]
+        pub fn createAudioPluginFormatManager() -> UniquePtr<AudioPluginFormatManager>;
+
+        #[rust_name = "add_default_formats"]
+        pub fn addDefaultFormats(self: Pin<&mut AudioPluginFormatManager>);
+
+        #[rust_name = "get_num_formats"]
+        pub fn getNumFormats(self: &AudioPluginFormatManager) -> i32;
+
+        #[namespace = "cxx_juce::audio_plugin_format_manager"]
+        #[rust_name = "get_plugin_format_names"]
+        pub fn getFormatNames(manager: &AudioPluginFormatManager) -> Vec<String>;
+
+        #[namespace = "cxx_juce::audio_plugin_format_manager"]
+        #[rust_name = "scan_path_for_plugins"]
+        pub fn scanPath(
+            manager: Pin<&mut AudioPluginFormatManager>,
+            path: &str,
+            recursive: bool,
+        ) -> Vec<PluginDescription>;
+
+        #[namespace = "cxx_juce::audio_plugin_format_manager"]
+        #[rust_name = "scan_default_plugin_locations"]
+        pub fn scanDefaultLocations(
+            manager: Pin<&mut AudioPluginFormatManager>,
+        ) -> Vec<PluginDescription>;
+
+        #[namespace = "cxx_juce::audio_plugin_format_manager"]
+        #[rust_name = "create_plugin_instance"]
+        pub fn createPluginInstance(
+            manager: Pin<&mut AudioPluginFormatManager>,
+            description: &PluginDescription,
+            sample_rate: f64,
+            block_size: usize,
+        ) -> Result<UniquePtr<AudioPluginInstance>>;
+
+        #[namespace = "juce"]
+        pub type AudioPluginInstance;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_plugin_description"]
+        pub fn getPluginDescription(instance: &AudioPluginInstance) -> PluginDescription;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_plugin_name"]
+        pub fn getName(instance: &AudioPluginInstance) -> String;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "prepare_plugin_instance"]
+        pub fn prepare(
+            instance: Pin<&mut AudioPluginInstance>,
+            sample_rate: f64,
+            block_size: usize,
+        );
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "process_plugin_instance"]
+        pub fn process(
+            instance: Pin<&mut AudioPluginInstance>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            midi: Pin<&mut MidiBuffer>,
+        ) -> Result<()>;
+
+        #[rust_name = "release_resources"]
+        pub fn releaseResources(self: Pin<&mut AudioPluginInstance>);
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_num_parameters"]
+        pub fn getNumParameters(instance: &AudioPluginInstance) -> usize;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_parameter_infos"]
+        pub fn getParameterInfos(instance: &AudioPluginInstance) -> Vec<ParameterInfo>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_parameter_groups"]
+        pub fn getParameterGroups(instance: &AudioPluginInstance) -> Vec<ParameterGroupEntry>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_parameter_value"]
+        pub unsafe fn getParameterValue(
+            instance: *const AudioPluginInstance,
+            index: usize,
+        ) -> Result<f32>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "set_parameter_value"]
+        pub unsafe fn setParameterValue(
+            instance: *const AudioPluginInstance,
+            index: usize,
+            value: f32,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "begin_parameter_change_gesture"]
+        pub unsafe fn beginParameterChangeGesture(
+            instance: *const AudioPluginInstance,
+            index: usize,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "end_parameter_change_gesture"]
+        pub unsafe fn endParameterChangeGesture(
+            instance: *const AudioPluginInstance,
+            index: usize,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_parameter_text"]
+        pub unsafe fn getParameterText(
+            instance: *const AudioPluginInstance,
+            index: usize,
+            value: f32,
+        ) -> Result<String>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_parameter_value_for_text"]
+        pub unsafe fn getParameterValueForText(
+            instance: *const AudioPluginInstance,
+            index: usize,
+            text: &str,
+        ) -> Result<f32>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_plugin_state_information"]
+        pub fn getStateInformation(instance: Pin<&mut AudioPluginInstance>) -> Vec<u8>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "set_plugin_state_information"]
+        pub fn setStateInformation(instance: Pin<&mut AudioPluginInstance>, data: &[u8]);
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_plugin_current_program_state_information"]
+        pub fn getCurrentProgramStateInformation(
+            instance: Pin<&mut AudioPluginInstance>,
+        ) -> Vec<u8>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "set_plugin_current_program_state_information"]
+        pub fn setCurrentProgramStateInformation(
+            instance: Pin<&mut AudioPluginInstance>,
+            data: &[u8],
+        );
+
+        #[rust_name = "get_num_programs"]
+        pub fn getNumPrograms(self: Pin<&mut AudioPluginInstance>) -> i32;
+
+        #[rust_name = "get_current_program"]
+        pub fn getCurrentProgram(self: Pin<&mut AudioPluginInstance>) -> i32;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "set_plugin_current_program"]
+        pub fn setCurrentProgram(
+            instance: Pin<&mut AudioPluginInstance>,
+            index: usize,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_plugin_program_name"]
+        pub fn getProgramName(
+            instance: Pin<&mut AudioPluginInstance>,
+            index: usize,
+        ) -> Result<String>;
+
+        pub type AudioProcessorListenerHandle;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "add_plugin_instance_listener"]
+        pub fn addListener(
+            instance: Pin<&mut AudioPluginInstance>,
+            listener: Box<BoxedAudioProcessorListener>,
+        ) -> UniquePtr<AudioProcessorListenerHandle>;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut AudioPluginInstance>);
+
+        #[rust_name = "get_total_num_input_channels"]
+        pub fn getTotalNumInputChannels(self: &AudioPluginInstance) -> i32;
+
+        #[rust_name = "get_total_num_output_channels"]
+        pub fn getTotalNumOutputChannels(self: &AudioPluginInstance) -> i32;
+
+        #[rust_name = "get_sample_rate"]
+        pub fn getSampleRate(self: &AudioPluginInstance) -> f64;
+
+        #[rust_name = "get_block_size"]
+        pub fn getBlockSize(self: &AudioPluginInstance) -> i32;
+
+        #[rust_name = "get_tail_length_seconds"]
+        pub fn getTailLengthSeconds(self: &AudioPluginInstance) -> f64;
+
+        #[rust_name = "accepts_midi"]
+        pub fn acceptsMidi(self: &AudioPluginInstance) -> bool;
+
+        #[rust_name = "produces_midi"]
+        pub fn producesMidi(self: &AudioPluginInstance) -> bool;
+
+        #[rust_name = "is_midi_effect"]
+        pub fn isMidiEffect(self: &AudioPluginInstance) -> bool;
+
+        #[namespace = "juce"]
+        pub type KnownPluginList;
+
+        #[namespace = "cxx_juce::known_plugin_list"]
+        #[rust_name = "create_known_plugin_list"]
+        pub fn createKnownPluginList() -> UniquePtr<KnownPluginList>;
+
+        #[rust_name = "clear"]
+        pub fn clear(self: Pin<&mut KnownPluginList>);
+
+        #[rust_name = "get_num_types"]
+        pub fn getNumTypes(self: &KnownPluginList) -> i32;
+
+        #[namespace = "cxx_juce::known_plugin_list"]
+        #[rust_name = "get_known_plugin_types"]
+        pub fn getTypes(list: &KnownPluginList) -> Vec<PluginDescription>;
+
+        #[namespace = "cxx_juce::known_plugin_list"]
+        #[rust_name = "add_known_plugin_type"]
+        pub fn addType(list: Pin<&mut KnownPluginList>, description: &PluginDescription) -> bool;
+
+        #[namespace = "cxx_juce::known_plugin_list"]
+        #[rust_name = "remove_known_plugin_type"]
+        pub fn removeType(list: Pin<&mut KnownPluginList>, description: &PluginDescription);
+
+        #[namespace = "cxx_juce::known_plugin_list"]
+        #[rust_name = "get_known_plugin_type_for_identifier_string"]
+        pub fn getTypeForIdentifierString(
+            list: &KnownPluginList,
+            identifier: &str,
+            description: &mut PluginDescription,
+        ) -> bool;
+
+        #[namespace = "cxx_juce::known_plugin_list"]
+        #[rust_name = "add_to_blacklist"]
+        pub fn addToBlacklist(list: Pin<&mut KnownPluginList>, file_or_identifier: &str);
+
+        #[namespace = "cxx_juce::known_plugin_list"]
+        #[rust_name = "remove_from_blacklist"]
+        pub fn removeFromBlacklist(list: Pin<&mut KnownPluginList>, file_or_identifier: &str);
+
+        #[rust_name = "clear_blacklisted_files"]
+        pub fn clearBlacklistedFiles(self: Pin<&mut KnownPluginList>);
+
+        #[namespace = "cxx_juce::known_plugin_list"]
+        #[rust_name = "get_blacklisted_files"]
+        pub fn getBlacklistedFiles(list: &KnownPluginList) -> Vec<String>;
+
+        #[namespace = "cxx_juce::known_plugin_list"]
+        #[rust_name = "known_plugin_list_to_xml"]
+        pub fn toXml(list: &KnownPluginList) -> String;
+
+        #[namespace = "cxx_juce::known_plugin_list"]
+        #[rust_name = "known_plugin_list_from_xml"]
+        pub fn fromXml(list: Pin<&mut KnownPluginList>, xml: &str) -> Result<()>;
+
+        #[namespace = "juce"]
+        pub type PluginDirectoryScanner;
+
+        #[namespace = "cxx_juce::plugin_directory_scanner"]
+        #[rust_name = "create_plugin_directory_scanner"]
+        pub fn createPluginDirectoryScanner(
+            list: Pin<&mut KnownPluginList>,
+            manager: &AudioPluginFormatManager,
+            format_name: &str,
+            paths: &[String],
+            recursive: bool,
+            dead_mans_pedal_file: &str,
+        ) -> Result<UniquePtr<PluginDirectoryScanner>>;
+
+        #[namespace = "cxx_juce::plugin_directory_scanner"]
+        #[rust_name = "scan_next_plugin_file"]
+        pub fn scanNextFile(
+            scanner: Pin<&mut PluginDirectoryScanner>,
+            dont_rescan_if_already_in_list: bool,
+            name_of_plugin_being_scanned: &mut String,
+        ) -> bool;
+
+        #[namespace = "cxx_juce::plugin_directory_scanner"]
+        #[rust_name = "get_next_plugin_file_that_will_be_scanned"]
+        pub fn getNextPluginFileThatWillBeScanned(scanner: &PluginDirectoryScanner) -> String;
+
+        #[rust_name = "get_progress"]
+        pub fn getProgress(self: &PluginDirectoryScanner) -> f32;
+
+        #[namespace = "cxx_juce::plugin_directory_scanner"]
+        #[rust_name = "get_failed_plugin_files"]
+        pub fn getFailedFiles(scanner: &PluginDirectoryScanner) -> Vec<String>;
+
+        #[namespace = "cxx_juce::out_of_process_scanner"]
+        #[rust_name = "set_child_process_plugin_scanner"]
+        pub fn setChildProcessScanner(
+            list: Pin<&mut KnownPluginList>,
+            command: &[String],
+            timeout_ms: i32,
+        );
+
+        #[namespace = "cxx_juce::out_of_process_scanner"]
+        #[rust_name = "clear_custom_plugin_scanner"]
+        pub fn clearCustomScanner(list: Pin<&mut KnownPluginList>);
+
+        #[namespace = "cxx_juce::out_of_process_scanner"]
+        #[rust_name = "run_child_plugin_scan"]
+        pub fn runChildScan(manager: &AudioPluginFormatManager, args: &[String]) -> Result<()>;
+
+        #[namespace = "juce"]
+        pub type AudioProcessorGraph;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "create_audio_processor_graph"]
+        pub fn createAudioProcessorGraph() -> UniquePtr<AudioProcessorGraph>;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "add_plugin_node"]
+        pub fn addPlugin(
+            graph: Pin<&mut AudioProcessorGraph>,
+            plugin: UniquePtr<AudioPluginInstance>,
+        ) -> NodeId;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "add_rust_processor_node"]
+        pub fn addRustProcessor(
+            graph: Pin<&mut AudioProcessorGraph>,
+            processor: UniquePtr<RustAudioProcessor>,
+        ) -> NodeId;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "add_io_processor_node"]
+        pub fn addIOProcessor(graph: Pin<&mut AudioProcessorGraph>, io_type: i32) -> NodeId;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "remove_node"]
+        pub fn removeNode(graph: Pin<&mut AudioProcessorGraph>, node_id: NodeId) -> bool;
+
+        #[rust_name = "get_num_nodes"]
+        pub fn getNumNodes(self: &AudioProcessorGraph) -> i32;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "get_node_ids"]
+        pub fn getNodeIds(graph: &AudioProcessorGraph) -> Vec<NodeId>;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "get_node_name"]
+        pub fn getNodeName(graph: &AudioProcessorGraph, node_id: NodeId, name: &mut String)
+            -> bool;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "set_node_bypassed"]
+        pub fn setNodeBypassed(
+            graph: Pin<&mut AudioProcessorGraph>,
+            node_id: NodeId,
+            bypassed: bool,
+        ) -> bool;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "is_node_bypassed"]
+        pub fn isNodeBypassed(graph: &AudioProcessorGraph, node_id: NodeId) -> bool;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "add_connection"]
+        pub fn addConnection(graph: Pin<&mut AudioProcessorGraph>, connection: &Connection)
+            -> bool;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "remove_connection"]
+        pub fn removeConnection(
+            graph: Pin<&mut AudioProcessorGraph>,
+            connection: &Connection,
+        ) -> bool;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "is_connected"]
+        pub fn isConnected(graph: &AudioProcessorGraph, connection: &Connection) -> bool;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "can_connect"]
+        pub fn canConnect(graph: &AudioProcessorGraph, connection: &Connection) -> bool;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "get_connections"]
+        pub fn getConnections(graph: &AudioProcessorGraph) -> Vec<Connection>;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "disconnect_node"]
+        pub fn disconnectNode(graph: Pin<&mut AudioProcessorGraph>, node_id: NodeId) -> bool;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "clear_graph"]
+        pub fn clear(graph: Pin<&mut AudioProcessorGraph>);
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "prepare_graph"]
+        pub fn prepare(
+            graph: Pin<&mut AudioProcessorGraph>,
+            num_input_channels: usize,
+            num_output_channels: usize,
+            sample_rate: f64,
+            block_size: usize,
+        );
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "process_graph"]
+        pub fn process(
+            graph: Pin<&mut AudioProcessorGraph>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            midi: Pin<&mut MidiBuffer>,
+        ) -> Result<()>;
+
+        #[rust_name = "release_resources"]
+        pub fn releaseResources(self: Pin<&mut AudioProcessorGraph>);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut AudioProcessorGraph>);
+
+        #[rust_name = "get_total_num_input_channels"]
+        pub fn getTotalNumInputChannels(self: &AudioProcessorGraph) -> i32;
+
+        #[rust_name = "get_total_num_output_channels"]
+        pub fn getTotalNumOutputChannels(self: &AudioProcessorGraph) -> i32;
+
+        #[rust_name = "has_editor"]
+        pub fn hasEditor(self: &AudioPluginInstance) -> bool;
+
+        pub type PluginEditorWindow;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "create_plugin_instance_editor_window"]
+        pub fn createEditorWindow(
+            instance: &AudioPluginInstance,
+            title: &str,
+            listener: Box<BoxedPluginEditorWindowListener>,
+        ) -> Result<UniquePtr<PluginEditorWindow>>;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "create_node_editor_window"]
+        pub fn createEditorWindow(
+            graph: &AudioProcessorGraph,
+            node_id: NodeId,
+            title: &str,
+            listener: Box<BoxedPluginEditorWindowListener>,
+        ) -> Result<UniquePtr<PluginEditorWindow>>;
+
+        #[rust_name = "set_visible"]
+        pub fn setVisible(self: Pin<&mut PluginEditorWindow>, should_be_visible: bool);
+
+        #[rust_name = "is_visible"]
+        pub fn isVisible(self: &PluginEditorWindow) -> bool;
+
+        #[rust_name = "set_top_left_position"]
+        pub fn setTopLeftPosition(self: Pin<&mut PluginEditorWindow>, x: i32, y: i32);
+
+        #[rust_name = "get_x"]
+        pub fn getX(self: &PluginEditorWindow) -> i32;
+
+        #[rust_name = "get_y"]
+        pub fn getY(self: &PluginEditorWindow) -> i32;
+
+        #[rust_name = "get_width"]
+        pub fn getWidth(self: &PluginEditorWindow) -> i32;
+
+        #[rust_name = "get_height"]
+        pub fn getHeight(self: &PluginEditorWindow) -> i32;
+
+        #[rust_name = "to_front"]
+        pub fn toFront(self: Pin<&mut PluginEditorWindow>, should_grab_keyboard_focus: bool);
+
+        #[rust_name = "get_latency_samples"]
+        pub fn getLatencySamples(self: &AudioPluginInstance) -> i32;
+
+        #[rust_name = "get_latency_samples"]
+        pub fn getLatencySamples(self: &AudioProcessorGraph) -> i32;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "get_node_latency_samples"]
+        pub fn getNodeLatencySamples(graph: &AudioProcessorGraph, node_id: NodeId) -> i32;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "get_node_state_information"]
+        pub fn getNodeStateInformation(
+            graph: Pin<&mut AudioProcessorGraph>,
+            node_id: NodeId,
+        ) -> Result<Vec<u8>>;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "set_node_state_information"]
+        pub fn setNodeStateInformation(
+            graph: Pin<&mut AudioProcessorGraph>,
+            node_id: NodeId,
+            data: &[u8],
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "get_node_current_program_state_information"]
+        pub fn getNodeCurrentProgramStateInformation(
+            graph: Pin<&mut AudioProcessorGraph>,
+            node_id: NodeId,
+        ) -> Result<Vec<u8>>;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "set_node_current_program_state_information"]
+        pub fn setNodeCurrentProgramStateInformation(
+            graph: Pin<&mut AudioProcessorGraph>,
+            node_id: NodeId,
+            data: &[u8],
+        ) -> Result<()>;
+
+        pub fn rebuild(self: Pin<&mut AudioProcessorGraph>);
+
+        pub type AudioPlayHeadHandle;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "set_plugin_instance_play_head"]
+        pub fn setPlayHead(
+            instance: Pin<&mut AudioPluginInstance>,
+            play_head: Box<BoxedAudioPlayHead>,
+        ) -> UniquePtr<AudioPlayHeadHandle>;
+
+        #[namespace = "cxx_juce::audio_processor_graph"]
+        #[rust_name = "set_graph_play_head"]
+        pub fn setPlayHead(
+            graph: Pin<&mut AudioProcessorGraph>,
+            play_head: Box<BoxedAudioPlayHead>,
+        ) -> UniquePtr<AudioPlayHeadHandle>;
+
+        #[namespace = "cxx_juce::audio_channel_set"]
+        #[rust_name = "canonical_channel_set"]
+        pub fn canonical(num_channels: usize) -> AudioChannelSet;
+
+        #[namespace = "cxx_juce::audio_channel_set"]
+        #[rust_name = "discrete_channel_set"]
+        pub fn discrete(num_channels: usize) -> AudioChannelSet;
+
+        #[namespace = "cxx_juce::audio_channel_set"]
+        #[rust_name = "get_channel_set_description"]
+        pub fn getDescription(channel_set: &AudioChannelSet) -> String;
+
+        #[namespace = "cxx_juce::audio_channel_set"]
+        #[rust_name = "get_speaker_arrangement"]
+        pub fn getSpeakerArrangement(channel_set: &AudioChannelSet) -> String;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_buses_layout"]
+        pub fn getBusesLayout(instance: &AudioPluginInstance) -> BusesLayout;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "check_buses_layout_supported"]
+        pub fn checkBusesLayoutSupported(
+            instance: &AudioPluginInstance,
+            layout: &BusesLayout,
+        ) -> bool;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "set_buses_layout"]
+        pub fn setBusesLayout(
+            instance: Pin<&mut AudioPluginInstance>,
+            layout: &BusesLayout,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "get_buses"]
+        pub fn getBuses(instance: &AudioPluginInstance, is_input: bool) -> Vec<BusInfo>;
+
+        #[namespace = "cxx_juce::plugin_instance"]
+        #[rust_name = "set_bus_enabled"]
+        pub fn setBusEnabled(
+            instance: Pin<&mut AudioPluginInstance>,
+            is_input: bool,
+            index: usize,
+            enabled: bool,
+        ) -> Result<()>;
+
+        pub type RustAudioProcessor;
+
+        #[namespace = "cxx_juce::rust_audio_processor"]
+        #[rust_name = "create_rust_audio_processor"]
+        pub fn createRustAudioProcessor(
+            processor: Box<BoxedAudioProcessor>,
+        ) -> UniquePtr<RustAudioProcessor>;
+
+        #[namespace = "cxx_juce::rust_audio_processor"]
+        #[rust_name = "add_rust_audio_parameter"]
+        pub fn addParameter(
+            processor: Pin<&mut RustAudioProcessor>,
+            parameter: Box<AudioParameter>,
+        );
+
+        #[rust_name = "swap_with"]
+        pub fn swapWith(self: Pin<&mut MidiBuffer>, other: Pin<&mut MidiBuffer>);
+
+        #[namespace = "cxx_juce::parameter_store"]
+        #[rust_name = "create_parameter_state"]
+        pub fn createState(state_type: &str, ids: &[String], values: &[f32]) -> String;
+
+        #[namespace = "cxx_juce::parameter_store"]
+        #[rust_name = "parse_parameter_state"]
+        pub fn parseState(
+            xml: &str,
+            state_type: &str,
+            ids: &mut Vec<String>,
+            values: &mut Vec<f32>,
+        ) -> Result<()>;
+
+        pub type RustAudioProcessorParameter;
+
+        #[rust_name = "begin_change_gesture"]
+        pub fn beginChangeGesture(self: Pin<&mut RustAudioProcessorParameter>);
+
+        #[rust_name = "end_change_gesture"]
+        pub fn endChangeGesture(self: Pin<&mut RustAudioProcessorParameter>);
+
+        #[rust_name = "set_value_notifying_host"]
+        pub fn setValueNotifyingHost(self: Pin<&mut RustAudioProcessorParameter>, value: f32);
+
+        #[rust_name = "set_non_realtime"]
+        pub fn setNonRealtime(self: Pin<&mut AudioPluginInstance>, is_non_realtime: bool);
+
+        #[rust_name = "is_non_realtime"]
+        pub fn isNonRealtime(self: &AudioPluginInstance) -> bool;
+
+        #[rust_name = "set_non_realtime"]
+        pub fn setNonRealtime(self: Pin<&mut AudioProcessorGraph>, is_non_realtime: bool);
+
+        #[rust_name = "is_non_realtime"]
+        pub fn isNonRealtime(self: &AudioProcessorGraph) -> bool;
+
+        #[rust_name = "get_tail_length_seconds"]
+        pub fn getTailLengthSeconds(self: &AudioProcessorGraph) -> f64;
+
+        pub type OutOfProcessPlugin;
+
+        #[namespace = "cxx_juce::out_of_process_plugin"]
+        #[rust_name = "create_out_of_process_plugin"]
+        pub fn createOutOfProcessPlugin(
+            command: &[String],
+            description: &PluginDescription,
+            sample_rate: f64,
+            block_size: usize,
+            timeout_ms: i32,
+        ) -> Result<UniquePtr<OutOfProcessPlugin>>;
+
+        #[namespace = "cxx_juce::out_of_process_plugin"]
+        #[rust_name = "run_child_plugin_host"]
+        pub fn runChild(manager: Pin<&mut AudioPluginFormatManager>, args: &[String])
+            -> Result<()>;
+
+        #[rust_name = "get_name"]
+        pub fn getName(self: &OutOfProcessPlugin) -> String;
+
+        #[rust_name = "get_num_input_channels"]
+        pub fn getNumInputChannels(self: &OutOfProcessPlugin) -> usize;
+
+        #[rust_name = "get_num_output_channels"]
+        pub fn getNumOutputChannels(self: &OutOfProcessPlugin) -> usize;
+
+        #[rust_name = "get_maximum_block_size"]
+        pub fn getMaximumBlockSize(self: &OutOfProcessPlugin) -> usize;
+
+        #[rust_name = "has_crashed"]
+        pub fn hasCrashed(self: &OutOfProcessPlugin) -> bool;
+
+        pub fn prepare(
+            self: Pin<&mut OutOfProcessPlugin>,
+            sample_rate: f64,
+            block_size: usize,
+        ) -> Result<()>;
+
+        pub fn process(
+            self: Pin<&mut OutOfProcessPlugin>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+        ) -> Result<()>;
+
+        #[rust_name = "release_resources"]
+        pub fn releaseResources(self: Pin<&mut OutOfProcessPlugin>) -> Result<()>;
+    }
+
+    #[cfg(feature = "juce_audio_utils")]
+    unsafe extern "C++" {
+        #[rust_name = "add_audio_processor_player"]
+        pub fn addAudioProcessorPlayer<'a>(
+            self: Pin<&mut AudioDeviceManager>,
+            player: Pin<&mut AudioProcessorPlayer>,
+        ) -> UniquePtr<AudioProcessorPlayerHandle<'a>>;
+
+        pub type AudioProcessorPlayerHandle<'a>;
+
+        pub type AudioFilePlayer;
+
+        #[namespace = "cxx_juce::audio_file_player"]
+        #[rust_name = "create_audio_file_player"]
+        pub fn createAudioFilePlayer(
+            buffering_thread: SharedPtr<TimeSliceThread>,
+        ) -> UniquePtr<AudioFilePlayer>;
+
+        #[rust_name = "set_reader"]
+        pub fn setReader(self: Pin<&mut AudioFilePlayer>, reader: UniquePtr<AudioFormatReader>);
+
+        #[rust_name = "start"]
+        pub fn start(self: Pin<&mut AudioFilePlayer>);
+
+        #[rust_name = "stop"]
+        pub fn stop(self: Pin<&mut AudioFilePlayer>);
+
+        #[rust_name = "is_playing"]
+        pub fn isPlaying(self: &AudioFilePlayer) -> bool;
+
+        #[rust_name = "has_stream_finished"]
+        pub fn hasStreamFinished(self: &AudioFilePlayer) -> bool;
+
+        #[rust_name = "set_position"]
+        pub fn setPosition(self: Pin<&mut AudioFilePlayer>, seconds: f64);
+
+        #[rust_name = "get_current_position"]
+        pub fn getCurrentPosition(self: &AudioFilePlayer) -> f64;
+
+        #[rust_name = "get_length_in_seconds"]
+        pub fn getLengthInSeconds(self: &AudioFilePlayer) -> f64;
+
+        #[rust_name = "set_gain"]
+        pub fn setGain(self: Pin<&mut AudioFilePlayer>, gain: f32);
+
+        #[rust_name = "get_gain"]
+        pub fn getGain(self: &AudioFilePlayer) -> f32;
+
+        #[rust_name = "set_looping"]
+        pub fn setLooping(self: Pin<&mut AudioFilePlayer>, should_loop: bool);
+
+        #[rust_name = "prepare_to_play"]
+        pub fn prepareToPlay(
+            self: Pin<&mut AudioFilePlayer>,
+            samples_per_block_expected: i32,
+            sample_rate: f64,
+        );
+
+        #[rust_name = "release_resources"]
+        pub fn releaseResources(self: Pin<&mut AudioFilePlayer>);
+
+        #[rust_name = "get_next_audio_block"]
+        pub fn getNextAudioBlock(
+            self: Pin<&mut AudioFilePlayer>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+        );
+
+        #[namespace = "juce"]
+        pub type AudioThumbnailCache;
+
+        #[namespace = "cxx_juce::audio_thumbnail_cache"]
+        #[rust_name = "create_audio_thumbnail_cache"]
+        pub fn createAudioThumbnailCache(
+            max_num_thumbs_to_store: i32,
+        ) -> SharedPtr<AudioThumbnailCache>;
+
+        #[namespace = "cxx_juce::audio_thumbnail_cache"]
+        #[rust_name = "clear_audio_thumbnail_cache"]
+        pub fn clear(cache: &SharedPtr<AudioThumbnailCache>);
+
+        pub type AudioThumbnail;
+
+        #[namespace = "cxx_juce::audio_thumbnail"]
+        #[rust_name = "create_audio_thumbnail"]
+        pub fn createAudioThumbnail(
+            source_samples_per_thumbnail_sample: i32,
+            cache: SharedPtr<AudioThumbnailCache>,
+        ) -> UniquePtr<AudioThumbnail>;
+
+        #[rust_name = "set_source_file"]
+        pub fn setSourceFile(self: Pin<&mut AudioThumbnail>, path: &str) -> bool;
+
+        #[rust_name = "set_reader"]
+        pub fn setReader(
+            self: Pin<&mut AudioThumbnail>,
+            reader: UniquePtr<AudioFormatReader>,
+            hash: i64,
+        );
+
+        #[rust_name = "clear"]
+        pub fn clear(self: Pin<&mut AudioThumbnail>);
+
+        #[rust_name = "reset"]
+        pub fn reset(
+            self: Pin<&mut AudioThumbnail>,
+            num_channels: i32,
+            sample_rate: f64,
+            total_samples: i64,
+        );
+
+        #[rust_name = "add_block"]
+        pub fn addBlock(
+            self: Pin<&mut AudioThumbnail>,
+            sample_number_in_source: i64,
+            buffer: &AudioSampleBuffer,
+        ) -> Result<()>;
+
+        #[rust_name = "get_num_channels"]
+        pub fn getNumChannels(self: &AudioThumbnail) -> i32;
+
+        #[rust_name = "get_total_length"]
+        pub fn getTotalLength(self: &AudioThumbnail) -> f64;
+
+        #[rust_name = "is_fully_loaded"]
+        pub fn isFullyLoaded(self: &AudioThumbnail) -> bool;
+
+        #[rust_name = "get_proportion_complete"]
+        pub fn getProportionComplete(self: &AudioThumbnail) -> f64;
+
+        #[rust_name = "get_num_samples_finished"]
+        pub fn getNumSamplesFinished(self: &AudioThumbnail) -> i64;
+
+        #[rust_name = "get_approximate_peak"]
+        pub fn getApproximatePeak(self: &AudioThumbnail) -> f32;
+
+        #[rust_name = "get_hash_code"]
+        pub fn getHashCode(self: &AudioThumbnail) -> i64;
+
+        #[rust_name = "get_peaks"]
+        pub fn getPeaks(
+            self: &AudioThumbnail,
+            channel_index: i32,
+            start_time: f64,
+            end_time: f64,
+            min_values: &mut [f32],
+            max_values: &mut [f32],
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::bluetooth_midi_device_pairing_dialogue"]
+        #[rust_name = "is_bluetooth_midi_available"]
+        pub fn isAvailable() -> bool;
+
+        #[namespace = "cxx_juce::bluetooth_midi_device_pairing_dialogue"]
+        #[rust_name = "open_bluetooth_midi_device_pairing_dialogue"]
+        pub fn open(callback: Box<BoxedPairingDialogueCallback>) -> Result<bool>;
+
+        #[namespace = "juce"]
+        pub type AudioProcessorPlayer;
+
+        #[namespace = "cxx_juce::audio_processor_player"]
+        #[rust_name = "create_audio_processor_player"]
+        pub fn createAudioProcessorPlayer() -> UniquePtr<AudioProcessorPlayer>;
+
+        #[namespace = "cxx_juce::audio_processor_player"]
+        #[rust_name = "set_graph"]
+        pub fn setGraph(
+            player: Pin<&mut AudioProcessorPlayer>,
+            graph: Pin<&mut AudioProcessorGraph>,
+        );
+
+        #[namespace = "cxx_juce::audio_processor_player"]
+        #[rust_name = "set_plugin_instance"]
+        pub fn setPluginInstance(
+            player: Pin<&mut AudioProcessorPlayer>,
+            instance: Pin<&mut AudioPluginInstance>,
+        );
+
+        #[namespace = "juce"]
+        pub type ScopedLock;
+
+        #[namespace = "cxx_juce::audio_processor_player"]
+        #[rust_name = "lock_audio_processor_player"]
+        pub fn lockProcessor(player: &AudioProcessorPlayer) -> UniquePtr<ScopedLock>;
+    }
+
+    #[cfg(feature = "juce_data_structures")]
+    unsafe extern "C++" {
+        #[namespace = "cxx_juce"]
+        pub type PropertiesFileOptions;
+
+        #[namespace = "juce"]
+        pub type PropertiesFile;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "create_properties_file_options"]
+        #[allow(clippy::too_many_arguments)]
+        pub fn createOptions(
+            application_name: &str,
+            filename_suffix: &str,
+            folder_name: &str,
+            osx_library_sub_folder: &str,
+            common_to_all_users: bool,
+            ignore_case_of_key_names: bool,
+            do_not_save: bool,
+            milliseconds_before_saving: i32,
+            storage_format: i32,
+        ) -> UniquePtr<PropertiesFileOptions>;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "get_default_properties_file"]
+        pub fn getDefaultFile(options: &PropertiesFileOptions) -> String;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "create_properties_file"]
+        pub fn createPropertiesFile(
+            path: &str,
+            options: &PropertiesFileOptions,
+        ) -> UniquePtr<PropertiesFile>;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "get_properties_file"]
+        pub fn getFile(file: &PropertiesFile) -> String;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "properties_contain_key"]
+        pub fn containsKey(file: &PropertiesFile, key: &str) -> bool;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "get_property_value"]
+        pub fn getValue(file: &PropertiesFile, key: &str, value: &mut String) -> bool;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "get_property_xml_value"]
+        pub fn getXmlValue(file: &PropertiesFile, key: &str) -> String;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "get_all_property_keys"]
+        pub fn getAllKeys(file: &PropertiesFile) -> Vec<String>;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "set_property_value"]
+        pub fn setValue(file: Pin<&mut PropertiesFile>, key: &str, value: &str);
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "set_property_xml_value"]
+        pub fn setXmlValue(file: Pin<&mut PropertiesFile>, key: &str, xml: &str) -> Result<()>;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "remove_property_value"]
+        pub fn removeValue(file: Pin<&mut PropertiesFile>, key: &str);
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "set_fallback_property_set"]
+        pub fn setFallbackPropertySet(
+            file: Pin<&mut PropertiesFile>,
+            fallback: Pin<&mut PropertiesFile>,
+        );
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "save_properties_file"]
+        pub fn save(file: Pin<&mut PropertiesFile>) -> Result<()>;
+
+        #[namespace = "cxx_juce::properties_file"]
+        #[rust_name = "save_properties_file_if_needed"]
+        pub fn saveIfNeeded(file: Pin<&mut PropertiesFile>) -> Result<()>;
+
+        #[rust_name = "needs_to_be_saved"]
+        pub fn needsToBeSaved(self: &PropertiesFile) -> bool;
+
+        #[rust_name = "is_valid_file"]
+        pub fn isValidFile(self: &PropertiesFile) -> bool;
+
+        #[namespace = "juce"]
+        pub type UndoManager;
+
+        #[namespace = "cxx_juce::undo_manager"]
+        #[rust_name = "create_undo_manager"]
+        pub fn createUndoManager() -> UniquePtr<UndoManager>;
+
+        #[namespace = "cxx_juce::undo_manager"]
+        #[rust_name = "begin_new_transaction"]
+        pub fn beginNewTransaction(undo_manager: Pin<&mut UndoManager>, name: &str);
+
+        #[namespace = "cxx_juce::undo_manager"]
+        #[rust_name = "get_undo_description"]
+        pub fn getUndoDescription(undo_manager: &UndoManager) -> String;
+
+        #[namespace = "cxx_juce::undo_manager"]
+        #[rust_name = "get_redo_description"]
+        pub fn getRedoDescription(undo_manager: &UndoManager) -> String;
+
+        pub fn undo(self: Pin<&mut UndoManager>) -> bool;
+
+        pub fn redo(self: Pin<&mut UndoManager>) -> bool;
+
+        #[rust_name = "can_undo"]
+        pub fn canUndo(self: &UndoManager) -> bool;
+
+        #[rust_name = "can_redo"]
+        pub fn canRedo(self: &UndoManager) -> bool;
+
+        #[rust_name = "clear_undo_history"]
+        pub fn clearUndoHistory(self: Pin<&mut UndoManager>);
+
+        #[namespace = "juce"]
+        pub type ValueTree;
+
+        pub type ValueTreeListenerHandle;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "create_value_tree"]
+        pub fn createValueTree(type_name: &str) -> Result<UniquePtr<ValueTree>>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "copy_value_tree"]
+        pub fn copy(tree: &ValueTree) -> UniquePtr<ValueTree>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "create_value_tree_copy"]
+        pub fn createCopy(tree: &ValueTree) -> UniquePtr<ValueTree>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "value_trees_are_equal"]
+        pub fn equals(tree: &ValueTree, other: &ValueTree) -> bool;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "get_value_tree_type"]
+        pub fn getType(tree: &ValueTree) -> String;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "get_value_tree_property_name"]
+        pub fn getPropertyName(tree: &ValueTree, index: i32) -> String;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "value_tree_has_property"]
+        pub fn hasProperty(tree: &ValueTree, name: &str) -> bool;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "get_value_tree_property"]
+        pub fn getProperty(tree: &ValueTree, name: &str) -> UniquePtr<Var>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "set_value_tree_property"]
+        pub unsafe fn setProperty(
+            tree: Pin<&mut ValueTree>,
+            name: &str,
+            value: &Var,
+            undo_manager: *mut UndoManager,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "remove_value_tree_property"]
+        pub unsafe fn removeProperty(
+            tree: Pin<&mut ValueTree>,
+            name: &str,
+            undo_manager: *mut UndoManager,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "remove_all_value_tree_properties"]
+        pub unsafe fn removeAllProperties(
+            tree: Pin<&mut ValueTree>,
+            undo_manager: *mut UndoManager,
+        );
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "copy_value_tree_properties_from"]
+        pub unsafe fn copyPropertiesFrom(
+            tree: Pin<&mut ValueTree>,
+            source: &ValueTree,
+            undo_manager: *mut UndoManager,
+        );
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "get_value_tree_child"]
+        pub fn getChild(tree: &ValueTree, index: i32) -> UniquePtr<ValueTree>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "get_value_tree_child_with_name"]
+        pub fn getChildWithName(tree: &ValueTree, type_name: &str) -> Result<UniquePtr<ValueTree>>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "get_value_tree_child_with_property"]
+        pub fn getChildWithProperty(
+            tree: &ValueTree,
+            name: &str,
+            value: &Var,
+        ) -> Result<UniquePtr<ValueTree>>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "get_or_create_value_tree_child_with_name"]
+        pub unsafe fn getOrCreateChildWithName(
+            tree: Pin<&mut ValueTree>,
+            type_name: &str,
+            undo_manager: *mut UndoManager,
+        ) -> Result<UniquePtr<ValueTree>>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "add_value_tree_child"]
+        pub unsafe fn addChild(
+            tree: Pin<&mut ValueTree>,
+            child: &ValueTree,
+            index: i32,
+            undo_manager: *mut UndoManager,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "remove_value_tree_child"]
+        pub unsafe fn removeChild(
+            tree: Pin<&mut ValueTree>,
+            index: i32,
+            undo_manager: *mut UndoManager,
+        );
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "remove_all_value_tree_children"]
+        pub unsafe fn removeAllChildren(tree: Pin<&mut ValueTree>, undo_manager: *mut UndoManager);
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "move_value_tree_child"]
+        pub unsafe fn moveChild(
+            tree: Pin<&mut ValueTree>,
+            current_index: i32,
+            new_index: i32,
+            undo_manager: *mut UndoManager,
+        );
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "value_tree_index_of"]
+        pub fn indexOf(tree: &ValueTree, child: &ValueTree) -> i32;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "get_value_tree_parent"]
+        pub fn getParent(tree: &ValueTree) -> UniquePtr<ValueTree>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "get_value_tree_root"]
+        pub fn getRoot(tree: &ValueTree) -> UniquePtr<ValueTree>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "value_tree_to_xml_string"]
+        pub fn toXmlString(tree: &ValueTree) -> String;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "value_tree_from_xml"]
+        pub fn fromXml(xml: &str) -> UniquePtr<ValueTree>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "create_value_tree_xml"]
+        pub fn createXml(tree: &ValueTree) -> UniquePtr<XmlElement>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "value_tree_from_xml_element"]
+        pub fn fromXmlElement(xml: &XmlElement) -> UniquePtr<ValueTree>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "write_value_tree_to_binary"]
+        pub fn writeToBinary(tree: &ValueTree) -> Vec<u8>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "read_value_tree_from_data"]
+        pub fn readFromData(data: &[u8]) -> UniquePtr<ValueTree>;
+
+        #[namespace = "cxx_juce::value_tree"]
+        #[rust_name = "add_value_tree_listener"]
+        pub fn addListener(
+            tree: &ValueTree,
+            listener: Box<BoxedValueTreeListener>,
+        ) -> UniquePtr<ValueTreeListenerHandle>;
+
+        #[rust_name = "is_valid"]
+        pub fn isValid(self: &ValueTree) -> bool;
+
+        #[rust_name = "get_num_properties"]
+        pub fn getNumProperties(self: &ValueTree) -> i32;
+
+        #[rust_name = "get_num_children"]
+        pub fn getNumChildren(self: &ValueTree) -> i32;
+
+        #[rust_name = "is_equivalent_to"]
+        pub fn isEquivalentTo(self: &ValueTree, other: &ValueTree) -> bool;
+
+        #[rust_name = "is_a_child_of"]
+        pub fn isAChildOf(self: &ValueTree, possible_parent: &ValueTree) -> bool;
+
+        pub fn reload(self: Pin<&mut PropertiesFile>) -> bool;
+
+        pub fn clear(self: Pin<&mut PropertiesFile>);
+    }
+
+    #[cfg(feature = "juce_dsp")]
+    unsafe extern "C++" {
+        #[namespace = "juce::dsp"]
+        pub type FFT;
+
+        #[namespace = "cxx_juce::fft"]
+        #[rust_name = "create_fft"]
+        pub fn createFFT(order: i32) -> Result<UniquePtr<FFT>>;
+
+        #[rust_name = "get_size"]
+        pub fn getSize(self: &FFT) -> i32;
+
+        #[namespace = "cxx_juce::fft"]
+        #[rust_name = "perform_fft"]
+        pub fn perform(
+            fft: &FFT,
+            input: &[Complex],
+            output: &mut [Complex],
+            inverse: bool,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::fft"]
+        #[rust_name = "perform_real_only_forward_transform"]
+        pub fn performRealOnlyForwardTransform(
+            fft: &FFT,
+            data: &mut [f32],
+            only_calculate_non_negative_frequencies: bool,
+        ) -> Result<()>;
+
+        #[namespace = "cxx_juce::fft"]
+        #[rust_name = "perform_real_only_inverse_transform"]
+        pub fn performRealOnlyInverseTransform(fft: &FFT, data: &mut [f32]) -> Result<()>;
+
+        #[namespace = "cxx_juce::fft"]
+        #[rust_name = "perform_frequency_only_forward_transform"]
+        pub fn performFrequencyOnlyForwardTransform(
+            fft: &FFT,
+            data: &mut [f32],
+            ignore_negative_frequencies: bool,
+        ) -> Result<()>;
+
+        pub type WindowingFunction;
+
+        #[namespace = "cxx_juce::windowing_function"]
+        #[rust_name = "create_windowing_function"]
+        pub fn createWindowingFunction(
+            size: usize,
+            method: i32,
+            normalise: bool,
+            beta: f32,
+        ) -> UniquePtr<WindowingFunction>;
+
+        #[namespace = "cxx_juce::windowing_function"]
+        #[rust_name = "fill_windowing_tables"]
+        pub fn fillWindowingTables(
+            window: Pin<&mut WindowingFunction>,
+            size: usize,
+            method: i32,
+            normalise: bool,
+            beta: f32,
+        );
+
+        #[namespace = "cxx_juce::windowing_function"]
+        #[rust_name = "multiply_with_windowing_table"]
+        pub fn multiplyWithWindowingTable(window: &WindowingFunction, samples: &mut [f32]);
+
+        #[namespace = "cxx_juce::windowing_function"]
+        #[rust_name = "fill_windowing_table"]
+        pub fn fillWindowingTable(samples: &mut [f32], method: i32, normalise: bool, beta: f32);
+
+        #[namespace = "juce::dsp"]
+        pub type Convolution;
+
+        #[namespace = "cxx_juce::convolution"]
+        #[rust_name = "create_convolution"]
+        pub fn createConvolution(latency: i32, head_size: i32) -> UniquePtr<Convolution>;
+
+        #[namespace = "cxx_juce::convolution"]
+        #[rust_name = "load_impulse_response_from_file"]
+        pub fn loadImpulseResponseFromFile(
+            convolution: Pin<&mut Convolution>,
+            path: &str,
+            stereo: bool,
+            trim: bool,
+            size: usize,
+            normalise: bool,
+        ) -> bool;
+
+        #[namespace = "cxx_juce::convolution"]
+        #[rust_name = "load_impulse_response_from_data"]
+        pub fn loadImpulseResponseFromData(
+            convolution: Pin<&mut Convolution>,
+            data: &[u8],
+            stereo: bool,
+            trim: bool,
+            size: usize,
+            normalise: bool,
+        ) -> bool;
+
+        #[namespace = "cxx_juce::convolution"]
+        #[rust_name = "load_impulse_response_from_buffer"]
+        pub fn loadImpulseResponseFromBuffer(
+            convolution: Pin<&mut Convolution>,
+            buffer: &AudioSampleBuffer,
+            sample_rate: f64,
+            stereo: bool,
+            trim: bool,
+            normalise: bool,
+        );
+
+        #[namespace = "cxx_juce::convolution"]
+        #[rust_name = "prepare_convolution"]
+        pub fn prepare(convolution: Pin<&mut Convolution>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::convolution"]
+        #[rust_name = "process_convolution"]
+        pub fn process(
+            convolution: Pin<&mut Convolution>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Convolution>);
+
+        #[rust_name = "get_current_ir_size"]
+        pub fn getCurrentIRSize(self: &Convolution) -> i32;
+
+        #[rust_name = "get_latency"]
+        pub fn getLatency(self: &Convolution) -> i32;
+
+        #[namespace = "cxx_juce::iir"]
+        #[rust_name = "design_iir_coefficients"]
+        pub fn design(
+            filter_type: i32,
+            sample_rate: f64,
+            frequency: f32,
+            q: f32,
+            gain_factor: f32,
+        ) -> Result<Vec<f32>>;
+
+        #[namespace = "cxx_juce::iir"]
+        #[rust_name = "get_iir_magnitude_for_frequency"]
+        pub fn getMagnitudeForFrequency(
+            coefficients: &[f32],
+            frequency: f64,
+            sample_rate: f64,
+        ) -> f64;
+
+        #[namespace = "cxx_juce::iir"]
+        #[rust_name = "get_iir_phase_for_frequency"]
+        pub fn getPhaseForFrequency(coefficients: &[f32], frequency: f64, sample_rate: f64) -> f64;
+
+        #[namespace = "cxx_juce::dsp"]
+        pub type IIRFilter;
+
+        #[namespace = "cxx_juce::iir"]
+        #[rust_name = "create_dsp_iir_filter"]
+        pub fn createFilter(coefficients: &[f32]) -> UniquePtr<IIRFilter>;
+
+        #[rust_name = "set_coefficients"]
+        pub fn setCoefficients(self: Pin<&mut IIRFilter>, coefficients: &[f32]);
+
+        #[rust_name = "prepare"]
+        pub fn prepare(self: Pin<&mut IIRFilter>, spec: &ProcessSpec);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut IIRFilter>);
+
+        #[rust_name = "process"]
+        pub fn process(self: Pin<&mut IIRFilter>, buffer: Pin<&mut AudioSampleBuffer>);
+
+        #[rust_name = "process_sample"]
+        pub fn processSample(self: Pin<&mut IIRFilter>, channel: usize, sample: f32) -> f32;
+
+        #[namespace = "cxx_juce::fir"]
+        #[rust_name = "design_fir_lowpass_window_method"]
+        pub fn designLowpassWindowMethod(
+            sample_rate: f64,
+            frequency: f32,
+            order: usize,
+            windowing_method: i32,
+            beta: f32,
+        ) -> Result<Vec<f32>>;
+
+        #[namespace = "cxx_juce::fir"]
+        #[rust_name = "design_fir_lowpass_kaiser_method"]
+        pub fn designLowpassKaiserMethod(
+            sample_rate: f64,
+            frequency: f32,
+            normalised_transition_width: f32,
+            amplitude_db: f32,
+        ) -> Result<Vec<f32>>;
+
+        #[namespace = "cxx_juce::fir"]
+        #[rust_name = "design_fir_lowpass_transition_method"]
+        pub fn designLowpassTransitionMethod(
+            sample_rate: f64,
+            frequency: f32,
+            order: usize,
+            normalised_transition_width: f32,
+            spline: f32,
+        ) -> Result<Vec<f32>>;
+
+        #[namespace = "cxx_juce::fir"]
+        #[rust_name = "design_fir_lowpass_least_squares_method"]
+        pub fn designLowpassLeastSquaresMethod(
+            sample_rate: f64,
+            frequency: f32,
+            order: usize,
+            normalised_transition_width: f32,
+            stop_band_weight: f32,
+        ) -> Result<Vec<f32>>;
+
+        #[namespace = "cxx_juce::fir"]
+        #[rust_name = "design_fir_lowpass_half_band_equiripple_method"]
+        pub fn designLowpassHalfBandEquirippleMethod(
+            normalised_transition_width: f32,
+            amplitude_db: f32,
+        ) -> Result<Vec<f32>>;
+
+        #[namespace = "cxx_juce::fir"]
+        #[rust_name = "get_fir_magnitude_for_frequency"]
+        pub fn getMagnitudeForFrequency(
+            coefficients: &[f32],
+            frequency: f64,
+            sample_rate: f64,
+        ) -> f64;
+
+        #[namespace = "cxx_juce::fir"]
+        #[rust_name = "get_fir_phase_for_frequency"]
+        pub fn getPhaseForFrequency(coefficients: &[f32], frequency: f64, sample_rate: f64) -> f64;
+
+        #[namespace = "cxx_juce::dsp"]
+        pub type FIRFilter;
+
+        #[namespace = "cxx_juce::fir"]
+        #[rust_name = "create_dsp_fir_filter"]
+        pub fn createFilter(coefficients: &[f32]) -> UniquePtr<FIRFilter>;
+
+        #[rust_name = "set_coefficients"]
+        pub fn setCoefficients(self: Pin<&mut FIRFilter>, coefficients: &[f32]);
+
+        #[rust_name = "prepare"]
+        pub fn prepare(self: Pin<&mut FIRFilter>, spec: &ProcessSpec);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut FIRFilter>);
+
+        #[rust_name = "process"]
+        pub fn process(self: Pin<&mut FIRFilter>, buffer: Pin<&mut AudioSampleBuffer>);
+
+        #[rust_name = "process_sample"]
+        pub fn processSample(self: Pin<&mut FIRFilter>, channel: usize, sample: f32) -> f32;
+
+        pub type StateVariableTPTFilter;
+
+        #[namespace = "cxx_juce::state_variable_tpt_filter"]
+        #[rust_name = "create_state_variable_tpt_filter"]
+        pub fn createStateVariableTPTFilter() -> UniquePtr<StateVariableTPTFilter>;
+
+        #[namespace = "cxx_juce::state_variable_tpt_filter"]
+        #[rust_name = "set_state_variable_tpt_filter_type"]
+        pub fn setType(filter: Pin<&mut StateVariableTPTFilter>, filter_type: i32);
+
+        #[namespace = "cxx_juce::state_variable_tpt_filter"]
+        #[rust_name = "get_state_variable_tpt_filter_type"]
+        pub fn getType(filter: &StateVariableTPTFilter) -> i32;
+
+        #[namespace = "cxx_juce::state_variable_tpt_filter"]
+        #[rust_name = "prepare_state_variable_tpt_filter"]
+        pub fn prepare(filter: Pin<&mut StateVariableTPTFilter>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::state_variable_tpt_filter"]
+        #[rust_name = "process_state_variable_tpt_filter"]
+        pub fn process(
+            filter: Pin<&mut StateVariableTPTFilter>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[namespace = "cxx_juce::state_variable_tpt_filter"]
+        #[rust_name = "process_state_variable_tpt_filter_sample"]
+        pub fn processSample(
+            filter: Pin<&mut StateVariableTPTFilter>,
+            channel: usize,
+            sample: f32,
+        ) -> f32;
+
+        #[rust_name = "set_cutoff_frequency"]
+        pub fn setCutoffFrequency(self: Pin<&mut StateVariableTPTFilter>, frequency: f32);
+
+        #[rust_name = "get_cutoff_frequency"]
+        pub fn getCutoffFrequency(self: &StateVariableTPTFilter) -> f32;
+
+        #[rust_name = "set_resonance"]
+        pub fn setResonance(self: Pin<&mut StateVariableTPTFilter>, resonance: f32);
+
+        #[rust_name = "get_resonance"]
+        pub fn getResonance(self: &StateVariableTPTFilter) -> f32;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut StateVariableTPTFilter>);
+
+        pub type LinkwitzRileyFilter;
+
+        #[namespace = "cxx_juce::linkwitz_riley_filter"]
+        #[rust_name = "create_linkwitz_riley_filter"]
+        pub fn createLinkwitzRileyFilter() -> UniquePtr<LinkwitzRileyFilter>;
+
+        #[namespace = "cxx_juce::linkwitz_riley_filter"]
+        #[rust_name = "set_linkwitz_riley_filter_type"]
+        pub fn setType(filter: Pin<&mut LinkwitzRileyFilter>, filter_type: i32);
+
+        #[namespace = "cxx_juce::linkwitz_riley_filter"]
+        #[rust_name = "get_linkwitz_riley_filter_type"]
+        pub fn getType(filter: &LinkwitzRileyFilter) -> i32;
+
+        #[namespace = "cxx_juce::linkwitz_riley_filter"]
+        #[rust_name = "prepare_linkwitz_riley_filter"]
+        pub fn prepare(filter: Pin<&mut LinkwitzRileyFilter>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::linkwitz_riley_filter"]
+        #[rust_name = "process_linkwitz_riley_filter"]
+        pub fn process(
+            filter: Pin<&mut LinkwitzRileyFilter>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[namespace = "cxx_juce::linkwitz_riley_filter"]
+        #[rust_name = "process_linkwitz_riley_filter_sample"]
+        pub fn processSample(
+            filter: Pin<&mut LinkwitzRileyFilter>,
+            channel: usize,
+            sample: f32,
+        ) -> f32;
+
+        #[namespace = "cxx_juce::linkwitz_riley_filter"]
+        #[rust_name = "process_linkwitz_riley_filter_sample_bands"]
+        pub fn processSampleBands(
+            filter: Pin<&mut LinkwitzRileyFilter>,
+            channel: usize,
+            sample: f32,
+            low: &mut f32,
+            high: &mut f32,
+        );
+
+        #[namespace = "cxx_juce::linkwitz_riley_filter"]
+        #[rust_name = "split_linkwitz_riley_filter"]
+        pub fn split(
+            filter: Pin<&mut LinkwitzRileyFilter>,
+            low: Pin<&mut AudioSampleBuffer>,
+            high: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_cutoff_frequency"]
+        pub fn setCutoffFrequency(self: Pin<&mut LinkwitzRileyFilter>, frequency: f32);
+
+        #[rust_name = "get_cutoff_frequency"]
+        pub fn getCutoffFrequency(self: &LinkwitzRileyFilter) -> f32;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut LinkwitzRileyFilter>);
+
+        pub type FirstOrderTPTFilter;
+
+        #[namespace = "cxx_juce::first_order_tpt_filter"]
+        #[rust_name = "create_first_order_tpt_filter"]
+        pub fn createFirstOrderTPTFilter() -> UniquePtr<FirstOrderTPTFilter>;
+
+        #[namespace = "cxx_juce::first_order_tpt_filter"]
+        #[rust_name = "set_first_order_tpt_filter_type"]
+        pub fn setType(filter: Pin<&mut FirstOrderTPTFilter>, filter_type: i32);
+
+        #[namespace = "cxx_juce::first_order_tpt_filter"]
+        #[rust_name = "get_first_order_tpt_filter_type"]
+        pub fn getType(filter: &FirstOrderTPTFilter) -> i32;
+
+        #[namespace = "cxx_juce::first_order_tpt_filter"]
+        #[rust_name = "prepare_first_order_tpt_filter"]
+        pub fn prepare(filter: Pin<&mut FirstOrderTPTFilter>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::first_order_tpt_filter"]
+        #[rust_name = "process_first_order_tpt_filter"]
+        pub fn process(
+            filter: Pin<&mut FirstOrderTPTFilter>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[namespace = "cxx_juce::first_order_tpt_filter"]
+        #[rust_name = "process_first_order_tpt_filter_sample"]
+        pub fn processSample(
+            filter: Pin<&mut FirstOrderTPTFilter>,
+            channel: usize,
+            sample: f32,
+        ) -> f32;
+
+        #[rust_name = "set_cutoff_frequency"]
+        pub fn setCutoffFrequency(self: Pin<&mut FirstOrderTPTFilter>, frequency: f32);
+
+        #[rust_name = "get_cutoff_frequency"]
+        pub fn getCutoffFrequency(self: &FirstOrderTPTFilter) -> f32;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut FirstOrderTPTFilter>);
+
+        pub type Oscillator;
+
+        #[namespace = "cxx_juce::oscillator"]
+        #[rust_name = "create_oscillator"]
+        pub fn createOscillator(waveform: i32, lookup_table_size: usize) -> UniquePtr<Oscillator>;
+
+        #[namespace = "cxx_juce::oscillator"]
+        #[rust_name = "create_wavetable_oscillator"]
+        pub fn createWavetableOscillator(wavetable: &[f32]) -> Result<UniquePtr<Oscillator>>;
+
+        #[namespace = "cxx_juce::oscillator"]
+        #[rust_name = "prepare_oscillator"]
+        pub fn prepare(oscillator: Pin<&mut Oscillator>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::oscillator"]
+        #[rust_name = "process_oscillator"]
+        pub fn process(
+            oscillator: Pin<&mut Oscillator>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_frequency"]
+        pub fn setFrequency(self: Pin<&mut Oscillator>, frequency: f32, force: bool);
+
+        #[rust_name = "get_frequency"]
+        pub fn getFrequency(self: &Oscillator) -> f32;
+
+        #[rust_name = "process_sample"]
+        pub fn processSample(self: Pin<&mut Oscillator>, input: f32) -> f32;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Oscillator>);
+
+        pub type LadderFilter;
+
+        #[namespace = "cxx_juce::ladder_filter"]
+        #[rust_name = "create_ladder_filter"]
+        pub fn createLadderFilter() -> UniquePtr<LadderFilter>;
+
+        #[namespace = "cxx_juce::ladder_filter"]
+        #[rust_name = "set_ladder_filter_mode"]
+        pub fn setMode(filter: Pin<&mut LadderFilter>, mode: i32);
+
+        #[namespace = "cxx_juce::ladder_filter"]
+        #[rust_name = "prepare_ladder_filter"]
+        pub fn prepare(filter: Pin<&mut LadderFilter>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::ladder_filter"]
+        #[rust_name = "process_ladder_filter"]
+        pub fn process(
+            filter: Pin<&mut LadderFilter>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_enabled"]
+        pub fn setEnabled(self: Pin<&mut LadderFilter>, enabled: bool);
+
+        #[rust_name = "set_cutoff_frequency_hz"]
+        pub fn setCutoffFrequencyHz(self: Pin<&mut LadderFilter>, frequency: f32);
+
+        #[rust_name = "set_resonance"]
+        pub fn setResonance(self: Pin<&mut LadderFilter>, resonance: f32);
+
+        #[rust_name = "set_drive"]
+        pub fn setDrive(self: Pin<&mut LadderFilter>, drive: f32);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut LadderFilter>);
+
+        #[namespace = "cxx_juce::dsp"]
+        pub type DelayLine;
+
+        #[namespace = "cxx_juce::delay_line"]
+        #[rust_name = "create_delay_line"]
+        pub fn createDelayLine(
+            interpolation: i32,
+            maximum_delay_in_samples: usize,
+        ) -> UniquePtr<DelayLine>;
+
+        #[rust_name = "set_delay"]
+        pub fn setDelay(self: Pin<&mut DelayLine>, delay_in_samples: f32);
+
+        #[rust_name = "get_delay"]
+        pub fn getDelay(self: &DelayLine) -> f32;
+
+        #[rust_name = "set_maximum_delay_in_samples"]
+        pub fn setMaximumDelayInSamples(self: Pin<&mut DelayLine>, maximum_delay_in_samples: usize);
+
+        #[rust_name = "get_maximum_delay_in_samples"]
+        pub fn getMaximumDelayInSamples(self: &DelayLine) -> usize;
+
+        #[rust_name = "prepare"]
+        pub fn prepare(self: Pin<&mut DelayLine>, spec: &ProcessSpec);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut DelayLine>);
+
+        #[rust_name = "process"]
+        pub fn process(self: Pin<&mut DelayLine>, buffer: Pin<&mut AudioSampleBuffer>);
+
+        #[rust_name = "push_sample"]
+        pub fn pushSample(self: Pin<&mut DelayLine>, channel: usize, sample: f32);
+
+        #[rust_name = "pop_sample"]
+        pub fn popSample(
+            self: Pin<&mut DelayLine>,
+            channel: usize,
+            delay_in_samples: f32,
+            update_read_pointer: bool,
+        ) -> f32;
+
+        #[namespace = "juce"]
+        pub type Reverb;
+
+        #[namespace = "cxx_juce::reverb"]
+        #[rust_name = "create_reverb"]
+        pub fn createReverb() -> UniquePtr<Reverb>;
+
+        #[namespace = "cxx_juce::reverb"]
+        #[rust_name = "set_reverb_parameters"]
+        pub fn setParameters(reverb: Pin<&mut Reverb>, parameters: &ReverbParameters);
+
+        #[namespace = "cxx_juce::reverb"]
+        #[rust_name = "get_reverb_parameters"]
+        pub fn getParameters(reverb: &Reverb) -> ReverbParameters;
+
+        #[namespace = "cxx_juce::reverb"]
+        #[rust_name = "process_reverb"]
+        pub fn process(
+            reverb: Pin<&mut Reverb>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[namespace = "cxx_juce::reverb"]
+        #[rust_name = "process_reverb_stereo"]
+        pub fn processStereo(reverb: Pin<&mut Reverb>, left: &mut [f32], right: &mut [f32]);
+
+        #[namespace = "cxx_juce::reverb"]
+        #[rust_name = "process_reverb_mono"]
+        pub fn processMono(reverb: Pin<&mut Reverb>, samples: &mut [f32]);
+
+        #[rust_name = "set_sample_rate"]
+        pub fn setSampleRate(self: Pin<&mut Reverb>, sample_rate: f64);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Reverb>);
+
+        pub type Compressor;
+
+        #[namespace = "cxx_juce::compressor"]
+        #[rust_name = "create_compressor"]
+        pub fn createCompressor() -> UniquePtr<Compressor>;
+
+        #[namespace = "cxx_juce::compressor"]
+        #[rust_name = "prepare_compressor"]
+        pub fn prepare(compressor: Pin<&mut Compressor>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::compressor"]
+        #[rust_name = "process_compressor"]
+        pub fn process(
+            compressor: Pin<&mut Compressor>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_threshold"]
+        pub fn setThreshold(self: Pin<&mut Compressor>, threshold_db: f32);
+
+        #[rust_name = "set_ratio"]
+        pub fn setRatio(self: Pin<&mut Compressor>, ratio: f32);
+
+        #[rust_name = "set_attack"]
+        pub fn setAttack(self: Pin<&mut Compressor>, attack_ms: f32);
+
+        #[rust_name = "set_release"]
+        pub fn setRelease(self: Pin<&mut Compressor>, release_ms: f32);
+
+        #[rust_name = "process_sample"]
+        pub fn processSample(self: Pin<&mut Compressor>, channel: i32, sample: f32) -> f32;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Compressor>);
+
+        pub type Limiter;
+
+        #[namespace = "cxx_juce::limiter"]
+        #[rust_name = "create_limiter"]
+        pub fn createLimiter() -> UniquePtr<Limiter>;
+
+        #[namespace = "cxx_juce::limiter"]
+        #[rust_name = "prepare_limiter"]
+        pub fn prepare(limiter: Pin<&mut Limiter>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::limiter"]
+        #[rust_name = "process_limiter"]
+        pub fn process(
+            limiter: Pin<&mut Limiter>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_threshold"]
+        pub fn setThreshold(self: Pin<&mut Limiter>, threshold_db: f32);
+
+        #[rust_name = "set_release"]
+        pub fn setRelease(self: Pin<&mut Limiter>, release_ms: f32);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Limiter>);
+
+        pub type NoiseGate;
+
+        #[namespace = "cxx_juce::noise_gate"]
+        #[rust_name = "create_noise_gate"]
+        pub fn createNoiseGate() -> UniquePtr<NoiseGate>;
+
+        #[namespace = "cxx_juce::noise_gate"]
+        #[rust_name = "prepare_noise_gate"]
+        pub fn prepare(noise_gate: Pin<&mut NoiseGate>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::noise_gate"]
+        #[rust_name = "process_noise_gate"]
+        pub fn process(
+            noise_gate: Pin<&mut NoiseGate>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_threshold"]
+        pub fn setThreshold(self: Pin<&mut NoiseGate>, threshold_db: f32);
+
+        #[rust_name = "set_ratio"]
+        pub fn setRatio(self: Pin<&mut NoiseGate>, ratio: f32);
+
+        #[rust_name = "set_attack"]
+        pub fn setAttack(self: Pin<&mut NoiseGate>, attack_ms: f32);
+
+        #[rust_name = "set_release"]
+        pub fn setRelease(self: Pin<&mut NoiseGate>, release_ms: f32);
+
+        #[rust_name = "process_sample"]
+        pub fn processSample(self: Pin<&mut NoiseGate>, channel: i32, sample: f32) -> f32;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut NoiseGate>);
+
+        pub type Phaser;
+
+        #[namespace = "cxx_juce::phaser"]
+        #[rust_name = "create_phaser"]
+        pub fn createPhaser() -> UniquePtr<Phaser>;
+
+        #[namespace = "cxx_juce::phaser"]
+        #[rust_name = "prepare_phaser"]
+        pub fn prepare(phaser: Pin<&mut Phaser>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::phaser"]
+        #[rust_name = "process_phaser"]
+        pub fn process(
+            phaser: Pin<&mut Phaser>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_rate"]
+        pub fn setRate(self: Pin<&mut Phaser>, rate_hz: f32);
+
+        #[rust_name = "set_depth"]
+        pub fn setDepth(self: Pin<&mut Phaser>, depth: f32);
+
+        #[rust_name = "set_centre_frequency"]
+        pub fn setCentreFrequency(self: Pin<&mut Phaser>, centre_frequency_hz: f32);
+
+        #[rust_name = "set_feedback"]
+        pub fn setFeedback(self: Pin<&mut Phaser>, feedback: f32);
+
+        #[rust_name = "set_mix"]
+        pub fn setMix(self: Pin<&mut Phaser>, mix: f32);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Phaser>);
+
+        pub type Chorus;
+
+        #[namespace = "cxx_juce::chorus"]
+        #[rust_name = "create_chorus"]
+        pub fn createChorus() -> UniquePtr<Chorus>;
+
+        #[namespace = "cxx_juce::chorus"]
+        #[rust_name = "prepare_chorus"]
+        pub fn prepare(chorus: Pin<&mut Chorus>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::chorus"]
+        #[rust_name = "process_chorus"]
+        pub fn process(
+            chorus: Pin<&mut Chorus>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_rate"]
+        pub fn setRate(self: Pin<&mut Chorus>, rate_hz: f32);
+
+        #[rust_name = "set_depth"]
+        pub fn setDepth(self: Pin<&mut Chorus>, depth: f32);
+
+        #[rust_name = "set_centre_delay"]
+        pub fn setCentreDelay(self: Pin<&mut Chorus>, centre_delay_ms: f32);
+
+        #[rust_name = "set_feedback"]
+        pub fn setFeedback(self: Pin<&mut Chorus>, feedback: f32);
+
+        #[rust_name = "set_mix"]
+        pub fn setMix(self: Pin<&mut Chorus>, mix: f32);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Chorus>);
+
+        pub type WaveShaper;
+
+        #[namespace = "cxx_juce::wave_shaper"]
+        #[rust_name = "create_wave_shaper"]
+        pub fn createWaveShaper(function: Box<BoxedWaveShaperFunction>) -> UniquePtr<WaveShaper>;
+
+        #[namespace = "cxx_juce::wave_shaper"]
+        #[rust_name = "create_lookup_table_wave_shaper"]
+        pub fn createLookupTableWaveShaper(
+            function: &BoxedWaveShaperFunction,
+            minimum_input: f32,
+            maximum_input: f32,
+            num_points: usize,
+        ) -> Result<UniquePtr<WaveShaper>>;
+
+        #[namespace = "cxx_juce::wave_shaper"]
+        #[rust_name = "process_wave_shaper_sample"]
+        pub fn processSample(shaper: &WaveShaper, sample: f32) -> f32;
+
+        #[namespace = "cxx_juce::wave_shaper"]
+        #[rust_name = "process_wave_shaper"]
+        pub fn process(
+            shaper: Pin<&mut WaveShaper>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        pub type DryWetMixer;
+
+        #[namespace = "cxx_juce::dry_wet_mixer"]
+        #[rust_name = "create_dry_wet_mixer"]
+        pub fn createDryWetMixer(maximum_wet_latency_in_samples: usize) -> UniquePtr<DryWetMixer>;
+
+        #[namespace = "cxx_juce::dry_wet_mixer"]
+        #[rust_name = "set_dry_wet_mixing_rule"]
+        pub fn setMixingRule(mixer: Pin<&mut DryWetMixer>, rule: i32);
+
+        #[namespace = "cxx_juce::dry_wet_mixer"]
+        #[rust_name = "prepare_dry_wet_mixer"]
+        pub fn prepare(mixer: Pin<&mut DryWetMixer>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::dry_wet_mixer"]
+        #[rust_name = "push_dry_samples"]
+        pub fn pushDrySamples(
+            mixer: Pin<&mut DryWetMixer>,
+            buffer: &AudioSampleBuffer,
+            spec: &ProcessSpec,
+        );
+
+        #[namespace = "cxx_juce::dry_wet_mixer"]
+        #[rust_name = "mix_wet_samples"]
+        pub fn mixWetSamples(
+            mixer: Pin<&mut DryWetMixer>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_wet_mix_proportion"]
+        pub fn setWetMixProportion(self: Pin<&mut DryWetMixer>, proportion: f32);
+
+        #[rust_name = "set_wet_latency"]
+        pub fn setWetLatency(self: Pin<&mut DryWetMixer>, latency_in_samples: f32);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut DryWetMixer>);
+
+        pub type Panner;
+
+        #[namespace = "cxx_juce::panner"]
+        #[rust_name = "create_panner"]
+        pub fn createPanner() -> UniquePtr<Panner>;
+
+        #[namespace = "cxx_juce::panner"]
+        #[rust_name = "set_panner_rule"]
+        pub fn setRule(panner: Pin<&mut Panner>, rule: i32);
+
+        #[namespace = "cxx_juce::panner"]
+        #[rust_name = "prepare_panner"]
+        pub fn prepare(panner: Pin<&mut Panner>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::panner"]
+        #[rust_name = "process_panner"]
+        pub fn process(
+            panner: Pin<&mut Panner>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_pan"]
+        pub fn setPan(self: Pin<&mut Panner>, pan: f32);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Panner>);
+
+        pub type BallisticsFilter;
+
+        #[namespace = "cxx_juce::ballistics_filter"]
+        #[rust_name = "create_ballistics_filter"]
+        pub fn createBallisticsFilter() -> UniquePtr<BallisticsFilter>;
+
+        #[namespace = "cxx_juce::ballistics_filter"]
+        #[rust_name = "set_ballistics_filter_level_calculation_type"]
+        pub fn setLevelCalculationType(filter: Pin<&mut BallisticsFilter>, level_type: i32);
+
+        #[namespace = "cxx_juce::ballistics_filter"]
+        #[rust_name = "prepare_ballistics_filter"]
+        pub fn prepare(filter: Pin<&mut BallisticsFilter>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::ballistics_filter"]
+        #[rust_name = "process_ballistics_filter"]
+        pub fn process(
+            filter: Pin<&mut BallisticsFilter>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_attack_time"]
+        pub fn setAttackTime(self: Pin<&mut BallisticsFilter>, attack_ms: f32);
+
+        #[rust_name = "set_release_time"]
+        pub fn setReleaseTime(self: Pin<&mut BallisticsFilter>, release_ms: f32);
+
+        #[rust_name = "process_sample"]
+        pub fn processSample(self: Pin<&mut BallisticsFilter>, channel: i32, sample: f32) -> f32;
+
+        #[rust_name = "snap_to_zero"]
+        pub fn snapToZero(self: Pin<&mut BallisticsFilter>);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut BallisticsFilter>);
+
+        #[namespace = "cxx_juce::dsp"]
+        pub type Oversampling;
+
+        #[namespace = "cxx_juce::oversampling"]
+        #[rust_name = "create_oversampling"]
+        pub fn createOversampling(factor: usize, filter_type: i32) -> UniquePtr<Oversampling>;
+
+        #[rust_name = "set_maximum_quality"]
+        pub fn setMaximumQuality(self: Pin<&mut Oversampling>, maximum_quality: bool);
+
+        #[rust_name = "set_using_integer_latency"]
+        pub fn setUsingIntegerLatency(self: Pin<&mut Oversampling>, use_integer_latency: bool);
+
+        #[rust_name = "get_latency_in_samples"]
+        pub fn getLatencyInSamples(self: &Oversampling) -> f32;
+
+        #[rust_name = "get_oversampling_factor"]
+        pub fn getOversamplingFactor(self: &Oversampling) -> usize;
+
+        #[rust_name = "prepare"]
+        pub fn prepare(self: Pin<&mut Oversampling>, spec: &ProcessSpec);
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Oversampling>);
+
+        #[rust_name = "process_samples_up"]
+        pub fn processSamplesUp<'a>(
+            self: Pin<&'a mut Oversampling>,
+            input: &AudioSampleBuffer,
+        ) -> Result<Pin<&'a mut AudioSampleBuffer>>;
+
+        #[rust_name = "process_samples_down"]
+        pub fn processSamplesDown(
+            self: Pin<&mut Oversampling>,
+            output: Pin<&mut AudioSampleBuffer>,
+        ) -> Result<()>;
+
+        pub type Gain;
+
+        #[namespace = "cxx_juce::gain"]
+        #[rust_name = "create_gain"]
+        pub fn createGain() -> UniquePtr<Gain>;
+
+        #[namespace = "cxx_juce::gain"]
+        #[rust_name = "prepare_gain"]
+        pub fn prepare(gain: Pin<&mut Gain>, spec: &ProcessSpec);
+
+        #[namespace = "cxx_juce::gain"]
+        #[rust_name = "process_gain"]
+        pub fn process(
+            gain: Pin<&mut Gain>,
+            buffer: Pin<&mut AudioSampleBuffer>,
+            spec: &ProcessSpec,
+        );
+
+        #[rust_name = "set_gain_linear"]
+        pub fn setGainLinear(self: Pin<&mut Gain>, gain: f32);
+
+        #[rust_name = "set_gain_decibels"]
+        pub fn setGainDecibels(self: Pin<&mut Gain>, gain_db: f32);
+
+        #[rust_name = "get_gain_linear"]
+        pub fn getGainLinear(self: &Gain) -> f32;
+
+        #[rust_name = "get_gain_decibels"]
+        pub fn getGainDecibels(self: &Gain) -> f32;
+
+        #[rust_name = "set_ramp_duration_seconds"]
+        pub fn setRampDurationSeconds(self: Pin<&mut Gain>, ramp_duration_seconds: f64);
+
+        #[rust_name = "get_ramp_duration_seconds"]
+        pub fn getRampDurationSeconds(self: &Gain) -> f64;
+
+        #[rust_name = "is_smoothing"]
+        pub fn isSmoothing(self: &Gain) -> bool;
+
+        #[rust_name = "process_sample"]
+        pub fn processSample(self: Pin<&mut Gain>, sample: f32) -> f32;
+
+        #[rust_name = "reset"]
+        pub fn reset(self: Pin<&mut Gain>);
+
+        pub type LookupTableTransform;
+
+        #[namespace = "cxx_juce::lookup_table_transform"]
+        #[rust_name = "create_lookup_table_transform"]
+        pub fn createLookupTableTransform(
+            function: &BoxedWaveShaperFunction,
+            minimum_input: f32,
+            maximum_input: f32,
+            num_points: usize,
+        ) -> Result<UniquePtr<LookupTableTransform>>;
+
+        #[namespace = "cxx_juce::lookup_table_transform"]
+        #[rust_name = "process_lookup_table_transform"]
+        pub fn process(transform: &LookupTableTransform, samples: &mut [f32]);
+
+        #[rust_name = "process_sample"]
+        pub fn processSample(self: &LookupTableTransform, input: f32) -> f32;
     }
 }
@@ -0,0 +1,40 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{Shutdown, TcpListener},
+};
+
+/// Serves a single request, announcing a body of `content_length` bytes and then sending `body`
+/// and waiting for the client to close the connection.
+///
+/// Without a `content_length` the body ends when the server closes its side of the connection.
+pub fn serve_once(body: Vec<u8>, content_length: Option<usize>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        let mut request = BufReader::new(stream.try_clone().unwrap());
+        let mut line = String::new();
+        while request.read_line(&mut line).unwrap() > 2 {
+            line.clear();
+        }
+
+        write!(stream, "HTTP/1.1 200 OK\r\n").unwrap();
+
+        if let Some(content_length) = content_length {
+            write!(stream, "Content-Length: {content_length}\r\n").unwrap();
+        }
+
+        write!(stream, "Connection: close\r\n\r\n").unwrap();
+        let _ = stream.write_all(&body);
+
+        if content_length.is_none() {
+            let _ = stream.shutdown(Shutdown::Write);
+        }
+
+        let _ = stream.read_to_end(&mut vec![]);
+    });
+
+    format!("http://{address}/file.wav")
+}
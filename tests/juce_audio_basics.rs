@@ -0,0 +1,404 @@
+use {
+    cxx_juce::{
+        juce_audio_basics::{
+            ADSRParameters, AudioBuffer, Decibels, FloatVectorOperations, Interpolator,
+            InterpolatorType, MidiBuffer, MidiClockGenerator, MidiClockTracker, MidiFilter,
+            MidiMessage, MidiMessageType, MidiRPNDetector, MidiRPNGenerator, MidiRPNMessage,
+            MidiTimecodeDecoder, SmoothedValue, SmpteTimecodeType, Timecode, ValueSmoothing,
+            VelocityCurve, ADSR,
+        },
+        juce_audio_devices::OutputAudioSampleBuffer,
+    },
+    std::time::Duration,
+};
+
+#[test]
+fn can_track_the_tempo_of_a_midi_clock() {
+    let mut generator = MidiClockGenerator::new(120.0, 0.0);
+    let mut tracker = MidiClockTracker::new();
+
+    tracker.process(&MidiMessage::midi_start());
+    for clock in generator.clocks_until(0.99) {
+        tracker.process(&clock);
+    }
+
+    assert!(tracker.is_playing());
+    assert!((tracker.bpm().unwrap() - 120.0).abs() < 0.001);
+    assert_eq!(tracker.position_in_quarter_notes(), 2.0);
+
+    tracker.process(&MidiMessage::midi_stop());
+    assert!(!tracker.is_playing());
+}
+
+#[test]
+fn song_position_pointer_moves_the_clock_position() {
+    let mut tracker = MidiClockTracker::new();
+
+    tracker.process(&MidiMessage::song_position_pointer(16));
+    assert_eq!(tracker.position_in_quarter_notes(), 4.0);
+}
+
+#[test]
+fn midi_clocks_cannot_be_generated_at_invalid_tempos() {
+    for bpm in [0.0, -120.0, f64::NAN, f64::INFINITY] {
+        assert!(std::panic::catch_unwind(|| MidiClockGenerator::new(bpm, 0.0)).is_err());
+        assert!(std::panic::catch_unwind(|| {
+            MidiClockGenerator::new(120.0, 0.0).set_bpm(bpm);
+        })
+        .is_err());
+    }
+}
+
+#[test]
+#[should_panic]
+fn quarter_frames_cannot_have_out_of_range_values() {
+    MidiMessage::quarter_frame(0, 16);
+}
+
+#[test]
+fn can_decode_midi_timecode_quarter_frames() {
+    let timecode = Timecode {
+        hours: 1,
+        minutes: 23,
+        seconds: 45,
+        frames: 17,
+        timecode_type: SmpteTimecodeType::Fps25,
+    };
+
+    let mut decoder = MidiTimecodeDecoder::new();
+    let decoded: Vec<_> = timecode
+        .quarter_frames()
+        .iter()
+        .filter_map(|message| decoder.process(message))
+        .collect();
+
+    assert_eq!(decoded, [timecode]);
+}
+
+#[test]
+fn can_decode_midi_timecode_full_frames() {
+    let timecode = Timecode {
+        hours: 10,
+        minutes: 0,
+        seconds: 59,
+        frames: 29,
+        timecode_type: SmpteTimecodeType::Fps30Drop,
+    };
+
+    let message = MidiMessage::full_frame(timecode);
+
+    assert_eq!(MidiTimecodeDecoder::new().process(&message), Some(timecode));
+}
+
+#[test]
+fn timecode_round_trips_through_seconds() {
+    for timecode_type in [
+        SmpteTimecodeType::Fps24,
+        SmpteTimecodeType::Fps25,
+        SmpteTimecodeType::Fps30Drop,
+        SmpteTimecodeType::Fps30,
+    ] {
+        let timecode = Timecode {
+            hours: 2,
+            minutes: 11,
+            seconds: 7,
+            frames: 12,
+            timecode_type,
+        };
+
+        assert_eq!(
+            Timecode::from_seconds(timecode.to_seconds() + 0.001, timecode_type),
+            timecode
+        );
+    }
+}
+
+#[test]
+fn can_iterate_over_a_midi_buffer() {
+    let mut buffer = MidiBuffer::new();
+    buffer.add_event(&MidiMessage::controller_event(1, 7, 100), 32);
+    buffer.add_raw_event(&[0x90, 60, 127], 0);
+
+    let events: Vec<_> = buffer
+        .iter()
+        .map(|event| (event.sample_position, event.bytes.to_vec()))
+        .collect();
+
+    assert_eq!(events, [(0, vec![0x90, 60, 127]), (32, vec![0xb0, 7, 100])]);
+}
+
+#[test]
+fn can_round_trip_rpn_messages() {
+    let message = MidiRPNMessage::pitch_bend_range(3, 12, 0);
+
+    let mut detector = MidiRPNDetector::new();
+    let parsed: Vec<_> = MidiRPNGenerator::generate(message)
+        .iter()
+        .filter_map(|event| detector.process(&event.to_message().unwrap()))
+        .collect();
+
+    assert_eq!(parsed, [message]);
+}
+
+#[test]
+#[should_panic]
+fn midi_messages_cannot_be_moved_to_an_invalid_channel() {
+    MidiMessage::from_bytes(&[0x90, 60, 100])
+        .unwrap()
+        .set_channel(0);
+}
+
+#[test]
+fn rpn_detector_ignores_invalid_channels() {
+    let mut detector = MidiRPNDetector::new();
+
+    for channel in [0, 17, -1, i32::MAX] {
+        assert_eq!(detector.parse_controller_message(channel, 101, 0), None);
+        assert_eq!(detector.parse_controller_message(channel, 100, 0), None);
+        assert_eq!(detector.parse_controller_message(channel, 6, 2), None);
+        assert_eq!(detector.parse_controller_message(channel, 38, 0), None);
+    }
+}
+
+#[test]
+fn midi_filter_blocks_messages_by_type_and_channel() {
+    let filter = MidiFilter::new()
+        .with_blocked_types([MidiMessageType::Controller])
+        .with_input_channels([1, 2]);
+
+    let note_on = MidiMessage::from_bytes(&[0x90, 60, 100]).unwrap();
+    let other_channel = MidiMessage::from_bytes(&[0x92, 60, 100]).unwrap();
+    let controller = MidiMessage::controller_event(1, 7, 100);
+
+    assert!(filter.process(note_on).is_some());
+    assert!(filter.process(other_channel).is_none());
+    assert!(filter.process(controller).is_none());
+    assert!(filter.process(MidiMessage::midi_clock()).is_some());
+}
+
+#[test]
+#[should_panic]
+fn midi_filters_reject_invalid_channels() {
+    let _ = MidiFilter::new().with_input_channels([1, 17]);
+}
+
+#[test]
+fn midi_filter_splits_the_keyboard_and_applies_velocity_curves() {
+    let filter = MidiFilter::new()
+        .with_split(0..=59, 2)
+        .with_split(60..=127, 3)
+        .with_velocity_curve(VelocityCurve::Fixed(64));
+
+    let mut input = MidiBuffer::new();
+    input.add_raw_event(&[0x90, 48, 10], 0);
+    input.add_raw_event(&[0x90, 72, 127], 1);
+    input.add_raw_event(&[0x80, 72, 0], 2);
+
+    let mut output = MidiBuffer::new();
+    filter.process_buffer(&input, &mut output);
+
+    let events: Vec<_> = output.iter().map(|event| event.bytes.to_vec()).collect();
+    assert_eq!(
+        events,
+        [vec![0x91, 48, 64], vec![0x92, 72, 64], vec![0x82, 72, 0]]
+    );
+}
+
+#[test]
+fn velocity_curves_never_turn_note_ons_into_note_offs() {
+    assert_eq!(VelocityCurve::Power(4.0).apply(1), 1);
+    assert_eq!(VelocityCurve::Power(0.5).apply(127), 127);
+    assert_eq!(VelocityCurve::Fixed(0).apply(100), 1);
+}
+
+#[test]
+fn smoothed_values_ramp_towards_their_target() {
+    let mut value = SmoothedValue::linear(0.0).with_ramp_length(1000.0, Duration::from_millis(4));
+    assert_eq!(value.smoothing(), ValueSmoothing::Linear);
+
+    value.set_target_value(1.0);
+    assert!(value.is_smoothing());
+    assert_eq!(value.target_value(), 1.0);
+    assert_eq!(value.next_value(), 0.25);
+    assert_eq!(value.current_value(), 0.25);
+    assert_eq!(value.skip(2), 0.75);
+    assert_eq!(value.next_value(), 1.0);
+    assert!(!value.is_smoothing());
+
+    value.set_current_and_target_value(0.5);
+    assert_eq!(value.current_value(), 0.5);
+    assert!(!value.is_smoothing());
+}
+
+#[test]
+fn multiplicative_smoothed_values_ramp_by_a_constant_ratio() {
+    let mut value = SmoothedValue::multiplicative(0.0);
+    assert_eq!(value.smoothing(), ValueSmoothing::Multiplicative);
+    assert!(value.current_value() > 0.0);
+
+    value.set_current_and_target_value(1.0);
+    value.reset_steps(2);
+    value.set_target_value(4.0);
+
+    assert!((value.next_value() - 2.0).abs() < 1e-5);
+    assert!((value.next_value() - 4.0).abs() < 1e-5);
+}
+
+#[test]
+fn smoothed_values_can_ramp_the_gain_of_a_buffer() {
+    let mut value = SmoothedValue::linear(1.0);
+    value.reset_steps(4);
+    value.set_target_value(0.0);
+
+    let mut buffer = AudioBuffer::new(2, 6);
+    buffer[0].fill(1.0);
+    buffer[1].fill(1.0);
+    value.apply_gain(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    assert_eq!(buffer[0], [0.75, 0.5, 0.25, 0.0, 0.0, 0.0]);
+    assert_eq!(buffer[1], buffer[0]);
+    assert!(!value.is_smoothing());
+}
+
+#[test]
+fn buffer_sizes_must_fit_in_an_i32() {
+    let too_large = i32::MAX as usize + 1;
+    assert!(AudioBuffer::try_new(1, too_large).is_err());
+    assert!(AudioBuffer::try_new(too_large, 1).is_err());
+
+    let mut buffer = AudioBuffer::try_new(2, 3).unwrap();
+    assert!(buffer.try_set_size(1, too_large).is_err());
+    assert_eq!((buffer.channels(), buffer.samples()), (2, 3));
+
+    buffer.try_set_size(1, 4).unwrap();
+    assert_eq!((buffer.channels(), buffer.samples()), (1, 4));
+}
+
+#[test]
+fn adsr_envelopes_follow_their_stages() {
+    let mut adsr = ADSR::new().with_parameters(ADSRParameters {
+        attack: Duration::from_millis(2),
+        decay: Duration::from_millis(2),
+        sustain: 2.0,
+        release: Duration::from_millis(2),
+    });
+    assert_eq!(adsr.parameters().sustain, 1.0);
+    assert_eq!(adsr.sample_rate(), None);
+
+    adsr.set_sample_rate(1000.0);
+    adsr.set_parameters(ADSRParameters {
+        sustain: 0.5,
+        ..adsr.parameters()
+    });
+    assert!(!adsr.is_active());
+
+    adsr.note_on();
+    assert!(adsr.is_active());
+
+    let attack_and_decay = [(); 4].map(|_| adsr.next_sample());
+    assert!(attack_and_decay[0] > 0.0);
+    assert!((attack_and_decay.iter().copied().fold(0.0, f32::max) - 1.0).abs() < 1e-5);
+    assert!((adsr.next_sample() - 0.5).abs() < 1e-5);
+
+    adsr.note_off();
+    let mut buffer = AudioBuffer::new(1, 4);
+    buffer[0].fill(1.0);
+    adsr.apply_envelope_to_buffer(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    assert!(buffer[0][0] < 0.5);
+    assert_eq!(buffer[0][3], 0.0);
+    assert!(!adsr.is_active());
+
+    adsr.note_on();
+    adsr.reset();
+    assert!(!adsr.is_active());
+}
+
+#[test]
+fn float_vector_operations_process_slices() {
+    let mut dest = [1.0, 2.0, 3.0, 4.0];
+
+    FloatVectorOperations::add(&mut dest, &[1.0, 1.0]);
+    assert_eq!(dest, [2.0, 3.0, 3.0, 4.0]);
+
+    FloatVectorOperations::add_value(&mut dest, 1.0);
+    assert_eq!(dest, [3.0, 4.0, 4.0, 5.0]);
+
+    FloatVectorOperations::multiply(&mut dest, &[2.0, 0.5, 1.0, 1.0, 10.0]);
+    assert_eq!(dest, [6.0, 2.0, 4.0, 5.0]);
+
+    FloatVectorOperations::multiply_by_value(&mut dest, 0.5);
+    assert_eq!(dest, [3.0, 1.0, 2.0, 2.5]);
+
+    FloatVectorOperations::copy_with_multiply(&mut dest, &[1.0, -1.0], 3.0);
+    assert_eq!(dest, [3.0, -3.0, 2.0, 2.5]);
+
+    assert_eq!(
+        FloatVectorOperations::find_min_and_max(&dest),
+        Some((-3.0, 3.0))
+    );
+    assert_eq!(FloatVectorOperations::find_min_and_max(&[]), None);
+
+    FloatVectorOperations::clip(&mut dest, -1.0, 2.0);
+    assert_eq!(dest, [2.0, -1.0, 2.0, 2.0]);
+
+    FloatVectorOperations::convert_fixed_to_float(&mut dest, &[256, -512, 0, 128], 1.0 / 512.0);
+    assert_eq!(dest, [0.5, -1.0, 0.0, 0.25]);
+}
+
+#[test]
+fn can_convert_between_gains_and_decibels() {
+    assert!((Decibels::to_gain(-6.0) - 0.501_187).abs() < 1e-5);
+    assert_eq!(Decibels::to_gain(-100.0), 0.0);
+    assert!((Decibels::to_gain_with_threshold(-100.0, -120.0) - 1e-5).abs() < 1e-9);
+    assert_eq!(Decibels::to_gain_with_threshold(-60.0, -60.0), 0.0);
+
+    assert!((Decibels::from_gain(2.0) - 6.020_6).abs() < 1e-3);
+    assert_eq!(
+        Decibels::from_gain(0.0),
+        Decibels::DEFAULT_MINUS_INFINITY_DB
+    );
+    assert_eq!(Decibels::from_gain_with_threshold(0.0, -60.0), -60.0);
+    assert_eq!(Decibels::from_gain_with_threshold(1e-4, -60.0), -60.0);
+
+    assert_eq!(Decibels::to_string(3.0, 2, true), "+3.00 dB");
+    assert_eq!(Decibels::to_string(-3.5, 1, false), "-3.5");
+    assert_eq!(Decibels::to_string(-200.0, 2, true), "-INF dB");
+}
+
+#[test]
+fn interpolators_resample_between_blocks() {
+    let mut interpolator = Interpolator::new(InterpolatorType::Linear);
+    assert_eq!(interpolator.interpolator_type(), InterpolatorType::Linear);
+    let latency = interpolator.base_latency() as usize;
+
+    let input = [1.0; 8];
+    let mut output = [0.0; 8];
+    assert_eq!(interpolator.process(1.0, &input, &mut output), 8);
+    assert!(output[latency..]
+        .iter()
+        .all(|sample| (sample - 1.0).abs() < 1e-6));
+
+    let mut output = [0.0; 16];
+    assert_eq!(interpolator.process(0.5, &input, &mut output), 8);
+    assert!(output.iter().all(|sample| (sample - 1.0).abs() < 1e-6));
+
+    let mut output = [1.0; 4];
+    assert_eq!(
+        interpolator.process_adding(1.0, &input[..4], &mut output, 0.5),
+        4
+    );
+    assert!(output.iter().all(|sample| (sample - 1.5).abs() < 1e-6));
+
+    interpolator.reset();
+    for interpolator_type in [
+        InterpolatorType::ZeroOrderHold,
+        InterpolatorType::CatmullRom,
+        InterpolatorType::Lagrange,
+        InterpolatorType::WindowedSinc,
+    ] {
+        let mut interpolator = Interpolator::new(interpolator_type);
+        let mut output = [0.0; 16];
+        assert_eq!(interpolator.process(0.5, &input, &mut output), 8);
+    }
+}
@@ -0,0 +1,1479 @@
+#![cfg(feature = "juce_audio_formats")]
+
+use {
+    cxx_juce::{
+        juce_audio_basics::AudioBuffer,
+        juce_audio_formats::{AudioFormatManager, WavAudioFormat},
+    },
+    std::io::{Cursor, Write},
+};
+
+#[cfg(feature = "network")]
+mod common;
+
+fn wav_file(sample_rate: u32, channels: u16, samples: &[i16]) -> Vec<u8> {
+    let data_size = std::mem::size_of_val(samples) as u32;
+    let block_align = channels * 2;
+
+    let mut wav = Vec::new();
+    wav.write_all(b"RIFF").unwrap();
+    wav.write_all(&(36 + data_size).to_le_bytes()).unwrap();
+    wav.write_all(b"WAVEfmt ").unwrap();
+    wav.write_all(&16_u32.to_le_bytes()).unwrap();
+    wav.write_all(&1_u16.to_le_bytes()).unwrap();
+    wav.write_all(&channels.to_le_bytes()).unwrap();
+    wav.write_all(&sample_rate.to_le_bytes()).unwrap();
+    wav.write_all(&(sample_rate * block_align as u32).to_le_bytes())
+        .unwrap();
+    wav.write_all(&block_align.to_le_bytes()).unwrap();
+    wav.write_all(&16_u16.to_le_bytes()).unwrap();
+    wav.write_all(b"data").unwrap();
+    wav.write_all(&data_size.to_le_bytes()).unwrap();
+
+    for sample in samples {
+        wav.write_all(&sample.to_le_bytes()).unwrap();
+    }
+
+    wav
+}
+
+#[test]
+fn can_register_the_basic_formats() {
+    let manager = AudioFormatManager::with_basic_formats();
+
+    assert!(manager.num_known_formats() > 0);
+    assert!(manager
+        .known_format_names()
+        .iter()
+        .any(|name| name.contains("WAV")));
+    assert!(manager.wildcard_for_all_formats().contains("*.wav"));
+}
+
+#[test]
+fn can_read_a_wav_file_from_a_stream() {
+    let mut manager = AudioFormatManager::with_basic_formats();
+    let wav = wav_file(44_100, 2, &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+    let reader = manager.create_reader_for_stream(Cursor::new(wav)).unwrap();
+
+    assert_eq!(reader.sample_rate(), 44_100.0);
+    assert_eq!(reader.num_channels(), 2);
+    assert_eq!(reader.length_in_samples(), 4);
+    assert_eq!(reader.bits_per_sample(), 16);
+    assert!(!reader.uses_floating_point_data());
+}
+
+#[test]
+fn returns_none_for_unrecognised_data() {
+    let mut manager = AudioFormatManager::with_basic_formats();
+
+    assert!(manager
+        .create_reader_for_stream(Cursor::new(vec![0_u8; 64]))
+        .is_none());
+    assert!(manager
+        .create_reader_for_file("does-not-exist.wav")
+        .is_none());
+}
+
+#[test]
+fn can_read_a_wav_file_into_a_buffer() {
+    let samples = [0, 8192, -8192, 16384, 32767, -32768];
+    let wav = wav_file(48_000, 2, &samples);
+    let path = std::env::temp_dir().join("cxx-juce-can-read-a-wav-file-into-a-buffer.wav");
+    std::fs::write(&path, wav).unwrap();
+
+    let mut format = WavAudioFormat::new();
+    let mut reader = format.create_reader_for_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(reader.format_name(), "WAV file");
+    assert_eq!(reader.sample_rate(), 48_000.0);
+    assert_eq!(reader.length_in_samples(), 3);
+
+    let buffer = reader.read(0, 3).unwrap();
+
+    assert_eq!(buffer.channels(), 2);
+    assert_eq!(buffer.samples(), 3);
+    assert_eq!(buffer[0], [0.0, -0.25, 1.0 - 1.0 / 32768.0]);
+    assert_eq!(buffer[1], [0.25, 0.5, -1.0]);
+}
+
+#[test]
+fn can_read_a_range_of_a_wav_stream_as_interleaved_samples() {
+    let wav = wav_file(44_100, 2, &[0, 0, 8192, -8192, 16384, -16384]);
+
+    let mut format = WavAudioFormat::new();
+    let mut reader = format.create_reader_for_stream(Cursor::new(wav)).unwrap();
+
+    assert_eq!(
+        reader.read_interleaved(1, 3).unwrap(),
+        [0.25, -0.25, 0.5, -0.5, 0.0, 0.0]
+    );
+}
+
+#[test]
+fn reading_past_the_end_of_a_buffer_is_an_error() {
+    let wav = wav_file(44_100, 1, &[0, 1, 2, 3]);
+
+    let mut format = WavAudioFormat::new();
+    let mut reader = format.create_reader_for_stream(Cursor::new(wav)).unwrap();
+    let mut buffer = AudioBuffer::new(1, 2);
+
+    assert!(reader.read_into(&mut buffer, 1, 0, 2).is_err());
+    assert!(reader.read_into(&mut buffer, usize::MAX, 0, 2).is_err());
+    assert!(reader.read_into(&mut buffer, 1, 0, usize::MAX).is_err());
+    assert!(reader
+        .read_into(&mut buffer, i32::MAX as usize, 0, 1)
+        .is_err());
+    assert!(reader
+        .read_channels_into(&mut buffer, usize::MAX, 0, 2, &[0], false)
+        .is_err());
+    assert!(reader
+        .read_channels_into(&mut buffer, 0, 0, i32::MAX as usize + 1, &[0], false)
+        .is_err());
+    assert!(reader.read_into(&mut buffer, 0, 2, 2).is_ok());
+    assert_eq!(buffer[0], [2.0 / 32768.0, 3.0 / 32768.0]);
+
+    // Buffers too large for JUCE are rejected before they're allocated.
+    assert!(reader.read(0, 1 << 31).is_err());
+    assert!(reader.read_channels(0, 1 << 31, &[0]).is_err());
+    assert!(reader.read_range_into(0..1 << 31, &mut buffer).is_err());
+    assert_eq!(buffer.samples(), 2);
+}
+
+#[test]
+fn can_write_a_wav_file_and_read_it_back() {
+    let path = std::env::temp_dir().join("cxx-juce-can-write-a-wav-file-and-read-it-back.wav");
+    let mut format = WavAudioFormat::new();
+
+    {
+        let mut writer = format
+            .create_writer_for_file(&path, 22_050.0, 2, 24)
+            .unwrap();
+
+        assert_eq!(writer.sample_rate(), 22_050.0);
+        assert_eq!(writer.num_channels(), 2);
+        assert_eq!(writer.bits_per_sample(), 24);
+
+        writer
+            .write_interleaved(&[0.0, 0.5, -0.5, 0.25, 0.125, -1.0])
+            .unwrap();
+
+        let mut buffer = AudioBuffer::new(2, 2);
+        buffer[0].copy_from_slice(&[0.75, -0.75]);
+        writer.write_buffer(&buffer).unwrap();
+    }
+
+    let mut reader = format.create_reader_for_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(reader.sample_rate(), 22_050.0);
+    assert_eq!(reader.bits_per_sample(), 24);
+    assert_eq!(reader.length_in_samples(), 5);
+
+    let buffer = reader.read(0, 5).unwrap();
+    assert_eq!(buffer[0], [0.0, -0.5, 0.125, 0.75, -0.75]);
+    assert_eq!(buffer[1], [0.5, 0.25, -1.0, 0.0, 0.0]);
+}
+
+#[test]
+fn writers_reject_partial_frames_and_out_of_range_samples() {
+    let path = std::env::temp_dir().join("cxx-juce-writers-reject-partial-frames.wav");
+    let mut format = WavAudioFormat::new();
+    let frames = 10_000;
+
+    {
+        let mut writer = format
+            .create_writer_for_file(&path, 44_100.0, 2, 16)
+            .unwrap();
+
+        assert!(writer.write_interleaved(&[0.0, 0.5, -0.5]).is_err());
+
+        let buffer = AudioBuffer::new(2, 4);
+        assert!(writer.write(&buffer, usize::MAX, 2).is_err());
+        assert!(writer.write(&buffer, 2, usize::MAX).is_err());
+        assert!(writer.write(&buffer, i32::MAX as usize, 1).is_err());
+
+        let samples: Vec<_> = (0..frames)
+            .flat_map(|frame| [0.5, if frame % 2 == 0 { 0.25 } else { -0.25 }])
+            .collect();
+        writer.write_interleaved(&samples).unwrap();
+    }
+
+    let mut reader = format.create_reader_for_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(reader.length_in_samples(), frames as u64);
+
+    let buffer = reader.read(frames as i64 - 2, 2).unwrap();
+    assert_eq!(buffer[0], [0.5, 0.5]);
+    assert_eq!(buffer[1], [0.25, -0.25]);
+}
+
+#[test]
+fn flushed_samples_can_be_read_while_the_writer_is_open() {
+    let path = std::env::temp_dir().join("cxx-juce-flushed-samples-can-be-read.wav");
+    std::fs::write(&path, b"an existing file").unwrap();
+    let mut format = WavAudioFormat::new();
+
+    let mut writer = format
+        .create_writer_for_file(&path, 44_100.0, 1, 16)
+        .unwrap();
+    writer.write_interleaved(&[0.5, -0.5, 0.25]).unwrap();
+    writer.flush().unwrap();
+
+    let mut reader = format.create_reader_for_file(&path).unwrap();
+    assert_eq!(reader.length_in_samples(), 3);
+    assert_eq!(reader.read(0, 3).unwrap()[0], [0.5, -0.5, 0.25]);
+
+    drop(reader);
+    drop(writer);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn writing_from_a_buffer_with_too_few_channels_is_an_error() {
+    let path = std::env::temp_dir().join("cxx-juce-writing-with-too-few-channels.wav");
+    let mut format = WavAudioFormat::new();
+
+    let mut writer = format
+        .create_writer_for_file(&path, 44_100.0, 2, 16)
+        .unwrap();
+
+    assert!(writer.write_buffer(&AudioBuffer::new(1, 16)).is_err());
+
+    drop(writer);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "flac")]
+#[test]
+fn can_write_a_flac_file_and_read_it_back() {
+    use cxx_juce::juce_audio_formats::FlacAudioFormat;
+
+    let path = std::env::temp_dir().join("cxx-juce-can-write-a-flac-file-and-read-it-back.flac");
+    let mut format = FlacAudioFormat::new();
+    let samples = [0.0, 0.5, -0.5, 0.25, 0.125, -1.0];
+
+    {
+        let mut writer = format
+            .create_writer_for_file(
+                &path,
+                44_100.0,
+                2,
+                16,
+                FlacAudioFormat::SMALLEST_COMPRESSION_LEVEL,
+            )
+            .unwrap();
+        writer.write_interleaved(&samples).unwrap();
+    }
+
+    let mut reader = format.create_reader_for_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(reader.format_name(), "FLAC file");
+    assert_eq!(reader.num_channels(), 2);
+    assert_eq!(reader.length_in_samples(), 3);
+    assert_eq!(reader.read_interleaved(0, 3).unwrap(), samples);
+}
+
+#[cfg(feature = "flac")]
+#[test]
+fn an_invalid_flac_compression_level_is_an_error() {
+    use cxx_juce::juce_audio_formats::FlacAudioFormat;
+
+    let path = std::env::temp_dir().join("cxx-juce-an-invalid-flac-compression-level.flac");
+    let mut format = FlacAudioFormat::new();
+
+    assert!(format
+        .create_writer_for_file(&path, 44_100.0, 2, 16, 9)
+        .is_err());
+    assert!(format
+        .create_writer_for_file(&path, 44_100.0, 2, 16, u32::MAX)
+        .is_err());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(feature = "ogg")]
+#[test]
+fn can_write_an_ogg_vorbis_file_and_read_it_back() {
+    use cxx_juce::juce_audio_formats::OggVorbisAudioFormat;
+
+    let path = std::env::temp_dir().join("cxx-juce-can-write-an-ogg-vorbis-file.ogg");
+    let mut format = OggVorbisAudioFormat::new();
+    let quality = format.quality_options().len() - 1;
+
+    let sine: Vec<f32> = (0..4410)
+        .map(|i| (i as f32 * 440.0 * std::f32::consts::TAU / 44_100.0).sin() * 0.5)
+        .collect();
+
+    {
+        let mut writer = format
+            .create_writer_for_file(&path, 44_100.0, 1, quality)
+            .unwrap();
+        writer.write_interleaved(&sine).unwrap();
+    }
+
+    let mut reader = format.create_reader_for_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(reader.format_name(), "Ogg-Vorbis file");
+    assert_eq!(reader.sample_rate(), 44_100.0);
+    assert_eq!(reader.num_channels(), 1);
+    assert_eq!(reader.length_in_samples(), sine.len() as u64);
+
+    let decoded = reader.read_interleaved(0, sine.len()).unwrap();
+    let peak = decoded
+        .iter()
+        .fold(0.0_f32, |peak, sample| peak.max(sample.abs()));
+    assert!((peak - 0.5).abs() < 0.05);
+}
+
+#[cfg(feature = "ogg")]
+#[test]
+fn an_invalid_ogg_vorbis_quality_is_an_error() {
+    use cxx_juce::juce_audio_formats::OggVorbisAudioFormat;
+
+    let path = std::env::temp_dir().join("cxx-juce-an-invalid-ogg-vorbis-quality.ogg");
+    let mut format = OggVorbisAudioFormat::new();
+    let quality = format.quality_options().len();
+
+    assert!(format
+        .create_writer_for_file(&path, 44_100.0, 2, quality)
+        .is_err());
+
+    // Would be option 0 if it were truncated to 32 bits.
+    assert!(format
+        .create_writer_for_file(&path, 44_100.0, 2, 1 << 32)
+        .is_err());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(feature = "mp3")]
+#[test]
+fn mp3_is_registered_as_a_basic_format() {
+    let manager = AudioFormatManager::with_basic_formats();
+
+    assert!(manager.wildcard_for_all_formats().contains("*.mp3"));
+}
+
+#[cfg(feature = "mp3")]
+#[test]
+fn returns_none_for_data_that_is_not_an_mp3() {
+    use cxx_juce::juce_audio_formats::MP3AudioFormat;
+
+    let mut format = MP3AudioFormat::new();
+    let wav = wav_file(44_100, 1, &[0; 64]);
+
+    assert!(format.create_reader_for_stream(Cursor::new(wav)).is_none());
+}
+
+#[cfg(feature = "lame")]
+#[test]
+fn creating_a_lame_encoder_without_an_executable_is_an_error() {
+    use cxx_juce::juce_audio_formats::LAMEEncoderAudioFormat;
+
+    assert!(LAMEEncoderAudioFormat::new("does-not-exist/lame").is_err());
+}
+
+#[test]
+fn can_write_an_aiff_file_with_metadata_and_read_it_back() {
+    use {
+        cxx_juce::juce_audio_formats::{AiffAudioFormat, AiffMetadata},
+        std::collections::HashMap,
+    };
+
+    let path = std::env::temp_dir().join("cxx-juce-can-write-an-aiff-file.aiff");
+    let mut format = AiffAudioFormat::new();
+    let metadata = HashMap::from([("MidiUnityNote".to_string(), "64".to_string())]);
+
+    {
+        let mut writer = format
+            .create_writer_for_file_with_metadata(&path, 44_100.0, 1, 16, &metadata)
+            .unwrap();
+        writer.write_interleaved(&[0.0, 0.5, -0.5, 0.25]).unwrap();
+    }
+
+    let mut reader = format.create_reader_for_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(reader.format_name(), "AIFF file");
+    assert_eq!(reader.length_in_samples(), 4);
+    assert_eq!(
+        reader.read_interleaved(0, 4).unwrap(),
+        [0.0, 0.5, -0.5, 0.25]
+    );
+    assert_eq!(
+        reader.metadata().get("MidiUnityNote").map(String::as_str),
+        Some("64")
+    );
+    assert_eq!(
+        AiffMetadata::from_metadata(&reader.metadata()),
+        AiffMetadata::default()
+    );
+}
+
+#[cfg(target_os = "macos")]
+#[test]
+fn core_audio_can_read_a_wav_stream() {
+    use cxx_juce::juce_audio_formats::CoreAudioFormat;
+
+    let mut format = CoreAudioFormat::new();
+    assert!(format
+        .file_extensions()
+        .iter()
+        .any(|extension| extension == ".m4a"));
+
+    let wav = wav_file(44_100, 1, &[0, 8192, -8192, 16384]);
+    let mut reader = format.create_reader_for_stream(Cursor::new(wav)).unwrap();
+
+    assert_eq!(reader.format_name(), "CoreAudio supported file");
+    assert_eq!(reader.length_in_samples(), 4);
+    assert_eq!(
+        reader.read_interleaved(0, 4).unwrap(),
+        [0.0, 0.25, -0.25, 0.5]
+    );
+}
+
+#[cfg(target_os = "windows")]
+#[test]
+fn windows_media_returns_none_for_unrecognised_data() {
+    use cxx_juce::juce_audio_formats::WindowsMediaAudioFormat;
+
+    let mut format = WindowsMediaAudioFormat::new();
+
+    assert!(format
+        .file_extensions()
+        .iter()
+        .any(|extension| extension == ".wma"));
+    assert!(format
+        .create_reader_for_stream(Cursor::new(vec![0_u8; 64]))
+        .is_none());
+}
+
+#[test]
+fn can_write_wav_metadata_and_read_it_back() {
+    use {
+        cxx_juce::juce_audio_formats::{
+            BwavMetadata, CuePoint, LoopType, SampleLoop, SamplerMetadata, WavMetadata,
+        },
+        std::collections::HashMap,
+    };
+
+    let path = std::env::temp_dir().join("cxx-juce-can-write-wav-metadata.wav");
+    let mut format = WavAudioFormat::new();
+
+    let wav_metadata = WavMetadata {
+        bwav: Some(BwavMetadata {
+            description: "Take 1".to_string(),
+            originator: "cxx-juce".to_string(),
+            origination_date: "2023-06-01".to_string(),
+            origination_time: "12:34:56".to_string(),
+            time_reference: 3_000_000_000,
+            ..BwavMetadata::default()
+        }),
+        cue_points: vec![
+            CuePoint {
+                identifier: 1,
+                offset: 2,
+                label: Some("Verse".to_string()),
+            },
+            CuePoint {
+                identifier: 2,
+                offset: 6,
+                label: None,
+            },
+        ],
+        sampler: Some(SamplerMetadata {
+            midi_unity_note: 64,
+            loops: vec![SampleLoop {
+                identifier: 0,
+                loop_type: LoopType::PingPong,
+                start: 1,
+                end: 7,
+                play_count: 0,
+            }],
+        }),
+    };
+
+    let mut metadata = wav_metadata.to_metadata();
+    metadata.insert(
+        WavAudioFormat::RIFF_INFO_TITLE.to_string(),
+        "Demo".to_string(),
+    );
+
+    {
+        let mut writer = format
+            .create_writer_for_file_with_metadata(&path, 48_000.0, 1, 16, &metadata)
+            .unwrap();
+        writer.write_interleaved(&[0.0; 8]).unwrap();
+    }
+
+    let reader = format.create_reader_for_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let read_metadata: HashMap<String, String> = reader.metadata();
+    assert_eq!(WavMetadata::from_metadata(&read_metadata), wav_metadata);
+    assert_eq!(
+        read_metadata
+            .get(WavAudioFormat::RIFF_INFO_TITLE)
+            .map(String::as_str),
+        Some("Demo")
+    );
+}
+
+#[test]
+fn can_read_from_a_memory_mapped_wav_file() {
+    let samples = [0, 8192, -8192, 16384, 0, 0, 8192, 16384];
+    let path = std::env::temp_dir().join("cxx-juce-can-read-from-a-memory-mapped-wav-file.wav");
+    std::fs::write(&path, wav_file(44_100, 2, &samples)).unwrap();
+
+    let mut format = WavAudioFormat::new();
+    let mut reader = format.create_memory_mapped_reader(&path).unwrap();
+
+    assert_eq!(reader.num_channels(), 2);
+    assert_eq!(reader.length_in_samples(), 4);
+    assert!(reader.mapped_section().is_empty());
+
+    let mut frame = [0.0; 2];
+    assert!(reader.sample(1, &mut frame).is_err());
+
+    assert!(reader.map_section_of_file(1..3));
+    assert_eq!(reader.mapped_section(), 1..3);
+    assert!(reader.num_bytes_used() > 0);
+
+    reader.touch_sample(1);
+    reader.sample(1, &mut frame).unwrap();
+    assert_eq!(frame, [-0.25, 0.5]);
+    assert!(reader.sample(3, &mut frame).is_err());
+    assert!(reader.sample(1, &mut [0.0; 1]).is_err());
+
+    assert!(reader.map_entire_file());
+    assert_eq!(reader.mapped_section(), 0..4);
+
+    let mut buffer = AudioBuffer::new(2, 4);
+    reader.read_into(&mut buffer, 0, 0, 4).unwrap();
+    assert_eq!(buffer[0], [0.0, -0.25, 0.0, 0.25]);
+    assert_eq!(buffer[1], [0.25, 0.5, 0.0, 0.5]);
+
+    drop(reader);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn can_read_through_a_buffering_reader() {
+    use {cxx_juce::juce_audio_formats::BufferingAudioReader, std::time::Duration};
+
+    let samples: Vec<i16> = (0..4096).map(|i| (i % 256) as i16 * 64).collect();
+    let wav = wav_file(44_100, 1, &samples);
+
+    let mut format = WavAudioFormat::new();
+    let source = format.create_reader_for_stream(Cursor::new(wav)).unwrap();
+    let mut reader = BufferingAudioReader::new(source, 1024);
+    reader.set_read_timeout(Duration::from_secs(5));
+
+    assert_eq!(reader.num_channels(), 1);
+    assert_eq!(reader.length_in_samples(), 4096);
+
+    let mut buffer = AudioBuffer::new(1, 256);
+    assert!(reader.read_buffered(&mut buffer, 0, 512, 256).unwrap());
+
+    let expected: Vec<f32> = samples[512..768]
+        .iter()
+        .map(|sample| *sample as f32 / 32768.0)
+        .collect();
+    assert_eq!(buffer[0], expected);
+
+    assert!(reader.read_buffered(&mut buffer, 1, 0, 256).is_err());
+    assert!(reader.read_buffered(&mut buffer, usize::MAX, 0, 2).is_err());
+    assert!(reader
+        .read_buffered(&mut buffer, i32::MAX as usize, 0, 1)
+        .is_err());
+}
+
+#[test]
+fn can_write_a_wav_file_on_a_background_thread() {
+    use cxx_juce::juce_audio_formats::ThreadedWriter;
+
+    let path =
+        std::env::temp_dir().join("cxx-juce-can-write-a-wav-file-on-a-background-thread.wav");
+    let mut format = WavAudioFormat::new();
+
+    {
+        let writer = format
+            .create_writer_for_file(&path, 44_100.0, 2, 16)
+            .unwrap();
+        let mut writer = ThreadedWriter::new(writer, 1024);
+
+        let mut buffer = AudioBuffer::new(2, 256);
+        buffer[0].fill(0.5);
+        buffer[1].fill(-0.5);
+
+        for _ in 0..2 {
+            assert!(writer.write(&buffer).unwrap());
+        }
+
+        assert!(!writer.write(&AudioBuffer::new(2, 2048)).unwrap());
+        assert_eq!(writer.overruns(), 1);
+        assert_eq!(writer.dropped_samples(), 2048);
+
+        assert!(writer.write(&AudioBuffer::new(1, 16)).is_err());
+    }
+
+    let mut reader = format.create_reader_for_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(reader.length_in_samples(), 512);
+
+    let buffer = reader.read(0, 512).unwrap();
+    assert!(buffer[0].iter().all(|sample| *sample == 0.5));
+    assert!(buffer[1].iter().all(|sample| *sample == -0.5));
+}
+
+#[test]
+fn threaded_writers_can_share_a_thread() {
+    use cxx_juce::{juce_audio_formats::ThreadedWriter, juce_core::TimeSliceThread};
+
+    let thread = TimeSliceThread::new("test writer thread");
+    let mut format = WavAudioFormat::new();
+    let paths: Vec<_> = (0..2)
+        .map(|i| {
+            std::env::temp_dir().join(format!(
+                "cxx-juce-threaded-writers-can-share-a-thread-{i}.wav"
+            ))
+        })
+        .collect();
+
+    {
+        let mut writers: Vec<_> = paths
+            .iter()
+            .map(|path| {
+                let writer = format
+                    .create_writer_for_file(path, 44_100.0, 1, 16)
+                    .unwrap();
+                ThreadedWriter::with_thread(writer, &thread, 1024)
+            })
+            .collect();
+        assert_eq!(thread.num_clients(), 2);
+
+        let mut buffer = AudioBuffer::new(1, 256);
+        buffer[0].fill(0.25);
+
+        for writer in &mut writers {
+            assert!(writer.write(&buffer).unwrap());
+        }
+    }
+
+    assert_eq!(thread.num_clients(), 0);
+
+    for path in &paths {
+        let reader = format.create_reader_for_file(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(reader.length_in_samples(), 256);
+    }
+}
+
+#[test]
+fn can_read_a_whole_file_into_vectors() {
+    use {cxx_juce::juce_audio_formats::read_file_to_vec, std::time::Duration};
+
+    let path = std::env::temp_dir().join("cxx-juce-can-read-a-whole-file-into-vectors.wav");
+    std::fs::write(&path, wav_file(8_000, 2, &[0, 8192, -8192, 16384])).unwrap();
+
+    let (channels, info) = read_file_to_vec(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(channels, [vec![0.0, -0.25], vec![0.25, 0.5]]);
+    assert_eq!(info.format_name, "WAV file");
+    assert_eq!(info.sample_rate, 8_000.0);
+    assert_eq!(info.num_channels, 2);
+    assert_eq!(info.length_in_samples, 2);
+    assert_eq!(info.bits_per_sample, 16);
+    assert!(info.duration().abs_diff(Duration::from_micros(250)) < Duration::from_nanos(1));
+
+    assert!(read_file_to_vec("does-not-exist.wav").is_none());
+}
+
+#[test]
+fn can_read_a_range_of_a_file_into_a_buffer() {
+    use cxx_juce::juce_audio_formats::read_file_range_into;
+
+    let path = std::env::temp_dir().join("cxx-juce-can-read-a-range-of-a-file-into-a-buffer.wav");
+    std::fs::write(&path, wav_file(8_000, 1, &[0, 8192, 16384, -8192])).unwrap();
+
+    let mut buffer = AudioBuffer::new(2, 16);
+    let info = read_file_range_into(&path, 1..5, &mut buffer).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(info.num_channels, 1);
+    assert_eq!(buffer.channels(), 1);
+    assert_eq!(buffer[0], [0.25, 0.5, -0.25, 0.0]);
+}
+
+#[test]
+fn can_resample_a_reader_to_a_different_sample_rate() {
+    use cxx_juce::juce_audio_formats::ResamplingAudioFormatReader;
+
+    let mut manager = AudioFormatManager::with_basic_formats();
+    let reader = manager
+        .create_reader_for_stream(Cursor::new(wav_file(8_000, 1, &[16384; 400])))
+        .unwrap();
+
+    let mut reader = ResamplingAudioFormatReader::new(reader, 16_000.0).unwrap();
+    assert_eq!(reader.sample_rate(), 16_000.0);
+    assert_eq!(reader.num_channels(), 1);
+    assert_eq!(reader.length_in_samples(), 800);
+
+    let buffer = reader.read(600).unwrap();
+    assert_eq!(reader.position(), 600);
+    assert!(buffer[0][100..]
+        .iter()
+        .all(|sample| (sample - 0.5).abs() < 0.01));
+
+    reader.set_position(0);
+    assert_eq!(reader.position(), 0);
+    assert!(reader.read_into(&mut AudioBuffer::new(0, 16)).is_err());
+}
+
+#[test]
+fn can_read_a_whole_file_at_a_different_sample_rate() {
+    use cxx_juce::juce_audio_formats::read_file_to_vec_at_sample_rate;
+
+    let path =
+        std::env::temp_dir().join("cxx-juce-can-read-a-whole-file-at-a-different-sample-rate.wav");
+    std::fs::write(&path, wav_file(16_000, 2, &[8192; 800])).unwrap();
+
+    let (channels, info) = read_file_to_vec_at_sample_rate(&path, 8_000.0).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(info.sample_rate, 16_000.0);
+    assert_eq!(info.length_in_samples, 400);
+    assert_eq!(channels.len(), 2);
+    assert!(channels.iter().all(|channel| channel.len() == 200));
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn can_stream_a_file_from_a_url() {
+    let wav = wav_file(8_000, 1, &[0, 8192, 16384, -8192]);
+    let url = common::serve_once(wav.clone(), Some(wav.len()));
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut reader = AudioFormatManager::with_basic_formats()
+        .create_reader_for_url(&url, move |progress| sender.send(progress).unwrap())
+        .unwrap();
+
+    assert_eq!(reader.sample_rate(), 8_000.0);
+    assert_eq!(reader.length_in_samples(), 4);
+    assert_eq!(reader.read(0, 4).unwrap()[0], [0.0, 0.25, 0.5, -0.25]);
+
+    let progress = receiver.iter().find(|progress| progress.finished).unwrap();
+    assert_eq!(progress.downloaded_bytes, 52);
+    assert_eq!(progress.total_bytes, Some(52));
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn can_stream_a_file_of_unknown_length_from_a_url() {
+    use {
+        cxx_juce::{juce_core::ProgressiveDownload, JUCE},
+        std::io::{ErrorKind, Seek, SeekFrom},
+    };
+
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let samples: Vec<i16> = (0..ProgressiveDownload::BUFFER_SIZE)
+        .map(|i| (i % 4) as i16 * 8192)
+        .collect();
+    let wav = wav_file(8_000, 1, &samples);
+
+    let mut download =
+        ProgressiveDownload::start(&common::serve_once(wav.clone(), None), |_| {}).unwrap();
+    assert_eq!(
+        download.seek(SeekFrom::End(0)).unwrap_err().kind(),
+        ErrorKind::Unsupported
+    );
+    drop(download);
+
+    // The stream is larger than the download buffer, so the reader mustn't wait for the end of it.
+    let mut reader = AudioFormatManager::with_basic_formats()
+        .create_reader_for_url(&common::serve_once(wav, None), |_| {})
+        .unwrap();
+    assert_eq!(reader.length_in_samples(), samples.len() as u64);
+
+    let end = samples.len() as i64 - 4;
+    assert_eq!(reader.read(end, 4).unwrap()[0], [0.0, 0.25, 0.5, 0.75]);
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn streaming_from_an_unreachable_url_fails() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/file.wav", listener.local_addr().unwrap());
+    drop(listener);
+
+    assert!(AudioFormatManager::with_basic_formats()
+        .create_reader_for_url(&url, |_| {})
+        .is_none());
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn progressive_downloads_only_buffer_recent_data() {
+    use {
+        cxx_juce::{juce_core::ProgressiveDownload, JUCE},
+        std::io::{Read, Seek, SeekFrom},
+    };
+
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let body: Vec<u8> = (0..ProgressiveDownload::BUFFER_SIZE * 3)
+        .map(|i| (i % 251) as u8)
+        .collect();
+    let url = common::serve_once(body.clone(), Some(body.len()));
+
+    let mut download = ProgressiveDownload::start(&url, |_| {}).unwrap();
+    let mut received = vec![];
+    download.read_to_end(&mut received).unwrap();
+    assert!(received == body);
+    assert!(download.progress().finished);
+    assert!(!download.progress().failed);
+
+    download.seek(SeekFrom::Start(0)).unwrap();
+    assert!(download.read(&mut [0; 16]).is_err());
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn dropping_a_progressive_download_cancels_it() {
+    use {
+        cxx_juce::{juce_core::ProgressiveDownload, JUCE},
+        std::{
+            sync::mpsc::{self, RecvTimeoutError},
+            time::Duration,
+        },
+    };
+
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let url = common::serve_once(vec![1; 16], Some(1024));
+
+    let (sender, receiver) = mpsc::channel();
+    let download = ProgressiveDownload::start(&url, move |progress| {
+        let _ = sender.send(progress);
+    })
+    .unwrap();
+
+    // The download thread drops the callback once the stalled read has been interrupted.
+    drop(download);
+    let disconnected = loop {
+        match receiver.recv_timeout(Duration::from_secs(5)) {
+            Ok(progress) => assert!(!progress.finished),
+            Err(error) => break error,
+        }
+    };
+    assert_eq!(disconnected, RecvTimeoutError::Disconnected);
+}
+
+#[test]
+fn can_read_from_a_custom_reader() {
+    use cxx_juce::{
+        juce_audio_devices::OutputAudioSampleBuffer,
+        juce_audio_formats::{AudioFormatReader, CustomAudioFormatReader},
+    };
+
+    struct Ramp;
+
+    impl CustomAudioFormatReader for Ramp {
+        fn format_name(&self) -> &str {
+            "Ramp"
+        }
+
+        fn sample_rate(&self) -> f64 {
+            100.0
+        }
+
+        fn num_channels(&self) -> usize {
+            2
+        }
+
+        fn length_in_samples(&self) -> u64 {
+            10
+        }
+
+        fn read_samples(
+            &mut self,
+            buffer: &mut OutputAudioSampleBuffer<'_>,
+            start_sample: u64,
+        ) -> bool {
+            for channel in 0..buffer.channels() {
+                for (offset, sample) in buffer[channel].iter_mut().enumerate() {
+                    let position = start_sample + offset as u64;
+                    if position < 10 {
+                        *sample = (channel as f32 + 1.0) * position as f32 / 10.0;
+                    }
+                }
+            }
+
+            true
+        }
+    }
+
+    let mut reader = AudioFormatReader::from_custom(Ramp);
+    assert_eq!(reader.format_name(), "Ramp");
+    assert_eq!(reader.sample_rate(), 100.0);
+    assert_eq!(reader.num_channels(), 2);
+    assert_eq!(reader.length_in_samples(), 10);
+    assert_eq!(reader.bits_per_sample(), 32);
+    assert!(reader.uses_floating_point_data());
+
+    let buffer = reader.read(8, 4).unwrap();
+    assert_eq!(buffer[0], [0.8, 0.9, 0.0, 0.0]);
+    assert_eq!(buffer[1], [1.6, 1.8, 0.0, 0.0]);
+}
+
+#[cfg(feature = "symphonia")]
+#[test]
+fn can_read_a_file_decoded_by_symphonia() {
+    use cxx_juce::interop::symphonia::SymphoniaReader;
+
+    let path = std::env::temp_dir().join("cxx-juce-can-read-a-file-decoded-by-symphonia.wav");
+    std::fs::write(
+        &path,
+        wav_file(8_000, 2, &[0, 8192, -8192, 16384, 16384, -16384]),
+    )
+    .unwrap();
+
+    let mut reader = SymphoniaReader::open(&path)
+        .unwrap()
+        .into_audio_format_reader();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(reader.sample_rate(), 8_000.0);
+    assert_eq!(reader.num_channels(), 2);
+    assert_eq!(reader.length_in_samples(), 3);
+
+    let buffer = reader.read(1, 2).unwrap();
+    assert_eq!(buffer[0], [-0.25, 0.5]);
+    assert_eq!(buffer[1], [0.5, -0.5]);
+
+    let buffer = reader.read(0, 1).unwrap();
+    assert_eq!(buffer[0], [0.0]);
+    assert_eq!(buffer[1], [0.25]);
+
+    let buffer = reader.read_symphonia(0, 3).unwrap();
+    assert_eq!(
+        cxx_juce::juce_audio_basics::AudioBuffer::from(
+            symphonia::core::audio::AudioBufferRef::F32(std::borrow::Cow::Borrowed(&buffer))
+        )[1],
+        [0.25, 0.5, -0.5]
+    );
+}
+
+#[cfg(feature = "symphonia")]
+#[test]
+fn gaps_between_symphonia_packets_are_read_as_silence() {
+    use {
+        cxx_juce::interop::symphonia::SymphoniaReader,
+        symphonia::core::{
+            audio::Channels,
+            codecs::{CodecParameters, CODEC_TYPE_PCM_F32LE},
+            errors::{Error, Result},
+            formats::{
+                Cue, FormatOptions, FormatReader, Packet, SeekMode, SeekTo, SeekedTo, Track,
+            },
+            io::MediaSourceStream,
+            meta::{Metadata, MetadataLog},
+        },
+    };
+
+    struct Packets {
+        tracks: Vec<Track>,
+        packets: Vec<Packet>,
+        next: usize,
+        metadata: MetadataLog,
+    }
+
+    impl FormatReader for Packets {
+        fn try_new(_: MediaSourceStream, _: &FormatOptions) -> Result<Self> {
+            unimplemented!()
+        }
+
+        fn cues(&self) -> &[Cue] {
+            &[]
+        }
+
+        fn metadata(&mut self) -> Metadata<'_> {
+            self.metadata.metadata()
+        }
+
+        fn seek(&mut self, _: SeekMode, to: SeekTo) -> Result<SeekedTo> {
+            let SeekTo::TimeStamp { ts, track_id } = to else {
+                unimplemented!()
+            };
+
+            self.next = 0;
+            Ok(SeekedTo {
+                track_id,
+                required_ts: ts,
+                actual_ts: ts,
+            })
+        }
+
+        fn tracks(&self) -> &[Track] {
+            &self.tracks
+        }
+
+        fn next_packet(&mut self) -> Result<Packet> {
+            let packet = self.packets.get(self.next).cloned();
+            self.next += 1;
+            packet.ok_or(Error::ResetRequired)
+        }
+
+        fn into_inner(self: Box<Self>) -> MediaSourceStream {
+            unimplemented!()
+        }
+    }
+
+    let packets = |n_frames: Option<u64>| {
+        let mut params = CodecParameters::new();
+        params
+            .for_codec(CODEC_TYPE_PCM_F32LE)
+            .with_sample_rate(8_000)
+            .with_channels(Channels::FRONT_LEFT)
+            .with_max_frames_per_packet(2);
+        if let Some(n_frames) = n_frames {
+            params.with_n_frames(n_frames);
+        }
+
+        let samples = [0.5_f32, 0.75].map(f32::to_le_bytes).concat();
+        Box::new(Packets {
+            tracks: vec![Track::new(0, params)],
+            packets: vec![
+                Packet::new_from_slice(0, 2, 2, &samples),
+                Packet::new_from_slice(0, 6, 2, &samples),
+            ],
+            next: 0,
+            metadata: MetadataLog::default(),
+        })
+    };
+
+    assert!(SymphoniaReader::new(packets(None)).is_none());
+
+    let mut reader = SymphoniaReader::new(packets(Some(8)))
+        .unwrap()
+        .into_audio_format_reader();
+
+    let buffer = reader.read(0, 8).unwrap();
+    assert_eq!(buffer[0], [0.0, 0.0, 0.5, 0.75, 0.0, 0.0, 0.5, 0.75]);
+
+    let buffer = reader.read(5, 2).unwrap();
+    assert_eq!(buffer[0], [0.0, 0.5]);
+}
+
+#[cfg(feature = "symphonia")]
+#[test]
+fn buffers_with_too_many_channels_for_symphonia_are_rejected() {
+    let channels = symphonia::core::audio::Channels::all().count();
+
+    assert!(AudioBuffer::new(channels, 4).to_symphonia(8_000).is_ok());
+    assert!(AudioBuffer::new(channels + 1, 4)
+        .to_symphonia(8_000)
+        .is_err());
+    assert!(AudioBuffer::new(64, 4).to_symphonia(8_000).is_err());
+}
+
+#[cfg(feature = "hound")]
+#[test]
+fn can_convert_between_hound_and_audio_buffers() {
+    let spec = hound::WavSpec {
+        channels: 2,
+        sample_rate: 8_000,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let mut buffer = AudioBuffer::new(2, 2);
+    buffer[0].copy_from_slice(&[0.5, -0.25]);
+    buffer[1].copy_from_slice(&[-1.0, 2.0]);
+
+    let mut wav = Cursor::new(Vec::new());
+    let mut writer = hound::WavWriter::new(&mut wav, spec).unwrap();
+    buffer.write_to_hound(&mut writer).unwrap();
+    assert!(AudioBuffer::new(1, 2).write_to_hound(&mut writer).is_err());
+    writer.finalize().unwrap();
+
+    wav.set_position(0);
+    let mut reader = hound::WavReader::new(wav).unwrap();
+    let buffer = AudioBuffer::from_hound(&mut reader).unwrap();
+
+    assert_eq!(buffer.channels(), 2);
+    assert_eq!(buffer[0], [0.5, -0.25]);
+    assert_eq!(buffer[1], [-1.0, 32767.0 / 32768.0]);
+}
+
+#[cfg(feature = "hound")]
+#[test]
+fn can_read_a_file_through_hound() {
+    use cxx_juce::interop::hound::HoundReader;
+
+    let wav = wav_file(8_000, 2, &[0, 8192, -8192, 16384, 16384, -16384]);
+    let mut reader = HoundReader::new(hound::WavReader::new(Cursor::new(wav)).unwrap())
+        .into_audio_format_reader();
+
+    assert_eq!(reader.format_name(), "WAV file");
+    assert_eq!(reader.sample_rate(), 8_000.0);
+    assert_eq!(reader.num_channels(), 2);
+    assert_eq!(reader.length_in_samples(), 3);
+    assert_eq!(reader.bits_per_sample(), 16);
+
+    let buffer = reader.read(1, 4).unwrap();
+    assert_eq!(buffer[0], [-0.25, 0.5, 0.0, 0.0]);
+    assert_eq!(buffer[1], [0.5, -0.5, 0.0, 0.0]);
+}
+
+#[test]
+fn can_transcode_between_formats() {
+    use cxx_juce::juce_audio_formats::{transcode, OutputFormat, OutputSpec};
+
+    let input = std::env::temp_dir().join("cxx-juce-can-transcode-between-formats.wav");
+    let output = std::env::temp_dir().join("cxx-juce-can-transcode-between-formats.aiff");
+    std::fs::write(&input, wav_file(8_000, 2, &[8192; 20_000])).unwrap();
+
+    let spec = OutputSpec::new(OutputFormat::Aiff).with_bits_per_sample(24);
+    assert_eq!(spec.format(), OutputFormat::Aiff);
+    assert_eq!(spec.sample_rate(), None);
+    assert_eq!(spec.bits_per_sample(), 24);
+
+    let mut progress = Vec::new();
+    transcode(&input, &output, &spec, |proportion| {
+        progress.push(proportion)
+    })
+    .unwrap();
+    assert_eq!(progress, [8192.0 / 10_000.0, 16384.0 / 10_000.0, 1.0]);
+
+    let mut reader = AudioFormatManager::with_basic_formats()
+        .create_reader_for_file(&output)
+        .unwrap();
+    assert_eq!(reader.format_name(), "AIFF file");
+    assert_eq!(reader.num_channels(), 2);
+    assert_eq!(reader.length_in_samples(), 10_000);
+    assert_eq!(reader.bits_per_sample(), 24);
+    assert!(reader.read(0, 10_000).unwrap()[1]
+        .iter()
+        .all(|sample| *sample == 0.25));
+    drop(reader);
+
+    let resampled =
+        std::env::temp_dir().join("cxx-juce-can-transcode-between-formats-resampled.wav");
+    let spec = OutputSpec::new(OutputFormat::Wav).with_sample_rate(16_000.0);
+    transcode(&input, &resampled, &spec, |_| {}).unwrap();
+
+    let reader = AudioFormatManager::with_basic_formats()
+        .create_reader_for_file(&resampled)
+        .unwrap();
+    assert_eq!(reader.format_name(), "WAV file");
+    assert_eq!(reader.sample_rate(), 16_000.0);
+    assert_eq!(reader.length_in_samples(), 20_000);
+    drop(reader);
+
+    std::fs::remove_file(&input).unwrap();
+    std::fs::remove_file(&output).unwrap();
+    std::fs::remove_file(&resampled).unwrap();
+
+    assert!(transcode("does-not-exist.wav", &output, &spec, |_| {}).is_err());
+    assert!(!output.exists());
+}
+
+#[test]
+fn can_read_a_subset_of_channels() {
+    let wav = wav_file(
+        8_000,
+        3,
+        &[0, 8192, 16384, -8192, -16384, 0, 8192, 8192, 8192],
+    );
+    let mut reader = AudioFormatManager::with_basic_formats()
+        .create_reader_for_stream(Cursor::new(wav))
+        .unwrap();
+
+    let buffer = reader.read_channels(1, 3, &[2, 0]).unwrap();
+    assert_eq!(buffer.channels(), 2);
+    assert_eq!(buffer[0], [0.0, 0.25, 0.0]);
+    assert_eq!(buffer[1], [-0.25, 0.25, 0.0]);
+
+    let buffer = reader.read_channels(1, 3, &[0, 2, 0]).unwrap();
+    assert_eq!(buffer[0], [-0.25, 0.25, 0.0]);
+    assert_eq!(buffer[1], [0.0, 0.25, 0.0]);
+    assert_eq!(buffer[2], [-0.25, 0.25, 0.0]);
+
+    let mut buffer = AudioBuffer::new(3, 4);
+    buffer[2].fill(1.0);
+    reader
+        .read_channels_into(&mut buffer, 1, 0, 2, &[1], true)
+        .unwrap();
+    assert_eq!(buffer[0], [0.0, 0.25, -0.5, 0.0]);
+    assert_eq!(buffer[1], [0.0, 0.25, -0.5, 0.0]);
+    assert_eq!(buffer[2], [1.0, 0.25, -0.5, 1.0]);
+
+    reader
+        .read_channels_into(&mut buffer, 0, 0, 4, &[1], false)
+        .unwrap();
+    assert_eq!(buffer[2], [0.0; 4]);
+
+    assert!(reader.read_channels(0, 1, &[3]).is_err());
+    assert!(reader.read_channels(0, 1, &[usize::MAX]).is_err());
+    assert!(reader
+        .read_channels_into(&mut AudioBuffer::new(1, 1), 0, 0, 1, &[0, 1], false)
+        .is_err());
+}
+
+#[test]
+fn can_register_a_custom_format() {
+    use {
+        cxx_juce::{
+            juce_audio_devices::{InputAudioSampleBuffer, OutputAudioSampleBuffer},
+            juce_audio_formats::{
+                AudioFormatWriterOptions, BoxedCustomAudioFormatReader,
+                BoxedCustomAudioFormatWriter, CustomAudioFormat, CustomAudioFormatReader,
+                CustomAudioFormatWriter,
+            },
+            juce_core::{JuceInputStream, JuceOutputStream},
+        },
+        std::io::Read,
+    };
+
+    // Interleaved 32-bit floats after a header with the sample rate and channel count.
+    struct RawFloatFormat;
+
+    struct RawFloatReader {
+        sample_rate: f64,
+        channels: Vec<Vec<f32>>,
+    }
+
+    struct RawFloatWriter(JuceOutputStream);
+
+    fn read_u32(stream: &mut impl Read) -> Option<u32> {
+        let mut bytes = [0; 4];
+        stream.read_exact(&mut bytes).ok()?;
+        Some(u32::from_le_bytes(bytes))
+    }
+
+    impl CustomAudioFormat for RawFloatFormat {
+        fn format_name(&self) -> &str {
+            "Raw float file"
+        }
+
+        fn file_extensions(&self) -> Vec<String> {
+            vec![".rawf".to_string()]
+        }
+
+        fn create_reader(
+            &mut self,
+            mut stream: JuceInputStream,
+        ) -> Option<BoxedCustomAudioFormatReader> {
+            let mut magic = [0; 4];
+            stream.read_exact(&mut magic).ok()?;
+            if &magic != b"RAWF" {
+                return None;
+            }
+
+            let sample_rate = read_u32(&mut stream)? as f64;
+            let num_channels = read_u32(&mut stream)? as usize;
+
+            let mut bytes = Vec::new();
+            stream.read_to_end(&mut bytes).ok()?;
+
+            let mut channels = vec![Vec::new(); num_channels];
+            for (index, sample) in bytes.chunks_exact(4).enumerate() {
+                channels[index % num_channels].push(f32::from_le_bytes(sample.try_into().unwrap()));
+            }
+
+            Some(Box::new(RawFloatReader {
+                sample_rate,
+                channels,
+            }))
+        }
+
+        fn create_writer(
+            &mut self,
+            mut stream: JuceOutputStream,
+            options: &AudioFormatWriterOptions,
+        ) -> Option<BoxedCustomAudioFormatWriter> {
+            if options.bits_per_sample != 32 {
+                return None;
+            }
+
+            stream.write_all(b"RAWF").ok()?;
+            stream
+                .write_all(&(options.sample_rate as u32).to_le_bytes())
+                .ok()?;
+            stream
+                .write_all(&(options.num_channels as u32).to_le_bytes())
+                .ok()?;
+
+            Some(Box::new(RawFloatWriter(stream)))
+        }
+    }
+
+    impl CustomAudioFormatReader for RawFloatReader {
+        fn format_name(&self) -> &str {
+            "Raw float file"
+        }
+
+        fn sample_rate(&self) -> f64 {
+            self.sample_rate
+        }
+
+        fn num_channels(&self) -> usize {
+            self.channels.len()
+        }
+
+        fn length_in_samples(&self) -> u64 {
+            self.channels.first().map_or(0, Vec::len) as u64
+        }
+
+        fn read_samples(
+            &mut self,
+            buffer: &mut OutputAudioSampleBuffer<'_>,
+            start_sample: u64,
+        ) -> bool {
+            for (channel, samples) in self.channels.iter().enumerate() {
+                let source = samples.iter().skip(start_sample as usize);
+                for (dest, sample) in buffer[channel].iter_mut().zip(source) {
+                    *dest = *sample;
+                }
+            }
+
+            true
+        }
+    }
+
+    impl CustomAudioFormatWriter for RawFloatWriter {
+        fn write(&mut self, buffer: &InputAudioSampleBuffer<'_>) -> bool {
+            (0..buffer.samples()).all(|sample| {
+                (0..buffer.channels()).all(|channel| {
+                    self.0
+                        .write_all(&buffer[channel][sample].to_le_bytes())
+                        .is_ok()
+                })
+            })
+        }
+    }
+
+    let mut manager = AudioFormatManager::with_basic_formats();
+    manager.register_format(RawFloatFormat, false);
+    assert!(manager
+        .known_format_names()
+        .contains(&"Raw float file".to_string()));
+    assert!(manager.wildcard_for_all_formats().contains("*.rawf"));
+
+    let path = std::env::temp_dir().join("cxx-juce-can-register-a-custom-format.rawf");
+    let mut buffer = AudioBuffer::new(2, 3);
+    buffer[0].copy_from_slice(&[0.1, 0.2, 0.3]);
+    buffer[1].copy_from_slice(&[-0.1, -0.2, -0.3]);
+
+    {
+        let mut writer = manager
+            .create_writer_for_file(&path, 22050.0, 2, 32)
+            .unwrap();
+        assert_eq!(writer.num_channels(), 2);
+        writer.write_buffer(&buffer).unwrap();
+    }
+
+    // A rejected writer must leave the existing file intact for the reads below.
+    assert!(manager
+        .create_writer_for_file(&path, 22050.0, 2, 16)
+        .is_err());
+    assert!(manager
+        .create_writer_for_file(path.with_extension("unknown"), 22050.0, 2, 32)
+        .is_err());
+
+    let mut reader = manager.create_reader_for_file(&path).unwrap();
+    assert_eq!(reader.format_name(), "Raw float file");
+    assert_eq!(reader.sample_rate(), 22050.0);
+    assert_eq!(reader.num_channels(), 2);
+    assert_eq!(reader.length_in_samples(), 3);
+
+    let read = reader.read(0, 3).unwrap();
+    assert_eq!(read[0], buffer[0]);
+    assert_eq!(read[1], buffer[1]);
+
+    // Streams that the custom format can't read are passed on to the other formats.
+    let mut manager = AudioFormatManager::new();
+    manager.register_format(RawFloatFormat, true);
+    manager.register_basic_formats();
+    let wav = wav_file(8_000, 1, &[0, 16384]);
+    let reader = manager.create_reader_for_stream(Cursor::new(wav)).unwrap();
+    assert_eq!(reader.format_name(), "WAV file");
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn streams_kept_by_a_custom_format_that_fails_to_open_them_are_detached() {
+    use {
+        cxx_juce::{
+            juce_audio_formats::{
+                AudioFormatWriterOptions, BoxedCustomAudioFormatReader,
+                BoxedCustomAudioFormatWriter, CustomAudioFormat,
+            },
+            juce_core::{JuceInputStream, JuceOutputStream},
+        },
+        std::{
+            io::Read,
+            sync::{Arc, Mutex},
+        },
+    };
+
+    #[derive(Default, Clone)]
+    struct KeepingFormat {
+        input: Arc<Mutex<Option<JuceInputStream>>>,
+        output: Arc<Mutex<Option<JuceOutputStream>>>,
+    }
+
+    impl CustomAudioFormat for KeepingFormat {
+        fn format_name(&self) -> &str {
+            "Keeping format"
+        }
+
+        fn file_extensions(&self) -> Vec<String> {
+            vec![".keep".to_string()]
+        }
+
+        fn create_reader(
+            &mut self,
+            stream: JuceInputStream,
+        ) -> Option<BoxedCustomAudioFormatReader> {
+            *self.input.lock().unwrap() = Some(stream);
+            None
+        }
+
+        fn create_writer(
+            &mut self,
+            stream: JuceOutputStream,
+            _options: &AudioFormatWriterOptions,
+        ) -> Option<BoxedCustomAudioFormatWriter> {
+            *self.output.lock().unwrap() = Some(stream);
+            None
+        }
+    }
+
+    let format = KeepingFormat::default();
+    let mut manager = AudioFormatManager::new();
+    manager.register_format(format.clone(), true);
+
+    // The manager deletes the stream once no format can read it.
+    assert!(manager
+        .create_reader_for_stream(Cursor::new(vec![0; 16]))
+        .is_none());
+    let mut input = format.input.lock().unwrap().take().unwrap();
+    assert!(input.read(&mut [0; 4]).is_err());
+    assert_eq!(input.total_length(), None);
+
+    let path = std::env::temp_dir().join("cxx-juce-streams-kept-by-a-custom-format.keep");
+    assert!(manager
+        .create_writer_for_file(&path, 8_000.0, 1, 16)
+        .is_err());
+    let mut output = format.output.lock().unwrap().take().unwrap();
+    assert!(output.write_all(&[0; 4]).is_err());
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,830 @@
+#![cfg(feature = "juce_audio_processors")]
+
+use cxx_juce::{
+    juce_audio_basics::{AudioBuffer, MidiBuffer},
+    juce_audio_devices::OutputAudioSampleBuffer,
+    juce_audio_formats::WavAudioFormat,
+    juce_audio_processors::{
+        AudioChannelSet, AudioGraphIOProcessorType, AudioParameter, AudioPlayHead,
+        AudioPluginFormatManager, AudioProcessor, AudioProcessorGraph, Connection, KnownPluginList,
+        NodeAndChannel, NormalisableRange, OfflineRenderer, OutOfProcessHost, OutOfProcessScanner,
+        ParameterListener, ParameterStore, PlayHeadPosition, PluginDescription,
+        PluginDirectoryScanner,
+    },
+    JUCE,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+#[test]
+fn registers_default_formats() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let manager = AudioPluginFormatManager::with_default_formats(&juce);
+
+    assert_eq!(manager.num_formats(), manager.format_names().len());
+    assert_eq!(
+        manager.format_names().iter().any(|name| name == "LV2"),
+        cfg!(feature = "lv2")
+    );
+    assert_eq!(
+        manager.format_names().iter().any(|name| name == "VST3"),
+        cfg!(feature = "vst3")
+    );
+    assert_eq!(
+        manager
+            .format_names()
+            .iter()
+            .any(|name| name == "AudioUnit"),
+        cfg!(target_os = "macos")
+    );
+}
+
+#[test]
+fn scanning_a_path_without_plugins_finds_nothing() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut manager = AudioPluginFormatManager::with_default_formats(&juce);
+
+    let path = std::env::temp_dir().join("cxx-juce-no-plugins-here");
+    assert!(manager.scan(&path, true).unwrap().is_empty());
+}
+
+#[test]
+fn creating_an_unknown_plugin_fails() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut manager = AudioPluginFormatManager::with_default_formats(&juce);
+
+    let description = PluginDescription {
+        name: "Missing".to_string(),
+        plugin_format_name: "Missing".to_string(),
+        file_or_identifier: "/path/to/missing.plugin".to_string(),
+        ..PluginDescription::default()
+    };
+
+    assert!(manager
+        .create_plugin_instance(&description, 44100.0, 512)
+        .is_err());
+}
+
+#[test]
+fn known_plugin_list_round_trips_through_xml() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut list = KnownPluginList::new(&juce);
+
+    let description = PluginDescription {
+        name: "Plugin".to_string(),
+        plugin_format_name: "VST3".to_string(),
+        manufacturer_name: "Manufacturer".to_string(),
+        version: "1.0.0".to_string(),
+        file_or_identifier: "/path/to/plugin.vst3".to_string(),
+        unique_id: 0x1234,
+        num_input_channels: 2,
+        num_output_channels: 2,
+        ..PluginDescription::default()
+    };
+
+    assert!(list.add_type(&description));
+    assert!(!list.add_type(&description));
+    assert_eq!(list.num_types(), 1);
+
+    list.add_to_blacklist("/path/to/crashing.vst3");
+
+    let mut restored = KnownPluginList::new(&juce);
+    restored.restore_from_xml(&list.to_xml()).unwrap();
+
+    let types = restored.types();
+    assert_eq!(types.len(), 1);
+    assert_eq!(types[0].name, description.name);
+    assert_eq!(types[0].file_or_identifier, description.file_or_identifier);
+    assert_eq!(types[0].unique_id, description.unique_id);
+    assert_eq!(restored.blacklisted_files(), ["/path/to/crashing.vst3"]);
+
+    assert!(restored.restore_from_xml("not xml").is_err());
+    assert_eq!(restored.num_types(), 1);
+
+    restored.remove_type(&description);
+    assert_eq!(restored.num_types(), 0);
+}
+
+#[test]
+fn scanner_requires_a_registered_format() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let manager = AudioPluginFormatManager::new(&juce);
+    let mut list = KnownPluginList::new(&juce);
+
+    assert!(PluginDirectoryScanner::new(
+        &mut list,
+        &manager,
+        "VST3",
+        &[std::env::temp_dir()],
+        true,
+        None,
+    )
+    .is_err());
+}
+
+#[cfg(feature = "vst3")]
+#[test]
+fn scanning_an_empty_directory_finds_nothing() {
+    use cxx_juce::juce_audio_processors::PluginScanResult;
+
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let manager = AudioPluginFormatManager::with_default_formats(&juce);
+    let mut list = KnownPluginList::new(&juce);
+
+    let path = std::env::temp_dir().join("cxx-juce-no-plugins-here");
+    let mut scanner =
+        PluginDirectoryScanner::new(&mut list, &manager, "VST3", &[path], true, None).unwrap();
+
+    let mut scanned = vec![];
+    scanner.scan(false, |name, _| scanned.push(name.to_string()));
+
+    assert!(scanned.is_empty());
+    assert!(scanner.failed_files().is_empty());
+    assert_eq!(scanner.scan_next_file(false), PluginScanResult::Finished);
+    drop(scanner);
+    assert_eq!(list.num_types(), 0);
+}
+
+#[cfg(feature = "vst3")]
+#[test]
+fn scanning_continues_past_plugins_already_in_the_list() {
+    use cxx_juce::juce_audio_processors::PluginScanResult;
+
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let manager = AudioPluginFormatManager::with_default_formats(&juce);
+    let mut list = KnownPluginList::new(&juce);
+
+    let directory = std::env::temp_dir().join("cxx-juce-rescan");
+    std::fs::create_dir_all(&directory).unwrap();
+    let paths = ["a", "b", "c", "d"].map(|name| {
+        directory
+            .join(format!("{name}.vst3"))
+            .to_string_lossy()
+            .into_owned()
+    });
+
+    // A listed file is up to date if it was modified when its description says it was, which is
+    // the epoch for descriptions that don't say.
+    for path in &paths {
+        std::fs::File::create(path)
+            .unwrap()
+            .set_modified(std::time::UNIX_EPOCH)
+            .unwrap();
+    }
+    for path in [&paths[0], &paths[2]] {
+        assert!(list.add_type(&PluginDescription {
+            name: "Listed".to_string(),
+            plugin_format_name: "VST3".to_string(),
+            file_or_identifier: path.clone(),
+            ..PluginDescription::default()
+        }));
+    }
+
+    let mut scanner =
+        PluginDirectoryScanner::new(&mut list, &manager, "VST3", &[&directory], true, None)
+            .unwrap();
+    let mut results = vec![];
+    loop {
+        match scanner.scan_next_file(true) {
+            PluginScanResult::Finished => break,
+            result => results.push(result),
+        }
+    }
+    assert_eq!(results.len(), 4);
+    assert_eq!(
+        results
+            .iter()
+            .filter(|result| **result == PluginScanResult::Skipped)
+            .count(),
+        2
+    );
+    let mut failed = scanner.failed_files();
+    failed.sort();
+    assert_eq!(failed, [paths[1].clone(), paths[3].clone()]);
+    drop(scanner);
+
+    let mut scanner =
+        PluginDirectoryScanner::new(&mut list, &manager, "VST3", &[&directory], true, None)
+            .unwrap();
+    let mut scanned = 0;
+    scanner.scan(true, |_, _| scanned += 1);
+    assert_eq!(scanned, 2);
+    assert_eq!(scanner.failed_files().len(), 2);
+    drop(scanner);
+    assert_eq!(list.num_types(), 2);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn test_process_is_not_a_scanner_child() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    assert!(OutOfProcessScanner::run_child_if_requested(&juce).is_none());
+}
+
+#[test]
+fn test_process_is_not_a_plugin_host_child() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    assert!(OutOfProcessHost::run_child_if_requested(&juce).is_none());
+}
+
+#[test]
+fn hosting_a_plugin_with_a_missing_program_is_an_error() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let host = OutOfProcessHost::new("/path/to/missing/host").with_timeout(Duration::from_secs(1));
+
+    assert!(host
+        .create_plugin_instance(&juce, &PluginDescription::default(), 44100.0, 512)
+        .is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn a_plugin_host_that_exits_is_reported_as_crashed() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let host = OutOfProcessHost::new("sh")
+        .with_args(["-c", "exit 1"])
+        .with_timeout(Duration::from_secs(5));
+
+    let error = host
+        .create_plugin_instance(&juce, &PluginDescription::default(), 44100.0, 512)
+        .err()
+        .unwrap();
+    assert_eq!(error.what(), "the plugin process has crashed");
+}
+
+#[cfg(feature = "vst3")]
+#[test]
+fn plugins_that_fail_to_scan_out_of_process_are_blacklisted() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let manager = AudioPluginFormatManager::with_default_formats(&juce);
+    let mut list = KnownPluginList::new(&juce);
+
+    let directory = std::env::temp_dir().join(format!(
+        "cxx-juce-out-of-process-scan-failure-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&directory).unwrap();
+    let plugin = directory.join("broken.vst3");
+    std::fs::write(&plugin, b"not a plugin").unwrap();
+
+    let scanner = OutOfProcessScanner::new("/path/to/missing/scanner")
+        .with_timeout(std::time::Duration::from_secs(1));
+    list.set_out_of_process_scanner(Some(&scanner)).unwrap();
+
+    PluginDirectoryScanner::new(&mut list, &manager, "VST3", &[&directory], true, None)
+        .unwrap()
+        .scan(false, |_, _| {});
+
+    assert_eq!(list.num_types(), 0);
+    assert!(list
+        .blacklisted_files()
+        .contains(&plugin.to_string_lossy().into_owned()));
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[cfg(all(unix, feature = "vst3"))]
+#[test]
+fn plugins_can_be_scanned_out_of_process() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let manager = AudioPluginFormatManager::with_default_formats(&juce);
+    let mut list = KnownPluginList::new(&juce);
+
+    let directory = std::env::temp_dir().join(format!(
+        "cxx-juce-out-of-process-scan-success-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&directory).unwrap();
+    let plugin = directory.join("plugin.vst3");
+    std::fs::write(&plugin, b"not a plugin").unwrap();
+
+    // Stands in for a child that loaded the plugin, writing its description to the results file.
+    let scanner = OutOfProcessScanner::new("sh")
+        .with_args([
+            "-c",
+            r#"printf '<PLUGINS><PLUGIN name="Scanned" format="%s" file="%s" uniqueId="4d2"/></PLUGINS>' "$1" "$2" > "$3""#,
+            "sh",
+        ])
+        .with_timeout(Duration::from_secs(5));
+    list.set_out_of_process_scanner(Some(&scanner)).unwrap();
+
+    PluginDirectoryScanner::new(&mut list, &manager, "VST3", &[&directory], true, None)
+        .unwrap()
+        .scan(false, |_, _| {});
+
+    let types = list.types();
+    assert_eq!(types.len(), 1);
+    assert_eq!(types[0].name, "Scanned");
+    assert_eq!(types[0].plugin_format_name, "VST3");
+    assert_eq!(types[0].file_or_identifier, plugin.to_string_lossy());
+    assert_eq!(types[0].unique_id, 0x4d2);
+    assert!(list.blacklisted_files().is_empty());
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn graphs_route_audio_between_nodes() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut graph = AudioProcessorGraph::new(&juce);
+
+    let input = graph.add_io_processor(AudioGraphIOProcessorType::AudioInput);
+    let output = graph.add_io_processor(AudioGraphIOProcessorType::AudioOutput);
+    assert_eq!(graph.num_nodes(), 2);
+    assert_eq!(graph.nodes(), [input, output]);
+    assert!(graph.node_name(input).is_some());
+
+    graph.prepare(2, 2, 44100.0, 8);
+
+    let left = Connection::new(
+        NodeAndChannel::audio(input, 0),
+        NodeAndChannel::audio(output, 0),
+    );
+    assert!(graph.can_connect(&left));
+    assert!(graph.add_connection(&left));
+    assert!(!graph.add_connection(&left));
+    assert!(graph.is_connected(&left));
+    assert_eq!(graph.connections(), [left]);
+
+    let mut buffer = AudioBuffer::new(2, 8);
+    buffer[0].fill(0.5);
+    buffer[1].fill(0.5);
+    graph
+        .process(
+            &mut OutputAudioSampleBuffer::from(&mut buffer),
+            &mut MidiBuffer::new(),
+        )
+        .unwrap();
+
+    assert_eq!(buffer[0], [0.5; 8]);
+    assert_eq!(buffer[1], [0.0; 8]);
+
+    let mut too_long = AudioBuffer::new(2, 16);
+    assert!(graph
+        .process(
+            &mut OutputAudioSampleBuffer::from(&mut too_long),
+            &mut MidiBuffer::new(),
+        )
+        .is_err());
+
+    assert!(graph.remove_connection(&left));
+    assert!(graph.connections().is_empty());
+
+    assert!(graph.remove_node(input));
+    assert!(!graph.remove_node(input));
+    assert_eq!(graph.node_name(input), None);
+
+    graph.clear();
+    assert_eq!(graph.num_nodes(), 0);
+}
+
+#[test]
+fn midi_connections_use_the_midi_channel_index() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut graph = AudioProcessorGraph::new(&juce);
+
+    let input = graph.add_io_processor(AudioGraphIOProcessorType::MidiInput);
+    let output = graph.add_io_processor(AudioGraphIOProcessorType::MidiOutput);
+
+    let midi = Connection::new(NodeAndChannel::midi(input), NodeAndChannel::midi(output));
+    assert!(midi.source.is_midi());
+    assert!(graph.add_connection(&midi));
+    assert!(!graph.can_connect(&Connection::new(
+        NodeAndChannel::midi(input),
+        NodeAndChannel::audio(output, 0),
+    )));
+
+    assert!(graph.disconnect_node(input));
+    assert!(!graph.is_connected(&midi));
+}
+
+#[cfg(feature = "juce_audio_utils")]
+#[test]
+fn players_own_their_processor() {
+    use cxx_juce::juce_audio_processors::AudioProcessorPlayer;
+
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut graph = AudioProcessorGraph::new(&juce);
+    let output = graph.add_io_processor(AudioGraphIOProcessorType::AudioOutput);
+
+    let mut player = AudioProcessorPlayer::new(graph);
+    assert_eq!(player.processor().nodes(), [output]);
+
+    let input = player
+        .with_processor(|graph| graph.add_io_processor(AudioGraphIOProcessorType::AudioInput));
+    assert_eq!(player.processor().nodes(), [output, input]);
+
+    let graph = player.into_processor();
+    assert_eq!(graph.num_nodes(), 2);
+}
+
+#[cfg(feature = "juce_audio_utils")]
+#[test]
+fn players_can_be_added_to_a_device_manager() {
+    use cxx_juce::{
+        juce_audio_devices::AudioDeviceManager, juce_audio_processors::AudioProcessorPlayer,
+    };
+
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut device_manager = AudioDeviceManager::new(&juce);
+
+    let graph = AudioProcessorGraph::new(&juce);
+    let mut handle = device_manager.add_audio_processor_player(AudioProcessorPlayer::new(graph));
+
+    handle
+        .player_mut()
+        .with_processor(|graph| graph.add_io_processor(AudioGraphIOProcessorType::MidiInput));
+
+    let player = handle.into_player();
+    assert_eq!(player.processor().num_nodes(), 1);
+}
+
+#[test]
+fn opening_an_editor_for_a_missing_node_fails() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut graph = AudioProcessorGraph::new(&juce);
+    let output = graph.add_io_processor(AudioGraphIOProcessorType::AudioOutput);
+    assert!(graph.remove_node(output));
+
+    assert!(graph.open_editor_window(output, "Editor", ()).is_err());
+}
+
+#[test]
+fn graphs_report_node_latency() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut graph = AudioProcessorGraph::new(&juce);
+    let input = graph.add_io_processor(AudioGraphIOProcessorType::AudioInput);
+    let output = graph.add_io_processor(AudioGraphIOProcessorType::AudioOutput);
+    assert!(graph.add_connection(&Connection::new(
+        NodeAndChannel::audio(input, 0),
+        NodeAndChannel::audio(output, 0),
+    )));
+
+    graph.prepare(2, 2, 44100.0, 8);
+    graph.rebuild();
+
+    assert_eq!(graph.node_latency_samples(input), Some(0));
+    assert_eq!(graph.latency_samples(), 0);
+
+    assert!(graph.remove_node(input));
+    assert_eq!(graph.node_latency_samples(input), None);
+}
+
+struct Transport;
+
+impl AudioPlayHead for Transport {
+    fn position(&self) -> Option<PlayHeadPosition> {
+        Some(PlayHeadPosition {
+            bpm: 90.0,
+            is_playing: true,
+            ..PlayHeadPosition::default()
+        })
+    }
+}
+
+#[test]
+fn graphs_can_replace_their_play_head() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut graph = AudioProcessorGraph::new(&juce);
+    graph.add_io_processor(AudioGraphIOProcessorType::AudioOutput);
+    graph.prepare(0, 2, 44100.0, 8);
+
+    graph.set_play_head(Transport);
+    graph.set_play_head(Transport);
+
+    let mut buffer = AudioBuffer::new(2, 8);
+    graph
+        .process(
+            &mut OutputAudioSampleBuffer::from(&mut buffer),
+            &mut MidiBuffer::new(),
+        )
+        .unwrap();
+
+    graph.clear_play_head();
+}
+
+#[test]
+fn channel_sets_describe_their_channels() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    assert_eq!(AudioChannelSet::mono().size(), 1);
+    assert_eq!(AudioChannelSet::stereo().size(), 2);
+    assert_eq!(AudioChannelSet::stereo().speaker_arrangement(), "L R");
+    assert_eq!(AudioChannelSet::canonical(2), AudioChannelSet::stereo());
+    assert_eq!(AudioChannelSet::discrete(3).size(), 3);
+    assert!(AudioChannelSet::disabled().is_disabled());
+}
+
+struct Gain {
+    gain: AudioParameter,
+}
+
+impl AudioProcessor for Gain {
+    fn name(&self) -> String {
+        "Gain".to_string()
+    }
+
+    fn num_input_channels(&self) -> usize {
+        2
+    }
+
+    fn num_output_channels(&self) -> usize {
+        2
+    }
+
+    fn parameters(&self) -> Vec<AudioParameter> {
+        vec![self.gain.clone()]
+    }
+
+    fn prepare_to_play(&mut self, _sample_rate: f64, _maximum_block_size: usize) {}
+
+    fn process_block(&mut self, buffer: &mut OutputAudioSampleBuffer<'_>, _midi: &mut MidiBuffer) {
+        for channel in 0..buffer.channels() {
+            for sample in buffer[channel].iter_mut() {
+                *sample *= self.gain.value();
+            }
+        }
+    }
+
+    fn state(&self) -> Vec<u8> {
+        self.gain.value().to_le_bytes().to_vec()
+    }
+
+    fn set_state(&mut self, state: &[u8]) {
+        if let Ok(bytes) = state.try_into() {
+            self.gain.set_value(f32::from_le_bytes(bytes));
+        }
+    }
+}
+
+#[test]
+fn rust_processors_can_be_added_to_graphs() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut graph = AudioProcessorGraph::new(&juce);
+
+    let gain = AudioParameter::new("gain", "Gain", 0.5);
+    let input = graph.add_io_processor(AudioGraphIOProcessorType::AudioInput);
+    let processor = graph.add_processor(Gain { gain: gain.clone() });
+    let output = graph.add_io_processor(AudioGraphIOProcessorType::AudioOutput);
+    assert_eq!(graph.node_name(processor).as_deref(), Some("Gain"));
+    assert!(graph.node_parameter_handle(processor).is_none());
+
+    for channel in 0..2 {
+        assert!(graph.add_connection(&Connection::new(
+            NodeAndChannel::audio(input, channel),
+            NodeAndChannel::audio(processor, channel),
+        )));
+        assert!(graph.add_connection(&Connection::new(
+            NodeAndChannel::audio(processor, channel),
+            NodeAndChannel::audio(output, channel),
+        )));
+    }
+
+    graph.prepare(2, 2, 44100.0, 8);
+
+    let mut buffer = AudioBuffer::new(2, 8);
+    buffer[0].fill(1.0);
+    buffer[1].fill(1.0);
+    graph
+        .process(
+            &mut OutputAudioSampleBuffer::from(&mut buffer),
+            &mut MidiBuffer::new(),
+        )
+        .unwrap();
+
+    assert_eq!(buffer[0], [0.5; 8]);
+    assert_eq!(buffer[1], [0.5; 8]);
+
+    gain.set_value(2.0);
+    assert_eq!(gain.value(), 1.0);
+}
+
+#[test]
+fn graph_nodes_save_and_restore_their_state() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut graph = AudioProcessorGraph::new(&juce);
+
+    let gain = AudioParameter::new("gain", "Gain", 0.25);
+    let node = graph.add_processor(Gain { gain: gain.clone() });
+
+    let state = graph.node_state(node).unwrap();
+    assert_eq!(state, 0.25_f32.to_le_bytes());
+
+    gain.set_value(1.0);
+    graph.set_node_state(node, &state).unwrap();
+    assert_eq!(gain.value(), 0.25);
+
+    assert!(graph.remove_node(node));
+    assert!(graph.node_state(node).is_err());
+    assert!(graph.set_node_state(node, &state).is_err());
+}
+
+#[test]
+fn graphs_can_render_files_offline() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let mut graph = AudioProcessorGraph::new(&juce);
+
+    let input = graph.add_io_processor(AudioGraphIOProcessorType::AudioInput);
+    let processor = graph.add_processor(Gain {
+        gain: AudioParameter::new("gain", "Gain", 0.5),
+    });
+    let output = graph.add_io_processor(AudioGraphIOProcessorType::AudioOutput);
+
+    for channel in 0..2 {
+        assert!(graph.add_connection(&Connection::new(
+            NodeAndChannel::audio(input, channel),
+            NodeAndChannel::audio(processor, channel),
+        )));
+        assert!(graph.add_connection(&Connection::new(
+            NodeAndChannel::audio(processor, channel),
+            NodeAndChannel::audio(output, channel),
+        )));
+    }
+
+    let source = std::env::temp_dir().join("cxx-juce-graphs-can-render-files-offline-source.wav");
+    let destination = std::env::temp_dir().join("cxx-juce-graphs-can-render-files-offline.wav");
+    let mut format = WavAudioFormat::new();
+
+    format
+        .create_writer_for_file(&source, 44_100.0, 2, 16)
+        .unwrap()
+        .write_interleaved(&[0.5; 20])
+        .unwrap();
+
+    let mut progress = vec![];
+    {
+        let mut reader = format.create_reader_for_file(&source).unwrap();
+        let mut writer = format
+            .create_writer_for_file(&destination, 44_100.0, 2, 16)
+            .unwrap();
+
+        OfflineRenderer::new()
+            .with_block_size(4)
+            .with_tail_length(Duration::ZERO)
+            .render(&mut graph, &mut reader, &mut writer, |fraction| {
+                progress.push(fraction)
+            })
+            .unwrap();
+    }
+
+    assert!(!graph.is_non_realtime());
+    assert_eq!(progress, [0.4, 0.8, 1.0]);
+
+    let mut reader = format.create_reader_for_file(&destination).unwrap();
+    std::fs::remove_file(&source).unwrap();
+    std::fs::remove_file(&destination).unwrap();
+
+    assert_eq!(reader.length_in_samples(), 10);
+    let buffer = reader.read(0, 10).unwrap();
+    assert_eq!(buffer[0], [0.25; 10]);
+    assert_eq!(buffer[1], [0.25; 10]);
+}
+
+#[test]
+fn ranges_map_between_plain_and_normalised_values() {
+    let range = NormalisableRange::new(-60.0, 0.0).with_interval(0.5);
+    assert_eq!(range.to_normalised(-30.0), 0.5);
+    assert_eq!(range.from_normalised(0.5), -30.0);
+    assert_eq!(range.snap(-30.2), -30.0);
+    assert_eq!(range.snap(10.0), 0.0);
+
+    let skewed = NormalisableRange::new(20.0, 20000.0).with_centre(1000.0);
+    assert!((skewed.from_normalised(0.5) - 1000.0).abs() < 0.1);
+
+    assert_eq!(skewed.to_normalised(f32::NAN), 0.0);
+    assert_eq!(skewed.from_normalised(f32::NAN), 20.0);
+}
+
+#[test]
+#[should_panic]
+fn ranges_cannot_be_centred_outside_themselves() {
+    NormalisableRange::new(20.0, 20000.0).with_centre(20.0);
+}
+
+#[test]
+fn parameter_listeners_can_change_while_the_value_is_being_set() {
+    let parameter = AudioParameter::new("mix", "Mix", 0.0);
+    let changes = Arc::new(Mutex::new(vec![]));
+    let stop = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+    let setter = std::thread::spawn({
+        let parameter = parameter.clone();
+        let stop = stop.clone();
+        move || {
+            let mut value = 0.0;
+            while !stop.load(std::sync::atomic::Ordering::Relaxed) {
+                value = 1.0 - value;
+                parameter.set_value(value);
+            }
+        }
+    });
+
+    for _ in 0..100 {
+        let listener = parameter.add_listener(RecordingListener(changes.clone()));
+        assert!(parameter.remove_listener(listener));
+    }
+
+    stop.store(true, std::sync::atomic::Ordering::Relaxed);
+    setter.join().unwrap();
+
+    parameter.set_value(0.5);
+    assert!(changes.lock().unwrap().iter().all(|(id, _)| id == "mix"));
+}
+
+struct RecordingListener(Arc<Mutex<Vec<(String, f32)>>>);
+
+impl ParameterListener for RecordingListener {
+    fn parameter_changed(&self, id: &str, value: f32) {
+        self.0.lock().unwrap().push((id.to_string(), value));
+    }
+}
+
+#[test]
+fn parameter_stores_notify_listeners_and_round_trip_through_xml() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    let store = ParameterStore::new("PARAMETERS")
+        .with(
+            AudioParameter::float(
+                "gain",
+                "Gain",
+                NormalisableRange::new(-60.0, 0.0).with_interval(1.0),
+                -6.0,
+            )
+            .with_label("dB"),
+        )
+        .with(AudioParameter::choice(
+            "mode",
+            "Mode",
+            &["Clean", "Warm", "Hot"],
+            1,
+        ))
+        .with(AudioParameter::boolean("bypass", "Bypass", false));
+
+    assert_eq!(store.value("gain"), Some(-6.0));
+    assert_eq!(store.value("missing"), None);
+
+    let mode = store.parameter("mode").unwrap();
+    assert_eq!(mode.text_for_value(mode.normalised_value()), "Warm");
+    assert_eq!(mode.value_for_text("Hot"), Some(1.0));
+
+    let changes = Arc::new(Mutex::new(vec![]));
+    let listener = store
+        .add_listener("gain", RecordingListener(changes.clone()))
+        .unwrap();
+
+    let saved = store.to_xml();
+    assert!(store.set_value("gain", -12.0));
+    assert!(store.set_value("bypass", 1.0));
+    assert!(!store.set_value("missing", 1.0));
+
+    store.restore_from_xml(&saved).unwrap();
+    assert_eq!(store.value("gain"), Some(-6.0));
+    assert_eq!(store.value("bypass"), Some(0.0));
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [("gain".to_string(), -12.0), ("gain".to_string(), -6.0)]
+    );
+
+    assert!(store.restore_from_xml("<OTHER/>").is_err());
+    assert!(store.remove_listener("gain", listener));
+    assert!(!store.remove_listener("gain", listener));
+}
+
+struct GestureListener(Arc<Mutex<Vec<String>>>);
+
+impl ParameterListener for GestureListener {
+    fn parameter_changed(&self, id: &str, value: f32) {
+        self.0.lock().unwrap().push(format!("{id} = {value}"));
+    }
+
+    fn gesture_began(&self, id: &str) {
+        self.0.lock().unwrap().push(format!("{id} began"));
+    }
+
+    fn gesture_ended(&self, id: &str) {
+        self.0.lock().unwrap().push(format!("{id} ended"));
+    }
+}
+
+#[test]
+fn parameter_gestures_reach_listeners() {
+    let store = ParameterStore::new("PARAMETERS").with(AudioParameter::new("mix", "Mix", 0.0));
+
+    let events = Arc::new(Mutex::new(vec![]));
+    store.add_listener("mix", GestureListener(events.clone()));
+
+    let mix = store.parameter("mix").unwrap();
+    assert!(store.begin_change_gesture("mix"));
+    mix.set_value_notifying_host(0.5);
+    assert!(store.end_change_gesture("mix"));
+    assert!(!store.begin_change_gesture("missing"));
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        ["mix began", "mix = 0.5", "mix ended"]
+    );
+}
@@ -0,0 +1,288 @@
+#![cfg(feature = "juce_audio_utils")]
+
+use {
+    cxx_juce::{
+        juce_audio_basics::AudioBuffer,
+        juce_audio_devices::{AudioIODevice, AudioIODeviceCallback},
+        juce_audio_formats::{read_file_to_vec, WavAudioFormat},
+        juce_audio_utils::{AudioFilePlayer, AudioRecorder, AudioThumbnail, AudioThumbnailCache},
+        Result, JUCE,
+    },
+    std::time::{Duration, Instant},
+};
+
+#[test]
+fn can_add_blocks_to_a_thumbnail() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+
+    let cache = AudioThumbnailCache::new(4);
+    let mut thumbnail = AudioThumbnail::new(&juce, 4, &cache);
+    thumbnail.reset(1, 16.0, 16);
+
+    let mut buffer = AudioBuffer::new(1, 16);
+    buffer[0][..8].fill(0.5);
+    buffer[0][8..].fill(-0.25);
+    thumbnail.add_block(0, &buffer).unwrap();
+
+    assert_eq!(thumbnail.num_channels(), 1);
+    assert_eq!(thumbnail.total_length(), Duration::from_secs(1));
+    assert_eq!(thumbnail.num_samples_finished(), 16);
+    assert!(thumbnail.is_fully_loaded());
+    assert!((thumbnail.approximate_peak() - 0.5).abs() < 0.01);
+
+    let peaks = thumbnail
+        .peaks(0, Duration::ZERO..Duration::from_secs(1), 2)
+        .unwrap();
+    assert_eq!(peaks.len(), 2);
+    assert!((peaks[0].1 - 0.5).abs() < 0.01);
+    assert!((peaks[1].0 + 0.25).abs() < 0.01);
+
+    assert!(thumbnail
+        .peaks(1, Duration::ZERO..Duration::from_secs(1), 2)
+        .is_err());
+    assert!(thumbnail.add_block(16, &AudioBuffer::new(0, 4)).is_err());
+}
+
+#[test]
+fn can_scan_a_file_in_the_background() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+
+    let path = std::env::temp_dir().join("cxx-juce-can-scan-a-file-in-the-background.wav");
+    {
+        let mut writer = WavAudioFormat::new()
+            .create_writer_for_file(&path, 8_000.0, 2, 16)
+            .unwrap();
+        let mut buffer = AudioBuffer::new(2, 8_000);
+        buffer[0].fill(0.5);
+        buffer[1].fill(-0.5);
+        writer.write_buffer(&buffer).unwrap();
+    }
+
+    let cache = AudioThumbnailCache::new(4);
+    let mut thumbnail = AudioThumbnail::new(&juce, 512, &cache);
+    assert!(thumbnail.set_source_file(&path));
+
+    let start = Instant::now();
+    while !thumbnail.is_fully_loaded() && start.elapsed() < Duration::from_secs(5) {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(thumbnail.is_fully_loaded());
+    assert_eq!(thumbnail.num_channels(), 2);
+    assert_eq!(thumbnail.total_length(), Duration::from_secs(1));
+    assert_eq!(thumbnail.proportion_complete(), 1.0);
+
+    let peaks = thumbnail
+        .peaks(1, Duration::ZERO..Duration::from_secs(1), 4)
+        .unwrap();
+    assert!(peaks.iter().all(|(min, _)| (min + 0.5).abs() < 0.01));
+
+    thumbnail.clear();
+    assert_eq!(thumbnail.num_channels(), 0);
+    assert!(!thumbnail.set_source_file("does-not-exist.wav"));
+}
+
+struct MockInputDevice;
+
+impl AudioIODevice for MockInputDevice {
+    fn name(&self) -> &str {
+        "Microphone"
+    }
+
+    fn type_name(&self) -> &str {
+        "Test"
+    }
+
+    fn sample_rate(&mut self) -> f64 {
+        1_000.0
+    }
+
+    fn buffer_size(&mut self) -> usize {
+        8
+    }
+
+    fn available_sample_rates(&mut self) -> Vec<f64> {
+        vec![1_000.0]
+    }
+
+    fn available_buffer_sizes(&mut self) -> Vec<usize> {
+        vec![8]
+    }
+
+    fn open(&mut self, _sample_rate: f64, _buffer_size: usize) -> Result<()> {
+        Ok(())
+    }
+
+    fn close(&mut self) {}
+
+    fn input_channels(&self) -> i32 {
+        1
+    }
+
+    fn output_channels(&self) -> i32 {
+        0
+    }
+}
+
+#[test]
+fn can_record_the_input_of_a_device() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    let recorder = AudioRecorder::new(Duration::from_millis(4));
+    let mut callback = recorder.callback();
+    callback.about_to_start(&mut MockInputDevice);
+
+    let mut process = |value: f32| {
+        let mut input = AudioBuffer::new(1, 8);
+        input[0].fill(value);
+        let mut output = AudioBuffer::new(0, 8);
+        callback.process_block(&(&input).into(), &mut (&mut output).into());
+    };
+
+    process(0.25);
+    assert_eq!(recorder.peak_levels(), [0.25]);
+    assert_eq!(recorder.peak_levels(), [0.0]);
+    assert!(!recorder.is_recording());
+
+    let path = std::env::temp_dir().join("cxx-juce-can-record-the-input-of-a-device.wav");
+    let writer = WavAudioFormat::new()
+        .create_writer_for_file(&path, 1_000.0, 1, 16)
+        .unwrap();
+    recorder.start(writer).unwrap();
+    assert!(recorder.is_recording());
+    assert_eq!(recorder.recorded_samples(), 4);
+
+    process(0.5);
+    recorder.pause();
+    assert!(recorder.is_paused());
+    process(1.0);
+    recorder.resume();
+    process(-0.5);
+
+    assert_eq!(recorder.recorded_samples(), 20);
+    assert_eq!(recorder.peak_levels(), [1.0]);
+    assert_eq!(recorder.dropouts(), 0);
+    assert_eq!(recorder.dropped_samples(), 0);
+
+    recorder.stop();
+    assert!(!recorder.is_recording());
+
+    let (channels, info) = read_file_to_vec(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(info.length_in_samples, 20);
+    assert_eq!(channels[0][..4], [0.25; 4]);
+    assert_eq!(channels[0][4..12], [0.5; 8]);
+    assert_eq!(channels[0][12..], [-0.5; 8]);
+}
+
+#[test]
+fn pre_roll_is_kept_when_a_recording_fails_to_start() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    let recorder = AudioRecorder::new(Duration::from_millis(4));
+    let mut callback = recorder.callback();
+    callback.about_to_start(&mut MockInputDevice);
+
+    let mut input = AudioBuffer::new(1, 8);
+    input[0].fill(0.25);
+    let mut output = AudioBuffer::new(0, 8);
+    callback.process_block(&(&input).into(), &mut (&mut output).into());
+
+    let path = std::env::temp_dir().join("cxx-juce-pre-roll-is-kept-when-a-recording-fails.wav");
+    let stereo = WavAudioFormat::new()
+        .create_writer_for_file(&path, 1_000.0, 2, 16)
+        .unwrap();
+    assert!(recorder.start(stereo).is_err());
+    assert!(!recorder.is_recording());
+
+    let mono = WavAudioFormat::new()
+        .create_writer_for_file(&path, 1_000.0, 1, 16)
+        .unwrap();
+    recorder.start(mono).unwrap();
+    assert_eq!(recorder.recorded_samples(), 4);
+    recorder.stop();
+
+    let (channels, _) = read_file_to_vec(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(channels[0], [0.25; 4]);
+}
+
+#[test]
+fn can_play_a_file() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    let path = std::env::temp_dir().join("cxx-juce-can-play-a-file.wav");
+    {
+        let mut writer = WavAudioFormat::new()
+            .create_writer_for_file(&path, 1_000.0, 1, 16)
+            .unwrap();
+        let mut buffer = AudioBuffer::new(1, 32);
+        buffer[0].fill(0.5);
+        writer.write_buffer(&buffer).unwrap();
+    }
+
+    let player = AudioFilePlayer::new();
+    assert!(!player.load("does-not-exist.wav"));
+
+    let mut callback = player.callback();
+    callback.about_to_start(&mut MockInputDevice);
+
+    assert!(player.load(&path));
+    std::fs::remove_file(&path).unwrap();
+    assert!(player.length().abs_diff(Duration::from_millis(32)) < Duration::from_micros(1));
+
+    let finished = Arc::new(AtomicUsize::new(0));
+    player.on_finished({
+        let finished = Arc::clone(&finished);
+        move || {
+            finished.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+
+    player.set_gain(0.5);
+    assert_eq!(player.gain(), 0.5);
+
+    player.play();
+    assert!(player.is_playing());
+
+    let input = AudioBuffer::new(0, 8);
+    let mut peak = 0.0_f32;
+    for _ in 0..16 {
+        let mut output = AudioBuffer::new(1, 8);
+        callback.process_block(&(&input).into(), &mut (&mut output).into());
+        peak = output[0]
+            .iter()
+            .fold(peak, |peak, sample| peak.max(sample.abs()));
+    }
+
+    assert!(!player.is_playing());
+    assert_eq!(finished.load(Ordering::Relaxed), 1);
+    assert!(peak > 0.0 && peak <= 0.5 + f32::EPSILON);
+
+    player.seek(Duration::from_millis(16));
+    assert!(player.position().abs_diff(Duration::from_millis(16)) < Duration::from_micros(1));
+
+    callback.stopped();
+}
+
+#[test]
+fn the_pairing_dialogue_can_only_be_opened_on_the_message_thread() {
+    use cxx_juce::juce_audio_utils::BluetoothMidiDevicePairingDialogue;
+
+    let juce = JUCE::wait_to_initialise_in_test_context();
+
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| assert!(BluetoothMidiDevicePairingDialogue::open(&juce, || {}).is_err()))
+            .join()
+            .unwrap();
+    });
+}
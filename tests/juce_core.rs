@@ -0,0 +1,653 @@
+use cxx_juce::{
+    juce_core::{
+        self, Base64, ChildFileType, DynamicObject, File, FileLogger, Json, MemoryBlock, Random,
+        SpecialLocation, SystemStats, ThreadPool, Time, TimeSliceThread, Url, Uuid, Var,
+        XmlElement, XmlTextFormat, ZipFile, ZipFileBuilder,
+    },
+    JUCE,
+};
+use std::{
+    sync::{mpsc, Arc, Mutex},
+    time::Duration,
+};
+
+#[test]
+fn log_messages_are_sent_to_the_current_logger() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let messages = Arc::new(Mutex::new(vec![]));
+
+    let received = messages.clone();
+    juce_core::set_logger(move |message: &str| {
+        received.lock().unwrap().push(message.to_string());
+    });
+    juce_core::write_to_log("hello from Rust");
+    juce_core::clear_logger();
+    juce_core::write_to_log("not received");
+
+    assert_eq!(*messages.lock().unwrap(), ["hello from Rust"]);
+}
+
+#[test]
+fn file_loggers_write_messages_to_their_file() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let path = std::env::temp_dir().join("cxx-juce-file-loggers-write-messages.log");
+    let _ = std::fs::remove_file(&path);
+
+    let logger = FileLogger::new(&path, "Welcome", 0).unwrap();
+    assert_eq!(logger.log_file(), path);
+
+    juce_core::set_logger(logger);
+    juce_core::write_to_log("hello from Rust");
+    juce_core::clear_logger();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(contents.contains("Welcome"));
+    assert!(contents.contains("hello from Rust"));
+}
+
+#[test]
+fn files_can_be_created_found_and_deleted() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let directory = File::special_location(SpecialLocation::TempDirectory)
+        .nonexistent_child_file("cxx-juce-files", "");
+    assert!(!directory.exists());
+
+    let file = directory.child_file("nested/sound.wav");
+    file.create().unwrap();
+    assert!(directory.is_directory());
+    assert!(file.exists_as_file());
+    assert_eq!(file.size(), 0);
+    assert_eq!(file.file_name(), "sound.wav");
+    assert_eq!(file.file_name_without_extension(), "sound");
+    assert_eq!(file.extension(), ".wav");
+    assert_eq!(file.parent_directory(), directory.child_file("nested"));
+    assert_eq!(file.with_extension("aif").file_name(), "sound.aif");
+    assert_eq!(
+        directory.child_files(ChildFileType::Files, true, "*.wav"),
+        std::slice::from_ref(&file)
+    );
+    assert!(directory
+        .child_files(ChildFileType::Files, false, "*")
+        .is_empty());
+
+    assert!(!directory.delete_file());
+    assert!(directory.delete_recursively());
+    assert!(!file.exists());
+}
+
+#[test]
+fn relative_files_are_resolved_against_the_working_directory() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let file = File::new("some/file.txt").unwrap();
+
+    assert_eq!(
+        file.path(),
+        std::env::current_dir().unwrap().join("some/file.txt")
+    );
+    assert_eq!(
+        file,
+        File::current_working_directory().child_file("some/file.txt")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn paths_that_are_not_utf8_are_rejected() {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let path = Path::new(OsStr::from_bytes(b"not-utf8-\xff.txt"));
+
+    assert!(File::new(path).is_err());
+    assert!(FileLogger::new(path, "Welcome", 0).is_err());
+}
+
+#[test]
+fn temp_files_do_not_exist_until_created() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let file = File::temp_file(".tmp");
+
+    assert!(!file.exists());
+    assert_eq!(file.extension(), ".tmp");
+    assert_eq!(
+        file.parent_directory(),
+        File::special_location(SpecialLocation::TempDirectory)
+    );
+}
+
+#[test]
+fn xml_documents_can_be_parsed_and_inspected() {
+    let xml = XmlElement::parse(
+        r#"<PLUGINS version="2"><PLUGIN name="Gain" format="VST3"/><PLUGIN name="EQ">Notes</PLUGIN></PLUGINS>"#,
+    )
+    .unwrap();
+
+    assert_eq!(xml.tag_name(), "PLUGINS");
+    assert_eq!(xml.attribute("version").as_deref(), Some("2"));
+    assert_eq!(xml.attribute("missing"), None);
+    assert_eq!(xml.num_children(), 2);
+
+    let names: Vec<_> = xml
+        .children()
+        .filter_map(|child| child.attribute("name"))
+        .collect();
+    assert_eq!(names, ["Gain", "EQ"]);
+
+    let gain = xml.child_by_attribute("name", "Gain").unwrap();
+    assert_eq!(
+        gain.attributes(),
+        [
+            ("name".to_string(), "Gain".to_string()),
+            ("format".to_string(), "VST3".to_string())
+        ]
+    );
+
+    let eq = xml.child(1).unwrap();
+    assert_eq!(eq.all_sub_text(), "Notes");
+    assert!(eq.child(0).unwrap().is_text_element());
+    assert!(xml.child(2).is_none());
+}
+
+#[test]
+fn invalid_xml_is_an_error() {
+    assert!(XmlElement::parse("<unclosed>").is_err());
+    assert!(XmlElement::parse("").is_err());
+    assert!(XmlElement::new("not valid").is_err());
+}
+
+#[test]
+fn xml_documents_can_be_built_and_written() {
+    let mut xml = XmlElement::new("STATE").unwrap();
+    xml.set_attribute("gain", 0.5).unwrap();
+    assert!(xml.set_attribute("not valid", 1).is_err());
+
+    let child = xml.create_new_child("CHILD").unwrap();
+    child.add_text_element("text");
+    child.set_tag_name("RENAMED").unwrap();
+
+    let mut other = XmlElement::new("OTHER").unwrap();
+    other.set_attribute("id", 1).unwrap();
+    xml.insert_child(other, 0);
+
+    assert_eq!(
+        xml.to_xml_string(XmlTextFormat {
+            single_line: true,
+            include_header: false,
+        }),
+        r#"<STATE gain="0.5"><OTHER id="1"/><RENAMED>text</RENAMED></STATE>"#
+    );
+
+    let copy = xml.create_copy();
+    assert!(copy.is_equivalent_to(&xml, false));
+
+    xml.child_by_name_mut("OTHER")
+        .unwrap()
+        .remove_attribute("id");
+    assert!(!copy.is_equivalent_to(&xml, false));
+
+    let removed = xml.remove_child(0).unwrap();
+    assert_eq!(removed.tag_name(), "OTHER");
+    assert_eq!(xml.num_children(), 1);
+    xml.remove_all_children();
+    assert_eq!(xml.num_children(), 0);
+
+    let file = File::temp_file(".xml");
+    copy.write_to(&file, XmlTextFormat::default()).unwrap();
+    assert!(XmlElement::parse_file(&file)
+        .unwrap()
+        .is_equivalent_to(&copy, false));
+    assert!(file.delete_file());
+}
+
+#[test]
+fn json_is_parsed_into_vars() {
+    let json = Json::parse(r#"{"name": "Gain", "version": 2, "tags": ["fx", 1.5], "meta": null}"#)
+        .unwrap();
+
+    let Var::Object(object) = &json else {
+        panic!("expected an object, got {json:?}");
+    };
+    assert_eq!(
+        object.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+        ["name", "version", "tags", "meta"]
+    );
+    assert_eq!(json.property("name"), Some(&Var::from("Gain")));
+    assert_eq!(json.property("version"), Some(&Var::Int(2)));
+    assert_eq!(
+        json.property("tags").and_then(|tags| tags.element(1)),
+        Some(&Var::Double(1.5))
+    );
+    assert_eq!(json.property("meta"), Some(&Var::Void));
+    assert_eq!(json.property("missing"), None);
+
+    assert!(Json::parse("{").is_err());
+}
+
+#[test]
+fn vars_are_written_as_json() {
+    let mut object = DynamicObject::new();
+    object.set("name", "Gain");
+    object.set("enabled", true);
+    object.set("values", vec![Var::Int(1), Var::Int(2)]);
+    object.set("name", "EQ");
+
+    let json = Json::to_string(&object.clone().into(), true);
+    assert_eq!(json, r#"{"name": "EQ", "enabled": true, "values": [1, 2]}"#);
+    assert_eq!(Json::parse(&json).unwrap(), Var::Object(object));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn vars_convert_to_and_from_serde_json() {
+    let value = serde_json::json!({"name": "Gain", "count": 5_000_000_000_i64, "ratio": 0.5});
+
+    let var = Var::from(value.clone());
+    assert_eq!(var.property("count"), Some(&Var::Int64(5_000_000_000)));
+    assert_eq!(serde_json::Value::from(var), value);
+}
+
+#[test]
+fn urls_are_split_into_their_parts() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let url = Url::new("https://example.com:8080/audio/loop.wav?format=wav&rate=48000");
+
+    assert!(url.is_well_formed());
+    assert!(!url.is_local_file());
+    assert_eq!(url.scheme(), "https");
+    assert_eq!(url.domain(), "example.com");
+    assert_eq!(url.port(), Some(8080));
+    assert_eq!(url.sub_path(), "audio/loop.wav");
+    assert_eq!(url.file_name(), "loop.wav");
+    assert_eq!(
+        url.parameters(),
+        [
+            ("format".to_string(), "wav".to_string()),
+            ("rate".to_string(), "48000".to_string())
+        ]
+    );
+    assert_eq!(
+        url.to_string_with_parameters(false),
+        "https://example.com:8080/audio/loop.wav"
+    );
+}
+
+#[test]
+fn urls_can_be_built_from_parts() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let base = Url::new("https://example.com/samples");
+
+    let url = base
+        .child("drums")
+        .with_parameter("name", "kick")
+        .with_post_data(b"payload");
+
+    assert_eq!(url.sub_path(), "samples/drums");
+    assert_eq!(url.parent().sub_path(), "samples");
+    assert_eq!(
+        url.to_string(),
+        "https://example.com/samples/drums?name=kick"
+    );
+    assert_eq!(url.post_data(), b"payload");
+    assert_eq!(url.clone(), url);
+    assert_eq!(base.port(), None);
+}
+
+#[test]
+fn time_slice_clients_are_called_until_removed() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let thread = TimeSliceThread::new("test time slice thread");
+    let (sender, receiver) = mpsc::channel();
+
+    let client = thread.add_client(move || {
+        let _ = sender.send(());
+        Duration::from_millis(1)
+    });
+    assert_eq!(thread.num_clients(), 1);
+
+    for _ in 0..3 {
+        receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+    }
+
+    drop(client);
+    assert_eq!(thread.num_clients(), 0);
+}
+
+#[test]
+fn thread_pools_run_jobs_in_the_background() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let pool = ThreadPool::new(2).unwrap();
+    assert_eq!(pool.num_threads(), 2);
+
+    let (sender, receiver) = mpsc::channel();
+    for i in 0..4 {
+        let sender = sender.clone();
+        pool.add_job("test job", move |job| {
+            assert!(!job.should_exit());
+            sender.send(i).unwrap();
+        });
+    }
+
+    let mut results: Vec<_> = (0..4)
+        .map(|_| receiver.recv_timeout(Duration::from_secs(5)).unwrap())
+        .collect();
+    results.sort();
+    assert_eq!(results, [0, 1, 2, 3]);
+
+    assert!(pool.remove_all_jobs(false, Duration::from_secs(5)));
+    assert_eq!(pool.num_jobs(), 0);
+    assert!(ThreadPool::new(0).is_err());
+}
+
+#[test]
+fn running_thread_pool_jobs_can_be_interrupted() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let pool = ThreadPool::new(1).unwrap();
+    let (sender, receiver) = mpsc::channel();
+
+    pool.add_job("long job", move |job| {
+        sender.send(()).unwrap();
+        while !job.should_exit() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    });
+
+    receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(pool.remove_all_jobs(true, Duration::from_secs(5)));
+}
+
+#[test]
+fn system_stats_describe_the_computer() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    assert!(!SystemStats::operating_system_name().is_empty());
+    assert!(!SystemStats::computer_name().is_empty());
+    assert!(SystemStats::num_cpus() >= 1);
+    assert!(SystemStats::num_physical_cpus() >= 1);
+    assert!(SystemStats::num_physical_cpus() <= SystemStats::num_cpus());
+    assert!(SystemStats::memory_size_in_megabytes() > 0);
+    assert!(SystemStats::page_size().is_power_of_two());
+    assert_eq!(
+        SystemStats::is_operating_system_64_bit(),
+        cfg!(target_pointer_width = "64")
+    );
+
+    let features = SystemStats::cpu_features();
+    if cfg!(target_arch = "x86_64") {
+        assert!(features.sse2);
+    }
+    if cfg!(target_arch = "aarch64") {
+        assert!(features.neon);
+    }
+}
+
+#[test]
+fn uuids_are_random_and_round_trip_through_strings() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let uuid = Uuid::new();
+
+    assert!(!uuid.is_null());
+    assert_ne!(uuid, Uuid::new());
+    assert_eq!(uuid.to_string().parse::<Uuid>().unwrap(), uuid);
+    assert_eq!(Uuid::parse(&uuid.to_simple_string()).unwrap(), uuid);
+    assert!(Uuid::default().is_null());
+}
+
+#[test]
+fn uuids_are_formatted_like_the_standard() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let uuid = Uuid::parse("67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap();
+
+    assert_eq!(uuid.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    assert_eq!(uuid.to_simple_string(), "67e5504410b1426f9247bb680e5fe0c8");
+    assert_eq!(uuid.as_bytes()[..4], [0x67, 0xe5, 0x50, 0x44]);
+
+    assert!(Uuid::parse("not a uuid").is_err());
+    assert!(Uuid::parse("67e55044-10b1-426f-9247").is_err());
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuids_convert_to_and_from_the_uuid_crate() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let uuid = Uuid::new();
+
+    let converted = uuid::Uuid::from(uuid);
+    assert_eq!(converted.to_string(), uuid.to_string());
+    assert_eq!(Uuid::from(converted), uuid);
+}
+
+#[test]
+fn seeded_random_generators_are_deterministic() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let mut a = Random::with_seed(1234);
+    let mut b = Random::with_seed(1234);
+
+    let sequence: Vec<_> = (0..8).map(|_| a.next_int()).collect();
+    assert_eq!(sequence, (0..8).map(|_| b.next_int()).collect::<Vec<_>>());
+
+    let mut clone = a.clone();
+    assert_eq!(clone.seed(), a.seed());
+    assert_eq!(clone.next_int64(), a.next_int64());
+
+    a.set_seed(1234);
+    assert_eq!(a.next_int(), sequence[0]);
+}
+
+#[test]
+fn random_numbers_are_in_range() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let mut random = Random::new();
+
+    for _ in 0..100 {
+        assert!((10..20).contains(&random.next_int_in_range(10..20)));
+        assert!((0.0..1.0).contains(&random.next_float()));
+        assert!((0.0..1.0).contains(&random.next_double()));
+    }
+
+    let mut bytes = [0; 64];
+    random.fill_bytes(&mut bytes);
+    assert!(bytes.iter().any(|byte| *byte != 0));
+}
+
+#[test]
+fn the_system_random_generator_can_be_used_from_any_thread() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            std::thread::spawn(|| {
+                let mut system = Random::system();
+                system.set_seed(0);
+                (0..100).all(|_| (0..10).contains(&system.next_int_in_range(0..10)))
+            })
+        })
+        .collect();
+
+    assert!(handles.into_iter().all(|handle| handle.join().unwrap()));
+    assert_eq!(format!("{:?}", Random::system()), "Random::System");
+}
+
+#[test]
+fn random_numbers_can_be_drawn_from_ranges_wider_than_i32_max() {
+    let mut random = Random::with_seed(0);
+
+    assert!((0..100).all(|_| {
+        let value = random.next_int_in_range(i32::MIN..i32::MAX);
+        (i32::MIN..i32::MAX).contains(&value)
+    }));
+}
+
+#[test]
+#[should_panic]
+fn random_numbers_cannot_be_drawn_from_an_empty_range() {
+    Random::with_seed(0).next_int_in_range(5..5);
+}
+
+#[test]
+fn times_convert_to_and_from_system_time() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let time = Time::from_milliseconds(1_700_000_000_123);
+
+    let system_time = std::time::SystemTime::from(time);
+    assert_eq!(Time::from(system_time), time);
+    assert_eq!(
+        Time::from(std::time::UNIX_EPOCH - Duration::from_secs(1)),
+        Time::from_milliseconds(-1000)
+    );
+
+    assert_eq!(
+        (time + Duration::from_secs(1)).to_milliseconds(),
+        1_700_000_001_123
+    );
+    assert_eq!(
+        time - Duration::from_millis(123),
+        Time::from_milliseconds(1_700_000_000_000)
+    );
+
+    let now = Time::now();
+    let system_now = Time::from(std::time::SystemTime::now());
+    assert!((system_now.to_milliseconds() - now.to_milliseconds()).abs() < 1000);
+}
+
+#[test]
+fn times_are_split_into_local_components() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let time = Time::from_milliseconds(1_700_000_000_123);
+    let components = time.local_components();
+
+    assert!(components.month < 12);
+    assert!((1..=31).contains(&components.day_of_month));
+    assert!(components.day_of_week < 7);
+    assert_eq!(components.milliseconds, 123);
+    assert!(time
+        .to_iso8601(true)
+        .starts_with(&components.year.to_string()));
+    assert!(!time.to_formatted_string(true, true, true, true).is_empty());
+}
+
+#[test]
+fn high_resolution_ticks_convert_to_seconds() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let ticks_per_second = Time::high_resolution_ticks_per_second();
+
+    assert!(ticks_per_second > 0);
+    assert_eq!(
+        Time::high_resolution_ticks_to_seconds(ticks_per_second),
+        1.0
+    );
+    assert_eq!(
+        Time::seconds_to_high_resolution_ticks(2.0),
+        2 * ticks_per_second
+    );
+
+    let start = Time::high_resolution_ticks();
+    std::thread::sleep(Duration::from_millis(10));
+    let elapsed = Time::high_resolution_ticks_to_seconds(Time::high_resolution_ticks() - start);
+    assert!(elapsed >= 0.01);
+}
+
+#[test]
+fn zip_files_round_trip_through_memory() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let modified = Time::from_milliseconds(1_700_000_000_000);
+
+    let mut builder = ZipFileBuilder::new();
+    builder.add_entry(
+        b"preset one",
+        ZipFile::BEST_COMPRESSION,
+        "presets/one.txt",
+        modified,
+    );
+    builder.add_entry(
+        b"preset two",
+        ZipFile::NO_COMPRESSION,
+        "presets/two.txt",
+        modified,
+    );
+    let data = builder.to_bytes().unwrap();
+
+    let mut zip = ZipFile::from_bytes(data);
+    assert_eq!(zip.num_entries(), 2);
+
+    let entries = zip.entries();
+    assert_eq!(entries[0].file_name, "presets/one.txt");
+    assert_eq!(entries[0].uncompressed_size, 10);
+    assert!(!entries[0].is_symbolic_link);
+    assert!(zip.entry(2).is_none());
+
+    let index = zip.index_of("PRESETS/TWO.TXT", true).unwrap();
+    assert_eq!(zip.read_entry(index).unwrap(), b"preset two");
+    assert_eq!(zip.index_of("PRESETS/TWO.TXT", false), None);
+    assert!(zip.read_entry(2).is_err());
+}
+
+#[test]
+fn zip_files_can_be_written_and_uncompressed() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let directory = std::env::temp_dir().join("cxx-juce-zip-files-can-be-written-and-uncompressed");
+    let _ = std::fs::remove_dir_all(&directory);
+    std::fs::create_dir_all(&directory).unwrap();
+
+    let source = directory.join("sample.txt");
+    std::fs::write(&source, "sample data").unwrap();
+
+    let zip_path = directory.join("bundle.zip");
+    let mut builder = ZipFileBuilder::new();
+    builder.add_file(&source, 6, "samples/sample.txt").unwrap();
+    assert!(builder
+        .add_file(directory.join("missing.txt"), 6, "missing.txt")
+        .is_err());
+    builder.write_to_file(&zip_path).unwrap();
+
+    let mut zip = ZipFile::open(&zip_path).unwrap();
+    let output = directory.join("output");
+    zip.uncompress_to(&output, true).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(output.join("samples/sample.txt")).unwrap(),
+        "sample data"
+    );
+
+    std::fs::remove_dir_all(&directory).unwrap();
+    assert!(ZipFile::open(&zip_path).is_err());
+}
+
+#[test]
+fn memory_blocks_can_be_resized_and_appended_to() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let mut block = MemoryBlock::with_size(4);
+    assert_eq!(&*block, &[0, 0, 0, 0]);
+
+    block[0] = 1;
+    block.append(&[5, 6]);
+    assert_eq!(&*block, &[1, 0, 0, 0, 5, 6]);
+
+    block.resize(2);
+    assert_eq!(&*block, &[1, 0]);
+
+    let copy = block.clone();
+    block.clear();
+    assert!(block.is_empty());
+    assert_eq!(block, MemoryBlock::new());
+    assert_eq!(Vec::from(copy), vec![1, 0]);
+}
+
+#[test]
+fn memory_blocks_round_trip_through_base64_encoding() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let block = MemoryBlock::from(b"some binary data".to_vec());
+
+    let encoded = block.to_base64_encoding();
+    assert_eq!(MemoryBlock::from_base64_encoding(&encoded).unwrap(), block);
+    assert!(MemoryBlock::from_base64_encoding("not encoded").is_err());
+}
+
+#[test]
+fn base64_encodes_and_decodes_data() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    assert_eq!(Base64::encode("hello"), "aGVsbG8=");
+    assert_eq!(Base64::decode("aGVsbG8=").unwrap(), b"hello");
+    assert!(Base64::decode("not base64!").is_err());
+}
@@ -0,0 +1,304 @@
+#![cfg(feature = "juce_data_structures")]
+
+use cxx_juce::{
+    juce_core::{File, SpecialLocation, Var},
+    juce_data_structures::{
+        ApplicationProperties, PropertiesFile, PropertiesFileOptions, StorageFormat, UndoManager,
+        ValueTree, ValueTreeListener,
+    },
+    JUCE,
+};
+use std::{cell::RefCell, rc::Rc};
+
+fn test_options(name: &str) -> PropertiesFileOptions {
+    PropertiesFileOptions {
+        save_delay: None,
+        ..PropertiesFileOptions::new(name)
+    }
+}
+
+fn test_file(name: &str) -> File {
+    File::special_location(SpecialLocation::TempDirectory).nonexistent_child_file(name, ".settings")
+}
+
+#[test]
+fn properties_are_saved_and_reloaded() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let file = test_file("cxx-juce-properties");
+    let options = test_options("cxx-juce-properties");
+
+    let mut properties = PropertiesFile::open(&juce, &file, &options);
+    assert!(properties.is_valid_file());
+    assert_eq!(properties.value("missing"), None);
+
+    properties.set_value("name", "value");
+    properties.set_value("count", 42);
+    properties.set_value("enabled", true);
+    properties
+        .set_xml_value("state", "<STATE level=\"1\"/>")
+        .unwrap();
+    assert!(properties.set_xml_value("invalid", "not xml").is_err());
+    assert!(properties.needs_to_be_saved());
+    properties.save().unwrap();
+    assert!(!properties.needs_to_be_saved());
+    drop(properties);
+
+    let mut properties = PropertiesFile::open(&juce, &file, &options);
+    assert_eq!(properties.file(), file);
+    assert_eq!(properties.value("name").as_deref(), Some("value"));
+    assert_eq!(properties.int_value("count"), Some(42));
+    assert_eq!(properties.bool_value("enabled"), Some(true));
+    assert!(properties
+        .xml_value("state")
+        .unwrap()
+        .contains("level=\"1\""));
+    assert_eq!(properties.xml_value("name"), None);
+
+    let mut keys = properties.keys();
+    keys.sort();
+    assert_eq!(keys, ["count", "enabled", "name", "state"]);
+
+    properties.remove_value("name");
+    assert!(!properties.contains_key("name"));
+    properties.clear();
+    assert!(properties.keys().is_empty());
+
+    drop(properties);
+    assert!(file.delete_file());
+}
+
+#[test]
+fn properties_can_be_stored_in_binary() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let file = test_file("cxx-juce-binary-properties");
+    let options = PropertiesFileOptions {
+        storage_format: StorageFormat::CompressedBinary,
+        ..test_options("cxx-juce-binary-properties")
+    };
+
+    let mut properties = PropertiesFile::open(&juce, &file, &options);
+    properties.set_value("name", "value");
+    drop(properties);
+
+    let properties = PropertiesFile::open(&juce, &file, &options);
+    assert_eq!(properties.value("name").as_deref(), Some("value"));
+
+    drop(properties);
+    assert!(file.delete_file());
+}
+
+#[test]
+fn application_properties_are_stored_in_the_default_location() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let options = PropertiesFileOptions {
+        do_not_save: true,
+        ..test_options("cxx-juce-application-properties")
+    };
+
+    let mut properties = ApplicationProperties::new(&juce, options.clone());
+    assert_eq!(properties.user_settings().file(), options.default_file());
+
+    properties.user_settings().set_value("name", "value");
+    assert_eq!(
+        properties.user_settings().value("name").as_deref(),
+        Some("value")
+    );
+    properties.save_if_needed().unwrap();
+    assert!(!options.default_file().exists());
+}
+
+#[test]
+fn value_trees_store_typed_properties() {
+    let mut tree = ValueTree::new("STATE").unwrap();
+    tree.set_property("gain", 0.5, None).unwrap();
+    tree.set_property("name", "preset", None).unwrap();
+    tree.set_property("bypassed", false, None).unwrap();
+    tree.set_property("steps", vec![Var::Int(1), Var::Int64(2)], None)
+        .unwrap();
+    tree.set_property("data", vec![1u8, 2, 3], None).unwrap();
+
+    assert_eq!(tree.type_name(), "STATE");
+    assert_eq!(tree.num_properties(), 5);
+    assert_eq!(
+        tree.property_names(),
+        ["gain", "name", "bypassed", "steps", "data"]
+    );
+    assert_eq!(tree.property("gain"), Some(Var::Double(0.5)));
+    assert_eq!(tree.property("name"), Some(Var::from("preset")));
+    assert_eq!(tree.property("bypassed"), Some(Var::Bool(false)));
+    assert_eq!(
+        tree.property("steps"),
+        Some(Var::Array(vec![Var::Int(1), Var::Int64(2)]))
+    );
+    assert_eq!(tree.property("data"), Some(Var::Binary(vec![1, 2, 3])));
+    assert_eq!(tree.property("missing"), None);
+    assert!(tree.set_property("", 1, None).is_err());
+
+    tree.remove_property("name", None).unwrap();
+    assert!(!tree.has_property("name"));
+    tree.remove_all_properties(None);
+    assert_eq!(tree.num_properties(), 0);
+}
+
+#[test]
+fn value_trees_have_children() {
+    let mut root = ValueTree::new("ROOT").unwrap();
+    let first = ValueTree::new("FIRST").unwrap();
+    let mut second = ValueTree::new("SECOND").unwrap();
+    second.set_property("id", 2, None).unwrap();
+
+    root.append_child(&first, None).unwrap();
+    root.add_child(&second, 0, None).unwrap();
+    assert!(root.append_child(&first, None).is_err());
+    assert!(second.clone().append_child(&root, None).is_err());
+
+    assert_eq!(root.num_children(), 2);
+    assert_eq!(
+        root.children().collect::<Vec<_>>(),
+        [second.clone(), first.clone()]
+    );
+    assert_eq!(root.child(2), None);
+    assert_eq!(root.child_with_name("FIRST"), Some(first.clone()));
+    assert_eq!(root.child_with_property("id", 2), Some(second.clone()));
+    assert_eq!(root.index_of(&first), Some(1));
+    assert_eq!(first.parent(), Some(root.clone()));
+    assert_eq!(first.root(), root);
+    assert!(first.is_a_child_of(&root));
+
+    root.move_child(0, 1, None);
+    assert_eq!(root.index_of(&second), Some(1));
+
+    let third = root.get_or_create_child_with_name("THIRD", None).unwrap();
+    assert_eq!(
+        root.get_or_create_child_with_name("THIRD", None).unwrap(),
+        third
+    );
+
+    assert_eq!(root.remove_child(0, None), Some(first.clone()));
+    assert_eq!(first.parent(), None);
+    root.remove_all_children(None);
+    assert_eq!(root.num_children(), 0);
+}
+
+#[test]
+fn value_trees_are_shared_by_clones_but_not_copies() {
+    let mut tree = ValueTree::new("STATE").unwrap();
+    let clone = tree.clone();
+    let copy = tree.create_copy();
+
+    tree.set_property("gain", 1, None).unwrap();
+
+    assert_eq!(clone.property("gain"), Some(Var::Int(1)));
+    assert_eq!(copy.property("gain"), None);
+    assert_ne!(tree, copy);
+    assert!(!tree.is_equivalent_to(&copy));
+}
+
+#[test]
+fn value_trees_round_trip_through_xml_and_binary() {
+    let mut tree = ValueTree::new("STATE").unwrap();
+    tree.set_property("gain", 0.5, None).unwrap();
+    tree.set_property("count", 3_i64, None).unwrap();
+    let mut child = ValueTree::new("CHILD").unwrap();
+    child.set_property("name", "child", None).unwrap();
+    tree.append_child(&child, None).unwrap();
+
+    let from_binary = ValueTree::from_binary(&tree.to_binary()).unwrap();
+    assert!(from_binary.is_equivalent_to(&tree));
+    assert_eq!(from_binary.property("count"), Some(Var::Int64(3)));
+
+    let xml = tree.to_xml();
+    assert_eq!(xml.tag_name(), "STATE");
+    assert_eq!(xml.attribute("gain").as_deref(), Some("0.5"));
+    assert!(ValueTree::from_xml_element(&xml)
+        .unwrap()
+        .is_equivalent_to(&ValueTree::from_xml(&tree.to_xml_string()).unwrap()));
+
+    let from_xml = ValueTree::from_xml(&tree.to_xml_string()).unwrap();
+    assert_eq!(from_xml.type_name(), "STATE");
+    assert_eq!(
+        from_xml.child_with_name("CHILD").unwrap().property("name"),
+        Some(Var::from("child"))
+    );
+
+    assert!(ValueTree::from_xml("not xml").is_none());
+    assert!(ValueTree::from_binary(&[]).is_none());
+}
+
+#[test]
+fn value_tree_changes_can_be_undone() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+    let mut undo_manager = UndoManager::new();
+    let mut tree = ValueTree::new("STATE").unwrap();
+
+    undo_manager.begin_new_transaction("Set gain");
+    tree.set_property("gain", 1, Some(&mut undo_manager))
+        .unwrap();
+    undo_manager.begin_new_transaction("Add child");
+    tree.append_child(&ValueTree::new("CHILD").unwrap(), Some(&mut undo_manager))
+        .unwrap();
+
+    assert_eq!(undo_manager.undo_description(), "Add child");
+    assert!(undo_manager.undo());
+    assert_eq!(tree.num_children(), 0);
+    assert!(undo_manager.undo());
+    assert!(!tree.has_property("gain"));
+    assert!(!undo_manager.can_undo());
+
+    assert!(undo_manager.redo());
+    assert_eq!(tree.property("gain"), Some(Var::Int(1)));
+    assert!(undo_manager.can_redo());
+
+    undo_manager.clear_undo_history();
+    assert!(!undo_manager.can_redo());
+}
+
+struct RecordingListener(Rc<RefCell<Vec<String>>>);
+
+impl ValueTreeListener for RecordingListener {
+    fn property_changed(&self, tree: &ValueTree, property: &str) {
+        self.0
+            .borrow_mut()
+            .push(format!("{}.{property}", tree.type_name()));
+    }
+
+    fn child_added(&self, parent: &ValueTree, child: &ValueTree) {
+        self.0.borrow_mut().push(format!(
+            "added {} to {}",
+            child.type_name(),
+            parent.type_name()
+        ));
+    }
+
+    fn child_removed(&self, parent: &ValueTree, child: &ValueTree, index: usize) {
+        self.0.borrow_mut().push(format!(
+            "removed {} from {} at {index}",
+            child.type_name(),
+            parent.type_name()
+        ));
+    }
+}
+
+#[test]
+fn value_tree_listeners_are_notified_until_dropped() {
+    let events = Rc::new(RefCell::new(vec![]));
+    let mut tree = ValueTree::new("ROOT").unwrap();
+    let handle = tree.add_listener(RecordingListener(events.clone()));
+
+    let mut child = ValueTree::new("CHILD").unwrap();
+    tree.append_child(&child, None).unwrap();
+    child.set_property("gain", 1, None).unwrap();
+    tree.remove_child(0, None);
+
+    drop(handle);
+    tree.set_property("gain", 1, None).unwrap();
+
+    assert_eq!(
+        *events.borrow(),
+        [
+            "added CHILD to ROOT",
+            "CHILD.gain",
+            "removed CHILD from ROOT at 0"
+        ]
+    );
+}
@@ -0,0 +1,1122 @@
+#![cfg(feature = "juce_dsp")]
+
+use {
+    cxx_juce::{
+        juce_audio_basics::{AudioBuffer, Q},
+        juce_audio_devices::{InputAudioSampleBuffer, OutputAudioSampleBuffer},
+        juce_dsp::{
+            BallisticsFilter, BallisticsLevelCalculationType, Chorus, Complex, Compressor,
+            Convolution, DelayLine, DelayLineInterpolation, DryWetMixer, DryWetMixingRule,
+            FIRCoefficients, FIRFilter, FirstOrderTPTFilter, FirstOrderTPTFilterType, Gain,
+            IIRCoefficients, IIRFilter, ImpulseResponseOptions, LadderFilter, LadderFilterMode,
+            Limiter, LinkwitzRileyFilter, LinkwitzRileyFilterType, LookupTableTransform, NoiseGate,
+            Oscillator, Oversampling, OversamplingFactor, OversamplingFilterType, Panner,
+            PannerRule, Phaser, ProcessContextNonReplacing, ProcessContextReplacing, ProcessSpec,
+            Processor, ProcessorChain, Reverb, ReverbParameters, StateVariableTPTFilter,
+            StateVariableTPTFilterType, WaveShaper, Waveform, WindowingFunction, WindowingMethod,
+            FFT,
+        },
+    },
+    std::time::{Duration, Instant},
+};
+
+#[test]
+fn can_perform_a_complex_fft_and_its_inverse() {
+    let fft = FFT::new(3).unwrap();
+    assert_eq!(fft.size(), 8);
+
+    let input: Vec<_> = (0..8).map(|i| Complex::new(i as f32, 0.0)).collect();
+    let mut spectrum = vec![Complex::default(); 8];
+    fft.perform(&input, &mut spectrum, false).unwrap();
+    assert!((spectrum[0].re - 28.0).abs() < 1e-4);
+    assert!(spectrum[0].im.abs() < 1e-4);
+
+    let mut output = vec![Complex::default(); 8];
+    fft.perform(&spectrum, &mut output, true).unwrap();
+    for (output, input) in output.iter().zip(&input) {
+        assert!((output.re - input.re).abs() < 1e-4);
+        assert!(output.im.abs() < 1e-4);
+    }
+
+    assert!(fft.perform(&input[..4], &mut output, false).is_err());
+}
+
+#[test]
+fn can_find_the_frequency_of_a_sine_wave() {
+    let fft = FFT::new(6).unwrap();
+    let size = fft.size();
+
+    let mut data = vec![0.0; size * 2];
+    for (i, sample) in data[..size].iter_mut().enumerate() {
+        *sample = (std::f32::consts::TAU * 4.0 * i as f32 / size as f32).sin();
+    }
+
+    fft.perform_frequency_only_forward_transform(&mut data, true)
+        .unwrap();
+
+    let peak = data[..size / 2 + 1]
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(bin, _)| bin);
+    assert_eq!(peak, Some(4));
+
+    assert!(fft
+        .perform_frequency_only_forward_transform(&mut data[..size], true)
+        .is_err());
+}
+
+#[test]
+fn can_round_trip_real_data() {
+    let fft = FFT::new(4).unwrap();
+    let size = fft.size();
+
+    let input: Vec<f32> = (0..size).map(|i| (i as f32 * 0.3).cos()).collect();
+    let mut data = vec![0.0; size * 2];
+    data[..size].copy_from_slice(&input);
+
+    fft.perform_real_only_forward_transform(&mut data, false)
+        .unwrap();
+    let dc = Complex::new(data[0], data[1]);
+    assert!((dc.re - input.iter().sum::<f32>()).abs() < 1e-4);
+
+    fft.perform_real_only_inverse_transform(&mut data).unwrap();
+    for (output, input) in data[..size].iter().zip(&input) {
+        assert!((output - input).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn an_fft_order_that_is_too_large_is_an_error() {
+    assert!(FFT::new(31).is_err());
+}
+
+#[test]
+fn can_fill_a_windowing_table() {
+    let mut table = [0.0; 5];
+    WindowingFunction::fill_windowing_table(&mut table, WindowingMethod::Hann, false, 0.0);
+    assert_eq!(table[0], 0.0);
+    assert!((table[2] - 1.0).abs() < 1e-6);
+    assert!((table[1] - table[3]).abs() < 1e-6);
+
+    WindowingFunction::fill_windowing_table(&mut table, WindowingMethod::Rectangular, false, 0.0);
+    assert_eq!(table, [1.0; 5]);
+}
+
+#[test]
+fn can_apply_a_window_to_samples() {
+    let window = WindowingFunction::new(4, WindowingMethod::Triangular).with_normalisation(false);
+    assert_eq!(window.size(), 4);
+    assert_eq!(window.method(), WindowingMethod::Triangular);
+
+    let mut samples = [2.0; 6];
+    window.multiply_with_windowing_table(&mut samples);
+    assert!(samples[..4].iter().all(|sample| *sample < 2.0));
+    assert_eq!(samples[4..], [2.0, 2.0]);
+
+    let mut window = WindowingFunction::new(8, WindowingMethod::Kaiser).with_beta(4.0);
+    let mut samples = [1.0; 8];
+    window.multiply_with_windowing_table(&mut samples);
+    let sum: f32 = samples.iter().sum();
+    assert!((sum - 8.0).abs() < 1e-3);
+
+    window.set_window(8, WindowingMethod::Rectangular);
+    let mut samples = [1.0; 8];
+    window.multiply_with_windowing_table(&mut samples);
+    assert_eq!(samples, [1.0; 8]);
+}
+
+#[test]
+fn can_convolve_with_an_impulse_response() {
+    let spec = ProcessSpec {
+        sample_rate: 1000.0,
+        max_block_size: 16,
+        num_channels: 1,
+    };
+
+    let mut impulse_response = AudioBuffer::new(1, 3);
+    impulse_response[0].copy_from_slice(&[0.0, 0.0, 0.5]);
+
+    let mut convolution = Convolution::new();
+    convolution.prepare(&spec);
+    convolution.load_impulse_response(
+        &impulse_response,
+        spec.sample_rate,
+        &ImpulseResponseOptions::new()
+            .with_stereo(false)
+            .with_normalise(false),
+    );
+
+    // The impulse response is loaded on a background thread and crossfaded in while processing.
+    let mut silence = AudioBuffer::new(1, 16);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while convolution.current_ir_size() != 3 {
+        assert!(Instant::now() < deadline, "impulse response wasn't loaded");
+        convolution.process(&mut OutputAudioSampleBuffer::from(&mut silence));
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    for _ in 0..100 {
+        convolution.process(&mut OutputAudioSampleBuffer::from(&mut silence));
+    }
+
+    // Buffers larger than the prepared block size are processed in blocks.
+    let mut buffer = AudioBuffer::new(1, 40);
+    buffer[0][20] = 1.0;
+    convolution.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    assert_eq!(convolution.latency(), 0);
+    assert!(buffer[0][20].abs() < 1e-4);
+    assert!((buffer[0][22] - 0.5).abs() < 1e-4);
+    assert!(buffer[0][23].abs() < 1e-4);
+}
+
+#[test]
+fn loading_an_invalid_impulse_response_fails() {
+    let mut convolution = Convolution::with_non_uniform_partitioning(64);
+    let options = ImpulseResponseOptions::new();
+    assert!(!convolution.load_impulse_response_from_data(b"not audio", &options));
+    assert!(!convolution.load_impulse_response_from_file("does-not-exist.wav", &options));
+}
+
+#[test]
+fn can_design_iir_coefficients() {
+    let low_pass = IIRCoefficients::low_pass(48000.0, 1000.0, Q::default()).unwrap();
+    assert_eq!(low_pass.filter_order(), 2);
+    assert_eq!(low_pass.raw().len(), 5);
+    assert!((low_pass.magnitude_for_frequency(10.0, 48000.0) - 1.0).abs() < 1e-3);
+    assert!((low_pass.magnitude_for_frequency(1000.0, 48000.0) - 0.5_f64.sqrt()).abs() < 1e-3);
+    assert!(low_pass.magnitude_for_frequency(10000.0, 48000.0) < 0.05);
+
+    let first_order = IIRCoefficients::first_order_high_pass(48000.0, 1000.0).unwrap();
+    assert_eq!(first_order.filter_order(), 1);
+
+    let peak = IIRCoefficients::peak_filter(48000.0, 1000.0, Q(2.0), 2.0).unwrap();
+    assert!((peak.magnitude_for_frequency(1000.0, 48000.0) - 2.0).abs() < 1e-3);
+
+    assert!(IIRCoefficients::low_pass(48000.0, 30000.0, Q::default()).is_err());
+    assert!(IIRCoefficients::low_pass(48000.0, 1000.0, Q(0.0)).is_err());
+    assert!(IIRCoefficients::low_shelf(48000.0, 1000.0, Q::default(), 0.0).is_err());
+
+    let identity = IIRCoefficients::from_biquad(2.0, 0.0, 0.0, 2.0, 0.0, 0.0);
+    assert_eq!(identity.raw(), [1.0, 0.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn an_iir_filter_keeps_state_for_each_channel() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 64,
+        num_channels: 2,
+    };
+
+    let mut filter =
+        IIRFilter::new(IIRCoefficients::low_pass(spec.sample_rate, 100.0, Q::default()).unwrap());
+    filter.prepare(&spec);
+
+    // A DC signal on the first channel passes through once the filter has settled, while the
+    // silent second channel stays silent.
+    let mut buffer = AudioBuffer::new(2, 64);
+    for _ in 0..100 {
+        buffer[0].fill(1.0);
+        buffer[1].fill(0.0);
+        filter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    }
+    assert!((buffer[0][63] - 1.0).abs() < 1e-3);
+    assert_eq!(buffer[1], [0.0; 64]);
+
+    filter.set_coefficients(
+        IIRCoefficients::high_pass(spec.sample_rate, 100.0, Q::default()).unwrap(),
+    );
+    filter.reset();
+    for _ in 0..100 {
+        buffer[0].fill(1.0);
+        filter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    }
+    assert!(buffer[0][63].abs() < 1e-3);
+
+    assert_eq!(filter.process_sample(2, 0.5), 0.5);
+}
+
+#[test]
+fn can_design_fir_coefficients() {
+    let window =
+        FIRCoefficients::low_pass_window_method(48000.0, 4000.0, 32, WindowingMethod::Hann, 0.0)
+            .unwrap();
+    assert_eq!(window.filter_order(), 32);
+    assert!((window.magnitude_for_frequency(100.0, 48000.0) - 1.0).abs() < 0.05);
+    assert!(window.magnitude_for_frequency(16000.0, 48000.0) < 0.01);
+
+    let kaiser = FIRCoefficients::low_pass_kaiser_method(48000.0, 4000.0, 0.05, -60.0).unwrap();
+    assert!(kaiser.magnitude_for_frequency(8000.0, 48000.0) < 0.01);
+
+    let half_band = FIRCoefficients::half_band_equiripple_method(0.1, -60.0).unwrap();
+    assert!(half_band.magnitude_for_frequency(20000.0, 48000.0) < 0.01);
+
+    assert!(FIRCoefficients::low_pass_transition_method(48000.0, 4000.0, 32, 0.1, 2.0).is_ok());
+    assert!(FIRCoefficients::low_pass_least_squares_method(48000.0, 4000.0, 32, 0.1, 10.0).is_ok());
+
+    assert!(FIRCoefficients::low_pass_kaiser_method(48000.0, 4000.0, 0.05, 0.0).is_err());
+    assert!(FIRCoefficients::low_pass_window_method(
+        48000.0,
+        30000.0,
+        32,
+        WindowingMethod::Hann,
+        0.0
+    )
+    .is_err());
+}
+
+#[test]
+fn can_process_with_an_fir_filter() {
+    let spec = ProcessSpec {
+        sample_rate: 1000.0,
+        max_block_size: 8,
+        num_channels: 2,
+    };
+
+    let mut filter = FIRFilter::new(FIRCoefficients::new(vec![0.5, 0.25]));
+    assert_eq!(filter.coefficients().raw(), [0.5, 0.25]);
+    filter.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(2, 4);
+    buffer[0][0] = 1.0;
+    buffer[1][1] = 1.0;
+    filter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert_eq!(buffer[0], [0.5, 0.25, 0.0, 0.0]);
+    assert_eq!(buffer[1], [0.0, 0.5, 0.25, 0.0]);
+
+    filter.set_coefficients(FIRCoefficients::new(vec![0.0, 1.0]));
+    filter.reset();
+    assert_eq!(filter.process_sample(0, 1.0), 0.0);
+    assert_eq!(filter.process_sample(0, 0.0), 1.0);
+}
+
+#[test]
+fn can_process_with_a_state_variable_filter() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 256,
+        num_channels: 2,
+    };
+
+    let mut filter = StateVariableTPTFilter::new()
+        .with_type(StateVariableTPTFilterType::HighPass)
+        .with_cutoff_frequency(100.0)
+        .with_resonance(Q(2.0));
+    assert_eq!(filter.filter_type(), StateVariableTPTFilterType::HighPass);
+    assert_eq!(filter.cutoff_frequency(), 100.0);
+    assert_eq!(filter.resonance().0, 2.0);
+
+    let mut buffer = AudioBuffer::new(2, 4800);
+    buffer[0].fill(1.0);
+    buffer[1].fill(1.0);
+    filter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!(buffer[0].iter().all(|&sample| sample == 1.0));
+
+    filter.prepare(&spec);
+    filter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!(buffer[0][4799].abs() < 0.01);
+    assert!(buffer[1][4799].abs() < 0.01);
+
+    filter.set_type(StateVariableTPTFilterType::LowPass);
+    filter.reset();
+    let output = (0..4800)
+        .map(|_| filter.process_sample(0, 1.0))
+        .last()
+        .unwrap();
+    assert!((output - 1.0).abs() < 0.01);
+    assert_eq!(filter.process_sample(2, 0.5), 0.5);
+
+    filter.set_cutoff_frequency(96000.0);
+    assert!(filter.cutoff_frequency() < 24000.0);
+    filter.set_resonance(Q(0.0));
+    assert!(filter.resonance().0 > 0.0);
+    assert!(filter.process_sample(0, 1.0).is_finite());
+}
+
+#[test]
+fn can_split_a_signal_with_a_linkwitz_riley_filter() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 256,
+        num_channels: 1,
+    };
+
+    let mut filter = LinkwitzRileyFilter::new()
+        .with_type(LinkwitzRileyFilterType::HighPass)
+        .with_cutoff_frequency(1000.0);
+    assert_eq!(filter.filter_type(), LinkwitzRileyFilterType::HighPass);
+    assert_eq!(filter.cutoff_frequency(), 1000.0);
+    assert_eq!(filter.process_sample_bands(0, 1.0), (1.0, 0.0));
+
+    filter.prepare(&spec);
+
+    let mut low = AudioBuffer::new(1, 4800);
+    let mut high = AudioBuffer::new(1, 4800);
+    low[0].fill(1.0);
+    filter.split(
+        &mut OutputAudioSampleBuffer::from(&mut low),
+        &mut OutputAudioSampleBuffer::from(&mut high),
+    );
+    assert!((low[0][4799] - 1.0).abs() < 0.01);
+    assert!(high[0][4799].abs() < 0.01);
+
+    filter.reset();
+    let mut all_pass = LinkwitzRileyFilter::new()
+        .with_type(LinkwitzRileyFilterType::AllPass)
+        .with_cutoff_frequency(1000.0);
+    all_pass.prepare(&spec);
+
+    for n in 0..480 {
+        let sample = (n as f32 * 0.3).sin();
+        let (low, high) = filter.process_sample_bands(0, sample);
+        assert!((low + high - all_pass.process_sample(0, sample)).abs() < 1.0e-4);
+    }
+
+    filter.reset();
+    let mut buffer = AudioBuffer::new(1, 4800);
+    buffer[0].fill(1.0);
+    filter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!(buffer[0][4799].abs() < 0.01);
+}
+
+#[test]
+fn can_process_with_a_first_order_filter() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 256,
+        num_channels: 1,
+    };
+
+    let mut filter = FirstOrderTPTFilter::new()
+        .with_type(FirstOrderTPTFilterType::HighPass)
+        .with_cutoff_frequency(1000.0);
+    assert_eq!(filter.filter_type(), FirstOrderTPTFilterType::HighPass);
+    assert_eq!(filter.cutoff_frequency(), 1000.0);
+    assert_eq!(filter.process_sample(0, 0.5), 0.5);
+
+    filter.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(2, 4800);
+    buffer[0].fill(1.0);
+    buffer[1].fill(1.0);
+    filter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!(buffer[0][4799].abs() < 0.01);
+    assert_eq!(buffer[1][4799], 1.0);
+
+    filter.set_type(FirstOrderTPTFilterType::LowPass);
+    filter.reset();
+    let output = (0..4800)
+        .map(|_| filter.process_sample(0, 1.0))
+        .last()
+        .unwrap();
+    assert!((output - 1.0).abs() < 0.01);
+
+    filter.set_cutoff_frequency(-10.0);
+    assert_eq!(filter.cutoff_frequency(), f64::from(f32::EPSILON));
+}
+
+#[test]
+fn can_generate_a_waveform_with_an_oscillator() {
+    let spec = ProcessSpec {
+        sample_rate: 4000.0,
+        max_block_size: 3,
+        num_channels: 2,
+    };
+
+    let mut oscillator = Oscillator::new(Waveform::Sine, 0).with_frequency(1000.0);
+    assert_eq!(oscillator.frequency(), 1000.0);
+
+    let mut buffer = AudioBuffer::new(2, 8);
+    oscillator.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!(buffer[0].iter().all(|&sample| sample == 0.0));
+
+    oscillator.prepare(&spec);
+    oscillator.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    let expected = [0.0, -1.0, 0.0, 1.0, 0.0, -1.0, 0.0, 1.0];
+    for channel in 0..2 {
+        for (sample, expected) in buffer[channel].iter().zip(expected) {
+            assert!((sample - expected).abs() < 1.0e-4);
+        }
+    }
+
+    oscillator.reset();
+    assert!((oscillator.process_sample(0.5) - 0.5).abs() < 1.0e-4);
+    assert!((oscillator.process_sample(0.5) + 0.5).abs() < 1.0e-4);
+
+    oscillator.set_frequency(500.0);
+    assert_eq!(oscillator.frequency(), 500.0);
+}
+
+#[test]
+fn can_play_a_wavetable_with_an_oscillator() {
+    let spec = ProcessSpec {
+        sample_rate: 4000.0,
+        max_block_size: 8,
+        num_channels: 1,
+    };
+
+    let mut oscillator = Oscillator::from_wavetable(&[0.0, 1.0, 0.0, -1.0])
+        .unwrap()
+        .with_frequency(500.0);
+    oscillator.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(1, 8);
+    oscillator.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    let expected = [0.0, 0.5, 1.0, 0.5, 0.0, -0.5, -1.0, -0.5];
+    for (sample, expected) in buffer[0].iter().zip(expected) {
+        assert!((sample - expected).abs() < 1.0e-4);
+    }
+
+    assert!(Oscillator::from_wavetable(&[]).is_err());
+}
+
+#[test]
+fn can_process_with_a_ladder_filter() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 256,
+        num_channels: 1,
+    };
+
+    let mut filter = LadderFilter::new()
+        .with_mode(LadderFilterMode::LowPass24)
+        .with_cutoff_frequency(200.0)
+        .with_resonance(2.0)
+        .with_drive(0.5);
+    assert_eq!(filter.mode(), LadderFilterMode::LowPass24);
+    assert_eq!(filter.cutoff_frequency(), 200.0);
+    assert_eq!(filter.resonance(), 1.0);
+    assert_eq!(filter.drive(), 1.0);
+
+    filter.set_resonance(0.0);
+    filter.prepare(&spec);
+
+    let alternating = |buffer: &mut AudioBuffer| {
+        for (n, sample) in buffer[0].iter_mut().enumerate() {
+            *sample = if n % 2 == 0 { 0.1 } else { -0.1 };
+        }
+    };
+
+    let mut buffer = AudioBuffer::new(1, 4800);
+    alternating(&mut buffer);
+    filter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!(buffer[0][4000..].iter().all(|sample| sample.abs() < 0.001));
+
+    filter.set_enabled(false);
+    assert!(!filter.is_enabled());
+    alternating(&mut buffer);
+    filter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert_eq!(buffer[0][4799], -0.1);
+
+    filter.set_cutoff_frequency(96000.0);
+    assert!(filter.cutoff_frequency() < 24000.0);
+}
+
+#[test]
+fn can_delay_a_signal_with_a_delay_line() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 8,
+        num_channels: 2,
+    };
+
+    let mut delay_line = DelayLine::new(DelayLineInterpolation::Linear, 16).with_delay(2.0);
+    assert_eq!(delay_line.delay(), 2.0);
+    assert_eq!(delay_line.maximum_delay_in_samples(), 16);
+
+    delay_line.set_delay(100.0);
+    assert_eq!(delay_line.delay(), 16.0);
+    delay_line.set_delay(f32::NAN);
+    assert_eq!(delay_line.delay(), 0.0);
+    delay_line.set_delay(f32::INFINITY);
+    assert_eq!(delay_line.delay(), 16.0);
+    delay_line.set_delay(f32::NEG_INFINITY);
+    assert_eq!(delay_line.delay(), 0.0);
+
+    let largest = i32::MAX as usize - 2;
+    assert_eq!(
+        DelayLine::new(DelayLineInterpolation::None, usize::MAX).maximum_delay_in_samples(),
+        largest
+    );
+    delay_line.set_maximum_delay_in_samples(usize::MAX);
+    assert_eq!(delay_line.maximum_delay_in_samples(), largest);
+    delay_line.set_maximum_delay_in_samples(16);
+
+    delay_line.set_delay(2.0);
+    delay_line.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(2, 6);
+    buffer[0][0] = 1.0;
+    buffer[1][1] = 1.0;
+    delay_line.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert_eq!(buffer[0], [0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(buffer[1], [0.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    delay_line.reset();
+    delay_line.push_sample(0, 1.0);
+    assert_eq!(delay_line.read_sample_with_delay(0, 0.0), 1.0);
+    assert_eq!(delay_line.pop_sample_with_delay(0, 0.5), 0.5);
+    assert_eq!(delay_line.delay(), 0.5);
+
+    delay_line.push_sample(2, 1.0);
+    assert_eq!(delay_line.pop_sample(2), 0.0);
+
+    delay_line.set_maximum_delay_in_samples(32);
+    assert_eq!(delay_line.maximum_delay_in_samples(), 32);
+}
+
+#[test]
+fn can_process_with_a_reverb() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 512,
+        num_channels: 2,
+    };
+
+    let mut reverb = Reverb::new().with_parameters(&ReverbParameters {
+        room_size: 2.0,
+        ..ReverbParameters::default()
+    });
+    assert_eq!(reverb.parameters().room_size, 1.0);
+    assert_eq!(reverb.parameters().damping, 0.5);
+    assert!(!reverb.parameters().freeze_mode);
+
+    let dry = ReverbParameters {
+        wet_level: 0.0,
+        dry_level: 0.5,
+        ..ReverbParameters::default()
+    };
+    reverb.set_parameters(&dry);
+    reverb.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(2, 4800);
+    buffer[0][0] = 1.0;
+    buffer[1][0] = 1.0;
+    reverb.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!((buffer[0][0] - 1.0).abs() < 1.0e-4);
+    assert!(buffer[0][1..].iter().all(|sample| sample.abs() < 1.0e-4));
+
+    let wet = ReverbParameters {
+        wet_level: 1.0,
+        dry_level: 0.0,
+        ..ReverbParameters::default()
+    };
+    reverb.set_parameters(&wet);
+    reverb.prepare(&spec);
+    reverb.reset();
+
+    let mut left = vec![0.0; 4800];
+    let mut right = vec![0.0; 4800];
+    left[0] = 1.0;
+    right[0] = 1.0;
+    reverb.process_stereo(&mut left, &mut right);
+    assert!(left[2400..].iter().any(|sample| sample.abs() > 1.0e-6));
+
+    let mut mono = vec![0.0; 4800];
+    mono[0] = 1.0;
+    reverb.reset();
+    reverb.process_mono(&mut mono);
+    assert!(mono[2400..].iter().any(|sample| sample.abs() > 1.0e-6));
+}
+
+#[test]
+fn can_process_with_a_compressor() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 256,
+        num_channels: 2,
+    };
+
+    let mut compressor = Compressor::new()
+        .with_threshold(-20.0)
+        .with_ratio(0.5)
+        .with_attack(Duration::from_millis(1))
+        .with_release(Duration::from_millis(50));
+    assert_eq!(compressor.threshold(), -20.0);
+    assert_eq!(compressor.ratio(), 1.0);
+    assert_eq!(compressor.attack(), Duration::from_millis(1));
+    assert_eq!(compressor.release(), Duration::from_millis(50));
+
+    compressor.set_ratio(10.0);
+    compressor.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(2, 4800);
+    buffer[0].fill(1.0);
+    buffer[1].fill(0.01);
+    compressor.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    // 0dB is 20dB above the threshold, which is reduced to 2dB above it at 10:1.
+    assert!((buffer[0][4799] - 0.126).abs() < 0.01);
+    assert!((buffer[1][4799] - 0.01).abs() < 1.0e-4);
+
+    assert_eq!(compressor.process_sample(2, 1.0), 1.0);
+}
+
+#[test]
+fn can_process_with_a_limiter() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 256,
+        num_channels: 2,
+    };
+
+    let mut limiter = Limiter::new()
+        .with_threshold(-6.0)
+        .with_release(Duration::from_millis(50));
+    assert_eq!(limiter.threshold(), -6.0);
+    assert_eq!(limiter.release(), Duration::from_millis(50));
+
+    limiter.set_threshold(0.0);
+    limiter.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(2, 4800);
+    for (n, sample) in buffer[0].iter_mut().enumerate() {
+        *sample = 4.0 * (n as f32 * 0.05).sin();
+    }
+    buffer[1].fill(0.25);
+    limiter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    assert!(buffer[0].iter().all(|sample| sample.abs() <= 1.0));
+    assert!((buffer[1][4799] - 0.25).abs() < 0.01);
+}
+
+#[test]
+fn can_process_with_a_noise_gate() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 256,
+        num_channels: 2,
+    };
+
+    let mut gate = NoiseGate::new()
+        .with_threshold(-40.0)
+        .with_ratio(0.0)
+        .with_attack(Duration::from_millis(1))
+        .with_release(Duration::from_millis(20));
+    assert_eq!(gate.threshold(), -40.0);
+    assert_eq!(gate.ratio(), 1.0);
+    assert_eq!(gate.attack(), Duration::from_millis(1));
+    assert_eq!(gate.release(), Duration::from_millis(20));
+
+    gate.set_ratio(10.0);
+    gate.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(2, 4800);
+    buffer[0].fill(0.5);
+    buffer[1].fill(0.001);
+    gate.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    assert!((buffer[0][4799] - 0.5).abs() < 1.0e-3);
+    assert!(buffer[1][4799].abs() < 1.0e-6);
+
+    assert_eq!(gate.process_sample(2, 0.001), 0.001);
+}
+
+#[test]
+fn can_process_with_a_phaser() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 256,
+        num_channels: 2,
+    };
+
+    let mut phaser = Phaser::new()
+        .with_rate(0.5)
+        .with_depth(2.0)
+        .with_centre_frequency(800.0)
+        .with_feedback(-2.0)
+        .with_mix(0.0);
+    assert_eq!(phaser.rate(), 0.5);
+    assert_eq!(phaser.depth(), 1.0);
+    assert_eq!(phaser.centre_frequency(), 800.0);
+    assert_eq!(phaser.feedback(), -1.0);
+    assert_eq!(phaser.mix(), 0.0);
+
+    phaser.set_feedback(0.5);
+    phaser.prepare(&spec);
+
+    let signal = |buffer: &mut AudioBuffer| {
+        for channel in 0..2 {
+            for (n, sample) in buffer[channel].iter_mut().enumerate() {
+                *sample = (n as f32 * 0.1).sin();
+            }
+        }
+    };
+
+    let mut buffer = AudioBuffer::new(2, 4800);
+    signal(&mut buffer);
+    phaser.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!((buffer[0][4799] - (4799.0f32 * 0.1).sin()).abs() < 1.0e-3);
+
+    phaser.set_mix(1.0);
+    phaser.prepare(&spec);
+    signal(&mut buffer);
+    phaser.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!((buffer[0][4799] - (4799.0f32 * 0.1).sin()).abs() > 1.0e-3);
+    assert!(buffer[0].iter().all(|sample| sample.is_finite()));
+}
+
+#[test]
+fn can_process_with_a_chorus() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 256,
+        num_channels: 2,
+    };
+
+    let mut chorus = Chorus::new()
+        .with_rate(2.0)
+        .with_depth(-1.0)
+        .with_centre_delay(Duration::from_secs(1))
+        .with_feedback(0.25)
+        .with_mix(0.0);
+    assert_eq!(chorus.rate(), 2.0);
+    assert_eq!(chorus.depth(), 0.0);
+    assert_eq!(chorus.centre_delay(), Duration::from_micros(99_999));
+    assert_eq!(chorus.feedback(), 0.25);
+    assert_eq!(chorus.mix(), 0.0);
+
+    chorus.set_centre_delay(Duration::from_millis(10));
+    chorus.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(2, 4800);
+    buffer[0][0] = 1.0;
+    chorus.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!((buffer[0][0] - 1.0).abs() < 1.0e-3);
+    assert!(buffer[0][1..].iter().all(|sample| sample.abs() < 1.0e-3));
+
+    chorus.set_mix(1.0);
+    chorus.prepare(&spec);
+    let mut buffer = AudioBuffer::new(2, 4800);
+    buffer[0][0] = 1.0;
+    chorus.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!(buffer[0][..400].iter().all(|sample| sample.abs() < 1.0e-3));
+    assert!(buffer[0][400..].iter().any(|sample| sample.abs() > 0.1));
+}
+
+#[test]
+fn can_shape_a_signal_with_a_rust_function() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 2,
+        num_channels: 1,
+    };
+
+    let mut clipper = WaveShaper::new(|x| x.clamp(-0.5, 0.5));
+    assert_eq!(clipper.process_sample(1.0), 0.5);
+
+    let mut buffer = AudioBuffer::new(2, 4);
+    buffer[0].copy_from_slice(&[1.0, -1.0, 0.25, -0.25]);
+    buffer[1].copy_from_slice(&[1.0, -1.0, 0.25, -0.25]);
+    clipper.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert_eq!(buffer[0], [1.0, -1.0, 0.25, -0.25]);
+
+    clipper.prepare(&spec);
+    clipper.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert_eq!(buffer[0], [0.5, -0.5, 0.25, -0.25]);
+    assert_eq!(buffer[1], [1.0, -1.0, 0.25, -0.25]);
+}
+
+#[test]
+fn can_shape_a_signal_with_a_lookup_table() {
+    let shaper = WaveShaper::with_lookup_table(f32::tanh, -5.0, 5.0, 1024).unwrap();
+    assert!((shaper.process_sample(0.5) - 0.5f32.tanh()).abs() < 1.0e-3);
+    assert!((shaper.process_sample(100.0) - 5.0f32.tanh()).abs() < 1.0e-3);
+
+    assert!(WaveShaper::with_lookup_table(f32::tanh, 1.0, -1.0, 1024).is_err());
+    assert!(WaveShaper::with_lookup_table(f32::tanh, -1.0, 1.0, 1).is_err());
+}
+
+#[test]
+fn can_mix_dry_and_wet_signals_with_latency() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 8,
+        num_channels: 1,
+    };
+
+    let mut mixer = DryWetMixer::new(4)
+        .with_mixing_rule(DryWetMixingRule::Balanced)
+        .with_wet_mix_proportion(2.0)
+        .with_wet_latency(10.0);
+    assert_eq!(mixer.mixing_rule(), DryWetMixingRule::Balanced);
+    assert_eq!(mixer.wet_mix_proportion(), 1.0);
+    assert_eq!(mixer.wet_latency(), 4.0);
+    assert_eq!(mixer.maximum_wet_latency(), 4);
+
+    mixer.set_mixing_rule(DryWetMixingRule::Linear);
+    mixer.set_wet_mix_proportion(0.5);
+    mixer.set_wet_latency(2.0);
+    mixer.prepare(&spec);
+
+    let mut delay_line = DelayLine::new(DelayLineInterpolation::None, 4).with_delay(2.0);
+    delay_line.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(1, 8);
+    buffer[0][0] = 1.0;
+    let mut output = OutputAudioSampleBuffer::from(&mut buffer);
+    mixer.push_dry_samples(&output.as_input());
+    delay_line.process(&mut output);
+    mixer.mix_wet_samples(&mut output);
+
+    assert_eq!(buffer[0], [0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn can_pan_a_stereo_signal() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 8,
+        num_channels: 2,
+    };
+
+    let mut panner = Panner::new().with_rule(PannerRule::Linear).with_pan(-2.0);
+    assert_eq!(panner.rule(), PannerRule::Linear);
+    assert_eq!(panner.pan(), -1.0);
+    panner.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(2, 8);
+    buffer[0].fill(1.0);
+    buffer[1].fill(1.0);
+    panner.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    assert!(buffer[0].iter().all(|sample| (sample - 2.0).abs() < 1e-6));
+    assert!(buffer[1].iter().all(|sample| sample.abs() < 1e-6));
+}
+
+#[test]
+fn can_follow_the_envelope_of_a_signal() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 512,
+        num_channels: 1,
+    };
+
+    let mut filter = BallisticsFilter::new()
+        .with_level_calculation_type(BallisticsLevelCalculationType::Rms)
+        .with_attack(Duration::ZERO)
+        .with_release(Duration::from_millis(10));
+    assert_eq!(
+        filter.level_calculation_type(),
+        BallisticsLevelCalculationType::Rms
+    );
+    assert_eq!(filter.attack(), Duration::ZERO);
+    assert_eq!(filter.release(), Duration::from_millis(10));
+
+    filter.set_level_calculation_type(BallisticsLevelCalculationType::Peak);
+    filter.prepare(&spec);
+    assert_eq!(filter.process_sample(1, -0.5), -0.5);
+
+    let mut buffer = AudioBuffer::new(1, 512);
+    buffer[0][0] = -1.0;
+    filter.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    assert!((buffer[0][0] - 1.0).abs() < 1e-6);
+    assert!(buffer[0].windows(2).all(|pair| pair[1] < pair[0]));
+    assert!(buffer[0][511] > 0.0);
+}
+
+#[test]
+fn can_oversample_a_signal() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 64,
+        num_channels: 1,
+    };
+
+    let mut oversampling = Oversampling::new(
+        OversamplingFactor::X4,
+        OversamplingFilterType::HalfBandPolyphaseIIR,
+    )
+    .with_maximum_quality(false)
+    .with_integer_latency(true);
+    assert_eq!(oversampling.factor(), OversamplingFactor::X4);
+    assert_eq!(oversampling.oversampling_factor(), 4);
+    assert_eq!(
+        oversampling.filter_type(),
+        OversamplingFilterType::HalfBandPolyphaseIIR
+    );
+    assert!(!oversampling.is_maximum_quality());
+    assert!(oversampling.is_using_integer_latency());
+    assert_eq!(oversampling.latency_in_samples().fract(), 0.0);
+
+    let mut buffer = AudioBuffer::new(1, 64);
+    assert!(oversampling
+        .process_samples_up(&InputAudioSampleBuffer::from(&buffer))
+        .is_err());
+
+    oversampling.prepare(&spec);
+
+    for _ in 0..16 {
+        buffer[0].fill(0.5);
+        let mut output = OutputAudioSampleBuffer::from(&mut buffer);
+
+        let mut upsampled = oversampling.process_samples_up(&output.as_input()).unwrap();
+        assert_eq!(upsampled.channels(), 1);
+        assert_eq!(upsampled.samples(), 256);
+        upsampled[0].iter_mut().for_each(|sample| *sample *= 2.0);
+
+        oversampling.process_samples_down(&mut output).unwrap();
+    }
+
+    assert!(buffer[0].iter().all(|sample| (sample - 1.0).abs() < 1e-3));
+
+    let too_large = AudioBuffer::new(1, 65);
+    assert!(oversampling
+        .process_samples_up(&InputAudioSampleBuffer::from(&too_large))
+        .is_err());
+}
+
+#[test]
+fn can_ramp_the_gain_of_a_signal() {
+    let spec = ProcessSpec {
+        sample_rate: 1000.0,
+        max_block_size: 20,
+        num_channels: 1,
+    };
+
+    let mut gain = Gain::new()
+        .with_gain_decibels(-6.0)
+        .with_ramp_duration(Duration::from_millis(10));
+    assert!((gain.gain_decibels() + 6.0).abs() < 1e-4);
+    assert_eq!(gain.ramp_duration(), Duration::from_millis(10));
+
+    gain.prepare(&spec);
+    gain.set_gain_linear(1.0);
+    assert_eq!(gain.gain_linear(), 1.0);
+    gain.reset();
+    assert!(!gain.is_smoothing());
+
+    gain.set_gain_linear(0.0);
+    assert!(gain.is_smoothing());
+
+    let mut buffer = AudioBuffer::new(1, 20);
+    buffer[0].fill(1.0);
+    gain.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+
+    assert!(buffer[0][..10].windows(2).all(|pair| pair[1] < pair[0]));
+    assert!(buffer[0][10..].iter().all(|sample| *sample == 0.0));
+    assert!(!gain.is_smoothing());
+}
+
+#[test]
+fn lookup_table_transforms_approximate_a_function() {
+    let transform = LookupTableTransform::new(|x| x * x, -1.0, 1.0, 201).unwrap();
+    assert_eq!(transform.minimum_input(), -1.0);
+    assert_eq!(transform.maximum_input(), 1.0);
+    assert_eq!(transform.num_points(), 201);
+
+    assert!((transform.process_sample(0.5) - 0.25).abs() < 1e-3);
+    assert!((transform.process_sample(-0.25) - 0.0625).abs() < 1e-3);
+    assert!((transform.process_sample(2.0) - 1.0).abs() < 1e-3);
+    assert!((transform.process_sample(f32::NAN) - 1.0).abs() < 1e-3);
+
+    let mut samples = [-1.0, 0.0, 0.3];
+    transform.process(&mut samples);
+    assert!((samples[0] - 1.0).abs() < 1e-3);
+    assert!(samples[1].abs() < 1e-3);
+    assert!((samples[2] - 0.09).abs() < 1e-3);
+
+    let transform = LookupTableTransform::new(|x| x + 2.0, 0.0, 1.0, 16).unwrap();
+    assert!((transform.process_sample(f32::NAN) - 2.0).abs() < 1e-3);
+    assert!((transform.process_sample(f32::INFINITY) - 3.0).abs() < 1e-3);
+
+    let mut samples = [f32::NAN, -1.0, 0.5, f32::NEG_INFINITY];
+    transform.process(&mut samples);
+    assert!((samples[0] - 2.0).abs() < 1e-3);
+    assert!((samples[1] - 2.0).abs() < 1e-3);
+    assert!((samples[2] - 2.5).abs() < 1e-3);
+    assert!((samples[3] - 2.0).abs() < 1e-3);
+
+    assert!(LookupTableTransform::new(|x| x, 1.0, 1.0, 16).is_err());
+    assert!(LookupTableTransform::new(|x| x, 0.0, 1.0, 1).is_err());
+}
+
+#[test]
+fn processor_chains_process_in_order() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 8,
+        num_channels: 1,
+    };
+
+    let mut chain = ProcessorChain::new()
+        .with(Gain::new().with_gain_linear(4.0))
+        .with(WaveShaper::new(|x| x.min(1.0)))
+        .with(Gain::new().with_gain_linear(0.5));
+    assert_eq!(chain.len(), 3);
+    assert!(!chain.is_empty());
+    assert!(chain.get::<Gain>(1).is_none());
+    assert_eq!(chain.get::<Gain>(2).unwrap().gain_linear(), 0.5);
+
+    chain.prepare(&spec);
+
+    let mut buffer = AudioBuffer::new(1, 8);
+    buffer[0].fill(0.5);
+    chain.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!(buffer[0].iter().all(|sample| *sample == 0.5));
+
+    chain.set_bypassed(1, true);
+    assert!(chain.is_bypassed(1));
+    assert!(!chain.is_bypassed(3));
+    chain.get_mut::<Gain>(0).unwrap().set_gain_linear(1.0);
+    chain.reset();
+
+    buffer[0].fill(0.5);
+    chain.process(&mut OutputAudioSampleBuffer::from(&mut buffer));
+    assert!(buffer[0].iter().all(|sample| *sample == 0.25));
+}
+
+#[test]
+fn processors_share_replacing_and_non_replacing_contexts() {
+    let spec = ProcessSpec {
+        sample_rate: 48000.0,
+        max_block_size: 8,
+        num_channels: 2,
+    };
+
+    let mut processors: Vec<Box<dyn Processor>> = vec![
+        Box::new(Gain::new().with_gain_linear(0.5)),
+        Box::new(ProcessorChain::new().with(Gain::new().with_gain_linear(0.5))),
+        Box::new(WaveShaper::new(|x| x * 0.5)),
+    ];
+
+    for processor in &mut processors {
+        processor.prepare(&spec);
+
+        let mut buffer = AudioBuffer::new(2, 8);
+        buffer[0].fill(1.0);
+        buffer[1].fill(-1.0);
+
+        let mut output = OutputAudioSampleBuffer::from(&mut buffer);
+        processor.process(&mut ProcessContextReplacing::new(&mut output).with_bypassed(true));
+        assert_eq!(output[0][0], 1.0);
+
+        let mut context = ProcessContextReplacing::new(&mut output);
+        assert_eq!(context.input_buffer()[1][0], -1.0);
+        processor.process(&mut context);
+        assert!(buffer[0].iter().all(|sample| *sample == 0.5));
+        assert!(buffer[1].iter().all(|sample| *sample == -0.5));
+
+        let mut output_buffer = AudioBuffer::new(1, 4);
+        let mut output = OutputAudioSampleBuffer::from(&mut output_buffer);
+        let mut context =
+            ProcessContextNonReplacing::new(InputAudioSampleBuffer::from(&buffer), &mut output);
+        assert_eq!(context.input_buffer().samples(), 8);
+        processor.process_non_replacing(&mut context);
+        assert_eq!(output_buffer[0], [0.25; 4]);
+
+        let mut output = OutputAudioSampleBuffer::from(&mut output_buffer);
+        let mut context =
+            ProcessContextNonReplacing::new(InputAudioSampleBuffer::from(&buffer), &mut output)
+                .with_bypassed(true);
+        processor.process_non_replacing(&mut context);
+        assert_eq!(output_buffer[0], [0.5; 4]);
+
+        processor.reset();
+    }
+}
@@ -0,0 +1,173 @@
+use cxx_juce::{
+    juce_events::{
+        call_async, HighResolutionTimer, InterprocessConnection, InterprocessConnectionListener,
+        InterprocessConnectionRef, InterprocessConnectionServer, MessageManager, Timer,
+    },
+    JUCE,
+};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    time::Duration,
+};
+
+#[test]
+fn the_initialising_thread_is_the_message_thread() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    assert!(MessageManager::is_this_the_message_thread());
+    assert!(
+        !std::thread::spawn(MessageManager::is_this_the_message_thread)
+            .join()
+            .unwrap()
+    );
+}
+
+#[test]
+fn the_dispatch_loop_only_runs_on_the_message_thread() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            assert!(MessageManager::run_dispatch_loop_for(&juce, Duration::ZERO).is_err());
+        });
+    });
+
+    assert!(MessageManager::run_dispatch_loop_for(&juce, Duration::ZERO).unwrap());
+}
+
+#[test]
+fn the_dispatch_loop_can_be_stopped_from_another_thread() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+
+    std::thread::spawn(MessageManager::stop_dispatch_loop)
+        .join()
+        .unwrap();
+    assert!(MessageManager::has_stop_been_called());
+
+    MessageManager::run_dispatch_loop(&juce).unwrap();
+    assert!(!MessageManager::run_dispatch_loop_for(&juce, Duration::ZERO).unwrap());
+}
+
+#[test]
+fn functions_can_be_called_on_the_message_thread() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let called_on_message_thread = Arc::new(AtomicBool::new(false));
+
+    let called = called_on_message_thread.clone();
+    std::thread::spawn(move || {
+        assert!(call_async(move || {
+            called.store(
+                MessageManager::is_this_the_message_thread(),
+                Ordering::SeqCst,
+            );
+            MessageManager::stop_dispatch_loop();
+        }));
+    })
+    .join()
+    .unwrap();
+
+    MessageManager::run_dispatch_loop(&juce).unwrap();
+    assert!(called_on_message_thread.load(Ordering::SeqCst));
+    assert!(!call_async(|| {}));
+}
+
+#[test]
+fn timers_call_their_function_until_stopped() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let counter = calls.clone();
+    let mut timer = Timer::new(&juce, Duration::from_millis(1), move || {
+        if counter.fetch_add(1, Ordering::SeqCst) == 2 {
+            MessageManager::stop_dispatch_loop();
+        }
+    });
+
+    assert!(!timer.is_running());
+    timer.start();
+    assert!(timer.is_running());
+
+    MessageManager::run_dispatch_loop(&juce).unwrap();
+    timer.stop();
+
+    assert!(!timer.is_running());
+    assert!(calls.load(Ordering::SeqCst) >= 3);
+}
+
+#[test]
+fn high_resolution_timers_call_their_function_on_another_thread() {
+    let juce = JUCE::wait_to_initialise_in_test_context();
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    let mut timer = HighResolutionTimer::new(&juce, Duration::from_millis(1), move || {
+        let _ = sender.send(std::thread::current().id());
+    });
+    timer.start();
+
+    for _ in 0..3 {
+        let thread = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_ne!(thread, std::thread::current().id());
+    }
+
+    timer.stop();
+    assert!(!timer.is_running());
+}
+
+struct EchoListener;
+
+impl InterprocessConnectionListener for EchoListener {
+    fn message_received(&mut self, connection: &InterprocessConnectionRef, message: &[u8]) {
+        connection.send_message(message);
+    }
+}
+
+#[test]
+fn interprocess_connections_exchange_messages_over_a_socket() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    let mut server = InterprocessConnectionServer::new(|| EchoListener);
+    server.start(0, "127.0.0.1").unwrap();
+    let port = server.port().unwrap();
+
+    let (sender, receiver) = mpsc::channel();
+    let mut client =
+        InterprocessConnection::new(move |_: &InterprocessConnectionRef, message: &[u8]| {
+            sender.send(message.to_vec()).unwrap();
+        });
+
+    client
+        .connect_to_socket("127.0.0.1", port, Some(Duration::from_secs(5)))
+        .unwrap();
+    assert!(client.is_connected());
+
+    assert!(client.send_message(b"hello"));
+    assert_eq!(
+        receiver.recv_timeout(Duration::from_secs(5)).unwrap(),
+        b"hello"
+    );
+
+    client.disconnect();
+    assert!(!client.is_connected());
+    assert!(!client.send_message(b"goodbye"));
+
+    server.stop();
+    assert_eq!(server.port(), None);
+}
+
+#[test]
+fn connecting_to_a_closed_port_is_an_error() {
+    let _juce = JUCE::wait_to_initialise_in_test_context();
+
+    let mut server = InterprocessConnectionServer::new(|| EchoListener);
+    server.start(0, "127.0.0.1").unwrap();
+    let port = server.port().unwrap();
+    drop(server);
+
+    let mut client = InterprocessConnection::new(EchoListener);
+    assert!(client
+        .connect_to_socket("127.0.0.1", port, Some(Duration::from_secs(1)))
+        .is_err());
+}